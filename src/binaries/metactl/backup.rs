@@ -0,0 +1,372 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Online backup of a running metasrv to a storage backend, and point-in-time restore from it.
+//!
+//! A backup dir contains two kinds of files:
+//! - `full-{last_applied_index}-{backup_time_ms}.json`: every key-value in the state machine.
+//! - `logs-{first_index}-{last_index}.json`: the applied raft logs since the previous backup.
+//!
+//! Every line in them is a json string in form of `(tree_name, entry)`.
+
+use anyhow::anyhow;
+use common_meta_client::MetaGrpcClient;
+use common_meta_raft_store::config::RaftConfig;
+use common_meta_raft_store::key_spaces::RaftStoreEntry;
+use common_meta_raft_store::state::RaftState;
+use common_meta_raft_store::state_machine::StateMachine;
+use common_meta_raft_store::state_machine::StateMachineMetaKey;
+use common_meta_raft_store::state_machine::StateMachineMetaValue;
+use common_meta_sled_store::get_sled_db;
+use common_meta_sled_store::init_sled_db;
+use common_meta_types::protobuf::BackupRequest;
+use common_meta_types::LogEntry;
+use common_meta_types::SeqV;
+use futures::io::AsyncBufReadExt;
+use futures::io::BufReader;
+use opendal::Operator;
+use openraft::raft::Entry;
+use openraft::raft::EntryPayload;
+use tokio_stream::Stream;
+use tokio_stream::StreamExt;
+
+use crate::snapshot::clear;
+use crate::snapshot::init_new_cluster;
+use crate::Config;
+
+/// A file in the backup dir.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BackupFile {
+    Full { index: u64, time_ms: u64 },
+    Logs { first: u64, last: u64 },
+}
+
+impl BackupFile {
+    fn parse(name: &str) -> Option<Self> {
+        let stem = name.strip_suffix(".json")?;
+        let mut parts = stem.split('-');
+        let kind = parts.next()?;
+        let a = parts.next()?.parse::<u64>().ok()?;
+        let b = parts.next()?.parse::<u64>().ok()?;
+
+        match kind {
+            "full" => Some(BackupFile::Full {
+                index: a,
+                time_ms: b,
+            }),
+            "logs" => Some(BackupFile::Logs { first: a, last: b }),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            BackupFile::Full { index, time_ms } => format!("full-{:020}-{}.json", index, time_ms),
+            BackupFile::Logs { first, last } => format!("logs-{:020}-{:020}.json", first, last),
+        }
+    }
+
+    /// The last log index this file covers.
+    fn last_index(&self) -> u64 {
+        match self {
+            BackupFile::Full { index, .. } => *index,
+            BackupFile::Logs { last, .. } => *last,
+        }
+    }
+}
+
+/// Back up a running metasrv to the storage.
+///
+/// Only the raft logs since the last backup are backed up,
+/// unless `--backup-full` is specified, there is no backup yet, or these logs are purged.
+pub async fn backup(config: &Config) -> anyhow::Result<()> {
+    if config.grpc_api_address.is_empty() {
+        return Err(anyhow!("backup requires --grpc-api-address"));
+    }
+
+    let op = backup_operator(config)?;
+    let files = list_backup_files(&op).await?;
+    let last_index = files.iter().map(|f| f.last_index()).max();
+
+    let client = MetaGrpcClient::try_create(
+        vec![config.grpc_api_address.clone()],
+        "root",
+        "xxx",
+        None,
+        None,
+        None,
+    )?;
+    let mut grpc_client = client.make_client().await?;
+
+    if let (Some(since), false) = (last_index, config.backup_full) {
+        let res = grpc_client
+            .backup(tonic::Request::new(BackupRequest {
+                logs_since: Some(since),
+            }))
+            .await;
+
+        match res {
+            Ok(resp) => {
+                let lines = read_lines(resp.into_inner()).await?;
+                if lines.is_empty() {
+                    eprintln!("no new raft log since {}, nothing to back up", since);
+                    return Ok(());
+                }
+
+                let entries = parse_logs(&lines)?;
+                let file = BackupFile::Logs {
+                    first: entries.first().unwrap().log_id.index,
+                    last: entries.last().unwrap().log_id.index,
+                };
+                write_backup_file(&op, &file, &lines).await?;
+                return Ok(());
+            }
+            Err(status) if status.code() == tonic::Code::FailedPrecondition => {
+                eprintln!("{}, fall back to a full backup", status.message());
+            }
+            Err(status) => return Err(status.into()),
+        }
+    }
+
+    let time_ms = SeqV::<()>::now_ms();
+    let resp = grpc_client
+        .backup(tonic::Request::new(BackupRequest { logs_since: None }))
+        .await?;
+    let lines = read_lines(resp.into_inner()).await?;
+
+    let index = last_applied(&lines)?;
+    let file = BackupFile::Full { index, time_ms };
+    write_backup_file(&op, &file, &lines).await?;
+
+    Ok(())
+}
+
+/// Rebuild the meta dir of a fresh cluster from the backups,
+/// up to `--restore-to-index` or `--restore-to-time`, or to the latest backup if none is specified.
+pub async fn restore(config: &Config) -> anyhow::Result<()> {
+    if config.initial_cluster.is_empty() {
+        return Err(anyhow!("restore requires --initial-cluster"));
+    }
+
+    let to_index = config.restore_to_index.unwrap_or(u64::MAX);
+    let to_time_ms = if config.restore_to_time.is_empty() {
+        u64::MAX
+    } else {
+        let t = chrono::DateTime::parse_from_rfc3339(&config.restore_to_time)?;
+        t.timestamp_millis() as u64
+    };
+
+    let op = backup_operator(config)?;
+    let files = list_backup_files(&op).await?;
+
+    // The latest full backup that does not contain any change after the target.
+    let full = files
+        .iter()
+        .filter_map(|f| match f {
+            BackupFile::Full { index, time_ms } if *index <= to_index && *time_ms <= to_time_ms => {
+                Some(f.clone())
+            }
+            _ => None,
+        })
+        .max_by_key(|f| f.last_index())
+        .ok_or_else(|| anyhow!("no full backup before the restore target"))?;
+
+    eprintln!("restore meta dir into: {}", config.raft_config.raft_dir);
+    eprintln!("restore from: {}", full.name());
+
+    init_sled_db(config.raft_config.raft_dir.clone());
+    clear()?;
+
+    let db = get_sled_db();
+    let raft_config = RaftConfig {
+        id: config.raft_config.id,
+        ..Default::default()
+    };
+    let raft_state = RaftState::open_create(&db, &raft_config, None, Some(())).await?;
+    let (sm_id, _prev_sm_id) = raft_state.read_state_machine_id()?;
+    let sm = StateMachine::open(&raft_config, sm_id).await?;
+
+    // Load the full backup into the state machine.
+    {
+        let mut lines = read_backup_file(&op, &full).await?;
+        let tree = db.open_tree(&sm.sm_tree.name)?;
+        let mut n = 0;
+        while let Some(line) = lines.next().await {
+            let (_tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(&line?)?;
+            let (k, v) = RaftStoreEntry::serialize(&entry)?;
+            tree.insert(k, v)?;
+            n += 1;
+        }
+        tree.flush_async().await?;
+        eprintln!("loaded {} records", n);
+    }
+
+    // Apply the logs after the full backup, until the target.
+    let mut next = full.last_index() + 1;
+    let mut logs = files
+        .iter()
+        .filter_map(|f| match f {
+            BackupFile::Logs { first, last } if *last >= next => Some((*first, f.clone())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    logs.sort_by_key(|(first, _)| *first);
+
+    'files: for (first, file) in logs {
+        if first > next {
+            return Err(anyhow!(
+                "can not restore beyond log index {}, the raft logs since {} are missing in the backup",
+                next - 1,
+                next
+            ));
+        }
+
+        let mut lines = read_backup_file(&op, &file).await?;
+        while let Some(line) = lines.next().await {
+            let Some(ent) = parse_log(&line?)? else {
+                continue;
+            };
+            if ent.log_id.index < next {
+                continue;
+            }
+            if ent.log_id.index > to_index || log_time_ms(&ent) > Some(to_time_ms) {
+                break 'files;
+            }
+
+            sm.apply(&ent).await?;
+            next = ent.log_id.index + 1;
+        }
+    }
+
+    let last_applied = sm.get_last_applied()?;
+    eprintln!("restored to log: {:?}", last_applied);
+
+    if config.restore_to_index.is_some() && next <= to_index {
+        return Err(anyhow!(
+            "can not restore to log index {}, the backup ends at {}",
+            to_index,
+            next - 1
+        ));
+    }
+
+    drop(sm);
+    drop(raft_state);
+
+    init_new_cluster(config.initial_cluster.clone(), None, config.raft_config.id).await?;
+
+    Ok(())
+}
+
+fn backup_operator(config: &Config) -> anyhow::Result<Operator> {
+    let storage: common_storage::StorageConfig = config.storage.clone().try_into()?;
+    let op = common_storage::init_operator_without_layers(&storage.params)?;
+    Ok(op)
+}
+
+async fn list_backup_files(op: &Operator) -> anyhow::Result<Vec<BackupFile>> {
+    let mut files = vec![];
+
+    let mut ds = match op.object("/").list().await {
+        Err(e) if e.kind() == opendal::ErrorKind::ObjectNotFound => return Ok(files),
+        Err(e) => return Err(e.into()),
+        Ok(ds) => ds,
+    };
+
+    while let Some(de) = ds.next().await {
+        let de = de?;
+        if let Some(f) = BackupFile::parse(de.name()) {
+            files.push(f);
+        }
+    }
+
+    Ok(files)
+}
+
+async fn write_backup_file(
+    op: &Operator,
+    file: &BackupFile,
+    lines: &[String],
+) -> anyhow::Result<()> {
+    let mut data = Vec::new();
+    for line in lines {
+        data.extend_from_slice(line.as_bytes());
+        data.push(b'\n');
+    }
+
+    op.object(&file.name()).write(data).await?;
+
+    eprintln!("backup {} records into {}", lines.len(), file.name());
+    Ok(())
+}
+
+/// Read the lines of a backup file as they are fetched, the file may not fit in memory.
+async fn read_backup_file(
+    op: &Operator,
+    file: &BackupFile,
+) -> anyhow::Result<impl Stream<Item = std::io::Result<String>> + Unpin> {
+    let reader = op.object(&file.name()).reader().await?;
+    Ok(BufReader::new(reader).lines())
+}
+
+async fn read_lines(
+    mut stream: tonic::Streaming<common_meta_types::protobuf::ExportedChunk>,
+) -> anyhow::Result<Vec<String>> {
+    let mut lines = vec![];
+    while let Some(chunk) = stream.next().await {
+        lines.extend(chunk?.data);
+    }
+    Ok(lines)
+}
+
+fn parse_logs(lines: &[String]) -> anyhow::Result<Vec<Entry<LogEntry>>> {
+    let mut entries = vec![];
+    for line in lines {
+        if let Some(entry) = parse_log(line)? {
+            entries.push(entry);
+        }
+    }
+    Ok(entries)
+}
+
+/// Parse a line of a backup file, which is a raft log if it is in the logs tree.
+fn parse_log(line: &str) -> anyhow::Result<Option<Entry<LogEntry>>> {
+    let (_tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(line)?;
+    match entry {
+        RaftStoreEntry::Logs { value, .. } => Ok(Some(value)),
+        _ => Ok(None),
+    }
+}
+
+/// Find the last applied log index in a full backup.
+fn last_applied(lines: &[String]) -> anyhow::Result<u64> {
+    for line in lines {
+        let (_tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(line)?;
+        if let RaftStoreEntry::StateMachineMeta {
+            key: StateMachineMetaKey::LastApplied,
+            value: StateMachineMetaValue::LogId(log_id),
+        } = entry
+        {
+            return Ok(log_id.index);
+        }
+    }
+    Ok(0)
+}
+
+/// The time a log is proposed, if it is a normal log.
+fn log_time_ms(ent: &Entry<LogEntry>) -> Option<u64> {
+    match &ent.payload {
+        EntryPayload::Normal(log_entry) => log_entry.time_ms,
+        _ => None,
+    }
+}
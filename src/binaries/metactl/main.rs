@@ -14,6 +14,8 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod backup;
+
 mod grpc;
 use grpc::export_meta;
 
@@ -65,6 +67,24 @@ pub struct Config {
 
     #[clap(flatten)]
     pub raft_config: RaftConfig,
+
+    /// The storage to back up to or restore from, e.g. `--storage-type s3 --storage-s3-bucket ...`.
+    #[clap(flatten)]
+    #[serde(skip)]
+    pub storage: common_config::StorageConfig,
+
+    /// Back up the whole state machine even if there is a previous backup.
+    #[clap(long)]
+    pub backup_full: bool,
+
+    /// The raft log index to restore to. The latest backed up log is used if it is absent.
+    #[clap(long)]
+    pub restore_to_index: Option<u64>,
+
+    /// The time in RFC3339 to restore to, e.g. `2023-01-01T00:00:00Z`.
+    /// Logs proposed after this time are not restored.
+    #[clap(long, default_value = "")]
+    pub restore_to_time: String,
 }
 
 /// TODO: This is a temp copy of RaftConfig, we will migrate them in the future.
@@ -211,11 +231,23 @@ async fn main() -> anyhow::Result<()> {
                 Ok(())
             }
 
+            "backup" => backup::backup(&config).await,
+
+            "restore" => backup::restore(&config).await,
+
             _ => {
                 eprintln!("valid commands are");
                 eprintln!("  --cmd bench-client-conn-num");
                 eprintln!("    Keep create new connections to metasrv.");
                 eprintln!("    Requires --grpc-api-address.");
+                eprintln!("  --cmd backup");
+                eprintln!("    Back up a running metasrv to the storage specified by --storage-*.");
+                eprintln!("    Only logs since the last backup are saved, unless --backup-full.");
+                eprintln!("    Requires --grpc-api-address.");
+                eprintln!("  --cmd restore");
+                eprintln!("    Restore a new cluster from the backups in --storage-*,");
+                eprintln!("    up to --restore-to-index or --restore-to-time.");
+                eprintln!("    Requires --raft-dir, --id and --initial-cluster.");
 
                 Err(anyhow::anyhow!("unknown cmd: {}", config.cmd))
             }
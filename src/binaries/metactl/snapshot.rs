@@ -146,7 +146,7 @@ fn import_from(restore: String) -> anyhow::Result<Option<LogId>> {
 }
 
 // initial_cluster format: node_id=endpoint,grpc_api_addr;
-async fn init_new_cluster(
+pub async fn init_new_cluster(
     initial_cluster: Vec<String>,
     max_log_id: Option<LogId>,
     id: u64,
@@ -251,7 +251,7 @@ async fn init_new_cluster(
     Ok(())
 }
 
-fn clear() -> anyhow::Result<()> {
+pub fn clear() -> anyhow::Result<()> {
     let db = get_sled_db();
 
     let tree_names = db.tree_names();
@@ -18,7 +18,16 @@ use poem::EndpointExt;
 use poem::Route;
 use poem::Server;
 use sharing_endpoint::configs::Config;
+use sharing_endpoint::handlers::get_share;
+use sharing_endpoint::handlers::list_all_tables;
+use sharing_endpoint::handlers::list_schemas;
+use sharing_endpoint::handlers::list_shares;
+use sharing_endpoint::handlers::list_tables;
 use sharing_endpoint::handlers::presign_files;
+use sharing_endpoint::handlers::query_table;
+use sharing_endpoint::handlers::table_metadata;
+use sharing_endpoint::handlers::table_version;
+use sharing_endpoint::middlewares::DeltaSharingAuth;
 use sharing_endpoint::middlewares::SharingAuth;
 use sharing_endpoint::services::SharingServices;
 #[tokio::main]
@@ -27,12 +36,43 @@ async fn main() -> Result<(), std::io::Error> {
     SharingServices::init(config.clone())
         .await
         .expect("failed to init sharing service");
-    let app = Route::new()
-        .at(
-            "/tenant/:tenant_id/:share_name/table/:table_name/presign",
-            poem::post(presign_files),
-        )
-        .with(SharingAuth);
+    let mut app = Route::new().at(
+        "/tenant/:tenant_id/:share_name/table/:table_name/presign",
+        poem::post(presign_files).with(SharingAuth),
+    );
+
+    // the open Delta Sharing protocol, only served to the recipients with bearer tokens
+    let delta_sharing_auth = DeltaSharingAuth::try_create(&config.delta_sharing_recipients)
+        .expect("invalid delta sharing recipients");
+    if !delta_sharing_auth.is_empty() {
+        let delta_sharing = Route::new()
+            .at("/shares", poem::get(list_shares))
+            .at("/shares/:share", poem::get(get_share))
+            .at("/shares/:share/schemas", poem::get(list_schemas))
+            .at(
+                "/shares/:share/schemas/:schema/tables",
+                poem::get(list_tables),
+            )
+            .at("/shares/:share/all-tables", poem::get(list_all_tables))
+            .at(
+                "/shares/:share/schemas/:schema/tables/:table",
+                poem::head(table_version),
+            )
+            .at(
+                "/shares/:share/schemas/:schema/tables/:table/version",
+                poem::get(table_version),
+            )
+            .at(
+                "/shares/:share/schemas/:schema/tables/:table/metadata",
+                poem::get(table_metadata),
+            )
+            .at(
+                "/shares/:share/schemas/:schema/tables/:table/query",
+                poem::post(query_table),
+            )
+            .with(delta_sharing_auth);
+        app = app.nest("/delta-sharing", delta_sharing);
+    }
 
     Server::new(TcpListener::bind(config.share_endpoint_address))
         .run(app)
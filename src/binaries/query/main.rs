@@ -29,6 +29,7 @@ use common_exception::Result;
 use common_meta_client::MIN_METASRV_SEMVER;
 use common_meta_embedded::MetaEmbedded;
 use common_metrics::init_default_metrics_recorder;
+use common_metrics::init_otlp_metrics_exporter;
 use common_tracing::set_panic_hook;
 use databend_query::api::HttpService;
 use databend_query::api::RpcService;
@@ -97,6 +98,12 @@ async fn main_entrypoint() -> Result<()> {
     let cluster_id = conf.query.cluster_id.clone();
     let flight_addr = conf.query.flight_api_address.clone();
 
+    // Same service name as the spans and logs exported by the `QueryLogger`.
+    init_otlp_metrics_exporter(
+        &format!("databend-query-{}-{}", tenant, cluster_id),
+        &conf.log.otlp,
+    )?;
+
     let mut _sentry_guard = None;
     let bend_sentry_env = env::var("DATABEND_SENTRY_DSN").unwrap_or_else(|_| "".to_string());
     if !bend_sentry_env.is_empty() {
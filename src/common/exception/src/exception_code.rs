@@ -133,6 +133,8 @@ build_exceptions! {
     PanicError(1104),
     TableInfoError(1106),
     ReadTableDataError(1107),
+    /// The samples of all the nodes are not merged in time before a range or skew-aware exchange.
+    ExchangeSampleTimeout(1108),
 
     // Data Related Errors
 
@@ -204,6 +206,14 @@ build_exceptions! {
     /// - not exists.
     /// - and without `IF EXISTS`
     CatalogNotFound(2320),
+    SequenceAlreadyExists(2321),
+    UnknownSequence(2322),
+    /// `SequenceOverflow` should be raised when the next value of a sequence is out of the range of i64.
+    SequenceOverflow(2323),
+    InvertedIndexAlreadyExists(2324),
+    UnknownInvertedIndex(2325),
+    /// `TransactionError` should be raised when an explicit transaction can not be started or committed.
+    TransactionError(2326),
 
     // Cluster error codes.
     ClusterUnknownNode(2401),
@@ -248,6 +258,14 @@ build_exceptions! {
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
 
+    // Workload group error codes.
+    IllegalWorkloadGroupFormat(2921),
+    UnknownWorkloadGroup(2922),
+    WorkloadGroupAlreadyExists(2923),
+    /// `WorkloadGroupQueueTimeout` should be raised when a query waits in the queue of its workload group
+    /// for longer than the `queue_timeout` of the group.
+    WorkloadGroupQueueTimeout(2924),
+
 }
 
 // Storage errors [3001, 4000].
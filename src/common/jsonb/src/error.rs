@@ -80,12 +80,19 @@ pub enum Error {
     InvalidJsonbJEntry,
 
     Syntax(ParseErrorCode, usize),
+
+    InvalidJsonPath(String, usize),
+    JsonPathEvaluation(String),
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::Syntax(code, pos) => write!(f, "{}, pos {}", code, pos),
+            Error::InvalidJsonPath(msg, pos) => {
+                write!(f, "invalid JSON path: {}, pos {}", msg, pos)
+            }
+            Error::JsonPathEvaluation(msg) => write!(f, "{}", msg),
             _ => write!(f, "{:?}", self),
         }
     }
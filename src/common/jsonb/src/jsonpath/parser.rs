@@ -0,0 +1,742 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+
+use regex::Regex;
+
+use super::path::*;
+use crate::error::Error;
+use crate::number::Number;
+use crate::util::parse_escaped_string;
+use crate::value::Value;
+
+/// Parse a SQL/JSON path, such as `lax $.a[*] ? (@.b > 10).c`.
+pub fn parse_json_path(path: &[u8]) -> Result<JsonPath<'_>, Error> {
+    let tokens = tokenize(path)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        filter_depth: 0,
+        subscript_depth: 0,
+    };
+    parser.parse()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token<'a> {
+    Dollar,
+    At,
+    Dot,
+    Star,
+    DoubleStar,
+    Comma,
+    Question,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    LBrace,
+    RBrace,
+    Not,
+    And,
+    Or,
+    Eq,
+    NotEq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    Plus,
+    Minus,
+    Slash,
+    Percent,
+    Ident(&'a str),
+    String(Cow<'a, str>),
+    Number(Number),
+    Eof,
+}
+
+fn is_ident_start(c: u8) -> bool {
+    c.is_ascii_alphabetic() || c == b'_' || c >= 0x80
+}
+
+fn is_ident_char(c: u8) -> bool {
+    is_ident_start(c) || c.is_ascii_digit()
+}
+
+fn tokenize(input: &[u8]) -> Result<Vec<(Token<'_>, usize)>, Error> {
+    let mut tokens = Vec::new();
+    let mut idx = 0;
+    while idx < input.len() {
+        let start = idx;
+        let c = input[idx];
+        let next = input.get(idx + 1).cloned();
+        let token = match c {
+            b' ' | b'\t' | b'\n' | b'\r' => {
+                idx += 1;
+                continue;
+            }
+            b'$' => {
+                if next.map(is_ident_char).unwrap_or(false) {
+                    return Err(Error::InvalidJsonPath(
+                        "path variables are not supported".to_string(),
+                        start,
+                    ));
+                }
+                idx += 1;
+                Token::Dollar
+            }
+            b'@' => {
+                idx += 1;
+                Token::At
+            }
+            b'.' => {
+                idx += 1;
+                Token::Dot
+            }
+            b'*' if next == Some(b'*') => {
+                idx += 2;
+                Token::DoubleStar
+            }
+            b'*' => {
+                idx += 1;
+                Token::Star
+            }
+            b',' => {
+                idx += 1;
+                Token::Comma
+            }
+            b'?' => {
+                idx += 1;
+                Token::Question
+            }
+            b'(' => {
+                idx += 1;
+                Token::LParen
+            }
+            b')' => {
+                idx += 1;
+                Token::RParen
+            }
+            b'[' => {
+                idx += 1;
+                Token::LBracket
+            }
+            b']' => {
+                idx += 1;
+                Token::RBracket
+            }
+            b'{' => {
+                idx += 1;
+                Token::LBrace
+            }
+            b'}' => {
+                idx += 1;
+                Token::RBrace
+            }
+            b'+' => {
+                idx += 1;
+                Token::Plus
+            }
+            b'-' => {
+                idx += 1;
+                Token::Minus
+            }
+            b'/' => {
+                idx += 1;
+                Token::Slash
+            }
+            b'%' => {
+                idx += 1;
+                Token::Percent
+            }
+            b'!' if next == Some(b'=') => {
+                idx += 2;
+                Token::NotEq
+            }
+            b'!' => {
+                idx += 1;
+                Token::Not
+            }
+            b'&' if next == Some(b'&') => {
+                idx += 2;
+                Token::And
+            }
+            b'|' if next == Some(b'|') => {
+                idx += 2;
+                Token::Or
+            }
+            b'=' if next == Some(b'=') => {
+                idx += 2;
+                Token::Eq
+            }
+            b'<' if next == Some(b'>') => {
+                idx += 2;
+                Token::NotEq
+            }
+            b'<' if next == Some(b'=') => {
+                idx += 2;
+                Token::Lte
+            }
+            b'<' => {
+                idx += 1;
+                Token::Lt
+            }
+            b'>' if next == Some(b'=') => {
+                idx += 2;
+                Token::Gte
+            }
+            b'>' => {
+                idx += 1;
+                Token::Gt
+            }
+            b'"' => {
+                idx += 1;
+                Token::String(tokenize_string(input, &mut idx)?)
+            }
+            b'0'..=b'9' => Token::Number(tokenize_number(input, &mut idx)?),
+            c if is_ident_start(c) => {
+                while idx < input.len() && is_ident_char(input[idx]) {
+                    idx += 1;
+                }
+                Token::Ident(std::str::from_utf8(&input[start..idx])?)
+            }
+            _ => {
+                return Err(Error::InvalidJsonPath(
+                    format!("unexpected character '{}'", c as char),
+                    start,
+                ));
+            }
+        };
+        tokens.push((token, start));
+    }
+    tokens.push((Token::Eof, input.len()));
+    Ok(tokens)
+}
+
+fn tokenize_string<'a>(input: &'a [u8], idx: &mut usize) -> Result<Cow<'a, str>, Error> {
+    let start = *idx;
+    let mut str_buf = String::new();
+    let mut escaped = false;
+    let mut copied = start;
+    loop {
+        match input.get(*idx) {
+            Some(b'"') => {
+                let s = std::str::from_utf8(&input[copied..*idx])?;
+                *idx += 1;
+                if escaped {
+                    str_buf.push_str(s);
+                    return Ok(Cow::Owned(str_buf));
+                }
+                return Ok(Cow::Borrowed(s));
+            }
+            Some(b'\\') => {
+                escaped = true;
+                str_buf.push_str(std::str::from_utf8(&input[copied..*idx])?);
+                *idx += 1;
+                if *idx >= input.len() {
+                    break;
+                }
+                parse_escaped_string(&input[*idx..], idx, &mut str_buf)?;
+                copied = *idx;
+            }
+            Some(_) => *idx += 1,
+            None => break,
+        }
+    }
+    Err(Error::InvalidJsonPath(
+        "unterminated string".to_string(),
+        start - 1,
+    ))
+}
+
+fn tokenize_number(input: &[u8], idx: &mut usize) -> Result<Number, Error> {
+    let start = *idx;
+    let mut is_float = false;
+    while *idx < input.len() && input[*idx].is_ascii_digit() {
+        *idx += 1;
+    }
+    if *idx + 1 < input.len() && input[*idx] == b'.' && input[*idx + 1].is_ascii_digit() {
+        is_float = true;
+        *idx += 1;
+        while *idx < input.len() && input[*idx].is_ascii_digit() {
+            *idx += 1;
+        }
+    }
+    if *idx < input.len() && (input[*idx] == b'e' || input[*idx] == b'E') {
+        let mut end = *idx + 1;
+        if end < input.len() && (input[end] == b'+' || input[end] == b'-') {
+            end += 1;
+        }
+        if end < input.len() && input[end].is_ascii_digit() {
+            is_float = true;
+            *idx = end;
+            while *idx < input.len() && input[*idx].is_ascii_digit() {
+                *idx += 1;
+            }
+        }
+    }
+    let s = std::str::from_utf8(&input[start..*idx])?;
+    if !is_float {
+        if let Ok(v) = s.parse::<u64>() {
+            return Ok(Number::UInt64(v));
+        }
+    }
+    s.parse::<f64>()
+        .map(Number::Float64)
+        .map_err(|_| Error::InvalidJsonPath(format!("invalid number '{s}'"), start))
+}
+
+struct Parser<'a> {
+    tokens: Vec<(Token<'a>, usize)>,
+    pos: usize,
+    /// `@` is only allowed in filters.
+    filter_depth: usize,
+    /// `last` is only allowed in array subscripts.
+    subscript_depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(&mut self) -> Result<JsonPath<'a>, Error> {
+        let mode = match self.peek() {
+            Token::Ident(ident) if ident.eq_ignore_ascii_case("strict") => {
+                self.pos += 1;
+                Mode::Strict
+            }
+            Token::Ident(ident) if ident.eq_ignore_ascii_case("lax") => {
+                self.pos += 1;
+                Mode::Lax
+            }
+            _ => Mode::Lax,
+        };
+        let expr = self.parse_or()?;
+        if *self.peek() != Token::Eof {
+            return Err(self.unexpected());
+        }
+        Ok(JsonPath { mode, expr })
+    }
+
+    fn peek(&self) -> &Token<'a> {
+        self.peek_nth(0)
+    }
+
+    fn peek_nth(&self, n: usize) -> &Token<'a> {
+        // The last token is always `Eof`.
+        let pos = (self.pos + n).min(self.tokens.len() - 1);
+        &self.tokens[pos].0
+    }
+
+    fn next(&mut self) -> Token<'a> {
+        let token = self.peek().clone();
+        self.pos += 1;
+        token
+    }
+
+    fn position(&self) -> usize {
+        let pos = self.pos.min(self.tokens.len() - 1);
+        self.tokens[pos].1
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Token::Ident(ident) if *ident == keyword)
+    }
+
+    fn expect(&mut self, expected: Token<'a>) -> Result<(), Error> {
+        if *self.peek() == expected {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), Error> {
+        if self.peek_keyword(keyword) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{keyword}`")))
+        }
+    }
+
+    fn error(&self, msg: String) -> Error {
+        Error::InvalidJsonPath(msg, self.position())
+    }
+
+    fn unexpected(&self) -> Error {
+        match self.peek() {
+            Token::Eof => self.error("unexpected end of path".to_string()),
+            token => self.error(format!("unexpected token {token:?}")),
+        }
+    }
+
+    fn expect_predicate(&self, expr: &PathExpr<'a>) -> Result<(), Error> {
+        if expr.is_predicate() {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected a predicate, but got `{expr}`")))
+        }
+    }
+
+    fn expect_operand(&self, expr: &PathExpr<'a>) -> Result<(), Error> {
+        if expr.is_predicate() {
+            Err(self.error(format!("predicate `{expr}` is not allowed here")))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn binary(&self, op: BinaryOp, left: PathExpr<'a>, right: PathExpr<'a>) -> PathExpr<'a> {
+        PathExpr::Binary {
+            op,
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<PathExpr<'a>, Error> {
+        let mut left = self.parse_and()?;
+        while *self.peek() == Token::Or {
+            self.pos += 1;
+            let right = self.parse_and()?;
+            self.expect_predicate(&left)?;
+            self.expect_predicate(&right)?;
+            left = self.binary(BinaryOp::Or, left, right);
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<PathExpr<'a>, Error> {
+        let mut left = self.parse_not()?;
+        while *self.peek() == Token::And {
+            self.pos += 1;
+            let right = self.parse_not()?;
+            self.expect_predicate(&left)?;
+            self.expect_predicate(&right)?;
+            left = self.binary(BinaryOp::And, left, right);
+        }
+        Ok(left)
+    }
+
+    fn parse_not(&mut self) -> Result<PathExpr<'a>, Error> {
+        if *self.peek() == Token::Not {
+            self.pos += 1;
+            let expr = self.parse_not()?;
+            self.expect_predicate(&expr)?;
+            return Ok(PathExpr::Unary {
+                op: UnaryOp::Not,
+                expr: Box::new(expr),
+            });
+        }
+        self.parse_predicate()
+    }
+
+    fn parse_predicate(&mut self) -> Result<PathExpr<'a>, Error> {
+        let left = self.parse_additive()?;
+        let op = match self.peek() {
+            Token::Eq => BinaryOp::Eq,
+            Token::NotEq => BinaryOp::NotEq,
+            Token::Lt => BinaryOp::Lt,
+            Token::Lte => BinaryOp::Lte,
+            Token::Gt => BinaryOp::Gt,
+            Token::Gte => BinaryOp::Gte,
+            Token::Ident("like_regex") => {
+                self.pos += 1;
+                self.expect_operand(&left)?;
+                let pattern = self.parse_string()?;
+                let flags = if self.peek_keyword("flag") {
+                    self.pos += 1;
+                    let flags = self.parse_string()?;
+                    if let Some(c) = flags.chars().find(|c| !"imsxq".contains(*c)) {
+                        return Err(self.error(format!("invalid like_regex flag '{c}'")));
+                    }
+                    Some(flags)
+                } else {
+                    None
+                };
+                let regex = build_regex(&pattern, flags.as_deref())
+                    .map_err(|e| self.error(format!("invalid like_regex pattern: {e}")))?;
+                return Ok(PathExpr::LikeRegex {
+                    expr: Box::new(left),
+                    pattern,
+                    flags,
+                    regex: PathRegex(regex),
+                });
+            }
+            Token::Ident("starts") => {
+                self.pos += 1;
+                self.expect_keyword("with")?;
+                let prefix = self.parse_additive()?;
+                self.expect_operand(&left)?;
+                self.expect_operand(&prefix)?;
+                return Ok(PathExpr::StartsWith {
+                    expr: Box::new(left),
+                    prefix: Box::new(prefix),
+                });
+            }
+            Token::Ident("is") => {
+                self.pos += 1;
+                self.expect_keyword("unknown")?;
+                self.expect_predicate(&left)?;
+                return Ok(PathExpr::IsUnknown(Box::new(left)));
+            }
+            _ => return Ok(left),
+        };
+        self.pos += 1;
+        let right = self.parse_additive()?;
+        self.expect_operand(&left)?;
+        self.expect_operand(&right)?;
+        Ok(self.binary(op, left, right))
+    }
+
+    fn parse_additive(&mut self) -> Result<PathExpr<'a>, Error> {
+        let mut left = self.parse_multiplicative()?;
+        loop {
+            let op = match self.peek() {
+                Token::Plus => BinaryOp::Add,
+                Token::Minus => BinaryOp::Subtract,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.parse_multiplicative()?;
+            self.expect_operand(&left)?;
+            self.expect_operand(&right)?;
+            left = self.binary(op, left, right);
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<PathExpr<'a>, Error> {
+        let mut left = self.parse_unary()?;
+        loop {
+            let op = match self.peek() {
+                Token::Star => BinaryOp::Multiply,
+                Token::Slash => BinaryOp::Divide,
+                Token::Percent => BinaryOp::Modulo,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.parse_unary()?;
+            self.expect_operand(&left)?;
+            self.expect_operand(&right)?;
+            left = self.binary(op, left, right);
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<PathExpr<'a>, Error> {
+        let op = match self.peek() {
+            Token::Plus => UnaryOp::Plus,
+            Token::Minus => UnaryOp::Minus,
+            _ => return self.parse_accessors(),
+        };
+        self.pos += 1;
+        let expr = self.parse_unary()?;
+        self.expect_operand(&expr)?;
+        Ok(PathExpr::Unary {
+            op,
+            expr: Box::new(expr),
+        })
+    }
+
+    fn parse_accessors(&mut self) -> Result<PathExpr<'a>, Error> {
+        let base = self.parse_primary()?;
+        let mut items = Vec::new();
+        loop {
+            let item = match self.peek() {
+                Token::Dot => {
+                    self.pos += 1;
+                    self.parse_member()?
+                }
+                Token::LBracket => {
+                    self.pos += 1;
+                    self.parse_subscripts()?
+                }
+                Token::Question => {
+                    self.pos += 1;
+                    self.expect(Token::LParen)?;
+                    self.filter_depth += 1;
+                    let predicate = self.parse_or()?;
+                    self.filter_depth -= 1;
+                    self.expect_predicate(&predicate)?;
+                    self.expect(Token::RParen)?;
+                    PathItem::Filter(Box::new(predicate))
+                }
+                _ => break,
+            };
+            items.push(item);
+        }
+        if items.is_empty() {
+            return Ok(base);
+        }
+        self.expect_operand(&base)?;
+        Ok(PathExpr::Path {
+            base: Box::new(base),
+            items,
+        })
+    }
+
+    fn parse_member(&mut self) -> Result<PathItem<'a>, Error> {
+        match self.next() {
+            Token::Star => Ok(PathItem::AnyKey),
+            Token::DoubleStar => {
+                let (from, to) = if *self.peek() == Token::LBrace {
+                    self.pos += 1;
+                    let from = self.parse_level()?;
+                    let to = if self.peek_keyword("to") {
+                        self.pos += 1;
+                        self.parse_level()?
+                    } else {
+                        from
+                    };
+                    self.expect(Token::RBrace)?;
+                    (from, to)
+                } else {
+                    (Level::Number(0), Level::Last)
+                };
+                Ok(PathItem::Descendant { from, to })
+            }
+            Token::Ident(name) if *self.peek() == Token::LParen => {
+                let method = match name {
+                    "type" => Method::Type,
+                    "size" => Method::Size,
+                    "double" => Method::Double,
+                    "ceiling" => Method::Ceiling,
+                    "floor" => Method::Floor,
+                    "abs" => Method::Abs,
+                    "keyvalue" => Method::KeyValue,
+                    _ => return Err(self.error(format!("unknown item method `{name}`"))),
+                };
+                self.pos += 1;
+                self.expect(Token::RParen)?;
+                Ok(PathItem::Method(method))
+            }
+            Token::Ident(name) => Ok(PathItem::Key(Cow::Borrowed(name))),
+            Token::String(name) => Ok(PathItem::Key(name)),
+            _ => {
+                self.pos -= 1;
+                Err(self.unexpected())
+            }
+        }
+    }
+
+    fn parse_level(&mut self) -> Result<Level, Error> {
+        match self.next() {
+            Token::Number(Number::UInt64(level)) if level <= u32::MAX as u64 => {
+                Ok(Level::Number(level as u32))
+            }
+            Token::Ident("last") => Ok(Level::Last),
+            _ => {
+                self.pos -= 1;
+                Err(self.error("expected a level number or `last`".to_string()))
+            }
+        }
+    }
+
+    fn parse_subscripts(&mut self) -> Result<PathItem<'a>, Error> {
+        if *self.peek() == Token::Star && *self.peek_nth(1) == Token::RBracket {
+            self.pos += 2;
+            return Ok(PathItem::AnyIndex);
+        }
+        self.subscript_depth += 1;
+        let mut indices = Vec::new();
+        loop {
+            let from = self.parse_additive()?;
+            self.expect_operand(&from)?;
+            let to = if self.peek_keyword("to") {
+                self.pos += 1;
+                let to = self.parse_additive()?;
+                self.expect_operand(&to)?;
+                Some(to)
+            } else {
+                None
+            };
+            indices.push(ArrayIndex { from, to });
+            match self.next() {
+                Token::Comma => continue,
+                Token::RBracket => break,
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.unexpected());
+                }
+            }
+        }
+        self.subscript_depth -= 1;
+        Ok(PathItem::Index(indices))
+    }
+
+    fn parse_string(&mut self) -> Result<Cow<'a, str>, Error> {
+        match self.next() {
+            Token::String(s) => Ok(s),
+            _ => {
+                self.pos -= 1;
+                Err(self.error("expected a string".to_string()))
+            }
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<PathExpr<'a>, Error> {
+        let expr = match self.next() {
+            Token::Dollar => PathExpr::Root,
+            Token::At if self.filter_depth > 0 => PathExpr::Current,
+            Token::At => {
+                self.pos -= 1;
+                return Err(self.error("`@` is only allowed in filters".to_string()));
+            }
+            Token::Ident("last") if self.subscript_depth > 0 => PathExpr::Last,
+            Token::Ident("last") => {
+                self.pos -= 1;
+                return Err(self.error("`last` is only allowed in array subscripts".to_string()));
+            }
+            Token::Ident("null") => PathExpr::Literal(Value::Null),
+            Token::Ident("true") => PathExpr::Literal(Value::Bool(true)),
+            Token::Ident("false") => PathExpr::Literal(Value::Bool(false)),
+            Token::Ident("exists") if *self.peek() == Token::LParen => {
+                self.pos += 1;
+                let expr = self.parse_or()?;
+                self.expect(Token::RParen)?;
+                PathExpr::Exists(Box::new(expr))
+            }
+            Token::Number(n) => PathExpr::Literal(Value::Number(n)),
+            Token::String(s) => PathExpr::Literal(Value::String(s)),
+            Token::LParen => {
+                let expr = self.parse_or()?;
+                self.expect(Token::RParen)?;
+                expr
+            }
+            _ => {
+                self.pos -= 1;
+                return Err(self.unexpected());
+            }
+        };
+        Ok(expr)
+    }
+}
+
+/// Compiles the pattern of `like_regex` with the flags, which are checked by the parser.
+fn build_regex(pattern: &str, flags: Option<&str>) -> Result<Regex, regex::Error> {
+    let mut prefix = String::new();
+    let mut pattern = Cow::Borrowed(pattern);
+    for flag in flags.unwrap_or_default().chars() {
+        match flag {
+            'q' => pattern = Cow::Owned(regex::escape(&pattern)),
+            _ => prefix.push(flag),
+        }
+    }
+    let pattern = if prefix.is_empty() {
+        pattern.to_string()
+    } else {
+        format!("(?{prefix}){pattern}")
+    };
+    Regex::new(&pattern)
+}
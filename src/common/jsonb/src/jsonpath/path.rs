@@ -0,0 +1,416 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use regex::Regex;
+
+use crate::number::Number;
+use crate::value::JsonPath as KeyPath;
+use crate::value::Value;
+
+/// Represents a SQL/JSON path, such as `strict $.a[*].b ? (@.price > 10)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPath<'a> {
+    pub mode: Mode,
+    pub expr: PathExpr<'a>,
+}
+
+/// In `lax` mode, structural errors such as accessing a missing key are ignored
+/// and arrays are unwrapped automatically, while `strict` mode raises them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Lax,
+    Strict,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathExpr<'a> {
+    /// `$`, the root value.
+    Root,
+    /// `@`, the current item of a filter.
+    Current,
+    /// `last`, the last index of the array in an array subscript.
+    Last,
+    /// Scalar literal, `null`, `true`, `false`, numbers or strings.
+    Literal(Value<'a>),
+    /// Expression followed by accessors, such as `$.a[0]`.
+    Path {
+        base: Box<PathExpr<'a>>,
+        items: Vec<PathItem<'a>>,
+    },
+    Unary {
+        op: UnaryOp,
+        expr: Box<PathExpr<'a>>,
+    },
+    Binary {
+        op: BinaryOp,
+        left: Box<PathExpr<'a>>,
+        right: Box<PathExpr<'a>>,
+    },
+    /// `exists (expr)`
+    Exists(Box<PathExpr<'a>>),
+    /// `expr starts with prefix`
+    StartsWith {
+        expr: Box<PathExpr<'a>>,
+        prefix: Box<PathExpr<'a>>,
+    },
+    /// `expr like_regex "pattern" flag "flags"`
+    LikeRegex {
+        expr: Box<PathExpr<'a>>,
+        pattern: Cow<'a, str>,
+        flags: Option<Cow<'a, str>>,
+        regex: PathRegex,
+    },
+    /// `(predicate) is unknown`
+    IsUnknown(Box<PathExpr<'a>>),
+}
+
+/// The regular expression of `like_regex`, it is compiled once when the path is parsed.
+#[derive(Debug, Clone)]
+pub struct PathRegex(pub Regex);
+
+impl PartialEq for PathRegex {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl Eq for PathRegex {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathItem<'a> {
+    /// `.name` or `."name"`
+    Key(Cow<'a, str>),
+    /// `.*`
+    AnyKey,
+    /// `[0, 2 to 4, last]`
+    Index(Vec<ArrayIndex<'a>>),
+    /// `[*]`
+    AnyIndex,
+    /// `.**` or `.**{from to to}`, selects the items of the given nesting levels,
+    /// the current item is of level 0.
+    Descendant { from: Level, to: Level },
+    /// `? (predicate)`
+    Filter(Box<PathExpr<'a>>),
+    /// `.type()`, `.size()` and other item methods.
+    Method(Method),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrayIndex<'a> {
+    pub from: PathExpr<'a>,
+    pub to: Option<PathExpr<'a>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Number(u32),
+    Last,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Type,
+    Size,
+    Double,
+    Ceiling,
+    Floor,
+    Abs,
+    KeyValue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Plus,
+    Minus,
+    Not,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    And,
+    Or,
+    Eq,
+    NotEq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+}
+
+impl<'a> JsonPath<'a> {
+    /// Returns the path as the keys and indices to the selected value if it only consists of
+    /// them, such as `$.a[0]`, it can be evaluated by [`crate::get_by_path`] without decoding
+    /// the whole value. The selected value is the same in both modes if it exists.
+    pub fn as_key_path(&self) -> Option<Vec<KeyPath<'a>>> {
+        let items = match &self.expr {
+            PathExpr::Root => return Some(vec![]),
+            PathExpr::Path { base, items } if **base == PathExpr::Root => items,
+            _ => return None,
+        };
+        items
+            .iter()
+            .map(|item| match item {
+                PathItem::Key(name) => Some(KeyPath::String(name.clone())),
+                PathItem::Index(indices) => match indices.as_slice() {
+                    [
+                        ArrayIndex {
+                            from: PathExpr::Literal(Value::Number(Number::UInt64(n))),
+                            to: None,
+                        },
+                    ] => Some(KeyPath::UInt64(*n)),
+                    [
+                        ArrayIndex {
+                            from: PathExpr::Literal(Value::Number(Number::Int64(n))),
+                            to: None,
+                        },
+                    ] if *n >= 0 => Some(KeyPath::UInt64(*n as u64)),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl<'a> PathExpr<'a> {
+    /// Predicates evaluate to `true`, `false` or `unknown`,
+    /// only they can be used in filters and logical operators.
+    pub fn is_predicate(&self) -> bool {
+        match self {
+            PathExpr::Unary {
+                op: UnaryOp::Not, ..
+            }
+            | PathExpr::Exists(_)
+            | PathExpr::StartsWith { .. }
+            | PathExpr::LikeRegex { .. }
+            | PathExpr::IsUnknown(_) => true,
+            PathExpr::Binary { op, .. } => op.is_predicate(),
+            _ => false,
+        }
+    }
+}
+
+impl BinaryOp {
+    pub fn is_predicate(&self) -> bool {
+        !matches!(
+            self,
+            BinaryOp::Add
+                | BinaryOp::Subtract
+                | BinaryOp::Multiply
+                | BinaryOp::Divide
+                | BinaryOp::Modulo
+        )
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            BinaryOp::Or => 1,
+            BinaryOp::And => 2,
+            BinaryOp::Eq
+            | BinaryOp::NotEq
+            | BinaryOp::Lt
+            | BinaryOp::Lte
+            | BinaryOp::Gt
+            | BinaryOp::Gte => 4,
+            BinaryOp::Add | BinaryOp::Subtract => 5,
+            BinaryOp::Multiply | BinaryOp::Divide | BinaryOp::Modulo => 6,
+        }
+    }
+}
+
+impl<'a> Display for JsonPath<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.mode == Mode::Strict {
+            write!(f, "strict ")?;
+        }
+        write!(f, "{}", self.expr)
+    }
+}
+
+impl<'a> PathExpr<'a> {
+    fn precedence(&self) -> u8 {
+        match self {
+            PathExpr::Binary { op, .. } => op.precedence(),
+            PathExpr::Unary {
+                op: UnaryOp::Not, ..
+            } => 3,
+            PathExpr::StartsWith { .. } | PathExpr::LikeRegex { .. } | PathExpr::IsUnknown(_) => 4,
+            PathExpr::Unary { .. } => 7,
+            _ => 8,
+        }
+    }
+
+    fn fmt_operand(&self, f: &mut Formatter<'_>, precedence: u8) -> std::fmt::Result {
+        if self.precedence() < precedence {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl<'a> Display for PathExpr<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathExpr::Root => write!(f, "$"),
+            PathExpr::Current => write!(f, "@"),
+            PathExpr::Last => write!(f, "last"),
+            PathExpr::Literal(Value::String(s)) => fmt_string(f, s),
+            PathExpr::Literal(value) => write!(f, "{value}"),
+            PathExpr::Path { base, items } => {
+                base.fmt_operand(f, 8)?;
+                for item in items {
+                    write!(f, "{item}")?;
+                }
+                Ok(())
+            }
+            PathExpr::Unary { op, expr } => {
+                match op {
+                    UnaryOp::Plus => write!(f, "+")?,
+                    UnaryOp::Minus => write!(f, "-")?,
+                    UnaryOp::Not => return write!(f, "!({expr})"),
+                }
+                expr.fmt_operand(f, 8)
+            }
+            PathExpr::Binary { op, left, right } => {
+                left.fmt_operand(f, op.precedence())?;
+                write!(f, " {op} ")?;
+                right.fmt_operand(f, op.precedence() + 1)
+            }
+            PathExpr::Exists(expr) => write!(f, "exists ({expr})"),
+            PathExpr::StartsWith { expr, prefix } => {
+                expr.fmt_operand(f, 5)?;
+                write!(f, " starts with ")?;
+                prefix.fmt_operand(f, 5)
+            }
+            PathExpr::LikeRegex {
+                expr,
+                pattern,
+                flags,
+                ..
+            } => {
+                expr.fmt_operand(f, 5)?;
+                write!(f, " like_regex ")?;
+                fmt_string(f, pattern)?;
+                if let Some(flags) = flags {
+                    write!(f, " flag ")?;
+                    fmt_string(f, flags)?;
+                }
+                Ok(())
+            }
+            PathExpr::IsUnknown(expr) => write!(f, "({expr}) is unknown"),
+        }
+    }
+}
+
+impl<'a> Display for PathItem<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathItem::Key(name) => {
+                write!(f, ".")?;
+                fmt_string(f, name)
+            }
+            PathItem::AnyKey => write!(f, ".*"),
+            PathItem::Index(indices) => {
+                write!(f, "[")?;
+                for (i, index) in indices.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", index.from)?;
+                    if let Some(to) = &index.to {
+                        write!(f, " to {to}")?;
+                    }
+                }
+                write!(f, "]")
+            }
+            PathItem::AnyIndex => write!(f, "[*]"),
+            PathItem::Descendant {
+                from: Level::Number(0),
+                to: Level::Last,
+            } => write!(f, ".**"),
+            PathItem::Descendant { from, to } if from == to => write!(f, ".**{{{from}}}"),
+            PathItem::Descendant { from, to } => write!(f, ".**{{{from} to {to}}}"),
+            PathItem::Filter(expr) => write!(f, "?({expr})"),
+            PathItem::Method(method) => write!(f, ".{method}()"),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Number(level) => write!(f, "{level}"),
+            Level::Last => write!(f, "last"),
+        }
+    }
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Method::Type => write!(f, "type"),
+            Method::Size => write!(f, "size"),
+            Method::Double => write!(f, "double"),
+            Method::Ceiling => write!(f, "ceiling"),
+            Method::Floor => write!(f, "floor"),
+            Method::Abs => write!(f, "abs"),
+            Method::KeyValue => write!(f, "keyvalue"),
+        }
+    }
+}
+
+impl Display for BinaryOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinaryOp::And => write!(f, "&&"),
+            BinaryOp::Or => write!(f, "||"),
+            BinaryOp::Eq => write!(f, "=="),
+            BinaryOp::NotEq => write!(f, "!="),
+            BinaryOp::Lt => write!(f, "<"),
+            BinaryOp::Lte => write!(f, "<="),
+            BinaryOp::Gt => write!(f, ">"),
+            BinaryOp::Gte => write!(f, ">="),
+            BinaryOp::Add => write!(f, "+"),
+            BinaryOp::Subtract => write!(f, "-"),
+            BinaryOp::Multiply => write!(f, "*"),
+            BinaryOp::Divide => write!(f, "/"),
+            BinaryOp::Modulo => write!(f, "%"),
+        }
+    }
+}
+
+fn fmt_string(f: &mut Formatter<'_>, s: &str) -> std::fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
@@ -0,0 +1,704 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use super::path::*;
+use crate::de::from_slice;
+use crate::error::Error;
+use crate::functions::get_by_path;
+use crate::functions::is_jsonb;
+use crate::number::Number;
+use crate::value::Object;
+use crate::value::Value;
+
+/// An item selected by a path, borrowed from the source value or computed
+/// by item methods and arithmetic expressions.
+type Item<'a, 'b> = Cow<'b, Value<'a>>;
+
+/// Returns all the items selected by `json_path` from the `JSONB` value.
+pub fn path_query(value: &[u8], json_path: &JsonPath) -> Result<Vec<Vec<u8>>, Error> {
+    if let Some(item) = select_by_key_path(value, json_path) {
+        return Ok(vec![item]);
+    }
+    let value = from_slice(value)?;
+    let selector = Selector::new(json_path.mode, &value);
+    let items = selector.select(&json_path.expr)?;
+    Ok(items.iter().map(|item| item.to_vec()).collect())
+}
+
+/// Checks whether `json_path` selects any item from the `JSONB` value.
+pub fn path_exists(value: &[u8], json_path: &JsonPath) -> Result<bool, Error> {
+    if select_by_key_path(value, json_path).is_some() {
+        return Ok(true);
+    }
+    let value = from_slice(value)?;
+    let selector = Selector::new(json_path.mode, &value);
+    Ok(!selector.select(&json_path.expr)?.is_empty())
+}
+
+/// Returns the result of the predicate `json_path` over the `JSONB` value,
+/// `None` means the result is unknown.
+pub fn path_match(value: &[u8], json_path: &JsonPath) -> Result<Option<bool>, Error> {
+    let value = from_slice(value)?;
+    let selector = Selector::new(json_path.mode, &value);
+    let items = selector.select(&json_path.expr)?;
+    match items.as_slice() {
+        [item] => match item.as_ref() {
+            Value::Bool(v) => Ok(Some(*v)),
+            Value::Null => Ok(None),
+            _ => Err(Error::JsonPathEvaluation(
+                "single boolean result is expected".to_string(),
+            )),
+        },
+        _ => Err(Error::JsonPathEvaluation(
+            "single boolean result is expected".to_string(),
+        )),
+    }
+}
+
+/// Selects the value of a path of keys and indices without decoding the whole value,
+/// returns None if the path is not such one or the value is not found.
+fn select_by_key_path(value: &[u8], json_path: &JsonPath) -> Option<Vec<u8>> {
+    if !is_jsonb(value) {
+        return None;
+    }
+    let key_path = json_path.as_key_path()?;
+    if key_path.is_empty() {
+        return None;
+    }
+    get_by_path(value, key_path)
+}
+
+/// Evaluates SQL/JSON path expressions over a root value.
+pub struct Selector<'a, 'b> {
+    mode: Mode,
+    root: &'b Value<'a>,
+}
+
+#[derive(Clone, Copy, Default)]
+struct Context<'i, 'a, 'b> {
+    /// The item referenced by `@`.
+    current: Option<&'i Item<'a, 'b>>,
+    /// The value of `last`.
+    last: Option<usize>,
+}
+
+/// Children of a value selected by an accessor.
+enum Selection<'s> {
+    Key(&'s str),
+    Values,
+    Elements,
+    Indices(&'s [usize]),
+    Descendants { from: Level, to: Level },
+}
+
+impl<'a, 'b> Selector<'a, 'b> {
+    pub fn new(mode: Mode, root: &'b Value<'a>) -> Self {
+        Self { mode, root }
+    }
+
+    /// Returns the items selected by `expr`, predicates produce a single
+    /// boolean, or `null` if the result is unknown.
+    pub fn select(&self, expr: &PathExpr) -> Result<Vec<Item<'a, 'b>>, Error> {
+        self.eval(expr, Context::default())
+    }
+
+    fn is_lax(&self) -> bool {
+        self.mode == Mode::Lax
+    }
+
+    fn eval(&self, expr: &PathExpr, cx: Context<'_, 'a, 'b>) -> Result<Vec<Item<'a, 'b>>, Error> {
+        match expr {
+            PathExpr::Root => Ok(vec![Cow::Borrowed(self.root)]),
+            PathExpr::Current => match cx.current {
+                Some(current) => Ok(vec![current.clone()]),
+                None => Err(Error::JsonPathEvaluation(
+                    "`@` is only allowed in filters".to_string(),
+                )),
+            },
+            PathExpr::Last => match cx.last {
+                Some(last) => Ok(vec![Cow::Owned(Value::Number(Number::UInt64(last as u64)))]),
+                None => Err(Error::JsonPathEvaluation(
+                    "`last` is only allowed in array subscripts".to_string(),
+                )),
+            },
+            PathExpr::Literal(value) => Ok(vec![Cow::Owned(to_owned_scalar(value))]),
+            PathExpr::Path { base, items } => {
+                let mut values = self.eval(base, cx)?;
+                let mut expanded = false;
+                for item in items {
+                    values = self.apply(item, values, cx, expanded)?;
+                    expanded = matches!(item, PathItem::Descendant { .. });
+                }
+                Ok(values)
+            }
+            PathExpr::Unary {
+                op: op @ (UnaryOp::Plus | UnaryOp::Minus),
+                expr,
+            } => {
+                let values = self.unwrap(self.eval(expr, cx)?);
+                values
+                    .into_iter()
+                    .map(|value| match value.as_ref() {
+                        Value::Number(n) if *op == UnaryOp::Minus => {
+                            Ok(Cow::Owned(Value::Number(negate(n))))
+                        }
+                        Value::Number(_) => Ok(value),
+                        _ => Err(Error::JsonPathEvaluation(format!(
+                            "operand of unary jsonpath operator is not a numeric value: {value}"
+                        ))),
+                    })
+                    .collect()
+            }
+            PathExpr::Binary { op, left, right } if !op.is_predicate() => {
+                let left = self.numeric_operand(left, cx, *op)?;
+                let right = self.numeric_operand(right, cx, *op)?;
+                let result = arithmetic(*op, &left, &right)?;
+                Ok(vec![Cow::Owned(Value::Number(result))])
+            }
+            _ => {
+                let result = match self.predicate(expr, cx) {
+                    Some(v) => Value::Bool(v),
+                    None => Value::Null,
+                };
+                Ok(vec![Cow::Owned(result)])
+            }
+        }
+    }
+
+    /// Unwraps the arrays into their elements in `lax` mode.
+    fn unwrap(&self, values: Vec<Item<'a, 'b>>) -> Vec<Item<'a, 'b>> {
+        if !self.is_lax() || !values.iter().any(|value| value.is_array()) {
+            return values;
+        }
+        let mut result = Vec::with_capacity(values.len());
+        for value in values {
+            if value.is_array() {
+                result.extend(select(&value, &Selection::Elements));
+            } else {
+                result.push(value);
+            }
+        }
+        result
+    }
+
+    fn numeric_operand(
+        &self,
+        expr: &PathExpr,
+        cx: Context<'_, 'a, 'b>,
+        op: BinaryOp,
+    ) -> Result<Number, Error> {
+        let values = self.unwrap(self.eval(expr, cx)?);
+        match values.as_slice() {
+            [value] => match value.as_ref() {
+                Value::Number(n) => Ok(n.clone()),
+                _ => Err(Error::JsonPathEvaluation(format!(
+                    "operand of jsonpath operator {op} is not a single numeric value"
+                ))),
+            },
+            _ => Err(Error::JsonPathEvaluation(format!(
+                "operand of jsonpath operator {op} is not a single numeric value"
+            ))),
+        }
+    }
+
+    /// Applies the accessor `item` to `values`. If `values` are selected by `.**`,
+    /// which already contains all the nested items, arrays are not unwrapped and
+    /// the items not matching the accessor are skipped silently.
+    fn apply(
+        &self,
+        item: &PathItem,
+        values: Vec<Item<'a, 'b>>,
+        cx: Context<'_, 'a, 'b>,
+        expanded: bool,
+    ) -> Result<Vec<Item<'a, 'b>>, Error> {
+        let strict = !self.is_lax() && !expanded;
+        let values = match item {
+            PathItem::Key(_) | PathItem::AnyKey if !expanded => self.unwrap(values),
+            _ => values,
+        };
+        let mut result = Vec::new();
+        match item {
+            PathItem::Key(name) => {
+                let selection = Selection::Key(name.as_ref());
+                for value in values {
+                    match value.as_ref() {
+                        Value::Object(obj) if obj.contains_key(name.as_ref()) => {
+                            result.extend(select(&value, &selection));
+                        }
+                        Value::Object(_) if strict => {
+                            return Err(Error::JsonPathEvaluation(format!(
+                                "JSON object does not contain key \"{name}\""
+                            )));
+                        }
+                        Value::Object(_) => {}
+                        _ if strict => {
+                            return Err(Error::JsonPathEvaluation(format!(
+                                "jsonpath member accessor .\"{name}\" can only be applied to an object"
+                            )));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PathItem::AnyKey => {
+                for value in values {
+                    if value.is_object() {
+                        result.extend(select(&value, &Selection::Values));
+                    } else if strict {
+                        return Err(Error::JsonPathEvaluation(
+                            "jsonpath wildcard member accessor can only be applied to an object"
+                                .to_string(),
+                        ));
+                    }
+                }
+            }
+            PathItem::AnyIndex => {
+                for value in values {
+                    if value.is_array() {
+                        result.extend(select(&value, &Selection::Elements));
+                    } else if expanded {
+                        continue;
+                    } else if self.is_lax() {
+                        result.push(value);
+                    } else {
+                        return Err(Error::JsonPathEvaluation(
+                            "jsonpath wildcard array accessor can only be applied to an array"
+                                .to_string(),
+                        ));
+                    }
+                }
+            }
+            PathItem::Index(indices) => {
+                for value in values {
+                    match value.as_array() {
+                        Some(array) => {
+                            let positions = self.positions(indices, array.len(), cx)?;
+                            result.extend(select(&value, &Selection::Indices(&positions)));
+                        }
+                        None if expanded => {}
+                        None if self.is_lax() => {
+                            // Non-array values are wrapped into an array of a single element.
+                            let positions = self.positions(indices, 1, cx)?;
+                            result.extend(positions.iter().map(|_| value.clone()));
+                        }
+                        None => {
+                            return Err(Error::JsonPathEvaluation(
+                                "jsonpath array accessor can only be applied to an array"
+                                    .to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+            PathItem::Descendant { from, to } => {
+                let selection = Selection::Descendants {
+                    from: *from,
+                    to: *to,
+                };
+                for value in values {
+                    result.extend(select(&value, &selection));
+                }
+            }
+            PathItem::Filter(predicate) => {
+                for value in self.unwrap(values) {
+                    let cx = Context {
+                        current: Some(&value),
+                        last: cx.last,
+                    };
+                    if self.predicate(predicate, cx) == Some(true) {
+                        result.push(value);
+                    }
+                }
+            }
+            PathItem::Method(method) => {
+                let values = match method {
+                    Method::Type | Method::Size => values,
+                    _ => self.unwrap(values),
+                };
+                for value in values {
+                    self.apply_method(*method, value, &mut result)?;
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Evaluates the array subscripts into positions of an array with `len` elements.
+    fn positions(
+        &self,
+        indices: &[ArrayIndex],
+        len: usize,
+        cx: Context<'_, 'a, 'b>,
+    ) -> Result<Vec<usize>, Error> {
+        let cx = Context {
+            current: cx.current,
+            last: Some(len.saturating_sub(1)),
+        };
+        let mut positions = Vec::new();
+        for index in indices {
+            let from = self.subscript(&index.from, cx)?;
+            let to = match &index.to {
+                Some(to) => self.subscript(to, cx)?,
+                None => from,
+            };
+            if self.is_lax() {
+                let from = from.max(0);
+                let to = to.min(len as i64 - 1);
+                positions.extend((from..=to).map(|i| i as usize));
+            } else {
+                if from < 0 || to >= len as i64 || from > to {
+                    return Err(Error::JsonPathEvaluation(
+                        "jsonpath array subscript is out of bounds".to_string(),
+                    ));
+                }
+                positions.extend((from..=to).map(|i| i as usize));
+            }
+        }
+        Ok(positions)
+    }
+
+    fn subscript(&self, expr: &PathExpr, cx: Context<'_, 'a, 'b>) -> Result<i64, Error> {
+        let values = self.eval(expr, cx)?;
+        match values.as_slice() {
+            [value] => match value.as_ref() {
+                Value::Number(Number::Float64(v)) if v.is_finite() => Ok(v.trunc() as i64),
+                Value::Number(n) => n.as_i64().ok_or_else(|| {
+                    Error::JsonPathEvaluation(
+                        "jsonpath array subscript is out of range".to_string(),
+                    )
+                }),
+                _ => Err(Error::JsonPathEvaluation(
+                    "jsonpath array subscript is not a single numeric value".to_string(),
+                )),
+            },
+            _ => Err(Error::JsonPathEvaluation(
+                "jsonpath array subscript is not a single numeric value".to_string(),
+            )),
+        }
+    }
+
+    fn apply_method(
+        &self,
+        method: Method,
+        value: Item<'a, 'b>,
+        result: &mut Vec<Item<'a, 'b>>,
+    ) -> Result<(), Error> {
+        let output = match (method, value.as_ref()) {
+            (Method::Type, value) => {
+                let ty = match value {
+                    Value::Null => "null",
+                    Value::Bool(_) => "boolean",
+                    Value::Number(_) => "number",
+                    Value::String(_) => "string",
+                    Value::Array(_) => "array",
+                    Value::Object(_) => "object",
+                };
+                Value::String(Cow::Borrowed(ty))
+            }
+            (Method::Size, Value::Array(array)) => {
+                Value::Number(Number::UInt64(array.len() as u64))
+            }
+            (Method::Size, _) if self.is_lax() => Value::Number(Number::UInt64(1)),
+            (Method::Double, Value::Number(n)) => {
+                Value::Number(Number::Float64(n.as_f64().unwrap()))
+            }
+            (Method::Double, Value::String(s)) => match s.trim().parse::<f64>() {
+                Ok(v) if v.is_finite() => Value::Number(Number::Float64(v)),
+                _ => {
+                    return Err(Error::JsonPathEvaluation(format!(
+                        "string argument of jsonpath item method .double() is not a valid representation of a double precision number: \"{s}\""
+                    )));
+                }
+            },
+            (Method::Abs, Value::Number(n)) => Value::Number(match n {
+                Number::Int64(v) if *v < 0 => Number::UInt64(v.unsigned_abs()),
+                Number::Float64(v) => Number::Float64(v.abs()),
+                n => n.clone(),
+            }),
+            (Method::Floor, Value::Number(n)) => Value::Number(match n {
+                Number::Float64(v) => Number::Float64(v.floor()),
+                n => n.clone(),
+            }),
+            (Method::Ceiling, Value::Number(n)) => Value::Number(match n {
+                Number::Float64(v) => Number::Float64(v.ceil()),
+                n => n.clone(),
+            }),
+            (Method::KeyValue, Value::Object(obj)) => {
+                for (key, val) in obj.iter() {
+                    let mut pair = Object::new();
+                    pair.insert("key".to_string(), Value::String(Cow::Owned(key.clone())));
+                    pair.insert("value".to_string(), val.clone());
+                    result.push(Cow::Owned(Value::Object(pair)));
+                }
+                return Ok(());
+            }
+            (method, _) => {
+                let expected = match method {
+                    Method::Size => "an array",
+                    Method::Double => "a string or numeric value",
+                    Method::KeyValue => "an object",
+                    _ => "a numeric value",
+                };
+                return Err(Error::JsonPathEvaluation(format!(
+                    "jsonpath item method .{method}() can only be applied to {expected}"
+                )));
+            }
+        };
+        result.push(Cow::Owned(output));
+        Ok(())
+    }
+
+    /// Evaluates a predicate, `None` means the result is unknown.
+    fn predicate(&self, expr: &PathExpr, cx: Context<'_, 'a, 'b>) -> Option<bool> {
+        match expr {
+            PathExpr::Binary {
+                op: BinaryOp::And,
+                left,
+                right,
+            } => match self.predicate(left, cx) {
+                Some(false) => Some(false),
+                left => match (left, self.predicate(right, cx)) {
+                    (_, Some(false)) => Some(false),
+                    (Some(true), right) => right,
+                    _ => None,
+                },
+            },
+            PathExpr::Binary {
+                op: BinaryOp::Or,
+                left,
+                right,
+            } => match self.predicate(left, cx) {
+                Some(true) => Some(true),
+                left => match (left, self.predicate(right, cx)) {
+                    (_, Some(true)) => Some(true),
+                    (Some(false), right) => right,
+                    _ => None,
+                },
+            },
+            PathExpr::Binary { op, left, right } if op.is_predicate() => {
+                let left = self.unwrap(self.eval(left, cx).ok()?);
+                let right = self.unwrap(self.eval(right, cx).ok()?);
+                self.any_of(left.iter().flat_map(|l| {
+                    right
+                        .iter()
+                        .map(move |r| compare(*op, l.as_ref(), r.as_ref()))
+                }))
+            }
+            PathExpr::Unary {
+                op: UnaryOp::Not,
+                expr,
+            } => self.predicate(expr, cx).map(|v| !v),
+            PathExpr::Exists(expr) => self.eval(expr, cx).ok().map(|values| !values.is_empty()),
+            PathExpr::StartsWith { expr, prefix } => {
+                let prefix = self.eval(prefix, cx).ok()?;
+                let prefix = match prefix.as_slice() {
+                    [prefix] => prefix.as_str()?.to_string(),
+                    _ => return None,
+                };
+                let values = self.unwrap(self.eval(expr, cx).ok()?);
+                self.any_of(
+                    values
+                        .iter()
+                        .map(|value| value.as_str().map(|s| s.starts_with(prefix.as_str()))),
+                )
+            }
+            PathExpr::LikeRegex { expr, regex, .. } => {
+                let regex = &regex.0;
+                let values = self.unwrap(self.eval(expr, cx).ok()?);
+                self.any_of(
+                    values
+                        .iter()
+                        .map(|value| value.as_str().map(|s| regex.is_match(s))),
+                )
+            }
+            PathExpr::IsUnknown(expr) => Some(self.predicate(expr, cx).is_none()),
+            _ => None,
+        }
+    }
+
+    /// Existential semantics of predicates over sequences: `true` if any of the results
+    /// is `true`. In `strict` mode any unknown result makes the whole result unknown,
+    /// while `lax` mode returns `true` as soon as one is found.
+    fn any_of(&self, results: impl Iterator<Item = Option<bool>>) -> Option<bool> {
+        let mut found = false;
+        let mut unknown = false;
+        for result in results {
+            match result {
+                Some(true) if self.is_lax() => return Some(true),
+                Some(true) => found = true,
+                Some(false) => {}
+                None if self.is_lax() => unknown = true,
+                None => return None,
+            }
+        }
+        if found {
+            Some(true)
+        } else if unknown {
+            None
+        } else {
+            Some(false)
+        }
+    }
+}
+
+/// Selects the children of the item, borrowing from the source value if possible.
+fn select<'a, 'b>(item: &Item<'a, 'b>, selection: &Selection) -> Vec<Item<'a, 'b>> {
+    match item {
+        Cow::Borrowed(value) => select_values(value, selection)
+            .into_iter()
+            .map(Cow::Borrowed)
+            .collect(),
+        Cow::Owned(value) => select_values(value, selection)
+            .into_iter()
+            .map(|v| Cow::Owned(v.clone()))
+            .collect(),
+    }
+}
+
+fn select_values<'x, 'a>(value: &'x Value<'a>, selection: &Selection) -> Vec<&'x Value<'a>> {
+    match (selection, value) {
+        (Selection::Key(name), Value::Object(obj)) => obj.get(*name).into_iter().collect(),
+        (Selection::Values, Value::Object(obj)) => obj.values().collect(),
+        (Selection::Elements, Value::Array(array)) => array.iter().collect(),
+        (Selection::Indices(positions), Value::Array(array)) => {
+            positions.iter().filter_map(|i| array.get(*i)).collect()
+        }
+        (Selection::Descendants { from, to }, value) => {
+            // `last` is the deepest level of the value.
+            let level = |level: &Level| match level {
+                Level::Number(level) => *level,
+                Level::Last => depth(value),
+            };
+            let mut result = Vec::new();
+            collect_descendants(value, 0, level(from), level(to), &mut result);
+            result
+        }
+        _ => vec![],
+    }
+}
+
+fn depth(value: &Value) -> u32 {
+    let children_depth = match value {
+        Value::Array(array) => array.iter().map(depth).max(),
+        Value::Object(obj) => obj.values().map(depth).max(),
+        _ => return 0,
+    };
+    children_depth.map(|depth| depth + 1).unwrap_or(0)
+}
+
+fn collect_descendants<'x, 'a>(
+    value: &'x Value<'a>,
+    level: u32,
+    from: u32,
+    to: u32,
+    result: &mut Vec<&'x Value<'a>>,
+) {
+    if level > to {
+        return;
+    }
+    if level >= from {
+        result.push(value);
+    }
+    let children: Box<dyn Iterator<Item = &'x Value<'a>>> = match value {
+        Value::Array(array) => Box::new(array.iter()),
+        Value::Object(obj) => Box::new(obj.values()),
+        _ => return,
+    };
+    for child in children {
+        collect_descendants(child, level + 1, from, to, result);
+    }
+}
+
+fn to_owned_scalar<'a>(value: &Value) -> Value<'a> {
+    match value {
+        Value::Null => Value::Null,
+        Value::Bool(v) => Value::Bool(*v),
+        Value::Number(n) => Value::Number(n.clone()),
+        Value::String(s) => Value::String(Cow::Owned(s.to_string())),
+        _ => unreachable!("literals of JSON path are scalars"),
+    }
+}
+
+fn compare(op: BinaryOp, left: &Value, right: &Value) -> Option<bool> {
+    let ordering = match (left, right) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) | (_, Value::Null) => return Some(op == BinaryOp::NotEq),
+        (Value::Bool(l), Value::Bool(r)) => l.cmp(r),
+        (Value::Number(l), Value::Number(r)) => l.cmp(r),
+        (Value::String(l), Value::String(r)) => l.cmp(r),
+        _ => return None,
+    };
+    let result = match op {
+        BinaryOp::Eq => ordering == Ordering::Equal,
+        BinaryOp::NotEq => ordering != Ordering::Equal,
+        BinaryOp::Lt => ordering == Ordering::Less,
+        BinaryOp::Lte => ordering != Ordering::Greater,
+        BinaryOp::Gt => ordering == Ordering::Greater,
+        BinaryOp::Gte => ordering != Ordering::Less,
+        _ => unreachable!(),
+    };
+    Some(result)
+}
+
+fn negate(n: &Number) -> Number {
+    match n {
+        Number::Int64(v) => match v.checked_neg() {
+            Some(v) => Number::Int64(v),
+            None => Number::Float64(-(*v as f64)),
+        },
+        Number::UInt64(v) if *v <= i64::MAX as u64 => Number::Int64(-(*v as i64)),
+        Number::UInt64(v) => Number::Float64(-(*v as f64)),
+        Number::Float64(v) => Number::Float64(-v),
+    }
+}
+
+fn arithmetic(op: BinaryOp, left: &Number, right: &Number) -> Result<Number, Error> {
+    let is_integer = |n: &Number| !matches!(n, Number::Float64(_));
+    if is_integer(left) && is_integer(right) {
+        if let (Some(l), Some(r)) = (left.as_i64(), right.as_i64()) {
+            if matches!(op, BinaryOp::Divide | BinaryOp::Modulo) && r == 0 {
+                return Err(Error::JsonPathEvaluation("division by zero".to_string()));
+            }
+            let result = match op {
+                BinaryOp::Add => l.checked_add(r),
+                BinaryOp::Subtract => l.checked_sub(r),
+                BinaryOp::Multiply => l.checked_mul(r),
+                BinaryOp::Divide if l.checked_rem(r) == Some(0) => l.checked_div(r),
+                BinaryOp::Divide => None,
+                BinaryOp::Modulo => l.checked_rem(r),
+                _ => unreachable!(),
+            };
+            if let Some(result) = result {
+                return Ok(Number::Int64(result));
+            }
+        }
+    }
+    let l = left.as_f64().unwrap();
+    let r = right.as_f64().unwrap();
+    if matches!(op, BinaryOp::Divide | BinaryOp::Modulo) && r == 0.0 {
+        return Err(Error::JsonPathEvaluation("division by zero".to_string()));
+    }
+    let result = match op {
+        BinaryOp::Add => l + r,
+        BinaryOp::Subtract => l - r,
+        BinaryOp::Multiply => l * r,
+        BinaryOp::Divide => l / r,
+        BinaryOp::Modulo => l % r,
+        _ => unreachable!(),
+    };
+    Ok(Number::Float64(result))
+}
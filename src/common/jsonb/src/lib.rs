@@ -20,6 +20,7 @@ mod error;
 mod from;
 mod functions;
 mod jentry;
+pub mod jsonpath;
 mod number;
 mod parser;
 mod ser;
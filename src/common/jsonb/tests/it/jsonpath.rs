@@ -0,0 +1,223 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_jsonb::as_f64;
+use common_jsonb::jsonpath::parse_json_path;
+use common_jsonb::jsonpath::path_exists;
+use common_jsonb::jsonpath::path_match;
+use common_jsonb::jsonpath::path_query;
+use common_jsonb::parse_value;
+use common_jsonb::to_string;
+
+#[test]
+fn test_parse_json_path() {
+    let sources = vec![
+        ("$", "$"),
+        ("$.a", r#"$."a""#),
+        ("strict $.a.b", r#"strict $."a"."b""#),
+        ("lax $.\"a b\"[0]", r#"$."a b"[0]"#),
+        (
+            "$.a[*].b ? (@.price > 10)",
+            r#"$."a"[*]."b"?(@."price" > 10)"#,
+        ),
+        ("$[1, 2 to last, last - 1]", "$[1,2 to last,last - 1]"),
+        ("$.*", "$.*"),
+        ("$.**", "$.**"),
+        ("$.**{2}", "$.**{2}"),
+        ("$.**{1 to last}", "$.**{1 to last}"),
+        ("$.a.type()", r#"$."a".type()"#),
+        ("$.a.size() + 1 * 2", r#"$."a".size() + 1 * 2"#),
+        ("($.a + 1) * 2", r#"($."a" + 1) * 2"#),
+        ("-$.a", r#"-$."a""#),
+        (
+            "$ ? (@.a == 1 && (@.b < 2 || !(@.c >= 3)))",
+            r#"$?(@."a" == 1 && (@."b" < 2 || !(@."c" >= 3)))"#,
+        ),
+        ("$ ? (exists (@.a))", r#"$?(exists (@."a"))"#),
+        (
+            "$.a ? (@ starts with \"ab\")",
+            r#"$."a"?(@ starts with "ab")"#,
+        ),
+        (
+            "$.a ? (@ like_regex \"^a.*\" flag \"i\")",
+            r#"$."a"?(@ like_regex "^a.*" flag "i")"#,
+        ),
+        (
+            "$ ? ((@.a > 1) is unknown)",
+            r#"$?((@."a" > 1) is unknown)"#,
+        ),
+        ("$.a == \"x\\ny\"", r#"$."a" == "x\ny""#),
+        ("$.a != null", r#"$."a" != null"#),
+        ("$.a <> 1.5", r#"$."a" != 1.5"#),
+    ];
+    for (source, expect) in sources {
+        let path = parse_json_path(source.as_bytes()).unwrap();
+        assert_eq!(path.to_string(), expect, "{source}");
+        // the displayed path can be parsed again
+        let displayed = path.to_string();
+        let reparsed = parse_json_path(displayed.as_bytes()).unwrap();
+        assert_eq!(reparsed, path, "{source}");
+    }
+
+    let wrong_sources = vec![
+        "",
+        "$.",
+        "$[",
+        "$[1",
+        "$.a ?",
+        "$ ? (@.a)",
+        "@.a",
+        "$[0] ? (last == 1)",
+        "$.a.unknown()",
+        "$var",
+        "$ && $",
+        "$.a like_regex \"a\" flag \"z\"",
+        "$.a like_regex \"(a\"",
+        "$.a \"b\"",
+        "$.\"a",
+    ];
+    for source in wrong_sources {
+        assert!(parse_json_path(source.as_bytes()).is_err(), "{source}");
+    }
+}
+
+#[test]
+fn test_path_query() {
+    let json = r#"{"store": {"book": [
+        {"title": "a", "price": 8, "tags": ["x"]},
+        {"title": "b", "price": 12, "isbn": "1-2"},
+        {"title": "c", "price": 22.5}
+    ], "bicycle": {"color": "red", "price": 19.95}}}"#;
+    let value = parse_value(json.as_bytes()).unwrap().to_vec();
+
+    let sources = vec![
+        ("$.store.book[0].title", vec![r#""a""#]),
+        ("$.store.book[*].title", vec![r#""a""#, r#""b""#, r#""c""#]),
+        ("$.store.book.title", vec![r#""a""#, r#""b""#, r#""c""#]),
+        ("$.store.book[last].title", vec![r#""c""#]),
+        ("$.store.book[last - 1].title", vec![r#""b""#]),
+        ("$.store.book[0 to 1].price", vec!["8", "12"]),
+        ("$.store.book[1, 0].price", vec!["12", "8"]),
+        ("$.store.book[5].price", vec![]),
+        ("$.store.book[*] ? (@.price > 10).title", vec![
+            r#""b""#, r#""c""#,
+        ]),
+        ("$.store.book ? (@.price > 10 && @.price < 20).title", vec![
+            r#""b""#,
+        ]),
+        ("$.store.book ? (exists (@.isbn)).title", vec![r#""b""#]),
+        ("$.store.book ? (@.tags == \"x\").title", vec![r#""a""#]),
+        ("$.store.book ? (@.title starts with \"b\").price", vec![
+            "12",
+        ]),
+        (
+            "$.store.book ? (@.title like_regex \"^[AB]$\" flag \"i\").price",
+            vec!["8", "12"],
+        ),
+        ("$.store.book ? ((@.isbn == 1) is unknown).title", vec![
+            r#""b""#,
+        ]),
+        ("$.store.bicycle.color", vec![r#""red""#]),
+        ("$.store.bicycle.*", vec![r#""red""#, "19.95"]),
+        ("$.**.price", vec!["19.95", "8", "12", "22.5"]),
+        ("$.store.**{2}.color", vec![]),
+        ("$.store.**{1}.color", vec![r#""red""#]),
+        ("$.store.book.size()", vec!["3"]),
+        ("$.store.book[0].price.type()", vec![r#""number""#]),
+        ("$.store.book[2].price.floor()", vec!["22"]),
+        ("$.store.book[2].price.ceiling()", vec!["23"]),
+        ("$.store.book[0].price * 2 + 1", vec!["17"]),
+        ("-$.store.book[0].price", vec!["-8"]),
+        ("$.store.book[0].price / 16", vec!["0.5"]),
+        (
+            "$.store.bicycle.keyvalue() ? (@.key == \"color\").value",
+            vec![r#""red""#],
+        ),
+        ("$.store.book[0].price > 5", vec!["true"]),
+        ("$.store.book[0].title > 5", vec!["null"]),
+        ("$.missing", vec![]),
+    ];
+    for (source, expect) in sources {
+        let path = parse_json_path(source.as_bytes()).unwrap();
+        let result = path_query(&value, &path).unwrap();
+        let result = result.iter().map(|v| to_string(v)).collect::<Vec<_>>();
+        assert_eq!(result, expect, "{source}");
+    }
+
+    let strict_errors = vec![
+        "strict $.missing",
+        "strict $.store.book.title",
+        "strict $.store.book[5]",
+        "strict $.store.bicycle[*]",
+        "$.store.book[0].title + 1",
+        "$.store.book[0].price / 0",
+        "$.store.book[0].price.keyvalue()",
+    ];
+    for source in strict_errors {
+        let path = parse_json_path(source.as_bytes()).unwrap();
+        assert!(path_query(&value, &path).is_err(), "{source}");
+    }
+
+    // integers overflowing the arithmetic are computed as floats
+    let value = parse_value(r#"{"a": -9223372036854775808}"#.as_bytes())
+        .unwrap()
+        .to_vec();
+    for (source, expect) in [
+        ("$.a / -1", 9223372036854775808.0),
+        ("$.a * 2", -18446744073709551616.0),
+    ] {
+        let path = parse_json_path(source.as_bytes()).unwrap();
+        let result = path_query(&value, &path).unwrap();
+        assert_eq!(result.len(), 1, "{source}");
+        assert_eq!(as_f64(&result[0]), Some(expect), "{source}");
+    }
+    let path = parse_json_path("$.a % -1".as_bytes()).unwrap();
+    let result = path_query(&value, &path).unwrap();
+    assert_eq!(as_f64(&result[0]), Some(0.0));
+}
+
+#[test]
+fn test_path_exists_and_match() {
+    let value = parse_value(r#"{"a": [1, 2, 3], "b": "x"}"#.as_bytes())
+        .unwrap()
+        .to_vec();
+
+    let sources = vec![
+        ("$.a", true),
+        ("$.a ? (@ > 2)", true),
+        ("$.a ? (@ > 3)", false),
+        ("$.c", false),
+        ("$.a[10]", false),
+    ];
+    for (source, expect) in sources {
+        let path = parse_json_path(source.as_bytes()).unwrap();
+        assert_eq!(path_exists(&value, &path).unwrap(), expect, "{source}");
+    }
+
+    let sources = vec![
+        ("$.a[*] > 2", Some(true)),
+        ("$.a[*] > 3", Some(false)),
+        ("$.b == \"x\"", Some(true)),
+        ("$.b > 1", None),
+        ("strict $.a[*] > 2", Some(true)),
+        ("exists ($.c)", Some(false)),
+    ];
+    for (source, expect) in sources {
+        let path = parse_json_path(source.as_bytes()).unwrap();
+        assert_eq!(path_match(&value, &path).unwrap(), expect, "{source}");
+    }
+
+    let path = parse_json_path("$.a".as_bytes()).unwrap();
+    assert!(path_match(&value, &path).is_err());
+}
@@ -15,4 +15,5 @@
 mod decode;
 mod encode;
 mod functions;
+mod jsonpath;
 mod parser;
@@ -16,6 +16,7 @@
 
 pub mod counter;
 mod dump;
+mod otlp;
 mod recorder;
 
 pub use dump::dump_metric_samples;
@@ -27,6 +28,9 @@ pub use metrics::Counter;
 pub use metrics::Gauge;
 pub use metrics::Histogram;
 pub use metrics_exporter_prometheus::PrometheusHandle;
+pub use otlp::export_metrics;
+pub use otlp::init_otlp_metrics_exporter;
+pub use otlp::metric_samples_to_otlp;
 pub use recorder::init_default_metrics_recorder;
 pub use recorder::label_counter;
 pub use recorder::label_counter_with_val;
@@ -0,0 +1,216 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_tracing::otlp_key_value;
+use common_tracing::otlp_resource;
+use common_tracing::otlp_scope;
+use common_tracing::unix_nanos;
+use common_tracing::OTLPClient;
+use common_tracing::OTLPConfig;
+use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+use opentelemetry_proto::tonic::common::v1::KeyValue;
+use opentelemetry_proto::tonic::metrics::v1::metric;
+use opentelemetry_proto::tonic::metrics::v1::number_data_point;
+use opentelemetry_proto::tonic::metrics::v1::summary_data_point::ValueAtQuantile;
+use opentelemetry_proto::tonic::metrics::v1::AggregationTemporality;
+use opentelemetry_proto::tonic::metrics::v1::Gauge;
+use opentelemetry_proto::tonic::metrics::v1::Histogram;
+use opentelemetry_proto::tonic::metrics::v1::HistogramDataPoint;
+use opentelemetry_proto::tonic::metrics::v1::Metric;
+use opentelemetry_proto::tonic::metrics::v1::NumberDataPoint;
+use opentelemetry_proto::tonic::metrics::v1::ResourceMetrics;
+use opentelemetry_proto::tonic::metrics::v1::ScopeMetrics;
+use opentelemetry_proto::tonic::metrics::v1::Sum;
+use opentelemetry_proto::tonic::metrics::v1::Summary;
+use opentelemetry_proto::tonic::metrics::v1::SummaryDataPoint;
+use tracing::warn;
+
+use crate::dump_metric_samples;
+use crate::try_handle;
+use crate::MetricSample;
+use crate::MetricValue;
+
+const OTLP_METRICS_EXPORT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Export the metrics recorded by the default recorder to the collector periodically,
+/// it is a no-op if OTLP is not on.
+///
+/// It must be called in a tokio runtime.
+pub fn init_otlp_metrics_exporter(service_name: &str, cfg: &OTLPConfig) -> Result<()> {
+    if !cfg.on {
+        return Ok(());
+    }
+
+    let client = OTLPClient::create(cfg)?;
+    let service_name = service_name.to_string();
+    let start_time = SystemTime::now();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(OTLP_METRICS_EXPORT_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            if let Err(cause) = export_metrics(&client, &service_name, start_time).await {
+                warn!("Export metrics to OTLP collector failed, cause: {}", cause);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Export a snapshot of the metrics recorded by the default recorder.
+pub async fn export_metrics(
+    client: &OTLPClient,
+    service_name: &str,
+    start_time: SystemTime,
+) -> Result<()> {
+    let samples = match try_handle() {
+        None => return Ok(()),
+        Some(handle) => dump_metric_samples(handle)?,
+    };
+
+    let request = ExportMetricsServiceRequest {
+        resource_metrics: vec![ResourceMetrics {
+            resource: Some(otlp_resource(service_name)),
+            scope_metrics: vec![ScopeMetrics {
+                scope: Some(otlp_scope()),
+                metrics: metric_samples_to_otlp(samples, start_time, SystemTime::now()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+    };
+
+    client.export_metrics(request).await
+}
+
+/// Convert the samples to OTLP metrics, the samples of the same name are the data points of a metric.
+///
+/// The counters are cumulative since `start_time`.
+pub fn metric_samples_to_otlp(
+    samples: Vec<MetricSample>,
+    start_time: SystemTime,
+    time: SystemTime,
+) -> Vec<Metric> {
+    let start_time_unix_nano = unix_nanos(start_time);
+    let time_unix_nano = unix_nanos(time);
+
+    let mut metrics: BTreeMap<String, Metric> = BTreeMap::new();
+    for sample in samples {
+        let mut attributes = sample
+            .labels
+            .iter()
+            .map(|(k, v)| otlp_key_value(k, v.as_str()))
+            .collect::<Vec<KeyValue>>();
+        attributes.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let number = |v: f64| NumberDataPoint {
+            attributes: attributes.clone(),
+            start_time_unix_nano,
+            time_unix_nano,
+            value: Some(number_data_point::Value::AsDouble(v)),
+            ..Default::default()
+        };
+
+        let data = match sample.value {
+            MetricValue::Counter(v) => metric::Data::Sum(Sum {
+                data_points: vec![number(v)],
+                aggregation_temporality: AggregationTemporality::Cumulative as i32,
+                is_monotonic: true,
+            }),
+            MetricValue::Gauge(v) | MetricValue::Untyped(v) => metric::Data::Gauge(Gauge {
+                data_points: vec![number(v)],
+            }),
+            MetricValue::Histogram(buckets) => {
+                // The prometheus buckets are cumulative and end with `+Inf`.
+                let mut explicit_bounds = vec![];
+                let mut bucket_counts = vec![];
+                let mut prev = 0.0;
+                for bucket in buckets.iter() {
+                    if bucket.less_than.is_finite() {
+                        explicit_bounds.push(bucket.less_than);
+                    }
+                    bucket_counts.push((bucket.count - prev).max(0.0) as u64);
+                    prev = bucket.count;
+                }
+                if buckets.last().map_or(true, |b| b.less_than.is_finite()) {
+                    bucket_counts.push(0);
+                }
+
+                metric::Data::Histogram(Histogram {
+                    data_points: vec![HistogramDataPoint {
+                        attributes: attributes.clone(),
+                        start_time_unix_nano,
+                        time_unix_nano,
+                        count: prev as u64,
+                        bucket_counts,
+                        explicit_bounds,
+                        ..Default::default()
+                    }],
+                    aggregation_temporality: AggregationTemporality::Cumulative as i32,
+                })
+            }
+            MetricValue::Summary(quantiles) => metric::Data::Summary(Summary {
+                data_points: vec![SummaryDataPoint {
+                    attributes: attributes.clone(),
+                    start_time_unix_nano,
+                    time_unix_nano,
+                    quantile_values: quantiles
+                        .iter()
+                        .map(|q| ValueAtQuantile {
+                            quantile: q.quantile,
+                            value: q.count,
+                        })
+                        .collect(),
+                    ..Default::default()
+                }],
+            }),
+        };
+
+        let metric = metrics
+            .entry(sample.name.clone())
+            .or_insert_with(|| Metric {
+                name: sample.name.clone(),
+                ..Default::default()
+            });
+        match (&mut metric.data, data) {
+            (Some(metric::Data::Sum(a)), metric::Data::Sum(b)) => {
+                a.data_points.extend(b.data_points)
+            }
+            (Some(metric::Data::Gauge(a)), metric::Data::Gauge(b)) => {
+                a.data_points.extend(b.data_points)
+            }
+            (Some(metric::Data::Histogram(a)), metric::Data::Histogram(b)) => {
+                a.data_points.extend(b.data_points)
+            }
+            (Some(metric::Data::Summary(a)), metric::Data::Summary(b)) => {
+                a.data_points.extend(b.data_points)
+            }
+            (existing, data) => {
+                if existing.is_none() {
+                    *existing = Some(data);
+                }
+            }
+        }
+    }
+
+    metrics.into_values().collect()
+}
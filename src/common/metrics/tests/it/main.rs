@@ -13,11 +13,24 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::time::SystemTime;
+
+#[path = "../../../tracing/tests/it/otlp_collector.rs"]
+mod otlp_collector;
 
 use common_metrics::dump_metric_samples;
+use common_metrics::export_metrics;
 use common_metrics::init_default_metrics_recorder;
 use common_metrics::try_handle;
 use common_metrics::MetricValue;
+use common_tracing::OTLPClient;
+use common_tracing::OTLPConfig;
+use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+use opentelemetry_proto::tonic::metrics::v1::metric;
+use opentelemetry_proto::tonic::metrics::v1::number_data_point;
+use prost::Message;
+
+use crate::otlp_collector::spawn_http_collector;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn test_dump_metric_samples() -> common_exception::Result<()> {
@@ -51,3 +64,41 @@ async fn test_dump_metric_samples() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_export_metrics_to_otlp_collector() -> common_exception::Result<()> {
+    init_default_metrics_recorder();
+    metrics::counter!("test.otlp_export_count", 3);
+
+    let (addr, collector) = spawn_http_collector().await;
+
+    let client = OTLPClient::create(&OTLPConfig {
+        on: true,
+        endpoint: format!("http://{}", addr),
+        protocol: "http".to_string(),
+        ..Default::default()
+    })?;
+    export_metrics(&client, "test", SystemTime::now()).await?;
+
+    let (path, body) = collector.await.unwrap();
+    assert_eq!("/v1/metrics", path);
+
+    let request = ExportMetricsServiceRequest::decode(body.as_slice()).unwrap();
+    let metrics = &request.resource_metrics[0].scope_metrics[0].metrics;
+    let metric = metrics
+        .iter()
+        .find(|m| m.name == "test_otlp_export_count")
+        .unwrap();
+    match &metric.data {
+        Some(metric::Data::Sum(sum)) => {
+            assert!(sum.is_monotonic);
+            assert_eq!(
+                Some(number_data_point::Value::AsDouble(3.0)),
+                sum.data_points[0].value
+            );
+        }
+        data => panic!("expect a sum, got {:?}", data),
+    }
+
+    Ok(())
+}
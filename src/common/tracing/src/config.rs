@@ -20,6 +20,7 @@ use std::fmt::Formatter;
 pub struct Config {
     pub file: FileConfig,
     pub stderr: StderrConfig,
+    pub otlp: OTLPConfig,
 }
 
 impl Config {
@@ -37,6 +38,7 @@ impl Config {
                 level: "INFO".to_string(),
                 format: "text".to_string(),
             },
+            otlp: OTLPConfig::default(),
         }
     }
 }
@@ -103,3 +105,35 @@ impl Default for StderrConfig {
         }
     }
 }
+
+/// Config for exporting traces, metrics and logs to an OpenTelemetry collector.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct OTLPConfig {
+    pub on: bool,
+    pub level: String,
+    /// The address of the collector, e.g. `http://127.0.0.1:4317`.
+    pub endpoint: String,
+    /// `grpc` or `http`, the latter sends protobuf to `{endpoint}/v1/{traces|metrics|logs}`.
+    pub protocol: String,
+}
+
+impl Display for OTLPConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "enabled={}, level={}, endpoint={}, protocol={}",
+            self.on, self.level, self.endpoint, self.protocol
+        )
+    }
+}
+
+impl Default for OTLPConfig {
+    fn default() -> Self {
+        Self {
+            on: false,
+            level: "INFO".to_string(),
+            endpoint: "http://127.0.0.1:4317".to_string(),
+            protocol: "grpc".to_string(),
+        }
+    }
+}
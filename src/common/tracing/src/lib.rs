@@ -19,15 +19,25 @@
 mod macros;
 mod config;
 mod logging;
+mod otlp;
 mod panic_hook;
 mod tracing_to_jaeger;
 
 pub use config::Config;
 pub use config::FileConfig;
+pub use config::OTLPConfig;
 pub use config::StderrConfig;
 pub use logging::init_logging;
 pub use logging::init_query_logger;
 pub use logging::QueryLogger;
+pub use otlp::new_otlp_tracer;
+pub use otlp::otlp_key_value;
+pub use otlp::otlp_resource;
+pub use otlp::otlp_scope;
+pub use otlp::unix_nanos;
+pub use otlp::OTLPClient;
+pub use otlp::OTLPLogLayer;
+pub use otlp::OTLPProtocol;
 pub use panic_hook::log_panic;
 pub use panic_hook::set_panic_hook;
 pub use tracing_to_jaeger::extract_remote_span_as_parent;
@@ -43,6 +43,9 @@ use tracing_subscriber::EnvFilter;
 use tracing_subscriber::Layer;
 use tracing_subscriber::Registry;
 
+use crate::otlp::new_otlp_tracer;
+use crate::otlp::OTLPClient;
+use crate::otlp::OTLPLogLayer;
 use crate::Config;
 
 /// Init logging and tracing.
@@ -139,12 +142,33 @@ pub fn init_logging(name: &str, cfg: &Config) -> Vec<WorkerGuard> {
         subscriber.with(None).with(None)
     };
 
+    // OpenTelemetry layers, exporting the spans and logs to a collector.
+    // The metrics are exported by `common_metrics::init_otlp_metrics_exporter`.
+    let mut otlp_trace_layer = None;
+    let mut otlp_log_layer = None;
+    if cfg.otlp.on {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+
+        let tracer = new_otlp_tracer(name, &cfg.otlp).expect("install OTLP tracer");
+        otlp_trace_layer = Some(
+            tracing_opentelemetry::layer()
+                .with_tracer(tracer)
+                .with_filter(EnvFilter::new(&cfg.otlp.level)),
+        );
+
+        let client = OTLPClient::create(&cfg.otlp).expect("create OTLP client");
+        otlp_log_layer =
+            Some(OTLPLogLayer::create(name, client).with_filter(EnvFilter::new(&cfg.otlp.level)));
+    }
+    let subscriber = subscriber.with(otlp_trace_layer).with(otlp_log_layer);
+
     // Jaeger layer.
     // TODO: we should support config this in the future.
+    // It is replaced by the OpenTelemetry layer if OTLP is on, the two can not share a span.
     let mut jaeger_layer = None;
     let jaeger_agent_endpoint =
         env::var("DATABEND_JAEGER_AGENT_ENDPOINT").unwrap_or_else(|_| "".to_string());
-    if !jaeger_agent_endpoint.is_empty() {
+    if !jaeger_agent_endpoint.is_empty() && !cfg.otlp.on {
         global::set_text_map_propagator(TraceContextPropagator::new());
 
         let tracer = opentelemetry_jaeger::new_agent_pipeline()
@@ -0,0 +1,504 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::str::FromStr;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::mpsc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_proto::tonic::collector::logs::v1::logs_service_client::LogsServiceClient;
+use opentelemetry_proto::tonic::collector::logs::v1::ExportLogsServiceRequest;
+use opentelemetry_proto::tonic::collector::metrics::v1::metrics_service_client::MetricsServiceClient;
+use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+use opentelemetry_proto::tonic::common::v1::any_value;
+use opentelemetry_proto::tonic::common::v1::AnyValue;
+use opentelemetry_proto::tonic::common::v1::InstrumentationScope;
+use opentelemetry_proto::tonic::common::v1::KeyValue;
+use opentelemetry_proto::tonic::logs::v1::LogRecord;
+use opentelemetry_proto::tonic::logs::v1::ResourceLogs;
+use opentelemetry_proto::tonic::logs::v1::ScopeLogs;
+use opentelemetry_proto::tonic::logs::v1::SeverityNumber;
+use opentelemetry_proto::tonic::resource::v1::Resource;
+use prost::Message;
+use tonic::transport::Channel;
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::span;
+use tracing::Event;
+use tracing::Level;
+use tracing::Subscriber;
+use tracing_opentelemetry::OtelData;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use crate::OTLPConfig;
+
+const OTLP_LOGS_BATCH_SIZE: usize = 512;
+const OTLP_LOGS_EXPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The records emitted while the channel is full are dropped, instead of blocking the caller.
+const OTLP_LOGS_CHANNEL_CAPACITY: usize = 8 * OTLP_LOGS_BATCH_SIZE;
+
+/// An export taking longer is abandoned, so a stuck collector doesn't stall the exports.
+const OTLP_EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The span fields that are attached to the logs emitted in the span.
+const OTLP_LOGS_SPAN_FIELDS: [&str; 2] = ["query_id", "tenant"];
+
+/// The logs of the crates sending the exports are not exported, to avoid a feedback loop.
+const OTLP_LOGS_IGNORED_TARGETS: [&str; 5] = ["h2", "hyper", "reqwest", "tonic", "tower"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OTLPProtocol {
+    Grpc,
+    Http,
+}
+
+impl FromStr for OTLPProtocol {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "grpc" => Ok(OTLPProtocol::Grpc),
+            "http" => Ok(OTLPProtocol::Http),
+            v => Err(ErrorCode::InvalidConfig(format!(
+                "OTLP protocol {v} is not supported, expect grpc or http"
+            ))),
+        }
+    }
+}
+
+/// Build a tracer exporting the spans to the collector in batches.
+///
+/// It must be called in a tokio runtime.
+pub fn new_otlp_tracer(
+    service_name: &str,
+    cfg: &OTLPConfig,
+) -> Result<opentelemetry::sdk::trace::Tracer> {
+    let resource = opentelemetry::sdk::Resource::new(vec![opentelemetry::KeyValue::new(
+        "service.name",
+        service_name.to_string(),
+    )]);
+    let pipeline = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_trace_config(opentelemetry::sdk::trace::config().with_resource(resource));
+
+    let endpoint = cfg.endpoint.trim_end_matches('/');
+    let pipeline = match cfg.protocol.parse::<OTLPProtocol>()? {
+        OTLPProtocol::Grpc => pipeline.with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint),
+        ),
+        OTLPProtocol::Http => pipeline.with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .http()
+                .with_endpoint(format!("{}/v1/traces", endpoint)),
+        ),
+    };
+
+    pipeline
+        .install_batch(opentelemetry::runtime::Tokio)
+        .map_err(|cause| ErrorCode::Internal(format!("Cannot install OTLP tracer: {}", cause)))
+}
+
+/// Client of an OpenTelemetry collector, for the signals that are not exported by the SDK.
+#[derive(Clone)]
+pub struct OTLPClient {
+    endpoint: String,
+    transport: OTLPTransport,
+}
+
+#[derive(Clone)]
+enum OTLPTransport {
+    Grpc(Channel),
+    Http(reqwest::Client),
+}
+
+impl OTLPClient {
+    /// The connection is established on the first export, it must be called in a tokio runtime.
+    pub fn create(cfg: &OTLPConfig) -> Result<Self> {
+        let endpoint = cfg.endpoint.trim_end_matches('/').to_string();
+
+        let transport = match cfg.protocol.parse::<OTLPProtocol>()? {
+            OTLPProtocol::Grpc => {
+                let channel = Channel::from_shared(endpoint.clone())
+                    .map_err(|cause| {
+                        ErrorCode::InvalidConfig(format!(
+                            "Invalid OTLP endpoint {}: {}",
+                            endpoint, cause
+                        ))
+                    })?
+                    .connect_lazy();
+                OTLPTransport::Grpc(channel)
+            }
+            OTLPProtocol::Http => OTLPTransport::Http(reqwest::Client::new()),
+        };
+
+        Ok(OTLPClient {
+            endpoint,
+            transport,
+        })
+    }
+
+    pub async fn export_logs(&self, request: ExportLogsServiceRequest) -> Result<()> {
+        with_timeout("logs", async {
+            match &self.transport {
+                OTLPTransport::Grpc(channel) => {
+                    let mut client = LogsServiceClient::new(channel.clone());
+                    client.export(request).await.map_err(|status| {
+                        ErrorCode::Internal(format!("Cannot export logs: {}", status))
+                    })?;
+                    Ok(())
+                }
+                OTLPTransport::Http(client) => self.post(client, "/v1/logs", request).await,
+            }
+        })
+        .await
+    }
+
+    pub async fn export_metrics(&self, request: ExportMetricsServiceRequest) -> Result<()> {
+        with_timeout("metrics", async {
+            match &self.transport {
+                OTLPTransport::Grpc(channel) => {
+                    let mut client = MetricsServiceClient::new(channel.clone());
+                    client.export(request).await.map_err(|status| {
+                        ErrorCode::Internal(format!("Cannot export metrics: {}", status))
+                    })?;
+                    Ok(())
+                }
+                OTLPTransport::Http(client) => self.post(client, "/v1/metrics", request).await,
+            }
+        })
+        .await
+    }
+
+    async fn post(
+        &self,
+        client: &reqwest::Client,
+        path: &str,
+        message: impl Message,
+    ) -> Result<()> {
+        let url = format!("{}{}", self.endpoint, path);
+        let response = client
+            .post(&url)
+            .header("content-type", "application/x-protobuf")
+            .body(message.encode_to_vec())
+            .send()
+            .await
+            .map_err(|cause| ErrorCode::Internal(format!("Cannot export to {}: {}", url, cause)))?;
+
+        if !response.status().is_success() {
+            return Err(ErrorCode::Internal(format!(
+                "Cannot export to {}: {}",
+                url,
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+}
+
+async fn with_timeout(
+    signal: &str,
+    export: impl std::future::Future<Output = Result<()>>,
+) -> Result<()> {
+    tokio::time::timeout(OTLP_EXPORT_TIMEOUT, export)
+        .await
+        .map_err(|_| {
+            ErrorCode::Internal(format!(
+                "Cannot export {}: timed out after {:?}",
+                signal, OTLP_EXPORT_TIMEOUT
+            ))
+        })?
+}
+
+pub fn otlp_resource(service_name: &str) -> Resource {
+    Resource {
+        attributes: vec![otlp_key_value("service.name", service_name)],
+        ..Default::default()
+    }
+}
+
+pub fn otlp_scope() -> InstrumentationScope {
+    InstrumentationScope {
+        name: "databend".to_string(),
+        ..Default::default()
+    }
+}
+
+pub fn otlp_key_value(key: &str, value: impl Into<String>) -> KeyValue {
+    otlp_attribute(key, any_value::Value::StringValue(value.into()))
+}
+
+fn otlp_attribute(key: &str, value: any_value::Value) -> KeyValue {
+    KeyValue {
+        key: key.to_string(),
+        value: Some(AnyValue { value: Some(value) }),
+    }
+}
+
+pub fn unix_nanos(t: SystemTime) -> u64 {
+    t.duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos() as u64
+}
+
+/// A layer exporting the events as OTLP logs, in the trace and span they are emitted in.
+pub struct OTLPLogLayer {
+    tx: mpsc::Sender<LogRecord>,
+    /// The number of records dropped since the last export, reported in the next batch.
+    dropped: Arc<AtomicU64>,
+}
+
+impl OTLPLogLayer {
+    /// Create the layer and spawn the task exporting the logs in batches.
+    ///
+    /// It must be called in a tokio runtime.
+    pub fn create(service_name: &str, client: OTLPClient) -> Self {
+        let (tx, rx) = mpsc::channel(OTLP_LOGS_CHANNEL_CAPACITY);
+        let dropped = Arc::new(AtomicU64::new(0));
+        tokio::spawn(export_logs(
+            client,
+            otlp_resource(service_name),
+            rx,
+            dropped.clone(),
+        ));
+        OTLPLogLayer { tx, dropped }
+    }
+}
+
+/// The fields of a span in [`OTLP_LOGS_SPAN_FIELDS`].
+struct OTLPSpanFields(Vec<KeyValue>);
+
+impl<S> Layer<S> for OTLPLogLayer
+where S: Subscriber + for<'a> LookupSpan<'a>
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+
+        let fields = visitor
+            .attributes
+            .into_iter()
+            .filter(|kv| OTLP_LOGS_SPAN_FIELDS.contains(&kv.key.as_str()))
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return;
+        }
+
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(OTLPSpanFields(fields));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let target = metadata.target();
+        if OTLP_LOGS_IGNORED_TARGETS
+            .iter()
+            .any(|t| target == *t || target.starts_with(&format!("{}::", t)))
+        {
+            return;
+        }
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+
+        let now = unix_nanos(SystemTime::now());
+        let mut record = LogRecord {
+            time_unix_nano: now,
+            observed_time_unix_nano: now,
+            severity_number: severity_number(metadata.level()) as i32,
+            severity_text: metadata.level().to_string(),
+            body: Some(AnyValue {
+                value: Some(any_value::Value::StringValue(visitor.message)),
+            }),
+            attributes: visitor.attributes,
+            ..Default::default()
+        };
+        record.attributes.push(otlp_key_value("target", target));
+
+        // From the innermost span to the root.
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope {
+                let extensions = span.extensions();
+
+                if let Some(fields) = extensions.get::<OTLPSpanFields>() {
+                    for kv in fields.0.iter() {
+                        if !record.attributes.iter().any(|x| x.key == kv.key) {
+                            record.attributes.push(kv.clone());
+                        }
+                    }
+                }
+
+                if record.span_id.is_empty() {
+                    if let Some(otel) = extensions.get::<OtelData>() {
+                        if let Some(span_id) = otel.builder.span_id {
+                            let trace_id = otel
+                                .builder
+                                .trace_id
+                                .unwrap_or_else(|| otel.parent_cx.span().span_context().trace_id());
+                            record.trace_id = trace_id.to_bytes().to_vec();
+                            record.span_id = span_id.to_bytes().to_vec();
+                        }
+                    }
+                }
+            }
+        }
+
+        // The receiver is only dropped when the runtime shuts down.
+        if let Err(mpsc::error::TrySendError::Full(_)) = self.tx.try_send(record) {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+fn severity_number(level: &Level) -> SeverityNumber {
+    match *level {
+        Level::TRACE => SeverityNumber::Trace,
+        Level::DEBUG => SeverityNumber::Debug,
+        Level::INFO => SeverityNumber::Info,
+        Level::WARN => SeverityNumber::Warn,
+        Level::ERROR => SeverityNumber::Error,
+    }
+}
+
+/// Collects the `message` field as the log body, and the others as attributes.
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    attributes: Vec<KeyValue>,
+}
+
+impl FieldVisitor {
+    fn record(&mut self, field: &Field, value: any_value::Value) {
+        // The metadata of the records from `log`, which are already in the log record.
+        if field.name().starts_with("log.") {
+            return;
+        }
+
+        match (field.name(), value) {
+            ("message", any_value::Value::StringValue(s)) => self.message = s,
+            (name, value) => self.attributes.push(otlp_attribute(name, value)),
+        }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.record(field, any_value::Value::DoubleValue(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record(field, any_value::Value::IntValue(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record(field, any_value::Value::IntValue(value as i64));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.record(field, any_value::Value::BoolValue(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, any_value::Value::StringValue(value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.record(field, any_value::Value::StringValue(format!("{:?}", value)));
+    }
+}
+
+async fn export_logs(
+    client: OTLPClient,
+    resource: Resource,
+    mut rx: mpsc::Receiver<LogRecord>,
+    dropped: Arc<AtomicU64>,
+) {
+    let mut records = vec![];
+    let mut interval = tokio::time::interval(OTLP_LOGS_EXPORT_INTERVAL);
+    let mut closed = false;
+
+    while !closed {
+        tokio::select! {
+            record = rx.recv() => match record {
+                Some(record) => {
+                    records.push(record);
+                    if records.len() < OTLP_LOGS_BATCH_SIZE {
+                        continue;
+                    }
+                }
+                None => closed = true,
+            },
+            _ = interval.tick() => {}
+        }
+
+        let num_dropped = dropped.swap(0, Ordering::Relaxed);
+        if num_dropped > 0 {
+            records.push(dropped_logs_record(num_dropped));
+        }
+
+        if records.is_empty() {
+            continue;
+        }
+
+        let request = ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                resource: Some(resource.clone()),
+                scope_logs: vec![ScopeLogs {
+                    scope: Some(otlp_scope()),
+                    log_records: std::mem::take(&mut records),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        };
+
+        // The failure is not logged, since the log would be exported again.
+        let _ = client.export_logs(request).await;
+    }
+}
+
+/// The record reporting the logs dropped because the export could not keep up.
+fn dropped_logs_record(num_dropped: u64) -> LogRecord {
+    let now = unix_nanos(SystemTime::now());
+    LogRecord {
+        time_unix_nano: now,
+        observed_time_unix_nano: now,
+        severity_number: SeverityNumber::Warn as i32,
+        severity_text: Level::WARN.to_string(),
+        body: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(format!(
+                "{} log records were dropped, the OTLP export could not keep up",
+                num_dropped
+            ))),
+        }),
+        attributes: vec![otlp_attribute(
+            "dropped",
+            any_value::Value::IntValue(num_dropped as i64),
+        )],
+        ..Default::default()
+    }
+}
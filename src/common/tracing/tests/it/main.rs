@@ -0,0 +1,217 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod otlp_collector;
+
+use std::net::SocketAddr;
+
+use common_base::base::tokio;
+use common_base::base::tokio::net::TcpListener;
+use common_base::base::tokio::sync::mpsc;
+use common_exception::Result;
+use common_tracing::new_otlp_tracer;
+use common_tracing::OTLPClient;
+use common_tracing::OTLPConfig;
+use common_tracing::OTLPLogLayer;
+use opentelemetry_proto::tonic::collector::logs::v1::logs_service_server::LogsService;
+use opentelemetry_proto::tonic::collector::logs::v1::logs_service_server::LogsServiceServer;
+use opentelemetry_proto::tonic::collector::logs::v1::ExportLogsServiceRequest;
+use opentelemetry_proto::tonic::collector::logs::v1::ExportLogsServiceResponse;
+use opentelemetry_proto::tonic::collector::trace::v1::trace_service_server::TraceService;
+use opentelemetry_proto::tonic::collector::trace::v1::trace_service_server::TraceServiceServer;
+use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceResponse;
+use opentelemetry_proto::tonic::common::v1::any_value;
+use opentelemetry_proto::tonic::common::v1::KeyValue;
+use prost::Message;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::transport::Server;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::Registry;
+
+use crate::otlp_collector::spawn_http_collector;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_export_logs_to_otlp_collector() -> Result<()> {
+    let (addr, collector) = spawn_http_collector().await;
+    emit_query_log(&otlp_config(addr, "http"))?;
+
+    let (path, body) = collector.await.unwrap();
+    assert_eq!("/v1/logs", path);
+
+    let request = ExportLogsServiceRequest::decode(body.as_slice()).unwrap();
+    assert_query_log(&request);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_export_logs_to_otlp_collector_over_grpc() -> Result<()> {
+    let (addr, mut exports) = spawn_grpc_collector().await;
+    emit_query_log(&otlp_config(addr, "grpc"))?;
+
+    match exports.recv().await {
+        Some(GrpcExport::Logs(request)) => assert_query_log(&request),
+        _ => panic!("expect the logs to be exported"),
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_export_traces_to_otlp_collector() -> Result<()> {
+    // The tracer is installed as the global tracer provider, thus the protocols are
+    // tested one after another.
+    let (addr, collector) = spawn_http_collector().await;
+    export_query_span(&otlp_config(addr, "http")).await?;
+
+    let (path, body) = collector.await.unwrap();
+    assert_eq!("/v1/traces", path);
+    let request = ExportTraceServiceRequest::decode(body.as_slice()).unwrap();
+    assert_query_span(&request);
+
+    let (addr, mut exports) = spawn_grpc_collector().await;
+    export_query_span(&otlp_config(addr, "grpc")).await?;
+
+    match exports.recv().await {
+        Some(GrpcExport::Traces(request)) => assert_query_span(&request),
+        _ => panic!("expect the spans to be exported"),
+    }
+    Ok(())
+}
+
+fn otlp_config(addr: SocketAddr, protocol: &str) -> OTLPConfig {
+    OTLPConfig {
+        on: true,
+        endpoint: format!("http://{}", addr),
+        protocol: protocol.to_string(),
+        ..Default::default()
+    }
+}
+
+fn emit_query_log(config: &OTLPConfig) -> Result<()> {
+    let client = OTLPClient::create(config)?;
+    let subscriber = Registry::default().with(OTLPLogLayer::create("test", client));
+    tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::info_span!("query", query_id = "q1", tenant = "t1");
+        let _guard = span.enter();
+        tracing::info!(rows = 3, "query finished");
+    });
+    Ok(())
+}
+
+/// Runs a span like the one of a query, and exports it.
+async fn export_query_span(config: &OTLPConfig) -> Result<()> {
+    let tracer = new_otlp_tracer("test", config)?;
+    let subscriber = Registry::default().with(tracing_opentelemetry::layer().with_tracer(tracer));
+    tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::info_span!("query", query_id = "q1", tenant = "t1");
+        let _guard = span.enter();
+        tracing::info!("query finished");
+    });
+
+    // The spans are exported in batches, shutting down the provider exports the batch.
+    tokio::task::spawn_blocking(opentelemetry::global::shutdown_tracer_provider)
+        .await
+        .unwrap();
+    Ok(())
+}
+
+fn assert_query_log(request: &ExportLogsServiceRequest) {
+    let record = &request.resource_logs[0].scope_logs[0].log_records[0];
+    assert_eq!("INFO", record.severity_text);
+    assert_eq!(
+        Some(any_value::Value::StringValue("query finished".to_string())),
+        record.body.as_ref().and_then(|v| v.value.clone())
+    );
+
+    assert_eq!(
+        Some(any_value::Value::IntValue(3)),
+        attribute(&record.attributes, "rows")
+    );
+    assert_query_attributes(&record.attributes);
+}
+
+fn assert_query_span(request: &ExportTraceServiceRequest) {
+    let span = &request.resource_spans[0].scope_spans[0].spans[0];
+    assert_eq!("query", span.name);
+    assert_query_attributes(&span.attributes);
+}
+
+fn assert_query_attributes(attributes: &[KeyValue]) {
+    assert_eq!(
+        Some(any_value::Value::StringValue("q1".to_string())),
+        attribute(attributes, "query_id")
+    );
+    assert_eq!(
+        Some(any_value::Value::StringValue("t1".to_string())),
+        attribute(attributes, "tenant")
+    );
+}
+
+fn attribute(attributes: &[KeyValue], key: &str) -> Option<any_value::Value> {
+    attributes
+        .iter()
+        .find(|kv| kv.key == key)
+        .and_then(|kv| kv.value.as_ref())
+        .and_then(|v| v.value.clone())
+}
+
+/// A request received by the stand-in of the collector over gRPC.
+enum GrpcExport {
+    Logs(ExportLogsServiceRequest),
+    Traces(ExportTraceServiceRequest),
+}
+
+#[derive(Clone)]
+struct GrpcCollector {
+    exports: mpsc::UnboundedSender<GrpcExport>,
+}
+
+#[tonic::async_trait]
+impl LogsService for GrpcCollector {
+    async fn export(
+        &self,
+        request: tonic::Request<ExportLogsServiceRequest>,
+    ) -> std::result::Result<tonic::Response<ExportLogsServiceResponse>, tonic::Status> {
+        let _ = self.exports.send(GrpcExport::Logs(request.into_inner()));
+        Ok(tonic::Response::new(ExportLogsServiceResponse::default()))
+    }
+}
+
+#[tonic::async_trait]
+impl TraceService for GrpcCollector {
+    async fn export(
+        &self,
+        request: tonic::Request<ExportTraceServiceRequest>,
+    ) -> std::result::Result<tonic::Response<ExportTraceServiceResponse>, tonic::Status> {
+        let _ = self.exports.send(GrpcExport::Traces(request.into_inner()));
+        Ok(tonic::Response::new(ExportTraceServiceResponse::default()))
+    }
+}
+
+/// Starts a collector serving the logs and the traces over gRPC, the received requests
+/// are sent to the returned receiver.
+async fn spawn_grpc_collector() -> (SocketAddr, mpsc::UnboundedReceiver<GrpcExport>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let (tx, rx) = mpsc::unbounded_channel();
+    let collector = GrpcCollector { exports: tx };
+    tokio::spawn(
+        Server::builder()
+            .add_service(LogsServiceServer::new(collector.clone()))
+            .add_service(TraceServiceServer::new(collector))
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+    (addr, rx)
+}
@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A stand-in of the OpenTelemetry collector for the tests of the exports over OTLP/HTTP,
+//! shared by the tests of the crates exporting to the collector.
+
+use std::net::SocketAddr;
+
+use common_base::base::tokio;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::net::TcpListener;
+use common_base::base::tokio::net::TcpStream;
+use common_base::base::tokio::task::JoinHandle;
+
+/// Starts a collector which receives a single OTLP/HTTP request, the handle returns the
+/// path and the body of the request.
+pub async fn spawn_http_collector() -> (SocketAddr, JoinHandle<(String, Vec<u8>)>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let collector = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let request = read_http_request(&mut stream).await;
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+            .await
+            .unwrap();
+        request
+    });
+    (addr, collector)
+}
+
+/// Returns the path and the body of a HTTP request.
+async fn read_http_request(stream: &mut TcpStream) -> (String, Vec<u8>) {
+    let mut buf = vec![];
+    let mut chunk = [0u8; 4096];
+
+    loop {
+        let n = stream.read(&mut chunk).await.unwrap();
+        assert!(n > 0, "connection closed before the request is received");
+        buf.extend_from_slice(&chunk[..n]);
+
+        let header_end = match buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            None => continue,
+            Some(pos) => pos + 4,
+        };
+        let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+        let content_length = head
+            .lines()
+            .find_map(|line| {
+                let line = line.to_lowercase();
+                line.strip_prefix("content-length:")
+                    .map(|v| v.trim().parse::<usize>().unwrap())
+            })
+            .unwrap_or(0);
+
+        while buf.len() < header_end + content_length {
+            let n = stream.read(&mut chunk).await.unwrap();
+            assert!(n > 0, "connection closed before the body is received");
+            buf.extend_from_slice(&chunk[..n]);
+        }
+
+        let path = head.split_whitespace().nth(1).unwrap().to_string();
+        return (path, buf[header_end..header_end + content_length].to_vec());
+    }
+}
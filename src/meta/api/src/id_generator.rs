@@ -16,6 +16,7 @@ use crate::kv_api_key::check_segment;
 use crate::kv_api_key::check_segment_absent;
 use crate::kv_api_key::check_segment_present;
 use crate::schema_api_keys::ID_GEN_DATABASE;
+use crate::schema_api_keys::ID_GEN_SEQUENCE;
 use crate::schema_api_keys::ID_GEN_TABLE;
 use crate::share_api_keys::ID_GEN_SHARE;
 use crate::KVApiKey;
@@ -54,6 +55,13 @@ impl IdGenerator {
             resource: ID_GEN_SHARE.to_string(),
         }
     }
+
+    /// Create a key for generating sequence id with KVApi
+    pub fn sequence_id() -> Self {
+        Self {
+            resource: ID_GEN_SEQUENCE.to_string(),
+        }
+    }
 }
 
 impl KVApiKey for IdGenerator {
@@ -116,6 +124,16 @@ mod t {
             assert_eq!(g, t2);
         }
 
+        // Sequence id generator
+        {
+            let g = IdGenerator::sequence_id();
+            let k = g.to_key();
+            assert_eq!("__fd_id_gen/sequence_id", k);
+
+            let t2 = IdGenerator::from_key(&k)?;
+            assert_eq!(g, t2);
+        }
+
         Ok(())
     }
 
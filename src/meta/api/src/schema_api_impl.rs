@@ -24,6 +24,8 @@ use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateSequenceReply;
+use common_meta_app::schema::CreateSequenceReq;
 use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DBIdTableName;
@@ -38,9 +40,15 @@ use common_meta_app::schema::DbIdList;
 use common_meta_app::schema::DbIdListKey;
 use common_meta_app::schema::DropDatabaseReply;
 use common_meta_app::schema::DropDatabaseReq;
+use common_meta_app::schema::DropSequenceReply;
+use common_meta_app::schema::DropSequenceReq;
 use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::DropTableReq;
 use common_meta_app::schema::GetDatabaseReq;
+use common_meta_app::schema::GetSequenceNextValueReply;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReply;
+use common_meta_app::schema::GetSequenceReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
@@ -50,6 +58,8 @@ use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SequenceIdent;
+use common_meta_app::schema::SequenceMeta;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
@@ -66,6 +76,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -82,6 +94,8 @@ use common_meta_types::errors::app_error::CreateTableWithDropTime;
 use common_meta_types::errors::app_error::DatabaseAlreadyExists;
 use common_meta_types::errors::app_error::DropDbWithDropTime;
 use common_meta_types::errors::app_error::DropTableWithDropTime;
+use common_meta_types::errors::app_error::SequenceAlreadyExists;
+use common_meta_types::errors::app_error::SequenceOverflow;
 use common_meta_types::errors::app_error::ShareHasNoGrantedDatabase;
 use common_meta_types::errors::app_error::ShareHasNoGrantedPrivilege;
 use common_meta_types::errors::app_error::TableAlreadyExists;
@@ -92,6 +106,7 @@ use common_meta_types::errors::app_error::UndropDbWithNoDropTime;
 use common_meta_types::errors::app_error::UndropTableAlreadyExists;
 use common_meta_types::errors::app_error::UndropTableHasNoHistory;
 use common_meta_types::errors::app_error::UndropTableWithNoDropTime;
+use common_meta_types::errors::app_error::UnknownSequence;
 use common_meta_types::errors::app_error::UnknownShareAccounts;
 use common_meta_types::errors::app_error::UnknownTable;
 use common_meta_types::errors::app_error::UnknownTableId;
@@ -2102,6 +2117,69 @@ impl<KV: KVApi> SchemaApi for KV {
         }
     }
 
+    #[tracing::instrument(level = "debug", ret, skip_all)]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let mut retry = 0;
+
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let mut condition = vec![];
+            let mut if_then = vec![];
+            for update in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: update.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_struct_value(self, &tbid).await?;
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(update.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if update.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            update.table_id,
+                            update.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                // table is not changed
+                condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                // tb_id -> tb_meta
+                if_then.push(txn_op_put(&tbid, serialize_struct(&update.new_table_meta)?));
+            }
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(succ = display(succ), "update_multi_table_meta");
+
+            if succ {
+                return Ok(UpdateMultiTableMetaReply {});
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("update_multi_table_meta", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn gc_dropped_data(
         &self,
@@ -2184,6 +2262,152 @@ impl<KV: KVApi> SchemaApi for KV {
         Ok(CountTablesReply { count })
     }
 
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn create_sequence(
+        &self,
+        req: CreateSequenceReq,
+    ) -> Result<CreateSequenceReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let ident = &req.ident;
+        let sequence_id = fetch_id(self, IdGenerator::sequence_id()).await?;
+        let meta = SequenceMeta {
+            sequence_id,
+            start: req.start,
+            increment: req.increment,
+            current: req.start,
+            comment: req.comment.clone(),
+            create_on: req.create_on,
+        };
+
+        let txn_req = TxnRequest {
+            condition: vec![txn_cond_seq(ident, Eq, 0)],
+            if_then: vec![
+                txn_op_put(ident, serialize_struct(&meta)?), /* (tenant, sequence_name) -> sequence_meta */
+            ],
+            else_then: vec![],
+        };
+
+        let (succ, _responses) = send_txn(self, txn_req).await?;
+
+        debug!(
+            ident = display(ident),
+            succ = display(succ),
+            "create_sequence"
+        );
+
+        if !succ && !req.if_not_exists {
+            return Err(KVAppError::AppError(AppError::SequenceAlreadyExists(
+                SequenceAlreadyExists::new(
+                    &ident.sequence_name,
+                    format!("create sequence: tenant: {}", ident.tenant),
+                ),
+            )));
+        }
+
+        Ok(CreateSequenceReply {})
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let (_, meta) = get_sequence_or_err(self, &req.ident, "get_sequence").await?;
+        Ok(GetSequenceReply { meta })
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let ident = &req.ident;
+        let (seq, _): (_, Option<SequenceMeta>) = get_struct_value(self, ident).await?;
+        if seq == 0 {
+            return if req.if_exists {
+                Ok(DropSequenceReply {})
+            } else {
+                Err(KVAppError::AppError(AppError::UnknownSequence(
+                    UnknownSequence::new(&ident.sequence_name, "drop_sequence"),
+                )))
+            };
+        }
+
+        // A sequence is never re-created with the same seq, the values it allocated are
+        // useless after it is dropped, thus it is just removed.
+        let txn_req = TxnRequest {
+            condition: vec![],
+            if_then: vec![txn_op_del(ident)],
+            else_then: vec![],
+        };
+        let (succ, _responses) = send_txn(self, txn_req).await?;
+
+        debug!(
+            ident = display(ident),
+            succ = display(succ),
+            "drop_sequence"
+        );
+
+        Ok(DropSequenceReply {})
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn get_sequence_next_value(
+        &self,
+        req: GetSequenceNextValueReq,
+    ) -> Result<GetSequenceNextValueReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let ident = &req.ident;
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+            let (seq, mut meta) =
+                get_sequence_or_err(self, ident, "get_sequence_next_value").await?;
+
+            let start = meta.current;
+            meta.current = i64::try_from(req.count)
+                .ok()
+                .and_then(|count| count.checked_mul(meta.increment))
+                .and_then(|delta| start.checked_add(delta))
+                .ok_or_else(|| {
+                    KVAppError::AppError(AppError::SequenceOverflow(SequenceOverflow::new(
+                        &ident.sequence_name,
+                        format!("allocate {} values from {}", req.count, start),
+                    )))
+                })?;
+
+            let txn_req = TxnRequest {
+                // sequence is not changed by other allocations
+                condition: vec![txn_cond_seq(ident, Eq, seq)],
+                if_then: vec![txn_op_put(ident, serialize_struct(&meta)?)],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                ident = display(ident),
+                start = display(start),
+                count = display(req.count),
+                succ = display(succ),
+                "get_sequence_next_value"
+            );
+
+            if succ {
+                return Ok(GetSequenceNextValueReply {
+                    sequence_id: meta.sequence_id,
+                    start,
+                    increment: meta.increment,
+                    count: req.count,
+                });
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("get_sequence_next_value", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     fn name(&self) -> String {
         "SchemaApiImpl".to_string()
     }
@@ -2507,6 +2731,21 @@ pub(crate) async fn get_db_or_err(
     ))
 }
 
+/// Returns (seq, sequence_meta), or UnknownSequence error if the sequence does not exist.
+async fn get_sequence_or_err(
+    kv_api: &(impl KVApi + ?Sized),
+    ident: &SequenceIdent,
+    msg: impl Display,
+) -> Result<(u64, SequenceMeta), KVAppError> {
+    let (seq, meta): (_, Option<SequenceMeta>) = get_struct_value(kv_api, ident).await?;
+    match meta {
+        Some(meta) if seq > 0 => Ok((seq, meta)),
+        _ => Err(KVAppError::AppError(AppError::UnknownSequence(
+            UnknownSequence::new(&ident.sequence_name, format!("{}: {}", msg, ident)),
+        ))),
+    }
+}
+
 /// Return OK if a db_id or db_meta does not exist by checking the seq.
 ///
 /// Otherwise returns DatabaseAlreadyExists error
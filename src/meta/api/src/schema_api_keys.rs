@@ -20,6 +20,7 @@ use common_meta_app::schema::DatabaseId;
 use common_meta_app::schema::DatabaseIdToName;
 use common_meta_app::schema::DatabaseNameIdent;
 use common_meta_app::schema::DbIdListKey;
+use common_meta_app::schema::SequenceIdent;
 use common_meta_app::schema::TableCopiedFileLockKey;
 use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
@@ -47,9 +48,11 @@ const PREFIX_DATABASE_ID_TO_NAME: &str = "__fd_database_id_to_name";
 const PREFIX_TABLE_ID_TO_NAME: &str = "__fd_table_id_to_name";
 const PREFIX_TABLE_COPIED_FILES: &str = "__fd_table_copied_files";
 const PREFIX_TABLE_COPIED_FILES_LOCK: &str = "__fd_table_copied_file_lock";
+const PREFIX_SEQUENCE: &str = "__fd_sequence";
 
 pub(crate) const ID_GEN_TABLE: &str = "table_id";
 pub(crate) const ID_GEN_DATABASE: &str = "database_id";
+pub(crate) const ID_GEN_SEQUENCE: &str = "sequence_id";
 
 /// __fd_database/<tenant>/<db_name> -> <db_id>
 impl KVApiKey for DatabaseNameIdent {
@@ -346,6 +349,41 @@ impl KVApiKey for TableCopiedFileLockKey {
     }
 }
 
+/// "__fd_sequence/<tenant>/<sequence_name>" -> <SequenceMeta>
+impl KVApiKey for SequenceIdent {
+    const PREFIX: &'static str = PREFIX_SEQUENCE;
+
+    fn to_key(&self) -> String {
+        format!(
+            "{}/{}/{}",
+            Self::PREFIX,
+            escape(&self.tenant),
+            escape(&self.sequence_name),
+        )
+    }
+
+    fn from_key(s: &str) -> Result<Self, KVApiKeyError> {
+        let mut elts = s.split('/');
+
+        let prefix = check_segment_present(elts.next(), 0, s)?;
+        check_segment(prefix, 0, Self::PREFIX)?;
+
+        let tenant = check_segment_present(elts.next(), 1, s)?;
+
+        let sequence_name = check_segment_present(elts.next(), 2, s)?;
+
+        check_segment_absent(elts.next(), 3, s)?;
+
+        let tenant = unescape(tenant)?;
+        let sequence_name = unescape(sequence_name)?;
+
+        Ok(SequenceIdent {
+            tenant,
+            sequence_name,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use common_meta_app::schema::TableCopiedFileNameIdent;
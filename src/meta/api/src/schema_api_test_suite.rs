@@ -26,6 +26,7 @@ use common_expression::TableSchema;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateSequenceReq;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DBIdTableName;
 use common_meta_app::schema::DatabaseId;
@@ -37,14 +38,18 @@ use common_meta_app::schema::DatabaseType;
 use common_meta_app::schema::DbIdList;
 use common_meta_app::schema::DbIdListKey;
 use common_meta_app::schema::DropDatabaseReq;
+use common_meta_app::schema::DropSequenceReq;
 use common_meta_app::schema::DropTableReq;
 use common_meta_app::schema::GetDatabaseReq;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReq;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListTableReq;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SequenceIdent;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
@@ -59,6 +64,7 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_app::schema::UpsertTableOptionReq;
@@ -259,6 +265,7 @@ impl SchemaApiTestSuite {
         suite.get_table_copied_file(&b.build().await).await?;
         suite.truncate_table(&b.build().await).await?;
         suite.get_tables_from_share(&b.build().await).await?;
+        suite.sequence_create_get_drop(&b.build().await).await?;
         Ok(())
     }
 
@@ -317,6 +324,12 @@ impl SchemaApiTestSuite {
             let cluster = b.build_cluster().await;
             suite.table_get_diff_nodes(&cluster[2], &cluster[1]).await?;
         }
+        {
+            let cluster = b.build_cluster().await;
+            suite
+                .sequence_next_value_diff_nodes(&cluster[2], &cluster[1])
+                .await?;
+        }
 
         Ok(())
     }
@@ -2083,6 +2096,73 @@ impl SchemaApiTestSuite {
                 assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
             }
         }
+
+        info!("--- update multi table meta");
+        {
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: "tb3".to_string(),
+                },
+                table_meta: table_meta(Utc::now()),
+            };
+            mt.create_table(req).await?;
+
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            let tb3 = mt.get_table((tenant, db_name, "tb3").into()).await?;
+            let update = |table: &TableInfo, seq: u64, data_bytes: u64| {
+                let mut new_table_meta = table.meta.clone();
+                new_table_meta.statistics = TableStatistics {
+                    data_bytes,
+                    ..Default::default()
+                };
+                UpdateTableMetaReq {
+                    table_id: table.ident.table_id,
+                    seq: MatchSeq::Exact(seq),
+                    new_table_meta,
+                }
+            };
+
+            info!("--- update multi table meta, normal case");
+            {
+                mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update(&tb2, tb2.ident.seq, 2),
+                        update(&tb3, tb3.ident.seq, 3),
+                    ],
+                })
+                .await?;
+
+                let got = mt.get_table((tenant, db_name, "tb2").into()).await?;
+                assert_eq!(got.meta.statistics.data_bytes, 2);
+                let got = mt.get_table((tenant, db_name, "tb3").into()).await?;
+                assert_eq!(got.meta.statistics.data_bytes, 3);
+            }
+
+            info!("--- update multi table meta: version mismatch of one table updates none");
+            {
+                let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+                let tb3 = mt.get_table((tenant, db_name, "tb3").into()).await?;
+                let res = mt
+                    .update_multi_table_meta(UpdateMultiTableMetaReq {
+                        update_table_metas: vec![
+                            update(&tb2, tb2.ident.seq, 4),
+                            update(&tb3, tb3.ident.seq + 1, 5),
+                        ],
+                    })
+                    .await;
+
+                let err = ErrorCode::from(res.unwrap_err());
+                assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+                let got = mt.get_table((tenant, db_name, "tb2").into()).await?;
+                assert_eq!(got.meta.statistics.data_bytes, 2);
+                let got = mt.get_table((tenant, db_name, "tb3").into()).await?;
+                assert_eq!(got.meta.statistics.data_bytes, 3);
+            }
+        }
         Ok(())
     }
 
@@ -3297,6 +3377,157 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn sequence_create_get_drop<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let ident = SequenceIdent::new("tenant1", "seq1");
+        let create_req = |if_not_exists, start| CreateSequenceReq {
+            if_not_exists,
+            ident: ident.clone(),
+            start,
+            increment: 10,
+            comment: "seq".to_string(),
+            create_on: Utc::now(),
+        };
+
+        info!("--- create sequence");
+        {
+            mt.create_sequence(create_req(false, 1)).await?;
+
+            let res = mt.create_sequence(create_req(false, 1)).await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::SequenceAlreadyExists("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            // if not exists does not reset the sequence
+            mt.create_sequence(create_req(true, 100)).await?;
+            let res = mt
+                .get_sequence(GetSequenceReq {
+                    ident: ident.clone(),
+                })
+                .await?;
+            assert_eq!(1, res.meta.start);
+            assert_eq!(1, res.meta.current);
+        }
+
+        info!("--- allocate values");
+        {
+            let res = mt
+                .get_sequence_next_value(GetSequenceNextValueReq {
+                    ident: ident.clone(),
+                    count: 3,
+                })
+                .await?;
+            assert_eq!((1, 10, 3), (res.start, res.increment, res.count));
+
+            let res = mt
+                .get_sequence_next_value(GetSequenceNextValueReq {
+                    ident: ident.clone(),
+                    count: 5,
+                })
+                .await?;
+            assert_eq!((31, 10, 5), (res.start, res.increment, res.count));
+
+            let res = mt
+                .get_sequence(GetSequenceReq {
+                    ident: ident.clone(),
+                })
+                .await?;
+            assert_eq!(81, res.meta.current);
+
+            let res = mt
+                .get_sequence_next_value(GetSequenceNextValueReq {
+                    ident: ident.clone(),
+                    count: u64::MAX,
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::SequenceOverflow("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- drop sequence");
+        {
+            mt.drop_sequence(DropSequenceReq {
+                if_exists: false,
+                ident: ident.clone(),
+            })
+            .await?;
+
+            let res = mt
+                .get_sequence_next_value(GetSequenceNextValueReq {
+                    ident: ident.clone(),
+                    count: 1,
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownSequence("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let res = mt
+                .drop_sequence(DropSequenceReq {
+                    if_exists: false,
+                    ident: ident.clone(),
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownSequence("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            mt.drop_sequence(DropSequenceReq {
+                if_exists: true,
+                ident: ident.clone(),
+            })
+            .await?;
+        }
+
+        info!("--- re-create sequence gets a new id");
+        {
+            mt.create_sequence(create_req(false, 1)).await?;
+            let first = mt
+                .get_sequence(GetSequenceReq {
+                    ident: ident.clone(),
+                })
+                .await?;
+
+            mt.drop_sequence(DropSequenceReq {
+                if_exists: false,
+                ident: ident.clone(),
+            })
+            .await?;
+            mt.create_sequence(create_req(false, 1)).await?;
+            let second = mt
+                .get_sequence(GetSequenceReq {
+                    ident: ident.clone(),
+                })
+                .await?;
+            assert_ne!(first.meta.sequence_id, second.meta.sequence_id);
+
+            let res = mt
+                .get_sequence_next_value(GetSequenceNextValueReq {
+                    ident: ident.clone(),
+                    count: 1,
+                })
+                .await?;
+            assert_eq!(second.meta.sequence_id, res.sequence_id);
+
+            mt.drop_sequence(DropSequenceReq {
+                if_exists: false,
+                ident: ident.clone(),
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn truncate_table<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
@@ -4162,4 +4393,45 @@ impl SchemaApiTestSuite {
 
         Ok(())
     }
+
+    pub async fn sequence_next_value_diff_nodes<MT: SchemaApi>(
+        &self,
+        node_a: &MT,
+        node_b: &MT,
+    ) -> anyhow::Result<()> {
+        let ident = SequenceIdent::new("tenant1", "seq1");
+
+        info!("--- create sequence on node_a");
+        node_a
+            .create_sequence(CreateSequenceReq {
+                if_not_exists: false,
+                ident: ident.clone(),
+                start: 1,
+                increment: 1,
+                comment: "".to_string(),
+                create_on: Utc::now(),
+            })
+            .await?;
+
+        info!("--- allocate values on both nodes");
+        {
+            let mut ranges = vec![];
+            for i in 0..10 {
+                let node = if i % 2 == 0 { node_a } else { node_b };
+                let res = node
+                    .get_sequence_next_value(GetSequenceNextValueReq {
+                        ident: ident.clone(),
+                        count: 100,
+                    })
+                    .await?;
+                ranges.push(res.start);
+            }
+
+            // ranges never overlap
+            let expected = (0..10).map(|i| 1 + i * 100).collect::<Vec<_>>();
+            assert_eq!(expected, ranges);
+        }
+
+        Ok(())
+    }
 }
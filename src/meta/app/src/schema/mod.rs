@@ -16,6 +16,7 @@
 
 mod catalog;
 mod database;
+mod sequence;
 mod table;
 
 pub use catalog::CatalogMeta;
@@ -41,6 +42,16 @@ pub use database::RenameDatabaseReply;
 pub use database::RenameDatabaseReq;
 pub use database::UndropDatabaseReply;
 pub use database::UndropDatabaseReq;
+pub use sequence::CreateSequenceReply;
+pub use sequence::CreateSequenceReq;
+pub use sequence::DropSequenceReply;
+pub use sequence::DropSequenceReq;
+pub use sequence::GetSequenceNextValueReply;
+pub use sequence::GetSequenceNextValueReq;
+pub use sequence::GetSequenceReply;
+pub use sequence::GetSequenceReq;
+pub use sequence::SequenceIdent;
+pub use sequence::SequenceMeta;
 pub use table::CountTablesKey;
 pub use table::CountTablesReply;
 pub use table::CountTablesReq;
@@ -73,6 +84,8 @@ pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
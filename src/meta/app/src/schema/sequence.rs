@@ -0,0 +1,141 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct SequenceIdent {
+    pub tenant: String,
+    pub sequence_name: String,
+}
+
+impl SequenceIdent {
+    pub fn new(tenant: impl Into<String>, sequence_name: impl Into<String>) -> SequenceIdent {
+        SequenceIdent {
+            tenant: tenant.into(),
+            sequence_name: sequence_name.into(),
+        }
+    }
+}
+
+impl Display for SequenceIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'.'{}'", self.tenant, self.sequence_name)
+    }
+}
+
+/// A sequence generates unique i64 values: `start`, `start + increment`, ...
+///
+/// `current` is the next value not allocated yet. Values are allocated in ranges,
+/// every allocation moves `current` forward in a transaction, thus a value is never
+/// handed out twice, but the values skipped by an unused range are never reused.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SequenceMeta {
+    /// Unique id of the sequence, a sequence re-created with the same name gets a new id.
+    pub sequence_id: u64,
+    pub start: i64,
+    pub increment: i64,
+    pub current: i64,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+}
+
+impl Display for SequenceMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "sequence_id: {} start: {} increment: {} current: {} comment: {} create_on: {:?}",
+            self.sequence_id,
+            self.start,
+            self.increment,
+            self.current,
+            self.comment,
+            self.create_on
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequenceReq {
+    pub if_not_exists: bool,
+    pub ident: SequenceIdent,
+    pub start: i64,
+    pub increment: i64,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+}
+
+impl Display for CreateSequenceReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "create_sequence(if_not_exists={}):{} start:{} increment:{}",
+            self.if_not_exists, self.ident, self.start, self.increment
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequenceReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceReq {
+    pub ident: SequenceIdent,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceReply {
+    pub meta: SequenceMeta,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropSequenceReq {
+    pub if_exists: bool,
+    pub ident: SequenceIdent,
+}
+
+impl Display for DropSequenceReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "drop_sequence(if_exists={}):{}",
+            self.if_exists, self.ident
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropSequenceReply {}
+
+/// Allocate the next `count` values of a sequence.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceNextValueReq {
+    pub ident: SequenceIdent,
+    pub count: u64,
+}
+
+/// The allocated values are `start, start + increment, ..., start + (count - 1) * increment`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceNextValueReply {
+    /// Id of the sequence the values are allocated from.
+    pub sequence_id: u64,
+    pub start: i64,
+    pub increment: i64,
+    pub count: u64,
+}
@@ -248,8 +248,14 @@ impl TableInfo {
         }
     }
 
+    /// The schema of the columns stored in the table, the virtual generated columns are
+    /// computed when they are read, and are only kept in `meta.schema`.
     pub fn schema(&self) -> Arc<TableSchema> {
-        self.meta.schema.clone()
+        if self.meta.schema.has_virtual_computed_fields() {
+            Arc::new(self.meta.schema.remove_virtual_computed_fields())
+        } else {
+            self.meta.schema.clone()
+        }
     }
 
     pub fn options(&self) -> &BTreeMap<String, String> {
@@ -576,6 +582,15 @@ pub struct UpsertTableOptionReply {}
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UpdateTableMetaReply {}
 
+/// Update the metas of several tables atomically: either all of them are updated, or none.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
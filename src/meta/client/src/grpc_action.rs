@@ -26,6 +26,7 @@ use common_meta_types::ListKVReply;
 use common_meta_types::ListKVReq;
 use common_meta_types::MGetKVReply;
 use common_meta_types::MGetKVReq;
+use common_meta_types::ReadConsistency;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVReply;
@@ -54,6 +55,18 @@ pub enum MetaGrpcReq {
     ListKV(ListKVReq),
 }
 
+impl MetaGrpcReq {
+    /// The consistency required if it is a read request.
+    pub fn read_consistency(&self) -> Option<ReadConsistency> {
+        match self {
+            MetaGrpcReq::UpsertKV(_) => None,
+            MetaGrpcReq::GetKV(r) => Some(r.consistency),
+            MetaGrpcReq::MGetKV(r) => Some(r.consistency),
+            MetaGrpcReq::ListKV(r) => Some(r.consistency),
+        }
+    }
+}
+
 impl TryInto<MetaGrpcReq> for Request<RaftRequest> {
     type Error = tonic::Status;
 
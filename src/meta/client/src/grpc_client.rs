@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::fmt::Debug;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
@@ -57,6 +59,7 @@ use common_meta_types::MetaClientError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaHandshakeError;
 use common_meta_types::MetaNetworkError;
+use common_meta_types::ReadConsistency;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_metrics::label_counter_with_val_and_labels;
@@ -249,6 +252,9 @@ pub struct MetaGrpcClient {
     unhealthy_endpoints: Mutex<TtlHashMap<String, ()>>,
     auto_sync_interval: Option<Duration>,
 
+    /// Round-robin counter to spread the reads that do not have to be served by the leader.
+    next_read_endpoint: AtomicUsize,
+
     /// Dedicated runtime to support meta client background tasks.
     ///
     /// In order not to let a blocking operation(such as calling the new PipelinePullingExecutor) in a tokio runtime block meta-client background tasks.
@@ -319,6 +325,7 @@ impl MetaGrpcClient {
             current_endpoint: Arc::new(Mutex::new(None)),
             unhealthy_endpoints: Mutex::new(TtlHashMap::new(Duration::from_secs(120))),
             auto_sync_interval,
+            next_read_endpoint: AtomicUsize::new(0),
             username: username.to_string(),
             password: password.to_string(),
             rt: rt.clone(),
@@ -474,6 +481,27 @@ impl MetaGrpcClient {
     pub async fn make_client(
         &self,
     ) -> Result<MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>, MetaClientError>
+    {
+        self.make_client_from(0).await
+    }
+
+    /// Make a client for a read that can be served by any node.
+    ///
+    /// The healthy endpoints are tried in a round-robin way, to spread the reads across the cluster.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn make_read_client(
+        &self,
+    ) -> Result<MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>, MetaClientError>
+    {
+        let start = self.next_read_endpoint.fetch_add(1, Ordering::Relaxed);
+        self.make_client_from(start).await
+    }
+
+    /// Make a client by trying the healthy endpoints one by one, starting from the `start`-th one.
+    async fn make_client_from(
+        &self,
+        start: usize,
+    ) -> Result<MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>, MetaClientError>
     {
         let mut eps = self.get_endpoints().await;
         debug!("service endpoints: {:?}", eps);
@@ -486,6 +514,11 @@ impl MetaGrpcClient {
             eps.retain(|e| !ues.contains_key(e));
         }
 
+        if !eps.is_empty() {
+            let n = eps.len();
+            eps.rotate_left(start % n);
+        }
+
         for (addr, is_last) in eps.iter().enumerate().map(|(i, a)| (a, i == eps.len() - 1)) {
             let channel = self.make_channel(Some(addr)).await;
             match channel {
@@ -821,7 +854,12 @@ impl MetaGrpcClient {
 
         let req = common_tracing::inject_span_to_tonic_request(req);
 
-        let mut client = self.make_client().await?;
+        let mut client = match read_req.read_consistency() {
+            Some(ReadConsistency::Linearizable) | Some(ReadConsistency::StaleOk) => {
+                self.make_read_client().await?
+            }
+            _ => self.make_client().await?,
+        };
         let result = client.kv_api(req).await;
 
         debug!(reply = debug(&result), "MetaGrpcClient::kv_api reply");
@@ -36,26 +36,18 @@ impl KVApi for MetaGrpcClient {
     }
 
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, KVAppError> {
-        let reply = self
-            .kv_api(GetKVReq {
-                key: key.to_string(),
-            })
-            .await?;
+        let reply = self.kv_api(GetKVReq::new(key)).await?;
         Ok(reply)
     }
 
     async fn mget_kv(&self, keys: &[String]) -> Result<MGetKVReply, KVAppError> {
         let keys = keys.to_vec();
-        let reply = self.kv_api(MGetKVReq { keys }).await?;
+        let reply = self.kv_api(MGetKVReq::new(keys)).await?;
         Ok(reply)
     }
 
     async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, KVAppError> {
-        let reply = self
-            .kv_api(ListKVReq {
-                prefix: prefix.to_string(),
-            })
-            .await?;
+        let reply = self.kv_api(ListKVReq::new(prefix)).await?;
         Ok(reply)
     }
 
@@ -73,26 +65,18 @@ impl KVApi for ClientHandle {
     }
 
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, KVAppError> {
-        let reply = self
-            .request(GetKVReq {
-                key: key.to_string(),
-            })
-            .await?;
+        let reply = self.request(GetKVReq::new(key)).await?;
         Ok(reply)
     }
 
     async fn mget_kv(&self, keys: &[String]) -> Result<MGetKVReply, KVAppError> {
         let keys = keys.to_vec();
-        let reply = self.request(MGetKVReq { keys }).await?;
+        let reply = self.request(MGetKVReq::new(keys)).await?;
         Ok(reply)
     }
 
     async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, KVAppError> {
-        let reply = self
-            .request(ListKVReq {
-                prefix: prefix.to_string(),
-            })
-            .await?;
+        let reply = self.request(ListKVReq::new(prefix)).await?;
         Ok(reply)
     }
 
@@ -65,6 +65,7 @@ mod database_from_to_protobuf_impl;
 mod datetime_from_to_protobuf_impl;
 mod from_to_protobuf;
 mod schema_from_to_protobuf_impl;
+mod sequence_from_to_protobuf_impl;
 mod share_from_to_protobuf_impl;
 mod table_from_to_protobuf_impl;
 mod user_from_to_protobuf_impl;
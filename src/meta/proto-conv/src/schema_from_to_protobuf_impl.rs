@@ -76,7 +76,16 @@ impl FromToProto for ex::TableField {
                 reason: "DataField.data_type can not be None".to_string(),
             })?)?,
         )
-        .with_default_expr(p.default_expr);
+        .with_default_expr(p.default_expr)
+        .with_computed_expr(match p.computed_expr {
+            Some(pb::data_field::ComputedExpr::VirtualExpr(expr)) => {
+                Some(ex::ComputedExpr::Virtual(expr))
+            }
+            Some(pb::data_field::ComputedExpr::StoredExpr(expr)) => {
+                Some(ex::ComputedExpr::Stored(expr))
+            }
+            None => None,
+        });
         Ok(v)
     }
 
@@ -87,6 +96,15 @@ impl FromToProto for ex::TableField {
             name: self.name().clone(),
             default_expr: self.default_expr().cloned(),
             data_type: Some(self.data_type().to_pb()?),
+            computed_expr: match self.computed_expr() {
+                Some(ex::ComputedExpr::Virtual(expr)) => {
+                    Some(pb::data_field::ComputedExpr::VirtualExpr(expr.clone()))
+                }
+                Some(ex::ComputedExpr::Stored(expr)) => {
+                    Some(pb::data_field::ComputedExpr::StoredExpr(expr.clone()))
+                }
+                None => None,
+            },
         };
         Ok(p)
     }
@@ -32,3 +32,5 @@ mod v012_table_meta;
 mod v023_table_meta;
 mod v024_table_meta;
 mod v025_user_stage;
+mod v026_table_meta;
+mod v027_sequence_meta;
@@ -205,6 +205,17 @@ pub(crate) fn new_table_copied_file_lock_v7() -> mt::TableCopiedFileLock {
     mt::TableCopiedFileLock {}
 }
 
+pub(crate) fn new_sequence_meta() -> mt::SequenceMeta {
+    mt::SequenceMeta {
+        sequence_id: 7,
+        start: 1,
+        increment: 2,
+        current: 101,
+        comment: "sequence_comment".to_string(),
+        create_on: Utc.ymd(2014, 11, 28).and_hms(12, 0, 9),
+    }
+}
+
 #[test]
 fn test_pb_from_to() -> anyhow::Result<()> {
     let db = new_db_meta();
@@ -333,5 +344,15 @@ fn test_build_pb_buf() -> anyhow::Result<()> {
         println!("copied_file_lock:{:?}", buf);
     }
 
+    // SequenceMeta
+    {
+        let sequence_meta = new_sequence_meta();
+        let p = sequence_meta.to_pb()?;
+
+        let mut buf = vec![];
+        common_protos::prost::Message::encode(&p, &mut buf)?;
+        println!("sequence_meta:{:?}", buf);
+    }
+
     Ok(())
 }
@@ -0,0 +1,50 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::schema as mt;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v27_sequence_meta() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        8, 1, 16, 2, 24, 101, 34, 16, 115, 101, 113, 117, 101, 110, 99, 101, 95, 99, 111, 109, 109,
+        101, 110, 116, 42, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58,
+        48, 57, 32, 85, 84, 67, 48, 7, 160, 6, 27, 168, 6, 24,
+    ];
+
+    let want = || mt::SequenceMeta {
+        sequence_id: 7,
+        start: 1,
+        increment: 2,
+        current: 101,
+        comment: "sequence_comment".to_string(),
+        create_on: Utc.ymd(2014, 11, 28).and_hms(12, 0, 9),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 27, want())
+}
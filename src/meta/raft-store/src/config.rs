@@ -79,6 +79,13 @@ pub struct RaftConfig {
     /// The maximum number of applied logs to keep before purging
     pub max_applied_log_to_keep: u64,
 
+    /// The max size in bytes of the snapshot data sent in one install-snapshot request.
+    /// A snapshot is sent to a follower or non-voter in chunks of this size.
+    pub snapshot_chunk_size: u64,
+
+    /// The max time in milli seconds a stale-ok read served by a follower or non-voter may lag behind the leader.
+    pub stale_read_max_lag: u64,
+
     /// Single node metasrv. It creates a single node cluster if meta data is not initialized.
     /// Otherwise it opens the previous one.
     /// This is mainly for testing purpose.
@@ -135,6 +142,8 @@ impl Default for RaftConfig {
             heartbeat_interval: 1000,
             install_snapshot_timeout: 4000,
             max_applied_log_to_keep: 1000,
+            snapshot_chunk_size: 4 * 1024 * 1024,
+            stale_read_max_lag: 1000,
             single: false,
             join: vec![],
             leave_via: vec![],
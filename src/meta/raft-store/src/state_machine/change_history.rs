@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use common_meta_types::Change;
+
+/// The max number of changes kept in the change history.
+pub const CHANGE_HISTORY_CAPACITY: usize = 10_000;
+
+/// The kv changes made by the recently applied logs, in the order they are applied.
+///
+/// It lets a watcher resume from a log index without missing any change.
+/// It is kept in memory and bounded, a resume from a log index that is too old,
+/// or from before the state machine is opened or installed from a snapshot, is not served.
+#[derive(Debug)]
+pub struct ChangeHistory {
+    /// The log index and the change made by the log.
+    changes: VecDeque<(u64, Change<Vec<u8>, String>)>,
+
+    /// The changes made by the logs after this index are all in the history.
+    covered_since: u64,
+
+    capacity: usize,
+}
+
+impl ChangeHistory {
+    /// Create an empty history that covers the changes after `last_applied_index`.
+    pub fn new(last_applied_index: u64, capacity: usize) -> Self {
+        ChangeHistory {
+            changes: VecDeque::new(),
+            covered_since: last_applied_index,
+            capacity,
+        }
+    }
+
+    /// Append the changes made by the log at `index`.
+    pub fn append(&mut self, index: u64, changes: &[Change<Vec<u8>, String>]) {
+        for change in changes {
+            self.changes.push_back((index, change.clone()));
+        }
+
+        while self.changes.len() > self.capacity {
+            if let Some((evicted, _)) = self.changes.pop_front() {
+                // Part of the changes of log `evicted` may be lost.
+                self.covered_since = std::cmp::max(self.covered_since, evicted);
+            }
+        }
+    }
+
+    /// Returns the changes made by the logs after `index`,
+    /// or None if some of them are no longer in the history.
+    pub fn since(&self, index: u64) -> Option<Vec<(u64, Change<Vec<u8>, String>)>> {
+        if index < self.covered_since {
+            return None;
+        }
+
+        let changes = self
+            .changes
+            .iter()
+            .filter(|(i, _)| *i > index)
+            .cloned()
+            .collect();
+        Some(changes)
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use change_history::ChangeHistory;
 pub use client_last_resp::ClientLastRespValue;
 pub use expire::ExpireKey;
 pub use expire::ExpireValue;
@@ -21,11 +22,15 @@ pub use sm::SerializableSnapshot;
 pub use sm::SnapshotKeyValue;
 pub use sm::StateMachine;
 pub use sm::StateMachineSubscriber;
+pub use snapshot::read_snapshot_chunks;
 pub use snapshot::Snapshot;
+pub use snapshot::SnapshotStat;
+pub use snapshot::SnapshotWriter;
 pub use snapshot_id::MetaSnapshotId;
 pub use state_machine_meta::StateMachineMetaKey;
 pub use state_machine_meta::StateMachineMetaValue;
 
+pub mod change_history;
 pub mod client_last_resp;
 mod expire;
 pub mod log_meta;
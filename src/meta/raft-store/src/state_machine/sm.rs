@@ -15,6 +15,8 @@
 use std::collections::BTreeMap;
 use std::convert::TryInto;
 use std::fmt::Debug;
+use std::fs;
+use std::sync::Mutex;
 use std::time::Duration;
 use std::time::Instant;
 use std::time::SystemTime;
@@ -22,10 +24,12 @@ use std::time::UNIX_EPOCH;
 
 use common_exception::WithContext;
 use common_meta_sled_store::get_sled_db;
+use common_meta_sled_store::get_sled_db_path;
 use common_meta_sled_store::openraft;
 use common_meta_sled_store::openraft::EffectiveMembership;
 use common_meta_sled_store::openraft::MessageSummary;
 use common_meta_sled_store::AsKeySpace;
+use common_meta_sled_store::SledFrozenView;
 use common_meta_sled_store::SledKeySpace;
 use common_meta_sled_store::SledTree;
 use common_meta_sled_store::Store;
@@ -78,10 +82,15 @@ use crate::key_spaces::GenericKV;
 use crate::key_spaces::Nodes;
 use crate::key_spaces::Sequences;
 use crate::key_spaces::StateMachineMeta;
+use crate::state_machine::change_history::CHANGE_HISTORY_CAPACITY;
+use crate::state_machine::snapshot::snapshot_io_error;
+use crate::state_machine::ChangeHistory;
 use crate::state_machine::ClientLastRespValue;
 use crate::state_machine::ExpireKey;
 use crate::state_machine::ExpireValue;
 use crate::state_machine::MetaSnapshotId;
+use crate::state_machine::SnapshotStat;
+use crate::state_machine::SnapshotWriter;
 use crate::state_machine::StateMachineMetaKey;
 use crate::state_machine::StateMachineMetaKey::Initialized;
 use crate::state_machine::StateMachineMetaKey::LastApplied;
@@ -95,7 +104,8 @@ const TREE_STATE_MACHINE: &str = "state_machine";
 
 /// StateMachine subscriber trait
 pub trait StateMachineSubscriber: Debug + Sync + Send {
-    fn kv_changed(&self, change: Change<Vec<u8>, String>);
+    /// Called with every kv change made by the log at `log_index`.
+    fn kv_changed(&self, log_index: u64, change: Change<Vec<u8>, String>);
 }
 
 /// The state machine of the `MemStore`.
@@ -110,13 +120,16 @@ pub struct StateMachine {
 
     /// subscriber of statemachine data
     pub subscriber: Option<Box<dyn StateMachineSubscriber>>,
+
+    /// The kv changes made by the recently applied logs, for a watcher to resume from.
+    pub change_history: Mutex<ChangeHistory>,
 }
 
 /// A key-value pair in a snapshot is a vec of two `Vec<u8>`.
 pub type SnapshotKeyValue = Vec<Vec<u8>>;
 type DeleteByPrefixKeyMap = BTreeMap<TxnDeleteByPrefixRequest, Vec<(String, SeqV)>>;
 
-/// Snapshot data for serialization and for transport.
+/// A chunk of snapshot data for serialization and for transport.
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct SerializableSnapshot {
     /// A list of kv pairs.
@@ -164,8 +177,11 @@ impl StateMachine {
         let sm = StateMachine {
             sm_tree,
             subscriber: None,
+            change_history: Mutex::new(ChangeHistory::new(0, CHANGE_HISTORY_CAPACITY)),
         };
 
+        sm.reset_change_history()?;
+
         let inited = {
             let sm_meta = sm.sm_meta();
             sm_meta.get(&Initialized)?
@@ -186,17 +202,57 @@ impl StateMachine {
         self.subscriber = Some(subscriber);
     }
 
-    /// Create a snapshot.
+    /// The dir to store the snapshot files of the state machine.
+    ///
+    /// It is in the dir of the global sled db, and is named after the tree name of the state machine,
+    /// so that multiple instances in one process do not share it.
+    pub fn snapshot_dir(config: &RaftConfig) -> String {
+        format!(
+            "{}/snapshot/{}",
+            get_sled_db_path(),
+            config.tree_name(TREE_STATE_MACHINE)
+        )
+    }
+
+    /// Freeze a consistent view of the state machine at the last applied log,
+    /// the logs applied after it do not change the view.
+    ///
+    /// The caller must prevent any write to the state machine while freezing, e.g., by holding the write lock,
+    /// the lock can be released once it returns.
+    pub fn freeze(&self) -> Result<(Option<LogId>, SledFrozenView), MetaStorageError> {
+        let last_applied = self.get_last_applied()?;
+        Ok((last_applied, self.sm_tree.freeze()))
+    }
+
+    /// Create a snapshot and write it into a file in `dir`.
+    ///
+    /// The caller must prevent any write to the state machine during building, see [`Self::write_snapshot`]
+    /// to build it from a frozen view without blocking the writes.
     ///
     /// Returns:
-    /// - all key values in state machine;
+    /// - the stat of the snapshot file;
     /// - the last applied log id
     /// - and a snapshot id that uniquely identifies this snapshot.
     pub fn build_snapshot(
         &self,
-    ) -> Result<(SerializableSnapshot, Option<LogId>, MetaSnapshotId), MetaStorageError> {
-        let last_applied = self.get_last_applied()?;
+        dir: &str,
+    ) -> Result<(SnapshotStat, Option<LogId>, MetaSnapshotId), MetaStorageError> {
+        let (last_applied, view) = self.freeze()?;
+        let (stat, snapshot_id) = Self::write_snapshot(view, last_applied, dir)?;
+        Ok((stat, last_applied, snapshot_id))
+    }
 
+    /// Write a snapshot of a frozen view of the state machine into a file in `dir`.
+    ///
+    /// The key-values are streamed into the file in chunks, thus the memory it takes does not grow
+    /// with the size of the state machine.
+    ///
+    /// Returns the stat of the snapshot file and a snapshot id that uniquely identifies this snapshot.
+    pub fn write_snapshot(
+        view: SledFrozenView,
+        last_applied: Option<LogId>,
+        dir: &str,
+    ) -> Result<(SnapshotStat, MetaSnapshotId), MetaStorageError> {
         let snapshot_idx = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
@@ -204,16 +260,17 @@ impl StateMachine {
 
         let snapshot_id = MetaSnapshotId::new(last_applied, snapshot_idx);
 
-        let view = self.sm_tree.tree.iter();
+        fs::create_dir_all(dir).map_err(|e| snapshot_io_error(e, "create dir of", dir))?;
+        let path = format!("{}/{}.snap", dir, snapshot_id);
+        let mut writer = SnapshotWriter::create(&path)?;
 
-        let mut kvs = Vec::new();
         for rkv in view {
-            let (k, v) = rkv.context(|| "taking snapshot")?;
-            kvs.push(vec![k.to_vec(), v.to_vec()]);
+            let (k, v) = rkv?;
+            writer.write_kv(vec![k.to_vec(), v.to_vec()])?;
         }
-        let snap = SerializableSnapshot { kvs };
+        let stat = writer.commit()?;
 
-        Ok((snap, last_applied, snapshot_id))
+        Ok((stat, snapshot_id))
     }
 
     fn scan_prefix_if_needed(
@@ -339,10 +396,17 @@ impl StateMachine {
             None => AppliedState::None,
         };
 
+        if !changes.is_empty() {
+            self.change_history
+                .lock()
+                .unwrap()
+                .append(log_id.index, &changes);
+        }
+
         // Send queued change events to subscriber
         if let Some(subscriber) = &self.subscriber {
             for event in changes {
-                subscriber.kv_changed(event);
+                subscriber.kv_changed(log_id.index, event);
             }
         }
 
@@ -1079,6 +1143,49 @@ impl StateMachine {
         self.sm_tree.key_space()
     }
 
+    /// Drop the change history, the changes applied from now on are recorded.
+    ///
+    /// It must be called when the data is replaced without applying logs, e.g., a snapshot is installed.
+    pub fn reset_change_history(&self) -> Result<(), MetaStorageError> {
+        let last_applied_index = self
+            .get_last_applied()?
+            .map(|x| x.index)
+            .unwrap_or_default();
+        *self.change_history.lock().unwrap() =
+            ChangeHistory::new(last_applied_index, CHANGE_HISTORY_CAPACITY);
+        Ok(())
+    }
+
+    /// Returns the unexpired key-values in range `[key, key_end)`, or only `key` if `key_end` is None.
+    pub fn range_kvs(
+        &self,
+        key: &str,
+        key_end: Option<&str>,
+    ) -> Result<Vec<(String, SeqV<Vec<u8>>)>, MetaStorageError> {
+        let kvs = self.kvs();
+
+        let kv_pairs = match key_end {
+            None => {
+                let v = kvs.get(&key.to_string())?;
+                v.map(|v| (key.to_string(), v)).into_iter().collect()
+            }
+            Some(key_end) => {
+                let mut res = vec![];
+                for item in kvs.range(key.to_string()..key_end.to_string())? {
+                    res.push(item?.kv()?);
+                }
+                res
+            }
+        };
+
+        let local_now_ms = SeqV::<()>::now_ms();
+        let res = kv_pairs
+            .into_iter()
+            .filter_map(|(k, v)| Self::expire_seq_v(Some(v), local_now_ms).1.map(|v| (k, v)))
+            .collect();
+        Ok(res)
+    }
+
     /// storage of auto-incremental number.
     pub fn sequences(&self) -> AsKeySpace<Sequences> {
         self.sm_tree.key_space()
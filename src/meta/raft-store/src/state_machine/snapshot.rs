@@ -12,16 +12,147 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs;
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufWriter;
+use std::io::Write;
+
 use common_meta_sled_store::openraft;
+use common_meta_stoerr::MetaStorageError;
+use common_meta_types::anyerror::AnyError;
 use openraft::SnapshotMeta;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::state_machine::SerializableSnapshot;
+use crate::state_machine::SnapshotKeyValue;
+
+/// The max number of key-values in one line of a snapshot file.
+///
+/// It bounds the memory used to build, send or install a snapshot,
+/// no matter how many keys there are in the state machine.
+pub const SNAPSHOT_KVS_PER_LINE: usize = 1024;
+
 /// The application snapshot type which the `MetaStore` works with.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Snapshot {
     pub meta: SnapshotMeta,
 
-    /// The data of the state machine at the time of this snapshot.
-    pub data: Vec<u8>,
+    /// The path of the file that stores the data of the state machine at the time of this snapshot.
+    pub path: String,
+
+    /// The size in bytes of the snapshot file.
+    pub size: u64,
+}
+
+/// The stat of a snapshot file that is built.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SnapshotStat {
+    pub path: String,
+
+    /// The number of key-values in the snapshot.
+    pub kvs: u64,
+
+    /// The size in bytes of the snapshot file.
+    pub size: u64,
+}
+
+/// Writes the key-values of a snapshot into a file.
+///
+/// Every line of the file is a json encoded `SerializableSnapshot` with at most
+/// `SNAPSHOT_KVS_PER_LINE` key-values. A snapshot written by an older version,
+/// which is a single json encoded `SerializableSnapshot`, is just a one-line file of this format.
+///
+/// The data is written into a temp file first, and is moved to `path` when committed,
+/// thus a file at `path` is always a complete snapshot.
+pub struct SnapshotWriter {
+    path: String,
+    temp_path: String,
+    writer: BufWriter<File>,
+    chunk: Vec<SnapshotKeyValue>,
+    kvs: u64,
+    size: u64,
+}
+
+impl SnapshotWriter {
+    pub fn create(path: &str) -> Result<Self, MetaStorageError> {
+        let temp_path = format!("{}.tmp", path);
+        let f = File::create(&temp_path).map_err(|e| snapshot_io_error(e, "create", &temp_path))?;
+
+        Ok(SnapshotWriter {
+            path: path.to_string(),
+            temp_path,
+            writer: BufWriter::new(f),
+            chunk: Vec::with_capacity(SNAPSHOT_KVS_PER_LINE),
+            kvs: 0,
+            size: 0,
+        })
+    }
+
+    pub fn write_kv(&mut self, kv: SnapshotKeyValue) -> Result<(), MetaStorageError> {
+        self.chunk.push(kv);
+        self.kvs += 1;
+
+        if self.chunk.len() >= SNAPSHOT_KVS_PER_LINE {
+            self.flush_chunk()?;
+        }
+        Ok(())
+    }
+
+    fn flush_chunk(&mut self) -> Result<(), MetaStorageError> {
+        if self.chunk.is_empty() {
+            return Ok(());
+        }
+
+        let snap = SerializableSnapshot {
+            kvs: std::mem::take(&mut self.chunk),
+        };
+        let mut line = serde_json::to_vec(&snap)?;
+        line.push(b'\n');
+
+        self.writer
+            .write_all(&line)
+            .map_err(|e| snapshot_io_error(e, "write", &self.temp_path))?;
+        self.size += line.len() as u64;
+        Ok(())
+    }
+
+    /// Flushes all the key-values to disk and moves the snapshot file to its final path.
+    pub fn commit(mut self) -> Result<SnapshotStat, MetaStorageError> {
+        self.flush_chunk()?;
+
+        let f = self
+            .writer
+            .into_inner()
+            .map_err(|e| snapshot_io_error(e.into_error(), "flush", &self.temp_path))?;
+        f.sync_all()
+            .map_err(|e| snapshot_io_error(e, "sync", &self.temp_path))?;
+
+        fs::rename(&self.temp_path, &self.path)
+            .map_err(|e| snapshot_io_error(e, "rename", &self.temp_path))?;
+
+        Ok(SnapshotStat {
+            path: self.path,
+            kvs: self.kvs,
+            size: self.size,
+        })
+    }
+}
+
+/// Reads the chunks of key-values from the data of a snapshot, one line at a time.
+pub fn read_snapshot_chunks<R: BufRead>(
+    data: R,
+) -> impl Iterator<Item = Result<SerializableSnapshot, MetaStorageError>> {
+    data.lines().filter_map(|line| match line {
+        Err(e) => Some(Err(snapshot_io_error(e, "read", "snapshot data"))),
+        Ok(line) if line.trim().is_empty() => None,
+        Ok(line) => Some(serde_json::from_str(&line).map_err(MetaStorageError::from)),
+    })
+}
+
+pub fn snapshot_io_error(e: std::io::Error, verb: &str, path: &str) -> MetaStorageError {
+    MetaStorageError::SnapshotError(
+        AnyError::new(&e).add_context(|| format!("{} snapshot file: {}", verb, path)),
+    )
 }
@@ -0,0 +1,80 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_raft_store::state_machine::ChangeHistory;
+use common_meta_types::Change;
+use common_meta_types::SeqV;
+
+fn add(key: &str, seq: u64) -> Change<Vec<u8>, String> {
+    Change::new_with_id(key.to_string(), None, Some(SeqV::new(seq, b"v".to_vec())))
+}
+
+fn keys(changes: Option<Vec<(u64, Change<Vec<u8>, String>)>>) -> Option<Vec<(u64, String)>> {
+    changes.map(|x| {
+        x.into_iter()
+            .map(|(i, c)| (i, c.ident.unwrap()))
+            .collect::<Vec<_>>()
+    })
+}
+
+#[test]
+fn test_change_history_since() -> anyhow::Result<()> {
+    let mut h = ChangeHistory::new(3, 10);
+
+    assert_eq!(Some(vec![]), keys(h.since(3)));
+    assert_eq!(None, keys(h.since(2)), "before the history starts");
+
+    h.append(4, &[add("a", 1), add("b", 2)]);
+    h.append(6, &[add("c", 3)]);
+
+    assert_eq!(
+        Some(vec![(4, s("a")), (4, s("b")), (6, s("c"))]),
+        keys(h.since(3))
+    );
+    assert_eq!(Some(vec![(6, s("c"))]), keys(h.since(4)));
+    assert_eq!(Some(vec![(6, s("c"))]), keys(h.since(5)));
+    assert_eq!(Some(vec![]), keys(h.since(6)));
+    assert_eq!(Some(vec![]), keys(h.since(100)));
+
+    Ok(())
+}
+
+#[test]
+fn test_change_history_evict() -> anyhow::Result<()> {
+    let mut h = ChangeHistory::new(0, 3);
+
+    h.append(1, &[add("a", 1), add("b", 2)]);
+    h.append(2, &[add("c", 3), add("d", 4)]);
+
+    // Log 1 is partially evicted, resuming from before it is refused.
+    assert_eq!(None, keys(h.since(0)));
+    assert_eq!(
+        Some(vec![(1, s("b")), (2, s("c")), (2, s("d"))]),
+        keys(h.since(1))
+    );
+    assert_eq!(Some(vec![]), keys(h.since(2)));
+
+    h.append(3, &[add("e", 5), add("f", 6), add("g", 7)]);
+    assert_eq!(None, keys(h.since(1)));
+    assert_eq!(
+        Some(vec![(3, s("e")), (3, s("f")), (3, s("g"))]),
+        keys(h.since(2))
+    );
+
+    Ok(())
+}
+
+fn s(x: impl ToString) -> String {
+    x.to_string()
+}
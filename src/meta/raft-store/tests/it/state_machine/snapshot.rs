@@ -12,9 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs::File;
+use std::io::BufReader;
+
 use common_base::base::tokio;
+use common_meta_raft_store::state_machine::read_snapshot_chunks;
+use common_meta_raft_store::state_machine::snapshot::SNAPSHOT_KVS_PER_LINE;
 use common_meta_raft_store::state_machine::testing::pretty_snapshot;
 use common_meta_raft_store::state_machine::testing::snapshot_logs;
+use common_meta_raft_store::state_machine::SnapshotWriter;
 use common_meta_raft_store::state_machine::StateMachine;
 use common_meta_sled_store::openraft::LogId;
 
@@ -42,14 +48,57 @@ async fn test_state_machine_snapshot() -> anyhow::Result<()> {
     // take snapshot and check it
 
     {
-        let (snap, last_applied, id) = sm.build_snapshot()?;
+        let dir = StateMachine::snapshot_dir(&tc.raft_config);
+        let (stat, last_applied, id) = sm.build_snapshot(&dir)?;
 
         assert_eq!(Some(LogId { term: 1, index: 9 }), last_applied);
         assert!(id.to_string().starts_with(&format!("{}-{}-", 1, 9)));
+        assert_eq!(format!("{}/{}.snap", dir, id), stat.path);
+
+        let f = File::open(&stat.path)?;
+        assert_eq!(f.metadata()?.len(), stat.size);
 
-        let res = pretty_snapshot(&snap.kvs);
+        let mut kvs = vec![];
+        for chunk in read_snapshot_chunks(BufReader::new(f)) {
+            kvs.extend(chunk?.kvs);
+        }
+        assert_eq!(stat.kvs, kvs.len() as u64);
+
+        let res = pretty_snapshot(&kvs);
         assert_eq!(want, res);
     }
 
     Ok(())
 }
+
+#[test]
+fn test_snapshot_writer_chunks() -> anyhow::Result<()> {
+    // - Write more key-values than a line holds.
+    // - Read them back chunk by chunk.
+
+    let dir = tempfile::tempdir()?;
+    let path = format!("{}/foo.snap", dir.path().to_str().unwrap());
+
+    let n = SNAPSHOT_KVS_PER_LINE * 2 + 1;
+    let mut writer = SnapshotWriter::create(&path)?;
+    for i in 0..n {
+        writer.write_kv(vec![format!("k{:05}", i).into_bytes(), vec![b'v']])?;
+    }
+    let stat = writer.commit()?;
+
+    assert_eq!(n as u64, stat.kvs);
+    assert!(!std::path::Path::new(&format!("{}.tmp", path)).exists());
+
+    let f = File::open(&stat.path)?;
+    assert_eq!(f.metadata()?.len(), stat.size);
+
+    let chunks = read_snapshot_chunks(BufReader::new(f)).collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(
+        vec![SNAPSHOT_KVS_PER_LINE, SNAPSHOT_KVS_PER_LINE, 1],
+        chunks.iter().map(|c| c.kvs.len()).collect::<Vec<_>>()
+    );
+    assert_eq!(b"k00000".to_vec(), chunks[0].kvs[0][0]);
+    assert_eq!(format!("k{:05}", n - 1).into_bytes(), chunks[2].kvs[0][0]);
+
+    Ok(())
+}
@@ -24,6 +24,7 @@ use common_grpc::GrpcToken;
 use common_meta_api::KVApi;
 use common_meta_client::MetaGrpcReq;
 use common_meta_types::protobuf::meta_service_server::MetaService;
+use common_meta_types::protobuf::BackupRequest;
 use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
@@ -35,6 +36,10 @@ use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::GetKVReply;
+use common_meta_types::KVAppError;
+use common_meta_types::ListKVReply;
+use common_meta_types::MGetKVReply;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_metrics::counter::Count;
@@ -189,15 +194,18 @@ impl MetaService for MetaServiceImpl {
                 RaftReply::from(res)
             }
             MetaGrpcReq::GetKV(a) => {
-                let res = m.get_kv(&a.key).await;
+                let res: Result<GetKVReply, KVAppError> =
+                    m.consistent_read(a).await.map_err(KVAppError::from);
                 RaftReply::from(res)
             }
             MetaGrpcReq::MGetKV(a) => {
-                let res = m.mget_kv(&a.keys).await;
+                let res: Result<MGetKVReply, KVAppError> =
+                    m.consistent_read(a).await.map_err(KVAppError::from);
                 RaftReply::from(res)
             }
             MetaGrpcReq::ListKV(a) => {
-                let res = m.prefix_list_kv(&a.prefix).await;
+                let res: Result<ListKVReply, KVAppError> =
+                    m.consistent_read(a).await.map_err(KVAppError::from);
                 RaftReply::from(res)
             }
         };
@@ -230,6 +238,40 @@ impl MetaService for MetaServiceImpl {
         Ok(Response::new(Box::pin(s)))
     }
 
+    type BackupStream =
+        Pin<Box<dyn Stream<Item = Result<ExportedChunk, tonic::Status>> + Send + Sync + 'static>>;
+
+    // Back up the state machine, or the raft logs since the last backup.
+    async fn backup(
+        &self,
+        request: Request<BackupRequest>,
+    ) -> Result<Response<Self::BackupStream>, Status> {
+        let _guard = RequestInFlight::guard();
+
+        self.check_token(request.metadata())?;
+
+        let logs_since = request.into_inner().logs_since;
+        info!("Received BackupRequest: logs_since: {:?}", logs_since);
+
+        let meta_node = &self.meta_node;
+        let res = meta_node.sto.backup(logs_since).await?;
+
+        let rx = res.ok_or_else(|| {
+            Status::failed_precondition(format!(
+                "raft logs since {:?} are purged, a full backup is required",
+                logs_since
+            ))
+        })?;
+
+        let stream = tokio_stream::wrappers::ReceiverStream::new(rx);
+        let s = stream.map(|res| {
+            res.map(|strings| ExportedChunk { data: strings })
+                .map_err(|e| Status::internal(e.to_string()))
+        });
+
+        Ok(Response::new(Box::pin(s)))
+    }
+
     type WatchStream =
         Pin<Box<dyn Stream<Item = Result<WatchResponse, tonic::Status>> + Send + Sync + 'static>>;
 
@@ -242,18 +284,18 @@ impl MetaService for MetaServiceImpl {
 
         let mn = &self.meta_node;
 
-        let add_res = mn.add_watcher(request.into_inner(), tx).await;
+        let (watcher, initial) = mn.add_watcher(request.into_inner(), tx).await?;
 
-        match add_res {
-            Ok(watcher) => {
-                let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
-                Ok(Response::new(Box::pin(stream) as Self::WatchStream))
-            }
-            Err(e) => {
-                // TODO: test error return.
-                Err(Status::invalid_argument(e))
-            }
+        for resp in initial.iter() {
+            network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
         }
+
+        // The initial events go first, then the changes.
+        let initial = futures::stream::iter(initial.into_iter().map(Ok));
+        let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
+        Ok(Response::new(
+            Box::pin(initial.chain(stream)) as Self::WatchStream
+        ))
     }
 
     async fn transaction(
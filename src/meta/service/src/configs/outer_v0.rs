@@ -266,6 +266,8 @@ pub struct ConfigViaEnv {
     pub kvsrv_heartbeat_interval: u64,
     pub kvsrv_install_snapshot_timeout: u64,
     pub raft_max_applied_log_to_keep: u64,
+    pub raft_snapshot_chunk_size: u64,
+    pub raft_stale_read_max_lag: u64,
     pub kvsrv_single: bool,
     pub metasrv_join: Vec<String>,
     pub kvsrv_id: u64,
@@ -308,6 +310,8 @@ impl From<Config> for ConfigViaEnv {
             kvsrv_heartbeat_interval: cfg.raft_config.heartbeat_interval,
             kvsrv_install_snapshot_timeout: cfg.raft_config.install_snapshot_timeout,
             raft_max_applied_log_to_keep: cfg.raft_config.max_applied_log_to_keep,
+            raft_snapshot_chunk_size: cfg.raft_config.snapshot_chunk_size,
+            raft_stale_read_max_lag: cfg.raft_config.stale_read_max_lag,
             kvsrv_single: cfg.raft_config.single,
             metasrv_join: cfg.raft_config.join,
             kvsrv_id: cfg.raft_config.id,
@@ -332,6 +336,8 @@ impl Into<Config> for ConfigViaEnv {
             heartbeat_interval: self.kvsrv_heartbeat_interval,
             install_snapshot_timeout: self.kvsrv_install_snapshot_timeout,
             max_applied_log_to_keep: self.raft_max_applied_log_to_keep,
+            snapshot_chunk_size: self.raft_snapshot_chunk_size,
+            stale_read_max_lag: self.raft_stale_read_max_lag,
             single: self.kvsrv_single,
             join: self.metasrv_join,
             // Do not allow to leave via environment variable
@@ -433,6 +439,15 @@ pub struct RaftConfig {
     #[clap(long, default_value = "1000")]
     pub max_applied_log_to_keep: u64,
 
+    /// The max size in bytes of the snapshot data sent in one install-snapshot request.
+    /// A snapshot is sent to a follower or non-voter in chunks of this size.
+    #[clap(long, default_value = "4194304")]
+    pub snapshot_chunk_size: u64,
+
+    /// The max time in milli seconds a stale-ok read served by a follower or non-voter may lag behind the leader.
+    #[clap(long, default_value = "1000")]
+    pub stale_read_max_lag: u64,
+
     /// Single node metasrv. It creates a single node cluster if meta data is not initialized.
     /// Otherwise it opens the previous one.
     /// This is mainly for testing purpose.
@@ -490,6 +505,8 @@ impl From<RaftConfig> for InnerRaftConfig {
             heartbeat_interval: x.heartbeat_interval,
             install_snapshot_timeout: x.install_snapshot_timeout,
             max_applied_log_to_keep: x.max_applied_log_to_keep,
+            snapshot_chunk_size: x.snapshot_chunk_size,
+            stale_read_max_lag: x.stale_read_max_lag,
             single: x.single,
             join: x.join,
             leave_via: x.leave_via,
@@ -514,6 +531,8 @@ impl From<InnerRaftConfig> for RaftConfig {
             heartbeat_interval: inner.heartbeat_interval,
             install_snapshot_timeout: inner.install_snapshot_timeout,
             max_applied_log_to_keep: inner.max_applied_log_to_keep,
+            snapshot_chunk_size: inner.snapshot_chunk_size,
+            stale_read_max_lag: inner.stale_read_max_lag,
             single: inner.single,
             join: inner.join,
             leave_via: inner.leave_via,
@@ -547,6 +566,7 @@ impl Into<InnerLogConfig> for LogConfig {
         InnerLogConfig {
             file: self.file.into(),
             stderr: self.stderr.into(),
+            otlp: Default::default(),
         }
     }
 }
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use common_meta_api::KVApi;
+use std::time::Duration;
+
+use common_meta_sled_store::openraft::error::CheckIsLeaderError;
 use common_meta_sled_store::openraft::error::RemoveLearnerError;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
@@ -36,6 +38,9 @@ use crate::meta_service::LeaveRequest;
 use crate::meta_service::MetaNode;
 use crate::metrics::ProposalPending;
 
+/// The max time to wait for a new leader to commit a log of its current term before serving a read index.
+const READ_INDEX_COMMIT_TIMEOUT_MS: u64 = 5_000;
+
 /// The container of APIs of a metasrv leader in a metasrv cluster.
 ///
 /// A meta leader does not imply it is actually the leader granted by the cluster.
@@ -72,29 +77,15 @@ impl<'a> MetaLeader<'a> {
                 Ok(ForwardResponse::AppliedState(res))
             }
 
-            ForwardRequestBody::GetKV(req) => {
-                let sm = self.meta_node.get_state_machine().await;
-                let res = sm
-                    .get_kv(&req.key)
-                    .await
-                    .map_err(|meta_err| MetaDataReadError::new("get_kv", "", &meta_err))?;
-                Ok(ForwardResponse::GetKV(res))
-            }
-            ForwardRequestBody::MGetKV(req) => {
-                let sm = self.meta_node.get_state_machine().await;
-                let res = sm
-                    .mget_kv(&req.keys)
-                    .await
-                    .map_err(|meta_err| MetaDataReadError::new("mget_kv", "", &meta_err))?;
-                Ok(ForwardResponse::MGetKV(res))
+            ForwardRequestBody::GetKV(_)
+            | ForwardRequestBody::MGetKV(_)
+            | ForwardRequestBody::ListKV(_) => {
+                let res = self.meta_node.read_local(req.body).await?;
+                Ok(res)
             }
-            ForwardRequestBody::ListKV(req) => {
-                let sm = self.meta_node.get_state_machine().await;
-                let res = sm
-                    .prefix_list_kv(&req.prefix)
-                    .await
-                    .map_err(|meta_err| MetaDataReadError::new("list_kv", "", &meta_err))?;
-                Ok(ForwardResponse::ListKV(res))
+            ForwardRequestBody::ReadIndex => {
+                let index = self.read_index().await?;
+                Ok(ForwardResponse::ReadIndex(index))
             }
         }
     }
@@ -195,6 +186,43 @@ impl<'a> MetaLeader<'a> {
         Ok(())
     }
 
+    /// Returns the read index for a linearizable read.
+    ///
+    /// Following the read-index rule, the leader must have committed an entry of its current term,
+    /// i.e., the blank log it appends when elected, before it knows the commit index.
+    /// openraft does not expose the commit index, the last log index of the leader is used instead:
+    /// it is an upper bound of the commit index and every entry up to it will be committed,
+    /// unless the leadership is lost, which is checked afterwards.
+    ///
+    /// The leadership is confirmed by a quorum after reading the index,
+    /// thus every write acknowledged before this call is included.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn read_index(&self) -> Result<u64, MetaOperationError> {
+        let metrics = self
+            .meta_node
+            .raft
+            .wait(Some(Duration::from_millis(READ_INDEX_COMMIT_TIMEOUT_MS)))
+            .metrics(
+                |m| m.last_applied.unwrap_or_default().term == m.current_term,
+                "commit a log of the current term",
+            )
+            .await
+            .map_err(|e| {
+                MetaDataReadError::new("read_index", "commit a log of the current term", &e)
+            })?;
+
+        let read_index = metrics.last_log_index.unwrap_or_default();
+
+        self.meta_node.raft.is_leader().await.map_err(|e| match e {
+            CheckIsLeaderError::ForwardToLeader(to_leader) => {
+                MetaOperationError::ForwardToLeader(to_leader)
+            }
+            _ => MetaDataReadError::new("read_index", "confirm leadership", &e).into(),
+        })?;
+
+        Ok(read_index)
+    }
+
     /// Write a log through local raft node and return the states before and after applying the log.
     ///
     /// If the raft node is not a leader, it returns MetaRaftError::ForwardToLeader.
@@ -63,33 +63,21 @@ impl KVApi for MetaNode {
 
     #[tracing::instrument(level = "debug", skip(self))]
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, KVAppError> {
-        let res = self
-            .consistent_read(GetKVReq {
-                key: key.to_string(),
-            })
-            .await?;
+        let res = self.consistent_read(GetKVReq::new(key)).await?;
 
         Ok(res)
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
     async fn mget_kv(&self, keys: &[String]) -> Result<MGetKVReply, KVAppError> {
-        let res = self
-            .consistent_read(MGetKVReq {
-                keys: keys.to_vec(),
-            })
-            .await?;
+        let res = self.consistent_read(MGetKVReq::new(keys.to_vec())).await?;
 
         Ok(res)
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
     async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, KVAppError> {
-        let res = self
-            .consistent_read(ListKVReq {
-                prefix: prefix.to_string(),
-            })
-            .await?;
+        let res = self.consistent_read(ListKVReq::new(prefix)).await?;
 
         Ok(res)
     }
@@ -29,6 +29,7 @@ use common_base::base::tokio::task::JoinHandle;
 use common_base::base::tokio::time::Instant;
 use common_grpc::ConnectionFactory;
 use common_grpc::DNSResolver;
+use common_meta_api::KVApi;
 use common_meta_raft_store::config::RaftConfig;
 use common_meta_raft_store::key_spaces::GenericKV;
 use common_meta_raft_store::state_machine::StateMachine;
@@ -41,6 +42,7 @@ use common_meta_stoerr::MetaStorageError;
 use common_meta_types::protobuf::raft_service_client::RaftServiceClient;
 use common_meta_types::protobuf::raft_service_server::RaftServiceServer;
 use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
 use common_meta_types::ConnectionError;
@@ -54,6 +56,7 @@ use common_meta_types::LeaveRequest;
 use common_meta_types::LogEntry;
 use common_meta_types::MetaAPIError;
 use common_meta_types::MetaDataError;
+use common_meta_types::MetaDataReadError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaManagementError;
 use common_meta_types::MetaNetworkError;
@@ -61,6 +64,7 @@ use common_meta_types::MetaOperationError;
 use common_meta_types::MetaStartupError;
 use common_meta_types::Node;
 use common_meta_types::NodeId;
+use common_meta_types::ReadConsistency;
 use futures::channel::oneshot;
 use itertools::Itertools;
 use openraft::Config;
@@ -69,6 +73,7 @@ use openraft::Raft;
 use openraft::RaftMetrics;
 use openraft::SnapshotPolicy;
 use openraft::State;
+use tonic::Status;
 use tracing::debug;
 use tracing::error;
 use tracing::info;
@@ -84,6 +89,7 @@ use crate::metrics::server_metrics;
 use crate::network::Network;
 use crate::store::RaftStore;
 use crate::store::RaftStoreBare;
+use crate::watcher::build_watch_response;
 use crate::watcher::DispatcherSender;
 use crate::watcher::EventDispatcher;
 use crate::watcher::EventDispatcherHandle;
@@ -121,6 +127,9 @@ pub struct MetaNodeStatus {
     pub last_seq: u64,
 }
 
+/// The max time to wait for the local state machine to apply a read index.
+const READ_INDEX_APPLY_TIMEOUT_MS: u64 = 5_000;
+
 // MetaRaft is a impl of the generic Raft handling meta data R/W.
 pub type MetaRaft = Raft<LogEntry, AppliedState, Network, RaftStore>;
 
@@ -133,6 +142,14 @@ pub struct MetaNode {
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<Result<(), AnyError>>>>,
     pub joined_tasks: AtomicI32,
+
+    /// The max time a `StaleOk` read may lag behind the leader.
+    pub stale_read_max_lag: Duration,
+
+    /// When the latest read index, that the local state machine has applied, was requested.
+    ///
+    /// The local state machine contains every write acknowledged before it.
+    pub read_index_applied_at: std::sync::Mutex<Option<Instant>>,
 }
 
 impl Opened for MetaNode {
@@ -147,6 +164,7 @@ pub struct MetaNodeBuilder {
     sto: Option<Arc<RaftStore>>,
     monitor_metrics: bool,
     endpoint: Option<Endpoint>,
+    stale_read_max_lag: Duration,
 }
 
 impl MetaNodeBuilder {
@@ -186,6 +204,8 @@ impl MetaNodeBuilder {
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
             joined_tasks: AtomicI32::new(1),
+            stale_read_max_lag: self.stale_read_max_lag,
+            read_index_applied_at: std::sync::Mutex::new(None),
         });
 
         if self.monitor_metrics {
@@ -246,6 +266,7 @@ impl MetaNode {
             sto: None,
             monitor_metrics: true,
             endpoint: None,
+            stale_read_max_lag: Duration::from_millis(config.stale_read_max_lag),
         }
     }
 
@@ -260,6 +281,7 @@ impl MetaNode {
             install_snapshot_timeout: config.install_snapshot_timeout,
             snapshot_policy: SnapshotPolicy::LogsSinceLast(config.snapshot_logs_since_last),
             max_applied_log_to_keep: config.max_applied_log_to_keep,
+            snapshot_max_chunk_size: config.snapshot_chunk_size,
             ..Default::default()
         }
         .validate()
@@ -881,12 +903,19 @@ impl MetaNode {
         ForwardResponse: TryInto<Reply>,
         <ForwardResponse as TryInto<Reply>>::Error: std::fmt::Display,
     {
-        let res = self
-            .handle_forwardable_request(ForwardRequest {
-                forward_to_leader: 1,
-                body: req.into(),
-            })
-            .await;
+        let body: ForwardRequestBody = req.into();
+
+        let res = match body.read_consistency() {
+            Some(ReadConsistency::Linearizable) => self.linearizable_read(body).await,
+            Some(ReadConsistency::StaleOk) => self.stale_read(body).await,
+            _ => {
+                self.handle_forwardable_request(ForwardRequest {
+                    forward_to_leader: 1,
+                    body,
+                })
+                .await
+            }
+        };
 
         match res {
             Err(e) => {
@@ -908,6 +937,112 @@ impl MetaNode {
         }
     }
 
+    /// Serve a read on this node after applying the read index confirmed by the leader.
+    #[tracing::instrument(level = "debug", skip(self, body))]
+    async fn linearizable_read(
+        &self,
+        body: ForwardRequestBody,
+    ) -> Result<ForwardResponse, MetaAPIError> {
+        self.wait_read_index().await?;
+        let res = self.read_local(body).await.map_err(MetaDataError::from)?;
+        Ok(res)
+    }
+
+    /// Serve a read on this node with the local state machine,
+    /// if it has applied a read index requested in the last `stale_read_max_lag`.
+    /// Otherwise catch up with a new read index first.
+    #[tracing::instrument(level = "debug", skip(self, body))]
+    async fn stale_read(&self, body: ForwardRequestBody) -> Result<ForwardResponse, MetaAPIError> {
+        let applied_at = *self.read_index_applied_at.lock().unwrap();
+
+        let fresh = applied_at
+            .map(|t| t.elapsed() <= self.stale_read_max_lag)
+            .unwrap_or(false);
+
+        if !fresh {
+            self.wait_read_index().await?;
+        }
+
+        let res = self.read_local(body).await.map_err(MetaDataError::from)?;
+        Ok(res)
+    }
+
+    /// Get a read index from the leader and wait for the local state machine to apply it.
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn wait_read_index(&self) -> Result<(), MetaAPIError> {
+        let requested_at = Instant::now();
+
+        let res = self
+            .handle_forwardable_request(ForwardRequest {
+                forward_to_leader: 1,
+                body: ForwardRequestBody::ReadIndex,
+            })
+            .await?;
+
+        let read_index: u64 = res.try_into().map_err(|e| {
+            let invalid_reply =
+                InvalidReply::new("expect reply type to be ReadIndex", &AnyError::error(e));
+            MetaNetworkError::from(invalid_reply)
+        })?;
+
+        self.raft
+            .wait(Some(Duration::from_millis(READ_INDEX_APPLY_TIMEOUT_MS)))
+            .metrics(
+                |m| m.last_applied.unwrap_or_default().index >= read_index,
+                format!("apply read index {}", read_index),
+            )
+            .await
+            .map_err(|e| {
+                MetaDataError::from(MetaDataReadError::new(
+                    "wait_read_index",
+                    format!("read index: {}", read_index),
+                    &e,
+                ))
+            })?;
+
+        let mut applied_at = self.read_index_applied_at.lock().unwrap();
+        if applied_at.map(|t| t < requested_at).unwrap_or(true) {
+            *applied_at = Some(requested_at);
+        }
+
+        Ok(())
+    }
+
+    /// Serve a read request with the local state machine.
+    pub async fn read_local(
+        &self,
+        body: ForwardRequestBody,
+    ) -> Result<ForwardResponse, MetaDataReadError> {
+        let sm = self.get_state_machine().await;
+
+        let res = match body {
+            ForwardRequestBody::GetKV(req) => {
+                let res = sm
+                    .get_kv(&req.key)
+                    .await
+                    .map_err(|meta_err| MetaDataReadError::new("get_kv", "", &meta_err))?;
+                ForwardResponse::GetKV(res)
+            }
+            ForwardRequestBody::MGetKV(req) => {
+                let res = sm
+                    .mget_kv(&req.keys)
+                    .await
+                    .map_err(|meta_err| MetaDataReadError::new("mget_kv", "", &meta_err))?;
+                ForwardResponse::MGetKV(res)
+            }
+            ForwardRequestBody::ListKV(req) => {
+                let res = sm
+                    .prefix_list_kv(&req.prefix)
+                    .await
+                    .map_err(|meta_err| MetaDataReadError::new("list_kv", "", &meta_err))?;
+                ForwardResponse::ListKV(res)
+            }
+            _ => unreachable!("expect a read request, got: {:?}", body),
+        };
+
+        Ok(res)
+    }
+
     #[tracing::instrument(level = "debug", skip(self, req), fields(target=%req.forward_to_leader))]
     pub async fn handle_forwardable_request(
         &self,
@@ -1086,22 +1221,93 @@ impl MetaNode {
         Ok(resp)
     }
 
+    /// Add a watcher, and returns the events to send before any change event.
+    ///
+    /// The initial events are the current values in the range if `initial_flush` is set,
+    /// or otherwise the changes made by the logs after `since_index` if it is set.
     pub(crate) async fn add_watcher(
         &self,
         request: WatchRequest,
         tx: WatcherSender,
-    ) -> Result<Watcher, &'static str> {
+    ) -> Result<(Watcher, Vec<WatchResponse>), Status> {
+        let range = EventDispatcher::build_key_range(request.key.clone(), &request.key_end)
+            .map_err(Status::invalid_argument)?;
+        let filter = request.filter_type();
+
+        // No log is applied until the watcher is added, thus a change is either reflected by
+        // the initial events, or sent to the watcher after them.
+        let sm = self.sto.state_machine.read().await;
+
+        let last_applied = sm
+            .get_last_applied()
+            .map_err(|e| Status::internal(e.to_string()))?
+            .map(|x| x.index)
+            .unwrap_or_default();
+
+        let mut initial = vec![];
+
+        if request.initial_flush {
+            if !Watcher::is_filtered(filter, false) {
+                let kvs = sm
+                    .range_kvs(&request.key, request.key_end.as_deref())
+                    .map_err(|e| Status::internal(e.to_string()))?;
+                for (key, value) in kvs {
+                    initial.push(build_watch_response(
+                        last_applied,
+                        key,
+                        Some(value),
+                        None,
+                        true,
+                    ));
+                }
+            }
+        } else if let Some(since_index) = request.since_index {
+            let changes = sm
+                .change_history
+                .lock()
+                .unwrap()
+                .since(since_index)
+                .ok_or_else(|| {
+                    Status::out_of_range(format!(
+                        "changes since log index {} are no longer kept, watch with initial_flush to rebuild the state",
+                        since_index
+                    ))
+                })?;
+
+            for (log_index, change) in changes {
+                let key = change.ident.unwrap_or_default();
+                let in_range = if range.start == range.end {
+                    key == range.start
+                } else {
+                    range.contains(&key)
+                };
+
+                if in_range && !Watcher::is_filtered(filter, change.result.is_none()) {
+                    initial.push(build_watch_response(
+                        log_index,
+                        key,
+                        change.result,
+                        change.prev,
+                        false,
+                    ));
+                }
+            }
+        }
+
         let (resp_tx, resp_rx) = oneshot::channel();
 
-        self.dispatcher_handle.request(|d: &mut EventDispatcher| {
-            let add_res = d.add_watcher(request, tx);
-            let _ = resp_tx.send(add_res);
-        });
+        self.dispatcher_handle
+            .request(move |d: &mut EventDispatcher| {
+                let add_res = d.add_watcher(request, tx, last_applied);
+                let _ = resp_tx.send(add_res);
+            });
 
         let recv_res = resp_rx.await;
-        match recv_res {
-            Ok(add_res) => add_res,
-            Err(_e) => Err("dispatcher closed"),
-        }
+        let watcher = match recv_res {
+            Ok(add_res) => add_res.map_err(Status::invalid_argument)?,
+            Err(_e) => return Err(Status::unavailable("dispatcher closed")),
+        };
+
+        Ok((watcher, initial))
     }
 }
@@ -181,7 +181,11 @@ pub mod raft_metrics {
     }
 
     pub mod storage {
+        use std::time::Duration;
+
         use metrics::counter;
+        use metrics::gauge;
+        use metrics::histogram;
 
         macro_rules! key {
             ($key: literal) => {
@@ -196,6 +200,20 @@ pub mod raft_metrics {
                 counter!(key!("raft_store_read_failed"), 1, &labels);
             }
         }
+
+        /// Sample the size and the time taken of the snapshot built by this node.
+        pub fn sample_snapshot_build(size: u64, kvs: u64, d: Duration) {
+            gauge!(key!("snapshot_size_bytes"), size as f64);
+            gauge!(key!("snapshot_key_num"), kvs as f64);
+            histogram!(key!("snapshot_build_seconds"), d);
+        }
+
+        /// Sample the size and the time taken of the snapshot received and installed by this node.
+        pub fn sample_snapshot_install(size: u64, kvs: u64, d: Duration) {
+            gauge!(key!("snapshot_size_bytes"), size as f64);
+            gauge!(key!("snapshot_key_num"), kvs as f64);
+            histogram!(key!("snapshot_install_seconds"), d);
+        }
     }
 }
 
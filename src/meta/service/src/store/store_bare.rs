@@ -13,18 +13,25 @@
 // limitations under the License.
 
 use std::fmt::Debug;
-use std::io::Cursor;
+use std::io::BufRead;
+use std::io::BufReader;
 use std::io::ErrorKind;
 use std::ops::RangeBounds;
+use std::time::Instant;
 
 use anyerror::AnyError;
+use common_base::base::tokio;
+use common_base::base::tokio::fs::File;
+use common_base::base::tokio::sync::mpsc;
 use common_base::base::tokio::sync::RwLock;
 use common_base::base::tokio::sync::RwLockWriteGuard;
 use common_exception::WithContext;
 use common_meta_raft_store::config::RaftConfig;
+use common_meta_raft_store::key_spaces::RaftStoreEntry;
 use common_meta_raft_store::log::RaftLog;
 use common_meta_raft_store::state::RaftState;
-use common_meta_raft_store::state_machine::SerializableSnapshot;
+use common_meta_raft_store::state_machine::read_snapshot_chunks;
+use common_meta_raft_store::state_machine::snapshot::snapshot_io_error;
 use common_meta_raft_store::state_machine::Snapshot;
 use common_meta_raft_store::state_machine::StateMachine;
 use common_meta_sled_store::get_sled_db;
@@ -54,6 +61,7 @@ use openraft::StorageError;
 use tracing::debug;
 use tracing::error;
 use tracing::info;
+use tracing::warn;
 
 use crate::export::vec_kv_to_json;
 use crate::metrics::raft_metrics;
@@ -61,6 +69,15 @@ use crate::metrics::server_metrics;
 use crate::store::ToStorageError;
 use crate::Opened;
 
+/// The number of the lines in a chunk of a backup.
+const BACKUP_CHUNK_SIZE: usize = 16;
+
+/// The number of the chunks of a backup buffered before they are sent.
+const BACKUP_CHANNEL_SIZE: usize = 64;
+
+/// The number of the raft logs read at a time for a backup.
+const BACKUP_LOGS_BATCH_SIZE: u64 = 1024;
+
 /// An storage implementing the `async_raft::RaftStorage` trait.
 ///
 /// It is the stateful part in a raft impl.
@@ -105,6 +122,9 @@ pub struct RaftStoreBare {
 
     /// The current snapshot.
     pub current_snapshot: RwLock<Option<Snapshot>>,
+
+    /// The dir to store snapshot files, including the one being received.
+    snapshot_dir: String,
 }
 
 impl AsRef<RaftStoreBare> for RaftStoreBare {
@@ -153,6 +173,10 @@ impl RaftStoreBare {
         let sm = RwLock::new(StateMachine::open(config, sm_id).await?);
         let current_snapshot = RwLock::new(None);
 
+        // Snapshot files left by the previous run are not referenced any more.
+        let snapshot_dir = StateMachine::snapshot_dir(config);
+        let _ = std::fs::remove_dir_all(&snapshot_dir);
+
         Ok(Self {
             id: raft_state.id,
             config: config.clone(),
@@ -162,6 +186,7 @@ impl RaftStoreBare {
             log,
             state_machine: sm,
             current_snapshot,
+            snapshot_dir,
         })
     }
 
@@ -181,15 +206,28 @@ impl RaftStoreBare {
     > {
         // NOTE: building snapshot is guaranteed to be serialized called by RaftCore.
 
-        // 1. Take a serialized snapshot
+        // 1. Freeze a view of the state machine at the last applied log, the write lock blocks applying logs only
+        //    while freezing. Then stream the view into a snapshot file, logs are applied meanwhile.
 
-        let (snap, last_applied_log, snapshot_id) = match self
+        let start = Instant::now();
+
+        let (last_applied_log, view) = self
             .state_machine
             .write()
             .await
-            .build_snapshot()
-            .map_to_sto_err(ErrorSubject::StateMachine, ErrorVerb::Read)
-        {
+            .freeze()
+            .map_to_sto_err(ErrorSubject::StateMachine, ErrorVerb::Read)?;
+
+        let dir = self.snapshot_dir.clone();
+        let written = tokio::task::spawn_blocking(move || {
+            StateMachine::write_snapshot(view, last_applied_log, &dir)
+        })
+        .await
+        .map_err(|e| MetaStorageError::SnapshotError(AnyError::new(&e)))
+        .and_then(|r| r)
+        .map_to_sto_err(ErrorSubject::StateMachine, ErrorVerb::Read);
+
+        let (stat, snapshot_id) = match written {
             Err(err) => {
                 raft_metrics::storage::incr_raft_storage_fail("build_snapshot", false);
                 return Err(err);
@@ -197,39 +235,103 @@ impl RaftStoreBare {
             Ok(r) => r,
         };
 
-        let data = serde_json::to_vec(&snap)
-            .map_err(MetaStorageError::from)
-            .map_to_sto_err(ErrorSubject::StateMachine, ErrorVerb::Read)?;
-
-        let snapshot_size = data.len();
+        raft_metrics::storage::sample_snapshot_build(stat.size, stat.kvs, start.elapsed());
 
         let snap_meta = SnapshotMeta {
             last_log_id: last_applied_log,
             snapshot_id: snapshot_id.to_string(),
         };
 
+        let f = open_snapshot_file(&stat.path)
+            .await
+            .map_to_sto_err(ErrorSubject::Snapshot(snap_meta.clone()), ErrorVerb::Read)?;
+
         let snapshot = Snapshot {
             meta: snap_meta.clone(),
-            data: data.clone(),
+            path: stat.path,
+            size: stat.size,
         };
 
         // Update the snapshot first.
-        {
-            let mut current_snapshot = self.current_snapshot.write().await;
-            *current_snapshot = Some(snapshot);
-        }
+        self.replace_current_snapshot(snapshot).await;
 
-        info!(snapshot_size = snapshot_size, "log compaction complete");
+        info!(
+            snapshot_size = stat.size,
+            snapshot_kvs = stat.kvs,
+            "log compaction complete"
+        );
 
         Ok(openraft::storage::Snapshot {
             meta: snap_meta,
-            snapshot: Box::new(Cursor::new(data)),
+            snapshot: Box::new(f),
         })
     }
 
+    /// Replace the current snapshot, and remove the file of the previous one.
+    ///
+    /// A reader that has opened the previous file, e.g., one that is sending it to a follower, is not affected.
+    async fn replace_current_snapshot(&self, snapshot: Snapshot) {
+        let path = snapshot.path.clone();
+
+        let prev = {
+            let mut current_snapshot = self.current_snapshot.write().await;
+            current_snapshot.replace(snapshot)
+        };
+
+        if let Some(prev) = prev {
+            if prev.path != path {
+                if let Err(e) = tokio::fs::remove_file(&prev.path).await {
+                    warn!("failed to remove snapshot file {}: {}", prev.path, e);
+                }
+            }
+        }
+    }
+
+    fn receiving_snapshot_path(&self) -> String {
+        format!("{}/receiving.snap", self.snapshot_dir)
+    }
+
+    /// Flush the received snapshot data to disk, and move it to the path named after the snapshot id.
+    ///
+    /// Returns the path and the size of the snapshot file.
+    async fn save_received_snapshot(
+        &self,
+        meta: &SnapshotMeta,
+        snapshot: File,
+    ) -> Result<(String, u64), MetaStorageError> {
+        let receiving_path = self.receiving_snapshot_path();
+        let path = format!("{}/{}.snap", self.snapshot_dir, meta.snapshot_id);
+
+        snapshot
+            .sync_all()
+            .await
+            .map_err(|e| snapshot_io_error(e, "sync", &receiving_path))?;
+        let size = snapshot
+            .metadata()
+            .await
+            .map_err(|e| snapshot_io_error(e, "stat", &receiving_path))?
+            .len();
+        drop(snapshot);
+
+        tokio::fs::rename(&receiving_path, &path)
+            .await
+            .map_err(|e| snapshot_io_error(e, "rename", &receiving_path))?;
+
+        Ok((path, size))
+    }
+
     /// Install a snapshot to build a state machine from it and replace the old state machine with the new one.
+    ///
+    /// The snapshot data is read and inserted into the new state machine one chunk at a time.
+    /// If it fails, the partially built state machine is cleaned and the old one is kept,
+    /// thus the snapshot can be installed again.
+    ///
+    /// Returns the number of key-values installed.
     #[tracing::instrument(level = "debug", skip(self, data))]
-    pub async fn install_snapshot(&self, data: &[u8]) -> Result<(), MetaStorageError> {
+    pub async fn install_snapshot<R: BufRead + Send + 'static>(
+        &self,
+        data: R,
+    ) -> Result<u64, MetaStorageError> {
         let mut sm = self.state_machine.write().await;
 
         let (sm_id, prev_sm_id) = self.raft_state.read_state_machine_id()?;
@@ -242,36 +344,30 @@ impl RaftStoreBare {
 
         let new_sm_id = sm_id + 1;
 
-        info!("snapshot data len: {}", data.len());
-
-        let snap: SerializableSnapshot = serde_json::from_slice(data)?;
-
         // If not finished, clean up the new tree.
         self.raft_state
             .write_state_machine_id(&(sm_id, new_sm_id))
             .await?;
 
-        let new_sm = StateMachine::open(&self.config, new_sm_id).await?;
-        info!(
-            "insert all key-value into new state machine, n={}",
-            snap.kvs.len()
-        );
-
-        let tree = &new_sm.sm_tree.tree;
-        let nkvs = snap.kvs.len();
-        for x in snap.kvs.into_iter() {
-            let k = &x[0];
-            let v = &x[1];
-            tree.insert(k, v.clone()).context(|| "insert snapshot")?;
-        }
-
-        info!(
-            "installed state machine from snapshot, no_kvs: {} last_applied: {:?}",
-            nkvs,
-            new_sm.get_last_applied()?,
-        );
+        let mut new_sm = StateMachine::open(&self.config, new_sm_id).await?;
+        info!("insert all key-value into new state machine");
 
-        tree.flush_async().await.context(|| "flush snapshot")?;
+        let nkvs = match Self::load_snapshot(&new_sm, data).await {
+            Ok(nkvs) => nkvs,
+            Err(e) => {
+                error!(
+                    "error: {:?} when loading snapshot, clean the new state machine",
+                    e
+                );
+
+                drop(new_sm);
+                StateMachine::clean(&self.config, new_sm_id)?;
+                self.raft_state
+                    .write_state_machine_id(&(sm_id, sm_id))
+                    .await?;
+                return Err(e);
+            }
+        };
 
         info!("flushed tree, no_kvs: {}", nkvs);
 
@@ -293,8 +389,65 @@ impl RaftStoreBare {
 
         // TODO(xp): use checksum to check consistency?
 
+        // Keep feeding changes to the watchers.
+        // A watcher can not resume from before the snapshot.
+        new_sm.reset_change_history()?;
+        new_sm.subscriber = sm.subscriber.take();
+
         *sm = new_sm;
-        Ok(())
+        Ok(nkvs)
+    }
+
+    /// Insert all key-values in the snapshot data into the state machine, and flush it.
+    ///
+    /// The data is read and inserted in a blocking thread.
+    async fn load_snapshot<R: BufRead + Send + 'static>(
+        sm: &StateMachine,
+        data: R,
+    ) -> Result<u64, MetaStorageError> {
+        let tree = sm.sm_tree.tree.clone();
+        let nkvs = tokio::task::spawn_blocking(move || Self::insert_snapshot_chunks(&tree, data))
+            .await
+            .map_err(|e| MetaStorageError::SnapshotError(AnyError::new(&e)))??;
+
+        info!(
+            "installed state machine from snapshot, no_kvs: {} last_applied: {:?}",
+            nkvs,
+            sm.get_last_applied()?,
+        );
+
+        sm.sm_tree
+            .tree
+            .flush_async()
+            .await
+            .context(|| "flush snapshot")?;
+
+        Ok(nkvs)
+    }
+
+    fn insert_snapshot_chunks<R: BufRead>(
+        tree: &sled::Tree,
+        data: R,
+    ) -> Result<u64, MetaStorageError> {
+        let mut nkvs = 0;
+        for chunk in read_snapshot_chunks(data) {
+            let mut batch = sled::Batch::default();
+            for x in chunk?.kvs.into_iter() {
+                let mut kv = x.into_iter();
+                match (kv.next(), kv.next()) {
+                    (Some(k), Some(v)) => batch.insert(k, v),
+                    _ => {
+                        return Err(MetaStorageError::SnapshotError(AnyError::error(
+                            "invalid key-value in snapshot",
+                        )));
+                    }
+                }
+                nkvs += 1;
+            }
+            tree.apply_batch(batch).context(|| "insert snapshot")?;
+        }
+
+        Ok(nkvs)
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
@@ -341,11 +494,132 @@ impl RaftStoreBare {
 
         Ok(res)
     }
+
+    /// Back up the data consistently, without stopping the service.
+    ///
+    /// - Without `logs_since`, it returns every key-value in the state machine, as of its last applied log.
+    /// - With `logs_since`, it returns the applied raft logs after it,
+    ///   or None if some of them are already purged.
+    ///
+    /// Every line is a json string in form of `(tree_name, entry)`, the same as `export()`.
+    /// The lines are read in a blocking thread and sent to the returned channel in chunks,
+    /// the state machine is not locked while reading.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn backup(
+        &self,
+        logs_since: Option<u64>,
+    ) -> Result<Option<mpsc::Receiver<Result<Vec<String>, std::io::Error>>>, std::io::Error> {
+        let (tx, rx) = mpsc::channel(BACKUP_CHANNEL_SIZE);
+
+        match logs_since {
+            None => {
+                // Freeze a view of the state machine while nothing is applied, the lock is
+                // released before reading it.
+                let (name, view) = {
+                    let sm = self.state_machine.write().await;
+                    let (_, view) = sm
+                        .freeze()
+                        .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+                    (sm.sm_tree.name.clone(), view)
+                };
+
+                tokio::task::spawn_blocking(move || {
+                    let lines = view.into_iter().map(|rkv| {
+                        let (k, v) = rkv?;
+                        vec_kv_to_json(&name, &[k.to_vec(), v.to_vec()])
+                    });
+                    send_backup_lines(&tx, lines);
+                });
+            }
+            Some(since) => {
+                let last_purged = self
+                    .log
+                    .get_last_purged()
+                    .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+
+                if last_purged.map(|x| x.index > since).unwrap_or(false) {
+                    return Ok(None);
+                }
+
+                let last_applied = self
+                    .state_machine
+                    .read()
+                    .await
+                    .get_last_applied()
+                    .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?
+                    .map(|x| x.index)
+                    .unwrap_or_default();
+
+                // The applied logs do not change, but they may be purged while reading.
+                let log = RaftLog {
+                    inner: self.log.inner.clone(),
+                };
+
+                tokio::task::spawn_blocking(move || {
+                    let mut start = since + 1;
+                    while start <= last_applied {
+                        let end = std::cmp::min(start + BACKUP_LOGS_BATCH_SIZE - 1, last_applied);
+                        let lines = match log.range_values(start..=end) {
+                            Ok(logs) if logs.len() as u64 == end - start + 1 => logs
+                                .into_iter()
+                                .map(|ent| {
+                                    let entry = RaftStoreEntry::Logs {
+                                        key: ent.log_id.index,
+                                        value: ent,
+                                    };
+                                    serde_json::to_string(&(&log.inner.name, entry))
+                                        .map_err(MetaStorageError::from)
+                                })
+                                .collect::<Vec<_>>(),
+                            Ok(_) => vec![Err(MetaStorageError::SnapshotError(AnyError::error(
+                                format!("raft logs since {} are purged during the backup", start),
+                            )))],
+                            Err(e) => vec![Err(e)],
+                        };
+
+                        if !send_backup_lines(&tx, lines.into_iter()) {
+                            return;
+                        }
+                        start = end + 1;
+                    }
+                });
+            }
+        }
+
+        Ok(Some(rx))
+    }
+}
+
+/// Send the lines of a backup to the receiver in chunks.
+///
+/// Returns false if it stops early, on an error or if the receiver is dropped.
+fn send_backup_lines(
+    tx: &mpsc::Sender<Result<Vec<String>, std::io::Error>>,
+    lines: impl Iterator<Item = Result<String, MetaStorageError>>,
+) -> bool {
+    let mut chunk = Vec::with_capacity(BACKUP_CHUNK_SIZE);
+    for line in lines {
+        match line {
+            Ok(line) => chunk.push(line),
+            Err(e) => {
+                let _ = tx.blocking_send(Err(std::io::Error::new(ErrorKind::InvalidData, e)));
+                return false;
+            }
+        }
+
+        if chunk.len() == BACKUP_CHUNK_SIZE
+            && tx.blocking_send(Ok(std::mem::take(&mut chunk))).is_err()
+        {
+            return false;
+        }
+    }
+
+    chunk.is_empty() || tx.blocking_send(Ok(chunk)).is_ok()
 }
 
 #[async_trait]
 impl RaftStorage<LogEntry, AppliedState> for RaftStoreBare {
-    type SnapshotData = Cursor<Vec<u8>>;
+    type SnapshotData = File;
 
     #[tracing::instrument(level = "debug", skip(self, hs), fields(id=self.id))]
     async fn save_hard_state(&self, hs: &HardState) -> Result<(), StorageError> {
@@ -488,10 +762,20 @@ impl RaftStorage<LogEntry, AppliedState> for RaftStoreBare {
         self.do_build_snapshot().await
     }
 
+    /// Receive a snapshot into a file.
+    ///
+    /// Every chunk of the snapshot is written at its offset,
+    /// so that a chunk resent after a timeout continues the install instead of restarting it.
     #[tracing::instrument(level = "debug", skip(self), fields(id=self.id))]
     async fn begin_receiving_snapshot(&self) -> Result<Box<Self::SnapshotData>, StorageError> {
         server_metrics::incr_applying_snapshot(1);
-        Ok(Box::new(Cursor::new(Vec::new())))
+
+        let path = self.receiving_snapshot_path();
+        let f = create_snapshot_file(&self.snapshot_dir, &path)
+            .await
+            .map_to_sto_err(ErrorSubject::StateMachine, ErrorVerb::Write)?;
+
+        Ok(Box::new(f))
     }
 
     #[tracing::instrument(level = "debug", skip(self, snapshot), fields(id=self.id))]
@@ -502,34 +786,43 @@ impl RaftStorage<LogEntry, AppliedState> for RaftStoreBare {
     ) -> Result<StateMachineChanges, StorageError> {
         // TODO(xp): disallow installing a snapshot with smaller last_applied.
 
-        info!(
-            { snapshot_size = snapshot.get_ref().len() },
-            "decoding snapshot for installation"
-        );
         server_metrics::incr_applying_snapshot(-1);
 
-        let new_snapshot = Snapshot {
-            meta: meta.clone(),
-            data: snapshot.into_inner(),
-        };
+        let start = Instant::now();
+
+        let (path, size) = self
+            .save_received_snapshot(meta, *snapshot)
+            .await
+            .map_to_sto_err(ErrorSubject::Snapshot(meta.clone()), ErrorVerb::Write)?;
 
+        info!(snapshot_size = size, "decoding snapshot for installation");
         info!("snapshot meta: {:?}", meta);
 
         // Replace state machine with the new one
-        let res = self.install_snapshot(&new_snapshot.data).await;
-        match res {
-            Ok(_) => {}
-            Err(e) => {
-                raft_metrics::storage::incr_raft_storage_fail("install_snapshot", true);
-                error!("error: {:?} when install_snapshot", e);
-            }
+        let res = match std::fs::File::open(&path) {
+            Ok(f) => self.install_snapshot(BufReader::new(f)).await,
+            Err(e) => Err(snapshot_io_error(e, "open", &path)),
         };
+        if let Err(e) = &res {
+            raft_metrics::storage::incr_raft_storage_fail("install_snapshot", true);
+            error!("error: {:?} when install_snapshot", e);
 
-        // Update current snapshot.
-        {
-            let mut current_snapshot = self.current_snapshot.write().await;
-            *current_snapshot = Some(new_snapshot);
+            // The partially built state machine is cleaned, the leader will send the snapshot again.
+            if let Err(rm_err) = std::fs::remove_file(&path) {
+                warn!("error: {:?} when removing snapshot file: {}", rm_err, path);
+            }
         }
+        let nkvs = res.map_to_sto_err(ErrorSubject::Snapshot(meta.clone()), ErrorVerb::Write)?;
+        raft_metrics::storage::sample_snapshot_install(size, nkvs, start.elapsed());
+
+        // Update current snapshot only if the state machine is replaced.
+        self.replace_current_snapshot(Snapshot {
+            meta: meta.clone(),
+            path,
+            size,
+        })
+        .await;
+
         Ok(StateMachineChanges {
             last_applied: meta.last_log_id,
             is_snapshot: true,
@@ -543,10 +836,13 @@ impl RaftStorage<LogEntry, AppliedState> for RaftStoreBare {
         info!("get snapshot start");
         let snap = match &*self.current_snapshot.read().await {
             Some(snapshot) => {
-                let data = snapshot.data.clone();
+                let f = open_snapshot_file(&snapshot.path).await.map_to_sto_err(
+                    ErrorSubject::Snapshot(snapshot.meta.clone()),
+                    ErrorVerb::Read,
+                )?;
                 Ok(Some(openraft::storage::Snapshot {
                     meta: snapshot.meta.clone(),
-                    snapshot: Box::new(Cursor::new(data)),
+                    snapshot: Box::new(f),
                 }))
             }
             None => Ok(None),
@@ -692,3 +988,24 @@ impl RaftStoreBare {
         Ok(endpoint)
     }
 }
+
+async fn open_snapshot_file(path: &str) -> Result<File, MetaStorageError> {
+    File::open(path)
+        .await
+        .map_err(|e| snapshot_io_error(e, "open", path))
+}
+
+async fn create_snapshot_file(dir: &str, path: &str) -> Result<File, MetaStorageError> {
+    tokio::fs::create_dir_all(dir)
+        .await
+        .map_err(|e| snapshot_io_error(e, "create dir of", dir))?;
+
+    tokio::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+        .await
+        .map_err(|e| snapshot_io_error(e, "create", path))
+}
@@ -15,6 +15,7 @@
 mod watcher_manager;
 mod watcher_stream;
 
+pub(crate) use watcher_manager::build_watch_response;
 pub(crate) use watcher_manager::DispatcherSender;
 pub(crate) use watcher_manager::EventDispatcher;
 pub use watcher_manager::EventDispatcherHandle;
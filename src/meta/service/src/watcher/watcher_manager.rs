@@ -25,6 +25,7 @@ use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::Change;
 use common_meta_types::PbSeqV;
+use common_meta_types::SeqV;
 use prost::Message;
 use tonic::Status;
 use tracing::info;
@@ -46,8 +47,8 @@ pub(crate) struct DispatcherSender(pub(crate) mpsc::UnboundedSender<WatchEvent>)
 
 /// An event sent to EventDispatcher.
 pub(crate) enum WatchEvent {
-    /// Submit a kv change event to dispatcher
-    KVChange(Change<Vec<u8>, String>),
+    /// Submit a kv change event made by the log at the index to dispatcher
+    KVChange(u64, Change<Vec<u8>, String>),
 
     /// Send a request to EventDispatcher.
     ///
@@ -119,8 +120,8 @@ impl EventDispatcher {
         loop {
             if let Some(event) = self.event_rx.recv().await {
                 match event {
-                    WatchEvent::KVChange(kv_change) => {
-                        self.dispatch_event(kv_change).await;
+                    WatchEvent::KVChange(log_index, kv_change) => {
+                        self.dispatch_event(log_index, kv_change).await;
                     }
                     WatchEvent::Request { req } => req(&mut self),
                 }
@@ -132,7 +133,7 @@ impl EventDispatcher {
     }
 
     /// Dispatch a kv change event to interested watchers.
-    async fn dispatch_event(&mut self, change: Change<Vec<u8>, String>) {
+    async fn dispatch_event(&mut self, log_index: u64, change: Change<Vec<u8>, String>) {
         let k = change.ident.as_ref().unwrap();
         let set = self.watcher_range_map.get_by_point(k);
         if set.is_empty() {
@@ -146,25 +147,28 @@ impl EventDispatcher {
         let mut remove_range_keys: Vec<RangeMapKey<String, WatcherId>> = vec![];
 
         for range_key_stream in set.iter() {
-            let filter = range_key_stream.1.watcher.filter_type;
+            let watcher = &range_key_stream.1.watcher;
 
             // filter out event
-            if (filter == FilterType::Delete && !is_delete_event)
-                || (filter == FilterType::Update && is_delete_event)
-            {
+            if Watcher::is_filtered(watcher.filter_type, is_delete_event) {
+                continue;
+            }
+
+            // The change is already in the initial events of the watch.
+            if log_index <= watcher.start_index {
                 continue;
             }
 
             let watcher_id = range_key_stream.0.key;
             let stream = range_key_stream.1;
             assert_eq!(stream.watcher.id, watcher_id);
-            let resp = WatchResponse {
-                event: Some(Event {
-                    key: k.to_string(),
-                    current: current.clone().map(PbSeqV::from),
-                    prev: prev.clone().map(PbSeqV::from),
-                }),
-            };
+            let resp = build_watch_response(
+                log_index,
+                k.to_string(),
+                current.clone(),
+                prev.clone(),
+                false,
+            );
 
             network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
 
@@ -186,13 +190,15 @@ impl EventDispatcher {
         }
     }
 
+    /// Add a watcher that receives the changes made by the logs after `start_index`.
     #[tracing::instrument(level = "debug", skip(self))]
     pub fn add_watcher(
         &mut self,
         create: WatchRequest,
         tx: WatcherSender,
+        start_index: u64,
     ) -> Result<Watcher, &'static str> {
-        info!("add_watcher: {:?}", create);
+        info!("add_watcher: {:?}, start_index: {}", create, start_index);
 
         let range = match EventDispatcher::build_key_range(create.key.clone(), &create.key_end) {
             Ok(range) => range,
@@ -203,7 +209,7 @@ impl EventDispatcher {
         let watcher_id = self.current_watcher_id;
         let filter: FilterType = create.filter_type();
 
-        let watcher = Watcher::new(watcher_id, filter, range.clone());
+        let watcher = Watcher::new(watcher_id, filter, range.clone(), start_index);
         let stream_handle = WatchStreamHandle::new(watcher.clone(), tx);
 
         self.watcher_range_map
@@ -224,7 +230,7 @@ impl EventDispatcher {
         server_metrics::incr_watchers(-1);
     }
 
-    fn build_key_range(
+    pub(crate) fn build_key_range(
         key: String,
         key_end: &Option<String>,
     ) -> Result<Range<String>, &'static str> {
@@ -244,8 +250,27 @@ impl EventDispatcher {
     }
 }
 
+/// Build a response of a watch stream for a change or an initial value of a key.
+pub(crate) fn build_watch_response(
+    log_index: u64,
+    key: String,
+    current: Option<SeqV<Vec<u8>>>,
+    prev: Option<SeqV<Vec<u8>>>,
+    is_initialization: bool,
+) -> WatchResponse {
+    WatchResponse {
+        event: Some(Event {
+            key,
+            current: current.map(PbSeqV::from),
+            prev: prev.map(PbSeqV::from),
+        }),
+        log_index,
+        is_initialization,
+    }
+}
+
 impl StateMachineSubscriber for DispatcherSender {
-    fn kv_changed(&self, change: Change<Vec<u8>, String>) {
-        let _ = self.0.send(WatchEvent::KVChange(change));
+    fn kv_changed(&self, log_index: u64, change: Change<Vec<u8>, String>) {
+        let _ = self.0.send(WatchEvent::KVChange(log_index, change));
     }
 }
@@ -39,16 +39,32 @@ pub struct Watcher {
 
     /// The range of key this watcher is interested in.
     pub key_range: Range<String>,
+
+    /// The changes made by the logs up to this index are not sent to this watcher,
+    /// they are already reflected by the initial events of the watch.
+    pub start_index: u64,
 }
 
 impl Watcher {
-    pub fn new(id: WatcherId, filter_type: FilterType, key_range: Range<String>) -> Self {
+    pub fn new(
+        id: WatcherId,
+        filter_type: FilterType,
+        key_range: Range<String>,
+        start_index: u64,
+    ) -> Self {
         Self {
             id,
             filter_type,
             key_range,
+            start_index,
         }
     }
+
+    /// Returns true if the event of the key is filtered out by `filter_type`.
+    pub fn is_filtered(filter_type: FilterType, is_delete_event: bool) -> bool {
+        (filter_type == FilterType::Delete && !is_delete_event)
+            || (filter_type == FilterType::Update && is_delete_event)
+    }
 }
 
 /// A handle of a watching stream, for feeding messages to the stream.
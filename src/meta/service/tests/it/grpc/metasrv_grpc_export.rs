@@ -17,6 +17,7 @@ use std::time::Duration;
 use common_base::base::tokio;
 use common_meta_api::KVApi;
 use common_meta_client::MetaGrpcClient;
+use common_meta_types::protobuf::BackupRequest;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
@@ -118,3 +119,95 @@ async fn test_export() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_backup() -> anyhow::Result<()> {
+    // - Start a metasrv server.
+    // - Write some data
+    // - Back up the state machine, then the logs since a given index.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = MetaGrpcClient::try_create(
+        vec![addr],
+        "root",
+        "xxx",
+        None,
+        Some(Duration::from_secs(10)),
+        None,
+    )?;
+
+    info!("--- upsert kv");
+    {
+        for k in ["foo", "bar", "wow"] {
+            client
+                .upsert_kv(UpsertKVReq::new(
+                    k,
+                    MatchSeq::Any,
+                    Operation::Update(k.as_bytes().to_vec()),
+                    None,
+                ))
+                .await?;
+        }
+    }
+
+    let mut grpc_client = client.make_client().await?;
+
+    info!("--- full backup contains only the state machine");
+    {
+        let resp = grpc_client
+            .backup(tonic::Request::new(BackupRequest { logs_since: None }))
+            .await?;
+        let lines = collect_lines(resp.into_inner()).await?;
+
+        assert_eq!(8, lines.len());
+        for line in lines.iter() {
+            assert!(line.contains("state_machine/0"), "{}", line);
+        }
+        assert!(
+            lines
+                .iter()
+                .any(|x| x.contains(r#""LastApplied","value":{"LogId":{"term":1,"index":5}}"#))
+        );
+    }
+
+    info!("--- backup logs since index 3");
+    {
+        let resp = grpc_client
+            .backup(tonic::Request::new(BackupRequest {
+                logs_since: Some(3),
+            }))
+            .await?;
+        let lines = collect_lines(resp.into_inner()).await?;
+
+        assert_eq!(2, lines.len());
+        assert!(lines[0].contains(r#"{"Logs":{"key":4,"#), "{}", lines[0]);
+        assert!(lines[1].contains(r#"{"Logs":{"key":5,"#), "{}", lines[1]);
+    }
+
+    info!("--- nothing to back up since the last applied");
+    {
+        let resp = grpc_client
+            .backup(tonic::Request::new(BackupRequest {
+                logs_since: Some(5),
+            }))
+            .await?;
+        let lines = collect_lines(resp.into_inner()).await?;
+
+        assert!(lines.is_empty());
+    }
+
+    Ok(())
+}
+
+async fn collect_lines(
+    mut stream: tonic::Streaming<common_meta_types::protobuf::ExportedChunk>,
+) -> anyhow::Result<Vec<String>> {
+    let mut lines = vec![];
+    while let Some(chunk_res) = stream.next().await {
+        let chunk = chunk_res?;
+
+        lines.extend_from_slice(&chunk.data);
+    }
+    Ok(lines)
+}
@@ -0,0 +1,108 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test reading with different consistency from the leader and followers.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_meta_api::KVApi;
+use common_meta_types::GetKVReply;
+use common_meta_types::GetKVReq;
+use common_meta_types::KVAppError;
+use common_meta_types::ListKVReply;
+use common_meta_types::ListKVReq;
+use common_meta_types::MGetKVReply;
+use common_meta_types::MGetKVReq;
+use common_meta_types::ReadConsistency;
+use common_meta_types::UpsertKVReq;
+use databend_meta::init_meta_ut;
+use tracing::info;
+
+use crate::tests::service::start_metasrv_cluster;
+
+/// - Start a cluster of 3.
+/// - Write to the leader, read from every node with every consistency.
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_kv_read_consistency() -> anyhow::Result<()> {
+    let tcs = start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    let leader_client = tcs[0].grpc_client().await?;
+
+    for (i, consistency) in [ReadConsistency::Leader, ReadConsistency::Linearizable]
+        .into_iter()
+        .enumerate()
+    {
+        info!("--- write to leader then read with {:?}", consistency);
+
+        let key = format!("read-consistency-{}", i);
+        leader_client
+            .upsert_kv(UpsertKVReq::update(&key, key.as_bytes()))
+            .await?;
+
+        for tc in tcs.iter() {
+            let client = tc.grpc_client().await?;
+
+            let got: Result<GetKVReply, KVAppError> = client
+                .request(GetKVReq::new(&key).with_consistency(consistency))
+                .await;
+            assert_eq!(key.as_bytes(), got?.unwrap().data);
+
+            let got: Result<MGetKVReply, KVAppError> = client
+                .request(
+                    MGetKVReq::new(vec![key.clone(), "foo".to_string()])
+                        .with_consistency(consistency),
+                )
+                .await;
+            let got = got?;
+            assert_eq!(key.as_bytes(), got[0].as_ref().unwrap().data);
+            assert!(got[1].is_none());
+
+            let got: Result<ListKVReply, KVAppError> = client
+                .request(ListKVReq::new(&key).with_consistency(consistency))
+                .await;
+            let got = got?;
+            assert_eq!(1, got.len());
+            assert_eq!(key, got[0].0);
+        }
+    }
+
+    info!("--- a follower that served a linearizable read has caught up with the leader");
+    for tc in tcs.iter() {
+        let meta_node = tc.grpc_srv.as_ref().unwrap().get_meta_node();
+        let applied_at = *meta_node.read_index_applied_at.lock().unwrap();
+        assert!(applied_at.is_some());
+    }
+
+    info!("--- a stale-ok read lags behind no more than stale_read_max_lag");
+    {
+        let key = "read-consistency-stale";
+        leader_client
+            .upsert_kv(UpsertKVReq::update(key, key.as_bytes()))
+            .await?;
+
+        let max_lag = tcs[1].config.raft_config.stale_read_max_lag;
+        tokio::time::sleep(Duration::from_millis(max_lag + 100)).await;
+
+        for tc in tcs.iter() {
+            let client = tc.grpc_client().await?;
+            let got: Result<GetKVReply, KVAppError> = client
+                .request(GetKVReq::new(key).with_consistency(ReadConsistency::StaleOk))
+                .await;
+            assert_eq!(key.as_bytes(), got?.unwrap().data);
+        }
+    }
+
+    Ok(())
+}
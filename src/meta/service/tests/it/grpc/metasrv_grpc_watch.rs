@@ -130,6 +130,8 @@ async fn test_watch() -> common_exception::Result<()> {
             key: "a".to_string(),
             key_end: Some("z".to_string()),
             filter_type: FilterType::All.into(),
+            initial_flush: false,
+            since_index: None,
         };
 
         let key_a = "a".to_string();
@@ -203,6 +205,8 @@ async fn test_watch() -> common_exception::Result<()> {
             key_end: None,
             // filter only delete events
             filter_type: FilterType::Delete.into(),
+            initial_flush: false,
+            since_index: None,
         };
 
         let key = key_str.to_string();
@@ -282,6 +286,8 @@ async fn test_watch() -> common_exception::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            initial_flush: false,
+            since_index: None,
         };
 
         let conditions = vec![TxnCondition {
@@ -433,6 +439,8 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
         key: start,
         key_end: Some(end),
         filter_type: FilterType::All.into(),
+        initial_flush: false,
+        since_index: None,
     };
 
     let txn = TxnRequest {
@@ -501,6 +509,113 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_watch_initial_flush_and_resume() -> anyhow::Result<()> {
+    // - Write some keys.
+    // - Watch with initial_flush, the current values are sent first, then the changes.
+    // - Drop the stream, write more while disconnected.
+    // - Resume from the last received log index, the missed changes are sent first.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = make_client(&addr)?;
+    client
+        .upsert_kv(UpsertKVReq::update("f_a", &b("a")))
+        .await?;
+    client
+        .upsert_kv(UpsertKVReq::update("f_b", &b("b")))
+        .await?;
+    client
+        .upsert_kv(UpsertKVReq::update("g_out_of_range", &b("x")))
+        .await?;
+
+    let (start, end) = get_start_and_end_of_prefix("f_")?;
+    let watch = WatchRequest {
+        key: start.clone(),
+        key_end: Some(end.clone()),
+        filter_type: FilterType::All.into(),
+        initial_flush: true,
+        since_index: None,
+    };
+
+    info!("--- initial values go first, then changes");
+    let last_index = {
+        let mut watch_stream = client.request(watch).await?;
+
+        for (key, val) in [("f_a", "a"), ("f_b", "b")] {
+            let resp = watch_stream.message().await?.unwrap();
+            assert!(resp.is_initialization);
+            let event = resp.event.unwrap();
+            assert_eq!(key, event.key);
+            assert_eq!(b(val), event.current.unwrap().data);
+            assert_eq!(None, event.prev);
+        }
+
+        client
+            .upsert_kv(UpsertKVReq::update("f_c", &b("c")))
+            .await?;
+
+        let resp = watch_stream.message().await?.unwrap();
+        assert!(!resp.is_initialization);
+        let event = resp.event.unwrap();
+        assert_eq!("f_c", event.key);
+        assert_eq!(b("c"), event.current.unwrap().data);
+
+        resp.log_index
+    };
+
+    info!("--- changes while disconnected");
+    client
+        .upsert_kv(UpsertKVReq::update("f_d", &b("d")))
+        .await?;
+    client
+        .upsert_kv(UpsertKVReq::update("g_out_of_range", &b("y")))
+        .await?;
+    client
+        .upsert_kv(UpsertKVReq::new(
+            "f_a",
+            MatchSeq::Any,
+            Operation::Delete,
+            None,
+        ))
+        .await?;
+
+    info!("--- resume from the last received log index");
+    {
+        let watch = WatchRequest {
+            key: start,
+            key_end: Some(end),
+            filter_type: FilterType::All.into(),
+            initial_flush: false,
+            since_index: Some(last_index),
+        };
+        let mut watch_stream = client.request(watch).await?;
+
+        let resp = watch_stream.message().await?.unwrap();
+        assert!(!resp.is_initialization);
+        assert!(resp.log_index > last_index);
+        let event = resp.event.unwrap();
+        assert_eq!("f_d", event.key);
+        assert_eq!(b("d"), event.current.unwrap().data);
+
+        let resp = watch_stream.message().await?.unwrap();
+        let event = resp.event.unwrap();
+        assert_eq!("f_a", event.key);
+        assert_eq!(None, event.current);
+        assert_eq!(b("a"), event.prev.unwrap().data);
+
+        client
+            .upsert_kv(UpsertKVReq::update("f_e", &b("e")))
+            .await?;
+
+        let resp = watch_stream.message().await?.unwrap();
+        let event = resp.event.unwrap();
+        assert_eq!("f_e", event.key);
+    }
+
+    Ok(())
+}
+
 #[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
 async fn test_watch_stream_count() -> common_exception::Result<()> {
     // When the client drops the stream, databend-meta should reclaim the resources.
@@ -511,6 +626,8 @@ async fn test_watch_stream_count() -> common_exception::Result<()> {
         key: "a".to_string(),
         key_end: Some("z".to_string()),
         filter_type: FilterType::All.into(),
+        initial_flush: false,
+        since_index: None,
     };
 
     let client1 = make_client(&addr)?;
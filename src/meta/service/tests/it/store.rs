@@ -16,9 +16,13 @@ use std::sync::Arc;
 use std::sync::Mutex;
 
 use common_base::base::tokio;
+use common_base::base::tokio::fs::File;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_meta_raft_store::state_machine::read_snapshot_chunks;
 use common_meta_raft_store::state_machine::testing::pretty_snapshot;
 use common_meta_raft_store::state_machine::testing::snapshot_logs;
-use common_meta_raft_store::state_machine::SerializableSnapshot;
+use common_meta_raft_store::state_machine::SnapshotKeyValue;
 use common_meta_sled_store::openraft::async_trait::async_trait;
 use common_meta_sled_store::openraft::raft::Entry;
 use common_meta_sled_store::openraft::raft::EntryPayload;
@@ -160,10 +164,8 @@ async fn test_meta_store_build_snapshot() -> anyhow::Result<()> {
 
     info!("--- check snapshot");
     {
-        let data = curr_snap.snapshot.into_inner();
-
-        let ser_snap: SerializableSnapshot = serde_json::from_slice(&data)?;
-        let res = pretty_snapshot(&ser_snap.kvs);
+        let (_, kvs) = read_snapshot(curr_snap.snapshot).await?;
+        let res = pretty_snapshot(&kvs);
         debug!("res: {:?}", res);
 
         assert_eq!(want, res);
@@ -204,10 +206,8 @@ async fn test_meta_store_current_snapshot() -> anyhow::Result<()> {
 
     info!("--- check snapshot");
     {
-        let data = curr_snap.snapshot.into_inner();
-
-        let ser_snap: SerializableSnapshot = serde_json::from_slice(&data)?;
-        let res = pretty_snapshot(&ser_snap.kvs);
+        let (_, kvs) = read_snapshot(curr_snap.snapshot).await?;
+        let res = pretty_snapshot(&kvs);
         debug!("res: {:?}", res);
 
         assert_eq!(want, res);
@@ -241,7 +241,7 @@ async fn test_meta_store_install_snapshot() -> anyhow::Result<()> {
         snap = sto.build_snapshot().await?;
     }
 
-    let data = snap.snapshot.into_inner();
+    let (data, _) = read_snapshot(snap.snapshot).await?;
 
     info!("--- reopen a new metasrv to install snapshot");
     {
@@ -252,7 +252,9 @@ async fn test_meta_store_install_snapshot() -> anyhow::Result<()> {
         info!("--- rejected because old sm is not cleaned");
         {
             sto.raft_state.write_state_machine_id(&(1, 2)).await?;
-            let res = sto.install_snapshot(&data).await;
+            let res = sto
+                .install_snapshot(std::io::Cursor::new(data.clone()))
+                .await;
             assert!(res.is_err(), "different ids disallow installing snapshot");
             assert!(
                 res.unwrap_err()
@@ -264,7 +266,10 @@ async fn test_meta_store_install_snapshot() -> anyhow::Result<()> {
         info!("--- install snapshot");
         {
             sto.raft_state.write_state_machine_id(&(0, 0)).await?;
-            sto.install_snapshot(&data).await?;
+            let nkvs = sto
+                .install_snapshot(std::io::Cursor::new(data.clone()))
+                .await?;
+            assert_eq!(want.len() as u64, nkvs);
         }
 
         info!("--- check installed meta");
@@ -287,10 +292,8 @@ async fn test_meta_store_install_snapshot() -> anyhow::Result<()> {
         info!("--- check snapshot");
         {
             let curr_snap = sto.build_snapshot().await?;
-            let data = curr_snap.snapshot.into_inner();
-
-            let ser_snap: SerializableSnapshot = serde_json::from_slice(&data)?;
-            let res = pretty_snapshot(&ser_snap.kvs);
+            let (_, kvs) = read_snapshot(curr_snap.snapshot).await?;
+            let res = pretty_snapshot(&kvs);
             debug!("res: {:?}", res);
 
             assert_eq!(want, res);
@@ -299,3 +302,80 @@ async fn test_meta_store_install_snapshot() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_meta_store_install_snapshot_retry() -> anyhow::Result<()> {
+    // - Create a snapshot
+    // - Install a snapshot broken in the middle, it fails and the old state machine is kept
+    // - Install the snapshot again
+
+    let (logs, want) = snapshot_logs();
+
+    let id = 3;
+    let snap;
+    {
+        let tc = MetaSrvTestContext::new(id);
+
+        let sto = RaftStoreBare::open_create(&tc.config.raft_config, None, Some(())).await?;
+        sto.log.append(&logs).await?;
+        for l in logs.iter() {
+            sto.state_machine.write().await.apply(l).await?;
+        }
+        snap = sto.build_snapshot().await?;
+    }
+
+    let meta = snap.meta;
+    let (data, _) = read_snapshot(snap.snapshot).await?;
+
+    let tc = MetaSrvTestContext::new(id);
+    let sto = RaftStoreBare::open_create(&tc.config.raft_config, None, Some(())).await?;
+
+    info!("--- install a snapshot broken after the first chunk");
+    {
+        let mut broken = data.clone();
+        broken.extend_from_slice(b"\n{broken chunk\n");
+
+        let mut f = sto.begin_receiving_snapshot().await?;
+        f.write_all(&broken).await?;
+        let res = RaftStorage::install_snapshot(&sto, &meta, f).await;
+        assert!(res.is_err(), "a broken snapshot can not be installed");
+
+        assert_eq!((0, 0), sto.raft_state.read_state_machine_id()?);
+        assert!(sto.get_current_snapshot().await?.is_none());
+        let last_applied = sto.state_machine.write().await.get_last_applied()?;
+        assert_eq!(None, last_applied);
+    }
+
+    info!("--- install the snapshot again");
+    {
+        let mut f = sto.begin_receiving_snapshot().await?;
+        f.write_all(&data).await?;
+        let changes = RaftStorage::install_snapshot(&sto, &meta, f).await?;
+        assert_eq!(meta.last_log_id, changes.last_applied);
+
+        assert_eq!((1, 1), sto.raft_state.read_state_machine_id()?);
+        let last_applied = sto.state_machine.write().await.get_last_applied()?;
+        assert_eq!(Some(LogId::new(1, 9)), last_applied);
+
+        let curr_snap = sto.get_current_snapshot().await?.unwrap();
+        assert_eq!(meta.snapshot_id, curr_snap.meta.snapshot_id);
+        let (_, kvs) = read_snapshot(curr_snap.snapshot).await?;
+        assert_eq!(want, pretty_snapshot(&kvs));
+    }
+
+    Ok(())
+}
+
+/// Read the data of a snapshot, and the key-values in it.
+async fn read_snapshot(
+    mut snapshot: Box<File>,
+) -> anyhow::Result<(Vec<u8>, Vec<SnapshotKeyValue>)> {
+    let mut data = vec![];
+    snapshot.read_to_end(&mut data).await?;
+
+    let mut kvs = vec![];
+    for chunk in read_snapshot_chunks(&data[..]) {
+        kvs.extend(chunk?.kvs);
+    }
+    Ok((data, kvs))
+}
@@ -105,3 +105,17 @@ pub fn get_sled_db() -> sled::Db {
 
     panic!("init_sled_db() or init_temp_sled_db() has to be called before using get_sled_db()");
 }
+
+/// Returns the path of the dir where the global sled::Db is stored.
+pub fn get_sled_db_path() -> String {
+    {
+        let guard = GLOBAL_SLED.as_ref().lock().unwrap();
+        if let Some(g) = guard.as_ref() {
+            return g.path.clone();
+        }
+    }
+
+    panic!(
+        "init_sled_db() or init_temp_sled_db() has to be called before using get_sled_db_path()"
+    );
+}
@@ -0,0 +1,139 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Weak;
+
+use common_exception::WithContext;
+use common_meta_stoerr::MetaStorageError;
+use sled::IVec;
+
+#[derive(Debug, Default)]
+pub(crate) struct FrozenState {
+    /// The values of the keys before they are written for the first time since the freezing,
+    /// None if the key did not exist.
+    pre_images: BTreeMap<IVec, Option<IVec>>,
+    /// The last key read from the tree, the keys up to it are no longer read.
+    read_to: Option<IVec>,
+}
+
+/// Keeps the values overwritten in a tree for the views of it that are frozen.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FrozenViews {
+    views: Arc<Mutex<Vec<Weak<Mutex<FrozenState>>>>>,
+}
+
+impl FrozenViews {
+    pub(crate) fn freeze(&self) -> Arc<Mutex<FrozenState>> {
+        let state = Arc::new(Mutex::new(FrozenState::default()));
+        let mut views = self.views.lock().unwrap();
+        views.retain(|view| view.strong_count() > 0);
+        views.push(Arc::downgrade(&state));
+        state
+    }
+
+    pub(crate) fn is_frozen(&self) -> bool {
+        let views = self.views.lock().unwrap();
+        views.iter().any(|view| view.strong_count() > 0)
+    }
+
+    /// Record the value of a key before it is written, it must be called before the write
+    /// is visible to the readers, e.g., before a transaction commits.
+    pub(crate) fn record(&self, key: &IVec, prev: Option<&IVec>) {
+        let mut views = self.views.lock().unwrap();
+        views.retain(|view| view.strong_count() > 0);
+        for view in views.iter().filter_map(|view| view.upgrade()) {
+            let mut state = view.lock().unwrap();
+            if matches!(&state.read_to, Some(read_to) if key <= read_to) {
+                continue;
+            }
+            state
+                .pre_images
+                .entry(key.clone())
+                .or_insert_with(|| prev.cloned());
+        }
+    }
+}
+
+/// A consistent view of a sled tree as of the time it is frozen, reading it does not block the
+/// writes to the tree.
+///
+/// The tree is read as it is, and the keys written after the freezing are read from the values
+/// they had before. Only the writes through the same `SledTree`, by a transaction or `insert()`,
+/// are tracked, and a view must be frozen when no write is in progress.
+pub struct SledFrozenView {
+    pub(crate) tree: sled::Tree,
+    pub(crate) state: Arc<Mutex<FrozenState>>,
+}
+
+impl IntoIterator for SledFrozenView {
+    type Item = Result<(IVec, IVec), MetaStorageError>;
+    type IntoIter = SledFrozenViewIter;
+
+    /// Iterate the key-values in the view, the keys removed after the freezing come last.
+    fn into_iter(self) -> SledFrozenViewIter {
+        SledFrozenViewIter {
+            live: self.tree.iter(),
+            view: self,
+            removed: None,
+        }
+    }
+}
+
+pub struct SledFrozenViewIter {
+    view: SledFrozenView,
+    live: sled::Iter,
+    removed: Option<std::vec::IntoIter<(IVec, IVec)>>,
+}
+
+impl Iterator for SledFrozenViewIter {
+    type Item = Result<(IVec, IVec), MetaStorageError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.removed.is_none() {
+            for rkv in self.live.by_ref() {
+                let (k, v) = match rkv.context(|| "iterate frozen view") {
+                    Ok(kv) => kv,
+                    Err(e) => return Some(Err(e.into())),
+                };
+
+                // A pre-image is recorded before the write is visible, the value just read is
+                // the frozen one if there is no pre-image of the key by now. The key will not
+                // be recorded after this.
+                let mut state = self.view.state.lock().unwrap();
+                state.read_to = Some(k.clone());
+                match state.pre_images.remove(&k) {
+                    None => return Some(Ok((k, v))),
+                    Some(Some(prev)) => return Some(Ok((k, prev))),
+                    Some(None) => continue,
+                }
+            }
+
+            // The keys left are removed before they are read from the tree.
+            let mut state = self.view.state.lock().unwrap();
+            let removed = std::mem::take(&mut state.pre_images)
+                .into_iter()
+                .filter_map(|(k, prev)| prev.map(|prev| (k, prev)))
+                .collect::<Vec<_>>();
+            self.removed = Some(removed.into_iter());
+        }
+
+        self.removed
+            .as_mut()
+            .and_then(|removed| removed.next())
+            .map(Ok)
+    }
+}
@@ -19,8 +19,11 @@
 //! It is used by raft for log and state machine storage.
 pub use bytes_error::SledBytesError;
 pub use db::get_sled_db;
+pub use db::get_sled_db_path;
 pub use db::init_sled_db;
 pub use db::init_temp_sled_db;
+pub use frozen_view::SledFrozenView;
+pub use frozen_view::SledFrozenViewIter;
 pub use openraft;
 pub use sled;
 pub use sled_iter::iter;
@@ -38,6 +41,7 @@ pub use store::Store;
 
 mod bytes_error;
 mod db;
+mod frozen_view;
 mod sled_iter;
 mod sled_key_space;
 mod sled_serde;
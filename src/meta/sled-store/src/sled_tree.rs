@@ -29,9 +29,11 @@ use sled::IVec;
 use tracing::debug;
 use tracing::warn;
 
+use crate::frozen_view::FrozenViews;
 use crate::sled::transaction::TransactionError;
 use crate::store::Store;
 use crate::SledBytesError;
+use crate::SledFrozenView;
 use crate::SledKeySpace;
 
 /// Get a ref to the key or to the value.
@@ -69,6 +71,9 @@ pub struct SledTree {
     sync: bool,
 
     pub tree: sled::Tree,
+
+    /// Keeps the values overwritten by the writes through this tree for its frozen views.
+    frozen: FrozenViews,
 }
 
 /// A key-value item stored in sled store.
@@ -127,10 +132,21 @@ impl SledTree {
             name: tree_name.to_string(),
             sync,
             tree: t,
+            frozen: FrozenViews::default(),
         };
         Ok(rl)
     }
 
+    /// Freeze a consistent view of the tree, the writes after it do not change the view.
+    ///
+    /// It must be called when no write is in progress, e.g., with the writes blocked by a lock.
+    pub fn freeze(&self) -> SledFrozenView {
+        SledFrozenView {
+            tree: self.tree.clone(),
+            state: self.frozen.freeze(),
+        }
+    }
+
     /// Borrows the SledTree and creates a wrapper with access limited to a specified key space `KV`.
     pub fn key_space<KV: SledKeySpace>(&self) -> AsKeySpace<KV> {
         AsKeySpace::<KV> {
@@ -160,9 +176,10 @@ impl SledTree {
         f: impl Fn(TransactionSledTree<'_>) -> Result<T, MetaStorageError>,
     ) -> Result<T, MetaStorageError> {
         let sync = sync && self.sync;
+        let frozen = &self.frozen;
 
         let result: TransactionResult<T, MetaStorageError> = self.tree.transaction(move |tree| {
-            let txn_sled_tree = TransactionSledTree::new(tree);
+            let txn_sled_tree = TransactionSledTree::new(tree, frozen.clone());
             let r = f(txn_sled_tree);
             match r {
                 Ok(r) => {
@@ -343,6 +360,11 @@ impl SledTree {
         let k = KV::serialize_key(key)?;
         let v = KV::serialize_value(value)?;
 
+        if self.frozen.is_frozen() {
+            let prev = self.tree.get(&k).context(|| format!("get_value {}", key))?;
+            self.frozen.record(&k, prev.as_ref());
+        }
+
         let prev = self
             .tree
             .insert(k, v)
@@ -391,13 +413,16 @@ pub struct TransactionSledTree<'a> {
 
     /// The changes that are collected during transaction execution.
     pub changes: Vec<Change<Vec<u8>, String>>,
+
+    frozen: FrozenViews,
 }
 
 impl<'a> TransactionSledTree<'a> {
-    pub fn new(txn_tree: &'a TransactionalTree) -> Self {
+    pub(crate) fn new(txn_tree: &'a TransactionalTree, frozen: FrozenViews) -> Self {
         Self {
             txn_tree,
             changes: vec![],
+            frozen,
         }
     }
 
@@ -439,7 +464,8 @@ impl<'a, KV: SledKeySpace> Store<KV> for TxnKeySpace<'a, KV> {
         let k = KV::serialize_key(key)?;
         let v = KV::serialize_value(value)?;
 
-        let prev = self.txn_tree.insert(k, v)?;
+        let prev = self.txn_tree.insert(k.clone(), v)?;
+        self.frozen.record(&k, prev.as_ref());
         match prev {
             Some(v) => Ok(Some(KV::deserialize_value(v)?)),
             None => Ok(None),
@@ -458,7 +484,8 @@ impl<'a, KV: SledKeySpace> Store<KV> for TxnKeySpace<'a, KV> {
 
     fn remove(&self, key: &KV::K) -> Result<Option<KV::V>, Self::Error> {
         let k = KV::serialize_key(key)?;
-        let removed = self.txn_tree.remove(k)?;
+        let removed = self.txn_tree.remove(k.clone())?;
+        self.frozen.record(&k, removed.as_ref());
 
         match removed {
             Some(v) => Ok(Some(KV::deserialize_value(v)?)),
@@ -14,6 +14,7 @@
 
 use common_base::base::tokio;
 use common_meta_sled_store::SledTree;
+use common_meta_sled_store::Store;
 use common_meta_types::Cmd;
 use common_meta_types::LogEntry;
 use common_meta_types::LogId;
@@ -638,6 +639,58 @@ async fn test_key_space_multi_types() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_freeze() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_sled_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_sled_test_context();
+    let db = &tc.db;
+    let tree = SledTree::open(db, tc.tree_name, true)?;
+    let kv_tree = tree.key_space::<GenericKV>();
+
+    let kvs = vec![
+        ("a".to_string(), SeqV::new(1, vec![])),
+        ("ab".to_string(), SeqV::new(2, vec![])),
+        ("b".to_string(), SeqV::new(3, vec![])),
+    ];
+
+    kv_tree.append(&kvs).await?;
+
+    let want = tree.freeze().into_iter().collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(3, want.len());
+
+    // Overwrite, remove and add keys after the view is frozen.
+
+    let view = tree.freeze();
+    let mut iter = view.into_iter();
+    let first = iter.next().unwrap()?;
+
+    tree.txn(false, |txn_tree| {
+        let ks = txn_tree.key_space::<GenericKV>();
+        ks.insert(&"a".to_string(), &SeqV::new(4, vec![]))?;
+        ks.insert(&"ab".to_string(), &SeqV::new(5, vec![]))?;
+        ks.remove(&"b".to_string())?;
+        ks.insert(&"c".to_string(), &SeqV::new(6, vec![]))?;
+        Ok(())
+    })?;
+
+    let mut got = vec![first];
+    for rkv in iter {
+        got.push(rkv?);
+    }
+    got.sort();
+    assert_eq!(want, got);
+
+    // The writes are visible in the tree.
+
+    assert_eq!(Some(SeqV::new(4, vec![])), kv_tree.get(&"a".to_string())?);
+    assert_eq!(None, kv_tree.get(&"b".to_string())?);
+    assert_eq!(Some(SeqV::new(6, vec![])), kv_tree.get(&"c".to_string())?);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_export() -> anyhow::Result<()> {
     let (_log_guards, ut_span) = init_sled_ut!();
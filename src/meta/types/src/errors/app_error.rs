@@ -250,6 +250,54 @@ impl UnknownTable {
     }
 }
 
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("SequenceAlreadyExists: `{sequence_name}` while `{context}`")]
+pub struct SequenceAlreadyExists {
+    sequence_name: String,
+    context: String,
+}
+
+impl SequenceAlreadyExists {
+    pub fn new(sequence_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            sequence_name: sequence_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("UnknownSequence: `{sequence_name}` while `{context}`")]
+pub struct UnknownSequence {
+    sequence_name: String,
+    context: String,
+}
+
+impl UnknownSequence {
+    pub fn new(sequence_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            sequence_name: sequence_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("SequenceOverflow: `{sequence_name}` while `{context}`")]
+pub struct SequenceOverflow {
+    sequence_name: String,
+    context: String,
+}
+
+impl SequenceOverflow {
+    pub fn new(sequence_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            sequence_name: sequence_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[error("UnknownTableId: `{table_id}` while `{context}`")]
 pub struct UnknownTableId {
@@ -486,6 +534,16 @@ pub enum AppError {
     #[error(transparent)]
     TxnRetryMaxTimes(#[from] TxnRetryMaxTimes),
 
+    // sequence api errors
+    #[error(transparent)]
+    SequenceAlreadyExists(#[from] SequenceAlreadyExists),
+
+    #[error(transparent)]
+    UnknownSequence(#[from] UnknownSequence),
+
+    #[error(transparent)]
+    SequenceOverflow(#[from] SequenceOverflow),
+
     // share api errors
     #[error(transparent)]
     ShareAlreadyExists(#[from] ShareAlreadyExists),
@@ -547,6 +605,27 @@ impl AppErrorMessage for UnknownTable {
 
 impl AppErrorMessage for UnknownTableId {}
 
+impl AppErrorMessage for SequenceAlreadyExists {
+    fn message(&self) -> String {
+        format!("Sequence '{}' already exists", self.sequence_name)
+    }
+}
+
+impl AppErrorMessage for UnknownSequence {
+    fn message(&self) -> String {
+        format!("Unknown sequence '{}'", self.sequence_name)
+    }
+}
+
+impl AppErrorMessage for SequenceOverflow {
+    fn message(&self) -> String {
+        format!(
+            "Sequence '{}' is out of the range of i64",
+            self.sequence_name
+        )
+    }
+}
+
 impl AppErrorMessage for UnknownDatabaseId {}
 
 impl AppErrorMessage for TableVersionMismatched {}
@@ -726,6 +805,9 @@ impl From<AppError> for ErrorCode {
             }
             AppError::WrongShare(err) => ErrorCode::WrongShare(err.message()),
             AppError::TxnRetryMaxTimes(err) => ErrorCode::TxnRetryMaxTimes(err.message()),
+            AppError::SequenceAlreadyExists(err) => ErrorCode::SequenceAlreadyExists(err.message()),
+            AppError::UnknownSequence(err) => ErrorCode::UnknownSequence(err.message()),
+            AppError::SequenceOverflow(err) => ErrorCode::SequenceOverflow(err.message()),
         }
     }
 }
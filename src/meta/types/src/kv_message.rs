@@ -18,19 +18,86 @@ use crate::UpsertKV;
 
 pub type UpsertKVReq = UpsertKV;
 
+/// The consistency a read request requires.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReadConsistency {
+    /// Forwarded to and served by the leader.
+    #[default]
+    Leader,
+
+    /// Served by any voter or learner,
+    /// after it applied the read index confirmed by the leader.
+    Linearizable,
+
+    /// Served by the local state machine,
+    /// which may miss the writes in the last `stale_read_max_lag` milli seconds.
+    StaleOk,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetKVReq {
     pub key: String,
+
+    #[serde(default)]
+    pub consistency: ReadConsistency,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct MGetKVReq {
     pub keys: Vec<String>,
+
+    #[serde(default)]
+    pub consistency: ReadConsistency,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ListKVReq {
     pub prefix: String,
+
+    #[serde(default)]
+    pub consistency: ReadConsistency,
+}
+
+impl GetKVReq {
+    pub fn new(key: impl ToString) -> Self {
+        Self {
+            key: key.to_string(),
+            consistency: ReadConsistency::default(),
+        }
+    }
+
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> Self {
+        self.consistency = consistency;
+        self
+    }
+}
+
+impl MGetKVReq {
+    pub fn new(keys: Vec<String>) -> Self {
+        Self {
+            keys,
+            consistency: ReadConsistency::default(),
+        }
+    }
+
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> Self {
+        self.consistency = consistency;
+        self
+    }
+}
+
+impl ListKVReq {
+    pub fn new(prefix: impl ToString) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            consistency: ReadConsistency::default(),
+        }
+    }
+
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> Self {
+        self.consistency = consistency;
+        self
+    }
 }
 
 pub type UpsertKVReply = Change<Vec<u8>>;
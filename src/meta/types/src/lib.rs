@@ -46,6 +46,7 @@ mod user_quota;
 mod user_setting;
 mod user_stage;
 mod with;
+mod workload_group;
 
 mod principal_identity;
 mod proto_display;
@@ -75,6 +76,8 @@ pub use errors::app_error::CreateTableWithDropTime;
 pub use errors::app_error::DatabaseAlreadyExists;
 pub use errors::app_error::DropDbWithDropTime;
 pub use errors::app_error::DropTableWithDropTime;
+pub use errors::app_error::SequenceAlreadyExists;
+pub use errors::app_error::SequenceOverflow;
 pub use errors::app_error::ShareAlreadyExists;
 pub use errors::app_error::TableAlreadyExists;
 pub use errors::app_error::TableVersionMismatched;
@@ -85,6 +88,7 @@ pub use errors::app_error::UndropTableHasNoHistory;
 pub use errors::app_error::UndropTableWithNoDropTime;
 pub use errors::app_error::UnknownDatabase;
 pub use errors::app_error::UnknownDatabaseId;
+pub use errors::app_error::UnknownSequence;
 pub use errors::app_error::UnknownShare;
 pub use errors::app_error::UnknownTable;
 pub use errors::app_error::UnknownTableId;
@@ -119,6 +123,7 @@ pub use kv_message::ListKVReply;
 pub use kv_message::ListKVReq;
 pub use kv_message::MGetKVReply;
 pub use kv_message::MGetKVReq;
+pub use kv_message::ReadConsistency;
 pub use kv_message::UpsertKVReply;
 pub use kv_message::UpsertKVReq;
 pub use log_entry::LogEntry;
@@ -183,3 +188,4 @@ pub use user_setting::UserSetting;
 pub use user_setting::UserSettingValue;
 pub use user_stage::*;
 pub use with::With;
+pub use workload_group::WorkloadGroup;
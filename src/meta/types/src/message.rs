@@ -33,6 +33,7 @@ use crate::LogEntry;
 use crate::MGetKVReply;
 use crate::MGetKVReq;
 use crate::NodeId;
+use crate::ReadConsistency;
 use crate::TxnOpResponse;
 use crate::TxnReply;
 
@@ -70,6 +71,22 @@ pub enum ForwardRequestBody {
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
+
+    /// Ask the leader for a log index, applying up to which is enough to serve a linearizable read.
+    #[from(ignore)]
+    ReadIndex,
+}
+
+impl ForwardRequestBody {
+    /// The consistency required if it is a read request.
+    pub fn read_consistency(&self) -> Option<ReadConsistency> {
+        match self {
+            ForwardRequestBody::GetKV(r) => Some(r.consistency),
+            ForwardRequestBody::MGetKV(r) => Some(r.consistency),
+            ForwardRequestBody::ListKV(r) => Some(r.consistency),
+            _ => None,
+        }
+    }
 }
 
 /// A request that is forwarded from one raft node to another
@@ -100,6 +117,8 @@ pub enum ForwardResponse {
     GetKV(GetKVReply),
     MGetKV(MGetKVReply),
     ListKV(ListKVReply),
+
+    ReadIndex(u64),
 }
 
 impl tonic::IntoRequest<RaftRequest> for ForwardRequest {
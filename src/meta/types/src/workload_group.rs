@@ -0,0 +1,75 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A named group of users and roles, the queries of which share the resources of a node.
+///
+/// For all the limits, 0 means unlimited.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct WorkloadGroup {
+    pub name: String,
+
+    /// The max number of queries of the group running on a node at the same time.
+    pub max_concurrency: u64,
+    /// The percentage of the node memory that the running queries of the group share.
+    pub memory_share: u64,
+    /// The max number of executor threads of a query of the group.
+    pub cpu_slots: u64,
+    /// How long in seconds a query waits for a free slot before it fails.
+    pub queue_timeout: u64,
+
+    pub users: Vec<String>,
+    pub roles: Vec<String>,
+}
+
+impl WorkloadGroup {
+    pub const DEFAULT_QUEUE_TIMEOUT: u64 = 60;
+
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            queue_timeout: Self::DEFAULT_QUEUE_TIMEOUT,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_assigned_to_user(&self, user: &str) -> bool {
+        self.users.iter().any(|u| u == user)
+    }
+
+    pub fn is_assigned_to_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+}
+
+impl TryFrom<Vec<u8>> for WorkloadGroup {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(group) => Ok(group),
+            Err(serialize_error) => Err(ErrorCode::IllegalWorkloadGroupFormat(format!(
+                "Cannot deserialize workload group from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -19,6 +19,7 @@ mod user_grant;
 mod user_info;
 mod user_privilege;
 mod user_quota;
+mod workload_group;
 
 #[test]
 fn test_bin_commit_version() -> anyhow::Result<()> {
@@ -153,6 +153,13 @@ pub enum Expr<'a> {
         args: Vec<Expr<'a>>,
         params: Vec<Literal>,
     },
+    /// Lambda expression used as an argument of higher-order functions, like `x -> x + 1`
+    /// or `(acc, x) -> acc + x`
+    Lambda {
+        span: &'a [Token<'a>],
+        params: Vec<Identifier<'a>>,
+        expr: Box<Expr<'a>>,
+    },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
         span: &'a [Token<'a>],
@@ -318,6 +325,8 @@ pub enum BinaryOperator {
     BitwiseOr,
     BitwiseAnd,
     BitwiseXor,
+    JsonPathExists,
+    JsonPathMatch,
 }
 
 impl BinaryOperator {
@@ -344,6 +353,8 @@ impl BinaryOperator {
             BinaryOperator::BitwiseOr => "bit_or".to_string(),
             BinaryOperator::BitwiseAnd => "bit_and".to_string(),
             BinaryOperator::BitwiseXor => "bit_xor".to_string(),
+            BinaryOperator::JsonPathExists => "json_path_exists".to_string(),
+            BinaryOperator::JsonPathMatch => "json_path_match".to_string(),
             _ => {
                 let name = format!("{:?}", self);
                 name.to_lowercase()
@@ -380,6 +391,7 @@ impl<'a> Expr<'a> {
             | Expr::CountAll { span }
             | Expr::Tuple { span, .. }
             | Expr::FunctionCall { span, .. }
+            | Expr::Lambda { span, .. }
             | Expr::Case { span, .. }
             | Expr::Exists { span, .. }
             | Expr::Subquery { span, .. }
@@ -513,6 +525,12 @@ impl Display for BinaryOperator {
             BinaryOperator::BitwiseXor => {
                 write!(f, "^")
             }
+            BinaryOperator::JsonPathExists => {
+                write!(f, "@?")
+            }
+            BinaryOperator::JsonPathMatch => {
+                write!(f, "@@")
+            }
         }
     }
 }
@@ -818,6 +836,16 @@ impl<'a> Display for Expr<'a> {
                 write_comma_separated_list(f, args)?;
                 write!(f, ")")?;
             }
+            Expr::Lambda { params, expr, .. } => {
+                if params.len() == 1 {
+                    write!(f, "{}", params[0])?;
+                } else {
+                    write!(f, "(")?;
+                    write_comma_separated_list(f, params)?;
+                    write!(f, ")")?;
+                }
+                write!(f, " -> {expr}")?;
+            }
             Expr::Case {
                 operand,
                 conditions,
@@ -472,6 +472,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_lambda(
+        &mut self,
+        _span: &'ast [Token<'ast>],
+        params: &'ast [Identifier<'ast>],
+        expr: &'ast Expr<'ast>,
+    ) {
+        self.visit_expr(expr);
+        let child = self.children.pop().unwrap();
+        let node_name = format!(
+            "Lambda {}",
+            params
+                .iter()
+                .map(|param| param.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let format_ctx = AstFormatContext::with_children(node_name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_case_when(
         &mut self,
         _span: &'ast [Token<'ast>],
@@ -717,6 +738,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             ExplainKind::Fragments => "Fragments",
             ExplainKind::Raw => "Raw",
             ExplainKind::Plan => "Plan",
+            ExplainKind::AnalyzePlan => "AnalyzePlan",
             ExplainKind::Memo(_) => "Memo",
         });
         let format_ctx = AstFormatContext::with_children(name, 1);
@@ -903,6 +925,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_begin(&mut self) {
+        let name = "Begin".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_commit(&mut self) {
+        let name = "Commit".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_abort(&mut self) {
+        let name = "Abort".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_set_variable(
         &mut self,
         is_global: bool,
@@ -1269,6 +1312,23 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                self.visit_table_ref(catalog, database, table);
+                let mut children = vec![self.children.pop().unwrap()];
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, children.len());
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1446,6 +1506,82 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_sequence(&mut self, stmt: &'ast CreateSequenceStmt<'ast>) {
+        let sequence_format_ctx = AstFormatContext::new(format!("Sequence {}", stmt.sequence));
+        let child = FormatTreeNode::new(sequence_format_ctx);
+
+        let name = "CreateSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_sequence(&mut self, stmt: &'ast DropSequenceStmt<'ast>) {
+        let sequence_format_ctx = AstFormatContext::new(format!("Sequence {}", stmt.sequence));
+        let child = FormatTreeNode::new(sequence_format_ctx);
+
+        let name = "DropSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt<'ast>) {
+        let index_format_ctx = AstFormatContext::new(format!("Index {}", stmt.index));
+        let index_child = FormatTreeNode::new(index_format_ctx);
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+        let column_format_ctx = AstFormatContext::new(format!("Column {}", stmt.column));
+        let column_child = FormatTreeNode::new(column_format_ctx);
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node =
+            FormatTreeNode::with_children(format_ctx, vec![index_child, table_child, column_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt<'ast>) {
+        let index_format_ctx = AstFormatContext::new(format!("Index {}", stmt.index));
+        let index_child = FormatTreeNode::new(index_format_ctx);
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_workload_group(&mut self, stmt: &'ast CreateWorkloadGroupStmt<'ast>) {
+        let mut children = Vec::new();
+        let group_format_ctx = AstFormatContext::new(format!("WorkloadGroup {}", stmt.name));
+        children.push(FormatTreeNode::new(group_format_ctx));
+        for assignee in stmt.assignees.iter() {
+            let assignee_format_ctx = AstFormatContext::new(format!("Assignee {}", assignee));
+            children.push(FormatTreeNode::new(assignee_format_ctx));
+        }
+        for (key, value) in stmt.options.iter() {
+            let option_format_ctx = AstFormatContext::new(format!("Option {} = {}", key, value));
+            children.push(FormatTreeNode::new(option_format_ctx));
+        }
+
+        let name = "CreateWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_workload_group(&mut self, stmt: &'ast DropWorkloadGroupStmt<'ast>) {
+        let group_format_ctx = AstFormatContext::new(format!("WorkloadGroup {}", stmt.name));
+        let child = FormatTreeNode::new(group_format_ctx);
+
+        let name = "DropWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_show_users(&mut self) {
         let name = "ShowUsers".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -2183,6 +2319,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 span: _,
                 subquery,
                 alias,
+                ..
             } => {
                 self.visit_query(subquery);
                 let child = self.children.pop().unwrap();
@@ -2199,13 +2336,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 span: _,
                 name,
                 params,
+                named_params,
                 alias,
+                ..
             } => {
-                let mut children = Vec::with_capacity(params.len());
+                let mut children = Vec::with_capacity(params.len() + named_params.len());
                 for param in params.iter() {
                     self.visit_expr(param);
                     children.push(self.children.pop().unwrap());
                 }
+                for (name, param) in named_params.iter() {
+                    self.visit_expr(param);
+                    let child = self.children.pop().unwrap();
+                    let format_ctx =
+                        AstFormatContext::with_children(format!("NamedArgument {}", name), 1);
+                    children.push(FormatTreeNode::with_children(format_ctx, vec![child]));
+                }
                 let func_name = format!("TableFunction {}", name);
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
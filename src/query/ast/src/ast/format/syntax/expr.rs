@@ -250,6 +250,22 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc {
             })
             .append(inline_comma(args.into_iter().map(pretty_expr)))
             .append(RcDoc::text(")")),
+        Expr::Lambda { params, expr, .. } => {
+            let params = if params.len() == 1 {
+                RcDoc::text(params[0].to_string())
+            } else {
+                RcDoc::text("(")
+                    .append(inline_comma(
+                        params
+                            .into_iter()
+                            .map(|param| RcDoc::text(param.to_string())),
+                    ))
+                    .append(RcDoc::text(")"))
+            };
+            params
+                .append(RcDoc::text(" -> "))
+                .append(pretty_expr(*expr))
+        }
         Expr::Case {
             operand,
             conditions,
@@ -261,27 +261,47 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc {
         }),
         TableReference::Subquery {
             span: _,
+            lateral,
             subquery,
             alias,
-        } => parenthenized(pretty_query(*subquery)).append(if let Some(alias) = alias {
+        } => (if lateral {
+            RcDoc::text("LATERAL").append(RcDoc::space())
+        } else {
+            RcDoc::nil()
+        })
+        .append(parenthenized(pretty_query(*subquery)))
+        .append(if let Some(alias) = alias {
             RcDoc::text(format!(" AS {alias}"))
         } else {
             RcDoc::nil()
         }),
         TableReference::TableFunction {
             span: _,
+            lateral,
             name,
             params,
+            named_params,
             alias,
-        } => RcDoc::text(name.to_string())
+        } => {
+            (if lateral {
+                RcDoc::text("LATERAL").append(RcDoc::space())
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(name.to_string()))
             .append(RcDoc::text("("))
-            .append(inline_comma(params.into_iter().map(pretty_expr)))
+            .append(inline_comma(params.into_iter().map(pretty_expr).chain(
+                named_params.into_iter().map(|(name, param)| {
+                    RcDoc::text(format!("{name} => ")).append(pretty_expr(param))
+                }),
+            )))
             .append(RcDoc::text(")"))
             .append(if let Some(alias) = alias {
                 RcDoc::text(format!(" AS {alias}"))
             } else {
                 RcDoc::nil()
-            }),
+            })
+        }
         TableReference::Join { span: _, join } => pretty_table(*join.left)
             .append(RcDoc::line())
             .append(if join.condition == JoinCondition::Natural {
@@ -159,16 +159,19 @@ pub enum TableReference<'a> {
         alias: Option<TableAlias<'a>>,
         travel_point: Option<TimeTravelPoint<'a>>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr, name => expr)[ AS alias ]`
     TableFunction {
         span: &'a [Token<'a>],
+        lateral: bool,
         name: Identifier<'a>,
         params: Vec<Expr<'a>>,
+        named_params: Vec<(Identifier<'a>, Expr<'a>)>,
         alias: Option<TableAlias<'a>>,
     },
     // Derived table, which can be a subquery or joined tables or combination of them
     Subquery {
         span: &'a [Token<'a>],
+        lateral: bool,
         subquery: Box<Query<'a>>,
         alias: Option<TableAlias<'a>>,
     },
@@ -284,12 +287,23 @@ impl<'a> Display for TableReference<'a> {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
+                named_params,
                 alias,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "{name}(")?;
                 write_comma_separated_list(f, params)?;
+                for (i, (name, value)) in named_params.iter().enumerate() {
+                    if i > 0 || !params.is_empty() {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{name} => {value}")?;
+                }
                 write!(f, ")")?;
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
@@ -297,9 +311,13 @@ impl<'a> Display for TableReference<'a> {
             }
             TableReference::Subquery {
                 span: _,
+                lateral,
                 subquery,
                 alias,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "({subquery})")?;
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
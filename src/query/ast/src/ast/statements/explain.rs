@@ -24,4 +24,6 @@ pub enum ExplainKind {
     Fragments,
     Raw,
     Plan,
+    // Execute the query and annotate the plan with the runtime profile.
+    AnalyzePlan,
 }
@@ -19,6 +19,7 @@ use std::fmt::Formatter;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_period_separated_list;
 use crate::ast::Identifier;
+use crate::ast::Literal;
 use crate::ast::Query;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -29,6 +30,8 @@ pub struct InsertStmt<'a> {
     pub columns: Vec<Identifier<'a>>,
     pub source: InsertSource<'a>,
     pub overwrite: bool,
+    // Static partition values, like `PARTITION (c_region = 'ASIA')`
+    pub partition: Vec<(Identifier<'a>, Literal)>,
 }
 
 impl Display for InsertStmt<'_> {
@@ -46,6 +49,16 @@ impl Display for InsertStmt<'_> {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if !self.partition.is_empty() {
+            write!(f, " PARTITION (")?;
+            for (i, (key, value)) in self.partition.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{key} = {value}")?;
+            }
+            write!(f, ")")?;
+        }
         if !self.columns.is_empty() {
             write!(f, " (")?;
             write_comma_separated_list(f, &self.columns)?;
@@ -17,9 +17,11 @@ mod catalog;
 mod copy;
 mod database;
 mod explain;
+mod index;
 mod insert;
 mod kill;
 mod presign;
+mod sequence;
 mod share;
 mod show;
 mod stage;
@@ -29,15 +31,18 @@ mod unset;
 mod update;
 mod user;
 mod view;
+mod workload_group;
 
 pub use call::*;
 pub use catalog::*;
 pub use copy::*;
 pub use database::*;
 pub use explain::*;
+pub use index::*;
 pub use insert::*;
 pub use kill::*;
 pub use presign::*;
+pub use sequence::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
@@ -47,3 +52,4 @@ pub use unset::*;
 pub use update::*;
 pub use user::*;
 pub use view::*;
+pub use workload_group::*;
@@ -53,6 +53,11 @@ pub enum Statement<'a> {
         object_id: String,
     },
 
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     SetVariable {
         is_global: bool,
         variable: Identifier<'a>,
@@ -112,6 +117,18 @@ pub enum Statement<'a> {
     AlterView(AlterViewStmt<'a>),
     DropView(DropViewStmt<'a>),
 
+    // Sequences
+    CreateSequence(CreateSequenceStmt<'a>),
+    DropSequence(DropSequenceStmt<'a>),
+
+    // Inverted indexes
+    CreateInvertedIndex(CreateInvertedIndexStmt<'a>),
+    DropInvertedIndex(DropInvertedIndexStmt<'a>),
+
+    // Workload groups
+    CreateWorkloadGroup(CreateWorkloadGroupStmt<'a>),
+    DropWorkloadGroup(DropWorkloadGroupStmt<'a>),
+
     // User
     ShowUsers,
     CreateUser(CreateUserStmt),
@@ -206,6 +223,7 @@ impl<'a> Display for Statement<'a> {
                     ExplainKind::Fragments => write!(f, " FRAGMENTS")?,
                     ExplainKind::Raw => write!(f, " RAW")?,
                     ExplainKind::Plan => (),
+                    ExplainKind::AnalyzePlan => write!(f, " ANALYZE")?,
                     ExplainKind::Memo(_) => write!(f, "MEMO")?,
                 }
                 write!(f, " {query}")?;
@@ -250,6 +268,9 @@ impl<'a> Display for Statement<'a> {
                 }
                 write!(f, " '{object_id}'")?;
             }
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Abort => write!(f, "ROLLBACK")?,
             Statement::SetVariable {
                 is_global,
                 variable,
@@ -300,6 +321,12 @@ impl<'a> Display for Statement<'a> {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::DropWorkloadGroup(stmt) => write!(f, "{stmt}")?,
             Statement::ShowUsers => write!(f, "SHOW USERS")?,
             Statement::ShowRoles => write!(f, "SHOW ROLES")?,
             Statement::CreateUser(stmt) => write!(f, "{stmt}")?,
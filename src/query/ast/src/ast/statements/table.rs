@@ -168,6 +168,12 @@ pub enum CreateTableSource<'a> {
         database: Option<Identifier<'a>>,
         table: Identifier<'a>,
     },
+    Clone {
+        catalog: Option<Identifier<'a>>,
+        database: Option<Identifier<'a>>,
+        table: Identifier<'a>,
+        travel_point: Option<TimeTravelPoint<'a>>,
+    },
 }
 
 impl Display for CreateTableSource<'_> {
@@ -186,6 +192,19 @@ impl Display for CreateTableSource<'_> {
                 write!(f, "LIKE ")?;
                 write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -529,9 +548,48 @@ pub struct ColumnDefinition<'a> {
     pub name: Identifier<'a>,
     pub data_type: TypeName,
     pub default_expr: Option<Box<Expr<'a>>>,
+    pub computed_expr: Option<ComputedExpr<'a>>,
+    pub identity: Option<ColumnIdentity>,
     pub comment: Option<String>,
 }
 
+/// `AUTOINCREMENT | IDENTITY [(<start>, <increment>)]`, the values are generated by a sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnIdentity {
+    pub start: Option<i64>,
+    pub increment: Option<i64>,
+}
+
+impl Display for ColumnIdentity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IDENTITY")?;
+        if self.start.is_some() || self.increment.is_some() {
+            write!(
+                f,
+                "({}, {})",
+                self.start.unwrap_or(1),
+                self.increment.unwrap_or(1)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComputedExpr<'a> {
+    Virtual(Box<Expr<'a>>),
+    Stored(Box<Expr<'a>>),
+}
+
+impl<'a> Display for ComputedExpr<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComputedExpr::Virtual(expr) => write!(f, "AS ({expr}) VIRTUAL"),
+            ComputedExpr::Stored(expr) => write!(f, "AS ({expr}) STORED"),
+        }
+    }
+}
+
 impl<'a> Display for ColumnDefinition<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} {}", self.name, self.data_type)?;
@@ -543,6 +601,12 @@ impl<'a> Display for ColumnDefinition<'a> {
         if let Some(default_expr) = &self.default_expr {
             write!(f, " DEFAULT {default_expr}")?;
         }
+        if let Some(computed_expr) = &self.computed_expr {
+            write!(f, " {computed_expr}")?;
+        }
+        if let Some(identity) = &self.identity {
+            write!(f, " {identity}")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT '{comment}'")?;
         }
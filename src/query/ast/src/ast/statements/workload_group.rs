@@ -0,0 +1,78 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::Identifier;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateWorkloadGroupStmt<'a> {
+    pub if_not_exists: bool,
+    pub name: Identifier<'a>,
+    pub assignees: Vec<WorkloadGroupAssignee>,
+    pub options: BTreeMap<String, u64>,
+}
+
+impl Display for CreateWorkloadGroupStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE WORKLOAD GROUP ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if !self.assignees.is_empty() {
+            write!(f, " TO ")?;
+            write_comma_separated_list(f, &self.assignees)?;
+        }
+        if !self.options.is_empty() {
+            write!(f, " WITH ")?;
+            write_comma_separated_list(f, self.options.iter().map(|(k, v)| format!("{k} = {v}")))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkloadGroupAssignee {
+    User(String),
+    Role(String),
+}
+
+impl Display for WorkloadGroupAssignee {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorkloadGroupAssignee::User(user) => write!(f, "USER '{user}'"),
+            WorkloadGroupAssignee::Role(role) => write!(f, "ROLE '{role}'"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropWorkloadGroupStmt<'a> {
+    pub if_exists: bool,
+    pub name: Identifier<'a>,
+}
+
+impl Display for DropWorkloadGroupStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DROP WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
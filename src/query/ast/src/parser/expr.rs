@@ -251,6 +251,11 @@ pub enum ExprElement<'a> {
         args: Vec<Expr<'a>>,
         params: Vec<Literal>,
     },
+    /// Lambda expression, like `x -> x + 1` or `(acc, x) -> acc + x`
+    Lambda {
+        params: Vec<Identifier<'a>>,
+        expr: Box<Expr<'a>>,
+    },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
         operand: Option<Box<Expr<'a>>>,
@@ -338,6 +343,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement<'a>>>> PrattParser<I> for E
                 BinaryOperator::NotRegexp => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::RLike => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::NotRLike => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::JsonPathExists => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::JsonPathMatch => Affix::Infix(Precedence(20), Associativity::Left),
 
                 BinaryOperator::BitwiseOr => Affix::Infix(Precedence(22), Associativity::Left),
                 BinaryOperator::BitwiseAnd => Affix::Infix(Precedence(22), Associativity::Left),
@@ -432,6 +439,11 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement<'a>>>> PrattParser<I> for E
                 args,
                 params,
             },
+            ExprElement::Lambda { params, expr } => Expr::Lambda {
+                span: elem.span.0,
+                params,
+                expr,
+            },
             ExprElement::Case {
                 operand,
                 conditions,
@@ -729,6 +741,22 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     let count_all = value(ExprElement::CountAll, rule! {
         COUNT ~ "(" ~ "*" ~ ^")"
     });
+    let lambda_params = alt((
+        map(ident, |param| vec![param]),
+        map(
+            rule! { "(" ~ #comma_separated_list1(ident) ~ ")" },
+            |(_, params, _)| params,
+        ),
+    ));
+    let lambda = map(
+        rule! {
+            #lambda_params ~ "->" ~ ^#subexpr(0)
+        },
+        |(params, _, expr)| ExprElement::Lambda {
+            params,
+            expr: Box::new(expr),
+        },
+    );
     let tuple = map(
         rule! {
             "(" ~ #comma_separated_list0_ignore_trailling(subexpr(0)) ~ ","? ~ ^")"
@@ -898,6 +926,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         rule!(
             #is_distinct_from: "`... IS [NOT] DISTINCT FROM ...`"
             | #count_all : "COUNT(*)"
+            | #lambda : "`<param> -> <expr>`"
             | #function_call_with_param : "<function>"
             | #function_call : "<function>"
             | #case : "`CASE ... END`"
@@ -948,6 +977,8 @@ pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
             value(BinaryOperator::BitwiseOr, rule! { "|" }),
             value(BinaryOperator::BitwiseAnd, rule! { "&" }),
             value(BinaryOperator::BitwiseXor, rule! { "^" }),
+            value(BinaryOperator::JsonPathExists, rule! { "@?" }),
+            value(BinaryOperator::JsonPathMatch, rule! { "@@" }),
         )),
     ))(i)
 }
@@ -1022,6 +1053,22 @@ pub fn literal_u64(i: Input) -> IResult<u64> {
     )(i)
 }
 
+pub fn literal_i64(i: Input) -> IResult<i64> {
+    map_res(
+        rule! {
+            Minus? ~ #literal_u64
+        },
+        |(minus, value)| {
+            let value = if minus.is_some() {
+                -(value as i128)
+            } else {
+                value as i128
+            };
+            i64::try_from(value).map_err(|_| ErrorKind::Other("integer out of range"))
+        },
+    )(i)
+}
+
 pub fn literal_f64(i: Input) -> IResult<f64> {
     map_res(
         rule! {
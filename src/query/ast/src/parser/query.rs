@@ -240,14 +240,17 @@ pub enum TableReferenceElement<'a> {
         alias: Option<TableAlias<'a>>,
         travel_point: Option<TimeTravelPoint<'a>>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr, name => expr)[ AS alias ]`
     TableFunction {
+        lateral: bool,
         name: Identifier<'a>,
         params: Vec<Expr<'a>>,
+        named_params: Vec<(Identifier<'a>, Expr<'a>)>,
         alias: Option<TableAlias<'a>>,
     },
     // Derived table, which can be a subquery or joined tables or combination of them
     Subquery {
+        lateral: bool,
         subquery: Box<Query<'a>>,
         alias: Option<TableAlias<'a>>,
     },
@@ -279,21 +282,40 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
             travel_point: travel_point_opt.map(|p| p.1),
         },
     );
-    let table_function = map(
+    let table_function_param = map(
         rule! {
-            #ident ~ "(" ~ #comma_separated_list0(expr) ~ ")" ~ #table_alias?
+            ( #function_name ~ "=>" )? ~ #expr
         },
-        |(name, _, params, _, alias)| TableReferenceElement::TableFunction {
-            name,
-            params,
-            alias,
+        |(name, value)| (name.map(|(name, _)| name), value),
+    );
+    let table_function = map(
+        rule! {
+            LATERAL? ~ #ident ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
+        },
+        |(lateral, name, _, all_params, _, alias)| {
+            let mut params = vec![];
+            let mut named_params = vec![];
+            for (param_name, value) in all_params {
+                match param_name {
+                    Some(param_name) => named_params.push((param_name, value)),
+                    None => params.push(value),
+                }
+            }
+            TableReferenceElement::TableFunction {
+                lateral: lateral.is_some(),
+                name,
+                params,
+                named_params,
+                alias,
+            }
         },
     );
     let subquery = map(
         rule! {
-            ( #parenthesized_query | #query ) ~ #table_alias?
+            LATERAL? ~ ( #parenthesized_query | #query ) ~ #table_alias?
         },
-        |(subquery, alias)| TableReferenceElement::Subquery {
+        |(lateral, subquery, alias)| TableReferenceElement::Subquery {
+            lateral: lateral.is_some(),
             subquery: Box::new(subquery),
             alias,
         },
@@ -404,17 +426,26 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement<'a>>>> PrattParse
                 travel_point,
             },
             TableReferenceElement::TableFunction {
+                lateral,
                 name,
                 params,
+                named_params,
                 alias,
             } => TableReference::TableFunction {
                 span: input.span.0,
+                lateral,
                 name,
                 params,
+                named_params,
                 alias,
             },
-            TableReferenceElement::Subquery { subquery, alias } => TableReference::Subquery {
+            TableReferenceElement::Subquery {
+                lateral,
+                subquery,
+                alias,
+            } => TableReference::Subquery {
                 span: input.span.0,
+                lateral,
                 subquery,
                 alias,
             },
@@ -82,14 +82,24 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let explain_analyze = map(
+        rule! {
+            EXPLAIN ~ ANALYZE ~ #statement
+        },
+        |(_, _, statement)| Statement::Explain {
+            kind: ExplainKind::AnalyzePlan,
+            query: Box::new(statement.stmt),
+        },
+    );
     let insert = map(
         rule! {
             INSERT ~ ( INTO | OVERWRITE ) ~ TABLE?
             ~ #peroid_separated_idents_1_to_3
+            ~ ( PARTITION ~ ^"(" ~ ^#comma_separated_list1(partition_value) ~ ^")" )?
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ #insert_source
         },
-        |(_, overwrite, _, (catalog, database, table), opt_columns, source)| {
+        |(_, overwrite, _, (catalog, database, table), opt_partition, opt_columns, source)| {
             Statement::Insert(InsertStmt {
                 catalog,
                 database,
@@ -99,6 +109,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     .unwrap_or_default(),
                 source,
                 overwrite: overwrite.kind == OVERWRITE,
+                partition: opt_partition
+                    .map(|(_, _, partition, _)| partition)
+                    .unwrap_or_default(),
             })
         },
     );
@@ -159,6 +172,11 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let start_transaction = value(Statement::Begin, rule! { START ~ TRANSACTION });
+    let commit = value(Statement::Commit, rule! { COMMIT ~ WORK? });
+    let abort = value(Statement::Abort, rule! { (ROLLBACK | ABORT) ~ WORK? });
+
     let set_variable = map(
         rule! {
             SET ~ (GLOBAL)? ~ #ident ~ "=" ~ #subexpr(0)
@@ -581,6 +599,98 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_sequence = map(
+        rule! {
+            CREATE ~ SEQUENCE ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ ( START ~ WITH? ~ ^#literal_i64 )?
+            ~ ( INCREMENT ~ BY? ~ ^#literal_i64 )?
+            ~ ( COMMENT ~ "=" ~ ^#literal_string )?
+        },
+        |(_, _, opt_if_not_exists, sequence, opt_start, opt_increment, opt_comment)| {
+            Statement::CreateSequence(CreateSequenceStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                sequence,
+                start: opt_start.map(|(_, _, start)| start),
+                increment: opt_increment.map(|(_, _, increment)| increment),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, sequence)| {
+            Statement::DropSequence(DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                sequence,
+            })
+        },
+    );
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ ON ~ #peroid_separated_idents_1_to_3
+            ~ "(" ~ ^#ident ~ ^")"
+        },
+        |(_, _, _, opt_if_not_exists, index, _, (catalog, database, table), _, column, _)| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index,
+                catalog,
+                database,
+                table,
+                column,
+            })
+        },
+    );
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ EXISTS )?
+            ~ #ident ~ ON ~ #peroid_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+    let create_workload_group = map(
+        rule! {
+            CREATE ~ WORKLOAD ~ GROUP ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ ( TO ~ ^#comma_separated_list1(workload_group_assignee) )?
+            ~ ( WITH ~ ^#comma_separated_list1(workload_group_option) )?
+        },
+        |(_, _, _, opt_if_not_exists, name, opt_assignees, opt_options)| {
+            Statement::CreateWorkloadGroup(CreateWorkloadGroupStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                assignees: opt_assignees
+                    .map(|(_, assignees)| assignees)
+                    .unwrap_or_default(),
+                options: opt_options
+                    .map(|(_, options)| BTreeMap::from_iter(options))
+                    .unwrap_or_default(),
+            })
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ GROUP ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropWorkloadGroup(DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
     let alter_view = map(
         rule! {
             ALTER ~ VIEW
@@ -980,6 +1090,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
+            | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
             | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
             | #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #delete : "`DELETE FROM <table> [WHERE ...]`"
@@ -1025,6 +1136,24 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> AS SELECT ...`"
         ),
+        rule!(
+            #create_sequence : "`CREATE SEQUENCE [IF NOT EXISTS] <sequence> [START [WITH] <start>] [INCREMENT [BY] <increment>] [COMMENT = '<comment>']`"
+            | #drop_sequence : "`DROP SEQUENCE [IF EXISTS] <sequence>`"
+        ),
+        rule!(
+            #begin : "`BEGIN [TRANSACTION]`"
+            | #start_transaction : "`START TRANSACTION`"
+            | #commit : "`COMMIT [WORK]`"
+            | #abort : "`ROLLBACK [WORK]`"
+        ),
+        rule!(
+            #create_inverted_index : "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>)`"
+            | #drop_inverted_index : "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+        ),
+        rule!(
+            #create_workload_group : "`CREATE WORKLOAD GROUP [IF NOT EXISTS] <group> [TO {USER '<user>' | ROLE '<role>'}, ...] [WITH <option> = <value>, ...]`"
+            | #drop_workload_group : "`DROP WORKLOAD GROUP [IF EXISTS] <group>`"
+        ),
         rule!(
             #show_users : "`SHOW USERS`"
             | #create_user : "`CREATE USER [IF NOT EXISTS] '<username>'@'hostname' IDENTIFIED [WITH <auth_type>] [BY <password>] [WITH <user_option>, ...]`"
@@ -1180,6 +1309,8 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     enum ColumnConstraint<'a> {
         Nullable(bool),
         DefaultExpr(Box<Expr<'a>>),
+        ComputedExpr(ComputedExpr<'a>),
+        Identity(ColumnIdentity),
     }
 
     let nullable = alt((
@@ -1192,6 +1323,46 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         },
         |(_, default_expr)| ColumnConstraint::DefaultExpr(Box::new(default_expr)),
     );
+    let computed_expr = map(
+        rule! {
+            ( GENERATED ~ ^ALWAYS )? ~ AS ~ ^"(" ~ ^#subexpr(0) ~ ^")" ~ ( STORED | VIRTUAL )?
+        },
+        |(_, _, _, expr, _, kind)| {
+            let expr = Box::new(expr);
+            match kind.map(|token| token.kind) {
+                Some(TokenKind::STORED) => {
+                    ColumnConstraint::ComputedExpr(ComputedExpr::Stored(expr))
+                }
+                _ => ColumnConstraint::ComputedExpr(ComputedExpr::Virtual(expr)),
+            }
+        },
+    );
+
+    let identity_args = alt((
+        map(
+            rule! {
+                "(" ~ ^#literal_i64 ~ ^"," ~ ^#literal_i64 ~ ^")"
+            },
+            |(_, start, _, increment, _)| (start, increment),
+        ),
+        map(
+            rule! {
+                START ~ ^#literal_i64 ~ ^INCREMENT ~ ^#literal_i64
+            },
+            |(_, start, _, increment)| (start, increment),
+        ),
+    ));
+    let identity = map(
+        rule! {
+            ( AUTOINCREMENT | IDENTITY ) ~ #identity_args?
+        },
+        |(_, opt_args)| {
+            ColumnConstraint::Identity(ColumnIdentity {
+                start: opt_args.map(|(start, _)| start),
+                increment: opt_args.map(|(_, increment)| increment),
+            })
+        },
+    );
 
     let comment = map(
         rule! {
@@ -1204,15 +1375,17 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         rule! {
             #ident
             ~ #type_name
-            ~ ( #nullable | #default_expr )*
+            ~ ( #nullable | #default_expr | #computed_expr | #identity )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <default value>] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <default value> | AS (<expr>) [STORED | VIRTUAL] | { AUTOINCREMENT | IDENTITY } [(<start>, <increment>)]] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let mut def = ColumnDefinition {
                 name,
                 data_type,
                 default_expr: None,
+                computed_expr: None,
+                identity: None,
                 comment,
             };
             for constraint in constraints {
@@ -1220,6 +1393,10 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
                     ColumnConstraint::DefaultExpr(default_expr) => {
                         def.default_expr = Some(default_expr)
                     }
+                    ColumnConstraint::ComputedExpr(computed_expr) => {
+                        def.computed_expr = Some(computed_expr)
+                    }
+                    ColumnConstraint::Identity(identity) => def.identity = Some(identity),
                     ColumnConstraint::Nullable(nullable) => {
                         if nullable {
                             def.data_type = def.data_type.wrap_nullable();
@@ -1414,10 +1591,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let clone = map(
+        rule! {
+            CLONE ~ #peroid_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)?
+        },
+        |(_, (catalog, database, table), travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: travel_point.map(|(_, point)| point),
+        },
+    );
 
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -1779,3 +1968,27 @@ pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
         UpdateExpr { name, expr }
     })(i)
 }
+
+pub fn partition_value(i: Input) -> IResult<(Identifier, Literal)> {
+    map(rule! { ( #ident ~ "=" ~ ^#literal ) }, |(key, _, value)| {
+        (key, value)
+    })(i)
+}
+
+pub fn workload_group_assignee(i: Input) -> IResult<WorkloadGroupAssignee> {
+    alt((
+        map(rule! { USER ~ #literal_string }, |(_, user)| {
+            WorkloadGroupAssignee::User(user)
+        }),
+        map(rule! { ROLE ~ #literal_string }, |(_, role)| {
+            WorkloadGroupAssignee::Role(role)
+        }),
+    ))(i)
+}
+
+pub fn workload_group_option(i: Input) -> IResult<(String, u64)> {
+    map(
+        rule! { #ident_to_string ~ "=" ~ ^#literal_u64 },
+        |(key, _, value)| (key.to_lowercase(), value),
+    )(i)
+}
@@ -238,6 +238,15 @@ pub trait Visitor<'ast>: Sized {
         }
     }
 
+    fn visit_lambda(
+        &mut self,
+        _span: &'ast [Token<'ast>],
+        _params: &'ast [Identifier<'ast>],
+        expr: &'ast Expr<'ast>,
+    ) {
+        walk_expr(self, expr);
+    }
+
     fn visit_case_when(
         &mut self,
         _span: &'ast [Token<'ast>],
@@ -365,6 +374,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_kill(&mut self, _kill_target: &'ast KillTarget, _object_id: &'ast str) {}
 
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
+
     fn visit_set_variable(
         &mut self,
         _is_global: bool,
@@ -446,6 +461,18 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt<'ast>) {}
 
+    fn visit_create_sequence(&mut self, _stmt: &'ast CreateSequenceStmt<'ast>) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &'ast DropSequenceStmt<'ast>) {}
+
+    fn visit_create_inverted_index(&mut self, _stmt: &'ast CreateInvertedIndexStmt<'ast>) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &'ast DropInvertedIndexStmt<'ast>) {}
+
+    fn visit_create_workload_group(&mut self, _stmt: &'ast CreateWorkloadGroupStmt<'ast>) {}
+
+    fn visit_drop_workload_group(&mut self, _stmt: &'ast DropWorkloadGroupStmt<'ast>) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &'ast CreateUserStmt) {}
@@ -243,6 +243,15 @@ pub trait VisitorMut: Sized {
         }
     }
 
+    fn visit_lambda(
+        &mut self,
+        _span: &mut &[Token<'_>],
+        _params: &mut [Identifier<'_>],
+        expr: &mut Expr<'_>,
+    ) {
+        walk_expr_mut(self, expr);
+    }
+
     fn visit_case_when(
         &mut self,
         _span: &mut &[Token<'_>],
@@ -368,6 +377,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_kill(&mut self, _kill_target: &mut KillTarget, _object_id: &mut String) {}
 
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
+
     fn visit_set_variable(
         &mut self,
         _is_global: bool,
@@ -451,6 +466,18 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt<'_>) {}
 
+    fn visit_create_sequence(&mut self, _stmt: &mut CreateSequenceStmt<'_>) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &mut DropSequenceStmt<'_>) {}
+
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt<'_>) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt<'_>) {}
+
+    fn visit_create_workload_group(&mut self, _stmt: &mut CreateWorkloadGroupStmt<'_>) {}
+
+    fn visit_drop_workload_group(&mut self, _stmt: &mut DropWorkloadGroupStmt<'_>) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &mut CreateUserStmt) {}
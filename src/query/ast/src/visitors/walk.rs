@@ -94,6 +94,7 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr<'a>) {
             args,
             params,
         } => visitor.visit_function_call(span, *distinct, name, args, params),
+        Expr::Lambda { span, params, expr } => visitor.visit_lambda(span, params, expr),
         Expr::Case {
             span,
             operand,
@@ -248,6 +249,7 @@ pub fn walk_table_reference<'a, V: Visitor<'a>>(
         TableReference::TableFunction {
             name,
             params,
+            named_params,
             alias,
             ..
         } => {
@@ -255,6 +257,10 @@ pub fn walk_table_reference<'a, V: Visitor<'a>>(
             for param in params {
                 visitor.visit_expr(param);
             }
+            for (name, param) in named_params {
+                visitor.visit_identifier(name);
+                visitor.visit_expr(param);
+            }
             if let Some(alias) = alias {
                 visitor.visit_identifier(&alias.name);
             }
@@ -314,6 +320,9 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             kill_target,
             object_id,
         } => visitor.visit_kill(kill_target, object_id),
+        Statement::Begin => visitor.visit_begin(),
+        Statement::Commit => visitor.visit_commit(),
+        Statement::Abort => visitor.visit_abort(),
         Statement::SetVariable {
             is_global,
             variable,
@@ -351,6 +360,12 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::CreateView(stmt) => visitor.visit_create_view(stmt),
         Statement::AlterView(stmt) => visitor.visit_alter_view(stmt),
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
+        Statement::CreateSequence(stmt) => visitor.visit_create_sequence(stmt),
+        Statement::DropSequence(stmt) => visitor.visit_drop_sequence(stmt),
+        Statement::CreateInvertedIndex(stmt) => visitor.visit_create_inverted_index(stmt),
+        Statement::DropInvertedIndex(stmt) => visitor.visit_drop_inverted_index(stmt),
+        Statement::CreateWorkloadGroup(stmt) => visitor.visit_create_workload_group(stmt),
+        Statement::DropWorkloadGroup(stmt) => visitor.visit_drop_workload_group(stmt),
         Statement::ShowUsers => visitor.visit_show_users(),
         Statement::ShowRoles => visitor.visit_show_roles(),
         Statement::CreateUser(stmt) => visitor.visit_create_user(stmt),
@@ -74,6 +74,7 @@ fn test_statement() {
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
+        r#"explain analyze select a from b;"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"create table if not exists a.b (c integer not null default 1, b varchar);"#,
@@ -81,6 +82,20 @@ fn test_statement() {
         r#"create table if not exists a.b (c tuple(m integer, n string), d tuple(integer, string));"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table t2 clone t1 at (snapshot => 'abc');"#,
+        r#"create table t (a int, b int as (a + 1) stored, c int generated always as (a * 2));"#,
+        r#"create table t (a int autoincrement, b int identity (10, -5));"#,
+        r#"create sequence if not exists seq start with 10 increment by -2 comment = 'ids';"#,
+        r#"drop sequence if exists seq;"#,
+        r#"create inverted index if not exists idx on db.t(content);"#,
+        r#"drop inverted index idx on t;"#,
+        r#"begin;"#,
+        r#"start transaction;"#,
+        r#"commit work;"#,
+        r#"rollback;"#,
+        r#"abort;"#,
+        r#"create workload group if not exists etl to user 'loader', role 'etl_role' with max_concurrency = 4, memory_share = 30;"#,
+        r#"drop workload group etl;"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
              connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900')
@@ -142,6 +157,7 @@ fn test_statement() {
         r#"insert into t (c1, c2) values (1, 2), (3, 4);"#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
+        r#"insert overwrite table t partition (k = 'v') select * from t2;"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
         r#"CREATE STAGE ~"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
@@ -535,6 +551,7 @@ fn test_expr() {
         r#"(arr[0]:a).b"#,
         r#"arr[4]["k"]"#,
         r#"a rlike '^11'"#,
+        r#"v @? '$.a ? (@ > 1)'"#,
         r#"G.E.B IS NOT NULL AND col1 not between col2 and (1 + col3) DIV sum(col4)"#,
         r#"sum(CASE WHEN n2.n_name = 'GERMANY' THEN ol_amount ELSE 0 END) / CASE WHEN sum(ol_amount) = 0 THEN 1 ELSE sum(ol_amount) END"#,
         r#"p_partkey = l_partkey
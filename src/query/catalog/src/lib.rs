@@ -24,3 +24,4 @@ pub mod table_args;
 pub mod table_context;
 pub mod table_function;
 pub mod table_mutator;
+pub mod txn;
@@ -15,14 +15,22 @@
 mod datasource;
 mod partition;
 mod partition_statistics;
+mod partition_stealing;
 mod projection;
 mod pushdown;
+mod range_partition;
+mod runtime_filter;
+mod skew_shuffle;
 mod stage_file_info;
 
 pub use datasource::*;
 pub use partition::*;
 pub use partition_statistics::PartStatistics;
+pub use partition_stealing::*;
 pub use projection::Projection;
 pub use pushdown::*;
+pub use range_partition::*;
+pub use runtime_filter::*;
+pub use skew_shuffle::*;
 pub use stage_file_info::StageFileInfo;
 pub use stage_file_info::StageFileStatus;
@@ -68,7 +68,32 @@ pub enum PartitionsShuffleKind {
     Mod,
     // Bind the Partition to executor by partition.rand() order.
     Rand,
+    // Bind the Partition to executor by consistent hashing of partition.hash(), most of the
+    // partitions are still routed to the same executor when executors join or leave.
+    ConsistentHash,
 }
+
+// Virtual nodes of each executor on the consistent hashing ring.
+const CONSISTENT_HASH_VIRTUAL_NODES: usize = 128;
+// An executor takes at most ceil(average * factor) partitions under consistent hashing.
+const CONSISTENT_HASH_LOAD_FACTOR: f64 = 1.25;
+
+/// A hash of the bytes which is stable across Rust versions and processes (FNV-1a followed by
+/// the finalizer of MurmurHash3), so the partitions keep the same executors across upgrades.
+pub fn stable_hash(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf29ce484222325_u64;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ceb9fe1a85ec53);
+    hash ^ (hash >> 33)
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
 pub struct Partitions {
     pub kind: PartitionsShuffleKind,
@@ -93,6 +118,10 @@ impl Partitions {
         executors_sort.sort();
 
         let executor_nums = executors_sort.len();
+        if self.kind == PartitionsShuffleKind::ConsistentHash {
+            return Ok(self.consistent_hash_reshuffle(&executors_sort));
+        }
+
         let partitions = match self.kind {
             PartitionsShuffleKind::Seq => self.partitions.clone(),
             PartitionsShuffleKind::Mod => {
@@ -111,6 +140,7 @@ impl Partitions {
                 parts.shuffle(&mut rng);
                 parts
             }
+            PartitionsShuffleKind::ConsistentHash => unreachable!(),
         };
 
         let mut executor_part = HashMap::default();
@@ -132,6 +162,62 @@ impl Partitions {
         }
         Ok(executor_part)
     }
+
+    // Consistent hashing with bounded loads: walk clockwise on the ring from the hash of the
+    // partition, and bind it to the first executor which is not full. The partitions of a
+    // hot spot on the ring are taken over by the next executors, rather than piling up on one.
+    fn consistent_hash_reshuffle(&self, executors: &[String]) -> HashMap<String, Partitions> {
+        let mut ring = Vec::with_capacity(executors.len() * CONSISTENT_HASH_VIRTUAL_NODES);
+        for (idx, executor) in executors.iter().enumerate() {
+            for virtual_node in 0..CONSISTENT_HASH_VIRTUAL_NODES {
+                let point = format!("{}#{}", executor, virtual_node);
+                ring.push((stable_hash(point.as_bytes()), idx));
+            }
+        }
+        ring.sort();
+
+        let average = self.partitions.len() as f64 / executors.len() as f64;
+        let capacity = (average * CONSISTENT_HASH_LOAD_FACTOR).ceil() as usize;
+
+        // Bind in the order of hash, so the result doesn't depend on the order of partitions.
+        let mut hashes = self
+            .partitions
+            .iter()
+            .enumerate()
+            .map(|(idx, part)| (part.hash(), idx))
+            .collect::<Vec<_>>();
+        hashes.sort();
+
+        let mut executors_parts = vec![vec![]; executors.len()];
+        for (hash, part_idx) in hashes {
+            let begin = ring.partition_point(|(point, _)| *point < hash);
+            for offset in 0..ring.len() {
+                let (_, executor_idx) = ring[(begin + offset) % ring.len()];
+                if executors_parts[executor_idx].len() < capacity {
+                    executors_parts[executor_idx].push(part_idx);
+                    break;
+                }
+            }
+        }
+
+        executors
+            .iter()
+            .zip(executors_parts)
+            .map(|(executor, mut parts_idx)| {
+                // Keep the partitions in the original order.
+                parts_idx.sort();
+                let parts = parts_idx
+                    .into_iter()
+                    .map(|idx| self.partitions[idx].clone())
+                    .collect();
+                // Keep the kind, the partitions of the executor can be stolen by others.
+                (
+                    executor.clone(),
+                    Partitions::create(PartitionsShuffleKind::ConsistentHash, parts),
+                )
+            })
+            .collect()
+    }
 }
 
 impl Default for Partitions {
@@ -0,0 +1,298 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::plan::PartInfoPtr;
+
+/// An executor gives up half of its unread partitions to the idle executors
+/// only if it has at least this number of them.
+const STEAL_MIN_REMAINING_PARTS: usize = 2;
+
+/// The partitions of a scan on an executor, reported to the coordinator.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct PartitionStealPartial {
+    pub scan_id: usize,
+    pub executor: String,
+    /// The number of the partitions not read yet.
+    pub remaining: usize,
+    /// All the partitions have been read, the sources are waiting for more.
+    pub idle: bool,
+    /// The number of the donate requests of the coordinator answered by this report.
+    pub answered: usize,
+    /// Partitions given up for the idle executors.
+    pub donated: Vec<PartInfoPtr>,
+}
+
+/// The instruction of the coordinator to an executor for a scan.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
+pub struct PartitionStealCommand {
+    pub scan_id: usize,
+    /// The number of the unread partitions to give up, 0 if not requested.
+    pub donate: usize,
+    /// Partitions taken from the other executors.
+    pub stolen: Vec<PartInfoPtr>,
+    /// No more partitions will be given, the sources of the scan can finish.
+    pub finished: bool,
+}
+
+struct LocalScan {
+    queue: Arc<RwLock<VecDeque<PartInfoPtr>>>,
+    idle: bool,
+    donate: usize,
+    requests: usize,
+    finished: bool,
+}
+
+#[derive(Default)]
+struct CoordinatedScan {
+    remaining: HashMap<String, usize>,
+    idle: HashSet<String>,
+    // Donate requests sent to the executors and not answered yet.
+    donating: HashMap<String, usize>,
+    pool: Vec<PartInfoPtr>,
+    finished: bool,
+    commands: HashMap<String, PartitionStealCommand>,
+}
+
+impl CoordinatedScan {
+    fn command(&mut self, scan_id: usize, executor: &str) -> &mut PartitionStealCommand {
+        self.commands
+            .entry(executor.to_string())
+            .or_insert_with(|| PartitionStealCommand {
+                scan_id,
+                ..PartitionStealCommand::default()
+            })
+    }
+
+    fn rebalance(&mut self, scan_id: usize) {
+        if self.finished || self.idle.is_empty() {
+            return;
+        }
+
+        // Hand out the donated partitions to the idle executors.
+        if !self.pool.is_empty() {
+            let mut idle = self.idle.drain().collect::<Vec<_>>();
+            idle.sort();
+
+            for (idx, part) in std::mem::take(&mut self.pool).into_iter().enumerate() {
+                let executor = &idle[idx % idle.len()];
+                *self.remaining.entry(executor.clone()).or_default() += 1;
+                self.command(scan_id, executor).stolen.push(part);
+            }
+            return;
+        }
+
+        if self.donating.values().any(|requests| *requests != 0) {
+            return;
+        }
+
+        // Ask the busiest executor to give up half of its unread partitions.
+        let donor = self
+            .remaining
+            .iter()
+            .filter(|(executor, _)| !self.idle.contains(*executor))
+            .max_by(|(a, a_remaining), (b, b_remaining)| {
+                a_remaining.cmp(b_remaining).then_with(|| b.cmp(a))
+            })
+            .map(|(executor, remaining)| (executor.clone(), *remaining));
+
+        match donor {
+            Some((donor, remaining)) if remaining >= STEAL_MIN_REMAINING_PARTS => {
+                let donate = remaining / 2;
+                self.remaining.insert(donor.clone(), remaining - donate);
+                *self.donating.entry(donor.clone()).or_default() += 1;
+                self.command(scan_id, &donor).donate += donate;
+            }
+            _ => {
+                // No executor has enough partitions to share, all of them can finish.
+                self.finished = true;
+                let executors = self.remaining.keys().cloned().collect::<Vec<_>>();
+                for executor in executors {
+                    self.command(scan_id, &executor).finished = true;
+                }
+            }
+        }
+    }
+}
+
+/// Work stealing of the scans of a distributed query. Each executor reads the partitions
+/// assigned to it, and reports the unread ones to the coordinator. When an executor has read
+/// all of them, the coordinator asks the busiest executor to give up half of its unread
+/// partitions, and hands them out to the idle executors.
+#[derive(Default)]
+pub struct PartitionStealing {
+    enabled: bool,
+    local_id: String,
+    local: HashMap<usize, LocalScan>,
+    coordinated: HashMap<usize, CoordinatedScan>,
+}
+
+impl PartitionStealing {
+    /// Enable the work stealing of the query on this executor, the sources wait for
+    /// the coordinator to finish their scans only if it is enabled.
+    pub fn enable(&mut self, local_id: &str) {
+        self.enabled = true;
+        self.local_id = local_id.to_string();
+    }
+
+    /// Register the partitions of a scan on this executor.
+    pub fn register(&mut self, scan_id: usize, queue: Arc<RwLock<VecDeque<PartInfoPtr>>>) {
+        self.local.insert(scan_id, LocalScan {
+            queue,
+            idle: false,
+            donate: 0,
+            requests: 0,
+            finished: false,
+        });
+    }
+
+    /// Take at most `num` partitions of the scan, returns None if the sources should wait for
+    /// the partitions stolen from the other executors, or empty if the scan can finish.
+    pub fn try_steal(&mut self, scan_id: usize, num: usize) -> Option<Vec<PartInfoPtr>> {
+        if !self.enabled {
+            return Some(vec![]);
+        }
+
+        let scan = match self.local.get_mut(&scan_id) {
+            None => return Some(vec![]),
+            Some(scan) => scan,
+        };
+
+        let mut queue = scan.queue.write();
+        let parts = (0..num)
+            .map_while(|_| queue.pop_front())
+            .collect::<Vec<_>>();
+
+        if !parts.is_empty() || scan.finished {
+            return Some(parts);
+        }
+
+        scan.idle = true;
+        None
+    }
+
+    /// Apply the instruction of the coordinator, returns true if the waiting sources should
+    /// be woken up.
+    pub fn install(&mut self, command: PartitionStealCommand) -> bool {
+        let scan = match self.local.get_mut(&command.scan_id) {
+            None => return false,
+            Some(scan) => scan,
+        };
+
+        if command.donate != 0 {
+            scan.donate += command.donate;
+            scan.requests += 1;
+        }
+
+        if !command.stolen.is_empty() {
+            scan.idle = false;
+            scan.queue.write().extend(command.stolen);
+        }
+
+        scan.finished |= command.finished;
+        !scan.idle || scan.finished
+    }
+
+    /// Take the reports of the unfinished scans on this executor, the partitions to give up are
+    /// taken from the tail of the queues.
+    pub fn take_outbox(&mut self) -> Vec<PartitionStealPartial> {
+        if !self.enabled {
+            return vec![];
+        }
+
+        let mut partials = Vec::with_capacity(self.local.len());
+        for (scan_id, scan) in self.local.iter_mut() {
+            if scan.finished {
+                continue;
+            }
+
+            let mut queue = scan.queue.write();
+            let donate = std::mem::take(&mut scan.donate).min(queue.len());
+            let donated = queue.split_off(queue.len() - donate).into_iter().collect();
+
+            partials.push(PartitionStealPartial {
+                scan_id: *scan_id,
+                executor: self.local_id.clone(),
+                remaining: queue.len(),
+                idle: scan.idle && queue.is_empty(),
+                answered: std::mem::take(&mut scan.requests),
+                donated,
+            });
+        }
+
+        partials
+    }
+
+    /// Merge a report received from an executor, on the coordinator.
+    pub fn merge_remote(&mut self, partial: PartitionStealPartial) {
+        let scan_id = partial.scan_id;
+        let scan = self.coordinated.entry(scan_id).or_default();
+
+        if let Some(requests) = scan.donating.get_mut(&partial.executor) {
+            *requests = requests.saturating_sub(partial.answered);
+        }
+
+        scan.pool.extend(partial.donated);
+        scan.remaining
+            .insert(partial.executor.clone(), partial.remaining);
+
+        match partial.idle {
+            true => scan.idle.insert(partial.executor.clone()),
+            false => scan.idle.remove(&partial.executor),
+        };
+
+        // An executor reports after the scan is finished, let it finish too.
+        if scan.finished {
+            scan.command(scan_id, &partial.executor).finished = true;
+        }
+    }
+
+    /// Rebalance the partitions of the scans, on the coordinator. The instructions for this
+    /// executor are applied directly, returns true if the waiting sources should be woken up.
+    pub fn rebalance(&mut self) -> bool {
+        if !self.enabled {
+            return false;
+        }
+
+        for partial in self.take_outbox() {
+            self.merge_remote(partial);
+        }
+
+        for (scan_id, scan) in self.coordinated.iter_mut() {
+            scan.rebalance(*scan_id);
+        }
+
+        let local_id = self.local_id.clone();
+        let mut wakeup = false;
+        for command in self.take_commands(&local_id) {
+            wakeup |= self.install(command);
+        }
+
+        wakeup
+    }
+
+    /// Take the instructions to be dispatched to an executor.
+    pub fn take_commands(&mut self, executor: &str) -> Vec<PartitionStealCommand> {
+        self.coordinated
+            .values_mut()
+            .filter_map(|scan| scan.commands.remove(executor))
+            .collect()
+    }
+}
@@ -18,6 +18,7 @@ use common_expression::RemoteExpr;
 use common_expression::TableSchema;
 
 use crate::plan::Projection;
+use crate::plan::RuntimeFilterDesc;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct PrewhereInfo {
@@ -46,6 +47,8 @@ pub struct PushDownInfo {
     pub limit: Option<usize>,
     /// Optional order_by expression plan, asc, null_first
     pub order_by: Vec<(RemoteExpr<String>, bool, bool)>,
+    /// Runtime filters generated by hash joins during execution
+    pub runtime_filters: Vec<RuntimeFilterDesc>,
 }
 
 impl PushDownInfo {
@@ -0,0 +1,336 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfo;
+use common_expression::BlockMetaInfoPtr;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+
+/// The max number of keys kept in a sample, more keys are compacted into quantiles.
+pub const RANGE_PARTITION_SAMPLE_SIZE: usize = 1024;
+
+/// Order of a sort key of the range partition.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RangeKeyOrder {
+    pub asc: bool,
+    pub nulls_first: bool,
+}
+
+/// Describes a range partition of a `Range` exchange, the data is partitioned
+/// by the bounds computed from the samples of all the partitions of the input.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RangePartitionDesc {
+    /// Id of the range partition, unique in a query.
+    pub id: usize,
+    pub orders: Vec<RangeKeyOrder>,
+    /// The number of samples to be merged before the bounds can be computed,
+    /// one for each node that produces the input.
+    pub partitions: usize,
+    /// The number of ranges, one for each destination node.
+    pub num_ranges: usize,
+}
+
+/// Sorted sample of the sort keys, every key represents a number of rows.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RangeSample {
+    pub keys: Vec<(Vec<Scalar>, u64)>,
+}
+
+impl RangeSample {
+    /// Create a sample from the keys sampled from `num_rows` rows.
+    pub fn create(orders: &[RangeKeyOrder], keys: Vec<Vec<Scalar>>, num_rows: u64) -> RangeSample {
+        if keys.is_empty() {
+            return RangeSample::default();
+        }
+
+        // Spread the rows over the keys.
+        let (quotient, remainder) = (num_rows / keys.len() as u64, num_rows % keys.len() as u64);
+        let keys = keys
+            .into_iter()
+            .enumerate()
+            .map(|(i, key)| (key, quotient + u64::from((i as u64) < remainder)))
+            .collect();
+
+        let mut sample = RangeSample { keys };
+        sample.compact(orders);
+        sample
+    }
+
+    /// The number of rows represented by the sample.
+    pub fn num_rows(&self) -> u64 {
+        self.keys.iter().map(|(_, rows)| rows).sum()
+    }
+
+    /// Merge the sample of another partition of the input.
+    pub fn merge(&mut self, orders: &[RangeKeyOrder], other: RangeSample) {
+        self.keys.extend(other.keys);
+        self.compact(orders);
+    }
+
+    /// Compute the bounds that split the rows into `num_ranges` ranges of roughly the same size.
+    pub fn bounds(&self, num_ranges: usize) -> Vec<Vec<Scalar>> {
+        let total = self.num_rows();
+        if total == 0 || num_ranges <= 1 {
+            return vec![];
+        }
+
+        let targets = (1..num_ranges)
+            .map(|i| (total as u128 * i as u128 / num_ranges as u128) as u64)
+            .collect::<Vec<_>>();
+        self.quantiles(&targets)
+            .into_iter()
+            .map(|index| self.keys[index].0.clone())
+            .collect()
+    }
+
+    /// Sort the keys and compact them into quantiles if there are too many.
+    fn compact(&mut self, orders: &[RangeKeyOrder]) {
+        self.keys
+            .sort_by(|(lhs, _), (rhs, _)| compare_range_keys(orders, lhs, rhs));
+
+        if self.keys.len() <= RANGE_PARTITION_SAMPLE_SIZE {
+            return;
+        }
+
+        let total = self.num_rows();
+        let size = RANGE_PARTITION_SAMPLE_SIZE as u128;
+        let targets = (0..size)
+            .map(|i| (total as u128 * (2 * i + 1) / (2 * size)) as u64)
+            .collect::<Vec<_>>();
+
+        let mut compacted = Vec::with_capacity(RANGE_PARTITION_SAMPLE_SIZE);
+        let mut represented = 0;
+        for (i, index) in self.quantiles(&targets).into_iter().enumerate() {
+            let upper = (total as u128 * (i as u128 + 1) / size) as u64;
+            compacted.push((self.keys[index].0.clone(), upper - represented));
+            represented = upper;
+        }
+        self.keys = compacted;
+    }
+
+    /// Indexes of the keys at the ascending positions of the rows.
+    fn quantiles(&self, targets: &[u64]) -> Vec<usize> {
+        let mut indexes = Vec::with_capacity(targets.len());
+        let mut index = 0;
+        let mut cumulative = 0;
+        for target in targets {
+            while index + 1 < self.keys.len() && cumulative + self.keys[index].1 <= *target {
+                cumulative += self.keys[index].1;
+                index += 1;
+            }
+            indexes.push(index);
+        }
+        indexes
+    }
+}
+
+/// Bounds of the ranges, the rows of the i-th range are not less than the (i-1)-th
+/// bound and less than the i-th bound.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RangeBounds {
+    pub orders: Vec<RangeKeyOrder>,
+    pub bounds: Vec<Vec<Scalar>>,
+}
+
+impl RangeBounds {
+    /// Get the index of the range the key belongs to.
+    pub fn partition(&self, key: &[ScalarRef]) -> usize {
+        self.bounds.partition_point(|bound| {
+            let ordering = self
+                .orders
+                .iter()
+                .zip(bound.iter().zip(key))
+                .map(|(order, (lhs, rhs))| compare_range_key(order, &lhs.as_ref(), rhs))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal);
+            ordering.is_le()
+        })
+    }
+}
+
+/// Compare two keys in the order of the sort keys.
+pub fn compare_range_keys(orders: &[RangeKeyOrder], lhs: &[Scalar], rhs: &[Scalar]) -> Ordering {
+    orders
+        .iter()
+        .zip(lhs.iter().zip(rhs))
+        .map(|(order, (lhs, rhs))| compare_range_key(order, &lhs.as_ref(), &rhs.as_ref()))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+fn compare_range_key(order: &RangeKeyOrder, lhs: &ScalarRef, rhs: &ScalarRef) -> Ordering {
+    let null_ordering = match order.nulls_first {
+        true => Ordering::Less,
+        false => Ordering::Greater,
+    };
+
+    match (lhs, rhs) {
+        (ScalarRef::Null, ScalarRef::Null) => Ordering::Equal,
+        (ScalarRef::Null, _) => null_ordering,
+        (_, ScalarRef::Null) => null_ordering.reverse(),
+        _ => {
+            let ordering = lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal);
+            match order.asc {
+                true => ordering,
+                false => ordering.reverse(),
+            }
+        }
+    }
+}
+
+/// Position of a block in the output of an ordered merge exchange, the blocks
+/// of the ranges are globally sorted in the order of `(range, seq)`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RangeBlockMeta {
+    pub range: usize,
+    pub seq: usize,
+    /// Whether it is the last block of the range.
+    pub last: bool,
+}
+
+#[typetag::serde(name = "range_block_meta")]
+impl BlockMetaInfo for RangeBlockMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        match info.as_any().downcast_ref::<RangeBlockMeta>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+}
+
+impl RangeBlockMeta {
+    pub fn create(range: usize, seq: usize, last: bool) -> BlockMetaInfoPtr {
+        Box::new(RangeBlockMeta { range, seq, last })
+    }
+
+    pub fn from_meta(info: &BlockMetaInfoPtr) -> Result<&RangeBlockMeta> {
+        match info.as_any().downcast_ref::<RangeBlockMeta>() {
+            Some(meta) => Ok(meta),
+            None => Err(ErrorCode::Internal(
+                "Cannot downcast from BlockMetaInfo to RangeBlockMeta.",
+            )),
+        }
+    }
+}
+
+/// Sample of the input built on a node.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RangePartitionPartial {
+    pub desc: RangePartitionDesc,
+    pub sample: RangeSample,
+}
+
+struct PendingRangePartition {
+    received: usize,
+    sample: RangeSample,
+}
+
+/// Range partitions of a query, it collects the samples of the inputs and
+/// serves the bounds of the ranges.
+#[derive(Default)]
+pub struct RangePartitions {
+    ready: HashMap<usize, Arc<RangeBounds>>,
+    pending: HashMap<usize, PendingRangePartition>,
+    /// Samples to be sent to the coordinator.
+    outbox: Vec<RangePartitionPartial>,
+    /// Ids of the ready bounds that should be dispatched to other nodes.
+    remote: HashSet<usize>,
+}
+
+impl RangePartitions {
+    /// Publish the sample of the input on this node.
+    pub fn publish(&mut self, desc: &RangePartitionDesc, sample: RangeSample) {
+        let partial = RangePartitionPartial {
+            desc: desc.clone(),
+            sample,
+        };
+        self.outbox.push(partial.clone());
+        self.merge_remote(partial);
+    }
+
+    /// Merge a sample received from another node of the cluster,
+    /// the bounds become ready after all the samples are merged.
+    pub fn merge_remote(&mut self, partial: RangePartitionPartial) {
+        let id = partial.desc.id;
+        if self.ready.contains_key(&id) {
+            return;
+        }
+
+        let pending = match self.pending.remove(&id) {
+            Some(mut pending) => {
+                pending.received += 1;
+                pending.sample.merge(&partial.desc.orders, partial.sample);
+                pending
+            }
+            None => PendingRangePartition {
+                received: 1,
+                sample: partial.sample,
+            },
+        };
+
+        if pending.received >= partial.desc.partitions {
+            let bounds = RangeBounds {
+                orders: partial.desc.orders.clone(),
+                bounds: pending.sample.bounds(partial.desc.num_ranges),
+            };
+            self.ready.insert(id, Arc::new(bounds));
+            self.remote.insert(id);
+        } else {
+            self.pending.insert(id, pending);
+        }
+    }
+
+    /// Install the bounds dispatched by the coordinator.
+    pub fn install(&mut self, id: usize, bounds: RangeBounds) {
+        self.pending.remove(&id);
+        self.ready.insert(id, Arc::new(bounds));
+    }
+
+    pub fn get(&self, id: usize) -> Option<Arc<RangeBounds>> {
+        self.ready.get(&id).cloned()
+    }
+
+    /// Take the samples to be sent to the coordinator.
+    pub fn take_outbox(&mut self) -> Vec<RangePartitionPartial> {
+        std::mem::take(&mut self.outbox)
+    }
+
+    /// The bounds merged on this node that should be dispatched to other nodes of the cluster.
+    pub fn remote_bounds(&self) -> Vec<(usize, Arc<RangeBounds>)> {
+        self.remote
+            .iter()
+            .filter_map(|id| self.ready.get(id).map(|bounds| (*id, bounds.clone())))
+            .collect()
+    }
+}
@@ -0,0 +1,283 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_expression::types::DataType;
+use common_expression::Scalar;
+
+/// The max number of distinct build keys kept in the in-list of a runtime filter.
+pub const RUNTIME_FILTER_IN_LIST_MAX_SIZE: usize = 1024;
+
+const BLOOM_FILTER_MIN_BITS: usize = 1 << 10;
+const BLOOM_FILTER_MAX_BITS: usize = 1 << 24;
+const BLOOM_FILTER_BITS_PER_KEY: usize = 8;
+const BLOOM_FILTER_NUM_HASHES: u64 = 3;
+
+/// Describes a runtime filter generated from the build side of a hash join
+/// and pushed down to a probe-side table scan.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RuntimeFilterDesc {
+    /// Id of the runtime filter, unique in a query.
+    pub id: usize,
+    /// Name of the table column to be filtered.
+    pub column_name: String,
+    pub data_type: DataType,
+    /// Whether the filter is built in another fragment of the cluster plan,
+    /// in which case the partial filters are merged and dispatched by the coordinator.
+    pub remote: bool,
+    /// The number of partial filters to be merged before the filter can be applied,
+    /// it's greater than 1 if the build side is partitioned among the cluster nodes.
+    pub partitions: usize,
+}
+
+/// Summary of the join keys of the build side, rows of the probe side that
+/// don't pass the filter can never be matched.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RuntimeFilter {
+    /// The min and max of the build keys, `None` if there are no keys.
+    pub min_max: Option<(Scalar, Scalar)>,
+    /// The distinct build keys, `None` if there are too many of them.
+    pub in_list: Option<Vec<Scalar>>,
+    /// Bloom filter of the hashes of the build keys.
+    pub bloom: BloomFilter,
+}
+
+impl RuntimeFilter {
+    /// Create a runtime filter from the non-null build keys and their hashes.
+    pub fn create(keys: Vec<Scalar>, hashes: &[u64]) -> RuntimeFilter {
+        let mut bloom = BloomFilter::with_capacity(hashes.len());
+        for hash in hashes {
+            bloom.insert(*hash);
+        }
+
+        let min_max = match (keys.iter().min(), keys.iter().max()) {
+            (Some(min), Some(max)) => Some((min.clone(), max.clone())),
+            _ => None,
+        };
+
+        let mut distinct = HashSet::new();
+        let mut in_list = Some(vec![]);
+        for key in keys {
+            if distinct.len() >= RUNTIME_FILTER_IN_LIST_MAX_SIZE {
+                in_list = None;
+                break;
+            }
+            distinct.insert(key);
+        }
+        if let Some(in_list) = in_list.as_mut() {
+            in_list.extend(distinct);
+            in_list.sort();
+        }
+
+        RuntimeFilter {
+            min_max,
+            in_list,
+            bloom,
+        }
+    }
+
+    /// Merge partial filter built from another partition of the build side.
+    pub fn merge(&mut self, other: RuntimeFilter) {
+        self.min_max = match (self.min_max.take(), other.min_max) {
+            (Some((min1, max1)), Some((min2, max2))) => Some((min1.min(min2), max1.max(max2))),
+            (Some(min_max), None) | (None, Some(min_max)) => Some(min_max),
+            (None, None) => None,
+        };
+
+        self.in_list = match (self.in_list.take(), other.in_list) {
+            (Some(list1), Some(list2)) => {
+                let mut distinct = list1.into_iter().collect::<HashSet<_>>();
+                distinct.extend(list2);
+                if distinct.len() > RUNTIME_FILTER_IN_LIST_MAX_SIZE {
+                    None
+                } else {
+                    let mut in_list = distinct.into_iter().collect::<Vec<_>>();
+                    in_list.sort();
+                    Some(in_list)
+                }
+            }
+            _ => None,
+        };
+
+        self.bloom.merge(other.bloom);
+    }
+
+    /// Whether the build side has no keys, so that no probe row can be matched.
+    pub fn is_empty(&self) -> bool {
+        self.min_max.is_none()
+    }
+}
+
+/// A bloom filter on 64-bit hashes. The number of bits is always power of two,
+/// so that a larger filter can be folded into a smaller one for merging.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+}
+
+impl BloomFilter {
+    pub fn with_capacity(num_keys: usize) -> BloomFilter {
+        let num_bits = (num_keys * BLOOM_FILTER_BITS_PER_KEY)
+            .next_power_of_two()
+            .clamp(BLOOM_FILTER_MIN_BITS, BLOOM_FILTER_MAX_BITS);
+        BloomFilter {
+            bits: vec![0; num_bits / 64],
+        }
+    }
+
+    pub fn insert(&mut self, hash: u64) {
+        let mask = self.mask();
+        for pos in Self::positions(hash) {
+            let pos = pos & mask;
+            self.bits[(pos / 64) as usize] |= 1 << (pos % 64);
+        }
+    }
+
+    pub fn contains(&self, hash: u64) -> bool {
+        let mask = self.mask();
+        Self::positions(hash).all(|pos| {
+            let pos = pos & mask;
+            self.bits[(pos / 64) as usize] & (1 << (pos % 64)) != 0
+        })
+    }
+
+    pub fn merge(&mut self, mut other: BloomFilter) {
+        if other.bits.len() < self.bits.len() {
+            std::mem::swap(&mut self.bits, &mut other.bits);
+        }
+        // Fold the larger filter into the size of smaller one, a bit at `pos` of
+        // the larger filter is at `pos & mask` of the smaller one.
+        let len = self.bits.len();
+        for (i, word) in other.bits.into_iter().enumerate() {
+            self.bits[i % len] |= word;
+        }
+    }
+
+    fn mask(&self) -> u64 {
+        (self.bits.len() * 64 - 1) as u64
+    }
+
+    fn positions(hash: u64) -> impl Iterator<Item = u64> {
+        let h1 = hash & 0xffff_ffff;
+        let h2 = hash >> 32;
+        (0..BLOOM_FILTER_NUM_HASHES).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)))
+    }
+}
+
+/// Partial runtime filter built from a partition of the build side.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RuntimeFilterPartial {
+    pub id: usize,
+    pub partitions: usize,
+    pub filter: RuntimeFilter,
+}
+
+struct PendingRuntimeFilter {
+    received: usize,
+    filter: RuntimeFilter,
+}
+
+/// Runtime filters of a query, it collects the filters built by hash joins
+/// and serves them to table scans.
+#[derive(Default)]
+pub struct RuntimeFilters {
+    ready: HashMap<usize, Arc<RuntimeFilter>>,
+    pending: HashMap<usize, PendingRuntimeFilter>,
+    /// Partial filters to be sent to the coordinator.
+    outbox: Vec<RuntimeFilterPartial>,
+    /// Ids of the ready filters that are built in other fragments.
+    remote: HashSet<usize>,
+}
+
+impl RuntimeFilters {
+    /// Publish a filter built by a hash join on this node.
+    pub fn publish(&mut self, desc: &RuntimeFilterDesc, filter: RuntimeFilter) {
+        if desc.remote {
+            self.outbox.push(RuntimeFilterPartial {
+                id: desc.id,
+                partitions: desc.partitions,
+                filter: filter.clone(),
+            });
+        }
+
+        self.merge(RuntimeFilterPartial {
+            id: desc.id,
+            partitions: desc.partitions,
+            filter,
+        });
+        if desc.remote && self.ready.contains_key(&desc.id) {
+            self.remote.insert(desc.id);
+        }
+    }
+
+    /// Merge a partial filter, the filter becomes ready after all partitions are merged.
+    pub fn merge(&mut self, partial: RuntimeFilterPartial) {
+        if self.ready.contains_key(&partial.id) {
+            return;
+        }
+
+        let pending = match self.pending.remove(&partial.id) {
+            Some(mut pending) => {
+                pending.received += 1;
+                pending.filter.merge(partial.filter);
+                pending
+            }
+            None => PendingRuntimeFilter {
+                received: 1,
+                filter: partial.filter,
+            },
+        };
+
+        if pending.received >= partial.partitions {
+            self.ready.insert(partial.id, Arc::new(pending.filter));
+        } else {
+            self.pending.insert(partial.id, pending);
+        }
+    }
+
+    /// Merge a partial filter received from another node of the cluster.
+    pub fn merge_remote(&mut self, partial: RuntimeFilterPartial) {
+        let id = partial.id;
+        self.merge(partial);
+        if self.ready.contains_key(&id) {
+            self.remote.insert(id);
+        }
+    }
+
+    /// Install a merged filter dispatched by the coordinator.
+    pub fn install(&mut self, id: usize, filter: RuntimeFilter) {
+        self.pending.remove(&id);
+        self.ready.insert(id, Arc::new(filter));
+    }
+
+    pub fn get(&self, id: usize) -> Option<Arc<RuntimeFilter>> {
+        self.ready.get(&id).cloned()
+    }
+
+    /// Take the partial filters to be sent to the coordinator.
+    pub fn take_outbox(&mut self) -> Vec<RuntimeFilterPartial> {
+        std::mem::take(&mut self.outbox)
+    }
+
+    /// The merged filters that should be dispatched to other nodes of the cluster.
+    pub fn remote_filters(&self) -> Vec<(usize, Arc<RuntimeFilter>)> {
+        self.remote
+            .iter()
+            .filter_map(|id| self.ready.get(id).map(|filter| (*id, filter.clone())))
+            .collect()
+    }
+}
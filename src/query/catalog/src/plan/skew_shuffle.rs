@@ -0,0 +1,197 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// A key is hot if it has more than `1 / (SKEW_HOT_KEY_FACTOR * nodes)` of the rows,
+/// i.e. it takes more than a quarter of the rows a node is expected to receive.
+pub const SKEW_HOT_KEY_FACTOR: u64 = 4;
+
+/// Describes a skew-aware shuffle, the hot keys are detected from the samples of
+/// the build side, or of the input of the aggregation, on all the nodes.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SkewShuffleDesc {
+    /// Id of the skew-aware shuffle, unique in a query.
+    pub id: usize,
+    /// The number of samples to be merged before the hot keys can be detected,
+    /// one for each node that produces the build side.
+    pub partitions: usize,
+    /// The number of nodes the rows are shuffled to.
+    pub num_nodes: usize,
+    /// Plan id of the join or the aggregation, the detected hot keys are recorded in its profile.
+    pub plan_id: usize,
+}
+
+/// Counts of the hashes of the keys on a node, only the candidates of the hot keys are kept.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SkewSample {
+    pub num_rows: u64,
+    pub counts: HashMap<u64, u64>,
+}
+
+impl SkewSample {
+    /// Create a sample from the hashes of the keys of the sampled rows.
+    pub fn create(num_nodes: usize, hashes: &[u64]) -> SkewSample {
+        let mut counts = HashMap::new();
+        for hash in hashes {
+            *counts.entry(*hash).or_insert(0) += 1;
+        }
+
+        // A globally hot key has at least half of the threshold on some node,
+        // the counts on the other nodes are missed if they are below it.
+        let num_rows = hashes.len() as u64;
+        let threshold = num_rows / (2 * SKEW_HOT_KEY_FACTOR * num_nodes as u64);
+        counts.retain(|_, count| *count > threshold);
+
+        SkewSample { num_rows, counts }
+    }
+
+    /// Merge the sample of another partition of the build side.
+    pub fn merge(&mut self, other: SkewSample) {
+        self.num_rows += other.num_rows;
+        for (hash, count) in other.counts {
+            *self.counts.entry(hash).or_insert(0) += count;
+        }
+    }
+
+    /// The hashes of the keys that are hot when the rows are shuffled to `num_nodes` nodes.
+    pub fn hot_keys(&self, num_nodes: usize) -> HotKeys {
+        if num_nodes <= 1 {
+            return HotKeys::default();
+        }
+
+        let threshold = self.num_rows / (SKEW_HOT_KEY_FACTOR * num_nodes as u64);
+        HotKeys {
+            hashes: self
+                .counts
+                .iter()
+                .filter(|(_, count)| **count > threshold)
+                .map(|(hash, _)| *hash)
+                .collect(),
+        }
+    }
+}
+
+/// Hashes of the hot keys of a skew-aware shuffle.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct HotKeys {
+    pub hashes: HashSet<u64>,
+}
+
+impl HotKeys {
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    pub fn contains(&self, hash: u64) -> bool {
+        self.hashes.contains(&hash)
+    }
+}
+
+/// Sample of the build side built on a node.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SkewShufflePartial {
+    pub desc: SkewShuffleDesc,
+    pub sample: SkewSample,
+}
+
+struct PendingSkewShuffle {
+    received: usize,
+    sample: SkewSample,
+}
+
+/// Skew-aware shuffles of a query, it collects the samples of the build sides
+/// and serves the detected hot keys.
+#[derive(Default)]
+pub struct SkewShuffles {
+    ready: HashMap<usize, Arc<HotKeys>>,
+    pending: HashMap<usize, PendingSkewShuffle>,
+    /// Samples to be sent to the coordinator.
+    outbox: Vec<SkewShufflePartial>,
+    /// Ids of the ready hot keys that should be dispatched to other nodes.
+    remote: HashSet<usize>,
+}
+
+impl SkewShuffles {
+    /// Publish the sample of the build side on this node, return the hot keys
+    /// if they are detected on this node.
+    pub fn publish(&mut self, desc: &SkewShuffleDesc, sample: SkewSample) -> Option<Arc<HotKeys>> {
+        let partial = SkewShufflePartial {
+            desc: desc.clone(),
+            sample,
+        };
+        self.outbox.push(partial.clone());
+        self.merge_remote(partial)
+    }
+
+    /// Merge a sample received from another node of the cluster, the hot keys are
+    /// detected and returned after all the samples are merged.
+    pub fn merge_remote(&mut self, partial: SkewShufflePartial) -> Option<Arc<HotKeys>> {
+        let id = partial.desc.id;
+        if self.ready.contains_key(&id) {
+            return None;
+        }
+
+        let pending = match self.pending.remove(&id) {
+            Some(mut pending) => {
+                pending.received += 1;
+                pending.sample.merge(partial.sample);
+                pending
+            }
+            None => PendingSkewShuffle {
+                received: 1,
+                sample: partial.sample,
+            },
+        };
+
+        if pending.received >= partial.desc.partitions {
+            let hot_keys = Arc::new(pending.sample.hot_keys(partial.desc.num_nodes));
+            self.ready.insert(id, hot_keys.clone());
+            self.remote.insert(id);
+            Some(hot_keys)
+        } else {
+            self.pending.insert(id, pending);
+            None
+        }
+    }
+
+    /// Install the hot keys dispatched by the coordinator.
+    pub fn install(&mut self, id: usize, hot_keys: HotKeys) {
+        self.pending.remove(&id);
+        self.ready.insert(id, Arc::new(hot_keys));
+    }
+
+    pub fn get(&self, id: usize) -> Option<Arc<HotKeys>> {
+        self.ready.get(&id).cloned()
+    }
+
+    /// Take the samples to be sent to the coordinator.
+    pub fn take_outbox(&mut self) -> Vec<SkewShufflePartial> {
+        std::mem::take(&mut self.outbox)
+    }
+
+    /// The hot keys detected on this node that should be dispatched to other nodes of the cluster.
+    pub fn remote_hot_keys(&self) -> Vec<(usize, Arc<HotKeys>)> {
+        self.remote
+            .iter()
+            .filter_map(|id| self.ready.get(id).map(|hot_keys| (*id, hot_keys.clone())))
+            .collect()
+    }
+}
@@ -105,6 +105,12 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// The partition columns, which can be given static values by `INSERT ... PARTITION (...)`.
+    /// only Hive Engine has partition columns.
+    fn partition_keys(&self) -> Vec<String> {
+        vec![]
+    }
+
     async fn alter_table_cluster_keys(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -189,6 +195,24 @@ pub trait Table: Sync + Send {
         Ok(())
     }
 
+    /// Commit the insertion of `INSERT [OVERWRITE] ... PARTITION (...)` with the static partition values,
+    /// which only overwrites the partitions matching them.
+    async fn commit_partition_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        operations: Vec<DataBlock>,
+        overwrite: bool,
+        partition: Vec<(String, Scalar)>,
+    ) -> Result<()> {
+        let (_, _, _, _) = (ctx, operations, overwrite, partition);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "insert into partitions of table {} is not supported. table engine : {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
     async fn truncate(&self, ctx: Arc<dyn TableContext>, purge: bool) -> Result<()> {
         let (_, _) = (ctx, purge);
         Ok(())
@@ -214,6 +238,19 @@ pub trait Table: Sync + Send {
         Ok(Box::new(DummyColumnStatisticsProvider))
     }
 
+    /// Returns the statistics of the terms of the column, which are read from the
+    /// inverted indexes, or None if the column is not indexed.
+    async fn term_statistics(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        column_name: &str,
+        terms: &[String],
+    ) -> Result<Option<TermStatistics>> {
+        let (_, _, _) = (ctx, column_name, terms);
+
+        Ok(None)
+    }
+
     async fn navigate_to(&self, instant: &NavigationPoint) -> Result<Arc<dyn Table>> {
         let _ = instant;
 
@@ -313,6 +350,20 @@ pub trait Table: Sync + Send {
             self.get_table_info().engine(),
         )))
     }
+
+    /// Share the data of the source table as the initial data of this table, without copying.
+    async fn clone_data_from(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: Arc<dyn Table>,
+    ) -> Result<()> {
+        let (_, _) = (ctx, source);
+        Err(ErrorCode::Unimplemented(format!(
+            "table {},  of engine type {}, does not support clone",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
 }
 
 #[async_trait::async_trait]
@@ -322,6 +373,10 @@ pub trait TableExt: Table {
         let name = table_info.name.clone();
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog())?;
+        // the running transaction reads its own writes
+        if let Some(table_info) = ctx.txn_mgr().lock().get_table_info(tid) {
+            return catalog.get_table_by_info(&table_info);
+        }
         let (ident, meta) = catalog.get_table_meta_by_id(tid).await?;
         let table_info: TableInfo = TableInfo {
             ident,
@@ -359,6 +414,15 @@ pub struct ColumnStatistics {
     pub number_of_distinct_values: u64,
 }
 
+/// Statistics of the terms of a column with inverted index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TermStatistics {
+    /// The number of the indexed rows.
+    pub num_rows: u64,
+    /// The number of the indexed rows which contain each of the terms.
+    pub doc_frequencies: Vec<u64>,
+}
+
 pub enum CompactTarget {
     Blocks,
     Segments,
@@ -37,7 +37,9 @@ use crate::cluster_info::Cluster;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
+use crate::plan::RuntimeFilter;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 #[derive(Debug)]
 pub struct ProcessInfo {
@@ -55,6 +57,8 @@ pub struct ProcessInfo {
     pub scan_progress_value: Option<ProgressValues>,
     pub mysql_connection_id: Option<u32>,
     pub created_time: SystemTime,
+    /// The workload group the running query is admitted by.
+    pub workload_group: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -80,6 +84,9 @@ pub trait TableContext: Send + Sync {
     fn get_result_progress_value(&self) -> ProgressValues;
     fn try_get_part(&self) -> Option<PartInfoPtr>;
     fn try_get_parts(&self, num: usize) -> Vec<PartInfoPtr>;
+    /// Take the partitions stolen from other nodes of the cluster when the local ones are used up,
+    /// it waits for the coordinator and returns empty if the scan can finish.
+    async fn try_steal_parts(&self, num: usize) -> Result<Vec<PartInfoPtr>>;
     // Update the context partition pool from the pipeline builder.
     fn try_set_partitions(&self, partitions: Partitions) -> Result<()>;
     fn attach_query_str(&self, kind: String, query: &str);
@@ -104,6 +111,8 @@ pub trait TableContext: Send + Sync {
     fn get_data_operator(&self) -> Result<DataOperator>;
     fn push_precommit_block(&self, block: DataBlock);
     fn consume_precommit_blocks(&self) -> Vec<DataBlock>;
+    /// Get the runtime filter if it has been built.
+    fn get_runtime_filter(&self, id: usize) -> Option<Arc<RuntimeFilter>>;
     fn try_get_function_context(&self) -> Result<FunctionContext>;
     fn get_connection_id(&self) -> String;
     fn get_settings(&self) -> Arc<Settings>;
@@ -113,4 +122,8 @@ pub trait TableContext: Send + Sync {
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
     fn set_on_error_map(&self, map: Option<HashMap<String, ErrorCode>>);
+    /// The explicit transaction of the session.
+    fn txn_mgr(&self) -> TxnManagerRef;
+    /// The id of the last finished query of the session, empty if there is none.
+    fn get_last_query_id(&self) -> String;
 }
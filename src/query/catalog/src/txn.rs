@@ -0,0 +1,130 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+/// A table which is changed by the running transaction.
+#[derive(Clone, Debug)]
+pub struct TxnTable {
+    pub catalog: String,
+    /// The table when it is first changed by the transaction, the transaction can only be
+    /// committed if the table is not changed by others since then.
+    pub origin: TableInfo,
+    /// The table meta with the latest snapshot written by the transaction.
+    pub new_table_meta: TableMeta,
+    /// The table is truncated by the transaction, its copied files are forgotten on `COMMIT`.
+    pub truncated: bool,
+}
+
+impl TxnTable {
+    /// The table seen by the statements of the transaction.
+    pub fn table_info(&self) -> TableInfo {
+        TableInfo {
+            meta: self.new_table_meta.clone(),
+            ..self.origin.clone()
+        }
+    }
+}
+
+/// The explicit transaction of a session, started by `BEGIN`.
+///
+/// The statements of the transaction write their blocks, segments and snapshots as usual,
+/// but the new table metas are buffered here instead of being committed to the meta service.
+/// `COMMIT` publishes all of them at once, `ROLLBACK` discards them.
+#[derive(Default, Debug)]
+pub struct TxnManager {
+    active: bool,
+    /// table id -> the table changed by the transaction.
+    tables: BTreeMap<u64, TxnTable>,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager::default()))
+    }
+
+    pub fn begin(&mut self) -> Result<()> {
+        if self.active {
+            return Err(ErrorCode::TransactionError(
+                "there is already a transaction in progress",
+            ));
+        }
+        self.active = true;
+        Ok(())
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Buffer the new meta of the table, instead of committing it to the meta service.
+    pub fn update_table_meta(
+        &mut self,
+        catalog: &str,
+        table_info: &TableInfo,
+        req: UpdateTableMetaReq,
+    ) -> Result<()> {
+        let table_id = table_info.ident.table_id;
+        match self.tables.get_mut(&table_id) {
+            Some(table) => {
+                // The statements of the transaction always see the table of the transaction.
+                if req.seq != MatchSeq::Exact(table.origin.ident.seq) {
+                    return Err(ErrorCode::TableVersionMismatched(format!(
+                        "table {} is changed by the transaction, but the change is based on version {:?} instead of {}",
+                        table_info.desc, req.seq, table.origin.ident.seq
+                    )));
+                }
+                table.new_table_meta = req.new_table_meta;
+            }
+            None => {
+                self.tables.insert(table_id, TxnTable {
+                    catalog: catalog.to_string(),
+                    origin: table_info.clone(),
+                    new_table_meta: req.new_table_meta,
+                    truncated: false,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Mark the table changed by the transaction as truncated.
+    pub fn truncate_table(&mut self, table_id: u64) {
+        if let Some(table) = self.tables.get_mut(&table_id) {
+            table.truncated = true;
+        }
+    }
+
+    /// The table as changed by the transaction, if it is.
+    pub fn get_table_info(&self, table_id: u64) -> Option<TableInfo> {
+        self.tables.get(&table_id).map(|table| table.table_info())
+    }
+
+    /// End the transaction, and return the tables changed by it.
+    pub fn finish(&mut self) -> Vec<TxnTable> {
+        self.active = false;
+        std::mem::take(&mut self.tables).into_values().collect()
+    }
+}
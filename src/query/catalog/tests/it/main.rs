@@ -15,3 +15,6 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod partitions;
+mod range_partition;
+mod runtime_filter;
+mod skew_shuffle;
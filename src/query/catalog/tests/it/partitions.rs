@@ -14,6 +14,9 @@
 
 use std::any::Any;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::io::Write;
@@ -21,9 +24,11 @@ use std::sync::Arc;
 
 use common_catalog::plan::PartInfo;
 use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::PartitionStealing;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PartitionsShuffleKind;
 use goldenfile::Mint;
+use parking_lot::RwLock;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 struct TestPartInfo {
@@ -172,3 +177,126 @@ fn test_partition_reshuffle() {
         writeln!(file, "{:?}", e2_parts.len()).unwrap();
     }
 }
+
+#[test]
+fn test_partition_consistent_hash_reshuffle() {
+    let executors = |nums: usize| {
+        (1..=nums)
+            .map(|idx| format!("node-{}", idx))
+            .collect::<Vec<_>>()
+    };
+
+    let assigned = |partitions: &Partitions, executors: Vec<String>| {
+        let mut assigned = HashMap::new();
+        for (executor, parts) in partitions.reshuffle(executors).unwrap() {
+            for part in parts.partitions {
+                let loc = part
+                    .as_any()
+                    .downcast_ref::<TestPartInfo>()
+                    .unwrap()
+                    .loc
+                    .clone();
+                assert!(assigned.insert(loc, executor.clone()).is_none());
+            }
+        }
+        assigned
+    };
+
+    let partitions = gen_parts(PartitionsShuffleKind::ConsistentHash, 100);
+    let assigned_3 = assigned(&partitions, executors(3));
+
+    // All the partitions are assigned, and no executor takes more than ceil(100 / 3 * 1.25).
+    assert_eq!(assigned_3.len(), 100);
+    for executor in executors(3) {
+        let parts = assigned_3.values().filter(|e| **e == executor).count();
+        assert!(parts <= 42, "{} takes {} partitions", executor, parts);
+    }
+
+    // The assignment doesn't depend on the order of partitions.
+    let mut reversed = partitions.clone();
+    reversed.partitions.reverse();
+    assert_eq!(assigned(&reversed, executors(3)), assigned_3);
+
+    // Most of the partitions stay on the same executor when an executor joins.
+    let assigned_4 = assigned(&partitions, executors(4));
+    let stay = assigned_3
+        .iter()
+        .filter(|(loc, executor)| &assigned_4[*loc] == *executor)
+        .count();
+    assert!(stay >= 60, "{} partitions stay", stay);
+
+    // Only the partitions of the left executor move when an executor leaves.
+    let assigned_2 = assigned(&partitions, executors(2));
+    let (stay, total) = assigned_3
+        .iter()
+        .filter(|(_, executor)| executor.as_str() != "node-3")
+        .fold((0, 0), |(stay, total), (loc, executor)| {
+            (stay + (&assigned_2[loc] == executor) as usize, total + 1)
+        });
+    assert!(
+        stay * 5 >= total * 4,
+        "{} of {} partitions stay",
+        stay,
+        total
+    );
+}
+
+#[test]
+fn test_partition_stealing() {
+    let locations = |parts: Vec<PartInfoPtr>| {
+        parts
+            .iter()
+            .map(|part| {
+                let part = part.as_any().downcast_ref::<TestPartInfo>().unwrap();
+                part.loc.clone()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // node-1 is the coordinator without partitions, node-2 has 10 partitions.
+    let mut coordinator = PartitionStealing::default();
+    coordinator.enable("node-1");
+    coordinator.register(0, Arc::new(RwLock::new(VecDeque::new())));
+
+    let mut executor = PartitionStealing::default();
+    executor.enable("node-2");
+    let parts = gen_parts(PartitionsShuffleKind::ConsistentHash, 10).partitions;
+    executor.register(0, Arc::new(RwLock::new(parts.into_iter().collect())));
+
+    let round = |coordinator: &mut PartitionStealing, executor: &mut PartitionStealing| {
+        for partial in executor.take_outbox() {
+            coordinator.merge_remote(partial);
+        }
+        let wakeup = coordinator.rebalance();
+        for command in coordinator.take_commands("node-2") {
+            executor.install(command);
+        }
+        wakeup
+    };
+
+    // node-1 is idle, node-2 is asked to give up half of its partitions.
+    assert!(coordinator.try_steal(0, 4).is_none());
+    assert!(!round(&mut coordinator, &mut executor));
+
+    // The donated partitions are handed out to node-1.
+    assert!(round(&mut coordinator, &mut executor));
+    let mut read = locations(coordinator.try_steal(0, 10).unwrap());
+    assert_eq!(read, vec!["5", "6", "7", "8", "9"]);
+
+    read.extend(locations(executor.try_steal(0, 10).unwrap()));
+    assert!(executor.try_steal(0, 10).is_none());
+    assert!(coordinator.try_steal(0, 10).is_none());
+
+    // No partitions left to share, both of the executors finish.
+    assert!(round(&mut coordinator, &mut executor));
+    assert_eq!(coordinator.try_steal(0, 10), Some(vec![]));
+    assert_eq!(executor.try_steal(0, 10), Some(vec![]));
+
+    let read = read.into_iter().collect::<HashSet<_>>();
+    assert_eq!(read.len(), 10);
+
+    // Without enabled, the sources never wait.
+    let mut disabled = PartitionStealing::default();
+    disabled.register(0, Arc::new(RwLock::new(VecDeque::new())));
+    assert_eq!(disabled.try_steal(0, 10), Some(vec![]));
+}
@@ -0,0 +1,139 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::plan::RangeBounds;
+use common_catalog::plan::RangeKeyOrder;
+use common_catalog::plan::RangePartitionDesc;
+use common_catalog::plan::RangePartitionPartial;
+use common_catalog::plan::RangePartitions;
+use common_catalog::plan::RangeSample;
+use common_catalog::plan::RANGE_PARTITION_SAMPLE_SIZE;
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+
+const ASC: RangeKeyOrder = RangeKeyOrder {
+    asc: true,
+    nulls_first: false,
+};
+
+fn int_key(v: i32) -> Vec<Scalar> {
+    vec![Scalar::Number(NumberScalar::Int32(v))]
+}
+
+fn int_sample(keys: impl Iterator<Item = i32>, num_rows: u64) -> RangeSample {
+    RangeSample::create(&[ASC], keys.map(int_key).collect(), num_rows)
+}
+
+fn desc(id: usize, partitions: usize, num_ranges: usize) -> RangePartitionDesc {
+    RangePartitionDesc {
+        id,
+        orders: vec![ASC],
+        partitions,
+        num_ranges,
+    }
+}
+
+#[test]
+fn test_range_sample_bounds() {
+    // Three partitions of different sizes, the keys are sampled from every 10 rows.
+    let mut sample = int_sample((0..3000).step_by(10), 3000);
+    sample.merge(&[ASC], int_sample((3000..9000).step_by(10), 6000));
+    sample.merge(&[ASC], int_sample((9000..10000).rev().step_by(10), 1000));
+
+    assert!(sample.keys.len() <= RANGE_PARTITION_SAMPLE_SIZE);
+    assert_eq!(sample.num_rows(), 10000);
+
+    let bounds = sample.bounds(4);
+    assert_eq!(bounds.len(), 3);
+    for (bound, expected) in bounds.iter().zip([2500, 5000, 7500]) {
+        match bound[0] {
+            Scalar::Number(NumberScalar::Int32(v)) => {
+                assert!((v - expected).abs() <= 20, "{} {}", v, expected)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    assert!(sample.bounds(1).is_empty());
+    assert!(RangeSample::default().bounds(4).is_empty());
+}
+
+#[test]
+fn test_range_bounds_partition() {
+    let orders = vec![
+        RangeKeyOrder {
+            asc: false,
+            nulls_first: true,
+        },
+        ASC,
+    ];
+    let bounds = RangeBounds {
+        orders,
+        bounds: vec![
+            vec![
+                Scalar::Number(NumberScalar::Int32(100)),
+                Scalar::Number(NumberScalar::Int32(0)),
+            ],
+            vec![
+                Scalar::Number(NumberScalar::Int32(10)),
+                Scalar::Number(NumberScalar::Int32(5)),
+            ],
+        ],
+    };
+
+    let partition = |a: Option<i32>, b: i32| {
+        let a = match a {
+            Some(a) => ScalarRef::Number(NumberScalar::Int32(a)),
+            None => ScalarRef::Null,
+        };
+        bounds.partition(&[a, ScalarRef::Number(NumberScalar::Int32(b))])
+    };
+
+    assert_eq!(partition(None, 0), 0);
+    assert_eq!(partition(Some(200), 0), 0);
+    assert_eq!(partition(Some(100), 0), 1);
+    assert_eq!(partition(Some(50), 0), 1);
+    assert_eq!(partition(Some(10), 4), 1);
+    assert_eq!(partition(Some(10), 5), 2);
+    assert_eq!(partition(Some(-1), 0), 2);
+}
+
+#[test]
+fn test_range_partitions_registry() {
+    let mut partitions = RangePartitions::default();
+
+    // The bounds are ready after the samples of all nodes are merged.
+    partitions.publish(&desc(0, 2, 2), int_sample(0..100, 100));
+    assert!(partitions.get(0).is_none());
+    assert_eq!(partitions.take_outbox().len(), 1);
+    assert!(partitions.take_outbox().is_empty());
+
+    partitions.merge_remote(RangePartitionPartial {
+        desc: desc(0, 2, 2),
+        sample: int_sample(100..200, 100),
+    });
+    let bounds = partitions.get(0).unwrap();
+    assert_eq!(bounds.bounds, vec![int_key(100)]);
+    assert_eq!(partitions.remote_bounds().len(), 1);
+
+    // Bounds dispatched by the coordinator.
+    partitions.publish(&desc(1, 3, 2), int_sample(0..100, 100));
+    partitions.install(1, RangeBounds {
+        orders: vec![ASC],
+        bounds: vec![int_key(42)],
+    });
+    assert_eq!(partitions.get(1).unwrap().bounds, vec![int_key(42)]);
+    assert_eq!(partitions.remote_bounds().len(), 1);
+}
@@ -0,0 +1,147 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::plan::BloomFilter;
+use common_catalog::plan::RuntimeFilter;
+use common_catalog::plan::RuntimeFilterDesc;
+use common_catalog::plan::RuntimeFilterPartial;
+use common_catalog::plan::RuntimeFilters;
+use common_catalog::plan::RUNTIME_FILTER_IN_LIST_MAX_SIZE;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
+
+fn int_keys(keys: &[i32]) -> (Vec<Scalar>, Vec<u64>) {
+    let scalars = keys
+        .iter()
+        .map(|k| Scalar::Number(NumberScalar::Int32(*k)))
+        .collect();
+    let hashes = keys
+        .iter()
+        .map(|k| (*k as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15))
+        .collect();
+    (scalars, hashes)
+}
+
+fn int_filter(keys: &[i32]) -> RuntimeFilter {
+    let (scalars, hashes) = int_keys(keys);
+    RuntimeFilter::create(scalars, &hashes)
+}
+
+fn desc(id: usize, remote: bool, partitions: usize) -> RuntimeFilterDesc {
+    RuntimeFilterDesc {
+        id,
+        column_name: "a".to_string(),
+        data_type: DataType::Number(NumberDataType::Int32),
+        remote,
+        partitions,
+    }
+}
+
+#[test]
+fn test_runtime_filter_create() {
+    let filter = int_filter(&[5, 3, 9, 3]);
+    assert_eq!(
+        filter.min_max,
+        Some((
+            Scalar::Number(NumberScalar::Int32(3)),
+            Scalar::Number(NumberScalar::Int32(9))
+        ))
+    );
+    assert_eq!(filter.in_list, Some(int_keys(&[3, 5, 9]).0));
+    assert!(!filter.is_empty());
+
+    let (_, hashes) = int_keys(&[5, 3, 9]);
+    for hash in hashes {
+        assert!(filter.bloom.contains(hash));
+    }
+
+    let empty = int_filter(&[]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.in_list, Some(vec![]));
+
+    let keys = (0..RUNTIME_FILTER_IN_LIST_MAX_SIZE as i32 + 1).collect::<Vec<_>>();
+    assert_eq!(int_filter(&keys).in_list, None);
+}
+
+#[test]
+fn test_runtime_filter_merge() {
+    let mut filter = int_filter(&[1, 2]);
+    filter.merge(int_filter(&(100..10000).collect::<Vec<_>>()));
+    assert_eq!(
+        filter.min_max,
+        Some((
+            Scalar::Number(NumberScalar::Int32(1)),
+            Scalar::Number(NumberScalar::Int32(9999))
+        ))
+    );
+    assert_eq!(filter.in_list, None);
+
+    // The bloom filters of different sizes are folded without false negative.
+    let (_, hashes) = int_keys(&[1, 2, 100, 5000, 9999]);
+    for hash in hashes {
+        assert!(filter.bloom.contains(hash));
+    }
+
+    let mut filter = int_filter(&[]);
+    filter.merge(int_filter(&[7]));
+    assert_eq!(filter.in_list, Some(int_keys(&[7]).0));
+}
+
+#[test]
+fn test_bloom_filter() {
+    let mut bloom = BloomFilter::with_capacity(1000);
+    for hash in int_keys(&(0..1000).collect::<Vec<_>>()).1 {
+        bloom.insert(hash);
+    }
+
+    let (_, hashes) = int_keys(&(1000..11000).collect::<Vec<_>>());
+    let false_positives = hashes.iter().filter(|h| bloom.contains(**h)).count();
+    assert!(false_positives < 2000, "{}", false_positives);
+}
+
+#[test]
+fn test_runtime_filters_registry() {
+    let mut filters = RuntimeFilters::default();
+
+    // Local filter is ready after being published.
+    filters.publish(&desc(0, false, 1), int_filter(&[1]));
+    assert!(filters.get(0).is_some());
+    assert!(filters.take_outbox().is_empty());
+    assert!(filters.remote_filters().is_empty());
+
+    // Partitioned filter is ready after all partitions are merged.
+    filters.publish(&desc(1, true, 2), int_filter(&[1]));
+    assert!(filters.get(1).is_none());
+    assert_eq!(filters.take_outbox().len(), 1);
+    assert!(filters.take_outbox().is_empty());
+
+    filters.merge_remote(RuntimeFilterPartial {
+        id: 1,
+        partitions: 2,
+        filter: int_filter(&[5]),
+    });
+    let merged = filters.get(1).unwrap();
+    assert_eq!(merged.in_list, Some(int_keys(&[1, 5]).0));
+    assert_eq!(filters.remote_filters().len(), 1);
+
+    // Merged filter dispatched by the coordinator.
+    filters.publish(&desc(2, true, 3), int_filter(&[1]));
+    filters.install(2, int_filter(&[1, 2, 3]));
+    assert_eq!(
+        filters.get(2).unwrap().in_list,
+        Some(int_keys(&[1, 2, 3]).0)
+    );
+}
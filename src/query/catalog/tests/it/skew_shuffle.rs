@@ -0,0 +1,88 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::plan::HotKeys;
+use common_catalog::plan::SkewSample;
+use common_catalog::plan::SkewShuffleDesc;
+use common_catalog::plan::SkewShufflePartial;
+use common_catalog::plan::SkewShuffles;
+
+fn desc(id: usize, partitions: usize, num_nodes: usize) -> SkewShuffleDesc {
+    SkewShuffleDesc {
+        id,
+        partitions,
+        num_nodes,
+        plan_id: 0,
+    }
+}
+
+// 1000 rows of distinct keys and `hot` rows of the key 0.
+fn skewed_hashes(hot: u64) -> Vec<u64> {
+    (1..=1000).chain((0..hot).map(|_| 0)).collect()
+}
+
+#[test]
+fn test_skew_sample_hot_keys() {
+    // 4 nodes, a key is hot with more than 1/16 of the rows.
+    let sample = SkewSample::create(4, &skewed_hashes(1000));
+    assert_eq!(sample.num_rows, 2000);
+    assert_eq!(sample.counts.len(), 1);
+    assert!(sample.hot_keys(4).contains(0));
+    assert!(sample.hot_keys(1).is_empty());
+
+    let sample = SkewSample::create(4, &skewed_hashes(10));
+    assert!(sample.hot_keys(4).is_empty());
+
+    // The key is hot on a node, but not in the whole build side.
+    let mut sample = SkewSample::create(4, &skewed_hashes(100));
+    sample.merge(SkewSample::create(4, &(1001..3000).collect::<Vec<_>>()));
+    assert_eq!(sample.num_rows, 3099);
+    assert!(sample.hot_keys(4).is_empty());
+
+    // The key is hot in the whole build side.
+    let mut sample = SkewSample::create(4, &skewed_hashes(400));
+    sample.merge(SkewSample::create(4, &skewed_hashes(400)));
+    assert_eq!(sample.hot_keys(4).len(), 1);
+}
+
+#[test]
+fn test_skew_shuffles_registry() {
+    let mut shuffles = SkewShuffles::default();
+
+    // The hot keys are detected after the samples of all nodes are merged.
+    assert!(
+        shuffles
+            .publish(&desc(0, 2, 2), SkewSample::create(2, &skewed_hashes(1000)))
+            .is_none()
+    );
+    assert!(shuffles.get(0).is_none());
+    assert_eq!(shuffles.take_outbox().len(), 1);
+    assert!(shuffles.take_outbox().is_empty());
+
+    let hot_keys = shuffles.merge_remote(SkewShufflePartial {
+        desc: desc(0, 2, 2),
+        sample: SkewSample::create(2, &skewed_hashes(0)),
+    });
+    assert!(hot_keys.unwrap().contains(0));
+    assert!(shuffles.get(0).unwrap().contains(0));
+    assert_eq!(shuffles.remote_hot_keys().len(), 1);
+
+    // Hot keys dispatched by the coordinator.
+    shuffles.publish(&desc(1, 3, 3), SkewSample::default());
+    shuffles.install(1, HotKeys {
+        hashes: [42].into_iter().collect(),
+    });
+    assert!(shuffles.get(1).unwrap().contains(42));
+    assert_eq!(shuffles.remote_hot_keys().len(), 1);
+}
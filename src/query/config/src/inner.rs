@@ -147,12 +147,22 @@ pub struct QueryConfig {
     pub table_engine_memory_enabled: bool,
     pub wait_timeout_mills: u64,
     pub max_query_log_size: usize,
+    /// Persist the query history into `system_history.query_history`, shared by the cluster
+    pub query_history_enabled: bool,
+    /// Interval in seconds to flush the buffered query history
+    pub query_history_flush_interval_secs: u64,
+    /// Max number of buffered query history rows before a flush
+    pub query_history_flush_batch_size: u64,
+    /// Days to keep the query history, 0 to keep it forever
+    pub query_history_retention_days: u64,
     /// Table Cached enabled
     pub table_meta_cache_enabled: bool,
     /// Max number of cached table block meta
     pub table_cache_block_meta_count: u64,
     /// Table memory cache size (mb)
     pub table_memory_cache_mb_size: u64,
+    /// Table data cache enabled, which caches the column chunks and bloom index on local disk
+    pub table_data_cache_enabled: bool,
     /// Table disk cache folder root
     pub table_disk_cache_root: String,
     /// Table disk cache size (mb)
@@ -212,9 +222,14 @@ impl Default for QueryConfig {
             table_engine_memory_enabled: true,
             wait_timeout_mills: 5000,
             max_query_log_size: 10000,
+            query_history_enabled: false,
+            query_history_flush_interval_secs: 10,
+            query_history_flush_batch_size: 1000,
+            query_history_retention_days: 30,
             table_meta_cache_enabled: false,
             table_cache_block_meta_count: 102400,
             table_memory_cache_mb_size: 256,
+            table_data_cache_enabled: false,
             table_disk_cache_root: "_cache".to_string(),
             table_disk_cache_mb_size: 1024,
             table_cache_snapshot_count: 256,
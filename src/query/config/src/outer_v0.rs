@@ -41,6 +41,8 @@ use common_storage::StorageRedisConfig as InnerStorageRedisConfig;
 use common_storage::StorageS3Config as InnerStorageS3Config;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
+use common_tracing::OTLPConfig as InnerOTLPLogConfig;
+use common_tracing::OTLPProtocol;
 use common_tracing::StderrConfig as InnerStderrLogConfig;
 use common_users::idm_config::IDMConfig as InnerIDMConfig;
 use serde::Deserialize;
@@ -1251,6 +1253,22 @@ pub struct QueryConfig {
     #[clap(long, default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// Persist the query history into `system_history.query_history`, shared by the cluster
+    #[clap(long)]
+    pub query_history_enabled: bool,
+
+    /// Interval in seconds to flush the buffered query history
+    #[clap(long, default_value = "10")]
+    pub query_history_flush_interval_secs: u64,
+
+    /// Max number of buffered query history rows before a flush
+    #[clap(long, default_value = "1000")]
+    pub query_history_flush_batch_size: u64,
+
+    /// Days to keep the query history, 0 to keep it forever
+    #[clap(long, default_value = "30")]
+    pub query_history_retention_days: u64,
+
     /// Table Meta Cached enabled
     #[clap(long, default_value = "true")]
     pub table_meta_cache_enabled: bool,
@@ -1263,6 +1281,10 @@ pub struct QueryConfig {
     #[clap(long, default_value = "256")]
     pub table_memory_cache_mb_size: u64,
 
+    /// Table data cache enabled, which caches the column chunks and bloom index on local disk
+    #[clap(long)]
+    pub table_data_cache_enabled: bool,
+
     /// Table disk cache folder root
     #[clap(long, default_value = "_cache")]
     pub table_disk_cache_root: String,
@@ -1366,9 +1388,14 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             wait_timeout_mills: self.wait_timeout_mills,
             max_query_log_size: self.max_query_log_size,
+            query_history_enabled: self.query_history_enabled,
+            query_history_flush_interval_secs: self.query_history_flush_interval_secs,
+            query_history_flush_batch_size: self.query_history_flush_batch_size,
+            query_history_retention_days: self.query_history_retention_days,
             table_meta_cache_enabled: self.table_meta_cache_enabled,
             table_cache_block_meta_count: self.table_cache_block_meta_count,
             table_memory_cache_mb_size: self.table_memory_cache_mb_size,
+            table_data_cache_enabled: self.table_data_cache_enabled,
             table_disk_cache_root: self.table_disk_cache_root,
             table_disk_cache_mb_size: self.table_disk_cache_mb_size,
             table_cache_snapshot_count: self.table_cache_snapshot_count,
@@ -1431,9 +1458,14 @@ impl From<InnerQueryConfig> for QueryConfig {
             database_engine_github_enabled: true,
             wait_timeout_mills: inner.wait_timeout_mills,
             max_query_log_size: inner.max_query_log_size,
+            query_history_enabled: inner.query_history_enabled,
+            query_history_flush_interval_secs: inner.query_history_flush_interval_secs,
+            query_history_flush_batch_size: inner.query_history_flush_batch_size,
+            query_history_retention_days: inner.query_history_retention_days,
             table_meta_cache_enabled: inner.table_meta_cache_enabled,
             table_cache_block_meta_count: inner.table_cache_block_meta_count,
             table_memory_cache_mb_size: inner.table_memory_cache_mb_size,
+            table_data_cache_enabled: inner.table_data_cache_enabled,
             table_disk_cache_root: inner.table_disk_cache_root,
             table_disk_cache_mb_size: inner.table_disk_cache_mb_size,
             table_cache_snapshot_count: inner.table_cache_snapshot_count,
@@ -1478,6 +1510,9 @@ pub struct LogConfig {
 
     #[clap(flatten)]
     pub stderr: StderrLogConfig,
+
+    #[clap(flatten)]
+    pub otlp: OTLPLogConfig,
 }
 
 impl Default for LogConfig {
@@ -1501,6 +1536,7 @@ impl TryInto<InnerLogConfig> for LogConfig {
         Ok(InnerLogConfig {
             file,
             stderr: self.stderr.try_into()?,
+            otlp: self.otlp.try_into()?,
         })
     }
 }
@@ -1513,6 +1549,7 @@ impl From<InnerLogConfig> for LogConfig {
             query_enabled: false,
             file: inner.file.into(),
             stderr: inner.stderr.into(),
+            otlp: inner.otlp.into(),
         }
     }
 }
@@ -1615,6 +1652,61 @@ impl From<InnerStderrLogConfig> for StderrLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct OTLPLogConfig {
+    /// Export traces, metrics and logs to an OpenTelemetry collector
+    #[clap(long = "log-otlp-on")]
+    #[serde(rename = "on")]
+    pub otlp_on: bool,
+
+    #[clap(long = "log-otlp-level", default_value = "INFO")]
+    #[serde(rename = "level")]
+    pub otlp_level: String,
+
+    /// The address of the collector
+    #[clap(long = "log-otlp-endpoint", default_value = "http://127.0.0.1:4317")]
+    #[serde(rename = "endpoint")]
+    pub otlp_endpoint: String,
+
+    /// The protocol to export with <grpc|http>
+    #[clap(long = "log-otlp-protocol", default_value = "grpc")]
+    #[serde(rename = "protocol")]
+    pub otlp_protocol: String,
+}
+
+impl Default for OTLPLogConfig {
+    fn default() -> Self {
+        InnerOTLPLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerOTLPLogConfig> for OTLPLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerOTLPLogConfig> {
+        self.otlp_protocol.parse::<OTLPProtocol>()?;
+
+        Ok(InnerOTLPLogConfig {
+            on: self.otlp_on,
+            level: self.otlp_level,
+            endpoint: self.otlp_endpoint,
+            protocol: self.otlp_protocol,
+        })
+    }
+}
+
+impl From<InnerOTLPLogConfig> for OTLPLogConfig {
+    fn from(inner: InnerOTLPLogConfig) -> Self {
+        Self {
+            otlp_on: inner.on,
+            otlp_level: inner.level,
+            otlp_endpoint: inner.endpoint,
+            otlp_protocol: inner.protocol,
+        }
+    }
+}
+
 /// Meta config group.
 /// deny_unknown_fields to check unknown field, like the deprecated `address`.
 /// TODO(xuanwo): All meta_xxx should be rename to xxx.
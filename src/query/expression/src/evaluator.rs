@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 #[cfg(debug_assertions)]
 use std::sync::Mutex;
 
 use common_arrow::arrow::bitmap;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use itertools::Itertools;
 use tracing::error;
 
@@ -30,7 +33,12 @@ use crate::types::any::AnyType;
 use crate::types::array::ArrayColumn;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableDomain;
+use crate::types::number::NumberDataType;
+use crate::types::number::NumberType;
+use crate::types::number::F64;
 use crate::types::DataType;
+use crate::types::NullableType;
+use crate::types::ValueType;
 use crate::utils::arrow::constant_bitmap;
 use crate::utils::calculate_function_domain;
 use crate::utils::eval_function;
@@ -165,6 +173,22 @@ impl<'a> Evaluator<'a> {
                     self.run_cast(span.clone(), expr.data_type(), dest_type, value)
                 }
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                ..
+            } => {
+                let value = self.run(&args[0])?;
+                let lambda_expr = lambda_expr.as_expr(self.fn_registry).ok_or_else(|| {
+                    (
+                        span.clone(),
+                        format!("unable to resolve lambda function of `{name}`"),
+                    )
+                })?;
+                self.run_lambda(span.clone(), name, args[0].data_type(), value, &lambda_expr)
+            }
         };
 
         #[cfg(debug_assertions)]
@@ -474,6 +498,327 @@ impl<'a> Evaluator<'a> {
         }
     }
 
+    /// Evaluate a higher-order function by running the lambda body on the flattened
+    /// elements of the array argument.
+    fn run_lambda(
+        &self,
+        span: Span,
+        name: &str,
+        src_type: &DataType,
+        value: Value<AnyType>,
+        lambda_expr: &Expr,
+    ) -> Result<Value<AnyType>> {
+        match (src_type, value) {
+            (DataType::Null, Value::Column(col)) => {
+                Ok(Value::Column(Column::Null { len: col.len() }))
+            }
+            (_, Value::Scalar(Scalar::Null)) => Ok(Value::Scalar(Scalar::Null)),
+            (_, Value::Scalar(Scalar::EmptyArray)) => match name {
+                "array_reduce" => Ok(Value::Scalar(Scalar::Null)),
+                _ => Ok(Value::Scalar(Scalar::EmptyArray)),
+            },
+            (_, Value::Column(Column::EmptyArray { len })) => match name {
+                "array_reduce" => Ok(Value::Column(Column::Null { len })),
+                _ => Ok(Value::Column(Column::EmptyArray { len })),
+            },
+            (DataType::Nullable(box inner_ty), Value::Scalar(scalar)) => {
+                self.run_lambda(span, name, inner_ty, Value::Scalar(scalar), lambda_expr)
+            }
+            (DataType::Nullable(box inner_ty), Value::Column(Column::Nullable(col))) => {
+                let column = self
+                    .run_lambda(span, name, inner_ty, Value::Column(col.column), lambda_expr)?
+                    .into_column()
+                    .unwrap();
+                Ok(Value::Column(wrap_validity(column, &col.validity)))
+            }
+            (DataType::Array(box elem_ty), Value::Scalar(Scalar::Array(values))) => {
+                let array = ArrayColumn {
+                    offsets: vec![0, values.len() as u64].into(),
+                    values,
+                };
+                let column = self.run_lambda_array(span, name, elem_ty, array, lambda_expr)?;
+                Ok(Value::Scalar(column.index(0).unwrap().to_owned()))
+            }
+            (DataType::Array(box elem_ty), Value::Column(Column::Array(array))) => {
+                let column = self.run_lambda_array(span, name, elem_ty, *array, lambda_expr)?;
+                Ok(Value::Column(column))
+            }
+            (_, other) => unreachable!("source: {}", other),
+        }
+    }
+
+    fn run_lambda_array(
+        &self,
+        span: Span,
+        name: &str,
+        elem_ty: &DataType,
+        array: ArrayColumn<AnyType>,
+        lambda_expr: &Expr,
+    ) -> Result<Column> {
+        // A sliced array column shares the whole values column, so rebase the offsets
+        // to make them index into the values directly.
+        let first = array.offsets[0] as usize;
+        let last = *array.offsets.last().unwrap() as usize;
+        let values = array.values.slice(first..last);
+        let offsets: Vec<u64> = array.offsets.iter().map(|o| o - first as u64).collect();
+        let num_rows = offsets.len() - 1;
+
+        match name {
+            "array_transform" | "array_apply" => {
+                let block = DataBlock::new(
+                    vec![BlockEntry {
+                        data_type: elem_ty.clone(),
+                        value: Value::Column(values.clone()),
+                    }],
+                    values.len(),
+                );
+                let result = self.run_lambda_body(&block, lambda_expr)?;
+                Ok(Column::Array(Box::new(ArrayColumn {
+                    values: result,
+                    offsets: offsets.into(),
+                })))
+            }
+            "array_filter" => {
+                let block = DataBlock::new(
+                    vec![BlockEntry {
+                        data_type: elem_ty.clone(),
+                        value: Value::Column(values.clone()),
+                    }],
+                    values.len(),
+                );
+                let result = self.run_lambda_body(&block, lambda_expr)?;
+                let bitmap = match DataBlock::cast_to_nonull_boolean(&Value::Column(result)) {
+                    Some(Value::Column(bitmap)) => bitmap,
+                    _ => unreachable!("lambda of array_filter must return boolean"),
+                };
+                let mut new_offsets = Vec::with_capacity(num_rows + 1);
+                new_offsets.push(0);
+                for (start, end) in offsets.iter().tuple_windows() {
+                    let kept = (*start..*end)
+                        .filter(|i| bitmap.get_bit(*i as usize))
+                        .count() as u64;
+                    new_offsets.push(new_offsets.last().unwrap() + kept);
+                }
+                Ok(Column::Array(Box::new(ArrayColumn {
+                    values: values.filter(&bitmap),
+                    offsets: new_offsets.into(),
+                })))
+            }
+            "array_sort" => {
+                // Merge sort the elements of all the arrays at once. The comparisons of one
+                // step of all the merges are evaluated in a batch, so that O(n*log(n)) pairs
+                // of elements are compared instead of all the n^2 pairs.
+                let float_ty =
+                    DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float64)));
+                let compare = |lhs: &[u32], rhs: &[u32]| -> Result<Vec<f64>> {
+                    let block = DataBlock::new(
+                        vec![
+                            BlockEntry {
+                                data_type: elem_ty.clone(),
+                                value: Value::Column(values.take(lhs)),
+                            },
+                            BlockEntry {
+                                data_type: elem_ty.clone(),
+                                value: Value::Column(values.take(rhs)),
+                            },
+                        ],
+                        lhs.len(),
+                    );
+                    let result = self.run_lambda_body(&block, lambda_expr)?;
+                    let result = self
+                        .run_cast(
+                            span.clone(),
+                            lambda_expr.data_type(),
+                            &float_ty,
+                            Value::Column(result),
+                        )?
+                        .into_column()
+                        .unwrap();
+                    let result =
+                        NullableType::<NumberType<F64>>::try_downcast_column(&result).unwrap();
+                    Ok((0..lhs.len())
+                        .map(|pos| match result.validity.get_bit(pos) {
+                            true => result.column[pos].0,
+                            false => 0.0,
+                        })
+                        .collect())
+                };
+
+                struct Merge {
+                    left: usize,
+                    left_end: usize,
+                    right: usize,
+                    right_end: usize,
+                    out: usize,
+                }
+
+                let mut order = (0..values.len() as u32).collect::<Vec<_>>();
+                let mut merged = order.clone();
+                let max_len = offsets
+                    .iter()
+                    .tuple_windows()
+                    .map(|(start, end)| (end - start) as usize)
+                    .max()
+                    .unwrap_or(0);
+                let mut width = 1;
+                while width < max_len {
+                    // Merge the sorted runs of `width` elements of every array in pairs.
+                    let mut merges = Vec::new();
+                    for (start, end) in offsets.iter().tuple_windows() {
+                        let (start, end) = (*start as usize, *end as usize);
+                        for lo in (start..end).step_by(2 * width) {
+                            let mid = (lo + width).min(end);
+                            merges.push(Merge {
+                                left: lo,
+                                left_end: mid,
+                                right: mid,
+                                right_end: (lo + 2 * width).min(end),
+                                out: lo,
+                            });
+                        }
+                    }
+
+                    loop {
+                        // The rest of a run is taken as it is once the other run is used up.
+                        merges.retain_mut(|m| {
+                            if m.left < m.left_end && m.right < m.right_end {
+                                return true;
+                            }
+                            for pos in (m.left..m.left_end).chain(m.right..m.right_end) {
+                                merged[m.out] = order[pos];
+                                m.out += 1;
+                            }
+                            false
+                        });
+                        if merges.is_empty() {
+                            break;
+                        }
+
+                        let lhs = merges.iter().map(|m| order[m.left]).collect::<Vec<_>>();
+                        let rhs = merges.iter().map(|m| order[m.right]).collect::<Vec<_>>();
+                        for (m, result) in merges.iter_mut().zip(compare(&lhs, &rhs)?) {
+                            // The left element goes first unless it's greater, the sort is stable.
+                            if result.partial_cmp(&0.0) == Some(Ordering::Greater) {
+                                merged[m.out] = order[m.right];
+                                m.right += 1;
+                            } else {
+                                merged[m.out] = order[m.left];
+                                m.left += 1;
+                            }
+                            m.out += 1;
+                        }
+                    }
+
+                    std::mem::swap(&mut order, &mut merged);
+                    width *= 2;
+                }
+
+                Ok(Column::Array(Box::new(ArrayColumn {
+                    values: values.take(&order),
+                    offsets: offsets.into(),
+                })))
+            }
+            "array_reduce" => {
+                // The accumulator is bound to the first lambda parameter, whose type is
+                // the same as the lambda body.
+                let acc_ty = lambda_expr.data_type().clone();
+                if acc_ty == DataType::Null {
+                    return Ok(Column::Null { len: num_rows });
+                }
+                let non_empty = offsets
+                    .iter()
+                    .tuple_windows()
+                    .enumerate()
+                    .filter(|(_, (start, end))| start < end)
+                    .map(|(row, _)| row)
+                    .collect::<Vec<_>>();
+                if non_empty.is_empty() {
+                    let mut builder =
+                        ColumnBuilder::with_capacity(&acc_ty.wrap_nullable(), num_rows);
+                    for _ in 0..num_rows {
+                        builder.push_default();
+                    }
+                    return Ok(builder.build());
+                }
+
+                // The accumulator of each non-empty array starts with its first element.
+                let first = non_empty
+                    .iter()
+                    .map(|row| offsets[*row] as u32)
+                    .collect::<Vec<_>>();
+                let mut acc = self
+                    .run_cast(
+                        span.clone(),
+                        elem_ty,
+                        &acc_ty,
+                        Value::Column(values.take(&first)),
+                    )?
+                    .into_column()
+                    .unwrap();
+
+                // Fold the k-th element of every array that is long enough at once.
+                let max_len = non_empty
+                    .iter()
+                    .map(|row| offsets[row + 1] - offsets[*row])
+                    .max()
+                    .unwrap();
+                for k in 1..max_len {
+                    let (mut active, mut positions) = (Vec::new(), Vec::new());
+                    for (i, row) in non_empty.iter().enumerate() {
+                        if offsets[row + 1] - offsets[*row] > k {
+                            active.push(i as u32);
+                            positions.push((offsets[*row] + k) as u32);
+                        }
+                    }
+                    let block = DataBlock::new(
+                        vec![
+                            BlockEntry {
+                                data_type: acc_ty.clone(),
+                                value: Value::Column(acc.take(&active)),
+                            },
+                            BlockEntry {
+                                data_type: elem_ty.clone(),
+                                value: Value::Column(values.take(&positions)),
+                            },
+                        ],
+                        active.len(),
+                    );
+                    let result = self.run_lambda_body(&block, lambda_expr)?;
+                    let result = self
+                        .run_cast(
+                            span.clone(),
+                            lambda_expr.data_type(),
+                            &acc_ty,
+                            Value::Column(result),
+                        )?
+                        .into_column()
+                        .unwrap();
+
+                    let mut merged = (0..acc.len() as u32).collect::<Vec<_>>();
+                    for (i, pos) in active.iter().enumerate() {
+                        merged[*pos as usize] = (acc.len() + i) as u32;
+                    }
+                    acc = Column::concat(&[acc, result]).take(&merged);
+                }
+
+                let mut indices = vec![0u32; num_rows];
+                let mut validity = MutableBitmap::from_len_zeroed(num_rows);
+                for (i, row) in non_empty.iter().enumerate() {
+                    indices[*row] = i as u32;
+                    validity.set(*row, true);
+                }
+                Ok(wrap_validity(acc.take(&indices), &validity.into()))
+            }
+            _ => unreachable!("unknown lambda function: {}", name),
+        }
+    }
+
+    fn run_lambda_body(&self, block: &DataBlock, lambda_expr: &Expr) -> Result<Column> {
+        let evaluator = Evaluator::new(block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(lambda_expr)?;
+        Ok(result.convert_to_full_column(lambda_expr.data_type(), block.num_rows()))
+    }
+
     fn run_simple_cast(
         &self,
         span: Span,
@@ -500,6 +845,20 @@ impl<'a> Evaluator<'a> {
     }
 }
 
+fn wrap_validity(column: Column, validity: &Bitmap) -> Column {
+    match column {
+        Column::Null { .. } => column,
+        Column::Nullable(col) => Column::Nullable(Box::new(NullableColumn {
+            column: col.column,
+            validity: (&col.validity) & validity,
+        })),
+        column => Column::Nullable(Box::new(NullableColumn {
+            column,
+            validity: validity.clone(),
+        })),
+    }
+}
+
 pub struct ConstantFolder<'a, Index: ColumnIndex> {
     input_domains: HashMap<Index, Domain>,
     func_ctx: FunctionContext,
@@ -722,6 +1081,49 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
 
                 (func_expr, func_domain)
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => {
+                let args_expr = args
+                    .iter()
+                    .map(|arg| self.fold_once(arg).0)
+                    .collect::<Vec<_>>();
+                let all_args_is_scalar = args_expr.iter().all(|arg| arg.as_constant().is_some());
+
+                let func_expr = Expr::LambdaFunctionCall {
+                    span: span.clone(),
+                    name: name.clone(),
+                    args: args_expr,
+                    lambda_expr: lambda_expr.clone(),
+                    lambda_display: lambda_display.clone(),
+                    return_type: return_type.clone(),
+                };
+
+                if all_args_is_scalar {
+                    let block = DataBlock::empty();
+                    let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+                    // Since we know the expression is constant, it'll be safe to change its column index type.
+                    let func_expr = func_expr.project_column_ref(|_| unreachable!());
+                    if let Ok(Value::Scalar(scalar)) = evaluator.run(&func_expr) {
+                        return (
+                            Expr::Constant {
+                                span: span.clone(),
+                                scalar,
+                                data_type: return_type.clone(),
+                            },
+                            None,
+                        );
+                    }
+                }
+
+                // The lambda body may fail at runtime, so no domain can be inferred.
+                (func_expr, None)
+            }
         };
 
         debug_assert_eq!(expr.data_type(), new_expr.data_type());
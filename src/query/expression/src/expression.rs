@@ -80,6 +80,17 @@ pub enum RawExpr<Index: ColumnIndex = usize> {
         params: Vec<usize>,
         args: Vec<RawExpr<Index>>,
     },
+    /// Higher-order function call, like `array_transform(arr, x -> x + 1)`.
+    ///
+    /// The lambda body is already type checked and refers to the lambda
+    /// parameters by `ColumnRef` with index `0..n`.
+    LambdaFunctionCall {
+        span: Span,
+        name: String,
+        args: Vec<RawExpr<Index>>,
+        lambda_expr: RemoteExpr,
+        lambda_display: String,
+    },
 }
 
 #[derive(Debug, Clone, Educe, EnumAsInner)]
@@ -110,13 +121,21 @@ pub enum Expr<Index: ColumnIndex = usize> {
         args: Vec<Expr<Index>>,
         return_type: DataType,
     },
+    LambdaFunctionCall {
+        span: Span,
+        name: String,
+        args: Vec<Expr<Index>>,
+        lambda_expr: RemoteExpr,
+        lambda_display: String,
+        return_type: DataType,
+    },
 }
 
 /// Serializable expression used to share executable expression between nodes.
 ///
 /// The remote node will recover the `Arc` pointer within `FunctionCall` by looking
 /// up the funciton registry with the `FunctionID`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RemoteExpr<Index: ColumnIndex = usize> {
     Constant {
         span: Span,
@@ -141,6 +160,14 @@ pub enum RemoteExpr<Index: ColumnIndex = usize> {
         args: Vec<RemoteExpr<Index>>,
         return_type: DataType,
     },
+    LambdaFunctionCall {
+        span: Span,
+        name: String,
+        args: Vec<RemoteExpr<Index>>,
+        lambda_expr: Box<RemoteExpr>,
+        lambda_display: String,
+        return_type: DataType,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, EnumAsInner)]
@@ -210,7 +237,9 @@ impl<Index: ColumnIndex> RawExpr<Index> {
                     buf.insert(id.clone(), data_type.clone());
                 }
                 RawExpr::Cast { expr, .. } => walk(expr, buf),
-                RawExpr::FunctionCall { args, .. } => args.iter().for_each(|expr| walk(expr, buf)),
+                RawExpr::FunctionCall { args, .. } | RawExpr::LambdaFunctionCall { args, .. } => {
+                    args.iter().for_each(|expr| walk(expr, buf))
+                }
                 RawExpr::Literal { .. } => (),
             }
         }
@@ -228,6 +257,7 @@ impl<Index: ColumnIndex> Expr<Index> {
             Expr::ColumnRef { data_type, .. } => data_type,
             Expr::Cast { dest_type, .. } => dest_type,
             Expr::FunctionCall { return_type, .. } => return_type,
+            Expr::LambdaFunctionCall { return_type, .. } => return_type,
         }
     }
 
@@ -238,7 +268,9 @@ impl<Index: ColumnIndex> Expr<Index> {
                     buf.insert(id.clone(), data_type.clone());
                 }
                 Expr::Cast { expr, .. } => walk(expr, buf),
-                Expr::FunctionCall { args, .. } => args.iter().for_each(|expr| walk(expr, buf)),
+                Expr::FunctionCall { args, .. } | Expr::LambdaFunctionCall { args, .. } => {
+                    args.iter().for_each(|expr| walk(expr, buf))
+                }
                 Expr::Constant { .. } => (),
             }
         }
@@ -277,6 +309,23 @@ impl<Index: ColumnIndex> Expr<Index> {
                 s += ")";
                 s
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                let mut s = String::new();
+                s += name;
+                s += "(";
+                for arg in args {
+                    s += &arg.sql_display();
+                    s += ", ";
+                }
+                s += lambda_display;
+                s += ")";
+                s
+            }
         }
     }
 
@@ -329,6 +378,21 @@ impl<Index: ColumnIndex> Expr<Index> {
                 args: args.iter().map(|expr| expr.project_column_ref(f)).collect(),
                 return_type: return_type.clone(),
             },
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => Expr::LambdaFunctionCall {
+                span: span.clone(),
+                name: name.clone(),
+                args: args.iter().map(|expr| expr.project_column_ref(f)).collect(),
+                lambda_expr: lambda_expr.clone(),
+                lambda_display: lambda_display.clone(),
+                return_type: return_type.clone(),
+            },
         }
     }
 
@@ -377,11 +441,36 @@ impl<Index: ColumnIndex> Expr<Index> {
                 args: args.iter().map(Expr::as_remote_expr).collect(),
                 return_type: return_type.clone(),
             },
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => RemoteExpr::LambdaFunctionCall {
+                span: span.clone(),
+                name: name.clone(),
+                args: args.iter().map(Expr::as_remote_expr).collect(),
+                lambda_expr: Box::new(lambda_expr.clone()),
+                lambda_display: lambda_display.clone(),
+                return_type: return_type.clone(),
+            },
         }
     }
 }
 
 impl<Index: ColumnIndex> RemoteExpr<Index> {
+    pub fn data_type(&self) -> &DataType {
+        match self {
+            RemoteExpr::Constant { data_type, .. } => data_type,
+            RemoteExpr::ColumnRef { data_type, .. } => data_type,
+            RemoteExpr::Cast { dest_type, .. } => dest_type,
+            RemoteExpr::FunctionCall { return_type, .. } => return_type,
+            RemoteExpr::LambdaFunctionCall { return_type, .. } => return_type,
+        }
+    }
+
     pub fn as_expr(&self, fn_registry: &FunctionRegistry) -> Option<Expr<Index>> {
         Some(match self {
             RemoteExpr::Constant {
@@ -433,6 +522,24 @@ impl<Index: ColumnIndex> RemoteExpr<Index> {
                     return_type: return_type.clone(),
                 }
             }
+            RemoteExpr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => Expr::LambdaFunctionCall {
+                span: span.clone(),
+                name: name.clone(),
+                args: args
+                    .iter()
+                    .map(|arg| arg.as_expr(fn_registry))
+                    .collect::<Option<_>>()?,
+                lambda_expr: *lambda_expr.clone(),
+                lambda_display: lambda_display.clone(),
+                return_type: return_type.clone(),
+            },
         })
     }
 }
@@ -82,6 +82,27 @@ pub struct TableField {
     name: String,
     default_expr: Option<String>,
     data_type: TableDataType,
+    #[serde(default)]
+    computed_expr: Option<ComputedExpr>,
+}
+
+/// The expression of a generated column, computed from the other columns of the row.
+///
+/// A stored column is evaluated when the row is written and kept in the data files like
+/// the other columns, a virtual column is not stored and is evaluated when it is read.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ComputedExpr {
+    Virtual(String),
+    Stored(String),
+}
+
+impl ComputedExpr {
+    pub fn expr(&self) -> &String {
+        match self {
+            ComputedExpr::Virtual(expr) => expr,
+            ComputedExpr::Stored(expr) => expr,
+        }
+    }
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -276,6 +297,35 @@ impl TableSchema {
         false
     }
 
+    /// The schema of the columns stored in the data files, without the virtual generated columns.
+    pub fn remove_virtual_computed_fields(&self) -> Self {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|f| !matches!(f.computed_expr(), Some(ComputedExpr::Virtual(_))))
+            .cloned()
+            .collect();
+        Self::new_from(fields, self.metadata.clone())
+    }
+
+    /// The schema of the columns which can be written, without any generated columns.
+    pub fn remove_computed_fields(&self) -> Self {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|f| f.computed_expr().is_none())
+            .cloned()
+            .collect();
+        Self::new_from(fields, self.metadata.clone())
+    }
+
+    #[inline]
+    pub fn has_virtual_computed_fields(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|f| matches!(f.computed_expr(), Some(ComputedExpr::Virtual(_))))
+    }
+
     pub fn fields_map(&self) -> BTreeMap<usize, TableField> {
         let x = self.fields().iter().cloned().enumerate();
         x.collect::<BTreeMap<_, _>>()
@@ -523,6 +573,7 @@ impl TableField {
             name: name.to_string(),
             default_expr: None,
             data_type,
+            computed_expr: None,
         }
     }
 
@@ -532,6 +583,12 @@ impl TableField {
         self
     }
 
+    #[must_use]
+    pub fn with_computed_expr(mut self, computed_expr: Option<ComputedExpr>) -> Self {
+        self.computed_expr = computed_expr;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -544,6 +601,10 @@ impl TableField {
         self.default_expr.as_ref()
     }
 
+    pub fn computed_expr(&self) -> Option<&ComputedExpr> {
+        self.computed_expr.as_ref()
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
@@ -843,6 +904,7 @@ impl From<&ArrowField> for TableField {
             name: f.name.clone(),
             data_type: f.into(),
             default_expr: None,
+            computed_expr: None,
         }
     }
 }
@@ -20,6 +20,7 @@ use itertools::Itertools;
 use crate::expression::Expr;
 use crate::expression::Literal;
 use crate::expression::RawExpr;
+use crate::expression::RemoteExpr;
 use crate::expression::Span;
 use crate::function::FunctionRegistry;
 use crate::function::FunctionSignature;
@@ -92,7 +93,97 @@ pub fn check<Index: ColumnIndex>(
                 .try_collect()?;
             check_function(span.clone(), name, params, &args_expr, fn_registry)
         }
+        RawExpr::LambdaFunctionCall {
+            span,
+            name,
+            args,
+            lambda_expr,
+            lambda_display,
+        } => {
+            let args_expr: Vec<_> = args
+                .iter()
+                .map(|arg| check(arg, fn_registry))
+                .try_collect()?;
+            check_lambda_function(span.clone(), name, args_expr, lambda_expr, lambda_display)
+        }
+    }
+}
+
+/// Resolve the return type of a higher-order function whose lambda body has
+/// already been type checked against the element type of the array argument.
+pub fn check_lambda_function<Index: ColumnIndex>(
+    span: Span,
+    name: &str,
+    args: Vec<Expr<Index>>,
+    lambda_expr: &RemoteExpr,
+    lambda_display: &str,
+) -> Result<Expr<Index>> {
+    if !is_lambda_function(name) {
+        return Err((span, format!("function `{name}` does not accept lambda")));
+    }
+    if args.len() != 1 {
+        return Err((
+            span,
+            format!(
+                "function `{name}` expects 1 argument and a lambda, but got {} arguments",
+                args.len()
+            ),
+        ));
     }
+
+    let arg_type = args[0].data_type().clone();
+    let (inner_type, is_nullable) = match &arg_type {
+        DataType::Nullable(box ty) => (ty.clone(), true),
+        ty => (ty.clone(), false),
+    };
+    let lambda_type = lambda_expr.data_type().clone();
+
+    let return_type = match (name, &inner_type) {
+        (_, DataType::Null) => DataType::Null,
+        ("array_reduce", DataType::EmptyArray) => DataType::Null,
+        (_, DataType::EmptyArray) => arg_type.clone(),
+        ("array_transform" | "array_apply", DataType::Array(_)) => {
+            let ty = DataType::Array(Box::new(lambda_type));
+            if is_nullable { ty.wrap_nullable() } else { ty }
+        }
+        ("array_filter", DataType::Array(_)) => {
+            if lambda_type.remove_nullable() != DataType::Boolean && lambda_type != DataType::Null {
+                return Err((
+                    span,
+                    format!("lambda of `{name}` must return a boolean, but got `{lambda_type}`"),
+                ));
+            }
+            arg_type.clone()
+        }
+        ("array_sort", DataType::Array(_)) => {
+            if !lambda_type.remove_nullable().is_numeric() {
+                return Err((
+                    span,
+                    format!("comparator of `{name}` must return a number, but got `{lambda_type}`"),
+                ));
+            }
+            arg_type.clone()
+        }
+        ("array_reduce", DataType::Array(_)) => match lambda_type {
+            DataType::Null => DataType::Null,
+            ty => ty.wrap_nullable(),
+        },
+        _ => {
+            return Err((
+                span,
+                format!("function `{name}` expects an array argument, but got `{arg_type}`"),
+            ));
+        }
+    };
+
+    Ok(Expr::LambdaFunctionCall {
+        span,
+        name: name.to_string(),
+        args,
+        lambda_expr: lambda_expr.clone(),
+        lambda_display: lambda_display.to_string(),
+        return_type,
+    })
 }
 
 fn wrap_nullable_for_try_cast(span: Span, ty: &DataType) -> Result<DataType> {
@@ -520,3 +611,14 @@ pub fn is_simple_cast_function(name: &str) -> bool {
     ];
     SIMPLE_CAST_FUNCTIONS.contains(&name)
 }
+
+pub fn is_lambda_function(name: &str) -> bool {
+    const LAMBDA_FUNCTIONS: &[&str; 5] = &[
+        "array_transform",
+        "array_apply",
+        "array_filter",
+        "array_reduce",
+        "array_sort",
+    ];
+    LAMBDA_FUNCTIONS.contains(&name)
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -41,6 +42,7 @@ use common_expression::vectorize_with_builder_2_arg;
 use common_expression::vectorize_with_builder_3_arg;
 use common_expression::with_number_mapped_type;
 use common_expression::Column;
+use common_expression::ColumnBuilder;
 use common_expression::Domain;
 use common_expression::Function;
 use common_expression::FunctionDomain;
@@ -48,6 +50,7 @@ use common_expression::FunctionProperty;
 use common_expression::FunctionRegistry;
 use common_expression::FunctionSignature;
 use common_expression::Scalar;
+use common_expression::ScalarRef;
 use common_expression::Value;
 use common_expression::ValueRef;
 use common_hashtable::HashtableKeyable;
@@ -261,6 +264,120 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_distinct",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<EmptyArrayType, EmptyArrayType>(|_, output, _| {
+            *output += 1;
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, _, _>(
+        "array_distinct",
+        FunctionProperty::default(),
+        |domain| FunctionDomain::Domain(domain.clone()),
+        vectorize_with_builder_1_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>>(
+            |arr, output, _| {
+                let mut items: Vec<ScalarRef> = Vec::with_capacity(arr.len());
+                for item in arr.iter() {
+                    if !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+                for item in items {
+                    output.put_item(item);
+                }
+                output.commit_row();
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_sort",
+        FunctionProperty::default(),
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<EmptyArrayType, EmptyArrayType>(|_, output, _| {
+            *output += 1;
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, _, _>(
+        "array_sort",
+        FunctionProperty::default(),
+        |domain| FunctionDomain::Domain(domain.clone()),
+        vectorize_with_builder_1_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>>(
+            |arr, output, _| {
+                let mut items = arr.iter().collect::<Vec<_>>();
+                items.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+                for item in items {
+                    output.put_item(item);
+                }
+                output.commit_row();
+            }
+        ),
+    );
+
+    registry.register_function_factory("arrays_zip", |_, args_type| {
+        if args_type.is_empty() {
+            return None;
+        }
+        let fields_type = args_type
+            .iter()
+            .map(|ty| match ty {
+                DataType::Array(box ty) => Some(ty.wrap_nullable()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let tuple_type = DataType::Tuple(fields_type);
+        let return_type = DataType::Array(Box::new(tuple_type.clone()));
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "arrays_zip".to_string(),
+                args_type: args_type.to_vec(),
+                return_type: return_type.clone(),
+                property: FunctionProperty::default(),
+            },
+            calc_domain: Box::new(|_| FunctionDomain::Full),
+            eval: Box::new(move |args, _| {
+                let len = args.iter().find_map(|arg| match arg {
+                    ValueRef::Column(col) => Some(col.len()),
+                    _ => None,
+                });
+
+                let mut builder = ColumnBuilder::with_capacity(&return_type, len.unwrap_or(1));
+                for idx in 0..(len.unwrap_or(1)) {
+                    let arrays = args
+                        .iter()
+                        .map(|arg| match arg {
+                            ValueRef::Scalar(ScalarRef::Array(col)) => col.clone(),
+                            ValueRef::Column(col) => unsafe {
+                                col.index_unchecked(idx).into_array().unwrap()
+                            },
+                            _ => unreachable!(),
+                        })
+                        .collect::<Vec<_>>();
+                    // Shorter arrays are padded with NULL.
+                    let max_len = arrays.iter().map(|arr| arr.len()).max().unwrap_or(0);
+                    let mut tuples = ColumnBuilder::with_capacity(&tuple_type, max_len);
+                    for i in 0..max_len {
+                        let fields = arrays
+                            .iter()
+                            .map(|arr| arr.index(i).unwrap_or(ScalarRef::Null))
+                            .collect();
+                        tuples.push(ScalarRef::Tuple(fields));
+                    }
+                    builder.push(ScalarRef::Array(tuples.build()));
+                }
+
+                match len {
+                    Some(_) => Value::Column(builder.build()),
+                    None => Value::Scalar(builder.build_scalar()),
+                }
+            }),
+        }))
+    });
+
     fn eval_contains<T: ArgType>(
         lhs: ValueRef<ArrayType<T>>,
         rhs: ValueRef<T>,
@@ -32,12 +32,17 @@ mod variant;
 mod comparison;
 mod hash;
 mod other;
+mod search;
+mod sequence;
 mod string;
 mod string_multi_args;
 
 pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
+pub use search::query_terms;
+pub use search::tokenize;
+pub use sequence::SequenceAllocator;
 
 use self::comparison::ALL_COMP_FUNC_NAMES;
 use self::comparison::ALL_MATCH_FUNC_NAMES;
@@ -64,6 +69,8 @@ fn builtin_functions() -> FunctionRegistry {
     geo::register(&mut registry);
     hash::register(&mut registry);
     other::register(&mut registry);
+    search::register(&mut registry);
+    sequence::register(&mut registry);
 
     registry
 }
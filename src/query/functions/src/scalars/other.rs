@@ -91,7 +91,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     registry.register_1_arg_core::<Float64Type, UInt8Type, _, _>(
         "sleep",
-        FunctionProperty::default(),
+        FunctionProperty::default().non_deterministic(),
         |_| FunctionDomain::MayThrow,
         vectorize_with_builder_1_arg::<Float64Type, UInt8Type>(move |val, output, ctx| {
             let duration = Duration::try_from_secs_f64(val.into()).map_err(|x| x.to_string());
@@ -209,7 +209,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     registry.register_0_arg_core::<StringType, _, _>(
         "gen_random_uuid",
-        FunctionProperty::default(),
+        FunctionProperty::default().non_deterministic(),
         || FunctionDomain::Full,
         |ctx| {
             let mut values: Vec<u8> = Vec::with_capacity(ctx.num_rows * 36);
@@ -0,0 +1,97 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_expression::types::number::F64;
+use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::FunctionDomain;
+use common_expression::FunctionProperty;
+use common_expression::FunctionRegistry;
+
+/// Split the text into lower-cased terms, a term is a run of alphanumeric characters.
+///
+/// The inverted indexes of fuse tables are built with the same tokenizer,
+/// so that `match` returns the same result whether the index is used or not.
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+        .collect()
+}
+
+/// The distinct terms of a `match` query, a row matches if it contains all of them.
+pub fn query_terms(query: &str) -> Vec<String> {
+    let mut terms = tokenize(query);
+    terms.sort();
+    terms.dedup();
+    terms
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::Full,
+        |text, query, _| {
+            let terms = query_terms(&String::from_utf8_lossy(query));
+            if terms.is_empty() {
+                return false;
+            }
+            let text = tokenize(&String::from_utf8_lossy(text));
+            terms.iter().all(|term| text.contains(term))
+        },
+    );
+
+    // `score()` is rewritten to `score(<column>, <query>, <idfs>)` of the `match` in the WHERE clause,
+    // the IDFs of the query terms are computed with the term statistics of the table.
+    registry.register_3_arg::<StringType, StringType, ArrayType<NumberType<F64>>, NumberType<F64>, _, _>(
+        "score",
+        FunctionProperty::default(),
+        |_, _, _| FunctionDomain::Full,
+        |text, query, idfs, _| {
+            score(
+                &String::from_utf8_lossy(text),
+                &String::from_utf8_lossy(query),
+                &idfs,
+            )
+        },
+    );
+}
+
+/// The relevance of the text to the query.
+///
+/// It's the classic TF-IDF similarity of Lucene, `idfs` are the IDFs of the terms
+/// returned by [`query_terms`].
+fn score(text: &str, query: &str, idfs: &[F64]) -> F64 {
+    let text = tokenize(text);
+    if text.is_empty() {
+        return F64::from(0.0);
+    }
+    let mut frequencies = HashMap::new();
+    for term in &text {
+        *frequencies.entry(term.as_str()).or_insert(0u32) += 1;
+    }
+    let length_norm = 1.0 / (text.len() as f64).sqrt();
+    let score = query_terms(query)
+        .iter()
+        .zip(idfs)
+        .filter_map(|(term, idf)| Some((frequencies.get(term.as_str())?, idf.into_inner())))
+        .map(|(tf, idf)| (*tf as f64).sqrt() * idf * idf * length_norm)
+        .sum::<f64>();
+    F64::from(score)
+}
@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::GlobalInstance;
+use common_exception::Result;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::FunctionDomain;
+use common_expression::FunctionProperty;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+/// Hands out the values of sequences to `nextval`.
+///
+/// The functions are evaluated without access to the catalog, so the query service
+/// registers its implementation into `GlobalInstance` when it starts.
+pub trait SequenceAllocator: Send + Sync {
+    /// Return the next `count` values of the sequence `tenant`.`sequence`, whose id is
+    /// `sequence_id` when the query is planned.
+    fn next_values(
+        &self,
+        tenant: &str,
+        sequence: &str,
+        sequence_id: u64,
+        count: u64,
+    ) -> Result<Vec<i64>>;
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // `nextval('seq')` is rewritten to `nextval('<tenant>', 'seq', <sequence_id>)` by the type checker.
+    registry.register_3_arg_core::<StringType, StringType, UInt64Type, NumberType<i64>, _, _>(
+        "nextval",
+        FunctionProperty::default().non_deterministic(),
+        |_, _, _| FunctionDomain::MayThrow,
+        |tenant, sequence, sequence_id, ctx| {
+            let allocator: Arc<dyn SequenceAllocator> = GlobalInstance::get();
+            let values = match (&tenant, &sequence, &sequence_id) {
+                (
+                    ValueRef::Scalar(tenant),
+                    ValueRef::Scalar(sequence),
+                    ValueRef::Scalar(sequence_id),
+                ) => allocator.next_values(
+                    &String::from_utf8_lossy(tenant),
+                    &String::from_utf8_lossy(sequence),
+                    *sequence_id,
+                    ctx.num_rows as u64,
+                ),
+                _ => (0..ctx.num_rows)
+                    .map(|row| {
+                        let values = allocator.next_values(
+                            &String::from_utf8_lossy(tenant.index(row).unwrap()),
+                            &String::from_utf8_lossy(sequence.index(row).unwrap()),
+                            sequence_id.index(row).unwrap(),
+                            1,
+                        )?;
+                        Ok(values[0])
+                    })
+                    .collect(),
+            };
+            match values {
+                Ok(values) => Value::Column(values.into()),
+                Err(err) => {
+                    ctx.set_error(0, err.message());
+                    Value::Column(vec![0; ctx.num_rows].into())
+                }
+            }
+        },
+    );
+}
@@ -19,12 +19,14 @@ use chrono::Datelike;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_expression::types::date::string_to_date;
 use common_expression::types::nullable::NullableColumn;
+use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::*;
 use common_expression::types::timestamp::string_to_timestamp;
 use common_expression::types::variant::cast_scalar_to_variant;
 use common_expression::types::variant::cast_scalars_to_variants;
 use common_expression::types::variant::JSONB_NULL;
+use common_expression::types::ArgType;
 use common_expression::types::BooleanType;
 use common_expression::types::DateType;
 use common_expression::types::GenericType;
@@ -41,6 +43,7 @@ use common_expression::vectorize_2_arg;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::with_number_mapped_type;
+use common_expression::EvalContext;
 use common_expression::FunctionDomain;
 use common_expression::FunctionProperty;
 use common_expression::FunctionRegistry;
@@ -51,10 +54,15 @@ use common_jsonb::as_bool;
 use common_jsonb::as_f64;
 use common_jsonb::as_i64;
 use common_jsonb::as_str;
+use common_jsonb::build_array;
 use common_jsonb::get_by_name_ignore_case;
 use common_jsonb::get_by_path;
 use common_jsonb::is_array;
 use common_jsonb::is_object;
+use common_jsonb::jsonpath;
+use common_jsonb::jsonpath::path_exists;
+use common_jsonb::jsonpath::path_match;
+use common_jsonb::jsonpath::path_query;
 use common_jsonb::object_keys;
 use common_jsonb::parse_json_path;
 use common_jsonb::parse_value;
@@ -391,6 +399,52 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_combine_nullable_2_arg::<VariantType, StringType, VariantType, _, _>(
+        "json_path_query",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_json_path::<VariantType>(|val, json_path, output, ctx| {
+            let result = path_query(val, json_path).and_then(|items| {
+                let mut buf = Vec::new();
+                build_array(items.iter().map(|item| item.as_slice()), &mut buf)?;
+                Ok(buf)
+            });
+            match result {
+                Ok(buf) => output.push(&buf),
+                Err(err) => {
+                    ctx.set_error(output.len(), err.to_string());
+                    output.push_null();
+                }
+            }
+        }),
+    );
+
+    registry.register_combine_nullable_2_arg::<VariantType, StringType, BooleanType, _, _>(
+        "json_path_exists",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_json_path::<BooleanType>(|val, json_path, output, _| {
+            match path_exists(val, json_path) {
+                Ok(exists) => output.push(exists),
+                // Errors during evaluation are suppressed like `@?` operator
+                Err(_) => output.push_null(),
+            }
+        }),
+    );
+
+    registry.register_combine_nullable_2_arg::<VariantType, StringType, BooleanType, _, _>(
+        "json_path_match",
+        FunctionProperty::default(),
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_json_path::<BooleanType>(|val, json_path, output, _| {
+            match path_match(val, json_path) {
+                Ok(Some(res)) => output.push(res),
+                // Unknown results and errors during evaluation are NULL like `@@` operator
+                Ok(None) | Err(_) => output.push_null(),
+            }
+        }),
+    );
+
     registry.register_combine_nullable_1_arg::<VariantType, BooleanType, _, _>(
         "as_boolean",
         FunctionProperty::default(),
@@ -732,3 +786,35 @@ pub fn register(registry: &mut FunctionRegistry) {
         });
     }
 }
+
+/// Vectorizes the SQL/JSON path functions, a constant path is parsed only once
+/// instead of once for each row.
+fn vectorize_json_path<O: ArgType>(
+    func: impl Fn(&[u8], &jsonpath::JsonPath, &mut NullableColumnBuilder<O>, &mut EvalContext)
+    + Copy
+    + Send
+    + Sync,
+) -> impl Fn(ValueRef<VariantType>, ValueRef<StringType>, &mut EvalContext) -> Value<NullableType<O>>
++ Copy
++ Send
++ Sync {
+    move |val, path, ctx| {
+        if let ValueRef::Scalar(path) = path {
+            if let Ok(json_path) = jsonpath::parse_json_path(path) {
+                return vectorize_with_builder_2_arg::<VariantType, StringType, NullableType<O>>(
+                    |val, _, output, ctx| func(val, &json_path, output, ctx),
+                )(val, ValueRef::Scalar(path), ctx);
+            }
+        }
+        // The errors of invalid paths are reported by the rows
+        vectorize_with_builder_2_arg::<VariantType, StringType, NullableType<O>>(
+            |val, path, output, ctx| match jsonpath::parse_json_path(path) {
+                Ok(json_path) => func(val, &json_path, output, ctx),
+                Err(err) => {
+                    ctx.set_error(output.len(), err.to_string());
+                    output.push_null();
+                }
+            },
+        )(val, path, ctx)
+    }
+}
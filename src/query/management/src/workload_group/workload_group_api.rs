@@ -0,0 +1,33 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_types::SeqV;
+use common_meta_types::WorkloadGroup;
+
+#[async_trait::async_trait]
+pub trait WorkloadGroupApi: Sync + Send {
+    // Add a workload group to /tenant/group-name.
+    async fn add_workload_group(&self, group: WorkloadGroup) -> Result<u64>;
+
+    // Get workload group by name.
+    async fn get_workload_group(&self, name: &str, seq: Option<u64>)
+    -> Result<SeqV<WorkloadGroup>>;
+
+    // Get all the workload groups for a tenant.
+    async fn get_workload_groups(&self) -> Result<Vec<WorkloadGroup>>;
+
+    // Drop the tenant's workload group by name.
+    async fn drop_workload_group(&self, name: &str, seq: Option<u64>) -> Result<()>;
+}
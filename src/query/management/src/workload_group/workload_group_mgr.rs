@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKVReq;
+use common_meta_types::WorkloadGroup;
+
+use crate::workload_group::WorkloadGroupApi;
+
+static WORKLOAD_GROUP_API_KEY_PREFIX: &str = "__fd_workload_groups";
+
+pub struct WorkloadGroupMgr {
+    kv_api: Arc<dyn KVApi>,
+    workload_group_prefix: String,
+}
+
+impl WorkloadGroupMgr {
+    pub fn create(kv_api: Arc<dyn KVApi>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while workload group mgr create)",
+            ));
+        }
+
+        Ok(WorkloadGroupMgr {
+            kv_api,
+            workload_group_prefix: format!(
+                "{}/{}",
+                WORKLOAD_GROUP_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+
+    fn key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.workload_group_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl WorkloadGroupApi for WorkloadGroupMgr {
+    async fn add_workload_group(&self, group: WorkloadGroup) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&group)?);
+        let key = self.key(&group.name)?;
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::WorkloadGroupAlreadyExists(format!(
+                "Workload group {} already exists, seq [{}]",
+                group.name, v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    async fn get_workload_group(
+        &self,
+        name: &str,
+        seq: Option<u64>,
+    ) -> Result<SeqV<WorkloadGroup>> {
+        let key = self.key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownWorkloadGroup(format!("Unknown workload group {}", name))
+        })?;
+
+        match MatchSeq::from(seq).match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownWorkloadGroup(format!(
+                "Unknown workload group {}",
+                name
+            ))),
+        }
+    }
+
+    async fn get_workload_groups(&self) -> Result<Vec<WorkloadGroup>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.workload_group_prefix)
+            .await?;
+
+        let mut groups = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            groups.push(WorkloadGroup::try_from(value.data)?);
+        }
+        Ok(groups)
+    }
+
+    async fn drop_workload_group(&self, name: &str, seq: Option<u64>) -> Result<()> {
+        let key = self.key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq.into(), Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownWorkloadGroup(format!(
+                "Unknown workload group {}",
+                name
+            )))
+        }
+    }
+}
@@ -14,6 +14,7 @@
 
 pub mod port;
 pub mod processor;
+pub mod profile;
 
 mod port_trigger;
 mod resize_processor;
@@ -23,4 +24,8 @@ pub use port_trigger::DirectedEdge;
 pub use port_trigger::UpdateList;
 pub use port_trigger::UpdateTrigger;
 pub use processor::Processor;
+pub use profile::PlanProfile;
+pub use profile::PlanProfiles;
+pub use profile::ProcessorProfileWrapper;
+pub use profile::ProfileStatistics;
 pub use resize_processor::ResizeProcessor;
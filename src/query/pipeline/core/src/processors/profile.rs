@@ -0,0 +1,303 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_exception::Result;
+use futures::future::BoxFuture;
+
+use crate::processors::processor::Event;
+use crate::processors::processor::ProcessorPtr;
+use crate::processors::Processor;
+
+thread_local! {
+    static CURRENT_PROFILE: RefCell<Option<Arc<PlanProfile>>> = RefCell::new(None);
+}
+
+/// The runtime statistics of a physical plan node, it can be sent to other nodes.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProfileStatistics {
+    pub input_rows: u64,
+    pub input_bytes: u64,
+    pub output_rows: u64,
+    pub output_bytes: u64,
+    /// Nanoseconds spent in processing.
+    pub cpu_time: u64,
+    /// Nanoseconds spent in waiting for the async works, e.g. IO.
+    pub wait_time: u64,
+    pub partitions_scanned: u64,
+    /// Partitions pruned when the plan is built, or by the runtime filters.
+    pub partitions_pruned: u64,
+    /// Hot keys detected for the skew-aware shuffle.
+    pub skew_hot_keys: u64,
+    /// Rows with hot keys that are spread or broadcast by the skew-aware shuffle.
+    pub skew_rows: u64,
+}
+
+impl ProfileStatistics {
+    pub fn merge(&mut self, other: &ProfileStatistics) {
+        self.input_rows += other.input_rows;
+        self.input_bytes += other.input_bytes;
+        self.output_rows += other.output_rows;
+        self.output_bytes += other.output_bytes;
+        self.cpu_time += other.cpu_time;
+        self.wait_time += other.wait_time;
+        self.partitions_scanned += other.partitions_scanned;
+        self.partitions_pruned += other.partitions_pruned;
+        self.skew_hot_keys += other.skew_hot_keys;
+        self.skew_rows += other.skew_rows;
+    }
+}
+
+/// Collects the runtime statistics of the processors built from a physical plan node.
+#[derive(Default)]
+pub struct PlanProfile {
+    output_rows: AtomicU64,
+    output_bytes: AtomicU64,
+    cpu_time: AtomicU64,
+    wait_time: AtomicU64,
+    partitions_scanned: AtomicU64,
+    partitions_pruned: AtomicU64,
+    skew_hot_keys: AtomicU64,
+    skew_rows: AtomicU64,
+}
+
+impl PlanProfile {
+    pub fn add_output(&self, rows: usize, bytes: usize) {
+        self.output_rows.fetch_add(rows as u64, Ordering::Relaxed);
+        self.output_bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_partitions(&self, scanned: usize, pruned: usize) {
+        self.partitions_scanned
+            .fetch_add(scanned as u64, Ordering::Relaxed);
+        self.partitions_pruned
+            .fetch_add(pruned as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_skew(&self, hot_keys: usize, rows: usize) {
+        self.skew_hot_keys
+            .fetch_add(hot_keys as u64, Ordering::Relaxed);
+        self.skew_rows.fetch_add(rows as u64, Ordering::Relaxed);
+    }
+
+    fn add_cpu_time(&self, duration: Duration) {
+        self.cpu_time
+            .fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn add_wait_time(&self, duration: Duration) {
+        self.wait_time
+            .fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub fn merge(&self, statistics: &ProfileStatistics) {
+        self.add_output(
+            statistics.output_rows as usize,
+            statistics.output_bytes as usize,
+        );
+        self.add_partitions(
+            statistics.partitions_scanned as usize,
+            statistics.partitions_pruned as usize,
+        );
+        self.add_skew(
+            statistics.skew_hot_keys as usize,
+            statistics.skew_rows as usize,
+        );
+        self.cpu_time
+            .fetch_add(statistics.cpu_time, Ordering::Relaxed);
+        self.wait_time
+            .fetch_add(statistics.wait_time, Ordering::Relaxed);
+    }
+
+    pub fn statistics(&self) -> ProfileStatistics {
+        ProfileStatistics {
+            output_rows: self.output_rows.load(Ordering::Relaxed),
+            output_bytes: self.output_bytes.load(Ordering::Relaxed),
+            cpu_time: self.cpu_time.load(Ordering::Relaxed),
+            wait_time: self.wait_time.load(Ordering::Relaxed),
+            partitions_scanned: self.partitions_scanned.load(Ordering::Relaxed),
+            partitions_pruned: self.partitions_pruned.load(Ordering::Relaxed),
+            skew_hot_keys: self.skew_hot_keys.load(Ordering::Relaxed),
+            skew_rows: self.skew_rows.load(Ordering::Relaxed),
+            ..Default::default()
+        }
+    }
+
+    /// Take the statistics collected since the last call.
+    pub fn take_statistics(&self) -> ProfileStatistics {
+        ProfileStatistics {
+            output_rows: self.output_rows.swap(0, Ordering::Relaxed),
+            output_bytes: self.output_bytes.swap(0, Ordering::Relaxed),
+            cpu_time: self.cpu_time.swap(0, Ordering::Relaxed),
+            wait_time: self.wait_time.swap(0, Ordering::Relaxed),
+            partitions_scanned: self.partitions_scanned.swap(0, Ordering::Relaxed),
+            partitions_pruned: self.partitions_pruned.swap(0, Ordering::Relaxed),
+            skew_hot_keys: self.skew_hot_keys.swap(0, Ordering::Relaxed),
+            skew_rows: self.skew_rows.swap(0, Ordering::Relaxed),
+            ..Default::default()
+        }
+    }
+
+    /// Record into the profile of the processor running on the current thread.
+    /// Do nothing if the query is not profiled.
+    pub fn record_current<F: FnOnce(&PlanProfile)>(f: F) {
+        CURRENT_PROFILE.with(|current| {
+            if let Some(profile) = current.borrow().as_ref() {
+                f(profile)
+            }
+        })
+    }
+
+    fn enter(profile: &Arc<PlanProfile>) -> CurrentProfileGuard {
+        let previous = CURRENT_PROFILE.with(|current| current.replace(Some(profile.clone())));
+        CurrentProfileGuard { previous }
+    }
+}
+
+struct CurrentProfileGuard {
+    previous: Option<Arc<PlanProfile>>,
+}
+
+impl Drop for CurrentProfileGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_PROFILE.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// The profiles of all the physical plan nodes of a query, keyed by plan id.
+#[derive(Default)]
+pub struct PlanProfiles {
+    profiles: Mutex<HashMap<usize, Arc<PlanProfile>>>,
+}
+
+impl PlanProfiles {
+    pub fn get_or_create(&self, plan_id: usize) -> Arc<PlanProfile> {
+        let mut profiles = self.profiles.lock().unwrap();
+        profiles.entry(plan_id).or_default().clone()
+    }
+
+    pub fn merge(&self, plan_id: usize, statistics: &ProfileStatistics) {
+        self.get_or_create(plan_id).merge(statistics)
+    }
+
+    pub fn statistics(&self) -> HashMap<usize, ProfileStatistics> {
+        let profiles = self.profiles.lock().unwrap();
+        profiles
+            .iter()
+            .map(|(plan_id, profile)| (*plan_id, profile.statistics()))
+            .collect()
+    }
+
+    /// Take the statistics collected since the last call, used to report to the coordinator.
+    pub fn take_statistics(&self) -> Vec<(usize, ProfileStatistics)> {
+        let profiles = self.profiles.lock().unwrap();
+        profiles
+            .iter()
+            .map(|(plan_id, profile)| (*plan_id, profile.take_statistics()))
+            .filter(|(_, statistics)| *statistics != ProfileStatistics::default())
+            .collect()
+    }
+}
+
+/// Measure the time spent by the wrapped processor.
+pub struct ProcessorProfileWrapper {
+    inner: ProcessorPtr,
+    profile: Arc<PlanProfile>,
+}
+
+impl ProcessorProfileWrapper {
+    pub fn create(inner: ProcessorPtr, profile: Arc<PlanProfile>) -> ProcessorPtr {
+        ProcessorPtr::create(Box::new(ProcessorProfileWrapper { inner, profile }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for ProcessorProfileWrapper {
+    fn name(&self) -> String {
+        unsafe { self.inner.name() }
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        unsafe { self.inner.as_any() }
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        unsafe { self.inner.event() }
+    }
+
+    fn interrupt(&self) {
+        unsafe { self.inner.interrupt() }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        let _guard = PlanProfile::enter(&self.profile);
+        let instant = Instant::now();
+        let res = unsafe { self.inner.process() };
+        self.profile.add_cpu_time(instant.elapsed());
+        res
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        ProfiledFuture {
+            inner: unsafe { self.inner.async_process() },
+            profile: self.profile.clone(),
+            started: None,
+            poll_time: Duration::ZERO,
+        }
+        .await
+    }
+}
+
+/// The time spent in polling is counted as cpu time, and the rest as wait time.
+struct ProfiledFuture {
+    inner: BoxFuture<'static, Result<()>>,
+    profile: Arc<PlanProfile>,
+    started: Option<Instant>,
+    poll_time: Duration,
+}
+
+impl Future for ProfiledFuture {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let started = *self.started.get_or_insert_with(Instant::now);
+
+        let _guard = PlanProfile::enter(&self.profile);
+        let instant = Instant::now();
+        let res = self.inner.as_mut().poll(cx);
+        let elapsed = instant.elapsed();
+
+        self.poll_time += elapsed;
+        self.profile.add_cpu_time(elapsed);
+        if res.is_ready() {
+            let wait_time = started.elapsed().saturating_sub(self.poll_time);
+            self.profile.add_wait_time(wait_time);
+        }
+        res
+    }
+}
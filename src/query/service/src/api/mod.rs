@@ -34,6 +34,7 @@ pub use rpc::PrecommitBlock;
 pub use rpc::QueryFragmentsPlanPacket;
 pub use rpc::ServerFlightExchange;
 pub use rpc::ShuffleDataExchange;
+pub use rpc::TransformSkewAggregate;
 pub use rpc_service::RpcService;
 
 pub mod http;
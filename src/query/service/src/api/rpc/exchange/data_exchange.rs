@@ -13,12 +13,15 @@
 // limitations under the License.
 
 use common_sql::executor::PhysicalScalar;
+use common_sql::executor::SkewShuffle;
+use common_sql::executor::SortDesc;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum DataExchange {
     Merge(MergeExchange),
     Broadcast(BroadcastExchange),
     ShuffleDataExchange(ShuffleDataExchange),
+    Range(RangeDataExchange),
 }
 
 impl DataExchange {
@@ -27,6 +30,7 @@ impl DataExchange {
             DataExchange::Merge(exchange) => vec![exchange.destination_id.clone()],
             DataExchange::Broadcast(exchange) => exchange.destination_ids.clone(),
             DataExchange::ShuffleDataExchange(exchange) => exchange.destination_ids.clone(),
+            DataExchange::Range(exchange) => exchange.destination_ids.clone(),
         }
     }
 
@@ -34,6 +38,7 @@ impl DataExchange {
         match self {
             DataExchange::Merge(_) => true,
             DataExchange::ShuffleDataExchange(_) => true,
+            DataExchange::Range(_) => true,
             DataExchange::Broadcast(exchange) => exchange.from_multiple_nodes,
         }
     }
@@ -43,6 +48,9 @@ impl DataExchange {
 pub struct ShuffleDataExchange {
     pub destination_ids: Vec<String>,
     pub shuffle_keys: Vec<PhysicalScalar>,
+    /// Set if the shuffle is a side of an inner join that is aware of the hot keys.
+    #[serde(default)]
+    pub skew: Option<SkewShuffle>,
 }
 
 impl ShuffleDataExchange {
@@ -50,6 +58,35 @@ impl ShuffleDataExchange {
         DataExchange::ShuffleDataExchange(ShuffleDataExchange {
             destination_ids,
             shuffle_keys,
+            skew: None,
+        })
+    }
+
+    pub fn create_skew(
+        destination_ids: Vec<String>,
+        shuffle_keys: Vec<PhysicalScalar>,
+        skew: SkewShuffle,
+    ) -> DataExchange {
+        DataExchange::ShuffleDataExchange(ShuffleDataExchange {
+            destination_ids,
+            shuffle_keys,
+            skew: Some(skew),
+        })
+    }
+}
+
+/// Partition the data by ranges of the sort keys, the i-th range is sent to the i-th destination.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RangeDataExchange {
+    pub destination_ids: Vec<String>,
+    pub order_by: Vec<SortDesc>,
+}
+
+impl RangeDataExchange {
+    pub fn create(destination_ids: Vec<String>, order_by: Vec<SortDesc>) -> DataExchange {
+        DataExchange::Range(RangeDataExchange {
+            destination_ids,
+            order_by,
         })
     }
 }
@@ -57,11 +94,31 @@ impl ShuffleDataExchange {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MergeExchange {
     pub destination_id: String,
+    /// The fragment can be retried on another node, the sender marks the end of
+    /// its data with `DataPacket::FragmentFinished`.
+    #[serde(default)]
+    pub retryable: bool,
+    /// The sources whose data is concatenated in this order,
+    /// empty if the data can be merged in any order.
+    #[serde(default)]
+    pub ordered_sources: Vec<String>,
 }
 
 impl MergeExchange {
     pub fn create(destination_id: String) -> DataExchange {
-        DataExchange::Merge(MergeExchange { destination_id })
+        DataExchange::Merge(MergeExchange {
+            destination_id,
+            retryable: false,
+            ordered_sources: vec![],
+        })
+    }
+
+    pub fn create_ordered(destination_id: String, ordered_sources: Vec<String>) -> DataExchange {
+        DataExchange::Merge(MergeExchange {
+            destination_id,
+            retryable: false,
+            ordered_sources,
+        })
     }
 }
 
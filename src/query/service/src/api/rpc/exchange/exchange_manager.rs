@@ -23,23 +23,34 @@ use common_base::base::GlobalInstance;
 use common_base::runtime::GlobalIORuntime;
 use common_base::runtime::Thread;
 use common_base::runtime::TrySpawn;
+use common_catalog::plan::RangeKeyOrder;
+use common_catalog::plan::RangePartitionDesc;
+use common_catalog::plan::SkewShuffleDesc;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_grpc::ConnectionFactory;
+use common_sql::executor::SkewShuffleKind;
 use parking_lot::Mutex;
 use parking_lot::ReentrantMutex;
 
 use crate::api::rpc::exchange::exchange_params::ExchangeParams;
 use crate::api::rpc::exchange::exchange_params::MergeExchangeParams;
+use crate::api::rpc::exchange::exchange_params::RangePartitionParams;
 use crate::api::rpc::exchange::exchange_params::ShuffleExchangeParams;
+use crate::api::rpc::exchange::exchange_params::SkewShuffleParams;
 use crate::api::rpc::exchange::exchange_sink::ExchangeSink;
 use crate::api::rpc::exchange::exchange_transform::ExchangeTransform;
+use crate::api::rpc::exchange::fragments_retry::FragmentsRetry;
+use crate::api::rpc::exchange::fragments_retry::RetryableFragment;
 use crate::api::rpc::exchange::statistics_receiver::StatisticsReceiver;
 use crate::api::rpc::exchange::statistics_sender::StatisticsSender;
 use crate::api::rpc::flight_client::FlightExchange;
 use crate::api::rpc::flight_scatter_broadcast::BroadcastFlightScatter;
 use crate::api::rpc::flight_scatter_hash::HashFlightScatter;
+use crate::api::rpc::flight_scatter_range::RangeFlightScatter;
+use crate::api::rpc::flight_scatter_skew::SkewFlightScatter;
+use crate::api::rpc::flight_scatter_skew::SkewKeyHasher;
 use crate::api::rpc::Packet;
 use crate::api::DataExchange;
 use crate::api::FlightClient;
@@ -216,6 +227,42 @@ impl DataExchangeManager {
         }
     }
 
+    // Take the exchanges which a retry attempt of a fragment created with the coordinator.
+    pub fn take_retry_exchanges(
+        &self,
+        query_id: &str,
+        executor: &str,
+        fragment_id: usize,
+    ) -> Result<(FlightExchange, FlightExchange)> {
+        let queries_coordinator_guard = self.queries_coordinator.lock();
+        let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
+
+        match queries_coordinator.remove(query_id) {
+            None => Err(ErrorCode::Internal(format!(
+                "Query {} not found in cluster.",
+                query_id
+            ))),
+            Some(mut query_coordinator) => {
+                let fragment_exchange = query_coordinator
+                    .fragment_exchanges
+                    .remove(&(executor.to_string(), fragment_id));
+
+                match (
+                    fragment_exchange,
+                    query_coordinator.statistics_exchanges.pop(),
+                ) {
+                    (Some(fragment_exchange), Some(statistics_exchange)) => {
+                        Ok((fragment_exchange, statistics_exchange))
+                    }
+                    _ => Err(ErrorCode::UnknownFragmentExchange(format!(
+                        "Unknown fragment exchange channel, {}, {}",
+                        executor, fragment_id
+                    ))),
+                }
+            }
+        }
+    }
+
     pub fn on_finished_query(&self, query_id: &str) {
         let queries_coordinator_guard = self.queries_coordinator.lock();
         let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
@@ -231,19 +278,29 @@ impl DataExchangeManager {
     pub async fn commit_actions(
         &self,
         ctx: Arc<QueryContext>,
-        actions: QueryFragmentsActions,
+        mut actions: QueryFragmentsActions,
     ) -> Result<PipelineBuildResult> {
         let settings = ctx.get_settings();
         let timeout = settings.get_flight_client_timeout()?;
-        let root_actions = actions.get_root_actions()?;
         let conf = GlobalConfig::instance();
 
+        let retryable_fragments = match settings.get_enable_fragment_retry()? {
+            true => RetryableFragment::collect(&mut actions)?,
+            false => vec![],
+        };
+
+        let root_actions = actions.get_root_actions()?;
+
         // Initialize channels between cluster nodes
         actions
             .get_init_nodes_channel_packets()?
             .commit(conf.as_ref(), timeout)
             .await?;
 
+        if !retryable_fragments.is_empty() {
+            self.init_fragments_retry(&ctx, retryable_fragments)?;
+        }
+
         // Submit distributed tasks to all nodes.
         let (local_query_fragments_plan_packet, query_fragments_plan_packets) =
             actions.get_query_fragments_plan_packets()?;
@@ -266,6 +323,25 @@ impl DataExchangeManager {
         Ok(build_res)
     }
 
+    fn init_fragments_retry(
+        &self,
+        ctx: &Arc<QueryContext>,
+        fragments: Vec<RetryableFragment>,
+    ) -> Result<()> {
+        let queries_coordinator_guard = self.queries_coordinator.lock();
+        let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
+
+        match queries_coordinator.get_mut(&ctx.get_id()) {
+            None => Err(ErrorCode::Internal("Query not exists.")),
+            Some(query_coordinator) => {
+                let fragments_retry = FragmentsRetry::create(ctx.clone(), fragments);
+                fragments_retry.start(&mut query_coordinator.fragment_exchanges)?;
+                query_coordinator.fragments_retry = Some(fragments_retry);
+                Ok(())
+            }
+        }
+    }
+
     fn get_root_pipeline(
         &self,
         ctx: Arc<QueryContext>,
@@ -284,7 +360,12 @@ impl DataExchangeManager {
                 let mut build_res = query_coordinator.subscribe_fragment(&ctx, fragment_id)?;
 
                 let exchanges = std::mem::take(&mut query_coordinator.statistics_exchanges);
-                let mut statistics_receiver = StatisticsReceiver::create(ctx.clone(), exchanges)?;
+                let fault_tolerant = query_coordinator.fragments_retry.is_some();
+                if !exchanges.is_empty() && partition_stealing_enabled(&ctx)? {
+                    ctx.enable_partition_stealing();
+                }
+                let mut statistics_receiver =
+                    StatisticsReceiver::create(ctx.clone(), exchanges, fault_tolerant)?;
                 statistics_receiver.start();
 
                 let statistics_receiver: Mutex<StatisticsReceiver> =
@@ -354,6 +435,7 @@ struct QueryCoordinator {
     statistics_exchanges: Vec<FlightExchange>,
     fragment_exchanges: HashMap<(String, usize), FlightExchange>,
     fragments_coordinator: HashMap<usize, Box<FragmentCoordinator>>,
+    fragments_retry: Option<Arc<FragmentsRetry>>,
 }
 
 impl QueryCoordinator {
@@ -363,6 +445,7 @@ impl QueryCoordinator {
             statistics_exchanges: vec![],
             fragment_exchanges: HashMap::new(),
             fragments_coordinator: HashMap::new(),
+            fragments_retry: None,
         }
     }
 
@@ -398,6 +481,33 @@ impl QueryCoordinator {
     pub fn get_flight_exchanges(&self, params: &ExchangeParams) -> Result<Vec<FlightExchange>> {
         match params {
             ExchangeParams::MergeExchange(params) => {
+                if let (true, Some(fragments_retry)) = (params.retryable, &self.fragments_retry) {
+                    return Ok(vec![
+                        fragments_retry.get_flight_exchange(params.fragment_id)?,
+                    ]);
+                }
+
+                if !params.ordered_sources.is_empty() {
+                    // The exchanges of the ordered sources in their order, dummy for localhost.
+                    return params
+                        .ordered_sources
+                        .iter()
+                        .map(|source| match source == &params.destination_id {
+                            true => Ok(FlightExchange::Dummy),
+                            false => match self
+                                .fragment_exchanges
+                                .get(&(source.clone(), params.fragment_id))
+                            {
+                                None => Err(ErrorCode::UnknownFragmentExchange(format!(
+                                    "Unknown fragment exchange channel, {}, {}",
+                                    source, params.fragment_id
+                                ))),
+                                Some(exchange_channel) => Ok(exchange_channel.clone()),
+                            },
+                        })
+                        .collect();
+                }
+
                 let mut exchanges = vec![];
                 for ((_target, fragment), exchange) in &self.fragment_exchanges {
                     if *fragment == params.fragment_id {
@@ -443,6 +553,10 @@ impl QueryCoordinator {
             query_executor: None,
         });
 
+        if packet.enable_profiling {
+            ctx.enable_plan_profiling();
+        }
+
         for fragment in &packet.fragments {
             self.fragments_coordinator.insert(
                 fragment.fragment_id.to_owned(),
@@ -512,6 +626,9 @@ impl QueryCoordinator {
 
     pub fn on_finished(self) {
         // Do something when query finished.
+        if let Some(fragments_retry) = &self.fragments_retry {
+            fragments_retry.shutdown();
+        }
     }
 
     pub fn execute_pipeline(&mut self) -> Result<()> {
@@ -550,9 +667,7 @@ impl QueryCoordinator {
             }
         }
 
-        let query_id = info.query_ctx.get_id();
-        let executor_settings =
-            ExecutorSettings::try_create(&info.query_ctx.get_settings(), query_id)?;
+        let executor_settings = ExecutorSettings::try_create(&info.query_ctx)?;
 
         let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
 
@@ -570,6 +685,10 @@ impl QueryCoordinator {
             ));
         }
 
+        if partition_stealing_enabled(&query_ctx)? {
+            query_ctx.enable_partition_stealing();
+        }
+
         let ctx = query_ctx.clone();
         let mut statistics_sender =
             StatisticsSender::create(&query_id, ctx, request_server_exchanges.remove(0));
@@ -586,6 +705,13 @@ impl QueryCoordinator {
     }
 }
 
+// The coordinator and the other nodes decide by the same settings, so they always agree.
+// The partitions of the retryable fragments are tracked by batches, they are never stolen.
+fn partition_stealing_enabled(ctx: &QueryContext) -> Result<bool> {
+    let settings = ctx.get_settings();
+    Ok(settings.get_enable_partition_stealing()? && !settings.get_enable_fragment_retry()?)
+}
+
 struct FragmentCoordinator {
     payload: FragmentPayload,
     initialized: bool,
@@ -614,6 +740,8 @@ impl FragmentCoordinator {
                     fragment_id: self.fragment_id,
                     query_id: info.query_id.to_string(),
                     destination_id: exchange.destination_id.clone(),
+                    retryable: exchange.retryable,
+                    ordered_sources: exchange.ordered_sources.clone(),
                 }))
             }
             Some(DataExchange::Broadcast(exchange)) => {
@@ -626,6 +754,46 @@ impl FragmentCoordinator {
                     shuffle_scatter: Arc::new(Box::new(BroadcastFlightScatter::try_create(
                         exchange.destination_ids.len(),
                     )?)),
+                    range_partition: None,
+                    skew_shuffle: None,
+                }))
+            }
+            Some(DataExchange::ShuffleDataExchange(exchange)) if exchange.skew.is_some() => {
+                let skew = exchange.skew.as_ref().unwrap();
+                let num_nodes = exchange.destination_ids.len();
+
+                // Every executor produces a part of the build side and samples it.
+                let desc = SkewShuffleDesc {
+                    id: skew.id,
+                    partitions: num_nodes,
+                    num_nodes,
+                    plan_id: skew.plan_id,
+                };
+                let hasher = SkewKeyHasher::try_create(
+                    info.query_ctx.try_get_function_context()?,
+                    &exchange.shuffle_keys,
+                )?;
+
+                Ok(ExchangeParams::ShuffleExchange(ShuffleExchangeParams {
+                    schema: self.payload.schema()?,
+                    fragment_id: self.fragment_id,
+                    query_id: info.query_id.to_string(),
+                    executor_id: info.current_executor.to_string(),
+                    destination_ids: exchange.destination_ids.to_owned(),
+                    shuffle_scatter: Arc::new(SkewFlightScatter::try_create(
+                        info.query_ctx.clone(),
+                        hasher.clone(),
+                        skew.id,
+                        skew.plan_id,
+                        skew.kind == SkewShuffleKind::Build,
+                        num_nodes,
+                    )?),
+                    range_partition: None,
+                    skew_shuffle: Some(SkewShuffleParams {
+                        desc,
+                        build: skew.kind == SkewShuffleKind::Build,
+                        hasher,
+                    }),
                 }))
             }
             Some(DataExchange::ShuffleDataExchange(exchange)) => {
@@ -640,6 +808,47 @@ impl FragmentCoordinator {
                         exchange.shuffle_keys.clone(),
                         exchange.destination_ids.len(),
                     )?),
+                    range_partition: None,
+                    skew_shuffle: None,
+                }))
+            }
+            Some(DataExchange::Range(exchange)) => {
+                let schema = self.payload.schema()?;
+                let key_offsets = exchange
+                    .order_by
+                    .iter()
+                    .map(|desc| schema.index_of(&desc.order_by.to_string()))
+                    .collect::<Result<Vec<_>>>()?;
+
+                // Every executor produces a part of the input and receives a range.
+                let desc = RangePartitionDesc {
+                    id: self.fragment_id,
+                    orders: exchange
+                        .order_by
+                        .iter()
+                        .map(|desc| RangeKeyOrder {
+                            asc: desc.asc,
+                            nulls_first: desc.nulls_first,
+                        })
+                        .collect(),
+                    partitions: exchange.destination_ids.len(),
+                    num_ranges: exchange.destination_ids.len(),
+                };
+
+                Ok(ExchangeParams::ShuffleExchange(ShuffleExchangeParams {
+                    schema,
+                    fragment_id: self.fragment_id,
+                    query_id: info.query_id.to_string(),
+                    executor_id: info.current_executor.to_string(),
+                    destination_ids: exchange.destination_ids.to_owned(),
+                    shuffle_scatter: Arc::new(RangeFlightScatter::try_create(
+                        info.query_ctx.clone(),
+                        self.fragment_id,
+                        key_offsets.clone(),
+                        exchange.destination_ids.len(),
+                    )?),
+                    range_partition: Some(RangePartitionParams { desc, key_offsets }),
+                    skew_shuffle: None,
                 }))
             }
         }
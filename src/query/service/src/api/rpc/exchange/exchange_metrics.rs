@@ -0,0 +1,60 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use metrics::counter;
+
+use crate::sessions::QueryContext;
+
+macro_rules! key {
+    ($key: literal) => {
+        concat!("exchange_", $key)
+    };
+}
+
+pub fn metrics_inc_exchange_skew_detected(hot_keys: usize) {
+    counter!(key!("skew_detected"), 1);
+    counter!(key!("skew_hot_keys"), hot_keys as u64);
+}
+
+pub fn metrics_inc_exchange_skew_rows(rows: usize) {
+    counter!(key!("skew_redistributed_rows"), rows as u64);
+}
+
+/// Record the hot keys detected for a skew-aware shuffle, into the metrics and
+/// the profile of the join or the aggregation.
+pub fn record_skew_detected(ctx: &Arc<QueryContext>, plan_id: usize, hot_keys: usize) {
+    if hot_keys == 0 {
+        return;
+    }
+
+    metrics_inc_exchange_skew_detected(hot_keys);
+    if let Some(profiles) = ctx.get_plan_profiles() {
+        profiles.get_or_create(plan_id).add_skew(hot_keys, 0);
+    }
+}
+
+/// Record the rows with hot keys that are spread or broadcast by a skew-aware shuffle,
+/// or the partial states of the hot keys that are merged before it.
+pub fn record_skew_rows(ctx: &Arc<QueryContext>, plan_id: usize, rows: usize) {
+    if rows == 0 {
+        return;
+    }
+
+    metrics_inc_exchange_skew_rows(rows);
+    if let Some(profiles) = ctx.get_plan_profiles() {
+        profiles.get_or_create(plan_id).add_skew(0, rows);
+    }
+}
@@ -17,11 +17,14 @@ use std::sync::Arc;
 use common_arrow::arrow::io::ipc::write::default_ipc_fields;
 use common_arrow::arrow::io::ipc::write::WriteOptions;
 use common_arrow::arrow::io::ipc::IpcField;
+use common_catalog::plan::RangePartitionDesc;
+use common_catalog::plan::SkewShuffleDesc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 
 use crate::api::rpc::flight_scatter::FlightScatter;
+use crate::api::rpc::flight_scatter_skew::SkewKeyHasher;
 
 #[derive(Clone)]
 pub struct SerializeParams {
@@ -38,6 +41,24 @@ pub struct ShuffleExchangeParams {
     pub schema: DataSchemaRef,
     pub destination_ids: Vec<String>,
     pub shuffle_scatter: Arc<Box<dyn FlightScatter>>,
+    pub range_partition: Option<RangePartitionParams>,
+    pub skew_shuffle: Option<SkewShuffleParams>,
+}
+
+/// The input of a range exchange is sampled to compute the bounds of the ranges before it is scattered.
+#[derive(Clone)]
+pub struct RangePartitionParams {
+    pub desc: RangePartitionDesc,
+    pub key_offsets: Vec<usize>,
+}
+
+/// The build side of a skew-aware shuffle is sampled to detect the hot keys,
+/// and the both sides wait for the hot keys before they are scattered.
+#[derive(Clone)]
+pub struct SkewShuffleParams {
+    pub desc: SkewShuffleDesc,
+    pub build: bool,
+    pub hasher: SkewKeyHasher,
 }
 
 #[derive(Clone)]
@@ -46,6 +67,8 @@ pub struct MergeExchangeParams {
     pub fragment_id: usize,
     pub destination_id: String,
     pub schema: DataSchemaRef,
+    pub retryable: bool,
+    pub ordered_sources: Vec<String>,
 }
 
 pub enum ExchangeParams {
@@ -0,0 +1,330 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::plan::RangeBlockMeta;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+
+use crate::api::rpc::exchange::exchange_params::ExchangeParams;
+use crate::api::rpc::exchange::exchange_params::MergeExchangeParams;
+use crate::api::rpc::exchange::exchange_transform_source::deserialize_fragment_data;
+use crate::api::rpc::flight_client::FlightExchange;
+use crate::api::DataPacket;
+use crate::clusters::ClusterHelper;
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::Pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Tags the sorted blocks of a range with their positions, an empty block is
+/// sent as the last block if the range has no data.
+pub struct TransformRangeTag {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    schema: DataSchemaRef,
+    range: usize,
+    seq: usize,
+    pending: Option<DataBlock>,
+    finished: bool,
+}
+
+impl TransformRangeTag {
+    /// Tag the output of the local range, the sorted output must be in a single lane.
+    pub fn via(
+        ctx: &Arc<QueryContext>,
+        params: &MergeExchangeParams,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let local_id = ctx.get_cluster().local_id();
+        let range = match params.ordered_sources.iter().position(|id| id == &local_id) {
+            Some(range) => range,
+            None => {
+                return Err(ErrorCode::Internal(format!(
+                    "Not found local executor {} in the ordered sources of fragment {}.",
+                    local_id, params.fragment_id
+                )));
+            }
+        };
+
+        pipeline.resize(1)?;
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(Box::new(TransformRangeTag {
+                input,
+                output,
+                schema: params.schema.clone(),
+                range,
+                seq: 0,
+                pending: None,
+                finished: false,
+            })))
+        })
+    }
+
+    fn tag(&mut self, data_block: DataBlock, last: bool) -> Result<DataBlock> {
+        let meta = RangeBlockMeta::create(self.range, self.seq, last);
+        self.seq += 1;
+        data_block.add_meta(Some(meta))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformRangeTag {
+    fn name(&self) -> String {
+        "TransformRangeTag".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.finished {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.input.has_data() {
+            let data_block = self.input.pull_data().unwrap()?;
+
+            // Hold back a block, so that the last block can be tagged.
+            if !data_block.is_empty() {
+                if let Some(pending) = self.pending.replace(data_block) {
+                    let data_block = self.tag(pending, false)?;
+                    self.output.push_data(Ok(data_block));
+                    return Ok(Event::NeedConsume);
+                }
+            }
+        }
+
+        if self.input.is_finished() {
+            let data_block = match self.pending.take() {
+                Some(pending) => pending,
+                None => DataBlock::empty_with_schema(self.schema.clone()),
+            };
+            let data_block = self.tag(data_block, true)?;
+            self.output.push_data(Ok(data_block));
+            self.finished = true;
+            return Ok(Event::NeedConsume);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+}
+
+/// Concatenates the ranges of an ordered merge exchange in the order of the ranges.
+/// Only the source of the current range is read, the others are held back by the
+/// bounded flight channels and the local input port. The blocks keep their
+/// [`RangeBlockMeta`], so that the sinks can tell the ranges apart.
+pub struct TransformRangeConcat {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    schema: DataSchemaRef,
+    // The exchanges of the ranges, dummy for the local range.
+    flight_exchanges: Vec<FlightExchange>,
+    range: usize,
+    seq: usize,
+    remote_flight_data: Option<DataPacket>,
+    output_data: Option<DataBlock>,
+}
+
+impl TransformRangeConcat {
+    pub fn via(
+        ctx: &Arc<QueryContext>,
+        params: &MergeExchangeParams,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let exchange_params = ExchangeParams::MergeExchange(params.clone());
+        let exchange_manager = ctx.get_exchange_manager();
+
+        pipeline.resize(1)?;
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(Box::new(TransformRangeConcat {
+                input,
+                output,
+                schema: params.schema.clone(),
+                flight_exchanges: exchange_manager.get_flight_exchanges(&exchange_params)?,
+                range: 0,
+                seq: 0,
+                remote_flight_data: None,
+                output_data: None,
+            })))
+        })
+    }
+
+    fn is_local_range(&self) -> bool {
+        matches!(self.flight_exchanges[self.range], FlightExchange::Dummy)
+    }
+
+    fn add_block(&mut self, data_block: DataBlock) -> Result<()> {
+        let meta = match data_block.get_meta() {
+            Some(meta) => RangeBlockMeta::from_meta(meta)?.clone(),
+            None => {
+                return Err(ErrorCode::Internal(
+                    "Block of ordered merge exchange has no RangeBlockMeta.",
+                ));
+            }
+        };
+
+        if meta.range != self.range || meta.seq != self.seq {
+            return Err(ErrorCode::Internal(format!(
+                "Ordered merge exchange expects block {} of range {}, but got block {} of range {}.",
+                self.seq, self.range, meta.seq, meta.range
+            )));
+        }
+
+        match meta.last {
+            true => {
+                self.range += 1;
+                self.seq = 0;
+            }
+            false => self.seq += 1,
+        }
+
+        if !data_block.is_empty() {
+            self.output_data = Some(data_block);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformRangeConcat {
+    fn name(&self) -> String {
+        "TransformRangeConcat".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.remote_flight_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if let Some(data_block) = self.output_data.take() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        loop {
+            if self.range == self.flight_exchanges.len() {
+                self.input.finish();
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+
+            if !self.is_local_range() {
+                self.input.set_not_need_data();
+                return match self.flight_exchanges[self.range].try_recv()? {
+                    Some(remote_flight_data) => {
+                        self.remote_flight_data = Some(remote_flight_data);
+                        Ok(Event::Sync)
+                    }
+                    None => Ok(Event::Async),
+                };
+            }
+
+            if !self.input.has_data() {
+                break;
+            }
+
+            let data_block = self.input.pull_data().unwrap()?;
+            self.add_block(data_block)?;
+
+            if let Some(data_block) = self.output_data.take() {
+                self.output.push_data(Ok(data_block));
+                return Ok(Event::NeedConsume);
+            }
+        }
+
+        if self.input.is_finished() {
+            return Err(ErrorCode::Internal(format!(
+                "Ordered merge exchange finished with {} of {} ranges.",
+                self.range,
+                self.flight_exchanges.len()
+            )));
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(remote_data) = self.remote_flight_data.take() {
+            return match remote_data {
+                DataPacket::ErrorCode(cause) => Err(cause),
+                DataPacket::FragmentData(fragment_data) => {
+                    let data_block = deserialize_fragment_data(&self.schema, fragment_data)?;
+                    self.add_block(data_block)
+                }
+                DataPacket::ClosingClient => Ok(()),
+                DataPacket::FetchProgressAndPrecommit => unreachable!(),
+                DataPacket::ProgressAndPrecommit { .. } => unreachable!(),
+                DataPacket::RuntimeFilters(_) => unreachable!(),
+                DataPacket::FragmentFinished => unreachable!(),
+                DataPacket::RangeBounds(_) => unreachable!(),
+                DataPacket::HotKeys(_) => unreachable!(),
+                DataPacket::PartitionSteals(_) => unreachable!(),
+            };
+        }
+
+        Ok(())
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        match self.flight_exchanges[self.range].recv().await? {
+            Some(data_packet) => {
+                self.remote_flight_data = Some(data_packet);
+                Ok(())
+            }
+            None => Err(ErrorCode::Internal(format!(
+                "Ordered merge exchange finished with {} of {} ranges.",
+                self.range,
+                self.flight_exchanges.len()
+            ))),
+        }
+    }
+}
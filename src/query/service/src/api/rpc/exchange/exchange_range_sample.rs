@@ -0,0 +1,97 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::RangeSample;
+use common_catalog::plan::RANGE_PARTITION_SAMPLE_SIZE;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+
+use crate::api::rpc::exchange::exchange_params::RangePartitionParams;
+use crate::api::rpc::exchange::exchange_sample::ExchangeSampler;
+use crate::api::rpc::exchange::exchange_sample::Reservoir;
+use crate::api::rpc::exchange::exchange_sample::TransformExchangeSample;
+use crate::api::rpc::flight_scatter_range::range_key_columns;
+use crate::pipelines::Pipeline;
+use crate::sessions::QueryContext;
+
+/// Samples the sort keys of the input of a range exchange, the input is held
+/// until the bounds of the ranges are ready.
+struct RangeSampler {
+    ctx: Arc<QueryContext>,
+    params: RangePartitionParams,
+}
+
+impl ExchangeSampler for RangeSampler {
+    type Item = Vec<Scalar>;
+    type Sample = RangeSample;
+
+    fn name(&self) -> String {
+        "TransformRangeSample".to_string()
+    }
+
+    fn sample_size(&self) -> usize {
+        RANGE_PARTITION_SAMPLE_SIZE
+    }
+
+    fn sample_block(
+        &self,
+        data_block: &DataBlock,
+        reservoir: &mut Reservoir<Vec<Scalar>>,
+    ) -> Result<()> {
+        let columns = range_key_columns(data_block, &self.params.key_offsets);
+        reservoir.offer(data_block.num_rows(), |row| {
+            Ok(columns
+                .iter()
+                .map(|column| column.index(row).unwrap().to_owned())
+                .collect())
+        })
+    }
+
+    fn create_sample(&self, keys: Vec<Vec<Scalar>>, num_rows: u64) -> RangeSample {
+        RangeSample::create(&self.params.desc.orders, keys, num_rows)
+    }
+
+    fn merge_sample(&self, sample: &mut RangeSample, other: RangeSample) {
+        sample.merge(&self.params.desc.orders, other)
+    }
+
+    fn publish(&self, sample: RangeSample) {
+        self.ctx.publish_range_sample(&self.params.desc, sample)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ctx.get_range_bounds(self.params.desc.id).is_some()
+    }
+}
+
+/// Buffers the input of a range exchange until it is sampled and the bounds of
+/// the ranges are ready, the blocks can be scattered by the ranges after that.
+pub struct TransformRangeSample;
+
+impl TransformRangeSample {
+    pub fn via(
+        ctx: &Arc<QueryContext>,
+        params: &RangePartitionParams,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let sampler = RangeSampler {
+            ctx: ctx.clone(),
+            params: params.clone(),
+        };
+        TransformExchangeSample::via(ctx, sampler, true, pipeline)
+    }
+}
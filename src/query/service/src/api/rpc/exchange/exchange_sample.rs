@@ -0,0 +1,266 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use parking_lot::Mutex;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::Pipeline;
+use crate::sessions::QueryContext;
+
+/// The max number of rows buffered on a node to sample the input of an exchange.
+const EXCHANGE_SAMPLE_BUFFER_ROWS: usize = 1024 * 1024;
+
+/// Uniform sample of a fixed number of rows out of the rows seen so far (reservoir sampling).
+pub struct Reservoir<T> {
+    capacity: usize,
+    seen: u64,
+    items: Vec<T>,
+    rng: StdRng,
+}
+
+impl<T> Reservoir<T> {
+    pub fn create(capacity: usize) -> Reservoir<T> {
+        Reservoir {
+            capacity,
+            seen: 0,
+            items: vec![],
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Offer the rows of a block, `item` builds the item of a row only if the row is picked.
+    pub fn offer(
+        &mut self,
+        num_rows: usize,
+        mut item: impl FnMut(usize) -> Result<T>,
+    ) -> Result<()> {
+        for row in 0..num_rows {
+            self.seen += 1;
+            if self.items.len() < self.capacity {
+                self.items.push(item(row)?);
+                continue;
+            }
+
+            let slot = self.rng.gen_range(0..self.seen);
+            if slot < self.capacity as u64 {
+                self.items[slot as usize] = item(row)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builds the samples of the input of an exchange and waits for the result
+/// of the samples of all the nodes.
+pub trait ExchangeSampler: Send + Sync + 'static {
+    /// The item sampled from a row.
+    type Item: Send;
+    /// The sample of the rows of a lane, or of all the lanes of the node.
+    type Sample: Default + Send;
+
+    fn name(&self) -> String;
+
+    /// The max number of the items sampled on a lane.
+    fn sample_size(&self) -> usize;
+
+    /// Offer the rows of a block to the reservoir of a lane.
+    fn sample_block(
+        &self,
+        data_block: &DataBlock,
+        reservoir: &mut Reservoir<Self::Item>,
+    ) -> Result<()>;
+
+    /// Create the sample of a lane from the items sampled from `num_rows` rows.
+    fn create_sample(&self, items: Vec<Self::Item>, num_rows: u64) -> Self::Sample;
+
+    fn merge_sample(&self, sample: &mut Self::Sample, other: Self::Sample);
+
+    /// Publish the sample of all the lanes of this node.
+    fn publish(&self, sample: Self::Sample);
+
+    /// The samples of all the nodes have been merged, the input can be exchanged.
+    fn is_ready(&self) -> bool;
+}
+
+/// Merges the samples of all the lanes of the pipeline, the sample of the node
+/// is published after every lane has reported.
+struct SampleCollector<S: ExchangeSampler> {
+    sampler: Arc<S>,
+    state: Mutex<(usize, S::Sample)>,
+}
+
+impl<S: ExchangeSampler> SampleCollector<S> {
+    fn add(&self, sample: S::Sample) {
+        let mut state = self.state.lock();
+        self.sampler.merge_sample(&mut state.1, sample);
+        state.0 -= 1;
+
+        if state.0 == 0 {
+            self.sampler.publish(std::mem::take(&mut state.1));
+        }
+    }
+}
+
+/// Buffers the input of an exchange until it is sampled and the samples of all the
+/// nodes are merged, the blocks can be scattered by the merged result after that.
+/// The input is only held if it is not sampled on this side of the exchange.
+pub struct TransformExchangeSample<S: ExchangeSampler> {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    ctx: Arc<QueryContext>,
+    sampler: Arc<S>,
+    collector: Option<Arc<SampleCollector<S>>>,
+    buffer_rows: usize,
+    buffered_rows: usize,
+    buffered: VecDeque<DataBlock>,
+    sampled: bool,
+    ready: bool,
+}
+
+impl<S: ExchangeSampler> TransformExchangeSample<S> {
+    pub fn via(
+        ctx: &Arc<QueryContext>,
+        sampler: S,
+        sample: bool,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let lanes = pipeline.output_len();
+        let sampler = Arc::new(sampler);
+        let collector = match sample {
+            true => Some(Arc::new(SampleCollector {
+                sampler: sampler.clone(),
+                state: Mutex::new((lanes, S::Sample::default())),
+            })),
+            false => None,
+        };
+
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(Box::new(TransformExchangeSample {
+                input,
+                output,
+                ctx: ctx.clone(),
+                sampler: sampler.clone(),
+                collector: collector.clone(),
+                buffer_rows: EXCHANGE_SAMPLE_BUFFER_ROWS / lanes,
+                buffered_rows: 0,
+                buffered: VecDeque::new(),
+                sampled: !sample,
+                ready: false,
+            })))
+        })
+    }
+
+    fn sample(&mut self) -> Result<()> {
+        let mut reservoir = Reservoir::create(self.sampler.sample_size());
+        for data_block in &self.buffered {
+            self.sampler.sample_block(data_block, &mut reservoir)?;
+        }
+
+        let sample = self
+            .sampler
+            .create_sample(reservoir.items, self.buffered_rows as u64);
+        if let Some(collector) = &self.collector {
+            collector.add(sample);
+        }
+        self.sampled = true;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: ExchangeSampler> Processor for TransformExchangeSample<S> {
+    fn name(&self) -> String {
+        self.sampler.name()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.ready {
+            if !self.output.can_push() {
+                self.input.set_not_need_data();
+                return Ok(Event::NeedConsume);
+            }
+
+            if let Some(data_block) = self.buffered.pop_front() {
+                self.output.push_data(Ok(data_block));
+                return Ok(Event::NeedConsume);
+            }
+
+            if self.input.is_finished() {
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+
+            if self.input.has_data() {
+                self.output.push_data(self.input.pull_data().unwrap());
+                return Ok(Event::NeedConsume);
+            }
+
+            self.input.set_need_data();
+            return Ok(Event::NeedData);
+        }
+
+        if self.sampled {
+            return Ok(Event::Async);
+        }
+
+        if self.input.has_data() {
+            let data_block = self.input.pull_data().unwrap()?;
+            self.buffered_rows += data_block.num_rows();
+            self.buffered.push_back(data_block);
+        }
+
+        if self.input.is_finished() || self.buffered_rows >= self.buffer_rows {
+            return Ok(Event::Sync);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        self.sample()
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        let sampler = self.sampler.clone();
+        self.ctx
+            .wait_exchange_sample(move || sampler.is_ready())
+            .await?;
+
+        self.ready = true;
+        Ok(())
+    }
+}
@@ -18,8 +18,11 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::api::rpc::exchange::exchange_params::ExchangeParams;
+use crate::api::rpc::exchange::exchange_range_concat::TransformRangeTag;
+use crate::api::rpc::exchange::exchange_range_sample::TransformRangeSample;
 use crate::api::rpc::exchange::exchange_sink_merge::ExchangeMergeSink;
 use crate::api::rpc::exchange::exchange_sink_shuffle::ExchangePublisherSink;
+use crate::api::rpc::exchange::exchange_skew_shuffle::TransformSkewShuffle;
 use crate::clusters::ClusterHelper;
 use crate::pipelines::Pipeline;
 use crate::sessions::QueryContext;
@@ -44,10 +47,29 @@ impl ExchangeSink {
                     )));
                 }
 
+                if params.retryable {
+                    // Only one sink is able to know that all the data has been sent.
+                    pipeline.resize(1)?;
+                }
+
+                if !params.ordered_sources.is_empty() {
+                    TransformRangeTag::via(ctx, params, pipeline)?;
+                }
+
                 pipeline.add_sink(|input| ExchangeMergeSink::try_create(ctx.clone(), input, params))
             }
-            ExchangeParams::ShuffleExchange(params) => pipeline
-                .add_sink(|input| ExchangePublisherSink::try_create(ctx.clone(), input, params)),
+            ExchangeParams::ShuffleExchange(params) => {
+                if let Some(range_partition) = &params.range_partition {
+                    TransformRangeSample::via(ctx, range_partition, pipeline)?;
+                }
+
+                if let Some(skew_shuffle) = &params.skew_shuffle {
+                    TransformSkewShuffle::via(ctx, skew_shuffle, pipeline)?;
+                }
+
+                pipeline
+                    .add_sink(|input| ExchangePublisherSink::try_create(ctx.clone(), input, params))
+            }
         }
     }
 }
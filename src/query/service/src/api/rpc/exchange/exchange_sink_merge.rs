@@ -38,6 +38,8 @@ pub struct ExchangeMergeSink {
     output_data: Option<DataPacket>,
     serialize_params: SerializeParams,
     flight_exchange: FlightExchange,
+    retryable: bool,
+    finished_sent: bool,
 }
 
 impl ExchangeMergeSink {
@@ -57,6 +59,8 @@ impl ExchangeMergeSink {
             output_data: None,
             flight_exchange: flight_exchange.remove(0),
             serialize_params: exchange_params.create_serialize_params()?,
+            retryable: exchange_params.retryable,
+            finished_sent: false,
         })))
     }
 }
@@ -81,6 +85,12 @@ impl Processor for ExchangeMergeSink {
         }
 
         if self.input.is_finished() {
+            if self.retryable && !self.finished_sent {
+                self.finished_sent = true;
+                self.output_data = Some(DataPacket::FragmentFinished);
+                return Ok(Event::Async);
+            }
+
             self.flight_exchange.close_output();
             return Ok(Event::Finished);
         }
@@ -96,7 +106,8 @@ impl Processor for ExchangeMergeSink {
 
     fn process(&mut self) -> Result<()> {
         if let Some(data_block) = self.input_data.take() {
-            if data_block.is_empty() {
+            // Empty blocks with meta may mark the end of an ordered range.
+            if data_block.is_empty() && data_block.get_meta().is_none() {
                 return Ok(());
             }
 
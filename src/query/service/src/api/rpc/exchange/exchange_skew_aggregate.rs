@@ -0,0 +1,438 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_catalog::plan::SkewSample;
+use common_catalog::plan::SkewShuffleDesc;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::HashMethod;
+use common_expression::HashMethodKind;
+use common_sql::executor::SkewShuffle;
+use parking_lot::Mutex;
+
+use crate::api::rpc::exchange::exchange_metrics::record_skew_detected;
+use crate::api::rpc::exchange::exchange_metrics::record_skew_rows;
+use crate::api::rpc::exchange::exchange_sample::ExchangeSampler;
+use crate::api::rpc::exchange::exchange_sample::Reservoir;
+use crate::api::rpc::exchange::exchange_sample::TransformExchangeSample;
+use crate::clusters::ClusterHelper;
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::transforms::group_by::KeysColumnIter;
+use crate::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
+use crate::pipelines::processors::transforms::AggregateInfo;
+use crate::pipelines::processors::transforms::BucketAggregator;
+use crate::pipelines::processors::AggregatorParams;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::Pipeline;
+use crate::sessions::QueryContext;
+
+/// Counts the hashes of the group keys of the input of a partial aggregation,
+/// the input is held until the hot keys are detected.
+struct SkewAggregateSampler<Method: HashMethod> {
+    ctx: Arc<QueryContext>,
+    method: Method,
+    params: Arc<AggregatorParams>,
+    desc: SkewShuffleDesc,
+}
+
+impl<Method> ExchangeSampler for SkewAggregateSampler<Method>
+where Method: HashMethod + PolymorphicKeysHelper<Method> + Send + Sync + 'static
+{
+    type Item = u64;
+    type Sample = SkewSample;
+
+    fn name(&self) -> String {
+        "TransformSkewAggregateSample".to_string()
+    }
+
+    fn sample_size(&self) -> usize {
+        // The keys of all the buffered rows are counted.
+        usize::MAX
+    }
+
+    fn sample_block(&self, data_block: &DataBlock, reservoir: &mut Reservoir<u64>) -> Result<()> {
+        let data_block = data_block.convert_to_full();
+        let group_columns = self
+            .params
+            .group_columns
+            .iter()
+            .map(|index| {
+                let entry = data_block.get_by_offset(*index);
+                (
+                    entry.value.as_column().unwrap().clone(),
+                    entry.data_type.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let keys_state = self
+            .method
+            .build_keys_state(&group_columns, data_block.num_rows())?;
+        let hashes = self
+            .method
+            .build_keys_iter(&keys_state)?
+            .map(|key| self.method.get_hash(key))
+            .collect::<Vec<_>>();
+        reservoir.offer(hashes.len(), |row| Ok(hashes[row]))
+    }
+
+    fn create_sample(&self, hashes: Vec<u64>, _num_rows: u64) -> SkewSample {
+        SkewSample::create(self.desc.num_nodes, &hashes)
+    }
+
+    fn merge_sample(&self, sample: &mut SkewSample, other: SkewSample) {
+        sample.merge(other)
+    }
+
+    fn publish(&self, sample: SkewSample) {
+        if let Some(hot_keys) = self.ctx.publish_skew_sample(&self.desc, sample) {
+            record_skew_detected(&self.ctx, self.desc.plan_id, hot_keys.len());
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ctx.get_hot_keys(self.desc.id).is_some()
+    }
+}
+
+/// The partial states of the hot keys of all the lanes of a node, they are merged
+/// after the last lane is finished.
+struct HotStates {
+    lanes: usize,
+    rows: usize,
+    buckets: HashMap<isize, Vec<DataBlock>>,
+}
+
+/// Merges the partial states of the hot keys of all the lanes into one state per key
+/// before the shuffle, the states of the other keys are passed through.
+struct TransformMergeHotStates<const HAS_AGG: bool, Method>
+where Method: HashMethod + PolymorphicKeysHelper<Method> + Send + 'static
+{
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    ctx: Arc<QueryContext>,
+    method: Method,
+    params: Arc<AggregatorParams>,
+    skew: SkewShuffle,
+    hot_states: Arc<Mutex<HotStates>>,
+    input_block: Option<DataBlock>,
+    output_blocks: VecDeque<DataBlock>,
+    finished: bool,
+}
+
+/// Makes the shuffle of the partial states of an aggregation aware of the hot group keys.
+/// The hot keys are detected from the input of the partial aggregation, and each node
+/// sends one partial state of a hot key instead of one from every lane.
+pub struct TransformSkewAggregate;
+
+impl TransformSkewAggregate {
+    /// Sample the group keys of the input of the partial aggregation on all the nodes.
+    pub fn sample_via(
+        ctx: &Arc<QueryContext>,
+        skew: &SkewShuffle,
+        params: &Arc<AggregatorParams>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let num_nodes = ctx.get_cluster().get_nodes().len();
+        // Every node runs the partial aggregation and samples its input.
+        let desc = SkewShuffleDesc {
+            id: skew.id,
+            partitions: num_nodes,
+            num_nodes,
+            plan_id: skew.plan_id,
+        };
+
+        macro_rules! sample_via {
+            ($method: expr) => {{
+                let sampler = SkewAggregateSampler {
+                    ctx: ctx.clone(),
+                    method: $method,
+                    params: params.clone(),
+                    desc,
+                };
+                TransformExchangeSample::via(ctx, sampler, true, pipeline)
+            }};
+        }
+
+        match DataBlock::choose_hash_method_with_types(&params.group_data_types)? {
+            HashMethodKind::KeysU8(method) => sample_via!(method),
+            HashMethodKind::KeysU16(method) => sample_via!(method),
+            HashMethodKind::KeysU32(method) => sample_via!(method),
+            HashMethodKind::KeysU64(method) => sample_via!(method),
+            HashMethodKind::KeysU128(method) => sample_via!(method),
+            HashMethodKind::KeysU256(method) => sample_via!(method),
+            HashMethodKind::KeysU512(method) => sample_via!(method),
+            HashMethodKind::Serializer(method) => sample_via!(method),
+        }
+    }
+
+    /// Merge the partial states of the hot keys of all the lanes of the partial aggregation.
+    pub fn merge_via(
+        ctx: &Arc<QueryContext>,
+        skew: &SkewShuffle,
+        params: &Arc<AggregatorParams>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        match DataBlock::choose_hash_method_with_types(&params.group_data_types)? {
+            HashMethodKind::KeysU8(method) => {
+                Self::merge_method_via(ctx, skew, params, method, pipeline)
+            }
+            HashMethodKind::KeysU16(method) => {
+                Self::merge_method_via(ctx, skew, params, method, pipeline)
+            }
+            HashMethodKind::KeysU32(method) => {
+                Self::merge_method_via(ctx, skew, params, method, pipeline)
+            }
+            HashMethodKind::KeysU64(method) => {
+                Self::merge_method_via(ctx, skew, params, method, pipeline)
+            }
+            HashMethodKind::KeysU128(method) => {
+                Self::merge_method_via(ctx, skew, params, method, pipeline)
+            }
+            HashMethodKind::KeysU256(method) => {
+                Self::merge_method_via(ctx, skew, params, method, pipeline)
+            }
+            HashMethodKind::KeysU512(method) => {
+                Self::merge_method_via(ctx, skew, params, method, pipeline)
+            }
+            HashMethodKind::Serializer(method) => {
+                Self::merge_method_via(ctx, skew, params, method, pipeline)
+            }
+        }
+    }
+
+    fn merge_method_via<Method>(
+        ctx: &Arc<QueryContext>,
+        skew: &SkewShuffle,
+        params: &Arc<AggregatorParams>,
+        method: Method,
+        pipeline: &mut Pipeline,
+    ) -> Result<()>
+    where
+        Method: HashMethod + PolymorphicKeysHelper<Method> + Send + 'static,
+    {
+        let hot_states = Arc::new(Mutex::new(HotStates {
+            lanes: pipeline.output_len(),
+            rows: 0,
+            buckets: HashMap::new(),
+        }));
+
+        pipeline.add_transform(|input, output| {
+            Ok(match params.aggregate_functions.is_empty() {
+                true => TransformMergeHotStates::<false, Method>::create(
+                    input,
+                    output,
+                    ctx,
+                    method.clone(),
+                    params,
+                    skew,
+                    &hot_states,
+                ),
+                false => TransformMergeHotStates::<true, Method>::create(
+                    input,
+                    output,
+                    ctx,
+                    method.clone(),
+                    params,
+                    skew,
+                    &hot_states,
+                ),
+            })
+        })
+    }
+}
+
+impl<const HAS_AGG: bool, Method> TransformMergeHotStates<HAS_AGG, Method>
+where Method: HashMethod + PolymorphicKeysHelper<Method> + Send + 'static
+{
+    fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        ctx: &Arc<QueryContext>,
+        method: Method,
+        params: &Arc<AggregatorParams>,
+        skew: &SkewShuffle,
+        hot_states: &Arc<Mutex<HotStates>>,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Box::new(TransformMergeHotStates::<HAS_AGG, Method> {
+            input,
+            output,
+            ctx: ctx.clone(),
+            method,
+            params: params.clone(),
+            skew: skew.clone(),
+            hot_states: hot_states.clone(),
+            input_block: None,
+            output_blocks: VecDeque::new(),
+            finished: false,
+        }))
+    }
+
+    /// Keep the states of the hot keys, and pass through the states of the other keys.
+    fn split(&mut self, data_block: DataBlock) -> Result<()> {
+        let bucket = match data_block
+            .get_meta()
+            .and_then(|meta| meta.as_any().downcast_ref::<AggregateInfo>())
+        {
+            // The spilled states are left to the final aggregation.
+            Some(info) if info.overflow.is_some() => None,
+            Some(info) => Some(info.bucket),
+            None => Some(-1),
+        };
+
+        let hot_keys = self.ctx.get_hot_keys(self.skew.id);
+        let (bucket, hot_keys) = match (bucket, hot_keys) {
+            (Some(bucket), Some(hot_keys)) if !hot_keys.is_empty() => (bucket, hot_keys),
+            _ => {
+                self.output_blocks.push_back(data_block);
+                return Ok(());
+            }
+        };
+
+        let block_meta = data_block.meta()?;
+        let data_block = data_block.convert_to_full();
+        let keys_column = data_block
+            .get_by_offset(self.params.aggregate_functions.len())
+            .value
+            .as_column()
+            .unwrap();
+        let keys_iter = self.method.keys_iter_from_column(keys_column)?;
+
+        let mut hot_rows = 0;
+        let indices = keys_iter
+            .iter()
+            .map(|key| match hot_keys.contains(self.method.get_hash(key)) {
+                true => {
+                    hot_rows += 1;
+                    1_u64
+                }
+                false => 0_u64,
+            })
+            .collect::<Vec<_>>();
+
+        if hot_rows == 0 {
+            self.output_blocks
+                .push_back(data_block.add_meta(block_meta)?);
+            return Ok(());
+        }
+
+        let mut data_blocks = DataBlock::scatter(&data_block, &indices, 2)?;
+        let hot_block = data_blocks.pop().unwrap();
+        let cold_block = data_blocks.pop().unwrap();
+        if !cold_block.is_empty() {
+            self.output_blocks
+                .push_back(cold_block.add_meta(block_meta)?);
+        }
+
+        let mut hot_states = self.hot_states.lock();
+        hot_states.rows += hot_rows;
+        hot_states
+            .buckets
+            .entry(bucket)
+            .or_default()
+            .push(hot_block);
+        Ok(())
+    }
+
+    /// The last finished lane merges the states of the hot keys of all the lanes.
+    fn finish(&mut self) -> Result<()> {
+        self.finished = true;
+
+        let (rows, buckets) = {
+            let mut hot_states = self.hot_states.lock();
+            hot_states.lanes -= 1;
+            if hot_states.lanes != 0 {
+                return Ok(());
+            }
+            (hot_states.rows, std::mem::take(&mut hot_states.buckets))
+        };
+
+        for (bucket, data_blocks) in buckets {
+            let mut bucket_aggregator =
+                BucketAggregator::<HAS_AGG, _>::create(self.method.clone(), self.params.clone())?;
+            for data_block in bucket_aggregator.merge_blocks_into_states(data_blocks)? {
+                let block_meta = match bucket {
+                    -1 => None,
+                    bucket => Some(AggregateInfo::create(bucket)),
+                };
+                self.output_blocks
+                    .push_back(data_block.add_meta(block_meta)?);
+            }
+        }
+
+        record_skew_rows(&self.ctx, self.skew.plan_id, rows);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<const HAS_AGG: bool, Method> Processor for TransformMergeHotStates<HAS_AGG, Method>
+where Method: HashMethod + PolymorphicKeysHelper<Method> + Send + 'static
+{
+    fn name(&self) -> String {
+        "TransformMergeHotStates".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input.has_data() {
+            self.input_block = Some(self.input.pull_data().unwrap()?);
+            return Ok(Event::Sync);
+        }
+
+        if self.input.is_finished() {
+            if self.finished {
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+            return Ok(Event::Sync);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match self.input_block.take() {
+            Some(data_block) => self.split(data_block),
+            None => self.finish(),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::SkewSample;
+use common_exception::Result;
+use common_expression::DataBlock;
+
+use crate::api::rpc::exchange::exchange_metrics::record_skew_detected;
+use crate::api::rpc::exchange::exchange_params::SkewShuffleParams;
+use crate::api::rpc::exchange::exchange_sample::ExchangeSampler;
+use crate::api::rpc::exchange::exchange_sample::Reservoir;
+use crate::api::rpc::exchange::exchange_sample::TransformExchangeSample;
+use crate::pipelines::Pipeline;
+use crate::sessions::QueryContext;
+
+/// Counts the hashes of the keys of the build side of a skew-aware shuffle,
+/// the both sides are held until the hot keys are detected.
+struct SkewSampler {
+    ctx: Arc<QueryContext>,
+    params: SkewShuffleParams,
+}
+
+impl ExchangeSampler for SkewSampler {
+    type Item = u64;
+    type Sample = SkewSample;
+
+    fn name(&self) -> String {
+        "TransformSkewShuffle".to_string()
+    }
+
+    fn sample_size(&self) -> usize {
+        // The keys of all the buffered rows are counted.
+        usize::MAX
+    }
+
+    fn sample_block(&self, data_block: &DataBlock, reservoir: &mut Reservoir<u64>) -> Result<()> {
+        let hashes = self.params.hasher.hash(data_block)?;
+        reservoir.offer(hashes.len(), |row| Ok(hashes[row]))
+    }
+
+    fn create_sample(&self, hashes: Vec<u64>, _num_rows: u64) -> SkewSample {
+        SkewSample::create(self.params.desc.num_nodes, &hashes)
+    }
+
+    fn merge_sample(&self, sample: &mut SkewSample, other: SkewSample) {
+        sample.merge(other)
+    }
+
+    fn publish(&self, sample: SkewSample) {
+        let desc = &self.params.desc;
+        if let Some(hot_keys) = self.ctx.publish_skew_sample(desc, sample) {
+            record_skew_detected(&self.ctx, desc.plan_id, hot_keys.len());
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ctx.get_hot_keys(self.params.desc.id).is_some()
+    }
+}
+
+/// Holds the input of a skew-aware shuffle until the hot keys are detected. The
+/// build side is buffered and sampled first, the probe side only waits.
+pub struct TransformSkewShuffle;
+
+impl TransformSkewShuffle {
+    pub fn via(
+        ctx: &Arc<QueryContext>,
+        params: &SkewShuffleParams,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let sampler = SkewSampler {
+            ctx: ctx.clone(),
+            params: params.clone(),
+        };
+        TransformExchangeSample::via(ctx, sampler, params.build, pipeline)
+    }
+}
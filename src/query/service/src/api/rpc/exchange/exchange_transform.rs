@@ -33,6 +33,10 @@ use common_pipeline_core::Pipeline;
 use crate::api::rpc::exchange::exchange_params::ExchangeParams;
 use crate::api::rpc::exchange::exchange_params::SerializeParams;
 use crate::api::rpc::exchange::exchange_params::ShuffleExchangeParams;
+use crate::api::rpc::exchange::exchange_range_concat::TransformRangeConcat;
+use crate::api::rpc::exchange::exchange_range_concat::TransformRangeTag;
+use crate::api::rpc::exchange::exchange_range_sample::TransformRangeSample;
+use crate::api::rpc::exchange::exchange_skew_shuffle::TransformSkewShuffle;
 use crate::api::rpc::exchange::exchange_transform_source::ExchangeSourceTransform;
 use crate::api::rpc::flight_client::FlightExchange;
 use crate::api::DataPacket;
@@ -100,16 +104,31 @@ impl ExchangeTransform {
                     )));
                 }
 
-                pipeline.add_transform(|transform_input_port, transform_output_port| {
-                    ExchangeSourceTransform::try_create(
-                        ctx,
-                        transform_input_port,
-                        transform_output_port,
-                        params,
-                    )
-                })
+                if params.ordered_sources.is_empty() {
+                    return pipeline.add_transform(
+                        |transform_input_port, transform_output_port| {
+                            ExchangeSourceTransform::try_create(
+                                ctx,
+                                transform_input_port,
+                                transform_output_port,
+                                params,
+                            )
+                        },
+                    );
+                }
+
+                TransformRangeTag::via(ctx, params, pipeline)?;
+                TransformRangeConcat::via(ctx, params, pipeline)
             }
             ExchangeParams::ShuffleExchange(params) => {
+                if let Some(range_partition) = &params.range_partition {
+                    TransformRangeSample::via(ctx, range_partition, pipeline)?;
+                }
+
+                if let Some(skew_shuffle) = &params.skew_shuffle {
+                    TransformSkewShuffle::via(ctx, skew_shuffle, pipeline)?;
+                }
+
                 pipeline.add_transform(|transform_input_port, transform_output_port| {
                     ExchangeTransform::try_create(
                         ctx.clone(),
@@ -265,6 +284,11 @@ impl Processor for ExchangeTransform {
                 DataPacket::FetchProgressAndPrecommit => unreachable!(),
                 DataPacket::FragmentData(v) => self.on_recv_data(v),
                 DataPacket::ClosingClient => Ok(()),
+                DataPacket::RuntimeFilters(_) => unreachable!(),
+                DataPacket::FragmentFinished => unreachable!(),
+                DataPacket::RangeBounds(_) => unreachable!(),
+                DataPacket::HotKeys(_) => unreachable!(),
+                DataPacket::PartitionSteals(_) => unreachable!(),
             };
         }
 
@@ -21,6 +21,7 @@ use common_arrow::arrow::io::ipc::IpcSchema;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
 
 use crate::api::rpc::exchange::exchange_params::ExchangeParams;
 use crate::api::rpc::exchange::exchange_params::MergeExchangeParams;
@@ -131,6 +132,11 @@ impl Processor for ExchangeSourceTransform {
                 DataPacket::FetchProgressAndPrecommit => unreachable!(),
                 DataPacket::ProgressAndPrecommit { .. } => unreachable!(),
                 DataPacket::ClosingClient => Ok(()),
+                DataPacket::RuntimeFilters(_) => unreachable!(),
+                DataPacket::FragmentFinished => unreachable!(),
+                DataPacket::RangeBounds(_) => unreachable!(),
+                DataPacket::HotKeys(_) => unreachable!(),
+                DataPacket::PartitionSteals(_) => unreachable!(),
             };
         }
 
@@ -159,29 +165,36 @@ impl ExchangeSourceTransform {
 
     fn on_recv_data(&mut self, fragment_data: FragmentData) -> Result<()> {
         let schema = &self.exchange_params.schema;
-        let arrow_schema = Arc::new(schema.to_arrow());
-        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
-        let ipc_schema = IpcSchema {
-            fields: ipc_fields,
-            is_little_endian: true,
-        };
-
-        let batch = deserialize_batch(
-            &fragment_data.data,
-            &arrow_schema.fields,
-            &ipc_schema,
-            &Default::default(),
-        )?;
-
-        let meta = match bincode::deserialize(fragment_data.get_meta()) {
-            Ok(meta) => Ok(meta),
-            Err(_) => Err(ErrorCode::BadBytes(
-                "block meta deserialize error when exchange",
-            )),
-        }?;
-
-        self.output_data = Some(DataBlock::from_arrow_chunk(&batch, schema)?.add_meta(meta)?);
-
+        self.output_data = Some(deserialize_fragment_data(schema, fragment_data)?);
         Ok(())
     }
 }
+
+/// Deserialize the block and its meta sent by [`FragmentData`].
+pub fn deserialize_fragment_data(
+    schema: &DataSchemaRef,
+    fragment_data: FragmentData,
+) -> Result<DataBlock> {
+    let arrow_schema = Arc::new(schema.to_arrow());
+    let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+    let ipc_schema = IpcSchema {
+        fields: ipc_fields,
+        is_little_endian: true,
+    };
+
+    let batch = deserialize_batch(
+        &fragment_data.data,
+        &arrow_schema.fields,
+        &ipc_schema,
+        &Default::default(),
+    )?;
+
+    let meta = match bincode::deserialize(fragment_data.get_meta()) {
+        Ok(meta) => Ok(meta),
+        Err(_) => Err(ErrorCode::BadBytes(
+            "block meta deserialize error when exchange",
+        )),
+    }?;
+
+    DataBlock::from_arrow_chunk(&batch, schema)?.add_meta(meta)
+}
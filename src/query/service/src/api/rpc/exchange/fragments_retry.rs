@@ -0,0 +1,788 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_channel::Receiver;
+use async_channel::Sender;
+use common_base::base::tokio;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::plan::Partitions;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::NodeInfo;
+use parking_lot::Mutex;
+use tracing::info;
+use tracing::warn;
+
+use crate::api::rpc::exchange::statistics_receiver::StatisticsReceiver;
+use crate::api::rpc::flight_client::FlightExchange;
+use crate::api::rpc::Packet;
+use crate::api::ConnectionInfo;
+use crate::api::DataExchange;
+use crate::api::DataExchangeManager;
+use crate::api::DataPacket;
+use crate::api::ExecutePartialQueryPacket;
+use crate::api::FragmentPayload;
+use crate::api::FragmentPlanPacket;
+use crate::api::InitNodesChannelPacket;
+use crate::api::QueryFragmentsPlanPacket;
+use crate::clusters::ClusterDiscovery;
+use crate::clusters::ClusterHelper;
+use crate::schedulers::QueryFragmentsActions;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::executor::PhysicalPlan;
+use crate::sql::executor::PhysicalPlanReplacer;
+use crate::sql::executor::TableScan;
+
+const MAX_RETRY_ATTEMPTS: usize = 3;
+const CHECK_NODES_INTERVAL: Duration = Duration::from_secs(5);
+/// The partitions of a fragment are split into about this many batches for each node.
+const RETRY_BATCHES_PER_EXECUTOR: usize = 8;
+/// A batch has at least this many partitions, unless the node has fewer, since each batch
+/// is submitted to the node as a query.
+const MIN_RETRY_BATCH_PARTITIONS: usize = 4;
+/// The batches of a node read at the same time, the next batch is scheduled once one of
+/// them is committed.
+const RETRY_INFLIGHT_BATCHES: usize = 3;
+/// The data packets of the committed batches not consumed yet by the downstream exchange,
+/// committing blocks when it's full, thus a slow consumer holds back the next batches.
+const RETRY_CHANNEL_CAPACITY: usize = 16;
+
+// (fragment id, the executor which the partitions were assigned to when scheduling, batch index)
+type Assignment = (usize, String, usize);
+
+/// A leaf fragment merged into the coordinator, which only reads its partitions and
+/// depends on no other fragment, so the partitions of a failed node can be read again
+/// by another node.
+pub struct RetryableFragment {
+    fragment_id: usize,
+    data_exchange: DataExchange,
+    // The plans of the batches of the partitions assigned to each remote executor.
+    batches: HashMap<String, Vec<FragmentPayload>>,
+}
+
+impl RetryableFragment {
+    /// Collect the retryable fragments and mark their exchanges as retryable.
+    ///
+    /// Nothing is retryable unless all the fragments except the root are retryable,
+    /// because a failed node would still fail the other fragments. The batches are sized
+    /// from the partitions of the fragment and the number of its remote executors, which
+    /// are scheduled with the first batch of their partitions.
+    pub fn collect(actions: &mut QueryFragmentsActions) -> Result<Vec<RetryableFragment>> {
+        let local_id = actions.get_local_executor();
+        let fragments_len = actions.fragments_actions.len();
+
+        if fragments_len < 2 {
+            return Ok(vec![]);
+        }
+
+        let non_root_actions = &actions.fragments_actions[..fragments_len - 1];
+        for fragment_actions in non_root_actions {
+            let merge_into_local = matches!(
+                &fragment_actions.data_exchange,
+                Some(DataExchange::Merge(exchange)) if exchange.destination_id == local_id
+            );
+
+            let read_only_leaf =
+                fragment_actions
+                    .fragment_actions
+                    .iter()
+                    .all(|action| match &action.payload {
+                        FragmentPayload::Plan(plan) => Self::is_read_only_leaf(plan),
+                    });
+
+            if !merge_into_local || !read_only_leaf {
+                return Ok(vec![]);
+            }
+        }
+
+        let mut fragments = Vec::with_capacity(fragments_len - 1);
+        for fragment_actions in &mut actions.fragments_actions[..fragments_len - 1] {
+            if let Some(DataExchange::Merge(exchange)) = &mut fragment_actions.data_exchange {
+                exchange.retryable = true;
+            }
+
+            let mut remote_executors = 0;
+            let mut remote_partitions = 0;
+            for action in &fragment_actions.fragment_actions {
+                if action.executor != local_id {
+                    remote_executors += 1;
+                    remote_partitions += match &action.payload {
+                        FragmentPayload::Plan(plan) => Self::scan_partitions(plan)
+                            .iter()
+                            .map(|parts| parts.len())
+                            .sum::<usize>(),
+                    };
+                }
+            }
+            let batch_partitions = Self::batch_partitions(remote_partitions, remote_executors);
+
+            let mut batches = HashMap::new();
+            for action in &mut fragment_actions.fragment_actions {
+                if action.executor != local_id {
+                    let executor_batches = match &action.payload {
+                        FragmentPayload::Plan(plan) => Self::split_batches(plan, batch_partitions)?,
+                    };
+                    action.payload = executor_batches[0].clone();
+                    batches.insert(action.executor.clone(), executor_batches);
+                }
+            }
+
+            fragments.push(RetryableFragment {
+                fragment_id: fragment_actions.fragment_id,
+                data_exchange: fragment_actions.data_exchange.clone().unwrap(),
+                batches,
+            });
+        }
+
+        Ok(fragments)
+    }
+
+    // The partitions of a batch, so that each remote executor reads about
+    // `RETRY_BATCHES_PER_EXECUTOR` batches.
+    fn batch_partitions(partitions: usize, executors: usize) -> usize {
+        let batches = executors.max(1) * RETRY_BATCHES_PER_EXECUTOR;
+        ((partitions + batches - 1) / batches).max(MIN_RETRY_BATCH_PARTITIONS)
+    }
+
+    fn scan_partitions(plan: &PhysicalPlan) -> Vec<Partitions> {
+        let mut sources = vec![];
+        PhysicalPlan::traverse(
+            plan,
+            &mut |_| true,
+            &mut |plan| {
+                if let PhysicalPlan::TableScan(scan) = plan {
+                    sources.push(scan.source.parts.clone());
+                }
+            },
+            &mut |_| {},
+        );
+        sources
+    }
+
+    // Split the partitions read by the plan into batches, one plan for each batch.
+    fn split_batches(plan: &PhysicalPlan, batch_partitions: usize) -> Result<Vec<FragmentPayload>> {
+        let mut sources = Self::scan_partitions(plan);
+        if sources.len() != 1 || sources[0].len() <= batch_partitions {
+            return Ok(vec![FragmentPayload::Plan(plan.clone())]);
+        }
+
+        let parts = sources.remove(0);
+        let mut batches = vec![];
+        for batch in parts.partitions.chunks(batch_partitions) {
+            let mut replace_partitions = ReplacePartitions {
+                parts: Partitions::create(parts.kind.clone(), batch.to_vec()),
+            };
+            batches.push(FragmentPayload::Plan(replace_partitions.replace(plan)?));
+        }
+
+        Ok(batches)
+    }
+
+    fn is_read_only_leaf(plan: &PhysicalPlan) -> bool {
+        let mut has_table_scan = false;
+        let mut has_dependency = false;
+
+        PhysicalPlan::traverse(
+            plan,
+            &mut |_| true,
+            &mut |plan| match plan {
+                PhysicalPlan::TableScan(_) => has_table_scan = true,
+                PhysicalPlan::ExchangeSource(_) | PhysicalPlan::DistributedInsertSelect(_) => {
+                    has_dependency = true
+                }
+                _ => {}
+            },
+            &mut |_| {},
+        );
+
+        has_table_scan && !has_dependency
+    }
+}
+
+struct ReplacePartitions {
+    parts: Partitions,
+}
+
+impl PhysicalPlanReplacer for ReplacePartitions {
+    fn replace_table_scan(&mut self, plan: &TableScan) -> Result<PhysicalPlan> {
+        let mut source = plan.source.clone();
+        source.parts = self.parts.clone();
+
+        Ok(PhysicalPlan::TableScan(TableScan {
+            source,
+            name_mapping: plan.name_mapping.clone(),
+            table_index: plan.table_index,
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+}
+
+#[derive(Default)]
+struct RetryState {
+    finished: bool,
+    next_attempt_id: usize,
+    // The executors reading the batch of partitions of each assignment.
+    running: HashMap<Assignment, Vec<String>>,
+    attempts: HashMap<Assignment, usize>,
+    committed: HashSet<Assignment>,
+    lost_nodes: HashSet<String>,
+    senders: HashMap<usize, Sender<Result<DataPacket>>>,
+    receivers: HashMap<usize, Receiver<Result<DataPacket>>>,
+    statistics_receivers: Vec<StatisticsReceiver>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+/// Runs on the coordinator, the partitions assigned to each remote executor are read in
+/// batches, `RETRY_INFLIGHT_BATCHES` of them at the same time. The data of a batch is
+/// buffered until the executor sends `DataPacket::FragmentFinished`, then it is committed
+/// once to the downstream exchange through a bounded channel, and the next batch is
+/// scheduled after the data is sent.
+///
+/// If an executor fails or disappears from the cluster before finishing a batch, only the
+/// batches not committed yet are read again by surviving nodes, the duplicated data of
+/// the slower attempt is discarded.
+pub struct FragmentsRetry {
+    ctx: Arc<QueryContext>,
+    fragments: HashMap<usize, RetryableFragment>,
+    state: Mutex<RetryState>,
+}
+
+impl FragmentsRetry {
+    pub fn create(ctx: Arc<QueryContext>, fragments: Vec<RetryableFragment>) -> Arc<Self> {
+        Arc::new(FragmentsRetry {
+            ctx,
+            fragments: fragments
+                .into_iter()
+                .map(|fragment| (fragment.fragment_id, fragment))
+                .collect(),
+            state: Mutex::new(RetryState::default()),
+        })
+    }
+
+    /// Take the exchanges of the retryable fragments and start receiving the data.
+    pub fn start(
+        self: &Arc<Self>,
+        exchanges: &mut HashMap<(String, usize), FlightExchange>,
+    ) -> Result<()> {
+        for fragment in self.fragments.values() {
+            let fragment_id = fragment.fragment_id;
+            let (tx, rx) = async_channel::bounded(RETRY_CHANNEL_CAPACITY);
+
+            {
+                let mut state = self.state.lock();
+                if fragment.batches.is_empty() {
+                    tx.close();
+                }
+
+                state.senders.insert(fragment_id, tx);
+                state.receivers.insert(fragment_id, rx);
+            }
+
+            for executor in fragment.batches.keys() {
+                match exchanges.remove(&(executor.clone(), fragment_id)) {
+                    None => {
+                        return Err(ErrorCode::UnknownFragmentExchange(format!(
+                            "Unknown fragment exchange channel, {}, {}",
+                            executor, fragment_id
+                        )));
+                    }
+                    Some(exchange) => {
+                        let assignment = (fragment_id, executor.clone(), 0);
+                        self.receive(assignment, executor.clone(), exchange)
+                    }
+                }
+            }
+
+            // The first batch is scheduled with the query, schedule the other in-flight batches.
+            for (executor, batches) in &fragment.batches {
+                for batch in 1..batches.len().min(RETRY_INFLIGHT_BATCHES) {
+                    let next = (fragment_id, executor.clone(), batch);
+                    let handle = GlobalIORuntime::instance().spawn(self.clone().schedule(next));
+                    self.state.lock().handles.push(handle);
+                }
+            }
+        }
+
+        let fragments_retry = self.clone();
+        let handle = GlobalIORuntime::instance().spawn(fragments_retry.check_nodes());
+        self.state.lock().handles.push(handle);
+        Ok(())
+    }
+
+    pub fn get_flight_exchange(&self, fragment_id: usize) -> Result<FlightExchange> {
+        match self.state.lock().receivers.get(&fragment_id) {
+            Some(rx) => Ok(FlightExchange::Retryable(rx.clone())),
+            None => Err(ErrorCode::UnknownFragmentExchange(format!(
+                "Unknown retryable fragment exchange, {}",
+                fragment_id
+            ))),
+        }
+    }
+
+    pub fn shutdown(&self) {
+        let (handles, mut statistics_receivers) = {
+            let mut state = self.state.lock();
+            state.finished = true;
+            (
+                std::mem::take(&mut state.handles),
+                std::mem::take(&mut state.statistics_receivers),
+            )
+        };
+
+        for statistics_receiver in statistics_receivers.iter_mut() {
+            statistics_receiver.shutdown();
+        }
+
+        for statistics_receiver in statistics_receivers.iter_mut() {
+            if let Err(cause) = statistics_receiver.wait_shutdown() {
+                warn!(
+                    "Shutdown statistics receiver of retry has error, cause: {:?}",
+                    cause
+                );
+            }
+        }
+
+        // The attempts still running are duplicated, stop receiving their data.
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    fn receive(
+        self: &Arc<Self>,
+        assignment: Assignment,
+        executor: String,
+        exchange: FlightExchange,
+    ) {
+        let fragments_retry = self.clone();
+        let mut state = self.state.lock();
+        state
+            .running
+            .entry(assignment.clone())
+            .or_default()
+            .push(executor.clone());
+
+        state
+            .handles
+            .push(GlobalIORuntime::instance().spawn(async move {
+                let mut packets = vec![];
+                let lost_cause = loop {
+                    match exchange.recv().await {
+                        Ok(Some(DataPacket::FragmentFinished)) => {
+                            fragments_retry
+                                .commit(&assignment, &executor, packets)
+                                .await;
+                            return;
+                        }
+                        Ok(Some(DataPacket::FragmentData(data))) => {
+                            // Another attempt has committed, the data is duplicated.
+                            if !fragments_retry.is_committed(&assignment) {
+                                packets.push(DataPacket::FragmentData(data));
+                            }
+                        }
+                        Ok(Some(DataPacket::ErrorCode(cause))) => {
+                            fragments_retry.abort(cause).await;
+                            return;
+                        }
+                        Ok(Some(_)) => {}
+                        Ok(None) => {
+                            break ErrorCode::CannotConnectNode(format!(
+                                "Batch {} of fragment {} on node {} closed before sending all data",
+                                assignment.2, assignment.0, executor
+                            ));
+                        }
+                        Err(cause) => break cause,
+                    }
+                };
+
+                fragments_retry
+                    .on_lost(assignment, executor, lost_cause)
+                    .await;
+            }));
+    }
+
+    fn is_committed(&self, assignment: &Assignment) -> bool {
+        self.state.lock().committed.contains(assignment)
+    }
+
+    async fn commit(
+        self: &Arc<Self>,
+        assignment: &Assignment,
+        executor: &str,
+        packets: Vec<DataPacket>,
+    ) {
+        let (fragment_id, assigned, batch) = assignment;
+        let fragment_id = *fragment_id;
+        let fragment = &self.fragments[&fragment_id];
+
+        let tx = {
+            let mut state = self.state.lock();
+            if let Some(executors) = state.running.get_mut(assignment) {
+                executors.retain(|running| running != executor);
+            }
+
+            if !state.committed.insert(assignment.clone()) {
+                info!(
+                    "Discard duplicated data of batch {} of fragment {} assigned to {}, sent by {}",
+                    batch, fragment_id, assigned, executor
+                );
+                return;
+            }
+
+            let tx = state.senders.get(&fragment_id).cloned();
+            let all_committed = fragment.batches.iter().all(|(assigned, batches)| {
+                (0..batches.len()).all(|index| {
+                    state
+                        .committed
+                        .contains(&(fragment_id, assigned.clone(), index))
+                })
+            });
+
+            // Dropping the last sender closes the channel, after the data of the batches
+            // being committed is sent.
+            if all_committed {
+                state.senders.remove(&fragment_id);
+            }
+            tx
+        };
+
+        if let Some(tx) = tx {
+            for packet in packets {
+                // The channel is closed by an aborted attempt.
+                if tx.send(Ok(packet)).await.is_err() {
+                    return;
+                }
+            }
+        }
+
+        let next = batch + RETRY_INFLIGHT_BATCHES;
+        if next < fragment.batches[assigned].len() {
+            let mut state = self.state.lock();
+            if !state.finished {
+                let next = (fragment_id, assigned.clone(), next);
+                let fragments_retry = self.clone();
+                let handle = GlobalIORuntime::instance().spawn(fragments_retry.schedule(next));
+                state.handles.push(handle);
+            }
+        }
+    }
+
+    async fn abort(&self, cause: ErrorCode) {
+        let senders = {
+            let mut state = self.state.lock();
+            state.senders.drain().map(|(_, tx)| tx).collect::<Vec<_>>()
+        };
+
+        for tx in senders {
+            let _ = tx.send(Err(cause.clone())).await;
+            tx.close();
+        }
+    }
+
+    async fn on_lost(self: Arc<Self>, assignment: Assignment, executor: String, cause: ErrorCode) {
+        {
+            let mut state = self.state.lock();
+            let running = state.running.entry(assignment.clone()).or_default();
+
+            // The attempt has been given up when its node disappeared from the cluster.
+            match running.iter().position(|running| running == &executor) {
+                None => return,
+                Some(position) => running.remove(position),
+            };
+
+            let has_running = !running.is_empty();
+            state.lost_nodes.insert(executor.clone());
+
+            if state.finished || has_running || state.committed.contains(&assignment) {
+                return;
+            }
+        }
+
+        warn!(
+            "Lost batch {} of fragment {} assigned to {} on node {}, cause: {:?}",
+            assignment.2, assignment.0, assignment.1, executor, cause
+        );
+        self.reschedule(assignment, cause).await;
+    }
+
+    // Give up the attempts on the nodes that disappeared from the cluster.
+    async fn check_nodes(self: Arc<Self>) {
+        loop {
+            tokio::time::sleep(CHECK_NODES_INTERVAL).await;
+
+            let nodes = match Self::discover_nodes().await {
+                Ok(nodes) => nodes,
+                Err(cause) => {
+                    warn!(
+                        "Cannot discover cluster nodes for retry, cause: {:?}",
+                        cause
+                    );
+                    continue;
+                }
+            };
+
+            let mut lost_assignments = vec![];
+            {
+                let mut state = self.state.lock();
+                if state.finished || state.senders.is_empty() {
+                    return;
+                }
+
+                let mut lost_nodes = vec![];
+                for (assignment, executors) in state.running.iter_mut() {
+                    let running = executors.len();
+                    executors.retain(|executor| match nodes.contains_key(executor) {
+                        true => true,
+                        false => {
+                            lost_nodes.push(executor.clone());
+                            false
+                        }
+                    });
+
+                    if running != 0 && executors.is_empty() {
+                        lost_assignments.push(assignment.clone());
+                    }
+                }
+
+                state.lost_nodes.extend(lost_nodes);
+                lost_assignments.retain(|assignment| !state.committed.contains(assignment));
+            }
+
+            for assignment in lost_assignments {
+                warn!(
+                    "Lost batch {} of fragment {} assigned to {}, the node is not in the cluster",
+                    assignment.2, assignment.0, assignment.1
+                );
+
+                let cause = ErrorCode::NotFoundClusterNode(format!(
+                    "The node of batch {} of fragment {} assigned to {} is not in the cluster",
+                    assignment.2, assignment.0, assignment.1
+                ));
+                self.clone().reschedule(assignment, cause).await;
+            }
+        }
+    }
+
+    // Read the next batch of the partitions after the previous batch is committed.
+    async fn schedule(self: Arc<Self>, assignment: Assignment) {
+        let attempt_id = {
+            let mut state = self.state.lock();
+            if state.finished {
+                return;
+            }
+
+            state.next_attempt_id += 1;
+            state.next_attempt_id
+        };
+
+        if let Err(cause) = self.submit(&assignment, attempt_id).await {
+            warn!(
+                "Schedule batch {} of fragment {} assigned to {} failed, cause: {:?}",
+                assignment.2, assignment.0, assignment.1, cause
+            );
+            self.reschedule(assignment, cause).await;
+        }
+    }
+
+    async fn reschedule(self: Arc<Self>, assignment: Assignment, mut cause: ErrorCode) {
+        loop {
+            let attempt_id = {
+                let mut state = self.state.lock();
+                if state.finished || state.committed.contains(&assignment) {
+                    return;
+                }
+
+                let attempts = state.attempts.entry(assignment.clone()).or_default();
+                *attempts += 1;
+
+                match *attempts > MAX_RETRY_ATTEMPTS {
+                    true => None,
+                    false => {
+                        state.next_attempt_id += 1;
+                        Some(state.next_attempt_id)
+                    }
+                }
+            };
+
+            let attempt_id = match attempt_id {
+                Some(attempt_id) => attempt_id,
+                None => {
+                    self.abort(cause.add_message_back(format!(
+                        "(while retrying batch {} of fragment {} assigned to {})",
+                        assignment.2, assignment.0, assignment.1
+                    )))
+                    .await;
+                    return;
+                }
+            };
+
+            match self.submit(&assignment, attempt_id).await {
+                Ok(_) => return,
+                Err(retry_cause) => {
+                    warn!(
+                        "Retry batch {} of fragment {} assigned to {} failed, cause: {:?}",
+                        assignment.2, assignment.0, assignment.1, retry_cause
+                    );
+                    cause = retry_cause;
+                }
+            }
+        }
+    }
+
+    // Read the batch of partitions of the assignment on the node it was assigned to,
+    // or on the least busy surviving node if that node has failed.
+    async fn submit(self: &Arc<Self>, assignment: &Assignment, attempt_id: usize) -> Result<()> {
+        let (fragment_id, assigned, batch) = assignment;
+        let fragment = &self.fragments[fragment_id];
+        let config = GlobalConfig::instance();
+        let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
+        let local_id = self.ctx.get_cluster().local_id();
+        let nodes_info = Self::discover_nodes().await?;
+
+        // The coordinator is not able to exchange with itself, pick the least busy remote node.
+        let executor = {
+            let state = self.state.lock();
+            let busy = |node: &String| {
+                state
+                    .running
+                    .values()
+                    .filter(|executors| executors.contains(node))
+                    .count()
+            };
+
+            let surviving = |node: &String| node != &local_id && !state.lost_nodes.contains(node);
+            match nodes_info.contains_key(assigned) && surviving(assigned) {
+                true => Some(assigned.clone()),
+                false => nodes_info
+                    .keys()
+                    .filter(|node| surviving(*node))
+                    .min_by_key(|node| (busy(*node), (*node).clone()))
+                    .cloned(),
+            }
+        };
+
+        let executor = match executor {
+            Some(executor) => executor,
+            None => {
+                return Err(ErrorCode::NotFoundClusterNode(format!(
+                    "No surviving node to read batch {} of fragment {} assigned to {}",
+                    batch, fragment_id, assigned
+                )));
+            }
+        };
+
+        let local_info = match nodes_info.get(&local_id) {
+            Some(local_info) => local_info.clone(),
+            None => {
+                return Err(ErrorCode::NotFoundClusterNode(format!(
+                    "Not found coordinator node {} in cluster",
+                    local_id
+                )));
+            }
+        };
+
+        info!(
+            "Read batch {} of fragment {} assigned to {} on node {}",
+            batch, fragment_id, assigned, executor
+        );
+
+        let query_id = format!("{}-batch-{}", self.ctx.get_id(), attempt_id);
+        let submitted = async {
+            InitNodesChannelPacket::create(query_id.clone(), nodes_info[&executor].clone(), vec![
+                ConnectionInfo {
+                    target: local_info,
+                    fragments: vec![*fragment_id],
+                    create_request_channel: true,
+                },
+            ])
+            .commit(config.as_ref(), timeout)
+            .await?;
+
+            let exchanges = DataExchangeManager::instance().take_retry_exchanges(
+                &query_id,
+                &executor,
+                *fragment_id,
+            )?;
+
+            QueryFragmentsPlanPacket::create(
+                query_id.clone(),
+                executor.clone(),
+                vec![FragmentPlanPacket::create(
+                    *fragment_id,
+                    fragment.batches[assigned][*batch].clone(),
+                    Some(fragment.data_exchange.clone()),
+                )],
+                nodes_info.clone(),
+                local_id.clone(),
+                false,
+            )
+            .commit(config.as_ref(), timeout)
+            .await?;
+
+            ExecutePartialQueryPacket::create(
+                query_id.clone(),
+                executor.clone(),
+                nodes_info.clone(),
+            )
+            .commit(config.as_ref(), timeout)
+            .await?;
+
+            Ok::<_, ErrorCode>(exchanges)
+        };
+
+        let (exchange, statistics_exchange) = match submitted.await {
+            Ok(exchanges) => exchanges,
+            Err(cause) => {
+                self.state.lock().lost_nodes.insert(executor);
+                return Err(cause);
+            }
+        };
+
+        let mut statistics_receiver =
+            StatisticsReceiver::create(self.ctx.clone(), vec![statistics_exchange], true)?;
+
+        {
+            let mut state = self.state.lock();
+            if state.finished {
+                return Ok(());
+            }
+
+            statistics_receiver.start();
+            state.statistics_receivers.push(statistics_receiver);
+        }
+
+        self.receive(assignment.clone(), executor, exchange);
+        Ok(())
+    }
+
+    async fn discover_nodes() -> Result<HashMap<String, Arc<NodeInfo>>> {
+        let config = GlobalConfig::instance();
+        let cluster = ClusterDiscovery::instance().discover(&config).await?;
+
+        Ok(cluster
+            .get_nodes()
+            .into_iter()
+            .map(|node| (node.id.clone(), node))
+            .collect())
+    }
+}
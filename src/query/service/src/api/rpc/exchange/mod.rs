@@ -14,17 +14,27 @@
 
 mod data_exchange;
 mod exchange_manager;
+mod exchange_metrics;
 mod exchange_params;
+mod exchange_range_concat;
+mod exchange_range_sample;
+mod exchange_sample;
 mod exchange_sink;
 mod exchange_sink_merge;
 mod exchange_sink_shuffle;
+mod exchange_skew_aggregate;
+mod exchange_skew_shuffle;
 mod exchange_transform;
 mod exchange_transform_source;
+mod fragments_retry;
 mod statistics_receiver;
 mod statistics_sender;
 
 pub use data_exchange::BroadcastExchange;
 pub use data_exchange::DataExchange;
 pub use data_exchange::MergeExchange;
+pub use data_exchange::RangeDataExchange;
 pub use data_exchange::ShuffleDataExchange;
 pub use exchange_manager::DataExchangeManager;
+pub use exchange_metrics::record_skew_rows;
+pub use exchange_skew_aggregate::TransformSkewAggregate;
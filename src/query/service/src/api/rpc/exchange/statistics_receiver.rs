@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::future::Future;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
@@ -27,7 +28,9 @@ use common_base::runtime::Runtime;
 use common_base::runtime::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use tracing::warn;
 
+use crate::api::rpc::exchange::exchange_metrics::record_skew_detected;
 use crate::api::rpc::flight_client::FlightExchange;
 use crate::api::DataPacket;
 use crate::sessions::QueryContext;
@@ -35,6 +38,8 @@ use crate::sessions::QueryContext;
 pub struct StatisticsReceiver {
     ctx: Arc<QueryContext>,
     exchanges: Vec<FlightExchange>,
+    // Tolerate the lost nodes, their fragments will be retried on other nodes.
+    fault_tolerant: bool,
     shutdown_flag: Arc<AtomicBool>,
     shutdown_notify: Arc<Notify>,
     exchange_handler: Vec<JoinHandle<Result<()>>>,
@@ -45,10 +50,12 @@ impl StatisticsReceiver {
     pub fn create(
         ctx: Arc<QueryContext>,
         exchanges: Vec<FlightExchange>,
+        fault_tolerant: bool,
     ) -> Result<StatisticsReceiver> {
         Ok(StatisticsReceiver {
             ctx,
             exchanges,
+            fault_tolerant,
             shutdown_flag: Arc::new(AtomicBool::new(false)),
             shutdown_notify: Arc::new(Notify::new()),
             exchange_handler: vec![],
@@ -62,12 +69,21 @@ impl StatisticsReceiver {
     pub fn start(&mut self) {
         while let Some(flight_exchange) = self.exchanges.pop() {
             let ctx = self.ctx.clone();
+            let fault_tolerant = self.fault_tolerant;
             let shutdown_flag = self.shutdown_flag.clone();
             let shutdown_notify = self.shutdown_notify.clone();
 
             self.exchange_handler.push(self.runtime.spawn(async move {
                 let mut recv = Box::pin(flight_exchange.recv());
                 let mut notified = Box::pin(shutdown_notify.notified());
+                // Ids of the runtime filters already dispatched to the node.
+                let mut dispatched = HashSet::new();
+                // Ids of the range bounds already dispatched to the node.
+                let mut dispatched_bounds = HashSet::new();
+                // Ids of the hot keys already dispatched to the node.
+                let mut dispatched_hot_keys = HashSet::new();
+                // Id of the node, learned from its reports of the stealable scans.
+                let mut executor = None;
 
                 'worker_loop: while !shutdown_flag.load(Ordering::Relaxed) {
                     let interval = Box::pin(tokio::time::sleep(Duration::from_millis(500)));
@@ -78,7 +94,18 @@ impl StatisticsReceiver {
                             notified = middle;
 
                             if !shutdown_flag.load(Ordering::Relaxed) {
-                                match Self::fetch(&ctx, &flight_exchange, recv).await {
+                                match Self::fetch(
+                                    &ctx,
+                                    &flight_exchange,
+                                    &mut dispatched,
+                                    &mut dispatched_bounds,
+                                    &mut dispatched_hot_keys,
+                                    &mut executor,
+                                    fault_tolerant,
+                                    recv,
+                                )
+                                .await
+                                {
                                     Ok(true) => {
                                         return Ok(());
                                     }
@@ -98,7 +125,7 @@ impl StatisticsReceiver {
                         }
                         Select3Output::Right((res, _, middle)) => {
                             notified = middle;
-                            match Self::recv_data(&ctx, res) {
+                            match Self::recv_data(&ctx, &mut executor, fault_tolerant, res) {
                                 Ok(true) => {
                                     return Ok(());
                                 }
@@ -114,7 +141,18 @@ impl StatisticsReceiver {
                     }
                 }
 
-                if let Err(cause) = Self::fetch(&ctx, &flight_exchange, recv).await {
+                if let Err(cause) = Self::fetch(
+                    &ctx,
+                    &flight_exchange,
+                    &mut dispatched,
+                    &mut dispatched_bounds,
+                    &mut dispatched_hot_keys,
+                    &mut executor,
+                    fault_tolerant,
+                    recv,
+                )
+                .await
+                {
                     ctx.get_current_session().force_kill_query(cause.clone());
                     return Err(cause);
                 }
@@ -127,26 +165,117 @@ impl StatisticsReceiver {
     async fn fetch(
         ctx: &Arc<QueryContext>,
         flight_exchange: &FlightExchange,
+        dispatched: &mut HashSet<usize>,
+        dispatched_bounds: &mut HashSet<usize>,
+        dispatched_hot_keys: &mut HashSet<usize>,
+        executor: &mut Option<String>,
+        fault_tolerant: bool,
         recv: impl Future<Output = Result<Option<DataPacket>>>,
     ) -> Result<bool> {
-        flight_exchange
+        ctx.rebalance_partition_steals();
+        if let Some(executor) = executor.as_deref() {
+            let commands = ctx.take_partition_steal_commands(executor);
+            if !commands.is_empty() {
+                if let Err(cause) = flight_exchange
+                    .send(DataPacket::PartitionSteals(commands))
+                    .await
+                {
+                    return Self::on_transport_error(fault_tolerant, cause);
+                }
+            }
+        }
+
+        let runtime_filters = ctx
+            .get_remote_runtime_filters()
+            .into_iter()
+            .filter(|(id, _)| dispatched.insert(*id))
+            .map(|(id, filter)| (id, filter.as_ref().clone()))
+            .collect::<Vec<_>>();
+        if !runtime_filters.is_empty() {
+            if let Err(cause) = flight_exchange
+                .send(DataPacket::RuntimeFilters(runtime_filters))
+                .await
+            {
+                return Self::on_transport_error(fault_tolerant, cause);
+            }
+        }
+
+        let range_bounds = ctx
+            .get_remote_range_bounds()
+            .into_iter()
+            .filter(|(id, _)| dispatched_bounds.insert(*id))
+            .map(|(id, bounds)| (id, bounds.as_ref().clone()))
+            .collect::<Vec<_>>();
+        if !range_bounds.is_empty() {
+            if let Err(cause) = flight_exchange
+                .send(DataPacket::RangeBounds(range_bounds))
+                .await
+            {
+                return Self::on_transport_error(fault_tolerant, cause);
+            }
+        }
+
+        let hot_keys = ctx
+            .get_remote_hot_keys()
+            .into_iter()
+            .filter(|(id, _)| dispatched_hot_keys.insert(*id))
+            .map(|(id, hot_keys)| (id, hot_keys.as_ref().clone()))
+            .collect::<Vec<_>>();
+        if !hot_keys.is_empty() {
+            if let Err(cause) = flight_exchange.send(DataPacket::HotKeys(hot_keys)).await {
+                return Self::on_transport_error(fault_tolerant, cause);
+            }
+        }
+
+        if let Err(cause) = flight_exchange
             .send(DataPacket::FetchProgressAndPrecommit)
-            .await?;
+            .await
+        {
+            return Self::on_transport_error(fault_tolerant, cause);
+        }
+
+        Self::recv_data(ctx, executor, fault_tolerant, recv.await)
+    }
 
-        Self::recv_data(ctx, recv.await)
+    fn on_transport_error(fault_tolerant: bool, cause: ErrorCode) -> Result<bool> {
+        match fault_tolerant {
+            true => {
+                warn!(
+                    "Lost statistics exchange with remote node, cause: {:?}",
+                    cause
+                );
+                Ok(true)
+            }
+            false => Err(cause),
+        }
     }
 
-    fn recv_data(ctx: &Arc<QueryContext>, recv_data: Result<Option<DataPacket>>) -> Result<bool> {
+    fn recv_data(
+        ctx: &Arc<QueryContext>,
+        executor: &mut Option<String>,
+        fault_tolerant: bool,
+        recv_data: Result<Option<DataPacket>>,
+    ) -> Result<bool> {
         match recv_data {
             Ok(None) => Ok(true),
             Ok(Some(DataPacket::ClosingClient)) => Ok(true),
-            Err(transport_error) => Err(transport_error),
+            Err(transport_error) => Self::on_transport_error(fault_tolerant, transport_error),
             Ok(Some(DataPacket::ErrorCode(error))) => Err(error),
             Ok(Some(DataPacket::FragmentData(_))) => unreachable!(),
             Ok(Some(DataPacket::FetchProgressAndPrecommit)) => unreachable!(),
+            Ok(Some(DataPacket::RuntimeFilters(_))) => unreachable!(),
+            Ok(Some(DataPacket::FragmentFinished)) => unreachable!(),
+            Ok(Some(DataPacket::RangeBounds(_))) => unreachable!(),
+            Ok(Some(DataPacket::HotKeys(_))) => unreachable!(),
+            Ok(Some(DataPacket::PartitionSteals(_))) => unreachable!(),
             Ok(Some(DataPacket::ProgressAndPrecommit {
                 progress,
                 precommit,
+                runtime_filters,
+                profiles,
+                range_partitions,
+                skew_shuffles,
+                partition_steals,
             })) => {
                 for progress_info in progress {
                     progress_info.inc(ctx);
@@ -156,6 +285,32 @@ impl StatisticsReceiver {
                     precommit_block.precommit(ctx);
                 }
 
+                for partial in runtime_filters {
+                    ctx.merge_remote_runtime_filter(partial);
+                }
+
+                if let Some(plan_profiles) = ctx.get_plan_profiles() {
+                    for (plan_id, statistics) in profiles {
+                        plan_profiles.merge(plan_id, &statistics);
+                    }
+                }
+
+                for partial in range_partitions {
+                    ctx.merge_remote_range_partition(partial);
+                }
+
+                for partial in skew_shuffles {
+                    let plan_id = partial.desc.plan_id;
+                    if let Some(hot_keys) = ctx.merge_remote_skew_shuffle(partial) {
+                        record_skew_detected(ctx, plan_id, hot_keys.len());
+                    }
+                }
+
+                for partial in partition_steals {
+                    executor.get_or_insert_with(|| partial.executor.clone());
+                    ctx.merge_remote_partition_steal(partial);
+                }
+
                 Ok(false)
             }
         }
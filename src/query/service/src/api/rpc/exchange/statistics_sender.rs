@@ -146,10 +146,43 @@ impl StatisticsSender {
                     .send(DataPacket::ProgressAndPrecommit {
                         progress: Self::fetch_progress(ctx).await?,
                         precommit: Self::fetch_precommit(ctx).await?,
+                        runtime_filters: ctx.take_runtime_filter_partials(),
+                        profiles: ctx
+                            .get_plan_profiles()
+                            .map(|profiles| profiles.take_statistics())
+                            .unwrap_or_default(),
+                        range_partitions: ctx.take_range_partition_partials(),
+                        skew_shuffles: ctx.take_skew_shuffle_partials(),
+                        partition_steals: ctx.take_partition_steal_partials(),
                     })
                     .await
             }
+            DataPacket::RuntimeFilters(runtime_filters) => {
+                for (id, filter) in runtime_filters {
+                    ctx.install_runtime_filter(id, filter);
+                }
+                Ok(())
+            }
+            DataPacket::RangeBounds(range_bounds) => {
+                for (id, bounds) in range_bounds {
+                    ctx.install_range_bounds(id, bounds);
+                }
+                Ok(())
+            }
+            DataPacket::HotKeys(hot_keys) => {
+                for (id, hot_keys) in hot_keys {
+                    ctx.install_hot_keys(id, hot_keys);
+                }
+                Ok(())
+            }
+            DataPacket::PartitionSteals(commands) => {
+                for command in commands {
+                    ctx.install_partition_steal(command);
+                }
+                Ok(())
+            }
             DataPacket::ClosingClient => unreachable!(),
+            DataPacket::FragmentFinished => unreachable!(),
         }
     }
 
@@ -89,10 +89,13 @@ impl FlightClient {
         ))
     }
 
+    #[tracing::instrument(level = "info", skip_all)]
     async fn exchange_streaming(
         &mut self,
         request: impl tonic::IntoStreamingRequest<Message = FlightData>,
     ) -> Result<Streaming<FlightData>> {
+        let request =
+            common_tracing::inject_span_to_tonic_request(request.into_streaming_request());
         match self.inner.do_exchange(request).await {
             Ok(res) => Ok(res.into_inner()),
             Err(status) => Err(ErrorCode::from(status).add_message_back("(while in query flight)")),
@@ -100,7 +103,7 @@ impl FlightClient {
     }
 
     // Execute do_action.
-    #[tracing::instrument(level = "debug", skip_all)]
+    #[tracing::instrument(level = "info", skip_all)]
     async fn do_action(&mut self, action: FlightAction, timeout: u64) -> Result<Vec<u8>> {
         let action: Action = action.try_into()?;
         let action_type = action.r#type.clone();
@@ -126,6 +129,8 @@ pub enum FlightExchange {
     Dummy,
     Client(ClientFlightExchange),
     Server(ServerFlightExchange),
+    // committed data of the retryable fragments
+    Retryable(Receiver<Result<DataPacket>>),
 }
 
 impl FlightExchange {
@@ -198,6 +203,9 @@ impl FlightExchange {
             )),
             FlightExchange::Client(exchange) => exchange.send(data).await,
             FlightExchange::Server(exchange) => exchange.send(data).await,
+            FlightExchange::Retryable(_) => Err(ErrorCode::Unimplemented(
+                "Unimplemented send in retryable exchange.",
+            )),
         }
     }
 
@@ -206,6 +214,10 @@ impl FlightExchange {
             FlightExchange::Client(exchange) => exchange.recv().await,
             FlightExchange::Server(exchange) => exchange.recv().await,
             FlightExchange::Dummy => Ok(None),
+            FlightExchange::Retryable(rx) => match rx.recv().await {
+                Err(_) => Ok(None),
+                Ok(message) => message.map(Some),
+            },
         }
     }
 
@@ -214,6 +226,10 @@ impl FlightExchange {
             FlightExchange::Dummy => Ok(None),
             FlightExchange::Client(exchange) => exchange.try_recv(),
             FlightExchange::Server(exchange) => exchange.try_recv(),
+            FlightExchange::Retryable(rx) => match rx.try_recv() {
+                Err(_) => Ok(None),
+                Ok(message) => message.map(Some),
+            },
         }
     }
 
@@ -222,6 +238,7 @@ impl FlightExchange {
             FlightExchange::Dummy => { /* do nothing*/ }
             FlightExchange::Client(exchange) => exchange.close_input(),
             FlightExchange::Server(exchange) => exchange.close_input(),
+            FlightExchange::Retryable(_) => { /* do nothing*/ }
         }
     }
 
@@ -230,6 +247,7 @@ impl FlightExchange {
             FlightExchange::Dummy => { /* do nothing*/ }
             FlightExchange::Client(exchange) => exchange.close_output(),
             FlightExchange::Server(exchange) => exchange.close_output(),
+            FlightExchange::Retryable(_) => { /* do nothing*/ }
         }
     }
 }
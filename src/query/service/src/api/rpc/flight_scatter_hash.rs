@@ -182,7 +182,7 @@ impl HashFlightScatter {
     }
 }
 
-fn get_hash_values(column: &Value<AnyType>, rows: usize) -> Result<Vec<u64>> {
+pub fn get_hash_values(column: &Value<AnyType>, rows: usize) -> Result<Vec<u64>> {
     match column {
         Value::Scalar(c) => match c {
             common_expression::Scalar::Null => Ok(vec![0; rows]),
@@ -0,0 +1,94 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Column;
+use common_expression::DataBlock;
+
+use crate::api::rpc::flight_scatter::FlightScatter;
+use crate::sessions::QueryContext;
+
+/// Scatter the rows by the ranges of the sort keys, the bounds of the ranges
+/// must have been installed before the first block is scattered.
+pub struct RangeFlightScatter {
+    ctx: Arc<QueryContext>,
+    range_id: usize,
+    key_offsets: Vec<usize>,
+    scatter_size: usize,
+}
+
+impl RangeFlightScatter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        range_id: usize,
+        key_offsets: Vec<usize>,
+        scatter_size: usize,
+    ) -> Result<Box<dyn FlightScatter>> {
+        Ok(Box::new(RangeFlightScatter {
+            ctx,
+            range_id,
+            key_offsets,
+            scatter_size,
+        }))
+    }
+}
+
+impl FlightScatter for RangeFlightScatter {
+    fn execute(&self, data_block: &DataBlock) -> Result<Vec<DataBlock>> {
+        let bounds = match self.ctx.get_range_bounds(self.range_id) {
+            Some(bounds) => bounds,
+            None => {
+                return Err(ErrorCode::Internal(format!(
+                    "Bounds of range partition {} are not ready.",
+                    self.range_id
+                )));
+            }
+        };
+
+        let columns = range_key_columns(data_block, &self.key_offsets);
+        let mut key = Vec::with_capacity(columns.len());
+        let mut indices = Vec::with_capacity(data_block.num_rows());
+        for row in 0..data_block.num_rows() {
+            key.clear();
+            key.extend(columns.iter().map(|column| column.index(row).unwrap()));
+            indices.push(bounds.partition(&key) as u64);
+        }
+
+        let block_meta = data_block.meta()?;
+        let data_blocks = DataBlock::scatter(data_block, &indices, self.scatter_size)?;
+
+        let mut res = Vec::with_capacity(data_blocks.len());
+        for data_block in data_blocks {
+            res.push(data_block.add_meta(block_meta.clone())?);
+        }
+
+        Ok(res)
+    }
+}
+
+/// Get the columns of the sort keys of the block.
+pub fn range_key_columns(data_block: &DataBlock, key_offsets: &[usize]) -> Vec<Column> {
+    key_offsets
+        .iter()
+        .map(|offset| {
+            let entry = data_block.get_by_offset(*offset);
+            entry
+                .value
+                .convert_to_full_column(&entry.data_type, data_block.num_rows())
+        })
+        .collect()
+}
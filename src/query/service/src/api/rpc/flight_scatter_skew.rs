@@ -0,0 +1,179 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RawExpr;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_sql::executor::PhysicalScalar;
+
+use crate::api::rpc::exchange::record_skew_rows;
+use crate::api::rpc::flight_scatter::FlightScatter;
+use crate::api::rpc::flight_scatter_hash::get_hash_values;
+use crate::sessions::QueryContext;
+
+/// Hashes the shuffle keys of the rows, the two sides of a join get the same
+/// hashes for the same keys.
+#[derive(Clone)]
+pub struct SkewKeyHasher {
+    func_ctx: FunctionContext,
+    hash_keys: Vec<Expr>,
+}
+
+impl SkewKeyHasher {
+    pub fn try_create(func_ctx: FunctionContext, scalars: &[PhysicalScalar]) -> Result<Self> {
+        let mut hash_keys = Vec::with_capacity(scalars.len());
+        for scalar in scalars {
+            let hash_raw = RawExpr::FunctionCall {
+                span: None,
+                name: "siphash".to_string(),
+                params: vec![],
+                args: vec![scalar.as_raw_expr()],
+            };
+            let expr = check(&hash_raw, &BUILTIN_FUNCTIONS)
+                .map_err(|(_, e)| ErrorCode::Internal(format!("Invalid expression: {}", e)))?;
+            hash_keys.push(expr);
+        }
+
+        Ok(SkewKeyHasher {
+            func_ctx,
+            hash_keys,
+        })
+    }
+
+    pub fn hash(&self, data_block: &DataBlock) -> Result<Vec<u64>> {
+        let evaluator = Evaluator::new(data_block, self.func_ctx, &BUILTIN_FUNCTIONS);
+        let num_rows = data_block.num_rows();
+
+        let mut hashes = vec![DefaultHasher::default(); num_rows];
+        for expr in &self.hash_keys {
+            let values = evaluator.run_auto_type(expr).unwrap();
+            let values = get_hash_values(&values, num_rows)?;
+            for (hash, value) in hashes.iter_mut().zip(values) {
+                hash.write_u64(value);
+            }
+        }
+
+        Ok(hashes.into_iter().map(|hash| hash.finish()).collect())
+    }
+}
+
+/// Scatter the rows by the hashes of the keys, the rows with the hot keys of the
+/// build side are spread across all the nodes, and the probe rows with the hot keys
+/// are sent to all the nodes, so that every pair of the matched rows meets on one node.
+pub struct SkewFlightScatter {
+    ctx: Arc<QueryContext>,
+    hasher: SkewKeyHasher,
+    skew_id: usize,
+    plan_id: usize,
+    build: bool,
+    scatter_size: usize,
+    next_spread: AtomicUsize,
+}
+
+impl SkewFlightScatter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        hasher: SkewKeyHasher,
+        skew_id: usize,
+        plan_id: usize,
+        build: bool,
+        scatter_size: usize,
+    ) -> Result<Box<dyn FlightScatter>> {
+        Ok(Box::new(SkewFlightScatter {
+            ctx,
+            hasher,
+            skew_id,
+            plan_id,
+            build,
+            scatter_size,
+            next_spread: AtomicUsize::new(0),
+        }))
+    }
+}
+
+impl FlightScatter for SkewFlightScatter {
+    fn execute(&self, data_block: &DataBlock) -> Result<Vec<DataBlock>> {
+        let hot_keys = match self.ctx.get_hot_keys(self.skew_id) {
+            Some(hot_keys) => hot_keys,
+            None => {
+                return Err(ErrorCode::Internal(format!(
+                    "Hot keys of skew-aware shuffle {} are not ready.",
+                    self.skew_id
+                )));
+            }
+        };
+
+        let size = self.scatter_size as u64;
+        let hashes = self.hasher.hash(data_block)?;
+        let hot_rows = hashes.iter().filter(|h| hot_keys.contains(**h)).count();
+
+        let mut indices = Vec::with_capacity(hashes.len());
+        let data_blocks = match self.build {
+            true => {
+                // Spread the hot rows across the nodes in turn.
+                let mut spread = self.next_spread.fetch_add(hot_rows, Ordering::Relaxed) as u64;
+                for hash in hashes {
+                    match hot_keys.contains(hash) {
+                        true => {
+                            indices.push(spread % size);
+                            spread += 1;
+                        }
+                        false => indices.push(hash % size),
+                    }
+                }
+                DataBlock::scatter(data_block, &indices, self.scatter_size)?
+            }
+            false => {
+                // Scatter the hot rows into an extra block, which is sent to all the nodes.
+                for hash in hashes {
+                    match hot_keys.contains(hash) {
+                        true => indices.push(size),
+                        false => indices.push(hash % size),
+                    }
+                }
+                let mut data_blocks =
+                    DataBlock::scatter(data_block, &indices, self.scatter_size + 1)?;
+                let hot_block = data_blocks.pop().unwrap();
+                match hot_block.is_empty() {
+                    true => data_blocks,
+                    false => data_blocks
+                        .into_iter()
+                        .map(|data_block| DataBlock::concat(&[data_block, hot_block.clone()]))
+                        .collect::<Result<Vec<_>>>()?,
+                }
+            }
+        };
+        record_skew_rows(&self.ctx, self.plan_id, hot_rows);
+
+        let block_meta = data_block.meta()?;
+        let mut res = Vec::with_capacity(data_blocks.len());
+        for data_block in data_blocks {
+            res.push(data_block.add_meta(block_meta.clone())?);
+        }
+
+        Ok(res)
+    }
+}
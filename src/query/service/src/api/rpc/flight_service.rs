@@ -101,7 +101,10 @@ impl FlightService for DatabendQueryFlightService {
         Err(Status::unimplemented("unimplement do_get"))
     }
 
+    #[tracing::instrument(level = "info", skip_all)]
     async fn do_exchange(&self, req: StreamReq<FlightData>) -> Response<Self::DoExchangeStream> {
+        common_tracing::extract_remote_span_as_parent(&req);
+
         match req.get_metadata("x-type")?.as_str() {
             "request_server_exchange" => {
                 let query_id = req.get_metadata("x-query-id")?;
@@ -132,7 +135,7 @@ impl FlightService for DatabendQueryFlightService {
 
     type DoActionStream = FlightStream<FlightResult>;
 
-    #[tracing::instrument(level = "debug", skip_all)]
+    #[tracing::instrument(level = "info", skip_all)]
     async fn do_action(&self, request: Request<Action>) -> Response<Self::DoActionStream> {
         common_tracing::extract_remote_span_as_parent(&request);
 
@@ -22,6 +22,8 @@ mod flight_client;
 mod flight_scatter;
 mod flight_scatter_broadcast;
 mod flight_scatter_hash;
+mod flight_scatter_range;
+mod flight_scatter_skew;
 mod flight_service;
 mod packets;
 mod request_builder;
@@ -30,7 +32,9 @@ pub use exchange::BroadcastExchange;
 pub use exchange::DataExchange;
 pub use exchange::DataExchangeManager;
 pub use exchange::MergeExchange;
+pub use exchange::RangeDataExchange;
 pub use exchange::ShuffleDataExchange;
+pub use exchange::TransformSkewAggregate;
 pub use flight_client::ClientFlightExchange;
 pub use flight_client::ServerFlightExchange;
 pub use packets::ConnectionInfo;
@@ -14,8 +14,13 @@
 
 mod packet;
 mod packet_data;
+mod packet_data_partition_steal;
 mod packet_data_precommit;
+mod packet_data_profile;
 mod packet_data_progressinfo;
+mod packet_data_range_partition;
+mod packet_data_runtime_filter;
+mod packet_data_skew_shuffle;
 mod packet_execute;
 mod packet_executor;
 mod packet_fragment;
@@ -24,8 +29,17 @@ mod packet_publisher;
 pub use packet::Packet;
 pub use packet_data::DataPacket;
 pub use packet_data::FragmentData;
+pub use packet_data_partition_steal::PartitionStealCommands;
+pub use packet_data_partition_steal::PartitionStealPartials;
 pub use packet_data_precommit::PrecommitBlock;
+pub use packet_data_profile::PlanProfilePartials;
 pub use packet_data_progressinfo::ProgressInfo;
+pub use packet_data_range_partition::RangeBoundsMerged;
+pub use packet_data_range_partition::RangePartitionPartials;
+pub use packet_data_runtime_filter::RuntimeFilterMerged;
+pub use packet_data_runtime_filter::RuntimeFilterPartials;
+pub use packet_data_skew_shuffle::HotKeysMerged;
+pub use packet_data_skew_shuffle::SkewShufflePartials;
 pub use packet_execute::ExecutePartialQueryPacket;
 pub use packet_executor::QueryFragmentsPlanPacket;
 pub use packet_fragment::FragmentPayload;
@@ -23,7 +23,26 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use tracing::error;
 
+use crate::api::rpc::packets::packet_data_partition_steal::read_partition_steals;
+use crate::api::rpc::packets::packet_data_partition_steal::write_partition_steals;
+use crate::api::rpc::packets::packet_data_profile::read_plan_profiles;
+use crate::api::rpc::packets::packet_data_profile::write_plan_profiles;
+use crate::api::rpc::packets::packet_data_range_partition::read_range_partitions;
+use crate::api::rpc::packets::packet_data_range_partition::write_range_partitions;
+use crate::api::rpc::packets::packet_data_runtime_filter::read_runtime_filters;
+use crate::api::rpc::packets::packet_data_runtime_filter::write_runtime_filters;
+use crate::api::rpc::packets::packet_data_skew_shuffle::read_skew_shuffles;
+use crate::api::rpc::packets::packet_data_skew_shuffle::write_skew_shuffles;
+use crate::api::rpc::packets::HotKeysMerged;
+use crate::api::rpc::packets::PartitionStealCommands;
+use crate::api::rpc::packets::PartitionStealPartials;
+use crate::api::rpc::packets::PlanProfilePartials;
 use crate::api::rpc::packets::ProgressInfo;
+use crate::api::rpc::packets::RangeBoundsMerged;
+use crate::api::rpc::packets::RangePartitionPartials;
+use crate::api::rpc::packets::RuntimeFilterMerged;
+use crate::api::rpc::packets::RuntimeFilterPartials;
+use crate::api::rpc::packets::SkewShufflePartials;
 use crate::api::PrecommitBlock;
 
 pub struct FragmentData {
@@ -54,6 +73,11 @@ pub enum DataPacket {
     ProgressAndPrecommit {
         progress: Vec<ProgressInfo>,
         precommit: Vec<PrecommitBlock>,
+        runtime_filters: RuntimeFilterPartials,
+        profiles: PlanProfilePartials,
+        range_partitions: RangePartitionPartials,
+        skew_shuffles: SkewShufflePartials,
+        partition_steals: PartitionStealPartials,
     },
     // NOTE: Unknown reason. This may be tonic's bug.
     // when we use two-way streaming grpc for data exchange,
@@ -61,6 +85,16 @@ pub enum DataPacket {
     // we will get a broken pipe or connect reset error.
     // we use the ClosingClient to notify the server side to close the connection for avoid errors.
     ClosingClient,
+    /// Runtime filters merged by the coordinator.
+    RuntimeFilters(RuntimeFilterMerged),
+    /// All the data of a retryable fragment has been sent, the receiver can commit it.
+    FragmentFinished,
+    /// Bounds of the range partitions merged by the coordinator.
+    RangeBounds(RangeBoundsMerged),
+    /// Hot keys of the skew-aware shuffles detected by the coordinator.
+    HotKeys(HotKeysMerged),
+    /// Instructions of the work stealing of the scans from the coordinator.
+    PartitionSteals(PartitionStealCommands),
 }
 
 impl DataPacket {
@@ -86,6 +120,11 @@ impl From<DataPacket> for FlightData {
             DataPacket::ProgressAndPrecommit {
                 progress,
                 precommit,
+                runtime_filters,
+                profiles,
+                range_partitions,
+                skew_shuffles,
+                partition_steals,
             } => {
                 let mut data_body = vec![];
                 data_body
@@ -107,6 +146,21 @@ impl From<DataPacket> for FlightData {
                     precommit_block.write(&mut data_body).unwrap();
                 }
 
+                // Runtime filters.
+                write_runtime_filters(&runtime_filters, &mut data_body).unwrap();
+
+                // Plan profiles.
+                write_plan_profiles(&profiles, &mut data_body).unwrap();
+
+                // Samples of range partitions.
+                write_range_partitions(&range_partitions, &mut data_body).unwrap();
+
+                // Samples of skew-aware shuffles.
+                write_skew_shuffles(&skew_shuffles, &mut data_body).unwrap();
+
+                // Unread partitions of the scans.
+                write_partition_steals(&partition_steals, &mut data_body).unwrap();
+
                 FlightData {
                     data_body,
                     data_header: vec![],
@@ -120,6 +174,56 @@ impl From<DataPacket> for FlightData {
                 flight_descriptor: None,
                 app_metadata: vec![0x05],
             },
+            DataPacket::RuntimeFilters(runtime_filters) => {
+                let mut data_body = vec![];
+                write_runtime_filters(&runtime_filters, &mut data_body).unwrap();
+
+                FlightData {
+                    data_body,
+                    data_header: vec![],
+                    flight_descriptor: None,
+                    app_metadata: vec![0x06],
+                }
+            }
+            DataPacket::FragmentFinished => FlightData {
+                data_body: vec![],
+                data_header: vec![],
+                flight_descriptor: None,
+                app_metadata: vec![0x07],
+            },
+            DataPacket::RangeBounds(range_bounds) => {
+                let mut data_body = vec![];
+                write_range_partitions(&range_bounds, &mut data_body).unwrap();
+
+                FlightData {
+                    data_body,
+                    data_header: vec![],
+                    flight_descriptor: None,
+                    app_metadata: vec![0x08],
+                }
+            }
+            DataPacket::HotKeys(hot_keys) => {
+                let mut data_body = vec![];
+                write_skew_shuffles(&hot_keys, &mut data_body).unwrap();
+
+                FlightData {
+                    data_body,
+                    data_header: vec![],
+                    flight_descriptor: None,
+                    app_metadata: vec![0x09],
+                }
+            }
+            DataPacket::PartitionSteals(commands) => {
+                let mut data_body = vec![];
+                write_partition_steals(&commands, &mut data_body).unwrap();
+
+                FlightData {
+                    data_body,
+                    data_header: vec![],
+                    flight_descriptor: None,
+                    app_metadata: vec![0x0a],
+                }
+            }
         }
     }
 }
@@ -167,12 +271,53 @@ impl TryFrom<FlightData> for DataPacket {
                     precommit.push(PrecommitBlock::read(&mut bytes)?);
                 }
 
+                // Runtime filters.
+                let runtime_filters = read_runtime_filters(&mut bytes)?;
+
+                // Plan profiles.
+                let profiles = read_plan_profiles(&mut bytes)?;
+
+                // Samples of range partitions.
+                let range_partitions = read_range_partitions(&mut bytes)?;
+
+                // Samples of skew-aware shuffles.
+                let skew_shuffles = read_skew_shuffles(&mut bytes)?;
+
+                // Unread partitions of the scans.
+                let partition_steals = read_partition_steals(&mut bytes)?;
+
                 Ok(DataPacket::ProgressAndPrecommit {
                     precommit,
                     progress: progress_info,
+                    runtime_filters,
+                    profiles,
+                    range_partitions,
+                    skew_shuffles,
+                    partition_steals,
                 })
             }
             0x05 => Ok(DataPacket::ClosingClient),
+            0x06 => {
+                let mut bytes = flight_data.data_body.as_slice();
+                Ok(DataPacket::RuntimeFilters(read_runtime_filters(
+                    &mut bytes,
+                )?))
+            }
+            0x07 => Ok(DataPacket::FragmentFinished),
+            0x08 => {
+                let mut bytes = flight_data.data_body.as_slice();
+                Ok(DataPacket::RangeBounds(read_range_partitions(&mut bytes)?))
+            }
+            0x09 => {
+                let mut bytes = flight_data.data_body.as_slice();
+                Ok(DataPacket::HotKeys(read_skew_shuffles(&mut bytes)?))
+            }
+            0x0a => {
+                let mut bytes = flight_data.data_body.as_slice();
+                Ok(DataPacket::PartitionSteals(read_partition_steals(
+                    &mut bytes,
+                )?))
+            }
             _ => Err(ErrorCode::BadBytes("Unknown flight data packet type.")),
         }
     }
@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+use std::io::Write;
+
+use byteorder::BigEndian;
+use byteorder::ReadBytesExt;
+use byteorder::WriteBytesExt;
+use common_catalog::plan::PartitionStealCommand;
+use common_catalog::plan::PartitionStealPartial;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Unread partitions of the scans sent from other nodes to the coordinator.
+pub type PartitionStealPartials = Vec<PartitionStealPartial>;
+
+/// Instructions of the work stealing dispatched from the coordinator to other nodes.
+pub type PartitionStealCommands = Vec<PartitionStealCommand>;
+
+// The partitions are tagged by their types, which needs a self-describing format.
+pub fn write_partition_steals<T: Write, V: Serialize>(value: &V, bytes: &mut T) -> Result<()> {
+    let serialized = serde_json::to_vec(value).map_err_to_code(
+        ErrorCode::BadBytes,
+        || "partition steal serialize error when exchange",
+    )?;
+
+    bytes.write_u64::<BigEndian>(serialized.len() as u64)?;
+    bytes.write_all(&serialized)?;
+    Ok(())
+}
+
+pub fn read_partition_steals<T: Read, V: DeserializeOwned>(bytes: &mut T) -> Result<V> {
+    let len = bytes.read_u64::<BigEndian>()? as usize;
+    let mut serialized = vec![0; len];
+
+    bytes.read_exact(&mut serialized)?;
+    serde_json::from_slice(&serialized).map_err_to_code(
+        ErrorCode::BadBytes,
+        || "partition steal deserialize error when exchange",
+    )
+}
@@ -0,0 +1,49 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+use std::io::Write;
+
+use byteorder::BigEndian;
+use byteorder::ReadBytesExt;
+use byteorder::WriteBytesExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_pipeline_core::processors::ProfileStatistics;
+
+/// Plan profiles collected since the last report, sent from other nodes to the coordinator.
+pub type PlanProfilePartials = Vec<(usize, ProfileStatistics)>;
+
+pub fn write_plan_profiles<T: Write>(profiles: &PlanProfilePartials, bytes: &mut T) -> Result<()> {
+    let serialized = bincode::serialize(profiles).map_err_to_code(
+        ErrorCode::BadBytes,
+        || "plan profile serialize error when exchange",
+    )?;
+
+    bytes.write_u64::<BigEndian>(serialized.len() as u64)?;
+    bytes.write_all(&serialized)?;
+    Ok(())
+}
+
+pub fn read_plan_profiles<T: Read>(bytes: &mut T) -> Result<PlanProfilePartials> {
+    let len = bytes.read_u64::<BigEndian>()? as usize;
+    let mut serialized = vec![0; len];
+
+    bytes.read_exact(&mut serialized)?;
+    bincode::deserialize(&serialized).map_err_to_code(
+        ErrorCode::BadBytes,
+        || "plan profile deserialize error when exchange",
+    )
+}
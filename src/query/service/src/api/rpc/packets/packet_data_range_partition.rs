@@ -0,0 +1,55 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+use std::io::Write;
+
+use byteorder::BigEndian;
+use byteorder::ReadBytesExt;
+use byteorder::WriteBytesExt;
+use common_catalog::plan::RangeBounds;
+use common_catalog::plan::RangePartitionPartial;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Samples of the range partitions sent from other nodes to the coordinator.
+pub type RangePartitionPartials = Vec<RangePartitionPartial>;
+
+/// Bounds of the range partitions dispatched from the coordinator to other nodes.
+pub type RangeBoundsMerged = Vec<(usize, RangeBounds)>;
+
+pub fn write_range_partitions<T: Write, V: Serialize>(value: &V, bytes: &mut T) -> Result<()> {
+    let serialized = bincode::serialize(value).map_err_to_code(
+        ErrorCode::BadBytes,
+        || "range partition serialize error when exchange",
+    )?;
+
+    bytes.write_u64::<BigEndian>(serialized.len() as u64)?;
+    bytes.write_all(&serialized)?;
+    Ok(())
+}
+
+pub fn read_range_partitions<T: Read, V: DeserializeOwned>(bytes: &mut T) -> Result<V> {
+    let len = bytes.read_u64::<BigEndian>()? as usize;
+    let mut serialized = vec![0; len];
+
+    bytes.read_exact(&mut serialized)?;
+    bincode::deserialize(&serialized).map_err_to_code(
+        ErrorCode::BadBytes,
+        || "range partition deserialize error when exchange",
+    )
+}
@@ -34,6 +34,8 @@ pub struct QueryFragmentsPlanPacket {
     pub fragments: Vec<FragmentPlanPacket>,
     // We send nodes info for each node. This is a bad choice
     pub executors_info: HashMap<String, Arc<NodeInfo>>,
+    /// Collect the runtime profile of the plan nodes, e.g. `EXPLAIN ANALYZE`.
+    pub enable_profiling: bool,
 }
 
 impl QueryFragmentsPlanPacket {
@@ -43,6 +45,7 @@ impl QueryFragmentsPlanPacket {
         fragments: Vec<FragmentPlanPacket>,
         executors_info: HashMap<String, Arc<NodeInfo>>,
         request_executor: String,
+        enable_profiling: bool,
     ) -> QueryFragmentsPlanPacket {
         QueryFragmentsPlanPacket {
             query_id,
@@ -50,6 +53,7 @@ impl QueryFragmentsPlanPacket {
             fragments,
             executors_info,
             request_executor,
+            enable_profiling,
         }
     }
 }
@@ -0,0 +1,159 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_base::base::tokio::runtime::Handle;
+use common_base::base::tokio::task::block_in_place;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::SequenceAllocator;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::SequenceIdent;
+use parking_lot::Mutex;
+
+/// The number of values a query node allocates from the meta service at least once.
+const SEQUENCE_CACHE_SIZE: u64 = 1000;
+
+/// The values allocated to this node but not handed out yet.
+struct SequenceRange {
+    sequence_id: u64,
+    next: i64,
+    increment: i64,
+    remaining: u64,
+}
+
+/// Node local cache of the sequence values.
+///
+/// Ranges are allocated from the meta service in batches of `SEQUENCE_CACHE_SIZE`,
+/// so `nextval` only talks to the meta service once a batch is used up.
+/// The ranges never overlap, thus the values are unique across the cluster,
+/// but they are not increasing across the nodes.
+///
+/// A range is only used by the queries planned with the same sequence id, the range of
+/// a dropped sequence is never handed out after the sequence is re-created.
+pub struct SequenceCache {
+    ranges: Mutex<HashMap<(String, String), SequenceRange>>,
+}
+
+impl SequenceCache {
+    pub fn init() -> Result<()> {
+        let cache = Arc::new(SequenceCache {
+            ranges: Mutex::new(HashMap::new()),
+        });
+        GlobalInstance::set(cache.clone());
+        GlobalInstance::set::<Arc<dyn SequenceAllocator>>(cache);
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<SequenceCache> {
+        GlobalInstance::get()
+    }
+
+    /// Forget the cached range of a dropped sequence.
+    pub fn invalidate(&self, tenant: &str, sequence: &str) {
+        self.ranges
+            .lock()
+            .remove(&(tenant.to_string(), sequence.to_string()));
+    }
+
+    fn take(range: &mut SequenceRange, count: u64, values: &mut Vec<i64>) {
+        let n = count.min(range.remaining);
+        for _ in 0..n {
+            values.push(range.next);
+            // The last value of a range may be the end of i64.
+            range.next = range.next.wrapping_add(range.increment);
+        }
+        range.remaining -= n;
+    }
+}
+
+impl SequenceAllocator for SequenceCache {
+    fn next_values(
+        &self,
+        tenant: &str,
+        sequence: &str,
+        sequence_id: u64,
+        count: u64,
+    ) -> Result<Vec<i64>> {
+        let key = (tenant.to_string(), sequence.to_string());
+        let mut values = Vec::with_capacity(count as usize);
+
+        {
+            let mut ranges = self.ranges.lock();
+            match ranges.get_mut(&key) {
+                Some(range) if range.sequence_id == sequence_id => {
+                    Self::take(range, count, &mut values)
+                }
+                // The cached range belongs to a dropped sequence.
+                Some(_) => {
+                    ranges.remove(&key);
+                }
+                None => {}
+            }
+        }
+
+        let needed = count - values.len() as u64;
+        if needed == 0 {
+            return Ok(values);
+        }
+
+        // The lock is not held while talking to the meta service, `nextval` of the
+        // other sequences goes on with their cached ranges.
+
+        let catalog = CatalogManager::instance().get_catalog(CATALOG_DEFAULT)?;
+        let req = GetSequenceNextValueReq {
+            ident: SequenceIdent::new(tenant, sequence),
+            count: needed.max(SEQUENCE_CACHE_SIZE),
+        };
+        let future = async move { catalog.get_sequence_next_value(req).await };
+        let reply = match Handle::try_current() {
+            // e.g. `INSERT ... VALUES` is evaluated inside the async runtime.
+            Ok(handle) => block_in_place(|| handle.block_on(future))?,
+            Err(_) => GlobalIORuntime::instance().block_on(future)?,
+        };
+
+        if reply.sequence_id != sequence_id {
+            return Err(ErrorCode::UnknownSequence(format!(
+                "Sequence '{}' was dropped while the query is running",
+                sequence
+            )));
+        }
+
+        let mut range = SequenceRange {
+            sequence_id,
+            next: reply.start,
+            increment: reply.increment,
+            remaining: reply.count,
+        };
+        Self::take(&mut range, needed, &mut values);
+
+        // Another allocation may have refilled the range meanwhile, its values are kept
+        // and the rest of this range is skipped.
+        let mut ranges = self.ranges.lock();
+        match ranges.get(&key) {
+            Some(cached) if cached.sequence_id == sequence_id && cached.remaining > 0 => {}
+            _ => {
+                ranges.insert(key, range);
+            }
+        }
+
+        Ok(values)
+    }
+}
@@ -24,3 +24,4 @@ pub use common_storages_hive as hive;
 pub use default::table_id_ranges::*;
 pub use default::table_memory_meta::InMemoryMetas;
 pub use default::DatabaseCatalog;
+pub use default::SequenceCache;
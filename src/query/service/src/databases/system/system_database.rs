@@ -42,6 +42,7 @@ use common_storages_system::TablesTableWithHistory;
 use common_storages_system::TablesTableWithoutHistory;
 use common_storages_system::TracingTable;
 use common_storages_system::UsersTable;
+use common_storages_system::WorkloadGroupsTable;
 
 use super::BuildOptionsTable;
 use crate::catalogs::InMemoryMetas;
@@ -86,6 +87,7 @@ impl SystemDatabase {
             StagesTable::create(sys_db_meta.next_table_id()),
             BuildOptionsTable::create(sys_db_meta.next_table_id()),
             CatalogsTable::create(sys_db_meta.next_table_id()),
+            WorkloadGroupsTable::create(sys_db_meta.next_table_id()),
         ];
 
         for tbl in table_list.into_iter() {
@@ -28,7 +28,10 @@ use storages_common_table_meta::caches::CacheManager;
 
 use crate::api::DataExchangeManager;
 use crate::catalogs::CatalogManagerHelper;
+use crate::catalogs::SequenceCache;
 use crate::clusters::ClusterDiscovery;
+use crate::query_history::QueryHistory;
+use crate::result_cache::ResultCache;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
 
@@ -63,6 +66,9 @@ impl GlobalServices {
 
         CacheManager::init(&config.query)?;
         CatalogManager::init(&config).await?;
+        SequenceCache::init()?;
+        ResultCache::init()?;
+        QueryHistory::init(&config)?;
         HttpQueryManager::init(&config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(&config)?;
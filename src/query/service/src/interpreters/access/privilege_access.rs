@@ -190,6 +190,45 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateSequence(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Create)
+                    .await?;
+            }
+            Plan::DropSequence(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Drop)
+                    .await?;
+            }
+            Plan::CreateInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::DropInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::CreateWorkloadGroup(_) | Plan::DropWorkloadGroup(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                    .await?;
+            }
             Plan::AlterUser(_) => {}
             Plan::CreateUser(_) => {}
             Plan::DropUser(_) => {}
@@ -217,6 +256,9 @@ impl AccessChecker for PrivilegeAccess {
                     .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
                     .await?;
             }
+            Plan::Begin => {}
+            Plan::Commit => {}
+            Plan::Abort => {}
             Plan::CreateShare(_) => {}
             Plan::DropShare(_) => {}
             Plan::GrantShareObject(_) => {}
@@ -48,22 +48,33 @@ pub trait Interpreter: Sync + Send {
     }
 
     /// The core of the databend processor which will execute the logical plan and get the DataBlock
+    #[tracing::instrument(level = "info", name = "query", skip_all, fields(query_id = %ctx.get_id(), tenant = %ctx.get_tenant()))]
     async fn execute(&self, ctx: Arc<QueryContext>) -> Result<SendableDataBlockStream> {
         InterpreterMetrics::record_query_start(&ctx);
         log_query_start(&ctx);
 
+        // Queue until the workload group of the query has a free slot.
+        let permitted = match ctx.acquire_workload_permit().await {
+            Ok(permitted) => permitted,
+            Err(admit_error) => {
+                InterpreterMetrics::record_query_error(&ctx);
+                log_query_finished(&ctx, Some(admit_error.clone()), false);
+                return Err(admit_error);
+            }
+        };
+
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
                 InterpreterMetrics::record_query_error(&ctx);
-                log_query_finished(&ctx, Some(build_error.clone()));
+                log_query_finished(&ctx, Some(build_error.clone()), permitted);
                 return Err(build_error);
             }
         };
 
         if build_res.main_pipeline.pipes.is_empty() {
             InterpreterMetrics::record_query_finished(&ctx, None);
-            log_query_finished(&ctx, None);
+            log_query_finished(&ctx, None, permitted);
 
             return Ok(Box::pin(DataBlockStream::create(None, vec![])));
         }
@@ -71,7 +82,7 @@ pub trait Interpreter: Sync + Send {
         let query_ctx = ctx.clone();
         build_res.main_pipeline.set_on_finished(move |may_error| {
             InterpreterMetrics::record_query_finished(&query_ctx, may_error.clone());
-            log_query_finished(&query_ctx, may_error.clone());
+            log_query_finished(&query_ctx, may_error.clone(), permitted);
 
             match may_error {
                 None => Ok(()),
@@ -80,9 +91,12 @@ pub trait Interpreter: Sync + Send {
         });
 
         let settings = ctx.get_settings();
-        let query_id = ctx.get_id();
-        build_res.set_max_threads(settings.get_max_threads()? as usize);
-        let settings = ExecutorSettings::try_create(&settings, query_id)?;
+        let max_threads = match ctx.get_workload_queue() {
+            None => settings.get_max_threads()?,
+            Some(queue) => queue.max_threads(settings.get_max_threads()?),
+        };
+        build_res.set_max_threads(max_threads as usize);
+        let settings = ExecutorSettings::try_create(&ctx)?;
 
         if build_res.main_pipeline.is_complete_pipeline()? {
             let mut pipelines = build_res.sources_pipelines;
@@ -130,10 +144,16 @@ fn log_query_start(ctx: &QueryContext) {
     }
 }
 
-fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
+/// `permitted` is true if the query took the permit of its workload group, the
+/// nested interpreters of the query leave the permit to the query.
+fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, permitted: bool) {
     let now = SystemTime::now();
     let session = ctx.get_current_session();
 
+    if permitted {
+        ctx.release_workload_permit();
+    }
+    session.set_last_query_id(ctx.get_id());
     session.get_status().write().query_finish();
     if session.get_type().is_user_session() {
         SessionManager::instance().status.write().query_finish(now)
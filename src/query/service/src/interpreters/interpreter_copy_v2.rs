@@ -27,6 +27,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_table_schema;
 use common_expression::DataField;
+use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableCopiedFileInfo;
@@ -42,6 +43,7 @@ use tracing::info;
 use crate::interpreters::common::append2table;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreterV2;
+use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -83,6 +85,7 @@ impl CopyInterpreterV2 {
             *s_expr.clone(),
             metadata.clone(),
             false,
+            None,
         )?;
 
         // Building data schema from bind_context columns
@@ -343,6 +346,22 @@ impl CopyInterpreterV2 {
             )?;
         }
 
+        // Compute the stored generated columns, which are not in the files.
+        if stage_table_info.schema.num_fields() < to_table.schema().num_fields() {
+            let source_schema: DataSchemaRef = Arc::new(stage_table_info.schema.as_ref().into());
+            build_res.main_pipeline.add_transform(
+                |transform_input_port, transform_output_port| {
+                    TransformResortAddOn::try_create(
+                        ctx.clone(),
+                        transform_input_port,
+                        transform_output_port,
+                        source_schema.clone(),
+                        to_table.clone(),
+                    )
+                },
+            )?;
+        }
+
         // Build append data pipeline.
         to_table.append_data(
             ctx.clone(),
@@ -11,6 +11,7 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
@@ -24,9 +25,12 @@ use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::FromData;
+use common_pipeline_core::processors::PlanProfiles;
 use common_sql::MetadataRef;
 
 use crate::interpreters::Interpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline;
 use crate::schedulers::Fragmenter;
@@ -100,6 +104,17 @@ impl Interpreter for ExplainInterpreter {
                 }
             },
 
+            ExplainKind::AnalyzePlan => match &self.plan {
+                Plan::Query {
+                    s_expr, metadata, ..
+                } => self.explain_analyze(s_expr, metadata).await?,
+                _ => {
+                    return Err(ErrorCode::Unimplemented(
+                        "Unsupported EXPLAIN ANALYZE statement",
+                    ));
+                }
+            },
+
             ExplainKind::Graph => {
                 return Err(ErrorCode::Unimplemented(
                     "ExplainKind graph is unimplemented",
@@ -179,6 +194,57 @@ impl ExplainInterpreter {
         Ok(blocks)
     }
 
+    /// Execute the query, and annotate the physical plan with the runtime profile
+    /// collected from all the nodes of the cluster.
+    async fn explain_analyze(
+        &self,
+        s_expr: &SExpr,
+        metadata: &MetadataRef,
+    ) -> Result<Vec<DataBlock>> {
+        let builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
+        let plan = builder.build(s_expr).await?;
+
+        self.ctx.enable_plan_profiling();
+        let build_res = build_query_pipeline(&self.ctx, &[], &plan, true).await?;
+
+        let executor_settings = ExecutorSettings::try_create(&self.ctx)?;
+
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        self.ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+        executor.execute()?;
+        drop(executor);
+
+        let profiles = match self.ctx.get_plan_profiles() {
+            Some(profiles) => {
+                Self::record_plan_pruning(&plan, &profiles);
+                profiles.statistics()
+            }
+            None => HashMap::new(),
+        };
+        let result = plan.format_with_profiles(metadata.clone(), profiles)?;
+        let line_splitted_result: Vec<&str> = result.lines().collect();
+        let formatted_plan = StringType::from_data(line_splitted_result);
+        Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
+    }
+
+    /// The partitions pruned when the plan is built are not seen by the executors,
+    /// record them into the profiles of the table scans.
+    fn record_plan_pruning(plan: &PhysicalPlan, profiles: &PlanProfiles) {
+        if let (PhysicalPlan::TableScan(scan), Some(plan_id)) = (plan, plan.plan_id()) {
+            let statistics = &scan.source.statistics;
+            let pruned = statistics
+                .partitions_total
+                .saturating_sub(statistics.partitions_scanned);
+            profiles.get_or_create(plan_id).add_partitions(0, pruned);
+        }
+
+        for child in plan.children() {
+            Self::record_plan_pruning(child, profiles);
+        }
+    }
+
     async fn explain_fragments(
         &self,
         s_expr: SExpr,
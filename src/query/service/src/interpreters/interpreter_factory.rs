@@ -15,8 +15,11 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
+use common_storages_fuse::FuseTable;
 use tracing::error;
 
 use super::interpreter_catalog_create::CreateCatalogInterpreter;
@@ -36,7 +39,10 @@ use crate::interpreters::DropShareInterpreter;
 use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::UpdateInterpreter;
+use crate::query_history::QUERY_HISTORY_DATABASE;
 use crate::sessions::QueryContext;
+use crate::sessions::SessionType;
+use crate::sql::plans::CopyPlanV2;
 use crate::sql::plans::Plan;
 
 /// InterpreterFactory is the entry of Interpreter.
@@ -52,9 +58,138 @@ impl InterpreterFactory {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+        Self::check_txn(ctx.as_ref(), plan).await?;
+        Self::check_reserved_database(ctx.as_ref(), plan)?;
         Self::get_inner(ctx, plan)
     }
 
+    /// Only the statements which either change fuse tables through snapshots, or change
+    /// nothing at all, can run inside an explicit transaction.
+    async fn check_txn(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        if !ctx.txn_mgr().lock().is_active() {
+            return Ok(());
+        }
+        match plan {
+            Plan::Insert(insert) => {
+                Self::check_txn_table(ctx, &insert.catalog, &insert.database, &insert.table).await
+            }
+            Plan::Delete(delete) => {
+                Self::check_txn_table(
+                    ctx,
+                    &delete.catalog_name,
+                    &delete.database_name,
+                    &delete.table_name,
+                )
+                .await
+            }
+            Plan::Update(update) => {
+                Self::check_txn_table(ctx, &update.catalog, &update.database, &update.table).await
+            }
+            Plan::Query { .. }
+            | Plan::Explain { .. }
+            | Plan::ExplainAst { .. }
+            | Plan::ExplainSyntax { .. }
+            | Plan::UseDatabase(_)
+            | Plan::ShowCreateCatalog(_)
+            | Plan::ShowCreateDatabase(_)
+            | Plan::ShowCreateTable(_)
+            | Plan::DescribeTable(_)
+            | Plan::ExistsTable(_)
+            | Plan::ShowRoles(_)
+            | Plan::ShowGrants(_)
+            | Plan::ListStage(_)
+            | Plan::Presign(_)
+            | Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::Kill(_)
+            | Plan::Begin
+            | Plan::Commit
+            | Plan::Abort => Ok(()),
+            _ => Err(ErrorCode::TransactionError(format!(
+                "{} is not supported in a transaction",
+                plan
+            ))),
+        }
+    }
+
+    /// The changes of a table are buffered by the transaction only if they are committed
+    /// through the snapshots of a fuse table, the other tables can't be changed in a transaction.
+    async fn check_txn_table(
+        ctx: &QueryContext,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<()> {
+        let tbl = ctx.get_table(catalog, database, table).await?;
+        if tbl.as_any().downcast_ref::<FuseTable>().is_none() {
+            return Err(ErrorCode::TransactionError(format!(
+                "table {}.{} of engine {} can't be changed in a transaction, only fuse tables can",
+                database,
+                table,
+                tbl.engine()
+            )));
+        }
+        Ok(())
+    }
+
+    /// The history database is written by the query history of the nodes only, the other
+    /// sessions can read it but can't change it, or create anything in it.
+    fn check_reserved_database(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        if matches!(
+            ctx.get_current_session().get_type(),
+            SessionType::QueryHistory
+        ) {
+            return Ok(());
+        }
+
+        let databases = match plan {
+            Plan::CreateDatabase(plan) => vec![&plan.database],
+            Plan::DropDatabase(plan) => vec![&plan.database],
+            Plan::UndropDatabase(plan) => vec![&plan.database],
+            Plan::RenameDatabase(plan) => plan
+                .entities
+                .iter()
+                .flat_map(|entity| [&entity.database, &entity.new_database])
+                .collect(),
+            Plan::CreateTable(plan) => vec![&plan.database],
+            Plan::DropTable(plan) => vec![&plan.database],
+            Plan::UndropTable(plan) => vec![&plan.database],
+            Plan::RenameTable(plan) => plan
+                .entities
+                .iter()
+                .flat_map(|entity| [&entity.database, &entity.new_database])
+                .collect(),
+            Plan::AlterTableClusterKey(plan) => vec![&plan.database],
+            Plan::DropTableClusterKey(plan) => vec![&plan.database],
+            Plan::ReclusterTable(plan) => vec![&plan.database],
+            Plan::RevertTable(plan) => vec![&plan.database],
+            Plan::TruncateTable(plan) => vec![&plan.database],
+            Plan::OptimizeTable(plan) => vec![&plan.database],
+            Plan::AnalyzeTable(plan) => vec![&plan.database],
+            Plan::Insert(plan) => vec![&plan.database],
+            Plan::Delete(plan) => vec![&plan.database_name],
+            Plan::Update(plan) => vec![&plan.database],
+            Plan::Copy(plan) => match plan.as_ref() {
+                CopyPlanV2::IntoTable { database_name, .. } => vec![database_name],
+                _ => vec![],
+            },
+            Plan::CreateView(plan) => vec![&plan.database],
+            Plan::AlterView(plan) => vec![&plan.database],
+            Plan::DropView(plan) => vec![&plan.database],
+            Plan::CreateInvertedIndex(plan) => vec![&plan.database],
+            Plan::DropInvertedIndex(plan) => vec![&plan.database],
+            _ => vec![],
+        };
+
+        match databases.iter().any(|db| *db == QUERY_HISTORY_DATABASE) {
+            true => Err(ErrorCode::PermissionDenied(format!(
+                "Permission denied, database {} is reserved for the query history",
+                QUERY_HISTORY_DATABASE
+            ))),
+            false => Ok(()),
+        }
+    }
+
     /// This is used for handlers to get the schema of the plan.
     /// Some plan may miss the schema and return empty plan such as `CallPlan`
     /// So we need to map the plan into to `Interpreter` and get the right schema.
@@ -75,6 +210,7 @@ impl InterpreterFactory {
                 bind_context,
                 metadata,
                 ignore_result,
+                formatted_ast,
                 ..
             } => Ok(Arc::new(SelectInterpreterV2::try_create(
                 ctx,
@@ -82,6 +218,7 @@ impl InterpreterFactory {
                 *s_expr.clone(),
                 metadata.clone(),
                 *ignore_result,
+                formatted_ast.clone(),
             )?)),
             Plan::Explain { kind, plan } => Ok(Arc::new(ExplainInterpreter::try_create(
                 ctx,
@@ -196,6 +333,31 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Sequences
+            Plan::CreateSequence(create_sequence) => Ok(Arc::new(
+                CreateSequenceInterpreter::try_create(ctx, *create_sequence.clone())?,
+            )),
+            Plan::DropSequence(drop_sequence) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *drop_sequence.clone(),
+            )?)),
+
+            // Inverted indexes
+            Plan::CreateInvertedIndex(create_index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *create_index.clone())?,
+            )),
+            Plan::DropInvertedIndex(drop_index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *drop_index.clone())?,
+            )),
+
+            // Workload groups
+            Plan::CreateWorkloadGroup(create_group) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *create_group.clone())?,
+            )),
+            Plan::DropWorkloadGroup(drop_group) => Ok(Arc::new(
+                DropWorkloadGroupInterpreter::try_create(ctx, *drop_group.clone())?,
+            )),
+
             // Users
             Plan::CreateUser(create_user) => Ok(Arc::new(CreateUserInterpreter::try_create(
                 ctx,
@@ -307,6 +469,11 @@ impl InterpreterFactory {
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
 
+            // Transactions
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+
             // share plans
             Plan::CreateShare(p) => Ok(Arc::new(CreateShareInterpreter::try_create(
                 ctx,
@@ -464,17 +464,30 @@ impl Interpreter for InsertInterpreterV2 {
 
                     let ctx = self.ctx.clone();
                     let overwrite = self.plan.overwrite;
+                    let partition = self.plan.partition.clone();
                     build_res.main_pipeline.set_on_finished(move |may_error| {
                         // capture out variable
                         let overwrite = overwrite;
                         let ctx = ctx.clone();
                         let table = table.clone();
+                        let partition = partition.clone();
 
                         if may_error.is_none() {
                             let append_entries = ctx.consume_precommit_blocks();
                             // We must put the commit operation to global runtime, which will avoid the "dispatch dropped without returning error" in tower
                             return GlobalIORuntime::instance().block_on(async move {
-                                table.commit_insertion(ctx, append_entries, overwrite).await
+                                if partition.is_empty() {
+                                    table.commit_insertion(ctx, append_entries, overwrite).await
+                                } else {
+                                    table
+                                        .commit_partition_insertion(
+                                            ctx,
+                                            append_entries,
+                                            overwrite,
+                                            partition,
+                                        )
+                                        .await
+                                }
                             });
                         }
 
@@ -0,0 +1,98 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateInvertedIndexPlan;
+use storages_common_table_meta::table::format_inverted_indexes;
+use storages_common_table_meta::table::parse_inverted_indexes;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEXES;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateInvertedIndexPlan,
+}
+
+impl CreateInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateInvertedIndexPlan) -> Result<Self> {
+        Ok(CreateInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateInvertedIndexInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "inverted index is only supported by FUSE tables, but table {}.{} is {}",
+                plan.database,
+                plan.table,
+                table.engine()
+            )));
+        }
+
+        let table_info = table.get_table_info();
+        let mut indexes = table_info
+            .options()
+            .get(OPT_KEY_INVERTED_INDEXES)
+            .map(|value| parse_inverted_indexes(value))
+            .unwrap_or_default();
+        if indexes.contains_key(&plan.index) {
+            return if plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::InvertedIndexAlreadyExists(format!(
+                    "inverted index {} already exists on table {}.{}",
+                    plan.index, plan.database, plan.table
+                )))
+            };
+        }
+        indexes.insert(plan.index.clone(), plan.column.clone());
+
+        // Only the blocks written from now on are indexed, the existing blocks get
+        // their indexes when they are compacted or reclustered.
+        let req = UpsertTableOptionReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_INVERTED_INDEXES.to_string(),
+                Some(format_inverted_indexes(&indexes)),
+            )]),
+        };
+        catalog
+            .upsert_table_option(&plan.tenant, &plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -30,6 +30,7 @@ use tracing::error;
 use tracing::info;
 use tracing::subscriber;
 
+use crate::query_history::QueryHistory;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 
@@ -59,7 +60,7 @@ fn error_fields(log_type: LogType, err: Option<ErrorCode>) -> (LogType, i32, Str
 }
 
 impl InterpreterQueryLog {
-    fn write_log(event: QueryLogElement) -> Result<()> {
+    fn write_log(ctx: &QueryContext, event: QueryLogElement) -> Result<()> {
         info!("{}", serde_json::to_string(&event)?);
 
         if let Some(logger) = QueryLogger::instance().get_subscriber() {
@@ -69,6 +70,8 @@ impl InterpreterQueryLog {
             });
         };
 
+        QueryHistory::instance().append(ctx, &event);
+
         QueryLogQueue::instance()?.append_data(event)
     }
 
@@ -136,7 +139,7 @@ impl InterpreterQueryLog {
         let (log_type, exception_code, exception_text, stack_trace) =
             error_fields(LogType::Start, err);
 
-        Self::write_log(QueryLogElement {
+        Self::write_log(ctx, QueryLogElement {
             log_type,
             handler_type,
             tenant_id,
@@ -248,7 +251,7 @@ impl InterpreterQueryLog {
         let (log_type, exception_code, exception_text, stack_trace) =
             error_fields(LogType::Finish, err);
 
-        Self::write_log(QueryLogElement {
+        Self::write_log(ctx, QueryLogElement {
             log_type,
             handler_type,
             tenant_id,
@@ -13,13 +13,21 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use common_sql::MetadataRef;
+use tracing::warn;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
+use crate::result_cache::build_result_cache_key;
+use crate::result_cache::CachedResult;
+use crate::result_cache::ResultCache;
+use crate::result_cache::ResultCacheStorage;
+use crate::result_cache::ResultCacheWriteInfo;
+use crate::result_cache::TransformResultCacheWriter;
 use crate::schedulers::build_query_pipeline;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -34,6 +42,7 @@ pub struct SelectInterpreterV2 {
     bind_context: BindContext,
     metadata: MetadataRef,
     ignore_result: bool,
+    formatted_ast: Option<String>,
 }
 
 impl SelectInterpreterV2 {
@@ -43,6 +52,7 @@ impl SelectInterpreterV2 {
         s_expr: SExpr,
         metadata: MetadataRef,
         ignore_result: bool,
+        formatted_ast: Option<String>,
     ) -> Result<Self> {
         Ok(SelectInterpreterV2 {
             ctx,
@@ -50,9 +60,36 @@ impl SelectInterpreterV2 {
             bind_context,
             metadata,
             ignore_result,
+            formatted_ast,
         })
     }
 
+    /// Reads the result cached under the key by any node, and keeps it in the memory
+    /// of this node. A result that can't be read is treated as not cached.
+    async fn read_cached_result(
+        storage: &ResultCacheStorage,
+        key: &str,
+        capacity_bytes: usize,
+    ) -> Option<(String, Arc<CachedResult>)> {
+        match storage.read_by_key(key).await {
+            Ok(Some((query_id, result))) => {
+                let result = Arc::new(result);
+                ResultCache::instance().put(
+                    &query_id,
+                    Some(key.to_string()),
+                    result.clone(),
+                    capacity_bytes,
+                );
+                Some((query_id, result))
+            }
+            Ok(None) => None,
+            Err(cause) => {
+                warn!("Cannot read the cached result: {}", cause);
+                None
+            }
+        }
+    }
+
     pub async fn build_pipeline(&self) -> Result<PipelineBuildResult> {
         let builder = PhysicalPlanBuilder::new(self.metadata.clone(), self.ctx.clone());
         let physical_plan = builder.build(&self.s_expr).await?;
@@ -80,7 +117,73 @@ impl Interpreter for SelectInterpreterV2 {
     /// The QueryPipelineBuilder will use the optimized plan to generate a Pipeline
     #[tracing::instrument(level = "debug", name = "select_interpreter_v2_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let build_res = self.build_pipeline().await?;
+        let cache_key = match &self.formatted_ast {
+            Some(formatted_ast) if !self.ignore_result => {
+                build_result_cache_key(&self.ctx, formatted_ast, &self.metadata).await?
+            }
+            _ => None,
+        };
+
+        let settings = self.ctx.get_settings();
+        let capacity_bytes = settings.get_query_result_cache_capacity_bytes()? as usize;
+        let result_cache = ResultCache::instance();
+        let storage = ResultCacheStorage::create(&self.ctx.get_tenant());
+        let cached = match &cache_key {
+            Some(key) => match result_cache.get(key) {
+                Some(cached) => Some(cached),
+                None => Self::read_cached_result(&storage, key, capacity_bytes).await,
+            },
+            None => None,
+        };
+
+        if let Some((cached_query_id, cached)) = cached {
+            // Keep the result under the id of this query too, for `RESULT_SCAN(last_query_id())`,
+            // it belongs to the user of this query.
+            let query_id = self.ctx.get_id();
+            let result = Arc::new(CachedResult::create(
+                self.ctx.get_current_user()?.identity().to_string(),
+                cached.schema.clone(),
+                cached.block.clone(),
+                Duration::from_secs(settings.get_query_result_cache_ttl_secs()?),
+            ));
+            result_cache.put(&query_id, None, result.clone(), capacity_bytes);
+            if let Err(cause) = storage
+                .write_alias(&query_id, &cached_query_id, &result)
+                .await
+            {
+                warn!(
+                    "Cannot write the cached result of query {}: {}",
+                    query_id, cause
+                );
+            }
+            return PipelineBuildResult::from_blocks(vec![cached.block.clone()]);
+        }
+
+        let mut build_res = self.build_pipeline().await?;
+
+        if let Some(key) = cache_key {
+            let info = ResultCacheWriteInfo {
+                tenant: self.ctx.get_tenant(),
+                query_id: self.ctx.get_id(),
+                key: Some(key),
+                user: self.ctx.get_current_user()?.identity().to_string(),
+                schema: self.bind_context.output_schema(),
+                ttl: Duration::from_secs(settings.get_query_result_cache_ttl_secs()?),
+                max_bytes: settings.get_query_result_cache_max_bytes()? as usize,
+                capacity_bytes,
+            };
+
+            // The result is cached in the order it's returned.
+            build_res.main_pipeline.resize(1)?;
+            build_res.main_pipeline.add_transform(|input, output| {
+                Ok(TransformResultCacheWriter::create(
+                    input,
+                    output,
+                    info.clone(),
+                ))
+            })?;
+        }
+
         Ok(build_res)
     }
 }
@@ -14,14 +14,24 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::CreateSequenceReq;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DropSequenceReq;
+use common_meta_app::schema::DropTableReq;
+use common_meta_app::schema::SequenceIdent;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::identity_sequence_of;
+use common_sql::plans::CloneTableSource;
+use common_sql::plans::CreateSequencePlan;
 use common_sql::plans::CreateTablePlanV2;
 use common_users::UserApiProvider;
+use tracing::warn;
 
 use crate::interpreters::InsertInterpreterV2;
 use crate::interpreters::Interpreter;
@@ -98,10 +108,31 @@ impl Interpreter for CreateTableInterpreterV2 {
             }
         }
 
-        match &self.plan.as_select {
+        // If the table exists, the creation is ignored or fails, the sequences are not used.
+        let create_sequences = name_not_duplicate && !self.plan.identity_sequences.is_empty();
+        if create_sequences {
+            self.create_identity_sequences().await?;
+        }
+
+        let result = match &self.plan.as_select {
             Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
-            None => self.create_table().await,
+            None => match &self.plan.clone_source {
+                // If the table exists, the creation is ignored or fails, there is nothing to clone.
+                Some(clone_source) if name_not_duplicate => {
+                    self.create_table_by_clone(clone_source).await
+                }
+                _ => self.create_table().await,
+            },
+        };
+
+        // The table may not be created, or be created concurrently by another query with
+        // IF NOT EXISTS, or be dropped after the clone failed, then no one uses the sequences.
+        if create_sequences && !self.table_uses_identity_sequences().await {
+            self.drop_identity_sequences(&self.plan.identity_sequences)
+                .await;
         }
+
+        result
     }
 }
 
@@ -133,6 +164,7 @@ impl CreateTableInterpreterV2 {
             schema: self.plan.schema.clone(),
             overwrite: false,
             source: InsertInputSource::SelectPlan(select_plan),
+            partition: vec![],
         };
 
         InsertInterpreterV2::try_create(self.ctx.clone(), insert_plan, false)?
@@ -140,6 +172,55 @@ impl CreateTableInterpreterV2 {
             .await
     }
 
+    async fn create_table_by_clone(
+        &self,
+        clone_source: &CloneTableSource,
+    ) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+
+        let req = self.build_request()?;
+        let name_ident = req.name_ident.clone();
+        catalog.create_table(req).await?;
+
+        // the table is dropped if the clone fails, no one can see the empty table but
+        // the concurrent queries.
+        let result = async {
+            let table = catalog
+                .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await?;
+            let mut source = self
+                .ctx
+                .get_table(
+                    &clone_source.catalog,
+                    &clone_source.database,
+                    &clone_source.table,
+                )
+                .await?;
+            if let Some(point) = &clone_source.point {
+                source = source.navigate_to(point).await?;
+            }
+            table.clone_data_from(self.ctx.clone(), source).await
+        }
+        .await;
+
+        if let Err(e) = result {
+            let req = DropTableReq {
+                if_exists: true,
+                name_ident,
+            };
+            if let Err(drop_err) = catalog.drop_table(req).await {
+                warn!(
+                    "failed to drop table {}.{} after the clone failed: {}",
+                    self.plan.database, self.plan.table, drop_err
+                );
+            }
+            return Err(e);
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+
     async fn create_table(&self) -> Result<PipelineBuildResult> {
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str())?;
         catalog.create_table(self.build_request()?).await?;
@@ -147,6 +228,69 @@ impl CreateTableInterpreterV2 {
         Ok(PipelineBuildResult::create())
     }
 
+    async fn create_identity_sequences(&self) -> Result<()> {
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+        for (i, sequence) in self.plan.identity_sequences.iter().enumerate() {
+            let req = CreateSequenceReq {
+                if_not_exists: sequence.if_not_exists,
+                ident: SequenceIdent::new(&sequence.tenant, &sequence.sequence),
+                start: sequence.start,
+                increment: sequence.increment,
+                comment: sequence.comment.clone(),
+                create_on: Utc::now(),
+            };
+            if let Err(e) = catalog.create_sequence(req).await {
+                self.drop_identity_sequences(&self.plan.identity_sequences[..i])
+                    .await;
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the table of the plan exists and takes its values from the identity sequences.
+    async fn table_uses_identity_sequences(&self) -> bool {
+        let Ok(catalog) = self.ctx.get_catalog(&self.plan.catalog) else {
+            return false;
+        };
+        let Ok(table) = catalog
+            .get_table(&self.plan.tenant, &self.plan.database, &self.plan.table)
+            .await
+        else {
+            return false;
+        };
+        let sequence = &self.plan.identity_sequences[0].sequence;
+        table.schema().fields().iter().any(|field| {
+            field
+                .default_expr()
+                .and_then(|expr| identity_sequence_of(expr))
+                .map_or(false, |s| s == sequence)
+        })
+    }
+
+    /// The failures are only logged, the sequences are left behind but never used.
+    async fn drop_identity_sequences(&self, sequences: &[CreateSequencePlan]) {
+        let catalog = match self.ctx.get_catalog(CATALOG_DEFAULT) {
+            Ok(catalog) => catalog,
+            Err(e) => {
+                warn!("failed to drop the identity sequences: {}", e);
+                return;
+            }
+        };
+        for sequence in sequences {
+            let req = DropSequenceReq {
+                if_exists: true,
+                ident: SequenceIdent::new(&sequence.tenant, &sequence.sequence),
+            };
+            if let Err(e) = catalog.drop_sequence(req).await {
+                warn!(
+                    "failed to drop the identity sequence {}: {}",
+                    sequence.sequence, e
+                );
+            }
+        }
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::StringType;
+use common_expression::ComputedExpr;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
 use common_expression::FromData;
@@ -58,18 +59,25 @@ impl Interpreter for DescribeTableInterpreter {
         let table = self.ctx.get_table(catalog, database, table).await?;
         let tbl_info = table.get_table_info();
 
-        let schema = if tbl_info.engine() == VIEW_ENGINE {
+        let (schema, computed_exprs) = if tbl_info.engine() == VIEW_ENGINE {
             if let Some(query) = tbl_info.options().get(QUERY) {
                 let mut planner = Planner::new(self.ctx.clone());
                 let (plan, _, _) = planner.plan_sql(query).await?;
-                plan.schema()
+                (plan.schema(), vec![])
             } else {
                 return Err(ErrorCode::Internal(
                     "Logical error, View Table must have a SelectQuery inside.",
                 ));
             }
         } else {
-            Arc::new((&table.schema()).into())
+            // The virtual generated columns are only in the meta of the table.
+            let schema = &tbl_info.meta.schema;
+            let computed_exprs = schema
+                .fields()
+                .iter()
+                .map(|field| field.computed_expr().cloned())
+                .collect::<Vec<_>>();
+            (Arc::new(schema.into()), computed_exprs)
         };
 
         let mut names: Vec<Vec<u8>> = vec![];
@@ -78,7 +86,7 @@ impl Interpreter for DescribeTableInterpreter {
         let mut default_exprs: Vec<Vec<u8>> = vec![];
         let mut extras: Vec<Vec<u8>> = vec![];
 
-        for field in schema.fields().iter() {
+        for (idx, field) in schema.fields().iter().enumerate() {
             names.push(field.name().to_string().as_bytes().to_vec());
 
             let non_null_type = field.data_type().remove_nullable();
@@ -98,7 +106,12 @@ impl Interpreter for DescribeTableInterpreter {
                     default_exprs.push(value.to_string().as_bytes().to_vec());
                 }
             }
-            extras.push("".to_string().as_bytes().to_vec());
+            let extra = match computed_exprs.get(idx) {
+                Some(Some(ComputedExpr::Virtual(_))) => "VIRTUAL GENERATED",
+                Some(Some(ComputedExpr::Stored(_))) => "STORED GENERATED",
+                _ => "",
+            };
+            extras.push(extra.as_bytes().to_vec());
         }
 
         PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
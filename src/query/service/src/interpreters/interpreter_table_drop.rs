@@ -14,12 +14,18 @@
 
 use std::sync::Arc;
 
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::table::TableExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::DropSequenceReq;
+use common_meta_app::schema::SequenceIdent;
+use common_sql::plans::identity_sequence_of;
 use common_sql::plans::DropTablePlan;
+use common_storages_fuse::FuseTable;
 use common_storages_view::view_table::VIEW_ENGINE;
 
+use crate::catalogs::SequenceCache;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -65,13 +71,38 @@ impl Interpreter for DropTableInterpreter {
         catalog.drop_table(self.plan.clone().into()).await?;
 
         if let Some(tbl) = tbl {
+            // the sequences of the identity columns are only used by the table
+            let sequences = tbl
+                .schema()
+                .fields()
+                .iter()
+                .filter_map(|field| field.default_expr().and_then(|e| identity_sequence_of(e)))
+                .map(|sequence| sequence.to_string())
+                .collect::<Vec<_>>();
+            if !sequences.is_empty() {
+                let tenant = self.ctx.get_tenant();
+                let default_catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+                for sequence in sequences {
+                    let req = DropSequenceReq {
+                        if_exists: true,
+                        ident: SequenceIdent::new(&tenant, &sequence),
+                    };
+                    default_catalog.drop_sequence(req).await?;
+                    SequenceCache::instance().invalidate(&tenant, &sequence);
+                }
+            }
+
             // if `plan.all`, truncate, then purge the historical data
             if self.plan.all {
                 let purge = true;
                 // the above `catalog.drop_table` operation changed the table meta version,
                 // thus if we do not refresh the table instance, `truncate` will fail
                 let latest = tbl.as_ref().refresh(self.ctx.as_ref()).await?;
-                latest.truncate(self.ctx.clone(), purge).await?
+                latest.truncate(self.ctx.clone(), purge).await?;
+                // the data of a clone is purged, the data it shares with its sources can be freed
+                if let Ok(fuse_table) = FuseTable::try_from_table(latest.as_ref()) {
+                    fuse_table.release_clone_refs().await?;
+                }
             }
         }
 
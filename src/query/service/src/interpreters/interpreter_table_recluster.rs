@@ -83,8 +83,7 @@ impl Interpreter for ReclusterTableInterpreter {
 
             pipeline.set_max_threads(settings.get_max_threads()? as usize);
 
-            let query_id = ctx.get_id();
-            let executor_settings = ExecutorSettings::try_create(&settings, query_id)?;
+            let executor_settings = ExecutorSettings::try_create(&ctx)?;
             let executor = PipelineCompleteExecutor::try_create(pipeline, executor_settings)?;
 
             ctx.set_executor(Arc::downgrade(&executor.get_inner()));
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::BlockEntry;
+use common_expression::ComputedExpr;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
 use common_expression::Scalar;
@@ -61,7 +62,8 @@ impl Interpreter for ShowCreateTableInterpreter {
 
         let name = table.name();
         let engine = table.engine();
-        let schema = table.schema();
+        // The virtual generated columns are only in the meta of the table.
+        let schema = table.get_table_info().meta.schema.clone();
         let field_comments = table.field_comments();
         let n_fields = schema.fields().len();
 
@@ -77,6 +79,11 @@ impl Interpreter for ShowCreateTableInterpreter {
                     }
                     None => "".to_string(),
                 };
+                let computed_expr = match field.computed_expr() {
+                    Some(ComputedExpr::Virtual(expr)) => format!(" AS ({expr}) VIRTUAL"),
+                    Some(ComputedExpr::Stored(expr)) => format!(" AS ({expr}) STORED"),
+                    None => "".to_string(),
+                };
                 // compatibility: creating table in the old planner will not have `fields_comments`
                 let comment = if field_comments.len() == n_fields && !field_comments[idx].is_empty()
                 {
@@ -89,10 +96,11 @@ impl Interpreter for ShowCreateTableInterpreter {
                     "".to_string()
                 };
                 let column = format!(
-                    "  `{}` {}{}{}",
+                    "  `{}` {}{}{}{}",
                     field.name(),
                     field.data_type().sql_name(),
                     default_expr,
+                    computed_expr,
                     comment
                 );
 
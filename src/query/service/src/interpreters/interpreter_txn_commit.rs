@@ -0,0 +1,120 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TruncateTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The transaction ends no matter whether the commit succeeds or not.
+        let tables = self.ctx.txn_mgr().lock().finish();
+        if tables.is_empty() {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let catalog = tables[0].catalog.clone();
+        if tables.iter().any(|table| table.catalog != catalog) {
+            return Err(ErrorCode::TransactionError(
+                "transaction across multiple catalogs is not supported, the transaction is aborted",
+            ));
+        }
+
+        let truncated = tables
+            .iter()
+            .filter(|table| table.truncated)
+            .map(|table| table.origin.clone())
+            .collect::<Vec<_>>();
+        let committed = tables
+            .iter()
+            .map(|table| table.table_info())
+            .collect::<Vec<_>>();
+
+        // All the tables are published at once, if and only if none of them has been changed
+        // by others since it was first changed by the transaction.
+        let update_table_metas = tables
+            .into_iter()
+            .map(|table| UpdateTableMetaReq {
+                table_id: table.origin.ident.table_id,
+                seq: MatchSeq::Exact(table.origin.ident.seq),
+                new_table_meta: table.new_table_meta,
+            })
+            .collect();
+        let req = UpdateMultiTableMetaReq { update_table_metas };
+
+        let catalog = self.ctx.get_catalog(&catalog)?;
+        match catalog.update_multi_table_meta(req).await {
+            Ok(_) => {}
+            Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
+                return Err(ErrorCode::TransactionError(format!(
+                    "the transaction is aborted, since the table has been changed concurrently: {}",
+                    e.message()
+                )));
+            }
+            Err(e) => return Err(e),
+        }
+
+        // Try to keep the hint files of the new snapshots, which are written by the commits
+        // outside of a transaction once the snapshots are visible.
+        for table_info in committed {
+            let table = catalog.get_table_by_info(&table_info)?;
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            if let Some(snapshot_location) = fuse_table.snapshot_loc().await? {
+                FuseTable::write_last_snapshot_hint(
+                    fuse_table.get_operator_ref(),
+                    fuse_table.meta_location_generator(),
+                    snapshot_location,
+                )
+                .await;
+            }
+        }
+
+        // Forget the copied files of the tables truncated by the transaction, as `TRUNCATE` does
+        // after the new snapshot is committed.
+        for table_info in truncated {
+            let table_id = table_info.ident.table_id;
+            catalog
+                .truncate_table(&table_info, TruncateTableReq { table_id })
+                .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
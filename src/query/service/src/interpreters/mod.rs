@@ -32,6 +32,8 @@ mod interpreter_delete;
 mod interpreter_explain_v2;
 mod interpreter_factory;
 mod interpreter_insert_v2;
+mod interpreter_inverted_index_create;
+mod interpreter_inverted_index_drop;
 mod interpreter_kill;
 mod interpreter_list;
 mod interpreter_metrics;
@@ -46,6 +48,8 @@ mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_show;
 mod interpreter_select_v2;
+mod interpreter_sequence_create;
+mod interpreter_sequence_drop;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
 mod interpreter_share_create;
@@ -69,6 +73,9 @@ mod interpreter_table_revert;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
+mod interpreter_txn_abort;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
@@ -84,6 +91,8 @@ mod interpreter_user_udf_drop;
 mod interpreter_view_alter;
 mod interpreter_view_create;
 mod interpreter_view_drop;
+mod interpreter_workload_group_create;
+mod interpreter_workload_group_drop;
 
 pub use access::ManagementModeAccess;
 pub use common::append2table;
@@ -102,6 +111,8 @@ pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_explain_v2::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_insert_v2::InsertInterpreterV2;
+pub use interpreter_inverted_index_create::CreateInvertedIndexInterpreter;
+pub use interpreter_inverted_index_drop::DropInvertedIndexInterpreter;
 pub use interpreter_kill::KillInterpreter;
 pub use interpreter_list::ListInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
@@ -114,6 +125,8 @@ pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
 pub use interpreter_select_v2::SelectInterpreterV2;
+pub use interpreter_sequence_create::CreateSequenceInterpreter;
+pub use interpreter_sequence_drop::DropSequenceInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
 pub use interpreter_share_create::CreateShareInterpreter;
@@ -135,6 +148,9 @@ pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
+pub use interpreter_txn_abort::AbortInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
@@ -150,3 +166,5 @@ pub use interpreter_user_udf_drop::DropUserUDFInterpreter;
 pub use interpreter_view_alter::AlterViewInterpreter;
 pub use interpreter_view_create::CreateViewInterpreter;
 pub use interpreter_view_drop::DropViewInterpreter;
+pub use interpreter_workload_group_create::CreateWorkloadGroupInterpreter;
+pub use interpreter_workload_group_drop::DropWorkloadGroupInterpreter;
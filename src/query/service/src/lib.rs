@@ -37,6 +37,8 @@ pub mod interpreters;
 pub mod metrics;
 pub mod pipelines;
 pub mod procedures;
+pub mod query_history;
+pub mod result_cache;
 pub mod schedulers;
 pub mod servers;
 pub mod sessions;
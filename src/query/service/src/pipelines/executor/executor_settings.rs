@@ -15,20 +15,27 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use common_base::runtime::MemStat;
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
-use common_settings::Settings;
+
+use crate::sessions::QueryContext;
 
 pub struct ExecutorSettings {
     pub query_id: Arc<String>,
     pub max_execute_time: Duration,
+    /// The memory of the executor threads is accounted to it, the memory tracker of the
+    /// workload group which admitted the query.
+    pub mem_stat: Option<Arc<MemStat>>,
 }
 
 impl ExecutorSettings {
-    pub fn try_create(settings: &Settings, query_id: String) -> Result<ExecutorSettings> {
-        let max_execute_time = settings.get_max_execute_time()?;
+    pub fn try_create(ctx: &QueryContext) -> Result<ExecutorSettings> {
+        let max_execute_time = ctx.get_settings().get_max_execute_time()?;
         Ok(ExecutorSettings {
-            query_id: Arc::new(query_id),
+            query_id: Arc::new(ctx.get_id()),
             max_execute_time: Duration::from_millis(max_execute_time),
+            mem_stat: ctx.get_workload_queue().map(|queue| queue.get_mem_stat()),
         })
     }
 }
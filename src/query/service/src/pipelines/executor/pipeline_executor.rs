@@ -21,6 +21,7 @@ use common_base::runtime::GlobalIORuntime;
 use common_base::runtime::Runtime;
 use common_base::runtime::Thread;
 use common_base::runtime::ThreadJoinHandle;
+use common_base::runtime::ThreadTracker;
 use common_base::runtime::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -263,7 +264,11 @@ impl PipelineExecutor {
                 }
             }
 
+            let mem_stat = self.settings.mem_stat.clone();
             thread_join_handles.push(Thread::named_spawn(name, move || unsafe {
+                // Memory of the query is accounted to the workload group it is admitted by.
+                let _guard = mem_stat.map(|mem_stat| ThreadTracker::enter(Some(mem_stat)));
+
                 let this_clone = this.clone();
                 let try_result = catch_unwind(move || -> Result<()> {
                     match this_clone.execute_single_thread(thread_num) {
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use async_channel::Receiver;
+use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::table::AppendMode;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -28,6 +29,8 @@ use common_expression::SortColumnDescription;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::aggregates::AggregateFunctionRef;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::PlanProfile;
+use common_pipeline_core::processors::ProcessorProfileWrapper;
 use common_pipeline_core::Pipe;
 use common_pipeline_sinks::processors::sinks::EmptySink;
 use common_pipeline_sinks::processors::sinks::UnionReceiveSink;
@@ -46,6 +49,7 @@ use common_sql::executor::HashJoin;
 use common_sql::executor::Limit;
 use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
+use common_sql::executor::ProjectSet;
 use common_sql::executor::Sort;
 use common_sql::executor::TableScan;
 use common_sql::executor::UnionAll;
@@ -53,6 +57,7 @@ use common_sql::plans::JoinType;
 use common_sql::ColumnBinding;
 use common_sql::IndexType;
 
+use crate::api::TransformSkewAggregate;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::transforms::efficiently_memory_final_aggregator;
 use crate::pipelines::processors::transforms::HashJoinDesc;
@@ -60,8 +65,10 @@ use crate::pipelines::processors::transforms::RightSemiAntiJoinCompactor;
 use crate::pipelines::processors::transforms::TransformLeftJoin;
 use crate::pipelines::processors::transforms::TransformMarkJoin;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
+use crate::pipelines::processors::transforms::TransformProfile;
 use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::transforms::TransformRightSemiAntiJoin;
+use crate::pipelines::processors::transforms::TransformRuntimeFilter;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::AggregatorTransformParams;
 use crate::pipelines::processors::JoinHashTable;
@@ -87,6 +94,8 @@ pub struct PipelineBuilder {
     ctx: Arc<QueryContext>,
     main_pipeline: Pipeline,
     pub pipelines: Vec<Pipeline>,
+    /// The pipes of main pipeline already profiled, only used when the query is profiled.
+    profiled_pipes: usize,
 }
 
 impl PipelineBuilder {
@@ -95,6 +104,7 @@ impl PipelineBuilder {
             ctx,
             pipelines: vec![],
             main_pipeline: Pipeline::create(),
+            profiled_pipes: 0,
         }
     }
 
@@ -121,6 +131,7 @@ impl PipelineBuilder {
             PhysicalPlan::Filter(filter) => self.build_filter(filter),
             PhysicalPlan::Project(project) => self.build_project(project),
             PhysicalPlan::EvalScalar(eval_scalar) => self.build_eval_scalar(eval_scalar),
+            PhysicalPlan::ProjectSet(project_set) => self.build_project_set(project_set),
             PhysicalPlan::AggregatePartial(aggregate) => self.build_aggregate_partial(aggregate),
             PhysicalPlan::AggregateFinal(aggregate) => self.build_aggregate_final(aggregate),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
@@ -135,12 +146,52 @@ impl PipelineBuilder {
             PhysicalPlan::Exchange(_) => Err(ErrorCode::Internal(
                 "Invalid physical plan with PhysicalPlan::Exchange",
             )),
+        }?;
+
+        self.attach_plan_profile(plan)
+    }
+
+    /// Profile the processors built for the plan node if the query is profiled,
+    /// the processors of its children have been profiled before.
+    fn attach_plan_profile(&mut self, plan: &PhysicalPlan) -> Result<()> {
+        let profile = match (self.ctx.get_plan_profiles(), plan.plan_id()) {
+            (Some(profiles), Some(plan_id)) => profiles.get_or_create(plan_id),
+            _ => {
+                self.profiled_pipes = self.main_pipeline.pipes.len();
+                return Ok(());
+            }
+        };
+
+        Self::profile_pipes(&mut self.main_pipeline, self.profiled_pipes, &profile);
+        if self.main_pipeline.is_pulling_pipeline()? {
+            self.main_pipeline.add_transform(|input, output| {
+                Ok(TransformProfile::create(input, output, profile.clone()))
+            })?;
+        }
+        self.profiled_pipes = self.main_pipeline.pipes.len();
+        Ok(())
+    }
+
+    fn profile_pipes(pipeline: &mut Pipeline, from: usize, profile: &Arc<PlanProfile>) {
+        for pipe in pipeline.pipes.iter_mut().skip(from) {
+            match pipe {
+                Pipe::SimplePipe { processors, .. } => {
+                    for processor in processors.iter_mut() {
+                        *processor =
+                            ProcessorProfileWrapper::create(processor.clone(), profile.clone());
+                    }
+                }
+                Pipe::ResizePipe { processor, .. } => {
+                    *processor =
+                        ProcessorProfileWrapper::create(processor.clone(), profile.clone());
+                }
+            }
         }
     }
 
     fn build_join(&mut self, join: &HashJoin) -> Result<()> {
         let state = self.build_join_state(join)?;
-        self.expand_build_side_pipeline(&join.build, state.clone())?;
+        self.expand_build_side_pipeline(join, state.clone())?;
         self.build_join_probe(join, state)
     }
 
@@ -156,12 +207,12 @@ impl PipelineBuilder {
 
     fn expand_build_side_pipeline(
         &mut self,
-        build: &PhysicalPlan,
+        join: &HashJoin,
         join_state: Arc<JoinHashTable>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
         let build_side_builder = PipelineBuilder::create(build_side_context);
-        let mut build_res = build_side_builder.finalize(build)?;
+        let mut build_res = build_side_builder.finalize(&join.build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
         build_res.main_pipeline.add_sink(|input| {
@@ -171,6 +222,13 @@ impl PipelineBuilder {
             ))
         })?;
 
+        // Building the hash table is a part of the join.
+        if let (Some(profiles), Some(info)) = (self.ctx.get_plan_profiles(), &join.stat_info) {
+            let sink_pipe = build_res.main_pipeline.pipes.len() - 1;
+            let profile = profiles.get_or_create(info.plan_id);
+            Self::profile_pipes(&mut build_res.main_pipeline, sink_pipe, &profile);
+        }
+
         self.pipelines.push(build_res.main_pipeline);
         self.pipelines
             .extend(build_res.sources_pipelines.into_iter());
@@ -218,9 +276,27 @@ impl PipelineBuilder {
     fn build_table_scan(&mut self, scan: &TableScan) -> Result<()> {
         let table = self.ctx.build_table_from_source_plan(&scan.source)?;
         self.ctx.try_set_partitions(scan.source.parts.clone())?;
+        if scan.source.parts.kind == PartitionsShuffleKind::ConsistentHash {
+            self.ctx.register_stealable_partitions(scan.table_index);
+        }
         table.read_data(self.ctx.clone(), &scan.source, &mut self.main_pipeline)?;
 
         let schema = scan.source.schema();
+        if let Some(push_downs) = &scan.source.push_downs {
+            if !push_downs.runtime_filters.is_empty() {
+                let ctx = self.ctx.clone();
+                self.main_pipeline.add_transform(|input, output| {
+                    TransformRuntimeFilter::try_create(
+                        ctx.clone(),
+                        input,
+                        output,
+                        schema.clone(),
+                        &push_downs.runtime_filters,
+                    )
+                })?;
+            }
+        }
+
         let projection = scan
             .name_mapping
             .keys()
@@ -322,6 +398,27 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_project_set(&mut self, project_set: &ProjectSet) -> Result<()> {
+        self.build_pipeline(&project_set.input)?;
+
+        let operators = vec![BlockOperator::ProjectSet {
+            srf: project_set.srf.clone(),
+            expr: project_set.arg.as_expr()?,
+        }];
+        let func_ctx = self.ctx.try_get_function_context()?;
+
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(CompoundBlockOperator::create(
+                input,
+                output,
+                func_ctx,
+                operators.clone(),
+            ))
+        })?;
+
+        Ok(())
+    }
+
     fn build_aggregate_partial(&mut self, aggregate: &AggregatePartial) -> Result<()> {
         self.build_pipeline(&aggregate.input)?;
         let params = Self::build_aggregator_params(
@@ -331,6 +428,10 @@ impl PipelineBuilder {
             &aggregate.agg_funcs,
         )?;
 
+        if let Some(skew) = &aggregate.skew_shuffle {
+            TransformSkewAggregate::sample_via(&self.ctx, skew, &params, &mut self.main_pipeline)?;
+        }
+
         self.main_pipeline.add_transform(|input, output| {
             TransformAggregator::try_create_partial(
                 AggregatorTransformParams::try_create(input, output, &params)?,
@@ -338,6 +439,10 @@ impl PipelineBuilder {
             )
         })?;
 
+        if let Some(skew) = &aggregate.skew_shuffle {
+            TransformSkewAggregate::merge_via(&self.ctx, skew, &params, &mut self.main_pipeline)?;
+        }
+
         Ok(())
     }
 
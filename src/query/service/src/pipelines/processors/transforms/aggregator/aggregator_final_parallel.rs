@@ -22,6 +22,8 @@ use common_base::runtime::ThreadPool;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::Column;
 use common_expression::ColumnBuilder;
 use common_expression::DataBlock;
 use common_expression::HashMethod;
@@ -35,6 +37,7 @@ use tracing::info;
 use crate::pipelines::processors::transforms::aggregator::aggregate_info::AggregateInfo;
 use crate::pipelines::processors::transforms::group_by::Area;
 use crate::pipelines::processors::transforms::group_by::GroupColumnsBuilder;
+use crate::pipelines::processors::transforms::group_by::KeysColumnBuilder;
 use crate::pipelines::processors::transforms::group_by::KeysColumnIter;
 use crate::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
 use crate::pipelines::processors::transforms::transform_aggregator::Aggregator;
@@ -171,60 +174,7 @@ where Method: HashMethod + PolymorphicKeysHelper<Method> + Send + 'static
         if blocks.is_empty() {
             return Ok(vec![]);
         }
-        for data_block in blocks {
-            let block = data_block.convert_to_full();
-            // 1.1 and 1.2.
-            let aggregate_function_len = self.params.aggregate_functions.len();
-            let keys_column = block
-                .get_by_offset(aggregate_function_len)
-                .value
-                .as_column()
-                .unwrap();
-            let keys_iter = self.method.keys_iter_from_column(keys_column)?;
-
-            if !HAS_AGG {
-                unsafe {
-                    for key in keys_iter.iter() {
-                        let _ = self.hash_table.insert_and_entry(key);
-                    }
-                }
-            } else {
-                // first state places of current block
-                let places = self.lookup_state(&keys_iter);
-
-                let states_columns = (0..aggregate_function_len)
-                    .map(|i| block.get_by_offset(i))
-                    .collect::<Vec<_>>();
-                let mut states_binary_columns = Vec::with_capacity(states_columns.len());
-
-                for agg in states_columns.iter().take(aggregate_function_len) {
-                    let aggr_column =
-                        agg.value.as_column().unwrap().as_string().ok_or_else(|| {
-                            ErrorCode::IllegalDataType(format!(
-                                "Aggregation column should be StringType, but got {:?}",
-                                agg.value
-                            ))
-                        })?;
-                    states_binary_columns.push(aggr_column);
-                }
-
-                let aggregate_functions = &self.params.aggregate_functions;
-                let offsets_aggregate_states = &self.params.offsets_aggregate_states;
-                if let Some(temp_place) = self.temp_place {
-                    for (row, place) in places.iter().enumerate() {
-                        for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
-                            let final_place = place.next(offsets_aggregate_states[idx]);
-                            let state_place = temp_place.next(offsets_aggregate_states[idx]);
-
-                            let mut data =
-                                unsafe { states_binary_columns[idx].index_unchecked(row) };
-                            aggregate_function.deserialize(state_place, &mut data)?;
-                            aggregate_function.merge(final_place, state_place)?;
-                        }
-                    }
-                }
-            }
-        }
+        self.merge_states(blocks)?;
 
         let mut estimated_key_size = self.hash_table.bytes_len();
         let value_size = std::mem::size_of::<u64>() * self.hash_table.len();
@@ -290,6 +240,103 @@ where Method: HashMethod + PolymorphicKeysHelper<Method> + Send + 'static
         }
     }
 
+    /// Merge the partial states of the blocks, and output the merged states in the
+    /// format of the partial states.
+    pub fn merge_blocks_into_states(&mut self, blocks: Vec<DataBlock>) -> Result<Vec<DataBlock>> {
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        self.merge_states(blocks)?;
+
+        let state_groups_len = self.hash_table.len();
+        let aggregate_functions = &self.params.aggregate_functions;
+        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+
+        let mut state_builders = (0..aggregate_functions.len())
+            .map(|_| StringColumnBuilder::with_capacity(state_groups_len, state_groups_len * 4))
+            .collect::<Vec<_>>();
+
+        let mut group_key_builder = self.method.keys_column_builder(state_groups_len);
+        for group_entity in self.hash_table.iter() {
+            if HAS_AGG {
+                let place = Into::<StateAddr>::into(*group_entity.get());
+                for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
+                    let arg_place = place.next(offsets_aggregate_states[idx]);
+                    aggregate_function.serialize(arg_place, &mut state_builders[idx].data)?;
+                    state_builders[idx].commit_row();
+                }
+            }
+
+            group_key_builder.append_value(group_entity.key());
+        }
+
+        let mut columns = state_builders
+            .into_iter()
+            .map(|builder| Column::String(builder.build()))
+            .collect::<Vec<_>>();
+        columns.push(group_key_builder.finish());
+        Ok(vec![DataBlock::new_from_columns(columns)])
+    }
+
+    fn merge_states(&mut self, blocks: Vec<DataBlock>) -> Result<()> {
+        for data_block in blocks {
+            let block = data_block.convert_to_full();
+            // 1.1 and 1.2.
+            let aggregate_function_len = self.params.aggregate_functions.len();
+            let keys_column = block
+                .get_by_offset(aggregate_function_len)
+                .value
+                .as_column()
+                .unwrap();
+            let keys_iter = self.method.keys_iter_from_column(keys_column)?;
+
+            if !HAS_AGG {
+                unsafe {
+                    for key in keys_iter.iter() {
+                        let _ = self.hash_table.insert_and_entry(key);
+                    }
+                }
+            } else {
+                // first state places of current block
+                let places = self.lookup_state(&keys_iter);
+
+                let states_columns = (0..aggregate_function_len)
+                    .map(|i| block.get_by_offset(i))
+                    .collect::<Vec<_>>();
+                let mut states_binary_columns = Vec::with_capacity(states_columns.len());
+
+                for agg in states_columns.iter().take(aggregate_function_len) {
+                    let aggr_column =
+                        agg.value.as_column().unwrap().as_string().ok_or_else(|| {
+                            ErrorCode::IllegalDataType(format!(
+                                "Aggregation column should be StringType, but got {:?}",
+                                agg.value
+                            ))
+                        })?;
+                    states_binary_columns.push(aggr_column);
+                }
+
+                let aggregate_functions = &self.params.aggregate_functions;
+                let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+                if let Some(temp_place) = self.temp_place {
+                    for (row, place) in places.iter().enumerate() {
+                        for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
+                            let final_place = place.next(offsets_aggregate_states[idx]);
+                            let state_place = temp_place.next(offsets_aggregate_states[idx]);
+
+                            let mut data =
+                                unsafe { states_binary_columns[idx].index_unchecked(row) };
+                            aggregate_function.deserialize(state_place, &mut data)?;
+                            aggregate_function.merge(final_place, state_place)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Allocate aggregation function state for each key(the same key can always get the same state)
     #[inline(always)]
     fn lookup_state(&mut self, keys_iter: &Method::KeysColumnIter) -> StateAddrs {
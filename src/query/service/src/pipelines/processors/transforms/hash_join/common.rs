@@ -14,6 +14,7 @@
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::plan::RuntimeFilter;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -22,6 +23,9 @@ use common_expression::arrow::or_validities;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::AnyType;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
 use common_expression::BlockEntry;
 use common_expression::Column;
 use common_expression::DataBlock;
@@ -31,8 +35,10 @@ use common_expression::Scalar;
 use common_expression::Value;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use common_hashtable::HashtableLike;
+use storages_common_index::BlockFilter;
 
 use crate::pipelines::processors::transforms::hash_join::desc::MarkerKind;
+use crate::pipelines::processors::transforms::hash_join::row::Chunk;
 use crate::pipelines::processors::transforms::hash_join::row::RowPtr;
 use crate::pipelines::processors::JoinHashTable;
 use crate::sql::plans::JoinType;
@@ -388,4 +394,46 @@ impl JoinHashTable {
 
         self.row_space.push_cols(data_block, build_cols)
     }
+
+    /// Build the runtime filters from the build keys of all chunks, and publish
+    /// them to the query context so that they can be applied by the probe-side scans.
+    pub(crate) fn publish_runtime_filters(&self, chunks: &[Chunk]) -> Result<()> {
+        if self.hash_join_desc.runtime_filters.is_empty() {
+            return Ok(());
+        }
+
+        let func_ctx = self.ctx.try_get_function_context()?;
+        let hash_type = DataType::Number(NumberDataType::UInt64);
+        for source in self.hash_join_desc.runtime_filters.iter() {
+            let mut keys = vec![];
+            let mut hashes = vec![];
+            for chunk in chunks {
+                let (column, validity) = match &chunk.cols[source.build_key].0 {
+                    Column::Nullable(nullable) => {
+                        (nullable.column.clone(), Some(nullable.validity.clone()))
+                    }
+                    column => (column.clone(), None),
+                };
+                let digests = BlockFilter::calculate_column_digest(
+                    func_ctx,
+                    &column,
+                    &source.desc.data_type,
+                    &hash_type,
+                )?;
+                let digests = UInt64Type::try_downcast_column(&digests).unwrap();
+
+                for (row, digest) in digests.iter().enumerate() {
+                    if validity.as_ref().map_or(true, |v| v.get_bit(row)) {
+                        keys.push(column.index(row).unwrap().to_owned());
+                        hashes.push(*digest);
+                    }
+                }
+            }
+
+            self.ctx
+                .publish_runtime_filter(&source.desc, RuntimeFilter::create(keys, &hashes));
+        }
+
+        Ok(())
+    }
 }
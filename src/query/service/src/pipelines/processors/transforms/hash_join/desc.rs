@@ -22,6 +22,7 @@ use common_expression::RawExpr;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use common_sql::executor::HashJoin;
 use common_sql::executor::PhysicalScalar;
+use common_sql::executor::RuntimeFilterSource;
 use parking_lot::RwLock;
 
 use crate::pipelines::processors::transforms::hash_join::row::RowPtr;
@@ -70,6 +71,8 @@ pub struct HashJoinDesc {
     /// Whether the Join are derived from correlated subquery.
     pub(crate) from_correlated_subquery: bool,
     pub(crate) join_state: JoinState,
+    /// Runtime filters to be built from the build keys.
+    pub(crate) runtime_filters: Vec<RuntimeFilterSource>,
 }
 
 impl HashJoinDesc {
@@ -90,6 +93,7 @@ impl HashJoinDesc {
             },
             from_correlated_subquery: join.from_correlated_subquery,
             join_state: JoinState::create()?,
+            runtime_filters: join.runtime_filters.clone(),
         })
     }
 
@@ -254,6 +254,7 @@ impl HashJoinState for JoinHashTable {
                 },
             }
         }
+        self.publish_runtime_filters(&chunks)?;
         Ok(())
     }
 
@@ -27,12 +27,16 @@ mod transform_mark_join;
 mod transform_add_const_columns;
 mod transform_convert_grouping;
 mod transform_merge_block;
+mod transform_profile;
 mod transform_resort_addon;
 mod transform_right_join;
 mod transform_right_semi_anti_join;
+mod transform_runtime_filter;
 
+pub use aggregator::AggregateInfo;
 pub use aggregator::AggregatorParams;
 pub use aggregator::AggregatorTransformParams;
+pub use aggregator::BucketAggregator;
 use common_pipeline_transforms::processors::transforms::transform;
 use common_pipeline_transforms::processors::transforms::transform_block_compact;
 use common_pipeline_transforms::processors::transforms::transform_compact;
@@ -64,11 +68,13 @@ pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_profile::TransformProfile;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_right_join::RightJoinCompactor;
 pub use transform_right_join::TransformRightJoin;
 pub use transform_right_semi_anti_join::RightSemiAntiJoinCompactor;
 pub use transform_right_semi_anti_join::TransformRightSemiAntiJoin;
+pub use transform_runtime_filter::TransformRuntimeFilter;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_merge::TransformSortMerge;
 pub use transform_sort_partial::TransformSortPartial;
@@ -19,9 +19,11 @@ use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
 use common_expression::Expr;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
+use common_sql::parse_computed_expr;
 use common_sql::parse_exprs;
 use common_storages_factory::Table;
 
@@ -34,7 +36,6 @@ use crate::sessions::QueryContext;
 
 pub struct TransformResortAddOn {
     expression_transform: CompoundBlockOperator,
-    input_len: usize,
 }
 
 impl TransformResortAddOn
@@ -47,15 +48,22 @@ where Self: Transform
         input_schema: DataSchemaRef,
         table: Arc<dyn Table>,
     ) -> Result<ProcessorPtr> {
-        let fields = table
-            .schema()
+        let schema = table.schema();
+        let fields = schema
             .fields()
             .iter()
             .map(DataField::from)
             .collect::<Vec<_>>();
 
-        let mut ops = Vec::with_capacity(fields.len());
-        for f in fields.iter() {
+        // The offsets of the table columns in the block after the operators.
+        let mut offsets = vec![0; fields.len()];
+        let mut next_offset = input_schema.num_fields();
+        let mut ops = Vec::with_capacity(fields.len() + 1);
+        for (idx, f) in fields.iter().enumerate() {
+            // Stored generated columns are computed after all the other columns are filled.
+            if schema.field(idx).computed_expr().is_some() {
+                continue;
+            }
             let expr = if !input_schema.has_field(f.name()) {
                 if let Some(default_expr) = f.default_expr() {
                     let mut expr = parse_exprs(ctx.clone(), table.clone(), false, default_expr)?;
@@ -78,8 +86,35 @@ where Self: Transform
                 }
             };
             ops.push(BlockOperator::Map { expr });
+            offsets[idx] = next_offset;
+            next_offset += 1;
         }
 
+        let data_schema = DataSchemaRefExt::create(fields.clone());
+        for (idx, f) in fields.iter().enumerate() {
+            if let Some(computed_expr) = schema.field(idx).computed_expr() {
+                let expr =
+                    parse_computed_expr(ctx.clone(), data_schema.clone(), computed_expr.expr())?
+                        .project_column_ref(|id| offsets[*id]);
+                let expr = if expr.data_type() != f.data_type() {
+                    Expr::Cast {
+                        span: None,
+                        is_try: false,
+                        expr: Box::new(expr),
+                        dest_type: f.data_type().clone(),
+                    }
+                } else {
+                    expr
+                };
+                ops.push(BlockOperator::Map { expr });
+                offsets[idx] = next_offset;
+                next_offset += 1;
+            }
+        }
+        ops.push(BlockOperator::Project {
+            projection: offsets,
+        });
+
         let func_ctx = ctx.try_get_function_context()?;
         let expression_transform = CompoundBlockOperator {
             ctx: func_ctx,
@@ -88,7 +123,6 @@ where Self: Transform
 
         Ok(Transformer::create(input, output, Self {
             expression_transform,
-            input_len: input_schema.num_fields(),
         }))
     }
 }
@@ -96,9 +130,7 @@ where Self: Transform
 impl Transform for TransformResortAddOn {
     const NAME: &'static str = "AddOnTransform";
 
-    fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
-        block = self.expression_transform.transform(block)?;
-        let columns = block.columns()[self.input_len..].to_owned();
-        Ok(DataBlock::new(columns, block.num_rows()))
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        self.expression_transform.transform(block)
     }
 }
@@ -0,0 +1,159 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::plan::RuntimeFilter;
+use common_catalog::plan::RuntimeFilterDesc;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::FunctionContext;
+use common_expression::TableSchemaRef;
+use storages_common_index::BlockFilter;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::transforms::transform::Transform;
+use crate::pipelines::processors::transforms::transform::Transformer;
+use crate::sessions::QueryContext;
+
+/// Filter the rows of table scan with the runtime filters generated by hash joins.
+/// The filters not built yet are skipped, so that the scan is never blocked.
+pub struct TransformRuntimeFilter {
+    ctx: Arc<QueryContext>,
+    func_ctx: FunctionContext,
+    /// The offsets of filtered columns in the block and the filters.
+    filters: Vec<(usize, RuntimeFilterDesc)>,
+}
+
+impl TransformRuntimeFilter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: TableSchemaRef,
+        runtime_filters: &[RuntimeFilterDesc],
+    ) -> Result<ProcessorPtr> {
+        let filters = runtime_filters
+            .iter()
+            .map(|desc| Ok((schema.index_of(&desc.column_name)?, desc.clone())))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Transformer::create(input, output, Self {
+            func_ctx: ctx.try_get_function_context()?,
+            ctx,
+            filters,
+        }))
+    }
+
+    fn apply(
+        &self,
+        filter: &RuntimeFilter,
+        data_type: &DataType,
+        column: &Column,
+        bitmap: &mut MutableBitmap,
+    ) -> Result<()> {
+        let (min, max) = match &filter.min_max {
+            Some(min_max) => min_max,
+            None => {
+                // No keys on the build side, nothing can be matched.
+                *bitmap = MutableBitmap::from_len_zeroed(column.len());
+                return Ok(());
+            }
+        };
+
+        let (column, validity) = match column {
+            Column::Nullable(nullable) => (&nullable.column, Some(&nullable.validity)),
+            column => (column, None),
+        };
+        let digests = match filter.in_list {
+            Some(_) => None,
+            None => {
+                let hash_type = DataType::Number(NumberDataType::UInt64);
+                let digests = BlockFilter::calculate_column_digest(
+                    self.func_ctx,
+                    column,
+                    data_type,
+                    &hash_type,
+                )?;
+                Some(UInt64Type::try_downcast_column(&digests).unwrap())
+            }
+        };
+
+        for row in 0..column.len() {
+            if !bitmap.get(row) {
+                continue;
+            }
+            if validity.map_or(false, |v| !v.get_bit(row)) {
+                bitmap.set(row, false);
+                continue;
+            }
+
+            let value = column.index(row).unwrap();
+            let keep = value >= min.as_ref()
+                && value <= max.as_ref()
+                && match (&filter.in_list, &digests) {
+                    (Some(in_list), _) => in_list
+                        .binary_search_by(|key| key.as_ref().partial_cmp(&value).unwrap())
+                        .is_ok(),
+                    (None, Some(digests)) => filter.bloom.contains(digests[row]),
+                    (None, None) => unreachable!(),
+                };
+            if !keep {
+                bitmap.set(row, false);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Transform for TransformRuntimeFilter {
+    const NAME: &'static str = "RuntimeFilterTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let mut bitmap: Option<MutableBitmap> = None;
+        for (offset, desc) in self.filters.iter() {
+            let filter = match self.ctx.get_runtime_filter(desc.id) {
+                Some(filter) => filter,
+                None => continue,
+            };
+
+            let entry = block.get_by_offset(*offset);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            let bitmap = bitmap.get_or_insert_with(|| {
+                let mut bitmap = MutableBitmap::with_capacity(num_rows);
+                bitmap.extend_constant(num_rows, true);
+                bitmap
+            });
+            self.apply(&filter, &desc.data_type, &column, bitmap)?;
+        }
+
+        match bitmap {
+            Some(bitmap) => DataBlock::filter_with_bitmap(block, &bitmap.into()),
+            None => Ok(block),
+        }
+    }
+}
@@ -71,6 +71,7 @@ impl OneBlockProcedure for SearchTablesProcedure {
                 *s_expr,
                 metadata,
                 false,
+                None,
             )?;
             interpreter.execute(ctx.clone()).await
         } else {
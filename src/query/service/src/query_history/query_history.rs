@@ -0,0 +1,449 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use chrono::NaiveDateTime;
+use common_base::base::escape_for_key;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::tokio::time::timeout;
+use common_base::base::GlobalInstance;
+use common_base::runtime::Runtime;
+use common_base::runtime::TrySpawn;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_config::Config;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_meta_api::KVApi;
+use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_meta_types::GrantObject;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVReq;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+use common_storages_system::QueryLogElement;
+use common_storages_system::SystemLogElement;
+use common_users::UserApiProvider;
+use parking_lot::Mutex;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use tracing::warn;
+
+use crate::databases::Database;
+use crate::interpreters::append2table;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sql::Planner;
+
+/// The reserved database of the tables persisting the history of the cluster.
+pub const QUERY_HISTORY_DATABASE: &str = "system_history";
+pub const QUERY_HISTORY_TABLE: &str = "query_history";
+
+/// The interval to remove the history beyond the retention.
+const QUERY_HISTORY_PURGE_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// The key of the lease held by the node purging the history, it expires after the purge
+/// interval, thus the history is purged by a single node of the tenant in each interval.
+const QUERY_HISTORY_PURGE_LEASE_PREFIX: &str = "__fd_query_history_purge";
+
+/// The buffered rows are dropped rather than keeping growing if the history can not be flushed.
+const QUERY_HISTORY_MAX_BUFFERED_BATCHES: usize = 10;
+
+/// A row of `system_history.query_history`: a `system.query_log` row with the node which ran it
+/// and the runtime profile of the query, if it is profiled.
+#[derive(Clone)]
+pub struct QueryHistoryElement {
+    pub log: QueryLogElement,
+    pub node_id: String,
+    /// The runtime statistics of each plan node in json, empty if the query is not profiled.
+    pub profiles: String,
+}
+
+pub fn query_history_schema() -> TableSchemaRef {
+    let mut fields = QueryLogElement::schema().fields().clone();
+    fields.push(TableField::new("node_id", TableDataType::String));
+    fields.push(TableField::new("profiles", TableDataType::String));
+    TableSchemaRefExt::create(fields)
+}
+
+/// Persists the query log of this node into the fuse table `system_history.query_history`.
+///
+/// The rows are buffered in memory and flushed in batches by a background task, when the batch
+/// is full or the flush interval elapses. All the nodes of a tenant flush into the same table,
+/// thus it contains the queries of the whole cluster and survives restarts. The history beyond
+/// the retention is purged by a single node of the tenant, see `purge()`.
+/// The history is best-effort: rows that can not be flushed for a long time are dropped.
+pub struct QueryHistory {
+    enabled: bool,
+    batch_size: usize,
+    buffer: Mutex<Vec<QueryHistoryElement>>,
+    notify: Arc<Notify>,
+    /// The history database and table are created, they are created again only if not found.
+    table_created: AtomicBool,
+    /// Runs the flush task, which stops when the query history is dropped.
+    runtime: Option<Runtime>,
+}
+
+impl QueryHistory {
+    pub fn init(config: &Config) -> Result<()> {
+        let enabled = config.query.query_history_enabled;
+        let runtime = if enabled {
+            Some(Runtime::with_worker_threads(
+                1,
+                Some("query-history".to_string()),
+            )?)
+        } else {
+            None
+        };
+
+        let history = Arc::new(QueryHistory {
+            enabled,
+            batch_size: config.query.query_history_flush_batch_size.max(1) as usize,
+            buffer: Mutex::new(vec![]),
+            notify: Arc::new(Notify::new()),
+            table_created: AtomicBool::new(false),
+            runtime,
+        });
+
+        if let Some(runtime) = &history.runtime {
+            runtime.spawn(flush_loop(
+                Arc::downgrade(&history),
+                history.notify.clone(),
+                Duration::from_secs(config.query.query_history_flush_interval_secs.max(1)),
+                config.query.query_history_retention_days,
+            ));
+        }
+
+        GlobalInstance::set(history);
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<QueryHistory> {
+        GlobalInstance::get()
+    }
+
+    /// Buffers a row of the query log, it is a no-op if the query history is not enabled.
+    pub fn append(&self, ctx: &QueryContext, log: &QueryLogElement) {
+        if !self.enabled {
+            return;
+        }
+
+        let profiles = match ctx.get_plan_profiles() {
+            None => "".to_string(),
+            Some(profiles) => serde_json::to_string(&profiles.statistics()).unwrap_or_default(),
+        };
+        let element = QueryHistoryElement {
+            log: log.clone(),
+            node_id: ctx.get_cluster().local_id.clone(),
+            profiles,
+        };
+
+        let mut buffer = self.buffer.lock();
+        buffer.push(element);
+        if buffer.len() >= self.batch_size {
+            self.notify.notify_one();
+        }
+    }
+
+    /// Flushes the buffered rows, they are put back to retry later if the flush fails.
+    async fn flush_buffer(&self) {
+        let mut elements = std::mem::take(&mut *self.buffer.lock());
+        if elements.is_empty() {
+            return;
+        }
+
+        if let Err(cause) = self.flush(&elements).await {
+            warn!(
+                "failed to flush {} query history: {:?}",
+                elements.len(),
+                cause
+            );
+
+            let mut buffer = self.buffer.lock();
+            if buffer.len() + elements.len() <= self.batch_size * QUERY_HISTORY_MAX_BUFFERED_BATCHES
+            {
+                elements.append(&mut buffer);
+                *buffer = elements;
+            }
+        }
+    }
+
+    async fn flush(&self, elements: &[QueryHistoryElement]) -> Result<()> {
+        let ctx = create_history_context().await?;
+        let table = self.get_history_table(&ctx).await?;
+
+        let schema = query_history_schema();
+        let mut columns: Vec<ColumnBuilder> = schema
+            .fields()
+            .iter()
+            .map(|f| ColumnBuilder::with_capacity(&DataType::from(f.data_type()), elements.len()))
+            .collect();
+        for element in elements {
+            element.log.fill_to_data_block(&mut columns)?;
+
+            let n = columns.len();
+            columns[n - 2].push(Scalar::String(element.node_id.as_bytes().to_vec()).as_ref());
+            columns[n - 1].push(Scalar::String(element.profiles.as_bytes().to_vec()).as_ref());
+        }
+        let block = DataBlock::new_from_columns(columns.into_iter().map(|c| c.build()).collect());
+
+        let mut build_res = PipelineBuildResult::from_blocks(vec![block])?;
+        append2table(
+            ctx.clone(),
+            table,
+            Arc::new(DataSchema::from(schema)),
+            &mut build_res,
+            false,
+            true,
+            AppendMode::Normal,
+        )?;
+
+        let settings = ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&ctx)?;
+        let executor =
+            PipelineCompleteExecutor::try_create(build_res.main_pipeline, executor_settings)?;
+        ctx.set_executor(Arc::downgrade(&executor.get_inner()));
+        executor.execute()
+    }
+
+    /// Gets the history table, it is only created by the first call, or if it is not found.
+    async fn get_history_table(&self, ctx: &Arc<QueryContext>) -> Result<Arc<dyn Table>> {
+        if self.table_created.load(Ordering::Acquire) {
+            let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+            match catalog
+                .get_table(
+                    &ctx.get_tenant(),
+                    QUERY_HISTORY_DATABASE,
+                    QUERY_HISTORY_TABLE,
+                )
+                .await
+            {
+                Ok(table) => return Ok(table),
+                Err(e)
+                    if e.code() == ErrorCode::UNKNOWN_DATABASE
+                        || e.code() == ErrorCode::UNKNOWN_TABLE => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        let table = create_history_table(ctx).await?;
+        self.table_created.store(true, Ordering::Release);
+        Ok(table)
+    }
+
+    /// Takes the purge lease of the tenant, returns false if another node holds it.
+    async fn try_acquire_purge_lease(&self, ctx: &Arc<QueryContext>) -> Result<bool> {
+        let key = format!(
+            "{}/{}",
+            QUERY_HISTORY_PURGE_LEASE_PREFIX,
+            escape_for_key(&ctx.get_tenant())?
+        );
+        let expire_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            + QUERY_HISTORY_PURGE_INTERVAL;
+        let node_id = ctx.get_cluster().local_id.clone();
+
+        let meta_store = UserApiProvider::instance().get_meta_store_client();
+        let reply = meta_store
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(0),
+                Operation::Update(node_id.into_bytes()),
+                Some(KVMeta {
+                    expire_at: Some(expire_at.as_secs()),
+                }),
+            ))
+            .await?;
+        Ok(reply.prev.is_none())
+    }
+
+    /// Removes the history older than the retention days, and compacts the table.
+    ///
+    /// It is done by the node holding the purge lease only, the other nodes skip it.
+    async fn purge(&self, retention_days: u64) -> Result<()> {
+        let ctx = create_history_context().await?;
+        if !self.try_acquire_purge_lease(&ctx).await? {
+            return Ok(());
+        }
+        self.get_history_table(&ctx).await?;
+
+        let now_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let expire_secs = now_secs.saturating_sub(retention_days * 24 * 3600);
+        let expire_date = NaiveDateTime::from_timestamp(expire_secs as i64, 0).format("%Y-%m-%d");
+
+        run_history_sql(
+            ctx,
+            &format!(
+                "DELETE FROM {}.{} WHERE event_date < to_date('{}')",
+                QUERY_HISTORY_DATABASE, QUERY_HISTORY_TABLE, expire_date
+            ),
+        )
+        .await?;
+
+        // Merge the small blocks of the flushes, and remove the snapshots and the blocks
+        // no longer referenced. A new context reads the table deleted above.
+        run_history_sql(
+            create_history_context().await?,
+            &format!(
+                "OPTIMIZE TABLE {}.{} ALL",
+                QUERY_HISTORY_DATABASE, QUERY_HISTORY_TABLE
+            ),
+        )
+        .await
+    }
+}
+
+/// Runs a statement on the history table, without logging it as a query.
+async fn run_history_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<()> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner.plan_sql(sql).await?;
+
+    // `execute2()` runs the statement without logging it as a query.
+    let interpreter = InterpreterFactory::get(ctx, &plan).await?;
+    interpreter.execute2().await?;
+    Ok(())
+}
+
+/// Creates a context with all the privileges to write the history, it's the only context
+/// allowed to change the history database, see `InterpreterFactory::check_reserved_database`.
+async fn create_history_context() -> Result<Arc<QueryContext>> {
+    let session = SessionManager::instance()
+        .create_session(SessionType::QueryHistory)
+        .await?;
+
+    let mut user_info = UserInfo::new_no_auth("root", "127.0.0.1");
+    user_info.grants.grant_privileges(
+        &GrantObject::Global,
+        UserPrivilegeSet::available_privileges_on_global(),
+    );
+    session.set_authed_user(user_info, None).await?;
+
+    session.create_query_context().await
+}
+
+async fn create_history_table(ctx: &Arc<QueryContext>) -> Result<Arc<dyn Table>> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+
+    catalog
+        .create_database(CreateDatabaseReq {
+            if_not_exists: true,
+            name_ident: DatabaseNameIdent {
+                tenant: tenant.clone(),
+                db_name: QUERY_HISTORY_DATABASE.to_string(),
+            },
+            meta: DatabaseMeta {
+                engine: "".to_string(),
+                ..Default::default()
+            },
+        })
+        .await?;
+    let db = catalog
+        .get_database(&tenant, QUERY_HISTORY_DATABASE)
+        .await?;
+
+    let mut options = BTreeMap::new();
+    options.insert(
+        OPT_KEY_DATABASE_ID.to_owned(),
+        db.get_db_info().ident.db_id.to_string(),
+    );
+    catalog
+        .create_table(CreateTableReq {
+            if_not_exists: true,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: QUERY_HISTORY_DATABASE.to_string(),
+                table_name: QUERY_HISTORY_TABLE.to_string(),
+            },
+            table_meta: TableMeta {
+                schema: query_history_schema(),
+                engine: "FUSE".to_string(),
+                options,
+                ..Default::default()
+            },
+        })
+        .await?;
+
+    catalog
+        .get_table(&tenant, QUERY_HISTORY_DATABASE, QUERY_HISTORY_TABLE)
+        .await
+}
+
+async fn flush_loop(
+    history: Weak<QueryHistory>,
+    notify: Arc<Notify>,
+    interval: Duration,
+    retention_days: u64,
+) {
+    let mut last_purged: Option<Instant> = None;
+
+    loop {
+        let _ = timeout(interval, notify.notified()).await;
+
+        let history = match history.upgrade() {
+            Some(history) => history,
+            None => return,
+        };
+
+        history.flush_buffer().await;
+
+        let purge_due = match last_purged {
+            None => true,
+            Some(t) => t.elapsed() >= QUERY_HISTORY_PURGE_INTERVAL,
+        };
+        if retention_days > 0 && purge_due {
+            last_purged = Some(Instant::now());
+            if let Err(cause) = history.purge(retention_days).await {
+                warn!("failed to purge query history: {:?}", cause);
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod result_cache;
+mod result_cache_key;
+mod result_cache_storage;
+mod transform_result_cache_writer;
+
+pub use result_cache::CachedResult;
+pub use result_cache::ResultCache;
+pub use result_cache_key::build_result_cache_key;
+pub use result_cache_storage::ResultCacheStorage;
+pub use transform_result_cache_writer::ResultCacheWriteInfo;
+pub use transform_result_cache_writer::TransformResultCacheWriter;
@@ -0,0 +1,164 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::GlobalInstance;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use parking_lot::Mutex;
+
+/// The result of a query kept by the result cache.
+pub struct CachedResult {
+    /// The user who ran the query, only the same user can read the result by `RESULT_SCAN`.
+    pub user: String,
+    pub schema: DataSchemaRef,
+    pub block: DataBlock,
+    pub expire_at: Instant,
+}
+
+impl CachedResult {
+    pub fn create(user: String, schema: DataSchemaRef, block: DataBlock, ttl: Duration) -> Self {
+        CachedResult {
+            user,
+            schema,
+            block,
+            expire_at: Instant::now() + ttl,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expire_at
+    }
+}
+
+#[derive(Default)]
+struct ResultCacheState {
+    /// Result key to the id of the query which produced the result.
+    keys: HashMap<String, String>,
+    /// Query id to the result of the query.
+    results: HashMap<String, Arc<CachedResult>>,
+    /// Query ids in the order of being cached, used to evict the oldest results first.
+    order: VecDeque<String>,
+    bytes: usize,
+}
+
+impl ResultCacheState {
+    fn remove(&mut self, query_id: &str) {
+        if let Some(result) = self.results.remove(query_id) {
+            self.bytes -= result.block.memory_size();
+        }
+        self.keys.retain(|_, id| id != query_id);
+    }
+}
+
+/// Node local cache of query results, kept in memory.
+///
+/// A result is cached under the id of the query, so it can be read again by `RESULT_SCAN`,
+/// and under the result key of the query if it has one, so the same query can be answered
+/// without being executed again. The key contains the snapshot locations of the tables
+/// read by the query, thus a new snapshot of any of the tables makes the cached result unreachable.
+///
+/// The results are written to the object storage too by [`ResultCacheStorage`], which
+/// is read on a miss, so they are shared by the nodes of a cluster and kept across restarts.
+///
+/// [`ResultCacheStorage`]: crate::result_cache::ResultCacheStorage
+pub struct ResultCache {
+    state: Mutex<ResultCacheState>,
+}
+
+impl ResultCache {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(ResultCache {
+            state: Mutex::new(ResultCacheState::default()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<ResultCache> {
+        GlobalInstance::get()
+    }
+
+    /// Gets the unexpired result cached under the result key, with the id of the query
+    /// which produced it.
+    pub fn get(&self, key: &str) -> Option<(String, Arc<CachedResult>)> {
+        let state = self.state.lock();
+        let query_id = state.keys.get(key)?;
+        state
+            .results
+            .get(query_id)
+            .filter(|result| !result.is_expired())
+            .map(|result| (query_id.clone(), result.clone()))
+    }
+
+    /// Gets the unexpired result of the query.
+    pub fn get_by_query_id(&self, query_id: &str) -> Option<Arc<CachedResult>> {
+        let state = self.state.lock();
+        state
+            .results
+            .get(query_id)
+            .filter(|result| !result.is_expired())
+            .cloned()
+    }
+
+    /// Caches the result of the query, and under the result key if it's not None.
+    ///
+    /// The oldest results are evicted until all of them fit in `capacity` bytes.
+    pub fn put(
+        &self,
+        query_id: &str,
+        key: Option<String>,
+        result: Arc<CachedResult>,
+        capacity: usize,
+    ) {
+        let size = result.block.memory_size();
+        if size > capacity {
+            return;
+        }
+
+        let mut state = self.state.lock();
+        state.remove(query_id);
+
+        // Evict the expired results, then the oldest ones until the new result fits.
+        let expired: Vec<String> = state
+            .results
+            .iter()
+            .filter(|(_, result)| result.is_expired())
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in expired {
+            state.remove(&id);
+        }
+        while state.bytes + size > capacity {
+            match state.order.pop_front() {
+                Some(id) => state.remove(&id),
+                None => break,
+            }
+        }
+        let ResultCacheState { results, order, .. } = &mut *state;
+        order.retain(|id| results.contains_key(id));
+
+        state.bytes += size;
+        state.results.insert(query_id.to_string(), result);
+        state.order.push_back(query_id.to_string());
+        if let Some(key) = key {
+            state.keys.insert(key, query_id.to_string());
+        }
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use common_exception::Result;
+use common_sql::MetadataRef;
+use common_storages_fuse::FuseTable;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Builds the key of the result of a query, which is made of the normalized query text,
+/// the settings and the current snapshot location of every table read by the query.
+///
+/// Returns None if the result can't be cached, that is when the query reads any table
+/// other than fuse tables, or runs inside an explicit transaction.
+pub async fn build_result_cache_key(
+    ctx: &QueryContext,
+    formatted_ast: &str,
+    metadata: &MetadataRef,
+) -> Result<Option<String>> {
+    if ctx.txn_mgr().lock().is_active() {
+        return Ok(None);
+    }
+
+    let tables = metadata.read().tables().to_vec();
+    if tables.is_empty() {
+        return Ok(None);
+    }
+
+    let mut key = format!("{}\n{}\n", ctx.get_tenant(), formatted_ast);
+    for entry in tables {
+        let table = entry.table();
+        let fuse_table = match table.as_any().downcast_ref::<FuseTable>() {
+            Some(fuse_table) => fuse_table,
+            None => return Ok(None),
+        };
+        let snapshot_loc = fuse_table.snapshot_loc().await?.unwrap_or_default();
+        writeln!(
+            key,
+            "{}.{}.{}#{}@{}",
+            entry.catalog(),
+            entry.database(),
+            entry.name(),
+            table.get_id(),
+            snapshot_loc
+        )
+        .ok();
+    }
+
+    // The settings of the result cache itself don't change the result.
+    for (name, value) in ctx.get_settings().get_setting_values_short() {
+        if !name.contains("query_result_cache") {
+            writeln!(key, "{}={:?}", name, value).ok();
+        }
+    }
+
+    Ok(Some(key))
+}
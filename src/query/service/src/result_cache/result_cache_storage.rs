@@ -0,0 +1,212 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Value;
+use common_storage::DataOperator;
+use naive_cityhash::cityhash128;
+use opendal::Operator;
+
+use crate::result_cache::CachedResult;
+
+/// The prefix of the results cached in the object storage, followed by the tenant.
+const RESULT_CACHE_PREFIX: &str = "_result_cache";
+
+/// The meta of a result cached in the object storage. It's written under the id of
+/// the query, and under the result key of the query if it has one.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ResultCacheMeta {
+    /// The result key, to tell apart the keys with the same hash.
+    key: Option<String>,
+    user: String,
+    schema: DataSchema,
+    num_rows: usize,
+    /// Location of the block of the result.
+    block_location: String,
+    /// Unix timestamp in seconds.
+    expire_at: i64,
+}
+
+/// Keeps the results of the queries in the object storage of the tenant, so they are
+/// shared by the nodes of a cluster and kept across restarts.
+///
+/// The block of a result is written first, then the meta under the query id, then the
+/// meta under the result key, so a meta always points to a complete block. The expired
+/// results are ignored when they are read, but not removed.
+pub struct ResultCacheStorage {
+    operator: Operator,
+    prefix: String,
+}
+
+impl ResultCacheStorage {
+    pub fn create(tenant: &str) -> ResultCacheStorage {
+        ResultCacheStorage {
+            operator: DataOperator::instance().operator(),
+            prefix: format!("{}/{}", RESULT_CACHE_PREFIX, tenant),
+        }
+    }
+
+    /// Writes the result of the query, and its meta under the result key if it's not None.
+    pub async fn write(
+        &self,
+        query_id: &str,
+        key: Option<&str>,
+        result: &CachedResult,
+    ) -> Result<()> {
+        let block = result.block.convert_to_full();
+        let columns = block
+            .columns()
+            .iter()
+            .map(|entry| entry.value.as_column().unwrap().clone())
+            .collect::<Vec<_>>();
+        let data = bincode::serialize(&columns).map_err(|cause| {
+            ErrorCode::Internal(format!("Cannot serialize the cached result: {}", cause))
+        })?;
+
+        let block_location = format!("{}/blocks/{}", self.prefix, query_id);
+        self.operator.object(&block_location).write(data).await?;
+
+        let meta = ResultCacheMeta {
+            key: key.map(|key| key.to_string()),
+            user: result.user.clone(),
+            schema: result.schema.as_ref().clone(),
+            num_rows: block.num_rows(),
+            block_location,
+            expire_at: expire_at_timestamp(result.expire_at),
+        };
+        self.write_meta(&self.query_meta_location(query_id), &meta)
+            .await?;
+        if let Some(key) = key {
+            self.write_meta(&self.key_meta_location(key), &meta).await?;
+        }
+        Ok(())
+    }
+
+    /// Writes the result cached under `cached_query_id` under the id of another query too.
+    pub async fn write_alias(
+        &self,
+        query_id: &str,
+        cached_query_id: &str,
+        result: &CachedResult,
+    ) -> Result<()> {
+        let meta = ResultCacheMeta {
+            key: None,
+            user: result.user.clone(),
+            schema: result.schema.as_ref().clone(),
+            num_rows: result.block.num_rows(),
+            block_location: format!("{}/blocks/{}", self.prefix, cached_query_id),
+            expire_at: expire_at_timestamp(result.expire_at),
+        };
+        self.write_meta(&self.query_meta_location(query_id), &meta)
+            .await
+    }
+
+    /// Reads the unexpired result of the query.
+    pub async fn read_by_query_id(&self, query_id: &str) -> Result<Option<CachedResult>> {
+        match self.read_meta(&self.query_meta_location(query_id)).await? {
+            Some(meta) => self.read_block(meta).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Reads the unexpired result cached under the result key, with the id of the query
+    /// which produced it.
+    pub async fn read_by_key(&self, key: &str) -> Result<Option<(String, CachedResult)>> {
+        let meta = match self.read_meta(&self.key_meta_location(key)).await? {
+            Some(meta) if meta.key.as_deref() == Some(key) => meta,
+            _ => return Ok(None),
+        };
+
+        let query_id = meta
+            .block_location
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        Ok(self
+            .read_block(meta)
+            .await?
+            .map(|result| (query_id, result)))
+    }
+
+    fn query_meta_location(&self, query_id: &str) -> String {
+        format!("{}/queries/{}.json", self.prefix, query_id)
+    }
+
+    fn key_meta_location(&self, key: &str) -> String {
+        let hash = cityhash128(key.as_bytes());
+        format!("{}/keys/{:016x}{:016x}.json", self.prefix, hash.hi, hash.lo)
+    }
+
+    async fn write_meta(&self, location: &str, meta: &ResultCacheMeta) -> Result<()> {
+        let data = serde_json::to_vec(meta)?;
+        self.operator.object(location).write(data).await?;
+        Ok(())
+    }
+
+    async fn read_meta(&self, location: &str) -> Result<Option<ResultCacheMeta>> {
+        let data = match self.operator.object(location).read().await {
+            Ok(data) => data,
+            Err(e) if e.kind() == opendal::ErrorKind::ObjectNotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let meta: ResultCacheMeta = serde_json::from_slice(&data)?;
+        match meta.expire_at > Utc::now().timestamp() {
+            true => Ok(Some(meta)),
+            false => Ok(None),
+        }
+    }
+
+    async fn read_block(&self, meta: ResultCacheMeta) -> Result<Option<CachedResult>> {
+        let data = match self.operator.object(&meta.block_location).read().await {
+            Ok(data) => data,
+            Err(e) if e.kind() == opendal::ErrorKind::ObjectNotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let columns: Vec<Column> = bincode::deserialize(&data).map_err(|cause| {
+            ErrorCode::Internal(format!("Cannot deserialize the cached result: {}", cause))
+        })?;
+
+        let entries = columns
+            .into_iter()
+            .zip(meta.schema.fields())
+            .map(|(column, field)| BlockEntry {
+                data_type: field.data_type().clone(),
+                value: Value::Column(column),
+            })
+            .collect();
+        let ttl = Duration::from_secs((meta.expire_at - Utc::now().timestamp()).max(0) as u64);
+        Ok(Some(CachedResult::create(
+            meta.user,
+            Arc::new(meta.schema),
+            DataBlock::new(entries, meta.num_rows),
+            ttl,
+        )))
+    }
+}
+
+fn expire_at_timestamp(expire_at: Instant) -> i64 {
+    let ttl = expire_at.saturating_duration_since(Instant::now());
+    Utc::now().timestamp() + ttl.as_secs() as i64
+}
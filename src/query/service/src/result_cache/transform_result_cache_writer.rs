@@ -0,0 +1,163 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+use tracing::warn;
+
+use crate::result_cache::CachedResult;
+use crate::result_cache::ResultCache;
+use crate::result_cache::ResultCacheStorage;
+
+/// Where and how long the result of a query is cached.
+#[derive(Clone)]
+pub struct ResultCacheWriteInfo {
+    pub tenant: String,
+    pub query_id: String,
+    pub key: Option<String>,
+    pub user: String,
+    pub schema: DataSchemaRef,
+    pub ttl: Duration,
+    pub max_bytes: usize,
+    /// The max bytes of all the results cached on this node.
+    pub capacity_bytes: usize,
+}
+
+/// Passes the result blocks of a query through, and caches them once all of them are passed,
+/// in the memory of this node and in the object storage of the tenant.
+///
+/// Nothing is cached if the result is larger than `max_bytes`, or the downstream stops
+/// pulling before the end of the result.
+pub struct TransformResultCacheWriter {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    info: ResultCacheWriteInfo,
+    blocks: Vec<DataBlock>,
+    bytes: usize,
+    exceeded: bool,
+    written: bool,
+}
+
+impl TransformResultCacheWriter {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        info: ResultCacheWriteInfo,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Box::new(TransformResultCacheWriter {
+            input,
+            output,
+            info,
+            blocks: vec![],
+            bytes: 0,
+            exceeded: false,
+            written: false,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformResultCacheWriter {
+    fn name(&self) -> String {
+        "TransformResultCacheWriter".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input.has_data() {
+            let data_block = self.input.pull_data().unwrap()?;
+            if !self.exceeded {
+                self.bytes += data_block.memory_size();
+                if self.bytes > self.info.max_bytes {
+                    self.exceeded = true;
+                    self.blocks.clear();
+                } else {
+                    self.blocks.push(data_block.clone());
+                }
+            }
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input.is_finished() {
+            if !self.exceeded && !self.written {
+                return Ok(Event::Async);
+            }
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        self.written = true;
+
+        let block = match self.blocks.is_empty() {
+            true => DataBlock::empty_with_schema(self.info.schema.clone()),
+            false => DataBlock::concat(&std::mem::take(&mut self.blocks))?,
+        };
+        let result = CachedResult::create(
+            self.info.user.clone(),
+            self.info.schema.clone(),
+            block,
+            self.info.ttl,
+        );
+        let result = Arc::new(result);
+        ResultCache::instance().put(
+            &self.info.query_id,
+            self.info.key.clone(),
+            result.clone(),
+            self.info.capacity_bytes,
+        );
+
+        // The result is still served from the memory of this node if it can't be written.
+        let storage = ResultCacheStorage::create(&self.info.tenant);
+        if let Err(cause) = storage
+            .write(&self.info.query_id, self.info.key.as_deref(), &result)
+            .await
+        {
+            warn!(
+                "Cannot write the cached result of query {}: {}",
+                self.info.query_id, cause
+            );
+        }
+        Ok(())
+    }
+}
@@ -17,10 +17,12 @@ use std::sync::Arc;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_sql::executor::FragmentKind;
+use common_sql::executor::SkewShuffleKind;
 
 use crate::api::BroadcastExchange;
 use crate::api::DataExchange;
 use crate::api::MergeExchange;
+use crate::api::RangeDataExchange;
 use crate::api::ShuffleDataExchange;
 use crate::clusters::ClusterHelper;
 use crate::schedulers::fragments::plan_fragment::FragmentType;
@@ -87,6 +89,27 @@ impl Fragmenter {
                     from_multiple_nodes,
                     Self::get_executors(ctx),
                 ))),
+                FragmentKind::Range(ref order_by) => Ok(Some(RangeDataExchange::create(
+                    Self::get_executors(ctx),
+                    order_by.clone(),
+                ))),
+                FragmentKind::OrderedMerge => Ok(Some(MergeExchange::create_ordered(
+                    Self::get_local_executor(ctx.clone()),
+                    Self::get_executors(ctx),
+                ))),
+                // The hot keys of an aggregation are merged before the shuffle, the partial
+                // states are shuffled by hash as usual.
+                FragmentKind::SkewHash(ref skew) if skew.kind == SkewShuffleKind::Aggregate => {
+                    Ok(Some(ShuffleDataExchange::create(
+                        Self::get_executors(ctx),
+                        plan.keys.clone(),
+                    )))
+                }
+                FragmentKind::SkewHash(ref skew) => Ok(Some(ShuffleDataExchange::create_skew(
+                    Self::get_executors(ctx),
+                    plan.keys.clone(),
+                    skew.clone(),
+                ))),
                 _ => Ok(None),
             },
             _ => Ok(None),
@@ -149,6 +172,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
+            runtime_filters: plan.runtime_filters.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -176,12 +176,14 @@ impl QueryFragmentsActions {
         let mut query_fragments_plan_packets = Vec::with_capacity(fragments_packets.len());
 
         let cluster = self.ctx.get_cluster();
+        let enable_profiling = self.ctx.get_plan_profiles().is_some();
         let local_query_fragments_plan_packet = QueryFragmentsPlanPacket::create(
             self.ctx.get_id(),
             cluster.local_id.clone(),
             fragments_packets.remove(&cluster.local_id).unwrap(),
             nodes_info.clone(),
             cluster.local_id(),
+            enable_profiling,
         );
 
         for (executor, fragments) in fragments_packets.into_iter() {
@@ -194,6 +196,7 @@ impl QueryFragmentsActions {
                 fragments,
                 executors_info,
                 cluster.local_id(),
+                enable_profiling,
             ));
         }
 
@@ -63,6 +63,7 @@ impl<'a> Display for QueryFragmentActionsWrap<'a> {
                 DataExchange::Merge(_) => writeln!(f, "  DataExchange: Merge")?,
                 DataExchange::Broadcast(_) => writeln!(f, "  DataExchange: Broadcast")?,
                 DataExchange::ShuffleDataExchange(_) => writeln!(f, "  DataExchange: Shuffle")?,
+                DataExchange::Range(_) => writeln!(f, "  DataExchange: Range")?,
             }
         }
 
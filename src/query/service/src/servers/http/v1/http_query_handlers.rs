@@ -129,7 +129,7 @@ impl QueryResponse {
             (JsonBlock::empty(), None)
         } else {
             match state.state {
-                ExecuteStateKind::Running => match r.data {
+                ExecuteStateKind::Queued | ExecuteStateKind::Running => match r.data {
                     None => (JsonBlock::empty(), Some(make_state_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
@@ -208,7 +208,10 @@ async fn query_final_handler(
     match http_query_manager.remove_query(&query_id).await {
         Some(query) => {
             let mut response = query.get_response_state_only().await;
-            if response.state.state == ExecuteStateKind::Running {
+            if matches!(
+                response.state.state,
+                ExecuteStateKind::Queued | ExecuteStateKind::Running
+            ) {
                 return Err(PoemError::from_string(
                     format!("query {} is still running, can not final it", query_id),
                     StatusCode::BAD_REQUEST,
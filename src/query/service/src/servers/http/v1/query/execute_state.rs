@@ -44,6 +44,8 @@ use crate::sessions::TableContext;
 
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ExecuteStateKind {
+    /// Waiting in the queue of the workload group of the query.
+    Queued,
     Running,
     Failed,
     Succeeded,
@@ -81,6 +83,7 @@ pub enum ExecuteState {
 impl ExecuteState {
     pub(crate) fn extract(&self) -> (ExecuteStateKind, Option<ErrorCode>) {
         match self {
+            Starting(s) if s.ctx.is_queued() => (ExecuteStateKind::Queued, None),
             Starting(_) | Running(_) => (ExecuteStateKind::Running, None),
             Stopped(v) => match &v.reason {
                 Ok(_) => (ExecuteStateKind::Succeeded, None),
@@ -205,7 +205,10 @@ impl HttpQuery {
             let mut n = 1;
             while let ExpiringState::InUse(query_id) = session.expire_state() {
                 if let Some(last_query) = &http_query_manager.get_query(&query_id).await {
-                    if last_query.get_state().await.state == ExecuteStateKind::Running {
+                    if matches!(
+                        last_query.get_state().await.state,
+                        ExecuteStateKind::Queued | ExecuteStateKind::Running
+                    ) {
                         return Err(ErrorCode::BadArguments(
                             "last query on the session not finished",
                         ));
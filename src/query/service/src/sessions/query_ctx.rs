@@ -21,19 +21,36 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
 use std::time::SystemTime;
 
 use chrono_tz::Tz;
+use common_base::base::tokio;
 use common_base::base::tokio::task::JoinHandle;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_base::runtime::TrySpawn;
 use common_catalog::plan::DataSourceInfo;
 use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::HotKeys;
 use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::PartitionStealCommand;
+use common_catalog::plan::PartitionStealPartial;
 use common_catalog::plan::Partitions;
+use common_catalog::plan::RangeBounds;
+use common_catalog::plan::RangePartitionDesc;
+use common_catalog::plan::RangePartitionPartial;
+use common_catalog::plan::RangeSample;
+use common_catalog::plan::RuntimeFilter;
+use common_catalog::plan::RuntimeFilterDesc;
+use common_catalog::plan::RuntimeFilterPartial;
+use common_catalog::plan::SkewSample;
+use common_catalog::plan::SkewShuffleDesc;
+use common_catalog::plan::SkewShufflePartial;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -44,6 +61,7 @@ use common_io::prelude::FormatSettings;
 use common_meta_app::schema::TableInfo;
 use common_meta_types::RoleInfo;
 use common_meta_types::UserInfo;
+use common_pipeline_core::processors::PlanProfiles;
 use common_settings::Settings;
 use common_storage::DataOperator;
 use common_storage::StorageMetrics;
@@ -62,12 +80,21 @@ use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
+use crate::sessions::WorkloadGroupQueue;
 use crate::storages::Table;
 
+/// How often the sources waiting for the stolen partitions check if the query is aborted.
+const WAIT_STOLEN_PARTS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How often the exchanges waiting for the samples of all the nodes check if the query is aborted.
+const WAIT_EXCHANGE_SAMPLE_INTERVAL: Duration = Duration::from_millis(100);
+
 #[derive(Clone)]
 pub struct QueryContext {
     version: String,
     partition_queue: Arc<RwLock<VecDeque<PartInfoPtr>>>,
+    /// The scan whose partitions in the queue can be stolen by or from other nodes.
+    stealable_scan: Arc<RwLock<Option<usize>>>,
     shared: Arc<QueryContextShared>,
     fragment_id: Arc<AtomicUsize>,
 }
@@ -82,6 +109,7 @@ impl QueryContext {
 
         Arc::new(QueryContext {
             partition_queue: Arc::new(RwLock::new(VecDeque::new())),
+            stealable_scan: Arc::new(RwLock::new(None)),
             version: format!("DatabendQuery {}", *DATABEND_COMMIT_VERSION),
             shared,
             fragment_id: Arc::new(AtomicUsize::new(0)),
@@ -196,6 +224,175 @@ impl QueryContext {
     pub fn get_on_error_map(&self) -> Option<HashMap<String, ErrorCode>> {
         self.shared.get_on_error_map()
     }
+
+    pub fn publish_runtime_filter(&self, desc: &RuntimeFilterDesc, filter: RuntimeFilter) {
+        self.shared.publish_runtime_filter(desc, filter)
+    }
+
+    pub fn merge_remote_runtime_filter(&self, partial: RuntimeFilterPartial) {
+        self.shared.merge_remote_runtime_filter(partial)
+    }
+
+    pub fn install_runtime_filter(&self, id: usize, filter: RuntimeFilter) {
+        self.shared.install_runtime_filter(id, filter)
+    }
+
+    pub fn take_runtime_filter_partials(&self) -> Vec<RuntimeFilterPartial> {
+        self.shared.take_runtime_filter_partials()
+    }
+
+    pub fn get_remote_runtime_filters(&self) -> Vec<(usize, Arc<RuntimeFilter>)> {
+        self.shared.get_remote_runtime_filters()
+    }
+
+    pub fn publish_range_sample(&self, desc: &RangePartitionDesc, sample: RangeSample) {
+        self.shared.publish_range_sample(desc, sample)
+    }
+
+    pub fn merge_remote_range_partition(&self, partial: RangePartitionPartial) {
+        self.shared.merge_remote_range_partition(partial)
+    }
+
+    pub fn install_range_bounds(&self, id: usize, bounds: RangeBounds) {
+        self.shared.install_range_bounds(id, bounds)
+    }
+
+    pub fn take_range_partition_partials(&self) -> Vec<RangePartitionPartial> {
+        self.shared.take_range_partition_partials()
+    }
+
+    pub fn get_remote_range_bounds(&self) -> Vec<(usize, Arc<RangeBounds>)> {
+        self.shared.get_remote_range_bounds()
+    }
+
+    /// Get the bounds of the range partition if the samples have been merged.
+    pub fn get_range_bounds(&self, id: usize) -> Option<Arc<RangeBounds>> {
+        self.shared.get_range_bounds(id)
+    }
+
+    /// Wait until `ready` returns true, it is checked again whenever the samples of the exchanges
+    /// are merged or their results are installed. Fails if the query is aborted, or the samples
+    /// are not merged within `wait_for_exchange_sample_timeout` seconds.
+    pub async fn wait_exchange_sample(&self, ready: impl Fn() -> bool + Send) -> Result<()> {
+        let timeout = self.get_settings().get_wait_for_exchange_sample_timeout()?;
+        let deadline = Instant::now() + Duration::from_secs(timeout);
+
+        loop {
+            // Created before checking, so a notification in between is not missed.
+            let notified = self.shared.exchange_sample_notify.notified();
+            if ready() {
+                return Ok(());
+            }
+
+            if self.shared.aborting.load(Ordering::Relaxed) {
+                return Err(ErrorCode::AbortedQuery(
+                    "Aborted query, because the server is shutting down or the query was killed.",
+                ));
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(ErrorCode::ExchangeSampleTimeout(format!(
+                    "The samples of the exchange are not merged in {} seconds",
+                    timeout
+                )));
+            }
+
+            let interval = WAIT_EXCHANGE_SAMPLE_INTERVAL.min(deadline - now);
+            let _ = tokio::time::timeout(interval, notified).await;
+        }
+    }
+
+    /// Publish the sample of the build side of a skew-aware shuffle on this node,
+    /// return the hot keys if they are detected on this node.
+    pub fn publish_skew_sample(
+        &self,
+        desc: &SkewShuffleDesc,
+        sample: SkewSample,
+    ) -> Option<Arc<HotKeys>> {
+        self.shared.publish_skew_sample(desc, sample)
+    }
+
+    pub fn merge_remote_skew_shuffle(&self, partial: SkewShufflePartial) -> Option<Arc<HotKeys>> {
+        self.shared.merge_remote_skew_shuffle(partial)
+    }
+
+    pub fn install_hot_keys(&self, id: usize, hot_keys: HotKeys) {
+        self.shared.install_hot_keys(id, hot_keys)
+    }
+
+    pub fn take_skew_shuffle_partials(&self) -> Vec<SkewShufflePartial> {
+        self.shared.take_skew_shuffle_partials()
+    }
+
+    pub fn get_remote_hot_keys(&self) -> Vec<(usize, Arc<HotKeys>)> {
+        self.shared.get_remote_hot_keys()
+    }
+
+    /// Get the hot keys of the skew-aware shuffle if the samples have been merged.
+    pub fn get_hot_keys(&self, id: usize) -> Option<Arc<HotKeys>> {
+        self.shared.get_hot_keys(id)
+    }
+
+    /// Let the partitions of the scan be stolen by or from other nodes of the cluster.
+    pub fn register_stealable_partitions(&self, scan_id: usize) {
+        *self.stealable_scan.write() = Some(scan_id);
+        self.shared
+            .partition_stealing
+            .write()
+            .register(scan_id, self.partition_queue.clone());
+    }
+
+    pub fn enable_partition_stealing(&self) {
+        self.shared.enable_partition_stealing()
+    }
+
+    pub fn take_partition_steal_partials(&self) -> Vec<PartitionStealPartial> {
+        self.shared.take_partition_steal_partials()
+    }
+
+    pub fn merge_remote_partition_steal(&self, partial: PartitionStealPartial) {
+        self.shared.merge_remote_partition_steal(partial)
+    }
+
+    /// Rebalance the unread partitions between the nodes, only on the coordinator.
+    pub fn rebalance_partition_steals(&self) {
+        self.shared.rebalance_partition_steals()
+    }
+
+    pub fn take_partition_steal_commands(&self, executor: &str) -> Vec<PartitionStealCommand> {
+        self.shared.take_partition_steal_commands(executor)
+    }
+
+    pub fn install_partition_steal(&self, command: PartitionStealCommand) {
+        self.shared.install_partition_steal(command)
+    }
+
+    /// Collect the runtime profile of each plan node for the query.
+    pub fn enable_plan_profiling(&self) {
+        self.shared.enable_plan_profiling()
+    }
+
+    pub fn get_plan_profiles(&self) -> Option<Arc<PlanProfiles>> {
+        self.shared.get_plan_profiles()
+    }
+
+    pub fn get_workload_queue(&self) -> Option<Arc<WorkloadGroupQueue>> {
+        self.shared.get_workload_queue()
+    }
+
+    pub async fn acquire_workload_permit(&self) -> Result<bool> {
+        self.shared.acquire_workload_permit().await
+    }
+
+    pub fn release_workload_permit(&self) {
+        self.shared.release_workload_permit()
+    }
+
+    /// Whether the query is waiting in the queue of its workload group.
+    pub fn is_queued(&self) -> bool {
+        self.shared.is_queued()
+    }
 }
 
 #[async_trait::async_trait]
@@ -255,6 +452,35 @@ impl TableContext for QueryContext {
         res
     }
 
+    async fn try_steal_parts(&self, num: usize) -> Result<Vec<PartInfoPtr>> {
+        let stealable_scan = *self.stealable_scan.read();
+        let scan_id = match stealable_scan {
+            None => return Ok(vec![]),
+            Some(scan_id) => scan_id,
+        };
+
+        loop {
+            // Created before checking, so a notification in between is not missed.
+            let notified = self.shared.partition_stealing_notify.notified();
+            let stolen = self
+                .shared
+                .partition_stealing
+                .write()
+                .try_steal(scan_id, num);
+            if let Some(parts) = stolen {
+                return Ok(parts);
+            }
+
+            if self.shared.aborting.load(Ordering::Relaxed) {
+                return Err(ErrorCode::AbortedQuery(
+                    "Aborted query, because the server is shutting down or the query was killed.",
+                ));
+            }
+
+            let _ = tokio::time::timeout(WAIT_STOLEN_PARTS_INTERVAL, notified).await;
+        }
+    }
+
     // Update the context partition pool from the pipeline builder.
     fn try_set_partitions(&self, partitions: Partitions) -> Result<()> {
         let mut partition_queue = self.partition_queue.write();
@@ -335,6 +561,10 @@ impl TableContext for QueryContext {
     fn consume_precommit_blocks(&self) -> Vec<DataBlock> {
         self.shared.consume_precommit_blocks()
     }
+
+    fn get_runtime_filter(&self, id: usize) -> Option<Arc<RuntimeFilter>> {
+        self.shared.get_runtime_filter(id)
+    }
     fn try_get_function_context(&self) -> Result<FunctionContext> {
         let tz = self.get_settings().get_timezone()?;
         let tz = tz.parse::<Tz>().map_err(|_| {
@@ -382,6 +612,14 @@ impl TableContext for QueryContext {
     fn set_on_error_map(&self, map: Option<HashMap<String, ErrorCode>>) {
         self.shared.set_on_error_map(map);
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
+
+    fn get_last_query_id(&self) -> String {
+        self.shared.session.get_last_query_id()
+    }
 }
 
 impl TrySpawn for QueryContext {
@@ -20,8 +20,28 @@ use std::sync::Arc;
 use std::sync::Weak;
 use std::time::SystemTime;
 
+use common_base::base::tokio::sync::Notify;
+use common_base::base::tokio::sync::OwnedSemaphorePermit;
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
+use common_base::runtime::ThreadTracker;
+use common_catalog::plan::HotKeys;
+use common_catalog::plan::PartitionStealCommand;
+use common_catalog::plan::PartitionStealPartial;
+use common_catalog::plan::PartitionStealing;
+use common_catalog::plan::RangeBounds;
+use common_catalog::plan::RangePartitionDesc;
+use common_catalog::plan::RangePartitionPartial;
+use common_catalog::plan::RangePartitions;
+use common_catalog::plan::RangeSample;
+use common_catalog::plan::RuntimeFilter;
+use common_catalog::plan::RuntimeFilterDesc;
+use common_catalog::plan::RuntimeFilterPartial;
+use common_catalog::plan::RuntimeFilters;
+use common_catalog::plan::SkewSample;
+use common_catalog::plan::SkewShuffleDesc;
+use common_catalog::plan::SkewShufflePartial;
+use common_catalog::plan::SkewShuffles;
 use common_catalog::table_context::StageAttachment;
 use common_config::Config;
 use common_exception::ErrorCode;
@@ -29,6 +49,7 @@ use common_exception::Result;
 use common_expression::DataBlock;
 use common_meta_types::RoleInfo;
 use common_meta_types::UserInfo;
+use common_pipeline_core::processors::PlanProfiles;
 use common_settings::Settings;
 use common_storage::DataOperator;
 use common_storage::StorageMetrics;
@@ -42,6 +63,7 @@ use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
+use crate::sessions::WorkloadGroupQueue;
 use crate::storages::Table;
 
 type DatabaseAndTable = (String, String, String);
@@ -80,6 +102,20 @@ pub struct QueryContextShared {
     pub(in crate::sessions) stage_attachment: Arc<RwLock<Option<StageAttachment>>>,
     pub(in crate::sessions) created_time: SystemTime,
     pub(in crate::sessions) on_error_map: Arc<RwLock<Option<HashMap<String, ErrorCode>>>>,
+    pub(in crate::sessions) runtime_filters: Arc<RwLock<RuntimeFilters>>,
+    pub(in crate::sessions) range_partitions: Arc<RwLock<RangePartitions>>,
+    pub(in crate::sessions) skew_shuffles: Arc<RwLock<SkewShuffles>>,
+    /// Wakes up the exchanges waiting for the samples of all the nodes to be merged.
+    pub(in crate::sessions) exchange_sample_notify: Arc<Notify>,
+    pub(in crate::sessions) partition_stealing: Arc<RwLock<PartitionStealing>>,
+    /// Wakes up the sources waiting for the partitions stolen from other nodes.
+    pub(in crate::sessions) partition_stealing_notify: Arc<Notify>,
+    /// Only set when the query is profiled, e.g. `EXPLAIN ANALYZE`
+    pub(in crate::sessions) plan_profiles: Arc<RwLock<Option<Arc<PlanProfiles>>>>,
+    /// The workload group the query is admitted by, if the user or role is assigned to one.
+    pub(in crate::sessions) workload_queue: Arc<RwLock<Option<Arc<WorkloadGroupQueue>>>>,
+    pub(in crate::sessions) workload_permit: Arc<Mutex<Option<OwnedSemaphorePermit>>>,
+    pub(in crate::sessions) queued: Arc<AtomicBool>,
 }
 
 impl QueryContextShared {
@@ -110,6 +146,16 @@ impl QueryContextShared {
             stage_attachment: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
             on_error_map: Arc::new(RwLock::new(None)),
+            runtime_filters: Arc::new(RwLock::new(RuntimeFilters::default())),
+            range_partitions: Arc::new(RwLock::new(RangePartitions::default())),
+            skew_shuffles: Arc::new(RwLock::new(SkewShuffles::default())),
+            exchange_sample_notify: Arc::new(Notify::new()),
+            partition_stealing: Arc::new(RwLock::new(PartitionStealing::default())),
+            partition_stealing_notify: Arc::new(Notify::new()),
+            plan_profiles: Arc::new(RwLock::new(None)),
+            workload_queue: Arc::new(RwLock::new(None)),
+            workload_permit: Arc::new(Mutex::new(None)),
+            queued: Arc::new(AtomicBool::new(false)),
         }))
     }
 
@@ -234,7 +280,14 @@ impl QueryContextShared {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self.catalog_manager.get_catalog(catalog)?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let mut cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+
+        // the running transaction reads its own writes
+        let table_id = cache_table.get_table_info().ident.table_id;
+        let pending = self.session.txn_mgr().lock().get_table_info(table_id);
+        if let Some(table_info) = pending {
+            cache_table = catalog.get_table_by_info(&table_info)?;
+        }
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -251,6 +304,10 @@ impl QueryContextShared {
         match &*query_runtime {
             Some(query_runtime) => Ok(query_runtime.clone()),
             None => {
+                // Memory of the query is accounted to the workload group it is admitted by.
+                let mem_stat = self.get_workload_queue().map(|queue| queue.get_mem_stat());
+                let _guard = mem_stat.map(|mem_stat| ThreadTracker::enter(Some(mem_stat)));
+
                 // To avoid possible deadlock, we should keep at least two threads.
                 let runtime = Arc::new(Runtime::with_worker_threads(
                     2,
@@ -262,6 +319,44 @@ impl QueryContextShared {
         }
     }
 
+    pub fn set_workload_queue(&self, queue: Option<Arc<WorkloadGroupQueue>>) {
+        *self.workload_queue.write() = queue;
+    }
+
+    pub fn get_workload_queue(&self) -> Option<Arc<WorkloadGroupQueue>> {
+        self.workload_queue.read().clone()
+    }
+
+    /// Waits until the workload group of the query admits it.
+    ///
+    /// Interpreters run by an admitted query, e.g. by a procedure, are not queued again.
+    /// Returns whether the permit is taken by this call, only the caller that took the
+    /// permit should release it.
+    pub async fn acquire_workload_permit(&self) -> Result<bool> {
+        let queue = match self.get_workload_queue() {
+            None => return Ok(false),
+            Some(queue) => queue,
+        };
+        if self.workload_permit.lock().is_some() {
+            return Ok(false);
+        }
+
+        self.queued.store(true, Ordering::Release);
+        let permit = queue.acquire().await;
+        self.queued.store(false, Ordering::Release);
+
+        *self.workload_permit.lock() = permit?;
+        Ok(true)
+    }
+
+    pub fn release_workload_permit(&self) {
+        self.workload_permit.lock().take();
+    }
+
+    pub fn is_queued(&self) -> bool {
+        self.queued.load(Ordering::Acquire)
+    }
+
     pub fn attach_query_str(&self, kind: String, query: &str) {
         {
             let mut running_query = self.running_query.write();
@@ -319,6 +414,130 @@ impl QueryContextShared {
         swaped_precommit_blocks
     }
 
+    pub fn publish_runtime_filter(&self, desc: &RuntimeFilterDesc, filter: RuntimeFilter) {
+        self.runtime_filters.write().publish(desc, filter)
+    }
+
+    pub fn merge_remote_runtime_filter(&self, partial: RuntimeFilterPartial) {
+        self.runtime_filters.write().merge_remote(partial)
+    }
+
+    pub fn install_runtime_filter(&self, id: usize, filter: RuntimeFilter) {
+        self.runtime_filters.write().install(id, filter)
+    }
+
+    pub fn take_runtime_filter_partials(&self) -> Vec<RuntimeFilterPartial> {
+        self.runtime_filters.write().take_outbox()
+    }
+
+    pub fn get_remote_runtime_filters(&self) -> Vec<(usize, Arc<RuntimeFilter>)> {
+        self.runtime_filters.read().remote_filters()
+    }
+
+    pub fn get_runtime_filter(&self, id: usize) -> Option<Arc<RuntimeFilter>> {
+        self.runtime_filters.read().get(id)
+    }
+
+    pub fn publish_range_sample(&self, desc: &RangePartitionDesc, sample: RangeSample) {
+        self.range_partitions.write().publish(desc, sample);
+        self.exchange_sample_notify.notify_waiters();
+    }
+
+    pub fn merge_remote_range_partition(&self, partial: RangePartitionPartial) {
+        self.range_partitions.write().merge_remote(partial);
+        self.exchange_sample_notify.notify_waiters();
+    }
+
+    pub fn install_range_bounds(&self, id: usize, bounds: RangeBounds) {
+        self.range_partitions.write().install(id, bounds);
+        self.exchange_sample_notify.notify_waiters();
+    }
+
+    pub fn take_range_partition_partials(&self) -> Vec<RangePartitionPartial> {
+        self.range_partitions.write().take_outbox()
+    }
+
+    pub fn get_remote_range_bounds(&self) -> Vec<(usize, Arc<RangeBounds>)> {
+        self.range_partitions.read().remote_bounds()
+    }
+
+    pub fn get_range_bounds(&self, id: usize) -> Option<Arc<RangeBounds>> {
+        self.range_partitions.read().get(id)
+    }
+
+    pub fn publish_skew_sample(
+        &self,
+        desc: &SkewShuffleDesc,
+        sample: SkewSample,
+    ) -> Option<Arc<HotKeys>> {
+        let hot_keys = self.skew_shuffles.write().publish(desc, sample);
+        self.exchange_sample_notify.notify_waiters();
+        hot_keys
+    }
+
+    pub fn merge_remote_skew_shuffle(&self, partial: SkewShufflePartial) -> Option<Arc<HotKeys>> {
+        let hot_keys = self.skew_shuffles.write().merge_remote(partial);
+        self.exchange_sample_notify.notify_waiters();
+        hot_keys
+    }
+
+    pub fn install_hot_keys(&self, id: usize, hot_keys: HotKeys) {
+        self.skew_shuffles.write().install(id, hot_keys);
+        self.exchange_sample_notify.notify_waiters();
+    }
+
+    pub fn take_skew_shuffle_partials(&self) -> Vec<SkewShufflePartial> {
+        self.skew_shuffles.write().take_outbox()
+    }
+
+    pub fn get_remote_hot_keys(&self) -> Vec<(usize, Arc<HotKeys>)> {
+        self.skew_shuffles.read().remote_hot_keys()
+    }
+
+    pub fn get_hot_keys(&self, id: usize) -> Option<Arc<HotKeys>> {
+        self.skew_shuffles.read().get(id)
+    }
+
+    pub fn enable_partition_stealing(&self) {
+        let local_id = self.cluster_cache.local_id();
+        self.partition_stealing.write().enable(&local_id)
+    }
+
+    pub fn take_partition_steal_partials(&self) -> Vec<PartitionStealPartial> {
+        self.partition_stealing.write().take_outbox()
+    }
+
+    pub fn merge_remote_partition_steal(&self, partial: PartitionStealPartial) {
+        self.partition_stealing.write().merge_remote(partial)
+    }
+
+    pub fn rebalance_partition_steals(&self) {
+        if self.partition_stealing.write().rebalance() {
+            self.partition_stealing_notify.notify_waiters();
+        }
+    }
+
+    pub fn take_partition_steal_commands(&self, executor: &str) -> Vec<PartitionStealCommand> {
+        self.partition_stealing.write().take_commands(executor)
+    }
+
+    pub fn install_partition_steal(&self, command: PartitionStealCommand) {
+        if self.partition_stealing.write().install(command) {
+            self.partition_stealing_notify.notify_waiters();
+        }
+    }
+
+    pub fn enable_plan_profiling(&self) {
+        let mut plan_profiles = self.plan_profiles.write();
+        if plan_profiles.is_none() {
+            *plan_profiles = Some(Arc::new(PlanProfiles::default()));
+        }
+    }
+
+    pub fn get_plan_profiles(&self) -> Option<Arc<PlanProfiles>> {
+        self.plan_profiles.read().clone()
+    }
+
     pub fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.stage_attachment.read().clone()
     }
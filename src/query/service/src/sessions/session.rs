@@ -16,6 +16,7 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use chrono_tz::Tz;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -130,6 +131,8 @@ impl Session {
         let session = self.clone();
         let cluster = ClusterDiscovery::instance().discover(&config).await?;
         let shared = QueryContextShared::try_create(&config, session, cluster)?;
+        let workload_queue = SessionManager::instance().get_workload_queue(self).await?;
+        shared.set_workload_queue(workload_queue);
 
         self.session_ctx
             .set_query_context_shared(Arc::downgrade(&shared));
@@ -352,6 +355,18 @@ impl Session {
     pub fn get_status(self: &Arc<Self>) -> Arc<RwLock<SessionStatus>> {
         self.status.clone()
     }
+
+    pub fn txn_mgr(self: &Arc<Self>) -> TxnManagerRef {
+        self.session_ctx.txn_mgr()
+    }
+
+    pub fn get_last_query_id(self: &Arc<Self>) -> String {
+        self.session_ctx.get_last_query_id()
+    }
+
+    pub fn set_last_query_id(self: &Arc<Self>, query_id: String) {
+        self.session_ctx.set_last_query_id(query_id)
+    }
 }
 
 impl Drop for Session {
@@ -18,6 +18,8 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::Result;
 use common_meta_types::RoleInfo;
@@ -51,6 +53,10 @@ pub struct SessionContext {
     client_host: RwLock<Option<SocketAddr>>,
     io_shutdown_tx: RwLock<Option<Sender<Sender<()>>>>,
     query_context_shared: RwLock<Weak<QueryContextShared>>,
+    // The explicit transaction of the session, started by `BEGIN`.
+    txn_mgr: TxnManagerRef,
+    // The id of the last finished query of the session.
+    last_query_id: RwLock<String>,
 }
 
 impl SessionContext {
@@ -67,6 +73,8 @@ impl SessionContext {
             current_database: RwLock::new("default".to_string()),
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
+            txn_mgr: TxnManager::init(),
+            last_query_id: Default::default(),
         }))
     }
 
@@ -212,4 +220,18 @@ impl SessionContext {
         let mut lock = self.query_context_shared.write();
         *lock = ctx
     }
+
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
+    pub fn get_last_query_id(&self) -> String {
+        let lock = self.last_query_id.read();
+        lock.clone()
+    }
+
+    pub fn set_last_query_id(&self, query_id: String) {
+        let mut lock = self.last_query_id.write();
+        *lock = query_id
+    }
 }
@@ -53,6 +53,7 @@ impl Session {
             scan_progress_value: Self::query_scan_progress_value(status),
             mysql_connection_id: self.mysql_connection_id,
             created_time: Self::query_created_time(status),
+            workload_group: Self::query_workload_group(status),
         }
     }
 
@@ -60,6 +61,7 @@ impl Session {
         match status.get_query_context_shared() {
             _ if status.get_abort() => String::from("Aborting"),
             None => String::from("Idle"),
+            Some(shared) if shared.is_queued() => String::from("Queued"),
             Some(_) => String::from("Query"),
         }
     }
@@ -104,4 +106,11 @@ impl Session {
             Some(v) => v.created_time,
         }
     }
+
+    fn query_workload_group(status: &SessionContext) -> Option<String> {
+        status
+            .get_query_context_shared()
+            .and_then(|context_shared| context_shared.get_workload_queue())
+            .map(|queue| queue.group().name.clone())
+    }
 }
@@ -20,6 +20,7 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 use std::time::Duration;
+use std::time::Instant;
 
 use common_base::base::tokio;
 use common_base::base::GlobalInstance;
@@ -28,6 +29,7 @@ use common_config::Config;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::WorkloadGroup;
 use common_metrics::label_counter;
 use common_metrics::label_gauge;
 use common_settings::Settings;
@@ -37,17 +39,27 @@ use futures::StreamExt;
 use parking_lot::RwLock;
 use tracing::debug;
 use tracing::info;
+use tracing::warn;
 
 use crate::sessions::session::Session;
 use crate::sessions::ProcessInfo;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
+use crate::sessions::WorkloadGroupQueue;
 
 static METRIC_SESSION_CONNECT_NUMBERS: &str = "session_connect_numbers";
 static METRIC_SESSION_CLOSE_NUMBERS: &str = "session_close_numbers";
 static METRIC_SESSION_ACTIVE_CONNECTIONS: &str = "session_connections";
 
+/// How long the workload groups of a tenant are cached before they are listed from the meta service again.
+const WORKLOAD_GROUPS_CACHE_TTL: Duration = Duration::from_secs(15);
+
+pub(in crate::sessions) struct CachedWorkloadGroups {
+    groups: Vec<WorkloadGroup>,
+    cached_at: Instant,
+}
+
 pub struct SessionManager {
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Weak<Session>>>>,
@@ -56,6 +68,12 @@ pub struct SessionManager {
     // When typ is MySQL, insert into this map, key is id, val is MySQL connection id.
     pub(crate) mysql_conn_map: Arc<RwLock<HashMap<Option<u32>, String>>>,
     pub(in crate::sessions) mysql_basic_conn_id: AtomicU32,
+
+    // The node-local admission queues of workload groups, key is the tenant and the group name.
+    pub(in crate::sessions) workload_queues:
+        Arc<RwLock<HashMap<(String, String), Arc<WorkloadGroupQueue>>>>,
+    // The workload groups listed from the meta service, key is the tenant.
+    pub(in crate::sessions) workload_groups: Arc<RwLock<HashMap<String, CachedWorkloadGroups>>>,
 }
 
 impl SessionManager {
@@ -73,6 +91,8 @@ impl SessionManager {
             status: Arc::new(RwLock::new(SessionManagerStatus::default())),
             mysql_conn_map: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
+            workload_queues: Arc::new(RwLock::new(HashMap::new())),
+            workload_groups: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
@@ -225,6 +245,94 @@ impl SessionManager {
         processes_info
     }
 
+    /// Finds the workload group the queries of the session are admitted by.
+    ///
+    /// A group assigned to the current user takes precedence over a group assigned to the current role.
+    pub async fn get_workload_queue(
+        &self,
+        session: &Arc<Session>,
+    ) -> Result<Option<Arc<WorkloadGroupQueue>>> {
+        if !session.get_type().is_user_session() {
+            return Ok(None);
+        }
+
+        let user = match session.get_current_user() {
+            Ok(user) => user,
+            Err(_) => return Ok(None),
+        };
+
+        let tenant = session.get_current_tenant();
+        let groups = self.get_workload_groups(&tenant).await;
+        if groups.is_empty() {
+            return Ok(None);
+        }
+
+        let current_role = session.get_current_role();
+        let group = match groups.iter().find(|g| g.is_assigned_to_user(&user.name)) {
+            Some(group) => group,
+            None => match current_role
+                .and_then(|role| groups.iter().find(|g| g.is_assigned_to_role(&role.name)))
+            {
+                Some(group) => group,
+                None => return Ok(None),
+            },
+        };
+
+        let key = (tenant, group.name.clone());
+        let mut queues = self.workload_queues.write();
+        if let Some(queue) = queues.get(&key) {
+            if queue.group() == group {
+                return Ok(Some(queue.clone()));
+            }
+        }
+
+        // The group is new or has been altered, queries admitted by the old queue keep their slots.
+        // The memory share is of the node, not of the settings of the session admitted first.
+        let max_server_memory_usage = Settings::max_server_memory_usage(&GlobalConfig::instance())?;
+        let queue = WorkloadGroupQueue::create(group.clone(), max_server_memory_usage);
+        queues.insert(key, queue.clone());
+        Ok(Some(queue))
+    }
+
+    /// Lists the workload groups of the tenant, the groups are cached for a while to
+    /// keep the meta service off the path of every query.
+    ///
+    /// If the groups can't be listed, the queries are not admission controlled.
+    async fn get_workload_groups(&self, tenant: &str) -> Vec<WorkloadGroup> {
+        if let Some(cached) = self.workload_groups.read().get(tenant) {
+            if cached.cached_at.elapsed() < WORKLOAD_GROUPS_CACHE_TTL {
+                return cached.groups.clone();
+            }
+        }
+
+        match UserApiProvider::instance()
+            .get_workload_groups(tenant)
+            .await
+        {
+            Ok(groups) => {
+                self.workload_groups
+                    .write()
+                    .insert(tenant.to_string(), CachedWorkloadGroups {
+                        groups: groups.clone(),
+                        cached_at: Instant::now(),
+                    });
+                groups
+            }
+            Err(cause) => {
+                warn!(
+                    "Cannot list workload groups of tenant {}, queries are admitted without workload groups, cause: {:?}",
+                    tenant, cause
+                );
+                vec![]
+            }
+        }
+    }
+
+    /// Drops the cached workload groups of the tenant after they are created or dropped on this node.
+    pub fn invalidate_workload_groups(&self, tenant: &str) {
+        self.workload_groups.write().remove(tenant);
+    }
+
     fn destroy_idle_sessions(sessions: &Arc<RwLock<HashMap<String, Weak<Session>>>>) -> bool {
         // Read lock does not support reentrant
         // https://github.com/Amanieu/parking_lot::/blob/lock_api-0.4.4/lock_api/src/rwlock.rs#L422
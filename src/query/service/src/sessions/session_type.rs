@@ -26,13 +26,19 @@ pub enum SessionType {
     Dummy,
     Fuzz,
     Local,
+    /// Writes the query history of the node, the only session allowed to change
+    /// the reserved history database.
+    QueryHistory,
 }
 
 impl SessionType {
     pub fn is_user_session(&self) -> bool {
         !matches!(
             self,
-            SessionType::HTTPAPI(_) | SessionType::Dummy | SessionType::Fuzz
+            SessionType::HTTPAPI(_)
+                | SessionType::Dummy
+                | SessionType::Fuzz
+                | SessionType::QueryHistory
         )
     }
 }
@@ -50,6 +56,7 @@ impl fmt::Display for SessionType {
             SessionType::HTTPAPI(usage) => format!("HTTPAPI({})", usage),
             SessionType::Fuzz => "Fuzz".to_string(),
             SessionType::Local => "Local".to_string(),
+            SessionType::QueryHistory => "QueryHistory".to_string(),
         };
         write!(f, "{}", name)
     }
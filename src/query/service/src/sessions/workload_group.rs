@@ -0,0 +1,102 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio::sync::OwnedSemaphorePermit;
+use common_base::base::tokio::sync::Semaphore;
+use common_base::base::tokio::time::timeout;
+use common_base::runtime::MemStat;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::WorkloadGroup;
+
+/// The node-local admission queue of a workload group.
+///
+/// All the queries of the group running on this node share the concurrency slots
+/// and the memory tracker of the queue.
+pub struct WorkloadGroupQueue {
+    group: WorkloadGroup,
+    /// None if the concurrency of the group is unlimited.
+    slots: Option<Arc<Semaphore>>,
+    mem_stat: Arc<MemStat>,
+}
+
+impl WorkloadGroupQueue {
+    /// The memory of the queries of the group is limited to `memory_share` percent of
+    /// `max_server_memory_usage`, the memory usage limit of the node.
+    pub fn create(group: WorkloadGroup, max_server_memory_usage: u64) -> Arc<WorkloadGroupQueue> {
+        let slots = match group.max_concurrency {
+            0 => None,
+            max_concurrency => Some(Arc::new(Semaphore::new(max_concurrency as usize))),
+        };
+
+        let mem_stat = MemStat::create_child(format!("WorkloadGroup-{}", group.name), None);
+        if group.memory_share > 0 {
+            let limit = max_server_memory_usage / 100 * group.memory_share;
+            mem_stat.set_limit(limit as i64);
+        }
+
+        Arc::new(WorkloadGroupQueue {
+            group,
+            slots,
+            mem_stat,
+        })
+    }
+
+    pub fn group(&self) -> &WorkloadGroup {
+        &self.group
+    }
+
+    pub fn get_mem_stat(&self) -> Arc<MemStat> {
+        self.mem_stat.clone()
+    }
+
+    /// Caps the number of executor threads of a query by the cpu slots of the group.
+    pub fn max_threads(&self, max_threads: u64) -> u64 {
+        match self.group.cpu_slots {
+            0 => max_threads,
+            cpu_slots => std::cmp::min(max_threads, cpu_slots),
+        }
+    }
+
+    /// Waits for a free concurrency slot of the group, at most `queue_timeout` seconds,
+    /// or without limit if `queue_timeout` is 0.
+    ///
+    /// The slot is released when the returned permit is dropped.
+    pub async fn acquire(&self) -> Result<Option<OwnedSemaphorePermit>> {
+        let slots = match &self.slots {
+            None => return Ok(None),
+            Some(slots) => slots.clone(),
+        };
+
+        let acquired = match self.group.queue_timeout {
+            0 => Ok(slots.acquire_owned().await),
+            secs => timeout(Duration::from_secs(secs), slots.acquire_owned()).await,
+        };
+
+        match acquired {
+            Ok(Ok(permit)) => Ok(Some(permit)),
+            Ok(Err(cause)) => Err(ErrorCode::Internal(format!(
+                "Workload group '{}' queue is closed, cause: {}",
+                self.group.name, cause
+            ))),
+            Err(_) => Err(ErrorCode::WorkloadGroupQueueTimeout(format!(
+                "Query waited more than {} seconds in the queue of workload group '{}' (max_concurrency: {})",
+                self.group.queue_timeout, self.group.name, self.group.max_concurrency
+            ))),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_base::base::tokio::runtime::Handle;
+use common_base::base::tokio::task::block_in_place;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::infer_table_schema;
+use common_expression::Scalar;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_sources::processors::sources::OneBlockSource;
+
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::Pipe;
+use crate::pipelines::Pipeline;
+use crate::result_cache::CachedResult;
+use crate::result_cache::ResultCache;
+use crate::result_cache::ResultCacheStorage;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+/// `RESULT_SCAN(<query_id>)` reads the cached result of a previous query again.
+pub struct ResultScanTable {
+    table_info: TableInfo,
+    query_id: String,
+    result: Arc<CachedResult>,
+}
+
+impl ResultScanTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let query_id = match table_args.as_deref() {
+            Some([Scalar::String(query_id)]) => String::from_utf8(query_id.clone())?,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "{} expects a query id as the only argument",
+                    table_func_name
+                )));
+            }
+        };
+
+        let result = match ResultCache::instance().get_by_query_id(&query_id) {
+            Some(result) => Some(result),
+            None => Self::read_cached_result(&query_id)?,
+        }
+        .ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "The result of query '{}' is not cached or has expired, the results are only cached when enable_query_result_cache is 1",
+                query_id
+            ))
+        })?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: String::from(table_func_name),
+            meta: TableMeta {
+                schema: infer_table_schema(&result.schema)?,
+                engine: String::from(table_func_name),
+                // Assuming that created_on is unnecessary for function table,
+                // we could make created_on fixed to pass test_shuffle_action_try_into.
+                created_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                updated_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(ResultScanTable {
+            table_info,
+            query_id,
+            result,
+        }))
+    }
+
+    /// Reads the result cached by any node of the tenant of this node.
+    fn read_cached_result(query_id: &str) -> Result<Option<Arc<CachedResult>>> {
+        let storage = ResultCacheStorage::create(&GlobalConfig::instance().query.tenant_id);
+        let query_id = query_id.to_string();
+        let future = async move { storage.read_by_query_id(&query_id).await };
+        let result = match Handle::try_current() {
+            Ok(handle) => block_in_place(|| handle.block_on(future))?,
+            Err(_) => GlobalIORuntime::instance().block_on(future)?,
+        };
+        Ok(result.map(Arc::new))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ResultScanTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let user = ctx.get_current_user()?.identity().to_string();
+        if user != self.result.user {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "Permission denied, the result of query '{}' belongs to another user",
+                self.query_id
+            )));
+        }
+
+        let block = &self.result.block;
+        Ok((
+            PartStatistics::new_exact(block.num_rows(), block.memory_size(), 1, 1),
+            Partitions::default(),
+        ))
+    }
+
+    fn table_args(&self) -> Option<Vec<Scalar>> {
+        Some(vec![Scalar::String(self.query_id.as_bytes().to_vec())])
+    }
+
+    fn read_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        pipeline.add_pipe(Pipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![OneBlockSource::create(output, self.result.block.clone())?],
+        });
+
+        Ok(())
+    }
+}
+
+impl TableFunction for ResultScanTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
@@ -15,10 +15,12 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_arrow::arrow_format::flight::data::FlightData;
 use common_base::base::tokio;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_storages_fuse::operations::AppendOperationLogEntry;
+use databend_query::api::DataPacket;
 use databend_query::api::PrecommitBlock;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::Compression;
@@ -37,6 +39,8 @@ async fn test_precommit_ser_and_deser() -> Result<()> {
         ("_b/1.json".to_string(), 1),
         None,
         4,
+        None,
+        0,
         Compression::Lz4Raw,
     );
     let segment_info = SegmentInfo::new(vec![Arc::new(block_meta)], Statistics::default());
@@ -53,3 +57,14 @@ async fn test_precommit_ser_and_deser() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_fragment_finished_ser_and_deser() -> Result<()> {
+    let flight_data = FlightData::from(DataPacket::FragmentFinished);
+    assert!(!DataPacket::is_closing_client(&flight_data));
+    assert!(matches!(
+        DataPacket::try_from(flight_data)?,
+        DataPacket::FragmentFinished
+    ));
+    Ok(())
+}
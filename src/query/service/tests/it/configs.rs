@@ -561,11 +561,16 @@ table_engine_memory_enabled = true
 database_engine_github_enabled = true
 wait_timeout_mills = 5000
 max_query_log_size = 10000
+query_history_enabled = false
+query_history_flush_interval_secs = 10
+query_history_flush_batch_size = 1000
+query_history_retention_days = 30
 table_meta_cache_enabled = false
 table_cache_snapshot_count = 256
 table_cache_segment_count = 10240
 table_cache_block_meta_count = 102400
 table_memory_cache_mb_size = 256
+table_data_cache_enabled = false
 table_disk_cache_root = "_cache"
 table_disk_cache_mb_size = 1024
 table_cache_bloom_index_meta_count = 3000
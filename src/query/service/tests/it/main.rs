@@ -22,6 +22,7 @@ mod clusters;
 mod configs;
 mod metrics;
 mod pipelines;
+mod query_history;
 mod servers;
 mod sessions;
 mod sql;
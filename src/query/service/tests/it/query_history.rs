@@ -0,0 +1,122 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::SendableDataBlockStream;
+use common_storage::StorageFsConfig;
+use common_storage::StorageParams;
+use databend_query::interpreters::Interpreter;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::query_history::query_history_schema;
+use databend_query::query_history::QUERY_HISTORY_DATABASE;
+use databend_query::query_history::QUERY_HISTORY_TABLE;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::sql::Planner;
+use futures::TryStreamExt;
+use tempfile::TempDir;
+
+#[test]
+fn test_query_history_schema() {
+    let schema = query_history_schema();
+    let names = schema
+        .fields()
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(names[0], "log_type");
+    assert!(names.contains(&"query_text"));
+    assert_eq!(&names[names.len() - 2..], &["node_id", "profiles"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_history_flush() -> Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.storage.params = StorageParams::Fs(StorageFsConfig {
+        root: tmp_dir.path().to_str().unwrap().to_string(),
+    });
+    conf.query.query_history_enabled = true;
+    conf.query.query_history_flush_interval_secs = 1;
+    conf.query.query_history_flush_batch_size = 1;
+
+    let (_guard, ctx) = crate::tests::create_query_context_with_config(conf, None).await?;
+
+    execute_query(ctx.clone(), "SELECT 'history me'")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    // The finished query of this node is flushed in the background.
+    let sql = format!(
+        "SELECT query_id FROM {}.{} WHERE query_text = 'SELECT ''history me''' AND log_type = 2 AND node_id = '{}'",
+        QUERY_HISTORY_DATABASE,
+        QUERY_HISTORY_TABLE,
+        ctx.get_cluster().local_id,
+    );
+    let mut rows = 0;
+    for _ in 0..30 {
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        rows = match execute_query(ctx.clone(), &sql).await {
+            Ok(stream) => {
+                let blocks = stream.try_collect::<Vec<_>>().await?;
+                blocks.iter().map(|b| b.num_rows()).sum::<usize>()
+            }
+            // The history table is not created yet.
+            Err(_) => 0,
+        };
+        if rows > 0 {
+            break;
+        }
+    }
+
+    assert_eq!(1, rows);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_history_database_reserved() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+
+    for sql in [
+        format!("CREATE DATABASE {}", QUERY_HISTORY_DATABASE),
+        format!("DROP DATABASE IF EXISTS {}", QUERY_HISTORY_DATABASE),
+        format!("CREATE TABLE {}.t(a int)", QUERY_HISTORY_DATABASE),
+        format!("CREATE VIEW {}.v AS SELECT 1", QUERY_HISTORY_DATABASE),
+        format!(
+            "DROP TABLE IF EXISTS {}.{}",
+            QUERY_HISTORY_DATABASE, QUERY_HISTORY_TABLE
+        ),
+    ] {
+        let err = execute_query(ctx.clone(), &sql).await.err().unwrap();
+        assert_eq!(err.code(), ErrorCode::PERMISSION_DENIED, "{}", sql);
+    }
+
+    Ok(())
+}
+
+async fn execute_query(ctx: Arc<QueryContext>, query: &str) -> Result<SendableDataBlockStream> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner.plan_sql(query).await?;
+    ctx.attach_query_str(plan.to_string(), query);
+    let executor = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    executor.execute(ctx.clone()).await
+}
@@ -158,6 +158,7 @@ fn test_format() {
                     limit: None,
                     order_by: None,
                     prewhere: None,
+                    computed_columns: vec![],
                     statistics: Statistics {
                         statistics: None,
                         col_stats: Default::default(),
@@ -175,6 +176,7 @@ fn test_format() {
                 limit: None,
                 order_by: None,
                 prewhere: None,
+                computed_columns: vec![],
                 statistics: Statistics {
                     statistics: None,
                     col_stats: Default::default(),
@@ -54,7 +54,9 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         .into(),
         field_default_exprs: vec![],
         field_comments: vec![],
+        identity_sequences: vec![],
         as_select: None,
+        clone_source: None,
         cluster_key: None,
     };
 
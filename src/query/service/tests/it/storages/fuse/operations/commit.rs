@@ -26,10 +26,12 @@ use common_catalog::database::Database;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
+use common_catalog::plan::RuntimeFilter;
 use common_catalog::table::Table;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
@@ -78,6 +80,7 @@ use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
 use walkdir::WalkDir;
 
+use crate::storages::fuse::table_test_fixture::execute_command;
 use crate::storages::fuse::table_test_fixture::execute_query;
 use crate::storages::fuse::table_test_fixture::TestFixture;
 
@@ -176,6 +179,44 @@ async fn test_last_snapshot_hint() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_last_snapshot_hint_of_txn() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    execute_command(ctx.clone(), "BEGIN").await?;
+    let qry = format!("insert into {}.{} values(1, (2, 3))", db, tbl);
+    execute_command(ctx.clone(), &qry).await?;
+
+    // the hint is not written before the snapshot is visible
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let operator = fuse_table.get_operator();
+    let location = fuse_table
+        .meta_location_generator()
+        .gen_last_snapshot_hint_location();
+    assert!(operator.object(location.as_str()).read().await.is_err());
+
+    execute_command(ctx.clone(), "COMMIT").await?;
+
+    // check last snapshot hit file
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let last_snapshot_location = fuse_table.snapshot_loc().await?.unwrap();
+    let storage_meta_data = operator.metadata();
+    let storage_prefix = storage_meta_data.root();
+
+    let expected = format!("{}{}", storage_prefix, last_snapshot_location);
+    let content = operator.object(location.as_str()).read().await?;
+
+    assert_eq!(content.as_slice(), expected.as_bytes());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_abort_on_error() -> Result<()> {
     struct Case {
@@ -351,6 +392,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn try_steal_parts(&self, _: usize) -> Result<Vec<PartInfoPtr>> {
+        todo!()
+    }
+
     fn try_set_partitions(&self, _partitions: Partitions) -> Result<()> {
         todo!()
     }
@@ -431,6 +476,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_runtime_filter(&self, _id: usize) -> Option<Arc<RuntimeFilter>> {
+        todo!()
+    }
+
     fn try_get_function_context(&self) -> Result<FunctionContext> {
         todo!()
     }
@@ -467,6 +516,14 @@ impl TableContext for CtxDelegation {
     fn set_on_error_map(&self, _map: Option<HashMap<String, ErrorCode>>) {
         todo!()
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+
+    fn get_last_query_id(&self) -> String {
+        self.ctx.get_last_query_id()
+    }
 }
 
 #[derive(Clone)]
@@ -98,7 +98,9 @@ async fn test_block_pruner() -> Result<()> {
         .into(),
         field_default_exprs: vec![],
         field_comments: vec![],
+        identity_sequences: vec![],
         as_select: None,
+        clone_source: None,
         cluster_key: None,
     };
 
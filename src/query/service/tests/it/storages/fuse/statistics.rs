@@ -518,6 +518,8 @@ fn test_reduce_block_meta() -> common_exception::Result<()> {
             location.clone(),
             None,
             bloom_filter_index_size,
+            None,
+            0,
             Compression::Lz4Raw,
         );
         blocks.push(block_meta);
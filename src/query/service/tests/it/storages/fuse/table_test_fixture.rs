@@ -174,7 +174,9 @@ impl TestFixture {
             .into(),
             field_default_exprs: vec![],
             field_comments: vec![],
+            identity_sequences: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: Some("(id)".to_string()),
         }
     }
@@ -198,7 +200,9 @@ impl TestFixture {
             .into(),
             field_default_exprs: vec![],
             field_comments: vec![],
+            identity_sequences: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -381,8 +385,7 @@ pub async fn execute_query(ctx: Arc<QueryContext>, query: &str) -> Result<Sendab
 }
 
 pub fn execute_pipeline(ctx: Arc<QueryContext>, mut res: PipelineBuildResult) -> Result<()> {
-    let query_id = ctx.get_id();
-    let executor_settings = ExecutorSettings::try_create(&ctx.get_settings(), query_id)?;
+    let executor_settings = ExecutorSettings::try_create(&ctx)?;
     res.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
     let mut pipelines = res.sources_pipelines;
     pipelines.push(res.main_pipeline);
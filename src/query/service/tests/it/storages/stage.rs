@@ -0,0 +1,158 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_catalog::plan::RangeBlockMeta;
+use common_catalog::plan::StageTableInfo;
+use common_catalog::table::AppendMode;
+use common_exception::Result;
+use common_expression::types::number::Int32Type;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchemaRefExt;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_types::UserStageInfo;
+use common_storage::StorageFsConfig;
+use common_storage::StorageParams;
+use common_storages_stage::StageTable;
+use databend_query::interpreters::append2table;
+use databend_query::pipelines::executor::ExecutorSettings;
+use databend_query::pipelines::executor::PipelineCompleteExecutor;
+use databend_query::pipelines::processors::BlocksSource;
+use databend_query::pipelines::PipelineBuildResult;
+use databend_query::pipelines::PipelineBuilder;
+use databend_query::sessions::TableContext;
+use databend_query::sql::ColumnBinding;
+use databend_query::sql::Visibility;
+use parking_lot::Mutex;
+use tempfile::TempDir;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_stage_unload_ranges_in_name_order() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+
+    let root = TempDir::new().unwrap();
+    let storage = StorageParams::Fs(StorageFsConfig {
+        root: root.path().to_str().unwrap().to_string(),
+    });
+    let mut stage = UserStageInfo::new_external_stage(storage, "/");
+    // Every block is written into its own file.
+    stage.copy_options.max_file_size = 1;
+
+    let schema = TableSchemaRefExt::create(vec![TableField::new(
+        "a",
+        TableDataType::Number(NumberDataType::Int32),
+    )]);
+    let table = StageTable::try_create(StageTableInfo {
+        schema: schema.clone(),
+        user_stage_info: stage,
+        path: "unload".to_string(),
+        files: vec![],
+        pattern: "".to_string(),
+        files_to_copy: None,
+    })?;
+
+    // The blocks of 3 ranges as the ordered merge exchange outputs them,
+    // more than 10 blocks for each range. The blocks carry a column which is
+    // projected away when the result set is rendered.
+    let (num_ranges, num_blocks) = (3, 12);
+    let mut blocks = VecDeque::new();
+    for range in 0..num_ranges {
+        for seq in 0..num_blocks {
+            let start = (range * 1000 + seq * 10) as i32;
+            let block = DataBlock::new_from_columns(vec![
+                Int32Type::from_data(vec![-1; 5]),
+                Int32Type::from_data((start..start + 5).collect::<Vec<_>>()),
+            ]);
+            let meta = RangeBlockMeta::create(range, seq, seq + 1 == num_blocks);
+            blocks.push_back(block.add_meta(Some(meta))?);
+        }
+    }
+
+    let mut build_res = PipelineBuildResult::create();
+    let blocks = Arc::new(Mutex::new(blocks));
+    build_res.main_pipeline.add_source(
+        |output| BlocksSource::create(ctx.clone(), output, blocks.clone()),
+        1,
+    )?;
+    // The result set is rendered before the sink, as `COPY INTO <location>` does,
+    // it must keep the range of the blocks.
+    let plan_schema = DataSchemaRefExt::create(vec![
+        DataField::new("0", DataType::Number(NumberDataType::Int32)),
+        DataField::new("1", DataType::Number(NumberDataType::Int32)),
+    ]);
+    let result_columns = vec![ColumnBinding {
+        database_name: None,
+        table_name: None,
+        column_name: "a".to_string(),
+        index: 1,
+        data_type: Box::new(DataType::Number(NumberDataType::Int32)),
+        visibility: Visibility::Visible,
+    }];
+    PipelineBuilder::render_result_set(
+        &ctx.try_get_function_context()?,
+        plan_schema,
+        &result_columns,
+        &mut build_res.main_pipeline,
+        false,
+    )?;
+    append2table(
+        ctx.clone(),
+        table,
+        Arc::new(schema.into()),
+        &mut build_res,
+        false,
+        true,
+        AppendMode::Normal,
+    )?;
+
+    let executor_settings = ExecutorSettings::try_create(&ctx)?;
+    let mut pipelines = build_res.sources_pipelines;
+    pipelines.push(build_res.main_pipeline);
+    let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+    executor.execute()?;
+
+    let mut names = std::fs::read_dir(root.path().join("unload"))?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    names.sort();
+    assert_eq!(names.len(), num_ranges * num_blocks);
+
+    // Reading the files back in the order of the names gives the global order.
+    let mut values = vec![];
+    for (i, name) in names.iter().enumerate() {
+        let range_id = format!("_{:06}_{:06}.csv", i / num_blocks, i % num_blocks);
+        assert!(name.ends_with(&range_id), "unexpected file {}", name);
+
+        let content = std::fs::read_to_string(root.path().join("unload").join(name))?;
+        for line in content.lines() {
+            values.push(line.parse::<i32>().unwrap());
+        }
+    }
+
+    let mut expected = values.clone();
+    expected.sort();
+    assert_eq!(values.len(), num_ranges * num_blocks * 5);
+    assert_eq!(values, expected);
+
+    Ok(())
+}
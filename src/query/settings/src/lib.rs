@@ -106,8 +106,19 @@ impl Settings {
         Ok(ret)
     }
 
-    pub fn default_settings(tenant: &str, conf: Arc<Config>) -> Result<Arc<Settings>> {
+    /// The memory usage limit of the node, `max_server_memory_usage` of the config, or 80%
+    /// of the physical memory if it is 0.
+    pub fn max_server_memory_usage(conf: &Config) -> Result<u64> {
+        if conf.query.max_server_memory_usage != 0 {
+            return Ok(conf.query.max_server_memory_usage);
+        }
+
+        // unit of memory_info.total is kB
         let memory_info = sys_info::mem_info().map_err(ErrorCode::from_std_error)?;
+        Ok(1024 * memory_info.total * 80 / 100)
+    }
+
+    pub fn default_settings(tenant: &str, conf: Arc<Config>) -> Result<Arc<Settings>> {
         let mut num_cpus = num_cpus::get() as u64;
         if conf.query.num_cpus != 0 {
             num_cpus = conf.query.num_cpus;
@@ -116,10 +127,7 @@ impl Settings {
             num_cpus = 16;
         }
 
-        let mut default_max_memory_usage = 1024 * memory_info.total * 80 / 100;
-        if conf.query.max_server_memory_usage != 0 {
-            default_max_memory_usage = conf.query.max_server_memory_usage;
-        }
+        let default_max_memory_usage = Self::max_server_memory_usage(&conf)?;
 
         let default_max_storage_io_requests = if conf.storage.params.is_fs() {
             num_cpus
@@ -152,7 +160,6 @@ impl Settings {
             },
             // max_memory_usage
             SettingValue {
-                // unit of memory_info.total is kB
                 default_value: UserSettingValue::UInt64(default_max_memory_usage),
                 user_setting: UserSetting::create(
                     "max_memory_usage",
@@ -312,6 +319,16 @@ impl Settings {
                 desc: "The timeout in seconds for waiting for processing of async insert, default value: 100.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(600),
+                user_setting: UserSetting::create(
+                    "wait_for_exchange_sample_timeout",
+                    UserSettingValue::UInt64(600),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The timeout in seconds for waiting for the samples of all the nodes before a range or skew-aware exchange, default value: 600.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(0),
                 user_setting: UserSetting::create(
@@ -389,6 +406,17 @@ impl Settings {
                 desc: "the max number of rows each read from parquet to databend processor",
                 possible_values: None,
             },
+            #[cfg(feature = "hive")]
+            SettingValue {
+                default_value: UserSettingValue::String("static".to_owned()),
+                user_setting: UserSetting::create(
+                    "hive_partition_overwrite_mode",
+                    UserSettingValue::String("static".to_owned()),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The partitions replaced by INSERT OVERWRITE into hive table, \"static\" replaces all the partitions matching the PARTITION clause (all the partitions without it), \"dynamic\" replaces the partitions written only, default value: \"static\".",
+                possible_values: Some(vec!["static", "dynamic"]),
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(0),
                 user_setting: UserSetting::create("max_result_rows", UserSettingValue::UInt64(0)),
@@ -416,6 +444,56 @@ impl Settings {
                 desc: "If enable broadcast join, default value: 0",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1),
+                user_setting: UserSetting::create(
+                    "enable_runtime_filter",
+                    UserSettingValue::UInt64(1),
+                ),
+                level: ScopeLevel::Session,
+                desc: "If enable runtime filters generated by hash join, default value: 1",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_fragment_retry",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "If enable retrying the leaf fragments of a distributed query on the surviving nodes when a node fails, default value: 0",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1),
+                user_setting: UserSetting::create(
+                    "enable_partition_stealing",
+                    UserSettingValue::UInt64(1),
+                ),
+                level: ScopeLevel::Session,
+                desc: "If enable idle nodes taking the unread partitions of busy nodes in a distributed scan, default value: 1",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_range_partition_sort",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "If enable sorting the ranges of the sort keys on all the nodes in parallel for ORDER BY without LIMIT in cluster mode, default value: 0",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_skew_aware_shuffle",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "If enable spreading the hot keys of the build side across the nodes for the hash shuffle of inner joins, and merging the states of the hot group keys before the shuffle of aggregations, in cluster mode, default value: 0",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(2),
                 user_setting: UserSetting::create(
@@ -456,6 +534,46 @@ impl Settings {
                 desc: "Parquet decompresses buffer size. default: 2MB",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_query_result_cache",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Whether to cache the results of SELECT queries over fuse tables, default value: 0.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1024 * 1024),
+                user_setting: UserSetting::create(
+                    "query_result_cache_max_bytes",
+                    UserSettingValue::UInt64(1024 * 1024),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The max bytes of a cached query result, larger results are not cached. default: 1MB",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(256 * 1024 * 1024),
+                user_setting: UserSetting::create(
+                    "query_result_cache_capacity_bytes",
+                    UserSettingValue::UInt64(256 * 1024 * 1024),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The max bytes of all the results cached in the memory of a query node, the oldest results are evicted first. default: 256MB",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(300),
+                user_setting: UserSetting::create(
+                    "query_result_cache_ttl_secs",
+                    UserSettingValue::UInt64(300),
+                ),
+                level: ScopeLevel::Session,
+                desc: "How many seconds a cached query result is valid, default value: 300.",
+                possible_values: None,
+            },
         ];
 
         let settings: Arc<DashMap<String, SettingValue>> = Arc::new(DashMap::default());
@@ -663,6 +781,11 @@ impl Settings {
         self.try_set_u64(key, val, false)
     }
 
+    pub fn get_wait_for_exchange_sample_timeout(&self) -> Result<u64> {
+        let key = "wait_for_exchange_sample_timeout";
+        self.try_get_u64(key)
+    }
+
     pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
         static KEY: &str = "unquoted_ident_case_sensitive";
         let v = self.try_get_u64(KEY)?;
@@ -723,6 +846,36 @@ impl Settings {
         Ok(v != 0)
     }
 
+    pub fn get_enable_runtime_filter(&self) -> Result<bool> {
+        static KEY: &str = "enable_runtime_filter";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_enable_fragment_retry(&self) -> Result<bool> {
+        static KEY: &str = "enable_fragment_retry";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_enable_partition_stealing(&self) -> Result<bool> {
+        static KEY: &str = "enable_partition_stealing";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_enable_range_partition_sort(&self) -> Result<bool> {
+        static KEY: &str = "enable_range_partition_sort";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_enable_skew_aware_shuffle(&self) -> Result<bool> {
+        static KEY: &str = "enable_skew_aware_shuffle";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
     pub fn set_prefer_broadcast_join(&self, val: bool) -> Result<()> {
         static KEY: &str = "join_distribution_type";
         let v = u64::from(val);
@@ -760,6 +913,13 @@ impl Settings {
         self.try_get_u64(KEY)
     }
 
+    pub fn get_hive_partition_overwrite_mode(&self) -> Result<String> {
+        let key = "hive_partition_overwrite_mode";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+            .map(|v| v.to_lowercase())
+    }
+
     pub fn set_load_file_metadata_expire_hours(&self, val: u64) -> Result<()> {
         let key = "load_file_metadata_expire_hours";
         self.try_set_u64(key, val, false)
@@ -776,6 +936,33 @@ impl Settings {
             .and_then(|v| v.user_setting.value.as_string())
     }
 
+    pub fn get_enable_query_result_cache(&self) -> Result<bool> {
+        static KEY: &str = "enable_query_result_cache";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn set_enable_query_result_cache(&self, val: bool) -> Result<()> {
+        static KEY: &str = "enable_query_result_cache";
+        let v = u64::from(val);
+        self.try_set_u64(KEY, v, false)
+    }
+
+    pub fn get_query_result_cache_max_bytes(&self) -> Result<u64> {
+        let key = "query_result_cache_max_bytes";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_query_result_cache_capacity_bytes(&self) -> Result<u64> {
+        let key = "query_result_cache_capacity_bytes";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_query_result_cache_ttl_secs(&self) -> Result<u64> {
+        let key = "query_result_cache_ttl_secs";
+        self.try_get_u64(key)
+    }
+
     pub fn has_setting(&self, key: &str) -> bool {
         self.settings.get(key).is_some()
     }
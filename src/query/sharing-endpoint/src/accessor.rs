@@ -12,17 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::marker::PhantomData;
+
 use common_base::base::GlobalInstance;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::TableSchema;
 use common_storage::init_operator;
 use common_storage::StorageParams;
 use opendal::Operator;
+use serde::de::DeserializeOwned;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::SegmentInfoVersion;
+use storages_common_table_meta::meta::SnapshotVersion;
+use storages_common_table_meta::meta::TableSnapshot;
 use time::Duration;
+use time::OffsetDateTime;
 
 use crate::configs::Config;
+use crate::delta_sharing;
 use crate::models;
 use crate::models::PresignFileResponse;
 use crate::models::SharedTableResponse;
+use crate::models::TableSpec;
+
+// the hint file of a fuse table, which contains the location of its latest snapshot
+const LAST_SNAPSHOT_HINT: &str = "last_snapshot_location_hint";
 
 #[derive(Clone)]
 pub struct SharingAccessor {
@@ -93,13 +109,128 @@ impl SharingAccessor {
         &self,
         input: &models::LambdaInput,
     ) -> Result<Option<SharedTableResponse>> {
-        let sharing_accessor = Self::instance();
-        let path = sharing_accessor.get_share_location();
-        let data = sharing_accessor.op.object(&path).read().await?;
-        let share_specs: models::SharingConfig = serde_json::from_slice(data.as_slice())?;
+        let share_specs = Self::instance().get_sharing_config().await?;
         share_specs.get_tables(input)
     }
 
+    // read all the share specs of the tenant
+    pub async fn get_sharing_config(&self) -> Result<models::SharingConfig> {
+        let path = self.get_share_location();
+        let data = self.op.object(&path).read().await?;
+        Ok(serde_json::from_slice(data.as_slice())?)
+    }
+
+    // the location of the table relative to the root of the operator
+    fn table_prefix(&self, table_location: &str) -> String {
+        let loc_prefix = table_location.trim_matches('/');
+        let root = self.get_root();
+        loc_prefix
+            .strip_prefix(root.as_str())
+            .unwrap_or(loc_prefix)
+            .to_string()
+    }
+
+    /// Read the latest snapshot of the shared table, or the snapshot of the given version.
+    ///
+    /// Returns `None` if nothing has been written into the table yet.
+    pub async fn read_table_snapshot(
+        &self,
+        table: &TableSpec,
+        version: Option<i64>,
+    ) -> Result<Option<TableSnapshot>> {
+        let prefix = self.table_prefix(&table.location);
+        let hint = format!("{}/{}", prefix, LAST_SNAPSHOT_HINT);
+        let location = match self.op.object(&hint).read().await {
+            Ok(data) => String::from_utf8_lossy(&data).to_string(),
+            Err(e) if e.kind() == opendal::ErrorKind::ObjectNotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut path = format!("{}/{}", prefix, truncate_root(self.get_root(), location));
+        loop {
+            let snapshot = self.read_snapshot(&path).await?;
+            let snapshot_version = delta_sharing::snapshot_version(&snapshot);
+            match version {
+                Some(version) if version < snapshot_version => match snapshot.prev_snapshot_id {
+                    Some((id, format_version)) => {
+                        let suffix = match format_version {
+                            0 => "".to_string(),
+                            v => format!("_v{}.json", v),
+                        };
+                        path = format!("{}/_ss/{}{}", prefix, id.simple(), suffix);
+                    }
+                    None => break,
+                },
+                Some(version) if version > snapshot_version => break,
+                _ => return Ok(Some(snapshot)),
+            }
+        }
+        Err(ErrorCode::TableVersionMismatched(format!(
+            "version {} of table {} is not found",
+            version.unwrap_or_default(),
+            table.name
+        )))
+    }
+
+    async fn read_snapshot(&self, path: &str) -> Result<TableSnapshot> {
+        let data = self.op.object(path).read().await?;
+        let format_version = if path.ends_with("_v2.json") {
+            2
+        } else if path.ends_with("_v1.json") {
+            1
+        } else {
+            0
+        };
+        let snapshot = match SnapshotVersion::try_from(format_version)? {
+            SnapshotVersion::V2(v) => load_by_version(&data, &v)?,
+            SnapshotVersion::V1(v) => load_by_version(&data, &v)?.into(),
+            SnapshotVersion::V0(v) => load_by_version(&data, &v)?.into(),
+        };
+        Ok(snapshot)
+    }
+
+    pub async fn read_segment(
+        &self,
+        table: &TableSpec,
+        location: &Location,
+        schema: &TableSchema,
+    ) -> Result<SegmentInfo> {
+        let prefix = self.table_prefix(&table.location);
+        let path = format!(
+            "{}/{}",
+            prefix,
+            truncate_root(self.get_root(), location.0.clone())
+        );
+        let data = self.op.object(&path).read().await?;
+        let segment = match SegmentInfoVersion::try_from(location.1)? {
+            SegmentInfoVersion::V2(v) => load_by_version(&data, &v)?,
+            SegmentInfoVersion::V1(v) => {
+                SegmentInfo::from_v1(load_by_version(&data, &v)?, &schema.leaf_fields())
+            }
+            SegmentInfoVersion::V0(v) => {
+                SegmentInfo::from_v0(load_by_version(&data, &v)?, &schema.leaf_fields())
+            }
+        };
+        Ok(segment)
+    }
+
+    // presign a data block of the shared table for reading
+    pub fn presign_block(&self, table: &TableSpec, location: &str) -> Result<(String, i64)> {
+        let prefix = self.table_prefix(&table.location);
+        let path = format!(
+            "{}/{}",
+            prefix,
+            truncate_root(self.get_root(), location.to_string())
+        );
+        let expire = Duration::hours(1);
+        let req = self.op.object(&path).presign_read(expire)?;
+        let expiration = OffsetDateTime::now_utc() + expire;
+        Ok((
+            req.uri().to_string(),
+            (expiration.unix_timestamp_nanos() / 1_000_000) as i64,
+        ))
+    }
+
     // presign_file would be separated into two steps:
     // 1. fetch the table location
     // 2. form the final path and presign it
@@ -145,3 +276,7 @@ impl SharingAccessor {
         };
     }
 }
+
+fn load_by_version<T: DeserializeOwned>(data: &[u8], _v: &PhantomData<T>) -> Result<T> {
+    Ok(serde_json::from_slice::<T>(data)?)
+}
@@ -22,6 +22,9 @@ pub struct Config {
     pub tenant: String,
     pub share_endpoint_address: String,
     pub storage: StorageConfig,
+    /// The comma separated `tenant=token` pairs of the Delta Sharing recipients,
+    /// the Delta Sharing protocol is not served if it's empty.
+    pub delta_sharing_recipients: String,
 }
 
 impl Config {
@@ -55,6 +58,7 @@ impl Default for Config {
             tenant: "".to_string(),
             share_endpoint_address: "".to_string(),
             storage: StorageConfig::default(),
+            delta_sharing_recipients: "".to_string(),
         }
     }
 }
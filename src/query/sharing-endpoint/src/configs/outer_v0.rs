@@ -33,6 +33,9 @@ pub struct Config {
     // Storage backend config.
     #[clap(flatten)]
     pub storage: common_config::StorageConfig,
+    /// The comma separated `tenant=token` pairs of the Delta Sharing recipients.
+    #[clap(long, default_value = "")]
+    pub delta_sharing_recipients: String,
 }
 
 impl Default for Config {
@@ -47,6 +50,7 @@ impl From<Config> for InnerConfig {
             tenant: x.tenant,
             share_endpoint_address: x.share_endpoint_address,
             storage: x.storage.try_into().expect("StorageConfig"),
+            delta_sharing_recipients: x.delta_sharing_recipients,
         }
     }
 }
@@ -57,6 +61,7 @@ impl From<InnerConfig> for Config {
             tenant: inner.tenant,
             share_endpoint_address: inner.share_endpoint_address,
             storage: inner.storage.into(),
+            delta_sharing_recipients: inner.delta_sharing_recipients,
         }
     }
 }
@@ -0,0 +1,438 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The models of the open [Delta Sharing protocol](https://github.com/delta-io/delta-sharing/blob/main/PROTOCOL.md),
+//! and the conversions from the fuse table snapshots to them.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::TableSnapshot;
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DeltaShare {
+    pub name: String,
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DeltaSchema {
+    pub name: String,
+    pub share: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeltaTable {
+    pub name: String,
+    pub schema: String,
+    pub share: String,
+    pub share_id: String,
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ListResponse<T> {
+    pub items: Vec<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct GetShareResponse {
+    pub share: DeltaShare,
+}
+
+/// The query parameters of the list APIs.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageParams {
+    pub max_results: Option<usize>,
+    pub page_token: Option<String>,
+}
+
+/// The body of `POST .../query`, the hints may be ignored by the server.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryTableRequest {
+    #[serde(default)]
+    pub predicate_hints: Vec<String>,
+    pub limit_hint: Option<u64>,
+    pub version: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Protocol {
+    pub min_reader_version: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Format {
+    pub provider: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    pub id: String,
+    pub name: String,
+    pub format: Format,
+    pub schema_string: String,
+    pub partition_columns: Vec<String>,
+    pub num_files: u64,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeltaFile {
+    pub url: String,
+    pub id: String,
+    pub partition_values: HashMap<String, String>,
+    pub size: u64,
+    /// The statistics of the file, which is a JSON string.
+    pub stats: String,
+    pub expiration_timestamp: i64,
+}
+
+/// A line of the responses of `metadata` and `query`, which are newline-delimited JSON.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DeltaLine {
+    Protocol(Protocol),
+    MetaData(Metadata),
+    File(DeltaFile),
+}
+
+impl DeltaLine {
+    pub fn protocol() -> Self {
+        DeltaLine::Protocol(Protocol {
+            min_reader_version: 1,
+        })
+    }
+
+    pub fn metadata(table_id: u64, table_name: &str, snapshot: &TableSnapshot) -> Self {
+        DeltaLine::MetaData(Metadata {
+            id: table_id.to_string(),
+            name: table_name.to_string(),
+            format: Format {
+                provider: "parquet".to_string(),
+            },
+            schema_string: spark_schema_string(&snapshot.schema),
+            partition_columns: vec![],
+            num_files: snapshot.summary.block_count,
+            size: snapshot.summary.compressed_byte_size,
+        })
+    }
+}
+
+/// Write the lines as newline-delimited JSON.
+pub fn to_ndjson(lines: &[DeltaLine]) -> Result<String> {
+    let mut body = String::new();
+    for line in lines {
+        body.push_str(&serde_json::to_string(line)?);
+        body.push('\n');
+    }
+    Ok(body)
+}
+
+/// The version of a table is the timestamp of its snapshot in milliseconds,
+/// which grows with the snapshots of the table.
+pub fn snapshot_version(snapshot: &TableSnapshot) -> i64 {
+    snapshot
+        .timestamp
+        .map(|ts| ts.timestamp_millis())
+        .unwrap_or_default()
+}
+
+/// Return a page of the items, the page token is the offset of the next page.
+pub fn paginate<T>(items: Vec<T>, params: &PageParams) -> Result<ListResponse<T>> {
+    let offset = match &params.page_token {
+        Some(token) if !token.is_empty() => token
+            .parse::<usize>()
+            .map_err(|_| ErrorCode::BadArguments(format!("invalid page token: {}", token)))?,
+        _ => 0,
+    };
+    let limit = params.max_results.unwrap_or(usize::MAX);
+    let total = items.len();
+    let items = items
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .collect::<Vec<_>>();
+    let next = offset + items.len();
+    Ok(ListResponse {
+        items,
+        next_page_token: if next < total {
+            Some(next.to_string())
+        } else {
+            None
+        },
+    })
+}
+
+/// Convert the schema into the JSON form of a Spark `StructType`.
+pub fn spark_schema_string(schema: &TableSchema) -> String {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| spark_field(field.name(), field.data_type()))
+        .collect::<Vec<_>>();
+    json!({ "type": "struct", "fields": fields }).to_string()
+}
+
+fn spark_field(name: &str, data_type: &TableDataType) -> Value {
+    let (ty, nullable) = spark_type(data_type);
+    json!({ "name": name, "type": ty, "nullable": nullable, "metadata": {} })
+}
+
+/// Returns the Spark type of the data type, and whether it's nullable.
+fn spark_type(data_type: &TableDataType) -> (Value, bool) {
+    let ty = match data_type {
+        TableDataType::Nullable(inner) => return (spark_type(inner).0, true),
+        TableDataType::Null => return (json!("void"), true),
+        TableDataType::EmptyArray => json!({
+            "type": "array",
+            "elementType": "void",
+            "containsNull": true,
+        }),
+        TableDataType::Boolean => json!("boolean"),
+        TableDataType::String => json!("string"),
+        TableDataType::Number(num) => json!(match num {
+            NumberDataType::Int8 => "byte",
+            NumberDataType::Int16 | NumberDataType::UInt8 => "short",
+            NumberDataType::Int32 | NumberDataType::UInt16 => "integer",
+            NumberDataType::Int64 | NumberDataType::UInt32 => "long",
+            NumberDataType::UInt64 => "decimal(20,0)",
+            NumberDataType::Float32 => "float",
+            NumberDataType::Float64 => "double",
+        }),
+        TableDataType::Timestamp => json!("timestamp"),
+        TableDataType::Date => json!("date"),
+        TableDataType::Array(inner) => {
+            let (element, contains_null) = spark_type(inner);
+            json!({ "type": "array", "elementType": element, "containsNull": contains_null })
+        }
+        TableDataType::Map(inner) => match inner.as_ref() {
+            TableDataType::Tuple { fields_type, .. } if fields_type.len() == 2 => {
+                let (key, _) = spark_type(&fields_type[0]);
+                let (value, value_contains_null) = spark_type(&fields_type[1]);
+                json!({
+                    "type": "map",
+                    "keyType": key,
+                    "valueType": value,
+                    "valueContainsNull": value_contains_null,
+                })
+            }
+            _ => json!("binary"),
+        },
+        TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } => {
+            let fields = fields_name
+                .iter()
+                .zip(fields_type)
+                .map(|(name, ty)| spark_field(name, ty))
+                .collect::<Vec<_>>();
+            json!({ "type": "struct", "fields": fields })
+        }
+        // Variants are stored as JSONB binaries.
+        TableDataType::Variant => json!("binary"),
+    };
+    (ty, false)
+}
+
+/// Build the statistics of a block in the form of Delta, only the top level columns
+/// are included.
+pub fn file_stats(schema: &TableSchema, block: &BlockMeta) -> Value {
+    let mut min_values = Map::new();
+    let mut max_values = Map::new();
+    let mut null_count = Map::new();
+    // The statistics are keyed by the index of the leaf columns.
+    for (column_id, leaf) in schema.leaf_fields().iter().enumerate() {
+        if schema.field_with_name(leaf.name()).is_err() {
+            continue;
+        }
+        if let Some(stats) = block.col_stats.get(&(column_id as u32)) {
+            if let (Some(min), Some(max)) = (scalar_to_json(&stats.min), scalar_to_json(&stats.max))
+            {
+                min_values.insert(leaf.name().clone(), min);
+                max_values.insert(leaf.name().clone(), max);
+            }
+            null_count.insert(leaf.name().clone(), json!(stats.null_count));
+        }
+    }
+    json!({
+        "numRecords": block.row_count,
+        "minValues": min_values,
+        "maxValues": max_values,
+        "nullCount": null_count,
+    })
+}
+
+fn scalar_to_json(scalar: &Scalar) -> Option<Value> {
+    let value = match scalar {
+        Scalar::Boolean(v) => json!(v),
+        Scalar::String(v) => json!(String::from_utf8_lossy(v)),
+        Scalar::Number(num) => match num {
+            NumberScalar::UInt8(v) => json!(v),
+            NumberScalar::UInt16(v) => json!(v),
+            NumberScalar::UInt32(v) => json!(v),
+            NumberScalar::UInt64(v) => json!(v),
+            NumberScalar::Int8(v) => json!(v),
+            NumberScalar::Int16(v) => json!(v),
+            NumberScalar::Int32(v) => json!(v),
+            NumberScalar::Int64(v) => json!(v),
+            NumberScalar::Float32(v) if v.is_finite() => json!(v.0),
+            NumberScalar::Float64(v) if v.is_finite() => json!(v.0),
+            _ => return None,
+        },
+        Scalar::Date(days) => {
+            let date = NaiveDate::from_num_days_from_ce_opt(*days + 719163)?;
+            json!(date.format("%Y-%m-%d").to_string())
+        }
+        Scalar::Timestamp(micros) => {
+            let ts = NaiveDateTime::from_timestamp_opt(
+                micros.div_euclid(1_000_000),
+                (micros.rem_euclid(1_000_000) * 1000) as u32,
+            )?;
+            json!(ts.format("%Y-%m-%dT%H:%M:%S%.6fZ").to_string())
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// A predicate hint like `a >= 10` or `date = '2023-01-01'`.
+///
+/// The hints which can't be parsed are ignored, the protocol allows the server
+/// to return more files than the hints require.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PredicateHint {
+    pub column: String,
+    pub op: String,
+    pub value: Value,
+}
+
+impl PredicateHint {
+    pub fn parse(hint: &str) -> Option<PredicateHint> {
+        const OPS: [&str; 7] = [">=", "<=", "<>", "!=", "=", ">", "<"];
+        let (pos, op) = OPS
+            .iter()
+            .filter_map(|op| hint.find(op).map(|pos| (pos, *op)))
+            .min_by_key(|(pos, op)| (*pos, usize::MAX - op.len()))?;
+
+        let column = hint[..pos].trim().trim_matches('`').trim_matches('"');
+        let literal = hint[pos + op.len()..].trim();
+        if column.is_empty() || column.contains(char::is_whitespace) {
+            return None;
+        }
+        let value = if literal.len() >= 2 && literal.starts_with('\'') && literal.ends_with('\'') {
+            json!(&literal[1..literal.len() - 1])
+        } else {
+            json!(literal.parse::<f64>().ok()?)
+        };
+
+        Some(PredicateHint {
+            column: column.to_string(),
+            op: op.to_string(),
+            value,
+        })
+    }
+
+    /// Returns false only if the statistics prove no row of the file matches the hint.
+    ///
+    /// The statistics are compared by the type of the column in `schema`, the dates and
+    /// timestamps are parsed from the hint literal. The columns of the other types, and
+    /// the literals which can't be parsed, are not pruned.
+    pub fn may_match(&self, schema: &TableSchema, stats: &Value) -> bool {
+        let Ok(field) = schema.field_with_name(&self.column) else {
+            return true;
+        };
+        let data_type = field.data_type().remove_nullable();
+        let min = &stats["minValues"][&self.column];
+        let max = &stats["maxValues"][&self.column];
+        let (Some(min), Some(max)) = (
+            compare(&data_type, min, &self.value),
+            compare(&data_type, max, &self.value),
+        ) else {
+            return true;
+        };
+        match self.op.as_str() {
+            "=" => min != Ordering::Greater && max != Ordering::Less,
+            "<" => min == Ordering::Less,
+            "<=" => min != Ordering::Greater,
+            ">" => max == Ordering::Greater,
+            ">=" => max != Ordering::Less,
+            _ => !(min == Ordering::Equal && max == Ordering::Equal),
+        }
+    }
+}
+
+fn compare(data_type: &TableDataType, stat: &Value, value: &Value) -> Option<Ordering> {
+    match (data_type, stat, value) {
+        (TableDataType::Number(_), Value::Number(a), Value::Number(b)) => {
+            a.as_f64()?.partial_cmp(&b.as_f64()?)
+        }
+        (TableDataType::String, Value::String(a), Value::String(b)) => {
+            Some(a.as_str().cmp(b.as_str()))
+        }
+        (TableDataType::Boolean, Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        (TableDataType::Date, Value::String(a), Value::String(b)) => {
+            let a = NaiveDate::parse_from_str(a, "%Y-%m-%d").ok()?;
+            let b = NaiveDate::parse_from_str(b, "%Y-%m-%d").ok()?;
+            Some(a.cmp(&b))
+        }
+        (TableDataType::Timestamp, Value::String(a), Value::String(b)) => {
+            let a = NaiveDateTime::parse_from_str(a, "%Y-%m-%dT%H:%M:%S%.fZ").ok()?;
+            Some(a.cmp(&parse_timestamp(b)?))
+        }
+        _ => None,
+    }
+}
+
+/// Parses the literal of a timestamp hint, like `'2023-01-01'` or `'2023-01-01 10:00:00'`.
+fn parse_timestamp(literal: &str) -> Option<NaiveDateTime> {
+    const FORMATS: [&str; 3] = [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.fZ",
+    ];
+    FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(literal, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(literal, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)
+        })
+}
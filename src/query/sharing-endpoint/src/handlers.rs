@@ -12,16 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_exception::ErrorCode;
 use models::Credentials;
 use models::RequestFile;
 use poem::error::BadRequest;
 use poem::error::Result as PoemResult;
+use poem::http::StatusCode;
 use poem::web::Json;
 use poem::web::Path;
+use poem::web::Query;
+use poem::Response;
 
 use crate::accessor::SharingAccessor;
+use crate::delta_sharing;
+use crate::delta_sharing::DeltaFile;
+use crate::delta_sharing::DeltaLine;
+use crate::delta_sharing::DeltaSchema;
+use crate::delta_sharing::DeltaShare;
+use crate::delta_sharing::DeltaTable;
+use crate::delta_sharing::GetShareResponse;
+use crate::delta_sharing::ListResponse;
+use crate::delta_sharing::PageParams;
+use crate::delta_sharing::PredicateHint;
+use crate::delta_sharing::QueryTableRequest;
 use crate::models;
 use crate::models::PresignFileResponse;
+use crate::models::ShareSpec;
 
 #[poem::handler]
 pub async fn presign_files(
@@ -43,3 +59,273 @@ pub async fn presign_files(
         Err(e) => Err(BadRequest(e)),
     };
 }
+
+// The handlers of the open Delta Sharing protocol, the bearer token is the recipient tenant.
+
+const DELTA_TABLE_VERSION: &str = "delta-table-version";
+
+fn delta_error(e: ErrorCode) -> poem::Error {
+    let status = match e.code() {
+        ErrorCode::UNKNOWN_SHARE | ErrorCode::UNKNOWN_DATABASE | ErrorCode::UNKNOWN_TABLE => {
+            StatusCode::NOT_FOUND
+        }
+        ErrorCode::BAD_ARGUMENTS | ErrorCode::TABLE_VERSION_MISMATCHED => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    poem::Error::from_string(e.message(), status)
+}
+
+fn delta_tables(share: &ShareSpec) -> Vec<DeltaTable> {
+    let mut tables = share
+        .tables
+        .iter()
+        .map(|table| DeltaTable {
+            name: table.name.clone(),
+            schema: share.database.name.clone(),
+            share: share.name.clone(),
+            share_id: share.share_id.to_string(),
+            id: table.table_id.to_string(),
+        })
+        .collect::<Vec<_>>();
+    tables.sort_by(|a, b| a.name.cmp(&b.name));
+    tables
+}
+
+#[poem::handler]
+pub async fn list_shares(
+    credentials: &Credentials,
+    Query(params): Query<PageParams>,
+) -> PoemResult<Json<ListResponse<DeltaShare>>> {
+    let config = SharingAccessor::instance()
+        .get_sharing_config()
+        .await
+        .map_err(delta_error)?;
+    let shares = config
+        .list_shares(&credentials.token)
+        .into_iter()
+        .map(|share| DeltaShare {
+            name: share.name.clone(),
+            id: share.share_id.to_string(),
+        })
+        .collect();
+    Ok(Json(
+        delta_sharing::paginate(shares, &params).map_err(delta_error)?,
+    ))
+}
+
+#[poem::handler]
+pub async fn get_share(
+    credentials: &Credentials,
+    Path(share_name): Path<String>,
+) -> PoemResult<Json<GetShareResponse>> {
+    let config = SharingAccessor::instance()
+        .get_sharing_config()
+        .await
+        .map_err(delta_error)?;
+    let share = config
+        .get_share(&share_name, &credentials.token)
+        .map_err(delta_error)?;
+    Ok(Json(GetShareResponse {
+        share: DeltaShare {
+            name: share.name.clone(),
+            id: share.share_id.to_string(),
+        },
+    }))
+}
+
+#[poem::handler]
+pub async fn list_schemas(
+    credentials: &Credentials,
+    Path(share_name): Path<String>,
+    Query(params): Query<PageParams>,
+) -> PoemResult<Json<ListResponse<DeltaSchema>>> {
+    let config = SharingAccessor::instance()
+        .get_sharing_config()
+        .await
+        .map_err(delta_error)?;
+    let share = config
+        .get_share(&share_name, &credentials.token)
+        .map_err(delta_error)?;
+    let schemas = vec![DeltaSchema {
+        name: share.database.name.clone(),
+        share: share.name.clone(),
+    }];
+    Ok(Json(
+        delta_sharing::paginate(schemas, &params).map_err(delta_error)?,
+    ))
+}
+
+#[poem::handler]
+pub async fn list_tables(
+    credentials: &Credentials,
+    Path((share_name, schema_name)): Path<(String, String)>,
+    Query(params): Query<PageParams>,
+) -> PoemResult<Json<ListResponse<DeltaTable>>> {
+    let config = SharingAccessor::instance()
+        .get_sharing_config()
+        .await
+        .map_err(delta_error)?;
+    let share = config
+        .get_share(&share_name, &credentials.token)
+        .map_err(delta_error)?;
+    if share.database.name != schema_name {
+        return Err(delta_error(ErrorCode::UnknownDatabase(format!(
+            "schema {} does not exist in share {}",
+            schema_name, share_name
+        ))));
+    }
+    Ok(Json(
+        delta_sharing::paginate(delta_tables(share), &params).map_err(delta_error)?,
+    ))
+}
+
+#[poem::handler]
+pub async fn list_all_tables(
+    credentials: &Credentials,
+    Path(share_name): Path<String>,
+    Query(params): Query<PageParams>,
+) -> PoemResult<Json<ListResponse<DeltaTable>>> {
+    let config = SharingAccessor::instance()
+        .get_sharing_config()
+        .await
+        .map_err(delta_error)?;
+    let share = config
+        .get_share(&share_name, &credentials.token)
+        .map_err(delta_error)?;
+    Ok(Json(
+        delta_sharing::paginate(delta_tables(share), &params).map_err(delta_error)?,
+    ))
+}
+
+#[poem::handler]
+pub async fn table_version(
+    credentials: &Credentials,
+    Path((share_name, schema_name, table_name)): Path<(String, String, String)>,
+) -> PoemResult<Response> {
+    let accessor = SharingAccessor::instance();
+    let config = accessor.get_sharing_config().await.map_err(delta_error)?;
+    let table = config
+        .get_share(&share_name, &credentials.token)
+        .and_then(|share| share.get_table(&schema_name, &table_name))
+        .map_err(delta_error)?;
+    let snapshot = accessor
+        .read_table_snapshot(table, None)
+        .await
+        .map_err(delta_error)?;
+    let version = snapshot
+        .as_ref()
+        .map(delta_sharing::snapshot_version)
+        .unwrap_or_default();
+    Ok(Response::builder()
+        .header(DELTA_TABLE_VERSION, version.to_string())
+        .finish())
+}
+
+#[poem::handler]
+pub async fn table_metadata(
+    credentials: &Credentials,
+    Path((share_name, schema_name, table_name)): Path<(String, String, String)>,
+) -> PoemResult<Response> {
+    query_delta_table(
+        &credentials.token,
+        &share_name,
+        &schema_name,
+        &table_name,
+        None,
+    )
+    .await
+    .map_err(delta_error)
+}
+
+#[poem::handler]
+pub async fn query_table(
+    credentials: &Credentials,
+    Path((share_name, schema_name, table_name)): Path<(String, String, String)>,
+    Json(request): Json<QueryTableRequest>,
+) -> PoemResult<Response> {
+    query_delta_table(
+        &credentials.token,
+        &share_name,
+        &schema_name,
+        &table_name,
+        Some(request),
+    )
+    .await
+    .map_err(delta_error)
+}
+
+// Returns the protocol and metadata of the table, and the files of it if `request` is given.
+async fn query_delta_table(
+    tenant_id: &str,
+    share_name: &str,
+    schema_name: &str,
+    table_name: &str,
+    request: Option<QueryTableRequest>,
+) -> common_exception::Result<Response> {
+    let accessor = SharingAccessor::instance();
+    let config = accessor.get_sharing_config().await?;
+    let table = config
+        .get_share(share_name, tenant_id)?
+        .get_table(schema_name, table_name)?;
+
+    let version = request.as_ref().and_then(|r| r.version);
+    let snapshot = accessor
+        .read_table_snapshot(table, version)
+        .await?
+        .ok_or_else(|| {
+            ErrorCode::UnknownTable(format!(
+                "table {}.{} has no data yet",
+                schema_name, table_name
+            ))
+        })?;
+
+    let mut lines = vec![
+        DeltaLine::protocol(),
+        DeltaLine::metadata(table.table_id, &table.name, &snapshot),
+    ];
+
+    if let Some(request) = request {
+        let hints = request
+            .predicate_hints
+            .iter()
+            .filter_map(|hint| PredicateHint::parse(hint))
+            .collect::<Vec<_>>();
+        let mut num_records = 0;
+        'segments: for location in &snapshot.segments {
+            let segment = accessor
+                .read_segment(table, location, &snapshot.schema)
+                .await?;
+            for block in &segment.blocks {
+                if matches!(request.limit_hint, Some(limit) if num_records >= limit) {
+                    break 'segments;
+                }
+                let stats = delta_sharing::file_stats(&snapshot.schema, block);
+                if !hints
+                    .iter()
+                    .all(|hint| hint.may_match(&snapshot.schema, &stats))
+                {
+                    continue;
+                }
+                let (url, expiration_timestamp) =
+                    accessor.presign_block(table, &block.location.0)?;
+                num_records += block.row_count;
+                lines.push(DeltaLine::File(DeltaFile {
+                    url,
+                    id: block.location.0.clone(),
+                    partition_values: Default::default(),
+                    size: block.file_size,
+                    stats: stats.to_string(),
+                    expiration_timestamp,
+                }));
+            }
+        }
+    }
+
+    Ok(Response::builder()
+        .header(
+            DELTA_TABLE_VERSION,
+            delta_sharing::snapshot_version(&snapshot).to_string(),
+        )
+        .content_type("application/x-ndjson")
+        .body(delta_sharing::to_ndjson(&lines)?))
+}
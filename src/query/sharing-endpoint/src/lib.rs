@@ -16,6 +16,7 @@
 
 pub mod accessor;
 pub mod configs;
+pub mod delta_sharing;
 pub mod handlers;
 pub mod middlewares;
 pub mod models;
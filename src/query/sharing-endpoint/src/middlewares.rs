@@ -12,8 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
 use poem::async_trait;
+use poem::http::StatusCode;
 use poem::Endpoint;
+use poem::Error;
 use poem::IntoResponse;
 use poem::Middleware;
 use poem::Request;
@@ -42,34 +48,117 @@ impl<E: Endpoint> Endpoint for SharingAuthImpl<E> {
     // for production usage, we need to implement a middleware with JWT authentication
     async fn call(&self, mut req: Request) -> Result<Self::Output> {
         // decode auth header from bearer base64
-        let auth_header = req
+        let tenant = bearer_token(&req)
+            .and_then(|token| base64::decode(token).ok())
+            .and_then(|tenant| String::from_utf8(tenant).ok())
+            .ok_or_else(|| Error::from_status(StatusCode::UNAUTHORIZED))?;
+        req.extensions_mut().insert(Credentials { token: tenant });
+        call_json_endpoint(&self.0, req).await
+    }
+}
+
+/// Authenticates the recipients of the open Delta Sharing protocol by the bearer tokens
+/// issued to them, requests without a valid token are rejected with 401.
+pub struct DeltaSharingAuth {
+    /// The recipient tenant of each bearer token.
+    recipients: Arc<HashMap<String, String>>,
+}
+
+impl DeltaSharingAuth {
+    /// Create from the comma separated `tenant=token` pairs, a tenant may have several tokens.
+    pub fn try_create(recipients: &str) -> common_exception::Result<Self> {
+        let mut tokens = HashMap::new();
+        for pair in recipients
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            match pair.split_once('=') {
+                Some((tenant, token)) if !tenant.is_empty() && !token.is_empty() => {
+                    tokens.insert(token.to_string(), tenant.to_string());
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidConfig(format!(
+                        "invalid delta sharing recipient '{}', expect 'tenant=token'",
+                        pair
+                    )));
+                }
+            }
+        }
+        Ok(DeltaSharingAuth {
+            recipients: Arc::new(tokens),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recipients.is_empty()
+    }
+
+    /// Returns the recipient tenant of the `Authorization` header.
+    pub fn recipient(&self, authorization: Option<&str>) -> Option<&str> {
+        let token = authorization?.strip_prefix("Bearer ")?.trim();
+        self.recipients.get(token).map(|tenant| tenant.as_str())
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for DeltaSharingAuth {
+    type Output = DeltaSharingAuthImpl<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        DeltaSharingAuthImpl {
+            ep,
+            auth: DeltaSharingAuth {
+                recipients: self.recipients.clone(),
+            },
+        }
+    }
+}
+
+pub struct DeltaSharingAuthImpl<E> {
+    ep: E,
+    auth: DeltaSharingAuth,
+}
+
+#[async_trait]
+impl<E: Endpoint> Endpoint for DeltaSharingAuthImpl<E> {
+    type Output = Response;
+
+    async fn call(&self, mut req: Request) -> Result<Self::Output> {
+        let authorization = req
             .headers()
             .get("Authorization")
-            .unwrap()
-            .to_str()
-            .unwrap();
-        let auth_header = auth_header.split(' ').collect::<Vec<&str>>();
-        let auth_header = auth_header[1];
-        let auth_header = base64::decode(auth_header).unwrap();
-        let auth_header = String::from_utf8(auth_header).unwrap();
-        req.extensions_mut()
-            .insert(Credentials { token: auth_header });
-        // add json content type if not provided
-        if req.headers().get("Content-Type").is_none() {
-            req.headers_mut()
-                .insert("Content-Type", "application/json".parse().unwrap());
-        }
+            .and_then(|v| v.to_str().ok());
+        let tenant = self
+            .auth
+            .recipient(authorization)
+            .ok_or_else(|| Error::from_status(StatusCode::UNAUTHORIZED))?
+            .to_string();
+        req.extensions_mut().insert(Credentials { token: tenant });
+        call_json_endpoint(&self.ep, req).await
+    }
+}
 
-        let res = self.0.call(req).await;
-        match res {
-            Ok(resp) => {
-                let resp = resp.into_response();
-                Ok(resp)
-            }
-            Err(err) => {
-                println!("err: {:?}", err);
-                Err(err)
-            }
+fn bearer_token(req: &Request) -> Option<&str> {
+    let auth_header = req.headers().get("Authorization")?.to_str().ok()?;
+    auth_header.strip_prefix("Bearer ").map(str::trim)
+}
+
+async fn call_json_endpoint<E: Endpoint>(ep: &E, mut req: Request) -> Result<Response> {
+    // add json content type if not provided
+    if req.headers().get("Content-Type").is_none() {
+        req.headers_mut()
+            .insert("Content-Type", "application/json".parse().unwrap());
+    }
+
+    let res = ep.call(req).await;
+    match res {
+        Ok(resp) => {
+            let resp = resp.into_response();
+            Ok(resp)
+        }
+        Err(err) => {
+            println!("err: {:?}", err);
+            Err(err)
         }
     }
 }
@@ -173,6 +173,52 @@ impl SharingConfig {
     }
 }
 
+impl SharingConfig {
+    /// Get the share, if the tenant is allowed to access it.
+    pub fn get_share(&self, share_name: &str, tenant_id: &str) -> Result<&ShareSpec> {
+        match self.share_specs.get(share_name) {
+            Some(share) if share.tenants.iter().any(|t| t == tenant_id) => Ok(share),
+            // Don't tell the tenant whether the share exists or not.
+            _ => Err(ErrorCode::UnknownShare(format!(
+                "share {} does not exist or tenant {} is not allowed to access it",
+                share_name, tenant_id
+            ))),
+        }
+    }
+
+    /// All the shares the tenant is allowed to access, ordered by name.
+    pub fn list_shares(&self, tenant_id: &str) -> Vec<&ShareSpec> {
+        let mut shares = self
+            .share_specs
+            .values()
+            .filter(|share| share.tenants.iter().any(|t| t == tenant_id))
+            .collect::<Vec<_>>();
+        shares.sort_by(|a, b| a.name.cmp(&b.name));
+        shares
+    }
+}
+
+impl ShareSpec {
+    /// Get the table of the share, a share has only one schema, which is the shared database.
+    pub fn get_table(&self, schema_name: &str, table_name: &str) -> Result<&TableSpec> {
+        if schema_name != self.database.name {
+            return Err(ErrorCode::UnknownDatabase(format!(
+                "schema {} does not exist in share {}",
+                schema_name, self.name
+            )));
+        }
+        self.tables
+            .iter()
+            .find(|table| table.name == table_name)
+            .ok_or_else(|| {
+                ErrorCode::UnknownTable(format!(
+                    "table {}.{} does not exist in share {}",
+                    schema_name, table_name, self.name
+                ))
+            })
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ShareSpec {
     pub name: String,
@@ -0,0 +1,219 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use serde_json::json;
+use sharing_endpoint::delta_sharing::paginate;
+use sharing_endpoint::delta_sharing::spark_schema_string;
+use sharing_endpoint::delta_sharing::DeltaLine;
+use sharing_endpoint::delta_sharing::PageParams;
+use sharing_endpoint::delta_sharing::PredicateHint;
+use sharing_endpoint::delta_sharing::QueryTableRequest;
+use sharing_endpoint::middlewares::DeltaSharingAuth;
+use sharing_endpoint::models::DatabaseSpec;
+use sharing_endpoint::models::ShareSpec;
+use sharing_endpoint::models::SharingConfig;
+
+fn share_spec(name: &str, tenants: Vec<&str>) -> ShareSpec {
+    ShareSpec {
+        name: name.to_string(),
+        share_id: 1,
+        version: 0,
+        database: DatabaseSpec {
+            name: "db1".to_string(),
+            location: "s3://db1".to_string(),
+            id: 0,
+        },
+        tables: vec![],
+        tenants: tenants.into_iter().map(|t| t.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_list_and_get_shares() -> Result<()> {
+    let mut config = SharingConfig {
+        share_specs: HashMap::new(),
+    };
+    for (name, tenants) in [
+        ("s2", vec!["t1"]),
+        ("s1", vec!["t1", "t2"]),
+        ("s3", vec!["t2"]),
+    ] {
+        config
+            .share_specs
+            .insert(name.to_string(), share_spec(name, tenants));
+    }
+
+    let shares = config.list_shares("t1");
+    let names = shares.iter().map(|s| s.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["s1", "s2"]);
+
+    assert_eq!(config.get_share("s3", "t2")?.name, "s3");
+    assert!(config.get_share("s3", "t1").is_err());
+    assert!(config.get_share("s4", "t1").is_err());
+
+    let share = config.get_share("s1", "t1")?;
+    assert!(share.get_table("db1", "t").is_err());
+    assert!(share.get_table("db2", "t").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_paginate() -> Result<()> {
+    let items = (0..5).collect::<Vec<_>>();
+    let params = PageParams {
+        max_results: Some(2),
+        page_token: None,
+    };
+    let page = paginate(items.clone(), &params)?;
+    assert_eq!(page.items, vec![0, 1]);
+    assert_eq!(page.next_page_token, Some("2".to_string()));
+
+    let params = PageParams {
+        max_results: Some(2),
+        page_token: Some("4".to_string()),
+    };
+    let page = paginate(items.clone(), &params)?;
+    assert_eq!(page.items, vec![4]);
+    assert_eq!(page.next_page_token, None);
+
+    let params = PageParams {
+        max_results: None,
+        page_token: Some("x".to_string()),
+    };
+    assert!(paginate(items, &params).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_predicate_hint() {
+    let hint = PredicateHint::parse("a >= 10").unwrap();
+    assert_eq!(hint.column, "a");
+    assert_eq!(hint.op, ">=");
+    assert_eq!(hint.value, json!(10.0));
+
+    let hint = PredicateHint::parse("d = '2023-01-01'").unwrap();
+    assert_eq!(hint.value, json!("2023-01-01"));
+
+    assert!(PredicateHint::parse("a is null").is_none());
+    assert!(PredicateHint::parse("a + b > 1").is_none());
+
+    let schema = TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("b", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("d", TableDataType::Date),
+        TableField::new(
+            "ts",
+            TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+        ),
+        TableField::new("s", TableDataType::String),
+    ]);
+    let stats = json!({
+        "numRecords": 10,
+        "minValues": {"a": 1, "d": "2022-01-01", "ts": "2023-01-01T09:00:00.000000Z", "s": "b"},
+        "maxValues": {"a": 5, "d": "2022-12-31", "ts": "2023-01-01T11:30:00.000000Z", "s": "d"},
+    });
+    let may_match = |hint: &str| {
+        PredicateHint::parse(hint)
+            .unwrap()
+            .may_match(&schema, &stats)
+    };
+    assert!(may_match("a = 3"));
+    assert!(!may_match("a = 6"));
+    assert!(!may_match("a > 5"));
+    assert!(may_match("a >= 5"));
+    assert!(!may_match("a < 1"));
+    assert!(may_match("a <> 1"));
+    assert!(!may_match("d > '2022-12-31'"));
+    assert!(may_match("d < '2022-01-02'"));
+    assert!(may_match("ts < '2023-01-01 10:00:00'"));
+    assert!(may_match("ts = '2023-01-01T11:30:00'"));
+    assert!(!may_match("ts > '2023-01-01 11:30:00'"));
+    assert!(!may_match("ts < '2023-01-01'"));
+    assert!(!may_match("ts >= '2023-01-02'"));
+    // literals which can't be parsed
+    assert!(may_match("ts > 'yesterday'"));
+    assert!(may_match("s = 'c'"));
+    assert!(!may_match("s > 'd'"));
+    // no statistics of the column
+    assert!(may_match("b = 1"));
+}
+
+#[test]
+fn test_spark_schema_string() {
+    let schema = TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new(
+            "b",
+            TableDataType::Nullable(Box::new(TableDataType::String)),
+        ),
+        TableField::new(
+            "c",
+            TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+        ),
+    ]);
+    let value: serde_json::Value = serde_json::from_str(&spark_schema_string(&schema)).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "type": "struct",
+            "fields": [
+                {"name": "a", "type": "integer", "nullable": false, "metadata": {}},
+                {"name": "b", "type": "string", "nullable": true, "metadata": {}},
+                {"name": "c", "type": {"type": "array", "elementType": "decimal(20,0)", "containsNull": false}, "nullable": false, "metadata": {}},
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_delta_lines() -> Result<()> {
+    let request: QueryTableRequest =
+        serde_json::from_str(r#"{"predicateHints": ["a > 1"], "limitHint": 10}"#)?;
+    assert_eq!(request.predicate_hints, vec!["a > 1".to_string()]);
+    assert_eq!(request.limit_hint, Some(10));
+    assert_eq!(request.version, None);
+
+    let line = serde_json::to_string(&DeltaLine::protocol())?;
+    assert_eq!(line, r#"{"protocol":{"minReaderVersion":1}}"#);
+    Ok(())
+}
+
+#[test]
+fn test_delta_sharing_auth() -> Result<()> {
+    let auth = DeltaSharingAuth::try_create("t1=token-a, t2=token-b,t1=token-c")?;
+    assert!(!auth.is_empty());
+    assert_eq!(auth.recipient(Some("Bearer token-a")), Some("t1"));
+    assert_eq!(auth.recipient(Some("Bearer token-b")), Some("t2"));
+    assert_eq!(auth.recipient(Some("Bearer token-c")), Some("t1"));
+
+    // the tenant id is not a credential
+    assert_eq!(auth.recipient(Some("Bearer dDE=")), None);
+    assert_eq!(auth.recipient(Some("Bearer t1")), None);
+    assert_eq!(auth.recipient(Some("token-a")), None);
+    assert_eq!(auth.recipient(Some("Bearer ")), None);
+    assert_eq!(auth.recipient(None), None);
+
+    assert!(DeltaSharingAuth::try_create("")?.is_empty());
+    assert!(DeltaSharingAuth::try_create("t1").is_err());
+    assert!(DeltaSharingAuth::try_create("t1=").is_err());
+    assert!(DeltaSharingAuth::try_create("=token").is_err());
+    Ok(())
+}
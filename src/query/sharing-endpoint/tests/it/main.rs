@@ -14,4 +14,5 @@
 #![feature(thread_local)]
 
 mod accessor;
+mod delta_sharing;
 mod models;
@@ -28,6 +28,9 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_transforms::processors::transforms::Transform;
 use common_pipeline_transforms::processors::transforms::Transformer;
 
+use super::project_set::project_set;
+use crate::plans::SetReturningFunction;
+
 /// `BlockOperator` takes a `DataBlock` as input and produces a `DataBlock` as output.
 #[derive(Clone)]
 pub enum BlockOperator {
@@ -39,6 +42,13 @@ pub enum BlockOperator {
 
     /// Reorganize the input `DataBlock` with `projection`.
     Project { projection: Vec<usize> },
+
+    /// Expand each row of the input `DataBlock` with the rows produced by
+    /// the set-returning function `srf` over `expr`.
+    ProjectSet {
+        srf: SetReturningFunction,
+        expr: Expr,
+    },
     // Remap { indices: Vec<(IndexType, IndexType)> },
 }
 
@@ -67,12 +77,23 @@ impl BlockOperator {
             }
 
             BlockOperator::Project { projection } => {
-                let mut result = DataBlock::new(vec![], input.num_rows());
+                // Keep the meta of the input, the sinks may rely on it.
+                let meta = input.take_meta();
+                let mut result = DataBlock::new_with_meta(vec![], input.num_rows(), meta);
                 for id in projection {
                     result.add_column(input.get_by_offset(*id).clone());
                 }
                 Ok(result)
             }
+
+            BlockOperator::ProjectSet { srf, expr } => {
+                let evaluator = Evaluator::new(&input, *func_ctx, &BUILTIN_FUNCTIONS);
+                let result = evaluator
+                    .run(expr)
+                    .map_err(|(_, e)| ErrorCode::Internal(e))?;
+                let arg = result.convert_to_full_column(expr.data_type(), input.num_rows());
+                project_set(srf, &arg, expr.data_type(), input)
+            }
         }
     }
 }
@@ -133,6 +154,7 @@ impl Transform for CompoundBlockOperator {
                         BlockOperator::Map { .. } => "Map",
                         BlockOperator::Filter { .. } => "Filter",
                         BlockOperator::Project { .. } => "Project",
+                        BlockOperator::ProjectSet { .. } => "ProjectSet",
                     }
                     .to_string()
                 })
@@ -0,0 +1,209 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_jsonb::JsonPath;
+
+use crate::plans::FlattenMode;
+use crate::plans::SetReturningFunction;
+
+/// Expand `input` with the rows produced by `srf` over the argument column `arg`,
+/// columns of each input row are repeated for every row it produces.
+pub fn project_set(
+    srf: &SetReturningFunction,
+    arg: &Column,
+    arg_type: &DataType,
+    input: DataBlock,
+) -> Result<DataBlock> {
+    let return_types = srf.return_types(arg_type);
+    let mut builders = return_types
+        .iter()
+        .map(|(_, data_type)| ColumnBuilder::with_capacity(data_type, input.num_rows()))
+        .collect::<Vec<_>>();
+    let mut indices: Vec<u32> = Vec::with_capacity(input.num_rows());
+
+    match srf {
+        SetReturningFunction::Unnest => {
+            for (row, scalar) in arg.iter().enumerate() {
+                let count = unnest_scalar(&scalar, &mut builders[0])?;
+                indices.extend(std::iter::repeat(row as u32).take(count));
+            }
+        }
+        SetReturningFunction::Flatten {
+            path,
+            outer,
+            recursive,
+            mode,
+        } => {
+            let json_path = common_jsonb::parse_json_path(path.as_bytes())
+                .map_err(|_| ErrorCode::BadArguments(format!("Invalid JSON path '{path}'")))?;
+            let mut flatten = Flatten {
+                recursive: *recursive,
+                mode: *mode,
+                builders: &mut builders,
+            };
+            for (row, scalar) in arg.iter().enumerate() {
+                let count = flatten.expand_row(&scalar, path, &json_path, *outer)?;
+                indices.extend(std::iter::repeat(row as u32).take(count));
+            }
+        }
+    }
+
+    let mut result = input.take(&indices)?;
+    for (builder, (_, data_type)) in builders.into_iter().zip(return_types) {
+        result.add_column(BlockEntry {
+            data_type,
+            value: Value::Column(builder.build()),
+        });
+    }
+    Ok(result)
+}
+
+/// Push the elements of an array or a Variant array into `builder`,
+/// returns the number of produced rows.
+fn unnest_scalar(scalar: &ScalarRef, builder: &mut ColumnBuilder) -> Result<usize> {
+    match scalar {
+        ScalarRef::Null | ScalarRef::EmptyArray => Ok(0),
+        ScalarRef::Array(column) => {
+            for item in column.iter() {
+                builder.push(item);
+            }
+            Ok(column.len())
+        }
+        ScalarRef::Variant(bytes) => {
+            let value = common_jsonb::from_slice(bytes)
+                .map_err(|e| ErrorCode::BadBytes(format!("Invalid variant value: {e}")))?;
+            match value.as_array() {
+                Some(items) => {
+                    for item in items {
+                        builder.push(ScalarRef::Variant(&item.to_vec()));
+                    }
+                    Ok(items.len())
+                }
+                None => Ok(0),
+            }
+        }
+        _ => Err(ErrorCode::Internal(format!(
+            "unnest expects an array or variant argument, but got {scalar:?}"
+        ))),
+    }
+}
+
+/// Produce the `key`, `path`, `index`, `value` and `this` columns of `flatten`.
+struct Flatten<'a> {
+    recursive: bool,
+    mode: FlattenMode,
+    builders: &'a mut [ColumnBuilder],
+}
+
+impl<'a> Flatten<'a> {
+    fn expand_row(
+        &mut self,
+        scalar: &ScalarRef,
+        path: &str,
+        json_path: &[JsonPath],
+        outer: bool,
+    ) -> Result<usize> {
+        let this = match scalar {
+            ScalarRef::Variant(bytes) => {
+                let value = common_jsonb::from_slice(bytes)
+                    .map_err(|e| ErrorCode::BadBytes(format!("Invalid variant value: {e}")))?;
+                value.get_by_path(json_path).cloned()
+            }
+            _ => None,
+        };
+
+        let count = match &this {
+            Some(this) => self.expand(this, path),
+            None => 0,
+        };
+        if count == 0 && outer {
+            let this = this.map(|this| this.to_vec());
+            self.push(None, Some(path), None, None, this.as_deref());
+            return Ok(1);
+        }
+        Ok(count)
+    }
+
+    fn expand(&mut self, this: &common_jsonb::Value, path: &str) -> usize {
+        let this_bytes = this.to_vec();
+        let mut count = 0;
+        match this {
+            common_jsonb::Value::Array(items) if self.mode != FlattenMode::Object => {
+                for (index, item) in items.iter().enumerate() {
+                    let item_path = format!("{path}[{index}]");
+                    self.push(
+                        None,
+                        Some(&item_path),
+                        Some(index as u64),
+                        Some(&item.to_vec()),
+                        Some(&this_bytes),
+                    );
+                    count += 1;
+                    if self.recursive {
+                        count += self.expand(item, &item_path);
+                    }
+                }
+            }
+            common_jsonb::Value::Object(fields) if self.mode != FlattenMode::Array => {
+                for (key, item) in fields.iter() {
+                    let item_path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{path}.{key}")
+                    };
+                    self.push(
+                        Some(key.as_str()),
+                        Some(&item_path),
+                        None,
+                        Some(&item.to_vec()),
+                        Some(&this_bytes),
+                    );
+                    count += 1;
+                    if self.recursive {
+                        count += self.expand(item, &item_path);
+                    }
+                }
+            }
+            _ => {}
+        }
+        count
+    }
+
+    fn push(
+        &mut self,
+        key: Option<&str>,
+        path: Option<&str>,
+        index: Option<u64>,
+        value: Option<&[u8]>,
+        this: Option<&[u8]>,
+    ) {
+        self.builders[0].push(key.map_or(ScalarRef::Null, |v| ScalarRef::String(v.as_bytes())));
+        self.builders[1].push(path.map_or(ScalarRef::Null, |v| ScalarRef::String(v.as_bytes())));
+        self.builders[2].push(index.map_or(ScalarRef::Null, |v| {
+            ScalarRef::Number(NumberScalar::UInt64(v))
+        }));
+        self.builders[3].push(value.map_or(ScalarRef::Null, ScalarRef::Variant));
+        self.builders[4].push(this.map_or(ScalarRef::Null, ScalarRef::Variant));
+    }
+}
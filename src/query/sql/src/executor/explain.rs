@@ -18,4 +18,6 @@ use serde::Serialize;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlanStatsInfo {
     pub estimated_rows: f64,
+    /// Unique id of the plan node in the query, used to collect the runtime profile
+    pub plan_id: usize,
 }
@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::time::Duration;
+
 use common_ast::ast::FormatTreeNode;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ConstantFolder;
 use common_expression::FunctionContext;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::ProfileStatistics;
 use itertools::Itertools;
 
 use super::AggregateFinal;
@@ -30,34 +34,90 @@ use super::HashJoin;
 use super::Limit;
 use super::PhysicalPlan;
 use super::Project;
+use super::ProjectSet;
 use super::Sort;
 use super::TableScan;
 use super::UnionAll;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::FragmentKind;
+use crate::executor::SkewShuffleKind;
 use crate::planner::MetadataRef;
 use crate::planner::DUMMY_TABLE_INDEX;
 use crate::ColumnEntry;
 
+/// Runtime profiles of the plan nodes, keyed by plan id.
+type Profiles = HashMap<usize, ProfileStatistics>;
+
 impl PhysicalPlan {
     pub fn format(&self, metadata: MetadataRef) -> Result<String> {
-        to_format_tree(self, &metadata)?.format_pretty()
+        to_format_tree(self, &metadata, &Profiles::new())?.format_pretty()
+    }
+
+    /// Format the plan annotated with the runtime profiles collected by `EXPLAIN ANALYZE`.
+    pub fn format_with_profiles(
+        &self,
+        metadata: MetadataRef,
+        profiles: Profiles,
+    ) -> Result<String> {
+        let mut profiles = profiles;
+        fill_input_statistics(self, &mut profiles);
+        to_format_tree(self, &metadata, &profiles)?.format_pretty()
+    }
+}
+
+/// The input of a plan node is the output of its children.
+fn fill_input_statistics(plan: &PhysicalPlan, profiles: &mut Profiles) {
+    for child in plan.children() {
+        fill_input_statistics(child, profiles);
+    }
+
+    if let Some(plan_id) = plan.plan_id() {
+        let (rows, bytes) = plan
+            .children()
+            .map(|child| output_statistics(child, profiles))
+            .fold((0, 0), |(rows, bytes), (r, b)| (rows + r, bytes + b));
+        if let Some(profile) = profiles.get_mut(&plan_id) {
+            profile.input_rows = rows;
+            profile.input_bytes = bytes;
+        }
+    }
+}
+
+fn output_statistics(plan: &PhysicalPlan, profiles: &Profiles) -> (u64, u64) {
+    match plan.plan_id() {
+        Some(plan_id) => profiles.get(&plan_id).map_or((0, 0), |profile| {
+            (profile.output_rows, profile.output_bytes)
+        }),
+        // Look through the exchanges.
+        None => plan
+            .children()
+            .map(|child| output_statistics(child, profiles))
+            .fold((0, 0), |(rows, bytes), (r, b)| (rows + r, bytes + b)),
     }
 }
 
-fn to_format_tree(plan: &PhysicalPlan, metadata: &MetadataRef) -> Result<FormatTreeNode<String>> {
+fn to_format_tree(
+    plan: &PhysicalPlan,
+    metadata: &MetadataRef,
+    profiles: &Profiles,
+) -> Result<FormatTreeNode<String>> {
     match plan {
-        PhysicalPlan::TableScan(plan) => table_scan_to_format_tree(plan, metadata),
-        PhysicalPlan::Filter(plan) => filter_to_format_tree(plan, metadata),
-        PhysicalPlan::Project(plan) => project_to_format_tree(plan, metadata),
-        PhysicalPlan::EvalScalar(plan) => eval_scalar_to_format_tree(plan, metadata),
-        PhysicalPlan::AggregatePartial(plan) => aggregate_partial_to_format_tree(plan, metadata),
-        PhysicalPlan::AggregateFinal(plan) => aggregate_final_to_format_tree(plan, metadata),
-        PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata),
-        PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata),
-        PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata),
-        PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata),
-        PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata),
+        PhysicalPlan::TableScan(plan) => table_scan_to_format_tree(plan, metadata, profiles),
+        PhysicalPlan::Filter(plan) => filter_to_format_tree(plan, metadata, profiles),
+        PhysicalPlan::Project(plan) => project_to_format_tree(plan, metadata, profiles),
+        PhysicalPlan::EvalScalar(plan) => eval_scalar_to_format_tree(plan, metadata, profiles),
+        PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, profiles),
+        PhysicalPlan::AggregatePartial(plan) => {
+            aggregate_partial_to_format_tree(plan, metadata, profiles)
+        }
+        PhysicalPlan::AggregateFinal(plan) => {
+            aggregate_final_to_format_tree(plan, metadata, profiles)
+        }
+        PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, profiles),
+        PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, profiles),
+        PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, profiles),
+        PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, profiles),
+        PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata, profiles),
         PhysicalPlan::ExchangeSource(_)
         | PhysicalPlan::ExchangeSink(_)
         | PhysicalPlan::DistributedInsertSelect(_) => {
@@ -69,6 +129,7 @@ fn to_format_tree(plan: &PhysicalPlan, metadata: &MetadataRef) -> Result<FormatT
 fn table_scan_to_format_tree(
     plan: &TableScan,
     metadata: &MetadataRef,
+    profiles: &Profiles,
 ) -> Result<FormatTreeNode<String>> {
     if plan.table_index == DUMMY_TABLE_INDEX {
         return Ok(FormatTreeNode::new("DummyTableScan".to_string()));
@@ -135,7 +196,7 @@ fn table_scan_to_format_tree(
     }
 
     if let Some(info) = &plan.stat_info {
-        let items = plan_stats_info_to_format_tree(info);
+        let items = plan_stats_info_to_format_tree(info, profiles);
         children.extend(items);
     }
 
@@ -145,7 +206,11 @@ fn table_scan_to_format_tree(
     ))
 }
 
-fn filter_to_format_tree(plan: &Filter, metadata: &MetadataRef) -> Result<FormatTreeNode<String>> {
+fn filter_to_format_tree(
+    plan: &Filter,
+    metadata: &MetadataRef,
+    profiles: &Profiles,
+) -> Result<FormatTreeNode<String>> {
     let filter = plan
         .predicates
         .iter()
@@ -155,11 +220,11 @@ fn filter_to_format_tree(plan: &Filter, metadata: &MetadataRef) -> Result<Format
     let mut children = vec![FormatTreeNode::new(format!("filters: [{filter}]"))];
 
     if let Some(info) = &plan.stat_info {
-        let items = plan_stats_info_to_format_tree(info);
+        let items = plan_stats_info_to_format_tree(info, profiles);
         children.extend(items);
     }
 
-    children.push(to_format_tree(&plan.input, metadata)?);
+    children.push(to_format_tree(&plan.input, metadata, profiles)?);
 
     Ok(FormatTreeNode::with_children(
         "Filter".to_string(),
@@ -170,6 +235,7 @@ fn filter_to_format_tree(plan: &Filter, metadata: &MetadataRef) -> Result<Format
 fn project_to_format_tree(
     plan: &Project,
     metadata: &MetadataRef,
+    profiles: &Profiles,
 ) -> Result<FormatTreeNode<String>> {
     let columns = plan
         .columns
@@ -190,11 +256,11 @@ fn project_to_format_tree(
     let mut children = vec![FormatTreeNode::new(format!("columns: [{columns}]"))];
 
     if let Some(info) = &plan.stat_info {
-        let items = plan_stats_info_to_format_tree(info);
+        let items = plan_stats_info_to_format_tree(info, profiles);
         children.extend(items);
     }
 
-    children.push(to_format_tree(&plan.input, metadata)?);
+    children.push(to_format_tree(&plan.input, metadata, profiles)?);
 
     Ok(FormatTreeNode::with_children(
         "Project".to_string(),
@@ -205,6 +271,7 @@ fn project_to_format_tree(
 fn eval_scalar_to_format_tree(
     plan: &EvalScalar,
     metadata: &MetadataRef,
+    profiles: &Profiles,
 ) -> Result<FormatTreeNode<String>> {
     let scalars = plan
         .scalars
@@ -215,11 +282,11 @@ fn eval_scalar_to_format_tree(
     let mut children = vec![FormatTreeNode::new(format!("expressions: [{scalars}]"))];
 
     if let Some(info) = &plan.stat_info {
-        let items = plan_stats_info_to_format_tree(info);
+        let items = plan_stats_info_to_format_tree(info, profiles);
         children.extend(items);
     }
 
-    children.push(to_format_tree(&plan.input, metadata)?);
+    children.push(to_format_tree(&plan.input, metadata, profiles)?);
 
     Ok(FormatTreeNode::with_children(
         "EvalScalar".to_string(),
@@ -227,6 +294,29 @@ fn eval_scalar_to_format_tree(
     ))
 }
 
+fn project_set_to_format_tree(
+    plan: &ProjectSet,
+    metadata: &MetadataRef,
+    profiles: &Profiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "function: {}",
+        plan.srf.display(&plan.arg.pretty_display())
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info, profiles);
+        children.extend(items);
+    }
+
+    children.push(to_format_tree(&plan.input, metadata, profiles)?);
+
+    Ok(FormatTreeNode::with_children(
+        "ProjectSet".to_string(),
+        children,
+    ))
+}
+
 pub fn pretty_display_agg_desc(desc: &AggregateFunctionDesc, metadata: &MetadataRef) -> String {
     format!(
         "{}({})",
@@ -248,6 +338,7 @@ pub fn pretty_display_agg_desc(desc: &AggregateFunctionDesc, metadata: &Metadata
 fn aggregate_partial_to_format_tree(
     plan: &AggregatePartial,
     metadata: &MetadataRef,
+    profiles: &Profiles,
 ) -> Result<FormatTreeNode<String>> {
     let group_by = plan
         .group_by
@@ -275,11 +366,11 @@ fn aggregate_partial_to_format_tree(
     ];
 
     if let Some(info) = &plan.stat_info {
-        let items = plan_stats_info_to_format_tree(info);
+        let items = plan_stats_info_to_format_tree(info, profiles);
         children.extend(items);
     }
 
-    children.push(to_format_tree(&plan.input, metadata)?);
+    children.push(to_format_tree(&plan.input, metadata, profiles)?);
 
     Ok(FormatTreeNode::with_children(
         "AggregatePartial".to_string(),
@@ -290,6 +381,7 @@ fn aggregate_partial_to_format_tree(
 fn aggregate_final_to_format_tree(
     plan: &AggregateFinal,
     metadata: &MetadataRef,
+    profiles: &Profiles,
 ) -> Result<FormatTreeNode<String>> {
     let group_by = plan
         .group_by
@@ -318,11 +410,11 @@ fn aggregate_final_to_format_tree(
     ];
 
     if let Some(info) = &plan.stat_info {
-        let items = plan_stats_info_to_format_tree(info);
+        let items = plan_stats_info_to_format_tree(info, profiles);
         children.extend(items);
     }
 
-    children.push(to_format_tree(&plan.input, metadata)?);
+    children.push(to_format_tree(&plan.input, metadata, profiles)?);
 
     Ok(FormatTreeNode::with_children(
         "AggregateFinal".to_string(),
@@ -330,7 +422,11 @@ fn aggregate_final_to_format_tree(
     ))
 }
 
-fn sort_to_format_tree(plan: &Sort, metadata: &MetadataRef) -> Result<FormatTreeNode<String>> {
+fn sort_to_format_tree(
+    plan: &Sort,
+    metadata: &MetadataRef,
+    profiles: &Profiles,
+) -> Result<FormatTreeNode<String>> {
     let sort_keys = plan
         .order_by
         .iter()
@@ -357,16 +453,20 @@ fn sort_to_format_tree(plan: &Sort, metadata: &MetadataRef) -> Result<FormatTree
     let mut children = vec![FormatTreeNode::new(format!("sort keys: [{sort_keys}]"))];
 
     if let Some(info) = &plan.stat_info {
-        let items = plan_stats_info_to_format_tree(info);
+        let items = plan_stats_info_to_format_tree(info, profiles);
         children.extend(items);
     }
 
-    children.push(to_format_tree(&plan.input, metadata)?);
+    children.push(to_format_tree(&plan.input, metadata, profiles)?);
 
     Ok(FormatTreeNode::with_children("Sort".to_string(), children))
 }
 
-fn limit_to_format_tree(plan: &Limit, metadata: &MetadataRef) -> Result<FormatTreeNode<String>> {
+fn limit_to_format_tree(
+    plan: &Limit,
+    metadata: &MetadataRef,
+    profiles: &Profiles,
+) -> Result<FormatTreeNode<String>> {
     let mut children = vec![
         FormatTreeNode::new(format!(
             "limit: {}",
@@ -377,11 +477,11 @@ fn limit_to_format_tree(plan: &Limit, metadata: &MetadataRef) -> Result<FormatTr
     ];
 
     if let Some(info) = &plan.stat_info {
-        let items = plan_stats_info_to_format_tree(info);
+        let items = plan_stats_info_to_format_tree(info, profiles);
         children.extend(items);
     }
 
-    children.push(to_format_tree(&plan.input, metadata)?);
+    children.push(to_format_tree(&plan.input, metadata, profiles)?);
 
     Ok(FormatTreeNode::with_children("Limit".to_string(), children))
 }
@@ -389,6 +489,7 @@ fn limit_to_format_tree(plan: &Limit, metadata: &MetadataRef) -> Result<FormatTr
 fn hash_join_to_format_tree(
     plan: &HashJoin,
     metadata: &MetadataRef,
+    profiles: &Profiles,
 ) -> Result<FormatTreeNode<String>> {
     let build_keys = plan
         .build_keys
@@ -409,8 +510,8 @@ fn hash_join_to_format_tree(
         .collect::<Vec<_>>()
         .join(", ");
 
-    let mut build_child = to_format_tree(&plan.build, metadata)?;
-    let mut probe_child = to_format_tree(&plan.probe, metadata)?;
+    let mut build_child = to_format_tree(&plan.build, metadata, profiles)?;
+    let mut probe_child = to_format_tree(&plan.probe, metadata, profiles)?;
 
     build_child.payload = format!("{}(Build)", build_child.payload);
     probe_child.payload = format!("{}(Probe)", probe_child.payload);
@@ -423,7 +524,7 @@ fn hash_join_to_format_tree(
     ];
 
     if let Some(info) = &plan.stat_info {
-        let items = plan_stats_info_to_format_tree(info);
+        let items = plan_stats_info_to_format_tree(info, profiles);
         children.extend(items);
     }
 
@@ -439,6 +540,7 @@ fn hash_join_to_format_tree(
 fn exchange_to_format_tree(
     plan: &Exchange,
     metadata: &MetadataRef,
+    profiles: &Profiles,
 ) -> Result<FormatTreeNode<String>> {
     Ok(FormatTreeNode::with_children("Exchange".to_string(), vec![
         FormatTreeNode::new(format!("exchange type: {}", match plan.kind {
@@ -453,25 +555,55 @@ fn exchange_to_format_tree(
             ),
             FragmentKind::Expansive => "Broadcast".to_string(),
             FragmentKind::Merge => "Merge".to_string(),
+            FragmentKind::Range(ref order_by) => format!(
+                "Range({})",
+                order_by
+                    .iter()
+                    .map(|desc| format!(
+                        "{} {}",
+                        match metadata.read().column(desc.order_by).clone() {
+                            ColumnEntry::BaseTableColumn { column_name, .. } => column_name,
+                            ColumnEntry::DerivedColumn { alias, .. } => alias,
+                        },
+                        if desc.asc { "ASC" } else { "DESC" }
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            FragmentKind::OrderedMerge => "OrderedMerge".to_string(),
+            FragmentKind::SkewHash(ref skew) => format!(
+                "Hash({}), skew-aware {}",
+                plan.keys
+                    .iter()
+                    .map(|scalar| { scalar.pretty_display() })
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                match skew.kind {
+                    SkewShuffleKind::Build => "build side",
+                    SkewShuffleKind::Probe => "probe side",
+                    SkewShuffleKind::Aggregate => "partial states",
+                }
+            ),
         })),
-        to_format_tree(&plan.input, metadata)?,
+        to_format_tree(&plan.input, metadata, profiles)?,
     ]))
 }
 
 fn union_all_to_format_tree(
     plan: &UnionAll,
     metadata: &MetadataRef,
+    profiles: &Profiles,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![];
 
     if let Some(info) = &plan.stat_info {
-        let items = plan_stats_info_to_format_tree(info);
+        let items = plan_stats_info_to_format_tree(info, profiles);
         children.extend(items);
     }
 
     children.extend(vec![
-        to_format_tree(&plan.left, metadata)?,
-        to_format_tree(&plan.right, metadata)?,
+        to_format_tree(&plan.left, metadata, profiles)?,
+        to_format_tree(&plan.right, metadata, profiles)?,
     ]);
 
     Ok(FormatTreeNode::with_children(
@@ -480,9 +612,46 @@ fn union_all_to_format_tree(
     ))
 }
 
-fn plan_stats_info_to_format_tree(info: &PlanStatsInfo) -> Vec<FormatTreeNode<String>> {
-    vec![FormatTreeNode::new(format!(
+fn plan_stats_info_to_format_tree(
+    info: &PlanStatsInfo,
+    profiles: &Profiles,
+) -> Vec<FormatTreeNode<String>> {
+    let mut items = vec![FormatTreeNode::new(format!(
         "estimated rows: {0:.2}",
         info.estimated_rows
-    ))]
+    ))];
+
+    if let Some(profile) = profiles.get(&info.plan_id) {
+        items.extend(vec![
+            FormatTreeNode::new(format!("input rows: {}", profile.input_rows)),
+            FormatTreeNode::new(format!("input bytes: {}", profile.input_bytes)),
+            FormatTreeNode::new(format!("output rows: {}", profile.output_rows)),
+            FormatTreeNode::new(format!("output bytes: {}", profile.output_bytes)),
+            FormatTreeNode::new(format!(
+                "cpu time: {:?}",
+                Duration::from_nanos(profile.cpu_time)
+            )),
+            FormatTreeNode::new(format!(
+                "wait time: {:?}",
+                Duration::from_nanos(profile.wait_time)
+            )),
+        ]);
+        if profile.partitions_scanned != 0 || profile.partitions_pruned != 0 {
+            items.extend(vec![
+                FormatTreeNode::new(format!(
+                    "partitions scanned: {}",
+                    profile.partitions_scanned
+                )),
+                FormatTreeNode::new(format!("partitions pruned: {}", profile.partitions_pruned)),
+            ]);
+        }
+        if profile.skew_hot_keys != 0 || profile.skew_rows != 0 {
+            items.extend(vec![
+                FormatTreeNode::new(format!("skew hot keys: {}", profile.skew_hot_keys)),
+                FormatTreeNode::new(format!("skew redistributed rows: {}", profile.skew_rows)),
+            ]);
+        }
+    }
+
+    items
 }
@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 
 use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::RuntimeFilterDesc;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::DataBlock;
@@ -29,6 +30,7 @@ use crate::executor::explain::PlanStatsInfo;
 use crate::executor::PhysicalScalar;
 use crate::optimizer::ColumnSet;
 use crate::plans::JoinType;
+use crate::plans::SetReturningFunction;
 use crate::ColumnBinding;
 use crate::IndexType;
 
@@ -115,11 +117,36 @@ impl EvalScalar {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ProjectSet {
+    pub input: Box<PhysicalPlan>,
+    pub srf: SetReturningFunction,
+    pub arg: PhysicalScalar,
+    pub columns: Vec<IndexType>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl ProjectSet {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        let return_types = self.srf.return_types(&self.arg.data_type());
+        for (index, (_, data_type)) in self.columns.iter().zip(return_types) {
+            fields.push(DataField::new(&index.to_string(), data_type));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct AggregatePartial {
     pub input: Box<PhysicalPlan>,
     pub group_by: Vec<IndexType>,
     pub agg_funcs: Vec<AggregateFunctionDesc>,
+    /// Set if the partial states are shuffled by a skew-aware shuffle.
+    pub skew_shuffle: Option<SkewShuffle>,
 
     /// Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -227,11 +254,20 @@ pub struct HashJoin {
     pub join_type: JoinType,
     pub marker_index: Option<IndexType>,
     pub from_correlated_subquery: bool,
+    /// Runtime filters generated from the build keys and pushed down to the probe side
+    pub runtime_filters: Vec<RuntimeFilterSource>,
 
     /// Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RuntimeFilterSource {
+    /// Index of the build key the filter is built from
+    pub build_key: usize,
+    pub desc: RuntimeFilterDesc,
+}
+
 impl HashJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.probe.output_schema()?.fields().clone();
@@ -351,6 +387,36 @@ pub enum FragmentKind {
     // Broadcast
     Expansive,
     Merge,
+    // Partitioned by ranges of the sort keys
+    Range(Vec<SortDesc>),
+    // Merge the range partitions in order
+    OrderedMerge,
+    // Partitioned by hash, aware of the hot keys
+    SkewHash(SkewShuffle),
+}
+
+/// A hash shuffle that is aware of the hot keys, of a side of an inner join or
+/// of the partial states of an aggregation.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SkewShuffle {
+    /// Id of the shuffle, shared by the two sides of a join, unique in a query.
+    pub id: usize,
+    /// Plan id of the join or the partial aggregation, the detected hot keys are
+    /// recorded in its profile.
+    pub plan_id: usize,
+    pub kind: SkewShuffleKind,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkewShuffleKind {
+    /// The build side of a join, the rows with hot keys are spread across the nodes.
+    Build,
+    /// The probe side of a join, the rows with hot keys are sent to all the nodes.
+    Probe,
+    /// The partial states of an aggregation, the hot keys are detected from the input
+    /// of the partial aggregation, and their states are merged on each node before
+    /// they are shuffled by the hash.
+    Aggregate,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -417,6 +483,7 @@ pub enum PhysicalPlan {
     Filter(Filter),
     Project(Project),
     EvalScalar(EvalScalar),
+    ProjectSet(ProjectSet),
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
     Sort(Sort),
@@ -448,6 +515,7 @@ impl PhysicalPlan {
             PhysicalPlan::Filter(plan) => plan.output_schema(),
             PhysicalPlan::Project(plan) => plan.output_schema(),
             PhysicalPlan::EvalScalar(plan) => plan.output_schema(),
+            PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
             PhysicalPlan::AggregatePartial(plan) => plan.output_schema(),
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
@@ -467,6 +535,7 @@ impl PhysicalPlan {
             PhysicalPlan::Filter(_) => "Filter".to_string(),
             PhysicalPlan::Project(_) => "Project".to_string(),
             PhysicalPlan::EvalScalar(_) => "EvalScalar".to_string(),
+            PhysicalPlan::ProjectSet(_) => "ProjectSet".to_string(),
             PhysicalPlan::AggregatePartial(_) => "AggregatePartial".to_string(),
             PhysicalPlan::AggregateFinal(_) => "AggregateFinal".to_string(),
             PhysicalPlan::Sort(_) => "Sort".to_string(),
@@ -480,12 +549,35 @@ impl PhysicalPlan {
         }
     }
 
+    /// Id of the plan node, the nodes synthesized by fragmenter or exchange have no id.
+    pub fn plan_id(&self) -> Option<usize> {
+        let stat_info = match self {
+            PhysicalPlan::TableScan(plan) => &plan.stat_info,
+            PhysicalPlan::Filter(plan) => &plan.stat_info,
+            PhysicalPlan::Project(plan) => &plan.stat_info,
+            PhysicalPlan::EvalScalar(plan) => &plan.stat_info,
+            PhysicalPlan::ProjectSet(plan) => &plan.stat_info,
+            PhysicalPlan::AggregatePartial(plan) => &plan.stat_info,
+            PhysicalPlan::AggregateFinal(plan) => &plan.stat_info,
+            PhysicalPlan::Sort(plan) => &plan.stat_info,
+            PhysicalPlan::Limit(plan) => &plan.stat_info,
+            PhysicalPlan::HashJoin(plan) => &plan.stat_info,
+            PhysicalPlan::UnionAll(plan) => &plan.stat_info,
+            PhysicalPlan::Exchange(_)
+            | PhysicalPlan::ExchangeSource(_)
+            | PhysicalPlan::ExchangeSink(_)
+            | PhysicalPlan::DistributedInsertSelect(_) => return None,
+        };
+        stat_info.as_ref().map(|info| info.plan_id)
+    }
+
     pub fn children<'a>(&'a self) -> Box<dyn Iterator<Item = &'a PhysicalPlan> + 'a> {
         match self {
             PhysicalPlan::TableScan(_) => Box::new(std::iter::empty()),
             PhysicalPlan::Filter(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Project(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::EvalScalar(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::ProjectSet(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregatePartial(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
@@ -14,6 +14,8 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_catalog::catalog::CatalogManager;
@@ -21,6 +23,7 @@ use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::PrewhereInfo;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::RuntimeFilterDesc;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -40,6 +43,9 @@ use super::Exchange as PhysicalExchange;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::Project;
+use super::ProjectSet;
+use super::RuntimeFilterSource;
 use super::Sort;
 use super::TableScan;
 use crate::executor::explain::PlanStatsInfo;
@@ -48,6 +54,8 @@ use crate::executor::EvalScalar;
 use crate::executor::FragmentKind;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalScalar;
+use crate::executor::SkewShuffle;
+use crate::executor::SkewShuffleKind;
 use crate::executor::SortDesc;
 use crate::executor::UnionAll;
 use crate::optimizer::ColumnSet;
@@ -56,6 +64,8 @@ use crate::optimizer::SExpr;
 use crate::plans::AggregateMode;
 use crate::plans::AndExpr;
 use crate::plans::Exchange;
+use crate::plans::Join;
+use crate::plans::JoinType;
 use crate::plans::RelOperator;
 use crate::plans::Scalar;
 use crate::plans::Scan;
@@ -70,11 +80,20 @@ use crate::DUMMY_TABLE_INDEX;
 pub struct PhysicalPlanBuilder {
     metadata: MetadataRef,
     ctx: Arc<dyn TableContext>,
+    next_runtime_filter_id: AtomicUsize,
+    next_plan_id: AtomicUsize,
+    next_skew_shuffle_id: AtomicUsize,
 }
 
 impl PhysicalPlanBuilder {
     pub fn new(metadata: MetadataRef, ctx: Arc<dyn TableContext>) -> Self {
-        Self { metadata, ctx }
+        Self {
+            metadata,
+            ctx,
+            next_runtime_filter_id: AtomicUsize::new(0),
+            next_plan_id: AtomicUsize::new(0),
+            next_skew_shuffle_id: AtomicUsize::new(0),
+        }
     }
 
     fn build_projection(
@@ -196,12 +215,13 @@ impl PhysicalPlanBuilder {
 
                     stat_info: Some(PlanStatsInfo {
                         estimated_rows: 1.0,
+                        plan_id: stat_info.plan_id,
                     }),
                 }))
             }
             RelOperator::Join(join) => {
-                let build_side = self.build(s_expr.child(1)?).await?;
-                let probe_side = self.build(s_expr.child(0)?).await?;
+                let mut build_side = self.build(s_expr.child(1)?).await?;
+                let mut probe_side = self.build(s_expr.child(0)?).await?;
                 let build_schema = build_side.output_schema()?;
                 let probe_schema = probe_side.output_schema()?;
                 let merged_schema = DataSchemaRefExt::create(
@@ -215,20 +235,30 @@ impl PhysicalPlanBuilder {
                 let probe_physical_scalar_builder = PhysicalScalarBuilder::new(&probe_schema);
                 let build_physical_scalar_builder = PhysicalScalarBuilder::new(&build_schema);
                 let merged_physical_scalar_builder = PhysicalScalarBuilder::new(&merged_schema);
+                let build_keys: Vec<PhysicalScalar> = join
+                    .right_conditions
+                    .iter()
+                    .map(|scalar| build_physical_scalar_builder.build(scalar))
+                    .collect::<Result<_>>()?;
+                let probe_keys: Vec<PhysicalScalar> = join
+                    .left_conditions
+                    .iter()
+                    .map(|scalar| probe_physical_scalar_builder.build(scalar))
+                    .collect::<Result<_>>()?;
+                let runtime_filters = self.build_runtime_filters(
+                    join,
+                    &build_side,
+                    &mut probe_side,
+                    &build_keys,
+                    &probe_keys,
+                )?;
+                self.build_skew_shuffle(join, &mut build_side, &mut probe_side, &stat_info)?;
                 Ok(PhysicalPlan::HashJoin(HashJoin {
                     build: Box::new(build_side),
                     probe: Box::new(probe_side),
                     join_type: join.join_type.clone(),
-                    build_keys: join
-                        .right_conditions
-                        .iter()
-                        .map(|scalar| build_physical_scalar_builder.build(scalar))
-                        .collect::<Result<_>>()?,
-                    probe_keys: join
-                        .left_conditions
-                        .iter()
-                        .map(|scalar| probe_physical_scalar_builder.build(scalar))
-                        .collect::<Result<_>>()?,
+                    build_keys,
+                    probe_keys,
                     non_equi_conditions: join
                         .non_equi_conditions
                         .iter()
@@ -236,6 +266,7 @@ impl PhysicalPlanBuilder {
                         .collect::<Result<_>>()?,
                     marker_index: join.marker_index,
                     from_correlated_subquery: join.from_correlated_subquery,
+                    runtime_filters,
 
                     stat_info: Some(stat_info),
                 }))
@@ -257,6 +288,20 @@ impl PhysicalPlanBuilder {
                 }))
             }
 
+            RelOperator::ProjectSet(project_set) => {
+                let input = Box::new(self.build(s_expr.child(0)?).await?);
+                let input_schema = input.output_schema()?;
+                let builder = PhysicalScalarBuilder::new(&input_schema);
+                Ok(PhysicalPlan::ProjectSet(ProjectSet {
+                    input,
+                    srf: project_set.srf.clone(),
+                    arg: builder.build(&project_set.arg)?,
+                    columns: project_set.columns.clone(),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             RelOperator::Filter(filter) => {
                 let input = Box::new(self.build(s_expr.child(0)?).await?);
                 let input_schema = input.output_schema()?;
@@ -317,10 +362,14 @@ impl PhysicalPlanBuilder {
 
                         match input {
                             PhysicalPlan::Exchange(PhysicalExchange { input, kind, .. }) => {
+                                let mut kind = kind;
+                                let skew_shuffle =
+                                    self.build_skew_aggregate(&mut kind, &group_items, &stat_info)?;
                                 let aggregate_partial = AggregatePartial {
                                     input,
                                     agg_funcs,
                                     group_by: group_items,
+                                    skew_shuffle,
                                     stat_info: Some(stat_info),
                                 };
 
@@ -351,6 +400,7 @@ impl PhysicalPlanBuilder {
                                 agg_funcs,
                                 group_by: group_items,
                                 input: Box::new(input),
+                                skew_shuffle: None,
 
                                 stat_info: Some(stat_info),
                             }),
@@ -491,6 +541,17 @@ impl PhysicalPlanBuilder {
                     }
                     Exchange::Broadcast => FragmentKind::Expansive,
                     Exchange::Merge => FragmentKind::Merge,
+                    Exchange::Range(items) => FragmentKind::Range(
+                        items
+                            .iter()
+                            .map(|v| SortDesc {
+                                asc: v.asc,
+                                nulls_first: v.nulls_first,
+                                order_by: v.index,
+                            })
+                            .collect(),
+                    ),
+                    Exchange::OrderedMerge => FragmentKind::OrderedMerge,
                 };
                 Ok(PhysicalPlan::Exchange(PhysicalExchange {
                     input,
@@ -646,6 +707,7 @@ impl PhysicalPlanBuilder {
             prewhere: prewhere_info,
             limit: scan.limit,
             order_by: order_by.unwrap_or_default(),
+            runtime_filters: vec![],
         })
     }
 
@@ -655,8 +717,222 @@ impl PhysicalPlanBuilder {
 
         Ok(PlanStatsInfo {
             estimated_rows: prop.cardinality,
+            plan_id: self.next_plan_id.fetch_add(1, Ordering::Relaxed),
         })
     }
+
+    /// Make the hash shuffles of the both sides of an inner join aware of the hot keys of the build side.
+    fn build_skew_shuffle(
+        &self,
+        join: &Join,
+        build_side: &mut PhysicalPlan,
+        probe_side: &mut PhysicalPlan,
+        stat_info: &PlanStatsInfo,
+    ) -> Result<()> {
+        // The probe rows with hot keys are sent to all the nodes, which is only correct
+        // if the unmatched probe rows are never output.
+        if join.join_type != JoinType::Inner
+            || !self.ctx.get_settings().get_enable_skew_aware_shuffle()?
+        {
+            return Ok(());
+        }
+
+        match (build_side, probe_side) {
+            (
+                PhysicalPlan::Exchange(PhysicalExchange {
+                    kind: build_kind @ FragmentKind::Normal,
+                    ..
+                }),
+                PhysicalPlan::Exchange(PhysicalExchange {
+                    kind: probe_kind @ FragmentKind::Normal,
+                    ..
+                }),
+            ) => {
+                let id = self.next_skew_shuffle_id.fetch_add(1, Ordering::Relaxed);
+                *build_kind = FragmentKind::SkewHash(SkewShuffle {
+                    id,
+                    plan_id: stat_info.plan_id,
+                    kind: SkewShuffleKind::Build,
+                });
+                *probe_kind = FragmentKind::SkewHash(SkewShuffle {
+                    id,
+                    plan_id: stat_info.plan_id,
+                    kind: SkewShuffleKind::Probe,
+                });
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Make the hash shuffle of the partial states of an aggregation aware of the hot group keys.
+    /// The hot keys are detected from the input of the partial aggregation, and their partial
+    /// states of all the threads are merged into one on each node before the shuffle, so the
+    /// node of a hot key merges a state from every node instead of one from every thread.
+    fn build_skew_aggregate(
+        &self,
+        kind: &mut FragmentKind,
+        group_items: &[IndexType],
+        stat_info: &PlanStatsInfo,
+    ) -> Result<Option<SkewShuffle>> {
+        if !matches!(kind, FragmentKind::Normal)
+            || group_items.is_empty()
+            || !self.ctx.get_settings().get_enable_skew_aware_shuffle()?
+        {
+            return Ok(None);
+        }
+
+        let skew = SkewShuffle {
+            id: self.next_skew_shuffle_id.fetch_add(1, Ordering::Relaxed),
+            plan_id: stat_info.plan_id,
+            kind: SkewShuffleKind::Aggregate,
+        };
+        *kind = FragmentKind::SkewHash(skew.clone());
+        Ok(Some(skew))
+    }
+
+    /// Generate runtime filters from the build keys of the hash join, and push them
+    /// down to the table scans which produce the probe keys.
+    fn build_runtime_filters(
+        &self,
+        join: &Join,
+        build_side: &PhysicalPlan,
+        probe_side: &mut PhysicalPlan,
+        build_keys: &[PhysicalScalar],
+        probe_keys: &[PhysicalScalar],
+    ) -> Result<Vec<RuntimeFilterSource>> {
+        // Only the joins that never output the unmatched probe rows can be filtered.
+        if !self.ctx.get_settings().get_enable_runtime_filter()?
+            || !matches!(
+                join.join_type,
+                JoinType::Inner
+                    | JoinType::LeftSemi
+                    | JoinType::Right
+                    | JoinType::RightSemi
+                    | JoinType::RightAnti
+            )
+        {
+            return Ok(vec![]);
+        }
+
+        // The partial filters have to be merged if the build side is partitioned by hash.
+        let partitions = match Self::first_exchange_kind(build_side) {
+            Some(FragmentKind::Normal) => self.ctx.get_cluster().nodes.len().max(1),
+            _ => 1,
+        };
+
+        let mut runtime_filters = vec![];
+        for (build_key_index, (condition, (build_key, probe_key))) in join
+            .left_conditions
+            .iter()
+            .zip(build_keys.iter().zip(probe_keys.iter()))
+            .enumerate()
+        {
+            let column_index = match condition {
+                Scalar::BoundColumnRef(column_ref) => column_ref.column.index,
+                _ => continue,
+            };
+            let data_type = probe_key.data_type().remove_nullable();
+            if build_key.data_type().remove_nullable() != data_type
+                || !matches!(
+                    data_type,
+                    DataType::Number(_) | DataType::String | DataType::Date | DataType::Timestamp
+                )
+            {
+                continue;
+            }
+
+            let mut desc = RuntimeFilterDesc {
+                id: self.next_runtime_filter_id.fetch_add(1, Ordering::Relaxed),
+                column_name: String::new(),
+                data_type,
+                remote: false,
+                partitions,
+            };
+            if Self::push_down_runtime_filter(probe_side, column_index, false, &mut desc)? {
+                runtime_filters.push(RuntimeFilterSource {
+                    build_key: build_key_index,
+                    desc,
+                });
+            }
+        }
+
+        Ok(runtime_filters)
+    }
+
+    /// Attach the runtime filter to the table scan which produces column `index`,
+    /// returns false if the column is not produced by a table scan directly.
+    fn push_down_runtime_filter(
+        plan: &mut PhysicalPlan,
+        index: IndexType,
+        remote: bool,
+        desc: &mut RuntimeFilterDesc,
+    ) -> Result<bool> {
+        let has_column = |plan: &PhysicalPlan| -> Result<bool> {
+            Ok(plan.output_schema()?.has_field(&index.to_string()))
+        };
+
+        match plan {
+            PhysicalPlan::TableScan(scan) => {
+                let column_name = match scan.name_mapping.iter().find(|(_, i)| **i == index) {
+                    Some((name, _)) => name.clone(),
+                    None => return Ok(false),
+                };
+                // Inner columns of nested types are not supported.
+                if scan.table_index == DUMMY_TABLE_INDEX
+                    || scan
+                        .source
+                        .source_info
+                        .schema()
+                        .field_with_name(&column_name)
+                        .is_err()
+                {
+                    return Ok(false);
+                }
+
+                desc.column_name = column_name;
+                desc.remote = remote;
+                scan.source
+                    .push_downs
+                    .get_or_insert_with(PushDownInfo::default)
+                    .runtime_filters
+                    .push(desc.clone());
+                Ok(true)
+            }
+            PhysicalPlan::Filter(Filter { input, .. })
+            | PhysicalPlan::EvalScalar(EvalScalar { input, .. })
+            | PhysicalPlan::Project(Project { input, .. }) => {
+                if !has_column(input)? {
+                    return Ok(false);
+                }
+                Self::push_down_runtime_filter(input, index, remote, desc)
+            }
+            PhysicalPlan::Exchange(exchange) => {
+                Self::push_down_runtime_filter(&mut exchange.input, index, true, desc)
+            }
+            PhysicalPlan::HashJoin(join) => {
+                if has_column(&join.probe)? {
+                    return Self::push_down_runtime_filter(&mut join.probe, index, remote, desc);
+                }
+                // The build side of single join is checked for duplicated keys,
+                // it should not be filtered.
+                if join.join_type != JoinType::Single && has_column(&join.build)? {
+                    return Self::push_down_runtime_filter(&mut join.build, index, remote, desc);
+                }
+                Ok(false)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn first_exchange_kind(plan: &PhysicalPlan) -> Option<FragmentKind> {
+        match plan {
+            PhysicalPlan::Exchange(exchange) => Some(exchange.kind.clone()),
+            _ => plan
+                .children()
+                .find_map(|child| Self::first_exchange_kind(child)),
+        }
+    }
 }
 
 pub struct PhysicalScalarBuilder<'a> {
@@ -728,6 +1004,17 @@ impl<'a> PhysicalScalarBuilder<'a> {
                     .collect::<Result<_>>()?,
                 return_type: *func.return_type.clone(),
             }),
+            Scalar::LambdaFunction(func) => Ok(PhysicalScalar::LambdaFunction {
+                name: func.func_name.clone(),
+                args: func
+                    .args
+                    .iter()
+                    .map(|scalar| self.build(scalar))
+                    .collect::<Result<_>>()?,
+                lambda_expr: *func.lambda_expr.clone(),
+                lambda_display: func.lambda_display.clone(),
+                return_type: *func.return_type.clone(),
+            }),
             Scalar::CastExpr(cast) => Ok(PhysicalScalar::Cast {
                 input: Box::new(self.build(&cast.argument)?),
                 target: *cast.target_type.clone(),
@@ -29,6 +29,7 @@ use crate::executor::HashJoin;
 use crate::executor::Limit;
 use crate::executor::PhysicalPlan;
 use crate::executor::Project;
+use crate::executor::ProjectSet;
 use crate::executor::Sort;
 use crate::executor::TableScan;
 use crate::executor::UnionAll;
@@ -54,6 +55,7 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::Filter(filter) => write!(f, "{}", filter)?,
             PhysicalPlan::Project(project) => write!(f, "{}", project)?,
             PhysicalPlan::EvalScalar(eval_scalar) => write!(f, "{}", eval_scalar)?,
+            PhysicalPlan::ProjectSet(project_set) => write!(f, "{}", project_set)?,
             PhysicalPlan::AggregatePartial(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::AggregateFinal(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::Sort(sort) => write!(f, "{}", sort)?,
@@ -141,6 +143,16 @@ impl Display for EvalScalar {
     }
 }
 
+impl Display for ProjectSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ProjectSet: [{}]",
+            self.srf.display(&self.arg.to_string())
+        )
+    }
+}
+
 impl Display for AggregateFinal {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let group_items = self
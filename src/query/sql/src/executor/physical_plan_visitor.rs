@@ -26,6 +26,7 @@ use super::HashJoin;
 use super::Limit;
 use super::PhysicalPlan;
 use super::Project;
+use super::ProjectSet;
 use super::Sort;
 use super::TableScan;
 use crate::executor::UnionAll;
@@ -37,6 +38,7 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::Filter(plan) => self.replace_filter(plan),
             PhysicalPlan::Project(plan) => self.replace_project(plan),
             PhysicalPlan::EvalScalar(plan) => self.replace_eval_scalar(plan),
+            PhysicalPlan::ProjectSet(plan) => self.replace_project_set(plan),
             PhysicalPlan::AggregatePartial(plan) => self.replace_aggregate_partial(plan),
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
@@ -85,6 +87,18 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_project_set(&mut self, plan: &ProjectSet) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::ProjectSet(ProjectSet {
+            input: Box::new(input),
+            srf: plan.srf.clone(),
+            arg: plan.arg.clone(),
+            columns: plan.columns.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_aggregate_partial(&mut self, plan: &AggregatePartial) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -92,6 +106,7 @@ pub trait PhysicalPlanReplacer {
             input: Box::new(input),
             group_by: plan.group_by.clone(),
             agg_funcs: plan.agg_funcs.clone(),
+            skew_shuffle: plan.skew_shuffle.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -121,6 +136,7 @@ pub trait PhysicalPlanReplacer {
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
+            runtime_filters: plan.runtime_filters.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -224,6 +240,9 @@ impl PhysicalPlan {
                 PhysicalPlan::EvalScalar(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::ProjectSet(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::AggregatePartial(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -21,6 +21,7 @@ use common_expression::types::DataType;
 use common_expression::Expr;
 use common_expression::Literal;
 use common_expression::RawExpr;
+use common_expression::RemoteExpr;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 
 type IndexType = usize;
@@ -44,6 +45,13 @@ pub enum PhysicalScalar {
         args: Vec<PhysicalScalar>,
         return_type: DataType,
     },
+    LambdaFunction {
+        name: String,
+        args: Vec<PhysicalScalar>,
+        lambda_expr: RemoteExpr,
+        lambda_display: String,
+        return_type: DataType,
+    },
 
     Cast {
         input: Box<PhysicalScalar>,
@@ -56,6 +64,7 @@ impl PhysicalScalar {
         match self {
             PhysicalScalar::Constant { data_type, .. } => data_type.clone(),
             PhysicalScalar::Function { return_type, .. } => return_type.clone(),
+            PhysicalScalar::LambdaFunction { return_type, .. } => return_type.clone(),
             PhysicalScalar::Cast { target, .. } => target.clone(),
             PhysicalScalar::IndexedVariable { data_type, .. } => data_type.clone(),
         }
@@ -73,6 +82,19 @@ impl PhysicalScalar {
                     .join(", ");
                 format!("{}({})", name, args)
             }
+            PhysicalScalar::LambdaFunction {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.pretty_display())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{}({}, {})", name, args, lambda_display)
+            }
             PhysicalScalar::Cast { input, target } => {
                 format!("CAST({} AS {})", input.pretty_display(), target.sql_name(),)
             }
@@ -97,6 +119,19 @@ impl PhysicalScalar {
                     args,
                 }
             }
+            PhysicalScalar::LambdaFunction {
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                ..
+            } => RawExpr::LambdaFunctionCall {
+                span: None,
+                name: name.clone(),
+                args: args.iter().map(|arg| arg.as_raw_expr()).collect(),
+                lambda_expr: lambda_expr.clone(),
+                lambda_display: lambda_display.clone(),
+            },
             PhysicalScalar::Cast { input, target } => {
                 let is_try = target.is_nullable();
                 RawExpr::Cast {
@@ -140,6 +175,21 @@ impl Display for PhysicalScalar {
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
+            PhysicalScalar::LambdaFunction {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => write!(
+                f,
+                "{}({}, {})",
+                name,
+                args.iter()
+                    .map(|arg| format!("{}", arg))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                lambda_display
+            ),
             PhysicalScalar::Cast { input, target } => {
                 write!(f, "CAST({} AS {})", input, target.sql_name())
             }
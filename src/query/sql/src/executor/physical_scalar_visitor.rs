@@ -53,6 +53,11 @@ pub trait PhysicalScalarVisitor: Sized {
                                         stack.push(RecursionProcessing::Call(arg));
                                     }
                                 }
+                                PhysicalScalar::LambdaFunction { args, .. } => {
+                                    for arg in args {
+                                        stack.push(RecursionProcessing::Call(arg));
+                                    }
+                                }
                                 PhysicalScalar::Cast { input, .. } => {
                                     stack.push(RecursionProcessing::Call(input));
                                 }
@@ -38,6 +38,7 @@ use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::Scalar;
@@ -130,6 +131,22 @@ impl<'a> AggregateRewriter<'a> {
                 }
                 .into())
             }
+            Scalar::LambdaFunction(lambda) => {
+                let new_args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(LambdaFunc {
+                    func_name: lambda.func_name.clone(),
+                    args: new_args,
+                    params: lambda.params.clone(),
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
             Scalar::CastExpr(cast) => Ok(CastExpr {
                 argument: Box::new(self.visit(&cast.argument)?),
                 from_type: cast.from_type.clone(),
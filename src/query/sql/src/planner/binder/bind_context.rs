@@ -95,6 +95,10 @@ pub struct BindContext {
     pub in_grouping: bool,
 
     pub ctes_map: Box<DashMap<String, CteInfo>>,
+
+    /// The arguments of the `match(<column>, <query>)` in the WHERE clause,
+    /// `score()` is resolved to the relevance of the rows to it.
+    pub match_predicate: Option<Vec<Scalar>>,
 }
 
 #[derive(Clone, Debug)]
@@ -112,6 +116,7 @@ impl BindContext {
             aggregate_info: AggregateInfo::default(),
             in_grouping: false,
             ctes_map: Box::new(DashMap::new()),
+            match_predicate: None,
         }
     }
 
@@ -122,6 +127,7 @@ impl BindContext {
             aggregate_info: Default::default(),
             in_grouping: false,
             ctes_map: parent.ctes_map.clone(),
+            match_predicate: None,
         }
     }
 
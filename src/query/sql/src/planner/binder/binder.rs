@@ -26,6 +26,7 @@ use common_exception::Result;
 use common_expression::types::DataType;
 use common_meta_types::UserDefinedFunction;
 
+use crate::planner::semantic::ResultCacheChecker;
 use crate::planner::udf_validator::UDFValidator;
 use crate::plans::AlterUDFPlan;
 use crate::plans::CallPlan;
@@ -89,12 +90,20 @@ impl<'a> Binder {
         let plan = match stmt {
             Statement::Query(query) => {
                 let (s_expr, bind_context) = self.bind_query(bind_context, query).await?;
+                let formatted_ast = if self.ctx.get_settings().get_enable_query_result_cache()?
+                    && ResultCacheChecker::is_cacheable(&s_expr, &self.metadata)
+                {
+                    Some(query.to_string())
+                } else {
+                    None
+                };
                 Plan::Query {
                     s_expr: Box::new(s_expr),
                     metadata: self.metadata.clone(),
                     bind_context: Box::new(bind_context),
                     rewrite_kind: None,
                     ignore_result: query.ignore_result,
+                    formatted_ast,
                 }
             }
 
@@ -169,6 +178,18 @@ impl<'a> Binder {
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
 
+            // Sequences
+            Statement::CreateSequence(stmt) => self.bind_create_sequence(stmt).await?,
+            Statement::DropSequence(stmt) => self.bind_drop_sequence(stmt).await?,
+
+            // Inverted indexes
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
+
+            // Workload groups
+            Statement::CreateWorkloadGroup(stmt) => self.bind_create_workload_group(stmt).await?,
+            Statement::DropWorkloadGroup(stmt) => self.bind_drop_workload_group(stmt).await?,
+
             // Users
             Statement::CreateUser(stmt) => self.bind_create_user(stmt).await?,
             Statement::DropUser { if_exists, user } => Plan::DropUser(Box::new(DropUserPlan {
@@ -318,6 +339,11 @@ impl<'a> Binder {
                     .await?
             }
 
+            // Transactions
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
+
             // share statements
             Statement::CreateShare(stmt) => {
                 self.bind_create_share(stmt).await?
@@ -361,8 +387,15 @@ impl<'a> Binder {
         let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL, &backtrace)?;
         let mut plan = self.bind_statement(bind_context, &stmt).await?;
 
-        if let Plan::Query { rewrite_kind, .. } = &mut plan {
-            *rewrite_kind = Some(rewrite_kind_r)
+        if let Plan::Query {
+            rewrite_kind,
+            formatted_ast,
+            ..
+        } = &mut plan
+        {
+            *rewrite_kind = Some(rewrite_kind_r);
+            // The results of the rewritten SHOW statements are never cached.
+            *formatted_ast = None;
         }
         Ok(plan)
     }
@@ -240,7 +240,8 @@ impl<'a> Binder {
         let from = DataSourcePlan {
             catalog: dst_catalog_name.to_string(),
             source_info: DataSourceInfo::StageSource(StageTableInfo {
-                schema: table.schema(),
+                // The stored generated columns are not in the files.
+                schema: Arc::new(table.schema().remove_computed_fields()),
                 user_stage_info: stage_info,
                 path,
                 files: stmt.files.clone(),
@@ -298,7 +299,8 @@ impl<'a> Binder {
         let from = DataSourcePlan {
             catalog: dst_catalog_name.to_string(),
             source_info: DataSourceInfo::StageSource(StageTableInfo {
-                schema: table.schema(),
+                // The stored generated columns are not in the files.
+                schema: Arc::new(table.schema().remove_computed_fields()),
                 user_stage_info: stage_info,
                 path,
                 files: stmt.files.clone(),
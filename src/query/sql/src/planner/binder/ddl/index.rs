@@ -0,0 +1,123 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateInvertedIndexStmt;
+use common_ast::ast::DropInvertedIndexStmt;
+use common_ast::ast::Identifier;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::plans::CreateInvertedIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
+use crate::plans::Plan;
+
+impl<'a> Binder {
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt<'a>,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index,
+            catalog,
+            database,
+            table,
+            column,
+        } = stmt;
+
+        let (catalog, database, table) = self.normalize_index_table(catalog, database, table);
+        let index = self.normalize_index_name(index)?;
+        let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+
+        let schema = self
+            .ctx
+            .get_table(&catalog, &database, &table)
+            .await?
+            .schema();
+        let field = schema.field_with_name(&column)?;
+        if field.data_type().remove_nullable() != TableDataType::String {
+            return Err(ErrorCode::SemanticError(format!(
+                "inverted index can only be created on string columns, but column {column} is of type {}",
+                field.data_type()
+            )));
+        }
+
+        let plan = CreateInvertedIndexPlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            catalog,
+            database,
+            table,
+            index,
+            column,
+        };
+        Ok(Plan::CreateInvertedIndex(Box::new(plan)))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt<'a>,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) = self.normalize_index_table(catalog, database, table);
+        let plan = DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            catalog,
+            database,
+            table,
+            index: self.normalize_index_name(index)?,
+        };
+        Ok(Plan::DropInvertedIndex(Box::new(plan)))
+    }
+
+    fn normalize_index_table(
+        &self,
+        catalog: &Option<Identifier<'a>>,
+        database: &Option<Identifier<'a>>,
+        table: &Identifier<'a>,
+    ) -> (String, String, String) {
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+        (catalog, database, table)
+    }
+
+    fn normalize_index_name(&self, index: &Identifier<'a>) -> Result<String> {
+        let index = normalize_identifier(index, &self.name_resolution_ctx).name;
+        // The indexes are kept in a table option as `<index>:<column>` pairs separated by commas.
+        if index.contains(',') || index.contains(':') {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid inverted index name {index}, it can't contain ',' or ':'"
+            )));
+        }
+        Ok(index)
+    }
+}
@@ -15,8 +15,11 @@
 mod account;
 mod catalog;
 mod database;
+mod index;
 mod role;
+mod sequence;
 mod share;
 mod stage;
 mod table;
 mod view;
+mod workload_group;
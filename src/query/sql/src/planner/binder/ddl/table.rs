@@ -19,7 +19,9 @@ use std::sync::Arc;
 use common_ast::ast::AlterTableAction;
 use common_ast::ast::AlterTableStmt;
 use common_ast::ast::AnalyzeTableStmt;
+use common_ast::ast::ColumnDefinition;
 use common_ast::ast::CompactTarget;
+use common_ast::ast::ComputedExpr as AstComputedExpr;
 use common_ast::ast::CreateTableSource;
 use common_ast::ast::CreateTableStmt;
 use common_ast::ast::DescribeTableStmt;
@@ -38,6 +40,7 @@ use common_ast::ast::ShowTablesStatusStmt;
 use common_ast::ast::ShowTablesStmt;
 use common_ast::ast::Statement;
 use common_ast::ast::TableReference;
+use common_ast::ast::TimeTravelPoint;
 use common_ast::ast::TruncateTableStmt;
 use common_ast::ast::UndropTableStmt;
 use common_ast::ast::UriLocation;
@@ -46,12 +49,15 @@ use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
 use common_ast::Backtrace;
 use common_ast::Dialect;
+use common_base::base::uuid::Uuid;
+use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_schema_type;
 use common_expression::infer_table_schema;
 use common_expression::type_check::common_super_type;
 use common_expression::types::DataType;
+use common_expression::ComputedExpr;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
 use common_expression::TableField;
@@ -62,6 +68,7 @@ use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::is_reserved_opt_key;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use tracing::debug;
 
 use crate::binder::location::parse_uri_location;
@@ -75,8 +82,11 @@ use crate::optimizer::OptimizerContext;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::identity_default_expr;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTableSource;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateTablePlanV2;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
@@ -337,7 +347,7 @@ impl<'a> Binder {
             )?;
         }
 
-        let (storage_params, part_prefix) = match uri_location {
+        let (mut storage_params, mut part_prefix) = match uri_location {
             Some(uri) => {
                 let mut uri = UriLocation {
                     protocol: uri.protocol.clone(),
@@ -368,6 +378,37 @@ impl<'a> Binder {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        // `CREATE TABLE ... CLONE` shares the data of the source table, so the storage,
+        // the options and the cluster key of the source table are inherited.
+        let mut clone_source = None;
+        let mut inherited_cluster_key = None;
+        if let Some(CreateTableSource::Clone {
+            catalog: source_catalog,
+            database: source_database,
+            table: source_table,
+            travel_point,
+        }) = source
+        {
+            if as_query.is_some() || uri_location.is_some() || engine != Engine::Fuse {
+                return Err(ErrorCode::BadArguments(
+                    "CREATE TABLE ... CLONE can only create a FUSE table without AS SELECT or external location",
+                ));
+            }
+            let (source, source_table) = self
+                .resolve_clone_source(source_catalog, source_database, source_table, travel_point)
+                .await?;
+            let source_meta = &source_table.get_table_info().meta;
+            storage_params = source_meta.storage_params.clone();
+            part_prefix = source_meta.part_prefix.clone();
+            for (key, value) in source_meta.options.iter() {
+                if !is_reserved_opt_key(key) && key != OPT_KEY_SNAPSHOT_LOCATION {
+                    options.entry(key.clone()).or_insert_with(|| value.clone());
+                }
+            }
+            inherited_cluster_key = source_meta.default_cluster_key.clone();
+            clone_source = Some(source);
+        }
+
         // Build table schema
         let (schema, field_default_exprs, field_comments) = match (&source, &as_query) {
             (Some(source), None) => {
@@ -419,6 +460,15 @@ impl<'a> Binder {
             ))?,
         };
 
+        // The default expressions of the identity columns take the values from their sequences.
+        let mut field_default_exprs = field_default_exprs;
+        let identity_sequences = match &source {
+            Some(CreateTableSource::Columns(columns)) => {
+                self.analyze_identity_columns(&database, &table, columns, &mut field_default_exprs)?
+            }
+            _ => vec![],
+        };
+
         if engine == Engine::Fuse {
             // Currently, [Table] can not accesses its database id yet, thus
             // here we keep the db id AS an entry of `table_meta.options`.
@@ -441,7 +491,7 @@ impl<'a> Binder {
                 .analyze_cluster_keys(cluster_by, schema.clone())
                 .await?;
             if keys.is_empty() {
-                None
+                inherited_cluster_key
             } else {
                 Some(format!("({})", keys.join(", ")))
             }
@@ -460,6 +510,7 @@ impl<'a> Binder {
             options,
             field_default_exprs,
             field_comments,
+            identity_sequences,
             cluster_key,
             as_select: if let Some(query) = as_query {
                 let bind_context = BindContext::new();
@@ -472,6 +523,7 @@ impl<'a> Binder {
             } else {
                 None
             },
+            clone_source,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -874,13 +926,77 @@ impl<'a> Binder {
                     &[],
                 );
                 let mut fields = Vec::with_capacity(columns.len());
-                let mut fields_default_expr = Vec::with_capacity(columns.len());
-                let mut fields_comments = Vec::with_capacity(columns.len());
                 for column in columns.iter() {
                     let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
                     let schema_data_type = TypeChecker::resolve_type_name(&column.data_type)?;
+                    fields.push(TableField::new(&name, schema_data_type));
+                }
+
+                // Generated columns are computed from the other columns, but not from
+                // the generated ones.
+                let mut columns_context = BindContext::new();
+                for (index, (column, field)) in columns.iter().zip(fields.iter()).enumerate() {
+                    if column.computed_expr.is_none() {
+                        columns_context.add_column_binding(ColumnBinding {
+                            database_name: None,
+                            table_name: None,
+                            column_name: field.name().clone(),
+                            index,
+                            data_type: Box::new(DataType::from(field.data_type())),
+                            visibility: Visibility::Visible,
+                        });
+                    }
+                }
+                let mut computed_binder = ScalarBinder::new(
+                    &columns_context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                );
 
-                    fields.push(TableField::new(&name, schema_data_type.clone()));
+                let mut fields_default_expr = Vec::with_capacity(columns.len());
+                let mut fields_comments = Vec::with_capacity(columns.len());
+                for (column, field) in columns.iter().zip(fields.iter_mut()) {
+                    let name = field.name().clone();
+                    let schema_data_type = field.data_type().clone();
+                    if column.identity.is_some() {
+                        if column.default_expr.is_some() || column.computed_expr.is_some() {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "identity column {name} can't have a default or generated expression"
+                            )));
+                        }
+                        let data_type = DataType::from(&schema_data_type);
+                        if !data_type.remove_nullable().is_integer() {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "identity column {name} must be of an integer type, but it is of type {data_type}"
+                            )));
+                        }
+                    }
+                    if let Some(computed_expr) = &column.computed_expr {
+                        if column.default_expr.is_some() {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "generated column {name} can't have a default expression"
+                            )));
+                        }
+                        let (expr, computed_expr) = match computed_expr {
+                            AstComputedExpr::Virtual(expr) => {
+                                (expr, ComputedExpr::Virtual(expr.to_string()))
+                            }
+                            AstComputedExpr::Stored(expr) => {
+                                (expr, ComputedExpr::Stored(expr.to_string()))
+                            }
+                        };
+                        let (_expr, expr_type) = computed_binder.bind(expr).await?;
+                        let data_type = DataType::from(&schema_data_type);
+                        if common_super_type(data_type.clone(), expr_type.clone()).is_none() {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "column {name} is of type {} but generated expression is of type {}",
+                                data_type, expr_type
+                            )));
+                        }
+                        *field = field.clone().with_computed_expr(Some(computed_expr));
+                    }
                     fields_default_expr.push({
                         if let Some(default_expr) = &column.default_expr {
                             let (_expr, expr_type) = scalar_binder.bind(default_expr).await?;
@@ -921,10 +1037,76 @@ impl<'a> Binder {
                     let (plan, _, _) = planner.plan_sql(query).await?;
                     Ok((infer_table_schema(&plan.schema())?, vec![], vec![]))
                 } else {
-                    Ok((table.schema(), vec![], table.field_comments().clone()))
+                    // The virtual generated columns are only in the meta of the table.
+                    let schema = table.get_table_info().meta.schema.clone();
+                    Ok((schema, vec![], table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                let (_, table) = self
+                    .resolve_clone_source(catalog, database, table, travel_point)
+                    .await?;
+                let schema = table.get_table_info().meta.schema.clone();
+                // The comments are of the current columns, which may differ from the cloned ones.
+                let field_comments = if table.field_comments().len() == schema.num_fields() {
+                    table.field_comments().clone()
+                } else {
+                    vec![]
+                };
+                Ok((schema, vec![], field_comments))
+            }
+        }
+    }
+
+    /// Resolve the source table of `CREATE TABLE ... CLONE`, navigated to the clone point.
+    async fn resolve_clone_source(
+        &self,
+        catalog: &Option<Identifier<'a>>,
+        database: &Option<Identifier<'a>>,
+        table: &Identifier<'a>,
+        travel_point: &Option<TimeTravelPoint<'a>>,
+    ) -> Result<(CloneTableSource, Arc<dyn Table>)> {
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database.as_ref().map_or_else(
+            || self.ctx.get_current_database(),
+            |ident| normalize_identifier(ident, &self.name_resolution_ctx).name,
+        );
+        let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+        let mut table = self.ctx.get_table(&catalog, &database, &table_name).await?;
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "can not clone table {} of engine {}, only FUSE table is supported",
+                table_name,
+                table.engine()
+            )));
         }
+
+        let point = match travel_point {
+            Some(travel_point) => {
+                let point = self
+                    .resolve_data_travel_point(&BindContext::new(), travel_point)
+                    .await?;
+                table = table.navigate_to(&point).await?;
+                Some(point)
+            }
+            None => None,
+        };
+
+        let source = CloneTableSource {
+            catalog,
+            database,
+            table: table_name,
+            point,
+        };
+        Ok((source, table))
     }
 
     /// Validate the schema of the table to be created.
@@ -943,6 +1125,41 @@ impl<'a> Binder {
         Ok(())
     }
 
+    /// Every identity column gets a sequence of its own, which is created together with the table.
+    ///
+    /// The sequences are named by uuid, thus the names never clash, even if the table is renamed.
+    fn analyze_identity_columns(
+        &self,
+        database: &str,
+        table: &str,
+        columns: &[ColumnDefinition<'a>],
+        field_default_exprs: &mut [Option<String>],
+    ) -> Result<Vec<CreateSequencePlan>> {
+        let mut sequences = vec![];
+        for (column, default_expr) in columns.iter().zip(field_default_exprs.iter_mut()) {
+            if let Some(identity) = &column.identity {
+                let increment = identity.increment.unwrap_or(1);
+                if increment == 0 {
+                    return Err(ErrorCode::BadArguments(
+                        "The increment of an identity column can not be 0",
+                    ));
+                }
+                let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+                let sequence = format!("identity_{}", Uuid::new_v4().simple());
+                *default_expr = Some(identity_default_expr(&sequence));
+                sequences.push(CreateSequencePlan {
+                    if_not_exists: false,
+                    tenant: self.ctx.get_tenant(),
+                    sequence,
+                    start: identity.start.unwrap_or(1),
+                    increment,
+                    comment: format!("identity of {database}.{table}.{name}"),
+                });
+            }
+        }
+        Ok(sequences)
+    }
+
     fn insert_table_option_with_validation(
         &self,
         options: &mut BTreeMap<String, String>,
@@ -1034,5 +1251,8 @@ fn is_expr_non_deterministic(expr: &common_expression::Expr) -> bool {
             function.signature.property.non_deterministic
                 || args.iter().any(is_expr_non_deterministic)
         }
+        common_expression::Expr::LambdaFunctionCall { args, .. } => {
+            args.iter().any(is_expr_non_deterministic)
+        }
     }
 }
@@ -0,0 +1,88 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateWorkloadGroupStmt;
+use common_ast::ast::DropWorkloadGroupStmt;
+use common_ast::ast::WorkloadGroupAssignee;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::WorkloadGroup;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::plans::CreateWorkloadGroupPlan;
+use crate::plans::DropWorkloadGroupPlan;
+use crate::plans::Plan;
+
+impl<'a> Binder {
+    pub(in crate::planner::binder) async fn bind_create_workload_group(
+        &mut self,
+        stmt: &CreateWorkloadGroupStmt<'a>,
+    ) -> Result<Plan> {
+        let CreateWorkloadGroupStmt {
+            if_not_exists,
+            name,
+            assignees,
+            options,
+        } = stmt;
+
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let mut group = WorkloadGroup::new(&name);
+        for assignee in assignees {
+            match assignee {
+                WorkloadGroupAssignee::User(user) => group.users.push(user.clone()),
+                WorkloadGroupAssignee::Role(role) => group.roles.push(role.clone()),
+            }
+        }
+        for (key, value) in options {
+            match key.as_str() {
+                "max_concurrency" => group.max_concurrency = *value,
+                "memory_share" if *value > 100 => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "memory_share of a workload group is a percentage, but got {value}"
+                    )));
+                }
+                "memory_share" => group.memory_share = *value,
+                "cpu_slots" => group.cpu_slots = *value,
+                "queue_timeout" => group.queue_timeout = *value,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Unknown workload group option {key}, expecting one of max_concurrency, memory_share, cpu_slots, queue_timeout"
+                    )));
+                }
+            }
+        }
+
+        let plan = CreateWorkloadGroupPlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            group,
+        };
+        Ok(Plan::CreateWorkloadGroup(Box::new(plan)))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_workload_group(
+        &mut self,
+        stmt: &DropWorkloadGroupStmt<'a>,
+    ) -> Result<Plan> {
+        let DropWorkloadGroupStmt { if_exists, name } = stmt;
+
+        let plan = DropWorkloadGroupPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        };
+        Ok(Plan::DropWorkloadGroup(Box::new(plan)))
+    }
+}
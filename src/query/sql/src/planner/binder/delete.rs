@@ -17,6 +17,7 @@ use common_ast::ast::TableReference;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::binder::replace_column_refs;
 use crate::binder::Binder;
 use crate::binder::ScalarBinder;
 use crate::plans::DeletePlan;
@@ -57,6 +58,16 @@ impl<'a> Binder {
             .bind_table_reference(bind_context, table_reference)
             .await?;
 
+        // The virtual generated columns are replaced by their expressions, since the mutation
+        // is applied to the stored columns.
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let virtual_columns = self
+            .bind_virtual_computed_columns(&context, table.as_ref())
+            .await?;
+
         let mut scalar_binder = ScalarBinder::new(
             &context,
             self.ctx.clone(),
@@ -67,7 +78,7 @@ impl<'a> Binder {
 
         let selection = if let Some(expr) = filter {
             let (scalar, _) = scalar_binder.bind(expr).await?;
-            Some(scalar)
+            Some(replace_column_refs(&scalar, &virtual_columns)?)
         } else {
             None
         };
@@ -14,11 +14,29 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Indirection;
 use common_ast::ast::InsertSource;
 use common_ast::ast::InsertStmt;
+use common_ast::ast::Literal;
+use common_ast::ast::Query;
+use common_ast::ast::SelectStmt;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
 use common_ast::ast::Statement;
+use common_ast::ast::TableReference;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::type_check::check_literal;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Scalar;
+use common_expression::TableField;
 use common_expression::TableSchemaRefExt;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
 use common_meta_types::FileFormatOptions;
 
 use crate::binder::Binder;
@@ -44,6 +62,7 @@ impl<'a> Binder {
             columns,
             source,
             overwrite,
+            partition,
         } = stmt;
         let catalog_name = catalog.as_ref().map_or_else(
             || self.ctx.get_current_catalog(),
@@ -60,22 +79,76 @@ impl<'a> Binder {
             .await?;
         let table_id = table.get_id();
 
+        // The static partition columns are filled by the values of the PARTITION clause.
+        let mut static_partition: Vec<(String, Scalar)> = Vec::with_capacity(partition.len());
+        let mut static_fields = Vec::with_capacity(partition.len());
+        if !partition.is_empty() {
+            if !matches!(source, InsertSource::Select { .. }) {
+                return Err(ErrorCode::SemanticError(
+                    "PARTITION clause is only supported by INSERT ... SELECT",
+                ));
+            }
+            let partition_keys = table.partition_keys();
+            let table_schema = table.schema();
+            for (key, value) in partition {
+                let key = normalize_identifier(key, &self.name_resolution_ctx).name;
+                if !partition_keys.contains(&key) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "'{}' is not a partition column of table '{}'",
+                        key, table_name
+                    )));
+                }
+                let specified = columns.iter().any(|ident| {
+                    normalize_identifier(ident, &self.name_resolution_ctx).name == key
+                });
+                if specified || static_partition.iter().any(|(k, _)| k == &key) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "partition column '{}' is specified more than once",
+                        key
+                    )));
+                }
+                let field = table_schema.field_with_name(&key)?;
+                static_partition.push((key, self.bind_partition_value(field, value)?));
+                static_fields.push(field.clone());
+            }
+        }
+
         let schema = if columns.is_empty() {
-            table.schema()
+            // The generated columns are computed from the inserted columns.
+            Arc::new(table.schema().remove_computed_fields())
         } else {
-            let schema = table.schema();
+            let schema = table.get_table_info().meta.schema.clone();
             let fields = columns
                 .iter()
                 .map(|ident| {
-                    schema
-                        .field_with_name(
-                            &normalize_identifier(ident, &self.name_resolution_ctx).name,
-                        )
-                        .map(|v| v.clone())
+                    let field = schema.field_with_name(
+                        &normalize_identifier(ident, &self.name_resolution_ctx).name,
+                    )?;
+                    if field.computed_expr().is_some() {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "The value specified for generated column '{}' is not allowed",
+                            field.name()
+                        )));
+                    }
+                    Ok(field.clone())
                 })
                 .collect::<Result<Vec<_>>>()?;
             TableSchemaRefExt::create(fields)
         };
+        // The static partition columns come first, the SELECT is rewritten to produce them.
+        let schema = if static_fields.is_empty() {
+            schema
+        } else {
+            let mut fields = static_fields;
+            fields.extend(
+                schema
+                    .fields()
+                    .iter()
+                    .filter(|field| !static_partition.iter().any(|(k, _)| k == field.name()))
+                    .cloned(),
+            );
+            TableSchemaRefExt::create(fields)
+        };
 
         let input_source: Result<InsertInputSource> = match source.clone() {
             InsertSource::Streaming {
@@ -107,6 +180,11 @@ impl<'a> Binder {
                 }
             }
             InsertSource::Select { query } => {
+                let query = if partition.is_empty() {
+                    query
+                } else {
+                    Box::new(Self::select_static_partition(query, partition))
+                };
                 let statement = Statement::Query(query);
                 let select_plan = self.bind_statement(bind_context, &statement).await?;
                 let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
@@ -125,8 +203,93 @@ impl<'a> Binder {
             schema,
             overwrite: *overwrite,
             source: input_source?,
+            partition: static_partition,
         };
 
         Ok(Plan::Insert(Box::new(plan)))
     }
+
+    // Cast the value of the PARTITION clause to the type of the partition column.
+    fn bind_partition_value(&self, field: &TableField, value: &Literal) -> Result<Scalar> {
+        let literal = match value {
+            Literal::Integer(v) => common_expression::Literal::UInt64(*v),
+            Literal::Float(v) => common_expression::Literal::Float64(F64::from(*v)),
+            Literal::String(v) => common_expression::Literal::String(v.as_bytes().to_vec()),
+            Literal::Boolean(v) => common_expression::Literal::Boolean(*v),
+            Literal::Null => return Ok(Scalar::Null),
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Unsupported partition value: {value}"
+                )));
+            }
+        };
+        let (scalar, data_type) = check_literal(&literal);
+        let expr = common_expression::Expr::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(common_expression::Expr::Constant {
+                span: None,
+                scalar,
+                data_type,
+            }),
+            dest_type: DataType::from(field.data_type()),
+        };
+        let block = DataBlock::empty();
+        let func_ctx = self.ctx.try_get_function_context()?;
+        let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&expr).map_err(|_| {
+            ErrorCode::SemanticError(format!(
+                "Failed to cast partition value {} to data type {}",
+                value,
+                field.data_type()
+            ))
+        })?;
+        Ok(value.into_scalar().unwrap())
+    }
+
+    // Rewrite the query to `SELECT <value> AS <key>, ..., * FROM (<query>)`,
+    // which produces the static partition columns ahead of the selected columns.
+    fn select_static_partition<'a>(
+        query: Box<Query<'a>>,
+        partition: &[(Identifier<'a>, Literal)],
+    ) -> Query<'a> {
+        let span = query.span;
+        let mut select_list = partition
+            .iter()
+            .map(|(key, value)| SelectTarget::AliasedExpr {
+                expr: Box::new(Expr::Literal {
+                    span,
+                    lit: value.clone(),
+                }),
+                alias: Some(key.clone()),
+            })
+            .collect::<Vec<_>>();
+        select_list.push(SelectTarget::QualifiedName {
+            qualified: vec![Indirection::Star],
+            exclude: None,
+        });
+
+        Query {
+            span,
+            with: None,
+            body: SetExpr::Select(Box::new(SelectStmt {
+                span,
+                distinct: false,
+                select_list,
+                from: vec![TableReference::Subquery {
+                    span,
+                    lateral: false,
+                    subquery: query,
+                    alias: None,
+                }],
+                selection: None,
+                group_by: vec![],
+                having: None,
+            })),
+            order_by: vec![],
+            limit: vec![],
+            offset: None,
+            ignore_result: false,
+        }
+    }
 }
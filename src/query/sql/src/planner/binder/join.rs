@@ -18,24 +18,33 @@ use async_recursion::async_recursion;
 use common_ast::ast::split_conjunctions_expr;
 use common_ast::ast::split_equivalent_predicate_expr;
 use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
 use common_ast::ast::JoinCondition;
 use common_ast::ast::JoinOperator;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
+use common_ast::DisplayError;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
 
+use crate::binder::project_set::is_set_returning_function;
 use crate::binder::JoinPredicate;
 use crate::binder::Visibility;
 use crate::normalize_identifier;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
+use crate::optimizer::SubqueryRewriter;
 use crate::planner::binder::scalar::ScalarBinder;
 use crate::planner::binder::wrap_cast;
 use crate::planner::binder::Binder;
 use crate::planner::semantic::NameResolutionContext;
 use crate::plans::BoundColumnRef;
+use crate::plans::ComparisonExpr;
+use crate::plans::ComparisonOp;
 use crate::plans::Filter;
 use crate::plans::Join;
 use crate::plans::JoinType;
@@ -60,8 +69,68 @@ impl<'a> Binder {
     ) -> Result<(SExpr, BindContext)> {
         let (left_child, left_context) =
             self.bind_table_reference(bind_context, &join.left).await?;
-        let (right_child, right_context) =
-            self.bind_table_reference(bind_context, &join.right).await?;
+
+        match &join.right {
+            TableReference::TableFunction {
+                name,
+                params,
+                named_params,
+                alias,
+                ..
+            } if is_set_returning_function(
+                &normalize_identifier(name, &self.name_resolution_ctx).name,
+            ) =>
+            {
+                // Set-returning functions are implicitly lateral, they are bound on top of
+                // the left side with its columns visible to the arguments.
+                return self
+                    .bind_lateral_project_set(
+                        left_child,
+                        &left_context,
+                        join,
+                        name,
+                        params,
+                        named_params,
+                        alias,
+                    )
+                    .await;
+            }
+            _ => (),
+        }
+
+        let (right_child, right_context) = match &join.right {
+            TableReference::Subquery { lateral: true, .. } => {
+                let (right_child, right_context) = self
+                    .bind_table_reference(&left_context, &join.right)
+                    .await?;
+                let outer_columns = RelExpr::with_s_expr(&right_child)
+                    .derive_relational_prop()?
+                    .outer_columns;
+                if !outer_columns.is_empty() {
+                    let left_columns = left_context
+                        .columns
+                        .iter()
+                        .map(|column| column.index)
+                        .collect::<ColumnSet>();
+                    if !outer_columns.is_subset(&left_columns) {
+                        return Err(ErrorCode::Unimplemented(
+                            "LATERAL subquery referring to the columns of an outer query is not supported yet",
+                        ));
+                    }
+                    return self
+                        .bind_correlated_lateral_join(
+                            bind_context,
+                            join,
+                            (left_child, left_context),
+                            (right_child, right_context),
+                            &outer_columns,
+                        )
+                        .await;
+                }
+                (right_child, right_context)
+            }
+            _ => self.bind_table_reference(bind_context, &join.right).await?,
+        };
 
         check_duplicate_join_tables(&left_context, &right_context)?;
 
@@ -169,6 +238,165 @@ impl<'a> Binder {
         Ok((s_expr, bind_context))
     }
 
+    #[allow(clippy::too_many_arguments)]
+    async fn bind_lateral_project_set(
+        &mut self,
+        left_child: SExpr,
+        left_context: &BindContext,
+        join: &common_ast::ast::Join<'a>,
+        name: &Identifier<'a>,
+        params: &[Expr<'a>],
+        named_params: &[(Identifier<'a>, Expr<'a>)],
+        alias: &Option<TableAlias<'a>>,
+    ) -> Result<(SExpr, BindContext)> {
+        match (&join.op, &join.condition) {
+            (JoinOperator::Inner | JoinOperator::CrossJoin, JoinCondition::None)
+            | (JoinOperator::Inner, JoinCondition::On(_)) => (),
+            _ => {
+                return Err(ErrorCode::SemanticError(name.span.display_error(format!(
+                    "{} can only be used in inner or cross join, \
+                    use `outer => true` of flatten to keep the rows producing nothing",
+                    name.name
+                ))));
+            }
+        }
+
+        let (mut s_expr, mut bind_context) = self
+            .bind_project_set(left_context, left_child, name, params, named_params, alias)
+            .await?;
+        if let JoinCondition::On(condition) = &join.condition {
+            s_expr = self
+                .bind_where(&mut bind_context, condition, s_expr)
+                .await?;
+        }
+        Ok((s_expr, bind_context))
+    }
+
+    /// Bind a join with a LATERAL subquery which refers to the columns of the left side. The
+    /// subquery is decorrelated into a join with the distinct values of the correlated columns,
+    /// which is joined back to the left side by the correlated columns.
+    async fn bind_correlated_lateral_join(
+        &mut self,
+        bind_context: &BindContext,
+        join: &common_ast::ast::Join<'a>,
+        (left_child, left_context): (SExpr, BindContext),
+        (right_child, right_context): (SExpr, BindContext),
+        correlated_columns: &ColumnSet,
+    ) -> Result<(SExpr, BindContext)> {
+        let join_type = match (&join.op, &join.condition) {
+            (JoinOperator::CrossJoin, JoinCondition::None) => JoinType::Inner,
+            (JoinOperator::Inner, _) => JoinType::Inner,
+            (JoinOperator::LeftOuter, JoinCondition::On(_) | JoinCondition::Using(_)) => {
+                JoinType::Left
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "LATERAL subquery can only be used in inner, cross or left outer join \
+                    with join conditions",
+                ));
+            }
+        };
+        check_duplicate_join_tables(&left_context, &right_context)?;
+
+        let mut bind_context = bind_context.replace();
+        let mut left_join_conditions: Vec<Scalar> = vec![];
+        let mut right_join_conditions: Vec<Scalar> = vec![];
+        let mut non_equi_conditions: Vec<Scalar> = vec![];
+        let mut other_conditions: Vec<Scalar> = vec![];
+        let mut join_condition_resolver = JoinConditionResolver::new(
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            join.op.clone(),
+            &left_context,
+            &right_context,
+            &mut bind_context,
+            &join.condition,
+        );
+        join_condition_resolver
+            .resolve(
+                &mut left_join_conditions,
+                &mut right_join_conditions,
+                &mut non_equi_conditions,
+                &mut other_conditions,
+                &join.op,
+            )
+            .await?;
+
+        // The correlated columns are compared null-safe as in the decorrelated subqueries, so
+        // the join conditions are evaluated as non-equi conditions, which are not null-safe.
+        let mut conditions = left_join_conditions
+            .into_iter()
+            .zip(right_join_conditions)
+            .map(|(left, right)| {
+                Scalar::ComparisonExpr(ComparisonExpr {
+                    op: ComparisonOp::Equal,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    return_type: Box::new(DataType::Nullable(Box::new(DataType::Boolean))),
+                })
+            })
+            .collect::<Vec<_>>();
+        conditions.extend(non_equi_conditions);
+        conditions.extend(other_conditions);
+
+        let mut rewriter = SubqueryRewriter::new(self.metadata.clone());
+        let lateral =
+            rewriter.decorrelate_lateral(&left_child, &right_child, correlated_columns)?;
+        let s_expr = match lateral.projection {
+            None => SExpr::create_binary(
+                Join {
+                    left_conditions: lateral.left_conditions,
+                    right_conditions: lateral.right_conditions,
+                    non_equi_conditions: conditions,
+                    join_type,
+                    marker_index: None,
+                    from_correlated_subquery: true,
+                }
+                .into(),
+                left_child,
+                lateral.right,
+            ),
+            // The aggregation returns a row for every row of the left side.
+            Some(_) if join_type == JoinType::Left => {
+                return Err(ErrorCode::Unimplemented(
+                    "LEFT JOIN LATERAL of an aggregation without GROUP BY is not supported yet, \
+                    use CROSS JOIN LATERAL instead",
+                ));
+            }
+            Some(projection) => {
+                let mut s_expr = SExpr::create_unary(
+                    projection.into(),
+                    SExpr::create_binary(
+                        Join {
+                            left_conditions: lateral.left_conditions,
+                            right_conditions: lateral.right_conditions,
+                            non_equi_conditions: vec![],
+                            join_type: JoinType::Left,
+                            marker_index: None,
+                            from_correlated_subquery: true,
+                        }
+                        .into(),
+                        left_child,
+                        lateral.right,
+                    ),
+                );
+                if !conditions.is_empty() {
+                    s_expr = SExpr::create_unary(
+                        Filter {
+                            predicates: conditions,
+                            is_having: false,
+                        }
+                        .into(),
+                        s_expr,
+                    );
+                }
+                s_expr
+            }
+        };
+        Ok((s_expr, bind_context))
+    }
+
     pub fn bind_join_with_type(
         &mut self,
         join_type: JoinType,
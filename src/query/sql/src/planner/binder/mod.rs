@@ -30,6 +30,7 @@ mod limit;
 mod location;
 mod presign;
 mod project;
+mod project_set;
 mod scalar;
 mod scalar_common;
 mod scalar_visitor;
@@ -0,0 +1,213 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::TableAlias;
+use common_ast::DisplayError;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Literal;
+
+use crate::binder::scalar::ScalarBinder;
+use crate::binder::wrap_cast_if_needed;
+use crate::binder::Binder;
+use crate::binder::ColumnBinding;
+use crate::binder::Visibility;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::FlattenMode;
+use crate::plans::ProjectSet;
+use crate::plans::Scalar;
+use crate::plans::ScalarExpr;
+use crate::plans::SetReturningFunction;
+use crate::BindContext;
+
+/// Check if the table function is a set-returning function, which is able to
+/// reference the columns of preceding tables in `FROM` clause.
+pub fn is_set_returning_function(name: &str) -> bool {
+    matches!(name.to_lowercase().as_str(), "unnest" | "flatten")
+}
+
+impl<'a> Binder {
+    /// Bind set-returning function `unnest` or `flatten` on top of `child`. The arguments
+    /// are resolved with `bind_context`, whose columns are kept in the result context.
+    pub(super) async fn bind_project_set(
+        &mut self,
+        bind_context: &BindContext,
+        child: SExpr,
+        name: &Identifier<'a>,
+        params: &[Expr<'a>],
+        named_params: &[(Identifier<'a>, Expr<'a>)],
+        alias: &Option<TableAlias<'a>>,
+    ) -> Result<(SExpr, BindContext)> {
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx)
+            .name
+            .to_lowercase();
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+
+        let (srf, arg) = match func_name.as_str() {
+            "unnest" => {
+                if params.len() != 1 || !named_params.is_empty() {
+                    return Err(ErrorCode::SemanticError(
+                        name.span
+                            .display_error("unnest expects exactly one argument".to_string()),
+                    ));
+                }
+                let (arg, arg_type) = scalar_binder.bind(&params[0]).await?;
+                match arg_type.remove_nullable() {
+                    DataType::Null
+                    | DataType::EmptyArray
+                    | DataType::Array(_)
+                    | DataType::Variant => {}
+                    _ => {
+                        return Err(ErrorCode::SemanticError(name.span.display_error(format!(
+                            "unnest expects an array or variant argument, but got {arg_type}"
+                        ))));
+                    }
+                }
+                (SetReturningFunction::Unnest, arg)
+            }
+            _ => {
+                if params.len() > 1 {
+                    return Err(ErrorCode::SemanticError(name.span.display_error(
+                        "flatten expects named arguments except for `input`".to_string(),
+                    )));
+                }
+                let mut input = params.first();
+                let mut path = String::new();
+                let mut outer = false;
+                let mut recursive = false;
+                let mut mode = FlattenMode::Both;
+                for (param_name, param) in named_params.iter() {
+                    let param_name = normalize_identifier(param_name, &self.name_resolution_ctx)
+                        .name
+                        .to_lowercase();
+                    if param_name == "input" {
+                        input = Some(param);
+                        continue;
+                    }
+                    let (value, _) = scalar_binder.bind(param).await?;
+                    let value = match value {
+                        Scalar::ConstantExpr(ConstantExpr { value, .. }) => value,
+                        _ => {
+                            return Err(ErrorCode::SemanticError(name.span.display_error(
+                                format!("argument `{param_name}` of flatten must be a constant"),
+                            )));
+                        }
+                    };
+                    match (param_name.as_str(), value) {
+                        ("path", Literal::String(value)) => {
+                            path = String::from_utf8(value)?;
+                            if common_jsonb::parse_json_path(path.as_bytes()).is_err() {
+                                return Err(ErrorCode::SemanticError(name.span.display_error(
+                                    format!("invalid JSON path '{path}' of flatten"),
+                                )));
+                            }
+                        }
+                        ("outer", Literal::Boolean(value)) => outer = value,
+                        ("recursive", Literal::Boolean(value)) => recursive = value,
+                        ("mode", Literal::String(value)) => {
+                            mode = match String::from_utf8(value)?.to_uppercase().as_str() {
+                                "OBJECT" => FlattenMode::Object,
+                                "ARRAY" => FlattenMode::Array,
+                                "BOTH" => FlattenMode::Both,
+                                other => {
+                                    return Err(ErrorCode::SemanticError(name.span.display_error(
+                                        format!(
+                                            "invalid mode '{other}' of flatten, \
+                                            expects 'OBJECT', 'ARRAY' or 'BOTH'"
+                                        ),
+                                    )));
+                                }
+                            };
+                        }
+                        ("path" | "outer" | "recursive" | "mode", value) => {
+                            return Err(ErrorCode::SemanticError(name.span.display_error(
+                                format!(
+                                    "invalid value {value} for argument `{param_name}` of flatten"
+                                ),
+                            )));
+                        }
+                        _ => {
+                            return Err(ErrorCode::SemanticError(name.span.display_error(
+                                format!("unknown argument `{param_name}` of flatten"),
+                            )));
+                        }
+                    }
+                }
+
+                let input = input.ok_or_else(|| {
+                    ErrorCode::SemanticError(
+                        name.span
+                            .display_error("flatten expects argument `input`".to_string()),
+                    )
+                })?;
+                let (arg, arg_type) = scalar_binder.bind(input).await?;
+                let arg = match arg_type {
+                    DataType::Null => arg,
+                    DataType::Nullable(_) => {
+                        wrap_cast_if_needed(&arg, &DataType::Nullable(Box::new(DataType::Variant)))
+                    }
+                    _ => wrap_cast_if_needed(&arg, &DataType::Variant),
+                };
+                let srf = SetReturningFunction::Flatten {
+                    path,
+                    outer,
+                    recursive,
+                    mode,
+                };
+                (srf, arg)
+            }
+        };
+
+        let mut srf_context = BindContext::new();
+        let mut columns = vec![];
+        for (column_name, data_type) in srf.return_types(&arg.data_type()) {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), data_type.clone());
+            srf_context.columns.push(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name,
+                index,
+                data_type: Box::new(data_type),
+                visibility: Visibility::Visible,
+            });
+            columns.push(index);
+        }
+        if let Some(alias) = alias {
+            srf_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        let mut output_context = bind_context.clone();
+        output_context.columns.extend(srf_context.columns);
+
+        let project_set = ProjectSet { srf, arg, columns };
+        Ok((
+            SExpr::create_unary(project_set.into(), child),
+            output_context,
+        ))
+    }
+}
@@ -12,22 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use common_exception::Result;
 use common_expression::types::DataType;
 
 use crate::binder::scalar_visitor::Recursion;
 use crate::binder::scalar_visitor::ScalarVisitor;
 use crate::optimizer::RelationalProperty;
+use crate::plans::AggregateFunction;
 use crate::plans::AndExpr;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::Scalar;
 use crate::plans::ScalarExpr;
+use crate::IndexType;
 
 // Visitor that find Expressions that match a particular predicate
 struct Finder<'a, F>
@@ -166,6 +171,7 @@ pub fn contain_subquery(scalar: &Scalar) -> bool {
         Scalar::FunctionCall(FunctionCall { arguments, .. }) => {
             arguments.iter().any(contain_subquery)
         }
+        Scalar::LambdaFunction(LambdaFunc { args, .. }) => args.iter().any(contain_subquery),
         Scalar::CastExpr(CastExpr { argument, .. }) => contain_subquery(argument),
         _ => false,
     }
@@ -188,3 +194,150 @@ pub fn wrap_cast_if_needed(scalar: &Scalar, target_type: &DataType) -> Scalar {
         wrap_cast(scalar, target_type)
     }
 }
+
+/// Rewrite the scalar from top to bottom, the sub-scalars replaced by `replacement_fn` are not
+/// visited further.
+pub fn rewrite_scalar_with_replacement<F>(
+    original_scalar: &Scalar,
+    replacement_fn: &F,
+) -> Result<Scalar>
+where
+    F: Fn(&Scalar) -> Result<Option<Scalar>>,
+{
+    let replacement_opt = replacement_fn(original_scalar)?;
+    match replacement_opt {
+        Some(replacement) => Ok(replacement),
+        None => match original_scalar {
+            Scalar::AndExpr(AndExpr {
+                left,
+                right,
+                return_type,
+            }) => {
+                let left = Box::new(rewrite_scalar_with_replacement(left, replacement_fn)?);
+                let right = Box::new(rewrite_scalar_with_replacement(right, replacement_fn)?);
+                Ok(Scalar::AndExpr(AndExpr {
+                    left,
+                    right,
+                    return_type: return_type.clone(),
+                }))
+            }
+            Scalar::OrExpr(OrExpr {
+                left,
+                right,
+                return_type,
+            }) => {
+                let left = Box::new(rewrite_scalar_with_replacement(left, replacement_fn)?);
+                let right = Box::new(rewrite_scalar_with_replacement(right, replacement_fn)?);
+                Ok(Scalar::OrExpr(OrExpr {
+                    left,
+                    right,
+                    return_type: return_type.clone(),
+                }))
+            }
+            Scalar::NotExpr(NotExpr {
+                argument,
+                return_type,
+            }) => {
+                let argument = Box::new(rewrite_scalar_with_replacement(argument, replacement_fn)?);
+                Ok(Scalar::NotExpr(NotExpr {
+                    argument,
+                    return_type: return_type.clone(),
+                }))
+            }
+            Scalar::ComparisonExpr(ComparisonExpr {
+                op,
+                left,
+                right,
+                return_type,
+            }) => {
+                let left = Box::new(rewrite_scalar_with_replacement(left, replacement_fn)?);
+                let right = Box::new(rewrite_scalar_with_replacement(right, replacement_fn)?);
+                Ok(Scalar::ComparisonExpr(ComparisonExpr {
+                    op: op.clone(),
+                    left,
+                    right,
+                    return_type: return_type.clone(),
+                }))
+            }
+            Scalar::AggregateFunction(AggregateFunction {
+                display_name,
+                func_name,
+                distinct,
+                params,
+                args,
+                return_type,
+            }) => {
+                let args = args
+                    .iter()
+                    .map(|arg| rewrite_scalar_with_replacement(arg, replacement_fn))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Scalar::AggregateFunction(AggregateFunction {
+                    display_name: display_name.clone(),
+                    func_name: func_name.clone(),
+                    distinct: *distinct,
+                    params: params.clone(),
+                    args,
+                    return_type: return_type.clone(),
+                }))
+            }
+            Scalar::FunctionCall(FunctionCall {
+                params,
+                arguments,
+                func_name,
+                return_type,
+            }) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|arg| rewrite_scalar_with_replacement(arg, replacement_fn))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Scalar::FunctionCall(FunctionCall {
+                    params: params.clone(),
+                    arguments,
+                    func_name: func_name.clone(),
+                    return_type: return_type.clone(),
+                }))
+            }
+            Scalar::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| rewrite_scalar_with_replacement(arg, replacement_fn))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Scalar::LambdaFunction(LambdaFunc {
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    params: lambda.params.clone(),
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }))
+            }
+            Scalar::CastExpr(CastExpr {
+                argument,
+                from_type,
+                target_type,
+            }) => {
+                let argument = Box::new(rewrite_scalar_with_replacement(argument, replacement_fn)?);
+                Ok(Scalar::CastExpr(CastExpr {
+                    argument,
+                    from_type: from_type.clone(),
+                    target_type: target_type.clone(),
+                }))
+            }
+            _ => Ok(original_scalar.clone()),
+        },
+    }
+}
+
+/// Replace the column refs with the scalars of the columns in `columns`.
+pub fn replace_column_refs(
+    scalar: &Scalar,
+    columns: &HashMap<IndexType, Scalar>,
+) -> Result<Scalar> {
+    rewrite_scalar_with_replacement(scalar, &|scalar| match scalar {
+        Scalar::BoundColumnRef(BoundColumnRef { column }) => {
+            Ok(columns.get(&column.index).cloned())
+        }
+        _ => Ok(None),
+    })
+}
@@ -19,6 +19,7 @@ use crate::plans::AndExpr;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::Scalar;
@@ -78,6 +79,11 @@ pub trait ScalarVisitor: Sized {
                                         stack.push(RecursionProcessing::Call(arg));
                                     }
                                 }
+                                Scalar::LambdaFunction(LambdaFunc { args, .. }) => {
+                                    for arg in args.iter() {
+                                        stack.push(RecursionProcessing::Call(arg));
+                                    }
+                                }
                                 Scalar::BoundColumnRef(_) | Scalar::ConstantExpr(_) => {}
                                 Scalar::CastExpr(CastExpr { argument, .. }) => {
                                     stack.push(RecursionProcessing::Call(argument))
@@ -44,6 +44,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::JoinType;
 use crate::plans::Scalar;
 use crate::plans::ScalarItem;
@@ -93,7 +94,7 @@ impl<'a> Binder {
         };
 
         if let Some(expr) = &stmt.selection {
-            s_expr = self.bind_where(&from_context, expr, s_expr).await?;
+            s_expr = self.bind_where(&mut from_context, expr, s_expr).await?;
         }
 
         // Generate a analyzed select list with from context
@@ -267,7 +268,7 @@ impl<'a> Binder {
 
     pub(super) async fn bind_where(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &mut BindContext,
         expr: &Expr<'a>,
         child: SExpr,
     ) -> Result<SExpr> {
@@ -279,8 +280,17 @@ impl<'a> Binder {
             &[],
         );
         let (scalar, _) = scalar_binder.bind(expr).await?;
+        let predicates = split_conjunctions(&scalar);
+        bind_context.match_predicate = predicates.iter().find_map(|predicate| match predicate {
+            Scalar::FunctionCall(FunctionCall {
+                func_name,
+                arguments,
+                ..
+            }) if func_name == "match" => Some(arguments.clone()),
+            _ => None,
+        });
         let filter_plan = Filter {
-            predicates: split_conjunctions(&scalar),
+            predicates,
             is_having: false,
         };
         let new_expr = SExpr::create_unary(filter_plan.into(), child);
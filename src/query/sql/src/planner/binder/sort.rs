@@ -23,6 +23,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use super::bind_context::NameResolutionResult;
+use crate::binder::rewrite_scalar_with_replacement;
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::select::SelectList;
 use crate::binder::Binder;
@@ -30,15 +31,8 @@ use crate::binder::ColumnBinding;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::GroupingChecker;
-use crate::plans::AggregateFunction;
-use crate::plans::AndExpr;
 use crate::plans::BoundColumnRef;
-use crate::plans::CastExpr;
-use crate::plans::ComparisonExpr;
 use crate::plans::EvalScalar;
-use crate::plans::FunctionCall;
-use crate::plans::NotExpr;
-use crate::plans::OrExpr;
 use crate::plans::Scalar;
 use crate::plans::ScalarItem;
 use crate::plans::Sort;
@@ -176,8 +170,8 @@ impl<'a> Binder {
                         &[],
                     );
                     let (bound_expr, _) = scalar_binder.bind(&order.expr).await?;
-                    let rewrite_scalar = self
-                        .rewrite_scalar_with_replacement(&bound_expr, &|nest_scalar| {
+                    let rewrite_scalar =
+                        rewrite_scalar_with_replacement(&bound_expr, &|nest_scalar| {
                             if let Scalar::BoundColumnRef(BoundColumnRef { column }) = nest_scalar {
                                 if let Some(scalar_item) = scalar_items.get(&column.index) {
                                     return Ok(Some(scalar_item.scalar.clone()));
@@ -342,131 +336,4 @@ impl<'a> Binder {
         };
         Ok(SExpr::create_unary(sort_plan.into(), child))
     }
-
-    #[allow(clippy::only_used_in_recursion)]
-    fn rewrite_scalar_with_replacement<F>(
-        &self,
-        original_scalar: &Scalar,
-        replacement_fn: &F,
-    ) -> Result<Scalar>
-    where
-        F: Fn(&Scalar) -> Result<Option<Scalar>>,
-    {
-        let replacement_opt = replacement_fn(original_scalar)?;
-        match replacement_opt {
-            Some(replacement) => Ok(replacement),
-            None => match original_scalar {
-                Scalar::AndExpr(AndExpr {
-                    left,
-                    right,
-                    return_type,
-                }) => {
-                    let left =
-                        Box::new(self.rewrite_scalar_with_replacement(left, replacement_fn)?);
-                    let right =
-                        Box::new(self.rewrite_scalar_with_replacement(right, replacement_fn)?);
-                    Ok(Scalar::AndExpr(AndExpr {
-                        left,
-                        right,
-                        return_type: return_type.clone(),
-                    }))
-                }
-                Scalar::OrExpr(OrExpr {
-                    left,
-                    right,
-                    return_type,
-                }) => {
-                    let left =
-                        Box::new(self.rewrite_scalar_with_replacement(left, replacement_fn)?);
-                    let right =
-                        Box::new(self.rewrite_scalar_with_replacement(right, replacement_fn)?);
-                    Ok(Scalar::OrExpr(OrExpr {
-                        left,
-                        right,
-                        return_type: return_type.clone(),
-                    }))
-                }
-                Scalar::NotExpr(NotExpr {
-                    argument,
-                    return_type,
-                }) => {
-                    let argument =
-                        Box::new(self.rewrite_scalar_with_replacement(argument, replacement_fn)?);
-                    Ok(Scalar::NotExpr(NotExpr {
-                        argument,
-                        return_type: return_type.clone(),
-                    }))
-                }
-                Scalar::ComparisonExpr(ComparisonExpr {
-                    op,
-                    left,
-                    right,
-                    return_type,
-                }) => {
-                    let left =
-                        Box::new(self.rewrite_scalar_with_replacement(left, replacement_fn)?);
-                    let right =
-                        Box::new(self.rewrite_scalar_with_replacement(right, replacement_fn)?);
-                    Ok(Scalar::ComparisonExpr(ComparisonExpr {
-                        op: op.clone(),
-                        left,
-                        right,
-                        return_type: return_type.clone(),
-                    }))
-                }
-                Scalar::AggregateFunction(AggregateFunction {
-                    display_name,
-                    func_name,
-                    distinct,
-                    params,
-                    args,
-                    return_type,
-                }) => {
-                    let args = args
-                        .iter()
-                        .map(|arg| self.rewrite_scalar_with_replacement(arg, replacement_fn))
-                        .collect::<Result<Vec<_>>>()?;
-                    Ok(Scalar::AggregateFunction(AggregateFunction {
-                        display_name: display_name.clone(),
-                        func_name: func_name.clone(),
-                        distinct: *distinct,
-                        params: params.clone(),
-                        args,
-                        return_type: return_type.clone(),
-                    }))
-                }
-                Scalar::FunctionCall(FunctionCall {
-                    params,
-                    arguments,
-                    func_name,
-                    return_type,
-                }) => {
-                    let arguments = arguments
-                        .iter()
-                        .map(|arg| self.rewrite_scalar_with_replacement(arg, replacement_fn))
-                        .collect::<Result<Vec<_>>>()?;
-                    Ok(Scalar::FunctionCall(FunctionCall {
-                        params: params.clone(),
-                        arguments,
-                        func_name: func_name.clone(),
-                        return_type: return_type.clone(),
-                    }))
-                }
-                Scalar::CastExpr(CastExpr {
-                    argument,
-                    from_type,
-                    target_type,
-                }) => {
-                    let argument =
-                        Box::new(self.rewrite_scalar_with_replacement(argument, replacement_fn)?);
-                    Ok(Scalar::CastExpr(CastExpr {
-                        argument,
-                        from_type: from_type.clone(),
-                        target_type: target_type.clone(),
-                    }))
-                }
-                _ => Ok(original_scalar.clone()),
-            },
-        }
-    }
 }
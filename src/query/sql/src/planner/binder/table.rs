@@ -27,6 +27,7 @@ use common_ast::ast::Statement;
 use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
 use common_ast::ast::TimeTravelPoint;
+use common_ast::parser::parse_expr;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
@@ -44,6 +45,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::check_literal;
 use common_expression::types::DataType;
+use common_expression::ComputedExpr;
 use common_expression::ConstantFolder;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
 use common_meta_types::FileFormatOptions;
@@ -57,7 +59,9 @@ use common_storages_view::view_table::QUERY;
 
 use crate::binder::copy::parse_stage_location_v2;
 use crate::binder::location::parse_uri_location;
+use crate::binder::project_set::is_set_returning_function;
 use crate::binder::scalar::ScalarBinder;
+use crate::binder::wrap_cast_if_needed;
 use crate::binder::Binder;
 use crate::binder::ColumnBinding;
 use crate::binder::CteInfo;
@@ -66,7 +70,10 @@ use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::ConstantExpr;
+use crate::plans::DummyTableScan;
+use crate::plans::EvalScalar;
 use crate::plans::Scalar;
+use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Statistics;
 use crate::BindContext;
@@ -219,10 +226,33 @@ impl<'a> Binder {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral: _,
                 name,
                 params,
+                named_params,
                 alias,
             } => {
+                if is_set_returning_function(
+                    &normalize_identifier(name, &self.name_resolution_ctx).name,
+                ) {
+                    return self
+                        .bind_project_set(
+                            &bind_context.replace(),
+                            SExpr::create_leaf(DummyTableScan.into()),
+                            name,
+                            params,
+                            named_params,
+                            alias,
+                        )
+                        .await;
+                }
+                if !named_params.is_empty() {
+                    return Err(ErrorCode::SemanticError(name.span.display_error(format!(
+                        "table function {} doesn't support named arguments",
+                        name.name
+                    ))));
+                }
+
                 let mut scalar_binder = ScalarBinder::new(
                     bind_context,
                     self.ctx.clone(),
@@ -282,6 +312,7 @@ impl<'a> Binder {
             TableReference::Join { span: _, join } => self.bind_join(bind_context, join).await,
             TableReference::Subquery {
                 span: _,
+                lateral: _,
                 subquery,
                 alias,
             } => {
@@ -479,35 +510,154 @@ impl<'a> Binder {
             }
         }
 
+        // The generated columns are bound in the context of the stored columns. Virtual columns
+        // are evaluated over the scan, and stored columns are recorded in the scan so that the
+        // predicates on their expressions can use them.
+        let mut virtual_items = vec![];
+        let mut computed_columns = vec![];
+        let table_schema = table.table().get_table_info().meta.schema.clone();
+        if table_schema
+            .fields()
+            .iter()
+            .any(|field| field.computed_expr().is_some())
+        {
+            let stored_context = bind_context.clone();
+            for (idx, field) in table_schema.fields().iter().enumerate() {
+                match field.computed_expr() {
+                    Some(ComputedExpr::Virtual(expr)) => {
+                        let data_type = DataType::from(field.data_type());
+                        let scalar = self.bind_computed_expr(&stored_context, expr).await?;
+                        let index = self
+                            .metadata
+                            .write()
+                            .add_derived_column(field.name().clone(), data_type.clone());
+                        virtual_items.push(ScalarItem {
+                            scalar: wrap_cast_if_needed(&scalar, &data_type),
+                            index,
+                        });
+
+                        // Keep the columns in the order of the table definition.
+                        let position = bind_context
+                            .columns
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, column)| column.visibility == Visibility::Visible)
+                            .map(|(position, _)| position)
+                            .nth(idx)
+                            .unwrap_or(bind_context.columns.len());
+                        bind_context.columns.insert(position, ColumnBinding {
+                            database_name: Some(database_name.to_string()),
+                            table_name: Some(table.name().to_string()),
+                            column_name: field.name().clone(),
+                            index,
+                            data_type: Box::new(data_type),
+                            visibility: Visibility::Visible,
+                        });
+                    }
+                    Some(ComputedExpr::Stored(expr)) => {
+                        let scalar = self.bind_computed_expr(&stored_context, expr).await?;
+                        if let Some(column) = stored_context.columns.iter().find(|column| {
+                            column.visibility == Visibility::Visible
+                                && &column.column_name == field.name()
+                        }) {
+                            computed_columns.push((scalar, column.clone()));
+                        }
+                    }
+                    None => {}
+                }
+            }
+        }
+
         let is_accurate = table.table().engine().to_lowercase() == "fuse";
         let stat = table.table().table_statistics()?;
+        let scan = SExpr::create_leaf(
+            Scan {
+                table_index,
+                columns: columns
+                    .into_iter()
+                    .map(|col| match col {
+                        ColumnEntry::BaseTableColumn { column_index, .. } => column_index,
+                        ColumnEntry::DerivedColumn { column_index, .. } => column_index,
+                    })
+                    .collect(),
+                push_down_predicates: None,
+                limit: None,
+                order_by: None,
+                statistics: Statistics {
+                    statistics: stat,
+                    col_stats,
+                    is_accurate,
+                },
+                prewhere: None,
+                computed_columns,
+            }
+            .into(),
+        );
+        if virtual_items.is_empty() {
+            return Ok((scan, bind_context));
+        }
         Ok((
-            SExpr::create_leaf(
-                Scan {
-                    table_index,
-                    columns: columns
-                        .into_iter()
-                        .map(|col| match col {
-                            ColumnEntry::BaseTableColumn { column_index, .. } => column_index,
-                            ColumnEntry::DerivedColumn { column_index, .. } => column_index,
-                        })
-                        .collect(),
-                    push_down_predicates: None,
-                    limit: None,
-                    order_by: None,
-                    statistics: Statistics {
-                        statistics: stat,
-                        col_stats,
-                        is_accurate,
-                    },
-                    prewhere: None,
+            SExpr::create_unary(
+                EvalScalar {
+                    items: virtual_items,
                 }
                 .into(),
+                scan,
             ),
             bind_context,
         ))
     }
 
+    /// Bind the expressions of the virtual generated columns of the table, keyed by the indices
+    /// of the columns in `bind_context`.
+    pub(in crate::planner::binder) async fn bind_virtual_computed_columns(
+        &self,
+        bind_context: &BindContext,
+        table: &dyn Table,
+    ) -> Result<HashMap<IndexType, Scalar>> {
+        let mut virtual_columns = HashMap::new();
+        for field in table.get_table_info().meta.schema.fields() {
+            if let Some(ComputedExpr::Virtual(expr)) = field.computed_expr() {
+                let scalar = self.bind_computed_expr(bind_context, expr).await?;
+                let data_type = DataType::from(field.data_type());
+                if let Some(column) = Self::find_column_binding(bind_context, field.name()) {
+                    virtual_columns.insert(column.index, wrap_cast_if_needed(&scalar, &data_type));
+                }
+            }
+        }
+        Ok(virtual_columns)
+    }
+
+    pub(in crate::planner::binder) fn find_column_binding<'b>(
+        bind_context: &'b BindContext,
+        column_name: &str,
+    ) -> Option<&'b ColumnBinding> {
+        bind_context.columns.iter().find(|column| {
+            column.visibility == Visibility::Visible && column.column_name == column_name
+        })
+    }
+
+    /// Bind the expression of a generated column, the columns in it are resolved in `bind_context`.
+    pub(in crate::planner::binder) async fn bind_computed_expr(
+        &self,
+        bind_context: &BindContext,
+        expr: &str,
+    ) -> Result<Scalar> {
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(expr)?;
+        let backtrace = Backtrace::new();
+        let expr = parse_expr(&tokens, sql_dialect, &backtrace)?;
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar, _) = scalar_binder.bind(&expr).await?;
+        Ok(scalar)
+    }
+
     async fn resolve_data_source(
         &self,
         tenant: &str,
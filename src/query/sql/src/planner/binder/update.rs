@@ -18,7 +18,11 @@ use common_ast::ast::TableReference;
 use common_ast::ast::UpdateStmt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ComputedExpr;
 
+use crate::binder::replace_column_refs;
+use crate::binder::wrap_cast_if_needed;
 use crate::binder::Binder;
 use crate::binder::ScalarBinder;
 use crate::normalize_identifier;
@@ -26,6 +30,7 @@ use crate::plans::Plan;
 use crate::plans::Scalar;
 use crate::plans::UpdatePlan;
 use crate::BindContext;
+use crate::ScalarExpr;
 
 impl<'a> Binder {
     pub(in crate::planner::binder) async fn bind_update(
@@ -69,6 +74,12 @@ impl<'a> Binder {
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
 
+        // The virtual generated columns are replaced by their expressions, since the mutation
+        // is applied to the stored columns.
+        let virtual_columns = self
+            .bind_virtual_computed_columns(&context, table.as_ref())
+            .await?;
+
         let mut scalar_binder = ScalarBinder::new(
             &context,
             self.ctx.clone(),
@@ -78,8 +89,21 @@ impl<'a> Binder {
         );
         let schema = table.schema();
         let mut update_columns = HashMap::with_capacity(update_list.len());
+        let mut updated_columns = HashMap::with_capacity(update_list.len());
         for update_expr in update_list {
             let col_name = normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
+            let field = table
+                .get_table_info()
+                .meta
+                .schema
+                .field_with_name(&col_name)?
+                .clone();
+            if field.computed_expr().is_some() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The value specified for generated column '{}' is not allowed",
+                    col_name
+                )));
+            }
             let index = schema.index_of(&col_name)?;
             if update_columns.contains_key(&index) {
                 return Err(ErrorCode::BadArguments(format!(
@@ -95,6 +119,11 @@ impl<'a> Binder {
                     "Update does not support subquery temporarily",
                 ));
             }
+            let scalar = replace_column_refs(&scalar, &virtual_columns)?;
+            if let Some(column) = Self::find_column_binding(&context, &col_name) {
+                let data_type = DataType::from(field.data_type());
+                updated_columns.insert(column.index, wrap_cast_if_needed(&scalar, &data_type));
+            }
             update_columns.insert(index, scalar);
         }
 
@@ -105,11 +134,27 @@ impl<'a> Binder {
                     "Update does not support subquery temporarily",
                 ));
             }
-            Some(scalar)
+            Some(replace_column_refs(&scalar, &virtual_columns)?)
         } else {
             None
         };
 
+        // The stored generated columns are computed again if the columns they depend on
+        // are updated.
+        for (index, field) in schema.fields().iter().enumerate() {
+            if let Some(ComputedExpr::Stored(expr)) = field.computed_expr() {
+                let scalar = self.bind_computed_expr(&context, expr).await?;
+                if scalar
+                    .used_columns()
+                    .iter()
+                    .any(|column| updated_columns.contains_key(column))
+                {
+                    let scalar = replace_column_refs(&scalar, &updated_columns)?;
+                    update_columns.insert(index, scalar);
+                }
+            }
+        }
+
         let plan = UpdatePlan {
             catalog: catalog_name,
             database: database_name,
@@ -27,6 +27,7 @@ use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataField;
+use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::Expr;
 use common_expression::RemoteExpr;
@@ -138,3 +139,51 @@ pub fn parse_to_remote_string_exprs(
 
     Ok(exprs)
 }
+
+/// Parse the expression of a generated column, the columns are resolved in the `schema`,
+/// and the indices of the column refs in the expression are the offsets in the `schema`.
+pub fn parse_computed_expr(
+    ctx: Arc<dyn TableContext>,
+    schema: DataSchemaRef,
+    sql: &str,
+) -> Result<Expr> {
+    let sql_dialect = Dialect::MySQL;
+    let tokens = tokenize_sql(sql)?;
+    let backtrace = Backtrace::new();
+    let mut exprs = parse_comma_separated_exprs(&tokens, sql_dialect, &backtrace)?;
+    if exprs.len() != 1 {
+        return Err(ErrorCode::BadArguments(format!(
+            "invalid generated column expression: {sql}"
+        )));
+    }
+    let expr = exprs.remove(0);
+
+    let settings = Settings::default_settings("", GlobalConfig::instance())?;
+    let mut bind_context = BindContext::new();
+    let mut fields = Vec::with_capacity(schema.num_fields());
+    for (index, field) in schema.fields().iter().enumerate() {
+        bind_context.add_column_binding(ColumnBinding {
+            database_name: None,
+            table_name: None,
+            column_name: field.name().clone(),
+            index,
+            data_type: Box::new(field.data_type().clone()),
+            visibility: Visibility::Visible,
+        });
+        fields.push(DataField::new(
+            &index.to_string(),
+            field.data_type().clone(),
+        ));
+    }
+
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+    let mut type_checker =
+        TypeChecker::new(&bind_context, ctx, &name_resolution_ctx, metadata, &[]);
+    let (scalar, _) =
+        *block_in_place(|| Handle::current().block_on(type_checker.resolve(&expr, None)))?;
+
+    let data_schema = DataSchemaRefExt::create(fields);
+    let physical_scalar_builder = PhysicalScalarBuilder::new(&data_schema);
+    physical_scalar_builder.build(&scalar)?.as_expr()
+}
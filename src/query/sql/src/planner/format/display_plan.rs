@@ -73,6 +73,18 @@ impl Plan {
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
 
+            // Sequences
+            Plan::CreateSequence(create_sequence) => Ok(format!("{:?}", create_sequence)),
+            Plan::DropSequence(drop_sequence) => Ok(format!("{:?}", drop_sequence)),
+
+            // Inverted indexes
+            Plan::CreateInvertedIndex(create_index) => Ok(format!("{:?}", create_index)),
+            Plan::DropInvertedIndex(drop_index) => Ok(format!("{:?}", drop_index)),
+
+            // Workload groups
+            Plan::CreateWorkloadGroup(create_group) => Ok(format!("{:?}", create_group)),
+            Plan::DropWorkloadGroup(drop_group) => Ok(format!("{:?}", drop_group)),
+
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
             Plan::Delete(delete) => Ok(format!("{:?}", delete)),
@@ -106,6 +118,9 @@ impl Plan {
             Plan::SetRole(p) => Ok(format!("{:?}", p)),
             Plan::UseDatabase(p) => Ok(format!("{:?}", p)),
             Plan::Kill(p) => Ok(format!("{:?}", p)),
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
 
             Plan::CreateShare(p) => Ok(format!("{:?}", p)),
             Plan::DropShare(p) => Ok(format!("{:?}", p)),
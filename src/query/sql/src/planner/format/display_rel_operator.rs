@@ -30,6 +30,7 @@ use crate::plans::Filter;
 use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::plans::Limit;
+use crate::plans::ProjectSet;
 use crate::plans::RelOperator;
 use crate::plans::Scalar;
 use crate::plans::Scan;
@@ -76,6 +77,7 @@ impl Display for FormatContext {
                 RelOperator::UnionAll(_) => write!(f, "Union"),
                 RelOperator::Pattern(_) => write!(f, "Pattern"),
                 RelOperator::DummyTableScan(_) => write!(f, "DummyTableScan"),
+                RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -127,6 +129,19 @@ pub fn format_scalar(_metadata: &MetadataRef, scalar: &Scalar) -> String {
                     .join(", ")
             )
         }
+        Scalar::LambdaFunction(lambda) => {
+            format!(
+                "{}({}, {})",
+                &lambda.func_name,
+                lambda
+                    .args
+                    .iter()
+                    .map(|arg| { format_scalar(_metadata, arg) })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                &lambda.lambda_display
+            )
+        }
         Scalar::CastExpr(cast) => {
             format!(
                 "CAST({} AS {})",
@@ -183,6 +198,12 @@ pub fn format_exchange(
         Exchange::Merge => {
             write!(f, "Exchange(Merge)")
         }
+        Exchange::Range(_) => {
+            write!(f, "Exchange(Range)")
+        }
+        Exchange::OrderedMerge => {
+            write!(f, "Exchange(OrderedMerge)")
+        }
     }
 }
 
@@ -202,6 +223,7 @@ fn to_format_tree(
         RelOperator::Sort(op) => sort_to_format_tree(op, metadata, children),
         RelOperator::Limit(op) => limit_to_format_tree(op, metadata, children),
         RelOperator::Exchange(op) => exchange_to_format_tree(op, metadata, children),
+        RelOperator::ProjectSet(op) => project_set_to_format_tree(op, metadata, children),
 
         _ => FormatTreeNode::with_children(
             FormatContext::RelOp {
@@ -535,6 +557,36 @@ fn eval_scalar_to_format_tree(
     )
 }
 
+fn project_set_to_format_tree(
+    op: &ProjectSet,
+    metadata: MetadataRef,
+    children: Vec<FormatTreeNode<FormatContext>>,
+) -> FormatTreeNode<FormatContext> {
+    let columns = op
+        .columns
+        .iter()
+        .map(|index| format!("#{index}"))
+        .collect::<Vec<String>>()
+        .join(", ");
+    FormatTreeNode::with_children(
+        FormatContext::RelOp {
+            metadata: metadata.clone(),
+            rel_operator: Box::new(op.clone().into()),
+        },
+        vec![
+            vec![
+                FormatTreeNode::new(FormatContext::Text(format!(
+                    "function: {}",
+                    op.srf.display(&format_scalar(&metadata, &op.arg))
+                ))),
+                FormatTreeNode::new(FormatContext::Text(format!("columns: [{}]", columns))),
+            ],
+            children,
+        ]
+        .concat(),
+    )
+}
+
 fn sort_to_format_tree(
     op: &Sort,
     metadata: MetadataRef,
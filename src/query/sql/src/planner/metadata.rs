@@ -56,9 +56,20 @@ pub type MetadataRef = Arc<RwLock<Metadata>>;
 pub struct Metadata {
     tables: Vec<TableEntry>,
     columns: Vec<ColumnEntry>,
+    /// Whether the query refers to the session, such as `database()` or `current_user()`,
+    /// which are folded into constants while binding.
+    session_dependent: bool,
 }
 
 impl Metadata {
+    pub fn set_session_dependent(&mut self) {
+        self.session_dependent = true;
+    }
+
+    pub fn is_session_dependent(&self) -> bool {
+        self.session_dependent
+    }
+
     pub fn table(&self, index: IndexType) -> &TableEntry {
         self.tables.get(index).expect("metadata must contain table")
     }
@@ -30,6 +30,7 @@ pub use binder::ColumnBinding;
 pub use binder::ScalarBinder;
 pub use binder::SelectBuilder;
 pub use binder::Visibility;
+pub use expression_parser::parse_computed_expr;
 pub use expression_parser::parse_exprs;
 pub use expression_parser::parse_to_remote_string_exprs;
 pub use metadata::*;
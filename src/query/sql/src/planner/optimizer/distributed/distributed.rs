@@ -32,8 +32,11 @@ pub fn optimize_distributed_query(ctx: Arc<dyn TableContext>, s_expr: &SExpr) ->
     let required = RequiredProperty {
         distribution: Distribution::Any,
     };
-    let mut result = require_property(ctx, &required, s_expr)?;
+    let mut result = require_property(ctx.clone(), &required, s_expr)?;
     push_down_topk_to_merge(&mut result, None)?;
+    if ctx.get_settings().get_enable_range_partition_sort()? {
+        partition_sort_by_range(&mut result)?;
+    }
     let rel_expr = RelExpr::with_s_expr(&result);
     let physical_prop = rel_expr.derive_physical_prop()?;
     let root_required = RequiredProperty {
@@ -84,3 +87,29 @@ fn push_down_topk_to_merge(s_expr: &mut SExpr, mut top_k: Option<TopK>) -> Resul
     }
     Ok(())
 }
+
+// Traverse the SExpr tree to find sort without limit on top of Exchange::Merge, if find,
+// partition the data by ranges of the sort keys and sort the ranges on all the nodes.
+fn partition_sort_by_range(s_expr: &mut SExpr) -> Result<()> {
+    if let RelOperator::Sort(sort) = &s_expr.plan {
+        let merge = s_expr.child(0)?;
+        if sort.limit.is_none()
+            && !sort.items.is_empty()
+            && matches!(merge.plan, RelOperator::Exchange(Exchange::Merge))
+            // Top-k has been pushed down to the merge.
+            && !matches!(merge.child(0)?.plan, RelOperator::Limit(_))
+        {
+            let range = SExpr::create_unary(
+                Exchange::Range(sort.items.clone()).into(),
+                merge.child(0)?.clone(),
+            );
+            let sorted = SExpr::create_unary(sort.clone().into(), range);
+            *s_expr = SExpr::create_unary(Exchange::OrderedMerge.into(), sorted);
+            return Ok(());
+        }
+    }
+    for child in s_expr.children.iter_mut() {
+        partition_sort_by_range(child)?;
+    }
+    Ok(())
+}
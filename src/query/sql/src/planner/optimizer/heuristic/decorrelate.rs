@@ -19,6 +19,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::common_super_type;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Literal;
 
 use crate::binder::JoinPredicate;
 use crate::binder::Visibility;
@@ -28,6 +30,7 @@ use crate::optimizer::heuristic::subquery_rewriter::UnnestResult;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
+use crate::planner::binder::replace_column_refs;
 use crate::planner::binder::wrap_cast;
 use crate::plans::Aggregate;
 use crate::plans::AggregateFunction;
@@ -37,14 +40,17 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::ComparisonOp;
+use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::PatternPlan;
+use crate::plans::ProjectSet;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
 use crate::plans::Scalar;
@@ -77,6 +83,52 @@ pub fn decorrelate_subquery(metadata: MetadataRef, s_expr: SExpr) -> Result<SExp
     Ok(hoisted)
 }
 
+/// The right side of a LATERAL join decorrelated by [`SubqueryRewriter::decorrelate_lateral`].
+pub struct LateralJoin {
+    pub right: SExpr,
+    /// The correlated columns of the left side.
+    pub left_conditions: Vec<Scalar>,
+    /// The columns derived from the correlated columns in the right side.
+    pub right_conditions: Vec<Scalar>,
+    /// The projection of an aggregation without group by, evaluated on top of the join.
+    pub projection: Option<EvalScalar>,
+}
+
+/// Replace the count of a missing group, which is NULL after the left join, with 0.
+fn count_or_zero(item: &ScalarItem) -> Scalar {
+    let data_type = item.scalar.data_type();
+    let column_ref = Scalar::BoundColumnRef(BoundColumnRef {
+        column: ColumnBinding {
+            database_name: None,
+            table_name: None,
+            column_name: format!("count_{}", item.index),
+            index: item.index,
+            data_type: Box::new(data_type.wrap_nullable()),
+            visibility: Visibility::Visible,
+        },
+    });
+    let is_not_null = Scalar::FunctionCall(FunctionCall {
+        params: vec![],
+        arguments: vec![column_ref.clone()],
+        func_name: "is_not_null".to_string(),
+        return_type: Box::new(DataType::Boolean),
+    });
+    let zero = Scalar::ConstantExpr(ConstantExpr {
+        value: Literal::UInt64(0),
+        data_type: Box::new(DataType::Number(NumberDataType::UInt64).wrap_nullable()),
+    });
+    Scalar::CastExpr(CastExpr {
+        argument: Box::new(Scalar::FunctionCall(FunctionCall {
+            params: vec![],
+            arguments: vec![is_not_null, column_ref, zero],
+            func_name: "if".to_string(),
+            return_type: Box::new(DataType::Number(NumberDataType::UInt64).wrap_nullable()),
+        })),
+        from_type: Box::new(DataType::Number(NumberDataType::UInt64).wrap_nullable()),
+        target_type: Box::new(data_type),
+    })
+}
+
 impl SubqueryRewriter {
     // Try to decorrelate a `CrossApply` into `SemiJoin` or `AntiJoin`.
     // We only do simple decorrelation here, the scheme is:
@@ -371,6 +423,19 @@ impl SubqueryRewriter {
             if !need_cross_join {
                 return Ok(plan.clone());
             }
+            if let Some(lateral_left) = self.lateral_left.clone() {
+                let cross_join = Join {
+                    left_conditions: vec![],
+                    right_conditions: vec![],
+                    non_equi_conditions: vec![],
+                    join_type: JoinType::Cross,
+                    marker_index: None,
+                    from_correlated_subquery: false,
+                }
+                .into();
+                let domain = self.build_lateral_domain(&lateral_left, correlated_columns)?;
+                return Ok(SExpr::create_binary(cross_join, domain, plan.clone()));
+            }
             // Construct a LogicalGet plan by correlated columns.
             // Finally generate a cross join, so we finish flattening the subquery.
             let mut metadata = self.metadata.write();
@@ -409,6 +474,7 @@ impl SubqueryRewriter {
                         is_accurate: false,
                     },
                     prewhere: None,
+                    computed_columns: vec![],
                 }
                 .into(),
             );
@@ -620,6 +686,10 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(plan.plan().clone(), flatten_plan))
             }
 
+            RelOperator::Limit(_) if self.lateral_left.is_some() => Err(ErrorCode::Unimplemented(
+                "LIMIT in a correlated LATERAL subquery is not supported yet",
+            )),
+
             RelOperator::Limit(_) => {
                 // Currently, we don't support limit contain subquery.
                 let flatten_plan = self.flatten(
@@ -631,6 +701,32 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(plan.plan().clone(), flatten_plan))
             }
 
+            RelOperator::ProjectSet(project_set) => {
+                if project_set
+                    .arg
+                    .used_columns()
+                    .iter()
+                    .any(|index| correlated_columns.contains(index))
+                {
+                    need_cross_join = true;
+                }
+                let flatten_plan = self.flatten(
+                    plan.child(0)?,
+                    correlated_columns,
+                    flatten_info,
+                    need_cross_join,
+                )?;
+                Ok(SExpr::create_unary(
+                    ProjectSet {
+                        srf: project_set.srf.clone(),
+                        arg: self.flatten_scalar(&project_set.arg, correlated_columns)?,
+                        columns: project_set.columns.clone(),
+                    }
+                    .into(),
+                    flatten_plan,
+                ))
+            }
+
             RelOperator::UnionAll(op) => {
                 if op
                     .used_columns()?
@@ -664,6 +760,143 @@ impl SubqueryRewriter {
         }
     }
 
+    /// Decorrelate the right side of a LATERAL join, which refers to the `correlated_columns`
+    /// of the left side.
+    ///
+    /// The right side is flattened over the distinct values of the correlated columns of the
+    /// left side (the dependent join of the paper), then it is joined back to the left side by
+    /// the correlated columns. If the right side is an aggregation without group by, which
+    /// returns a row for every row of the left side, its projection is returned to be evaluated
+    /// on top of a left join, with the counts of the missing groups converted to 0.
+    pub fn decorrelate_lateral(
+        &mut self,
+        left: &SExpr,
+        right: &SExpr,
+        correlated_columns: &ColumnSet,
+    ) -> Result<LateralJoin> {
+        let right = self.rewrite(right)?;
+        let projection = match right.plan() {
+            RelOperator::EvalScalar(eval_scalar)
+                if matches!(
+                    right.child(0)?.plan(),
+                    RelOperator::Aggregate(aggregate) if aggregate.group_items.is_empty()
+                ) =>
+            {
+                Some(eval_scalar.clone())
+            }
+            _ => None,
+        };
+        let right = match projection {
+            Some(_) => right.child(0)?.clone(),
+            None => right,
+        };
+
+        self.lateral_left = Some(left.clone());
+        let mut flatten_info = FlattenInfo {
+            from_count_func: false,
+        };
+        let flatten_plan = self.flatten(&right, correlated_columns, &mut flatten_info, true);
+        self.lateral_left = None;
+        let flatten_plan = flatten_plan?;
+
+        let mut left_conditions = Vec::with_capacity(correlated_columns.len());
+        let mut right_conditions = Vec::with_capacity(correlated_columns.len());
+        self.add_equi_conditions(
+            correlated_columns,
+            &mut right_conditions,
+            &mut left_conditions,
+        )?;
+
+        let projection = match projection {
+            Some(mut projection) => {
+                let aggregate: Aggregate = right.plan().clone().try_into()?;
+                let counts = aggregate
+                    .aggregate_functions
+                    .iter()
+                    .filter(|item| {
+                        matches!(&item.scalar, Scalar::AggregateFunction(agg)
+                            if agg.func_name.eq_ignore_ascii_case("count")
+                                || agg.func_name.eq("count_distinct"))
+                    })
+                    .map(|item| (item.index, count_or_zero(item)))
+                    .collect::<HashMap<_, _>>();
+                for item in projection.items.iter_mut() {
+                    item.scalar = replace_column_refs(&item.scalar, &counts)?;
+                }
+                Some(projection)
+            }
+            None => None,
+        };
+
+        Ok(LateralJoin {
+            right: flatten_plan,
+            left_conditions,
+            right_conditions,
+            projection,
+        })
+    }
+
+    /// Build the distinct values of the correlated columns of the left side of a LATERAL join,
+    /// the correlated columns are renamed to the derived columns.
+    fn build_lateral_domain(
+        &mut self,
+        left: &SExpr,
+        correlated_columns: &ColumnSet,
+    ) -> Result<SExpr> {
+        let mut items = Vec::with_capacity(correlated_columns.len());
+        let mut group_items = Vec::with_capacity(correlated_columns.len());
+        for correlated_column in correlated_columns.iter() {
+            let (name, data_type) = match self.metadata.read().column(*correlated_column) {
+                ColumnEntry::BaseTableColumn {
+                    column_name,
+                    data_type,
+                    ..
+                } => (column_name.clone(), DataType::from(data_type)),
+                ColumnEntry::DerivedColumn {
+                    alias, data_type, ..
+                } => (alias.clone(), data_type.clone()),
+            };
+            let derived_column = self
+                .metadata
+                .write()
+                .add_derived_column(name.clone(), data_type.clone());
+            self.derived_columns
+                .insert(*correlated_column, derived_column);
+
+            let column_ref = |index: IndexType| {
+                Scalar::BoundColumnRef(BoundColumnRef {
+                    column: ColumnBinding {
+                        database_name: None,
+                        table_name: None,
+                        column_name: name.clone(),
+                        index,
+                        data_type: Box::new(data_type.clone()),
+                        visibility: Visibility::Visible,
+                    },
+                })
+            };
+            items.push(ScalarItem {
+                scalar: column_ref(*correlated_column),
+                index: derived_column,
+            });
+            group_items.push(ScalarItem {
+                scalar: column_ref(derived_column),
+                index: derived_column,
+            });
+        }
+
+        Ok(SExpr::create_unary(
+            Aggregate {
+                mode: AggregateMode::Initial,
+                group_items,
+                aggregate_functions: vec![],
+                from_distinct: true,
+            }
+            .into(),
+            SExpr::create_unary(EvalScalar { items }.into(), left.clone()),
+        ))
+    }
+
     fn flatten_scalar(
         &mut self,
         scalar: &Scalar,
@@ -749,6 +982,20 @@ impl SubqueryRewriter {
                     return_type: fun_call.return_type.clone(),
                 }))
             }
+            Scalar::LambdaFunction(lambda) => {
+                let mut args = Vec::with_capacity(lambda.args.len());
+                for arg in &lambda.args {
+                    args.push(self.flatten_scalar(arg, correlated_columns)?);
+                }
+                Ok(Scalar::LambdaFunction(LambdaFunc {
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    params: lambda.params.clone(),
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }))
+            }
             Scalar::CastExpr(cast_expr) => {
                 let scalar = self.flatten_scalar(&cast_expr.argument, correlated_columns)?;
                 Ok(Scalar::CastExpr(CastExpr {
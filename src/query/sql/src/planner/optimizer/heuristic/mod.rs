@@ -23,3 +23,4 @@ mod subquery_rewriter;
 pub use heuristic::HeuristicOptimizer;
 pub use heuristic::DEFAULT_REWRITE_RULES;
 pub use rule_list::RuleList;
+pub use subquery_rewriter::SubqueryRewriter;
@@ -73,6 +73,11 @@ impl PrewhereOptimizer {
                     Self::collect_columns_impl(arg, columns);
                 }
             }
+            Scalar::LambdaFunction(lambda) => {
+                for arg in lambda.args.iter() {
+                    Self::collect_columns_impl(arg, columns);
+                }
+            }
             Scalar::CastExpr(cast) => {
                 Self::collect_columns_impl(cast.argument.as_ref(), columns);
             }
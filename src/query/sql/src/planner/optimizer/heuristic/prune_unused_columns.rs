@@ -103,6 +103,7 @@ impl UnusedColumnPruner {
                         is_accurate: p.statistics.is_accurate,
                     },
                     prewhere,
+                    computed_columns: p.computed_columns.clone(),
                 })))
             }
             RelOperator::Join(p) => {
@@ -243,6 +244,15 @@ impl UnusedColumnPruner {
                 ))
             }
 
+            RelOperator::ProjectSet(p) => {
+                // The function changes the number of rows, so it's always kept.
+                let used = required.union(&p.arg.used_columns()).cloned().collect();
+                Ok(SExpr::create_unary(
+                    RelOperator::ProjectSet(p.clone()),
+                    self.keep_required_columns(expr.child(0)?, used)?,
+                ))
+            }
+
             RelOperator::DummyTableScan(_) => Ok(expr.clone()),
 
             _ => Err(ErrorCode::Internal(
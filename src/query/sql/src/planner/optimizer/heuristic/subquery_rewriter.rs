@@ -40,6 +40,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::Limit;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
@@ -68,6 +69,9 @@ pub struct FlattenInfo {
 pub struct SubqueryRewriter {
     pub(crate) metadata: MetadataRef,
     pub(crate) derived_columns: HashMap<IndexType, IndexType>,
+    /// The left side of the LATERAL join being decorrelated, its distinct correlated
+    /// columns take the place of the scan of the outer table.
+    pub(crate) lateral_left: Option<SExpr>,
 }
 
 impl SubqueryRewriter {
@@ -75,6 +79,7 @@ impl SubqueryRewriter {
         Self {
             metadata,
             derived_columns: Default::default(),
+            lateral_left: None,
         }
     }
 
@@ -119,6 +124,14 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(plan.into(), input))
             }
 
+            RelOperator::ProjectSet(mut plan) => {
+                let input = self.rewrite(s_expr.child(0)?)?;
+                let (arg, input) = self.try_rewrite_subquery(&plan.arg, &input, false)?;
+                plan.arg = arg;
+
+                Ok(SExpr::create_unary(plan.into(), input))
+            }
+
             RelOperator::Join(_) | RelOperator::UnionAll(_) => Ok(SExpr::create_binary(
                 s_expr.plan().clone(),
                 self.rewrite(s_expr.child(0)?)?,
@@ -229,6 +242,28 @@ impl SubqueryRewriter {
                 Ok((expr, s_expr))
             }
 
+            Scalar::LambdaFunction(lambda) => {
+                let mut args = vec![];
+                let mut s_expr = s_expr.clone();
+                for arg in lambda.args.iter() {
+                    let res = self.try_rewrite_subquery(arg, &s_expr, false)?;
+                    s_expr = res.1;
+                    args.push(res.0);
+                }
+
+                let expr: Scalar = LambdaFunc {
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    params: lambda.params.clone(),
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into();
+
+                Ok((expr, s_expr))
+            }
+
             Scalar::CastExpr(cast) => {
                 let (scalar, s_expr) = self.try_rewrite_subquery(&cast.argument, s_expr, false)?;
                 Ok((
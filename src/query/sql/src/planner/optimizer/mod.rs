@@ -29,6 +29,7 @@ mod s_expr;
 mod util;
 
 pub use heuristic::HeuristicOptimizer;
+pub use heuristic::SubqueryRewriter;
 pub use heuristic::DEFAULT_REWRITE_RULES;
 pub use m_expr::MExpr;
 pub use memo::Memo;
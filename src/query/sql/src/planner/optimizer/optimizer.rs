@@ -64,6 +64,7 @@ pub fn optimize(
             metadata,
             rewrite_kind,
             ignore_result,
+            formatted_ast,
         } => Ok(Plan::Query {
             s_expr: Box::new(optimize_query(
                 ctx,
@@ -76,6 +77,7 @@ pub fn optimize(
             metadata,
             rewrite_kind,
             ignore_result,
+            formatted_ast,
         }),
         Plan::Explain { kind, plan } => match kind {
             ExplainKind::Raw | ExplainKind::Ast(_) | ExplainKind::Syntax(_) => {
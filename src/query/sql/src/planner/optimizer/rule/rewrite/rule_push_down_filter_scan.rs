@@ -18,10 +18,15 @@ use crate::optimizer::rule::Rule;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
+use crate::planner::binder::rewrite_scalar_with_replacement;
+use crate::plans::BoundColumnRef;
 use crate::plans::Filter;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
+use crate::plans::Scalar;
 use crate::plans::Scan;
+use crate::ColumnBinding;
+use crate::ScalarExpr;
 
 pub struct RulePushDownFilterScan {
     id: RuleID,
@@ -57,13 +62,20 @@ impl Rule for RulePushDownFilterScan {
     }
 
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
-        let filter: Filter = s_expr.plan().clone().try_into()?;
+        let mut filter: Filter = s_expr.plan().clone().try_into()?;
         let mut get: Scan = s_expr.child(0)?.plan().clone().try_into()?;
 
         if get.push_down_predicates.is_some() {
             return Ok(());
         }
 
+        if !get.computed_columns.is_empty() {
+            filter.predicates = filter
+                .predicates
+                .iter()
+                .map(|predicate| replace_computed_exprs(predicate, &get.computed_columns))
+                .collect::<Result<Vec<_>>>()?;
+        }
         get.push_down_predicates = Some(filter.predicates.clone());
 
         let result = SExpr::create_unary(filter.into(), SExpr::create_leaf(get.into()));
@@ -76,3 +88,21 @@ impl Rule for RulePushDownFilterScan {
         &self.pattern
     }
 }
+
+// Replace the expressions of the stored generated columns with the columns, so that the
+// predicates can be pruned by the statistics of the columns.
+fn replace_computed_exprs(
+    predicate: &Scalar,
+    computed_columns: &[(Scalar, ColumnBinding)],
+) -> Result<Scalar> {
+    rewrite_scalar_with_replacement(predicate, &|scalar| {
+        for (expr, column) in computed_columns {
+            if expr == scalar && expr.data_type() == *column.data_type {
+                return Ok(Some(Scalar::BoundColumnRef(BoundColumnRef {
+                    column: column.clone(),
+                })));
+            }
+        }
+        Ok(None)
+    })
+}
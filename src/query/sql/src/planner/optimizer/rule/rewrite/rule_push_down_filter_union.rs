@@ -27,6 +27,7 @@ use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::PatternPlan;
@@ -191,6 +192,18 @@ fn replace_column_binding(
             func_name: expr.func_name,
             return_type: expr.return_type,
         })),
+        Scalar::LambdaFunction(expr) => Ok(Scalar::LambdaFunction(LambdaFunc {
+            func_name: expr.func_name,
+            args: expr
+                .args
+                .into_iter()
+                .map(|arg| replace_column_binding(index_pairs, arg))
+                .collect::<Result<Vec<_>>>()?,
+            params: expr.params,
+            lambda_expr: expr.lambda_expr,
+            lambda_display: expr.lambda_display,
+            return_type: expr.return_type,
+        })),
         Scalar::CastExpr(expr) => Ok(Scalar::CastExpr(CastExpr {
             argument: Box::new(replace_column_binding(index_pairs, *(expr.argument))?),
             from_type: expr.from_type,
@@ -14,15 +14,21 @@
 mod account;
 mod catalog;
 mod database;
+mod index;
+mod sequence;
 mod stage;
 mod table;
 mod udf;
 mod view;
+mod workload_group;
 
 pub use account::*;
 pub use catalog::*;
 pub use database::*;
+pub use index::*;
+pub use sequence::*;
 pub use stage::*;
 pub use table::*;
 pub use udf::*;
 pub use view::*;
+pub use workload_group::*;
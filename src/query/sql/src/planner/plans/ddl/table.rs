@@ -29,6 +29,7 @@ use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
 use common_storage::StorageParams;
 
+use crate::plans::CreateSequencePlan;
 use crate::plans::Plan;
 
 pub type TableOptions = BTreeMap<String, String>;
@@ -48,8 +49,37 @@ pub struct CreateTablePlanV2 {
     pub options: TableOptions,
     pub field_default_exprs: Vec<Option<String>>,
     pub field_comments: Vec<String>,
+    /// The sequences of the identity columns, created before the table and dropped with it.
+    pub identity_sequences: Vec<CreateSequencePlan>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    pub clone_source: Option<CloneTableSource>,
+}
+
+/// The default expression of an identity column, which takes the values from its sequence.
+pub fn identity_default_expr(sequence: &str) -> String {
+    format!("nextval('{sequence}')")
+}
+
+/// The sequence of an identity column, if `default_expr` is the one of [`identity_default_expr`].
+pub fn identity_sequence_of(default_expr: &str) -> Option<&str> {
+    default_expr
+        .strip_prefix("nextval('")?
+        .strip_suffix("')")
+        .filter(|sequence| {
+            sequence.strip_prefix("identity_").map_or(false, |uuid| {
+                uuid.len() == 32 && uuid.bytes().all(|b| b.is_ascii_hexdigit())
+            })
+        })
+}
+
+/// The table, and the point of it, whose data is shared by `CREATE TABLE ... CLONE`.
+#[derive(Clone, Debug)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub point: Option<NavigationPoint>,
 }
 
 impl CreateTablePlanV2 {
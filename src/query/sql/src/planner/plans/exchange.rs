@@ -25,6 +25,7 @@ use crate::optimizer::RequiredProperty;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::Scalar;
+use crate::plans::SortItem;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Exchange {
@@ -32,6 +33,12 @@ pub enum Exchange {
     Hash(Vec<Scalar>),
     Broadcast,
     Merge,
+    /// Partition the data by ranges of the sort keys, the bounds of
+    /// the ranges are computed from samples of the data at runtime.
+    Range(Vec<SortItem>),
+    /// Merge the partitions of a `Range` exchange to a single node,
+    /// concatenating them in the order of the ranges.
+    OrderedMerge,
 }
 
 impl Operator for Exchange {
@@ -50,6 +57,8 @@ impl Operator for Exchange {
                 Exchange::Hash(hash_keys) => Distribution::Hash(hash_keys.clone()),
                 Exchange::Broadcast => Distribution::Broadcast,
                 Exchange::Merge => Distribution::Serial,
+                Exchange::Range(_) => Distribution::Random,
+                Exchange::OrderedMerge => Distribution::Serial,
             },
         })
     }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_catalog::table_context::StageAttachment;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use common_expression::Scalar;
 use common_expression::TableSchemaRef;
 use common_meta_types::FileFormatOptions;
 use common_meta_types::MetaId;
@@ -52,6 +53,8 @@ pub struct Insert {
     pub schema: TableSchemaRef,
     pub overwrite: bool,
     pub source: InsertInputSource,
+    // static partition values of `INSERT ... PARTITION (...)`
+    pub partition: Vec<(String, Scalar)>,
 }
 
 impl PartialEq for Insert {
@@ -82,6 +85,7 @@ impl std::fmt::Debug for Insert {
             .field("table_id", &self.table_id)
             .field("schema", &self.schema)
             .field("overwrite", &self.overwrite)
+            .field("partition", &self.partition)
             .finish()
     }
 }
@@ -30,6 +30,7 @@ mod operator;
 mod pattern;
 mod plan;
 mod presign;
+mod project_set;
 mod recluster_table;
 mod revert_table;
 mod scalar;
@@ -61,6 +62,7 @@ pub use plan::Plan::*;
 pub use plan::RewriteKind::*;
 pub use plan::*;
 pub use presign::*;
+pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
 pub use revert_table::RevertTablePlan;
 pub use scalar::*;
@@ -25,6 +25,7 @@ use super::filter::Filter;
 use super::join::Join;
 use super::limit::Limit;
 use super::pattern::PatternPlan;
+use super::project_set::ProjectSet;
 use super::scan::Scan;
 use super::sort::Sort;
 use super::union_all::UnionAll;
@@ -67,6 +68,7 @@ pub enum RelOp {
     Exchange,
     UnionAll,
     DummyTableScan,
+    ProjectSet,
 
     // Pattern
     Pattern,
@@ -85,6 +87,7 @@ pub enum RelOperator {
     Exchange(Exchange),
     UnionAll(UnionAll),
     DummyTableScan(DummyTableScan),
+    ProjectSet(ProjectSet),
 
     Pattern(PatternPlan),
 }
@@ -103,6 +106,7 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.rel_op(),
             RelOperator::UnionAll(rel_op) => rel_op.rel_op(),
             RelOperator::DummyTableScan(rel_op) => rel_op.rel_op(),
+            RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -119,6 +123,7 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -135,6 +140,7 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -179,6 +185,9 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::ProjectSet(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -386,3 +395,22 @@ impl TryFrom<RelOperator> for DummyTableScan {
         }
     }
 }
+
+impl From<ProjectSet> for RelOperator {
+    fn from(v: ProjectSet) -> Self {
+        Self::ProjectSet(v)
+    }
+}
+
+impl TryFrom<RelOperator> for ProjectSet {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::ProjectSet(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to ProjectSet",
+            ))
+        }
+    }
+}
@@ -44,23 +44,29 @@ use crate::plans::AnalyzeTablePlan;
 use crate::plans::CallPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
+use crate::plans::CreateInvertedIndexPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::CreateTablePlanV2;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
+use crate::plans::CreateWorkloadGroupPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropSequencePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
+use crate::plans::DropWorkloadGroupPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
@@ -98,6 +104,8 @@ pub enum Plan {
         bind_context: Box<BindContext>,
         rewrite_kind: Option<RewriteKind>,
         ignore_result: bool,
+        /// The normalized query text, only set when the result of the query can be cached.
+        formatted_ast: Option<String>,
     },
 
     Explain {
@@ -156,6 +164,18 @@ pub enum Plan {
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
 
+    // Sequences
+    CreateSequence(Box<CreateSequencePlan>),
+    DropSequence(Box<DropSequencePlan>),
+
+    // Inverted indexes
+    CreateInvertedIndex(Box<CreateInvertedIndexPlan>),
+    DropInvertedIndex(Box<DropInvertedIndexPlan>),
+
+    // Workload groups
+    CreateWorkloadGroup(Box<CreateWorkloadGroupPlan>),
+    DropWorkloadGroup(Box<DropWorkloadGroupPlan>),
+
     // Account
     AlterUser(Box<AlterUserPlan>),
     CreateUser(Box<CreateUserPlan>),
@@ -191,6 +211,11 @@ pub enum Plan {
     UnSetVariable(Box<UnSettingPlan>),
     Kill(Box<KillPlan>),
 
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     // Share
     CreateShare(Box<CreateSharePlan>),
     DropShare(Box<DropSharePlan>),
@@ -254,6 +279,12 @@ impl Display for Plan {
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateSequence(_) => write!(f, "CreateSequence"),
+            Plan::DropSequence(_) => write!(f, "DropSequence"),
+            Plan::CreateInvertedIndex(_) => write!(f, "CreateInvertedIndex"),
+            Plan::DropInvertedIndex(_) => write!(f, "DropInvertedIndex"),
+            Plan::CreateWorkloadGroup(_) => write!(f, "CreateWorkloadGroup"),
+            Plan::DropWorkloadGroup(_) => write!(f, "DropWorkloadGroup"),
             Plan::AlterUser(_) => write!(f, "AlterUser"),
             Plan::CreateUser(_) => write!(f, "CreateUser"),
             Plan::DropUser(_) => write!(f, "DropUser"),
@@ -281,6 +312,9 @@ impl Display for Plan {
             Plan::UnSetVariable(_) => write!(f, "UnSetVariable"),
             Plan::SetRole(_) => write!(f, "SetRole"),
             Plan::Kill(_) => write!(f, "Kill"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Abort => write!(f, "Abort"),
             Plan::CreateShare(_) => write!(f, "CreateShare"),
             Plan::DropShare(_) => write!(f, "DropShare"),
             Plan::GrantShareObject(_) => write!(f, "GrantShareObject"),
@@ -337,6 +371,12 @@ impl Plan {
             Plan::CreateView(plan) => plan.schema(),
             Plan::AlterView(plan) => plan.schema(),
             Plan::DropView(plan) => plan.schema(),
+            Plan::CreateSequence(plan) => plan.schema(),
+            Plan::DropSequence(plan) => plan.schema(),
+            Plan::CreateInvertedIndex(plan) => plan.schema(),
+            Plan::DropInvertedIndex(plan) => plan.schema(),
+            Plan::CreateWorkloadGroup(plan) => plan.schema(),
+            Plan::DropWorkloadGroup(plan) => plan.schema(),
             Plan::AlterUser(plan) => plan.schema(),
             Plan::CreateUser(plan) => plan.schema(),
             Plan::DropUser(plan) => plan.schema(),
@@ -364,6 +404,9 @@ impl Plan {
             Plan::UnSetVariable(plan) => plan.schema(),
             Plan::SetRole(plan) => plan.schema(),
             Plan::Kill(_) => Arc::new(DataSchema::empty()),
+            Plan::Begin => Arc::new(DataSchema::empty()),
+            Plan::Commit => Arc::new(DataSchema::empty()),
+            Plan::Abort => Arc::new(DataSchema::empty()),
             Plan::CreateShare(plan) => plan.schema(),
             Plan::DropShare(plan) => plan.schema(),
             Plan::GrantShareObject(plan) => plan.schema(),
@@ -0,0 +1,194 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::Scalar;
+use crate::plans::ScalarExpr;
+use crate::IndexType;
+
+/// Which kind of containers `flatten` will expand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum FlattenMode {
+    Object,
+    Array,
+    Both,
+}
+
+impl FlattenMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FlattenMode::Object => "OBJECT",
+            FlattenMode::Array => "ARRAY",
+            FlattenMode::Both => "BOTH",
+        }
+    }
+}
+
+/// Table function which produces a set of rows for each input row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum SetReturningFunction {
+    /// `unnest(array)`, produces a row for each element of an array.
+    Unnest,
+    /// `flatten(input => v, path => 'a.b', outer => true, recursive => true, mode => 'both')`,
+    /// produces a row for each element of a Variant array or object.
+    Flatten {
+        path: String,
+        outer: bool,
+        recursive: bool,
+        mode: FlattenMode,
+    },
+}
+
+impl SetReturningFunction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SetReturningFunction::Unnest => "unnest",
+            SetReturningFunction::Flatten { .. } => "flatten",
+        }
+    }
+
+    /// Display the function call with the formatted argument `arg`.
+    pub fn display(&self, arg: &str) -> String {
+        match self {
+            SetReturningFunction::Unnest => format!("unnest({arg})"),
+            SetReturningFunction::Flatten {
+                path,
+                outer,
+                recursive,
+                mode,
+            } => format!(
+                "flatten(input => {arg}, path => '{path}', outer => {outer}, recursive => {recursive}, mode => '{}')",
+                mode.as_str()
+            ),
+        }
+    }
+
+    /// Names and types of the columns produced by the function.
+    pub fn return_types(&self, arg_type: &DataType) -> Vec<(String, DataType)> {
+        match self {
+            SetReturningFunction::Unnest => {
+                let element_type = match arg_type.remove_nullable() {
+                    DataType::Array(box ty) => ty,
+                    DataType::Variant => DataType::Variant,
+                    _ => DataType::Null,
+                };
+                vec![("unnest".to_string(), element_type)]
+            }
+            SetReturningFunction::Flatten { .. } => vec![
+                (
+                    "key".to_string(),
+                    DataType::Nullable(Box::new(DataType::String)),
+                ),
+                (
+                    "path".to_string(),
+                    DataType::Nullable(Box::new(DataType::String)),
+                ),
+                (
+                    "index".to_string(),
+                    DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64))),
+                ),
+                (
+                    "value".to_string(),
+                    DataType::Nullable(Box::new(DataType::Variant)),
+                ),
+                (
+                    "this".to_string(),
+                    DataType::Nullable(Box::new(DataType::Variant)),
+                ),
+            ],
+        }
+    }
+}
+
+/// Expand each input row with the rows produced by a set-returning function,
+/// columns of the input row are repeated for every produced row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProjectSet {
+    pub srf: SetReturningFunction,
+    pub arg: Scalar,
+    /// Indices of the columns produced by `srf`, in the order of `return_types`.
+    pub columns: Vec<IndexType>,
+}
+
+impl ProjectSet {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = self.arg.used_columns();
+        used_columns.extend(self.columns.iter().cloned());
+        Ok(used_columns)
+    }
+}
+
+impl Operator for ProjectSet {
+    fn rel_op(&self) -> RelOp {
+        RelOp::ProjectSet
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = input_prop.output_columns;
+        output_columns.extend(self.columns.iter().cloned());
+
+        // Derive outer columns
+        let mut outer_columns = input_prop.outer_columns;
+        outer_columns.extend(self.arg.used_columns());
+        outer_columns = outer_columns.difference(&output_columns).cloned().collect();
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(input_prop.used_columns);
+
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            // The number of produced rows is unknown, assume one row for each input row.
+            cardinality: input_prop.cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+                is_accurate: false,
+            },
+        })
+    }
+}
@@ -19,6 +19,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::Literal;
+use common_expression::RemoteExpr;
 
 use crate::binder::ColumnBinding;
 use crate::optimizer::ColumnSet;
@@ -49,6 +50,7 @@ pub enum Scalar {
     ComparisonExpr(ComparisonExpr),
     AggregateFunction(AggregateFunction),
     FunctionCall(FunctionCall),
+    LambdaFunction(LambdaFunc),
     // TODO(leiysky): maybe we don't need this variant any more
     // after making functions static typed?
     CastExpr(CastExpr),
@@ -66,6 +68,7 @@ impl ScalarExpr for Scalar {
             Scalar::ComparisonExpr(scalar) => scalar.data_type(),
             Scalar::AggregateFunction(scalar) => scalar.data_type(),
             Scalar::FunctionCall(scalar) => scalar.data_type(),
+            Scalar::LambdaFunction(scalar) => scalar.data_type(),
             Scalar::CastExpr(scalar) => scalar.data_type(),
             Scalar::SubqueryExpr(scalar) => scalar.data_type(),
         }
@@ -81,6 +84,7 @@ impl ScalarExpr for Scalar {
             Scalar::ComparisonExpr(scalar) => scalar.used_columns(),
             Scalar::AggregateFunction(scalar) => scalar.used_columns(),
             Scalar::FunctionCall(scalar) => scalar.used_columns(),
+            Scalar::LambdaFunction(scalar) => scalar.used_columns(),
             Scalar::CastExpr(scalar) => scalar.used_columns(),
             Scalar::SubqueryExpr(scalar) => scalar.used_columns(),
         }
@@ -233,6 +237,23 @@ impl TryFrom<Scalar> for FunctionCall {
     }
 }
 
+impl From<LambdaFunc> for Scalar {
+    fn from(v: LambdaFunc) -> Self {
+        Self::LambdaFunction(v)
+    }
+}
+
+impl TryFrom<Scalar> for LambdaFunc {
+    type Error = ErrorCode;
+    fn try_from(value: Scalar) -> Result<Self> {
+        if let Scalar::LambdaFunction(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal("Cannot downcast Scalar to LambdaFunc"))
+        }
+    }
+}
+
 impl From<CastExpr> for Scalar {
     fn from(v: CastExpr) -> Self {
         Self::CastExpr(v)
@@ -472,6 +493,33 @@ impl ScalarExpr for FunctionCall {
     }
 }
 
+/// Higher-order function call with a lambda argument, like `array_filter(arr, x -> x > 1)`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LambdaFunc {
+    pub func_name: String,
+    pub args: Vec<Scalar>,
+    /// Name and type of the lambda parameters.
+    pub params: Vec<(String, DataType)>,
+    /// The lambda body, which refers to the i-th parameter by `ColumnRef` with index `i`.
+    pub lambda_expr: Box<RemoteExpr>,
+    pub lambda_display: String,
+    pub return_type: Box<DataType>,
+}
+
+impl ScalarExpr for LambdaFunc {
+    fn data_type(&self) -> DataType {
+        *self.return_type.clone()
+    }
+
+    fn used_columns(&self) -> ColumnSet {
+        let mut result = ColumnSet::new();
+        for scalar in self.args.iter() {
+            result = result.union(&scalar.used_columns()).cloned().collect();
+        }
+        result
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct CastExpr {
     pub argument: Box<Scalar>,
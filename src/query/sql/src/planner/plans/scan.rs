@@ -37,6 +37,7 @@ use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::Scalar;
 use crate::plans::SortItem;
+use crate::ColumnBinding;
 use crate::IndexType;
 use crate::ScalarExpr;
 
@@ -67,6 +68,9 @@ pub struct Scan {
     pub limit: Option<usize>,
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
+    // stored generated columns and their expressions, the predicates on the expressions
+    // can be rewritten to use the columns.
+    pub computed_columns: Vec<(Scalar, ColumnBinding)>,
 
     pub statistics: Statistics,
 }
@@ -89,6 +89,7 @@ impl VisitorMut for DistinctToGroupBy {
                         }],
                         from: vec![TableReference::Subquery {
                             span: &[],
+                            lateral: false,
                             subquery: Box::new(subquery),
                             alias: None,
                         }],
@@ -24,6 +24,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::Scalar;
@@ -113,6 +114,22 @@ impl<'a> GroupingChecker<'a> {
                 }
                 .into())
             }
+            Scalar::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.resolve(arg, span))
+                    .collect::<Result<Vec<Scalar>>>()?;
+                Ok(LambdaFunc {
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    params: lambda.params.clone(),
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
             Scalar::CastExpr(cast) => Ok(CastExpr {
                 argument: Box::new(self.resolve(&cast.argument, span)?),
                 from_type: cast.from_type.clone(),
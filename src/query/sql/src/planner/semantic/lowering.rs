@@ -72,6 +72,13 @@ impl Scalar {
                 params: func.params.clone(),
                 args: func.arguments.iter().map(Scalar::as_raw_expr).collect(),
             },
+            Scalar::LambdaFunction(func) => RawExpr::LambdaFunctionCall {
+                span: None,
+                name: func.func_name.clone(),
+                args: func.args.iter().map(Scalar::as_raw_expr).collect(),
+                lambda_expr: *func.lambda_expr.clone(),
+                lambda_display: func.lambda_display.clone(),
+            },
             Scalar::CastExpr(cast) => {
                 let is_try = cast.target_type.is_nullable();
                 RawExpr::Cast {
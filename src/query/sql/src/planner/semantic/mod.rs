@@ -16,6 +16,7 @@ mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
 mod name_resolution;
+mod result_cache_check;
 mod type_check;
 
 pub use distinct_to_groupby::DistinctToGroupBy;
@@ -24,5 +25,6 @@ pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
+pub use result_cache_check::ResultCacheChecker;
 pub use type_check::validate_function_arg;
 pub use type_check::TypeChecker;
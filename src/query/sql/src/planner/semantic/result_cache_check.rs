@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::RemoteExpr;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+
+use crate::optimizer::SExpr;
+use crate::plans::Exchange;
+use crate::plans::RelOperator;
+use crate::plans::Scalar;
+use crate::MetadataRef;
+
+/// Checks whether the result of a query only depends on the tables it reads,
+/// so that the result can be served from the query result cache.
+///
+/// The check runs on the bound plan, thus the functions called by the views
+/// and the UDFs used by the query are checked as well.
+pub struct ResultCacheChecker;
+
+impl ResultCacheChecker {
+    pub fn is_cacheable(s_expr: &SExpr, metadata: &MetadataRef) -> bool {
+        !metadata.read().is_session_dependent() && Self::check_plan(s_expr)
+    }
+
+    fn check_plan(s_expr: &SExpr) -> bool {
+        let deterministic = match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                let predicates = scan.push_down_predicates.iter().flatten();
+                let prewhere = scan.prewhere.iter().flat_map(|p| p.predicates.iter());
+                predicates.chain(prewhere).all(Self::check_scalar)
+            }
+            RelOperator::Join(join) => join
+                .left_conditions
+                .iter()
+                .chain(join.right_conditions.iter())
+                .chain(join.non_equi_conditions.iter())
+                .all(Self::check_scalar),
+            RelOperator::EvalScalar(eval) => eval
+                .items
+                .iter()
+                .all(|item| Self::check_scalar(&item.scalar)),
+            RelOperator::Filter(filter) => filter.predicates.iter().all(Self::check_scalar),
+            RelOperator::Aggregate(agg) => agg
+                .group_items
+                .iter()
+                .chain(agg.aggregate_functions.iter())
+                .all(|item| Self::check_scalar(&item.scalar)),
+            RelOperator::ProjectSet(project_set) => Self::check_scalar(&project_set.arg),
+            RelOperator::Exchange(Exchange::Hash(keys)) => keys.iter().all(Self::check_scalar),
+            RelOperator::Exchange(_)
+            | RelOperator::Sort(_)
+            | RelOperator::Limit(_)
+            | RelOperator::UnionAll(_)
+            | RelOperator::DummyTableScan(_)
+            | RelOperator::Pattern(_) => true,
+        };
+        deterministic && s_expr.children().iter().all(Self::check_plan)
+    }
+
+    fn check_scalar(scalar: &Scalar) -> bool {
+        match scalar {
+            Scalar::BoundColumnRef(_) | Scalar::ConstantExpr(_) => true,
+            Scalar::AndExpr(expr) => {
+                Self::check_scalar(&expr.left) && Self::check_scalar(&expr.right)
+            }
+            Scalar::OrExpr(expr) => {
+                Self::check_scalar(&expr.left) && Self::check_scalar(&expr.right)
+            }
+            Scalar::ComparisonExpr(expr) => {
+                Self::check_scalar(&expr.left) && Self::check_scalar(&expr.right)
+            }
+            Scalar::NotExpr(expr) => Self::check_scalar(&expr.argument),
+            Scalar::CastExpr(expr) => Self::check_scalar(&expr.argument),
+            Scalar::AggregateFunction(agg) => agg.args.iter().all(Self::check_scalar),
+            Scalar::FunctionCall(func) => {
+                !Self::is_non_deterministic_function(&func.func_name)
+                    && func.arguments.iter().all(Self::check_scalar)
+            }
+            Scalar::LambdaFunction(lambda) => {
+                lambda.args.iter().all(Self::check_scalar) && Self::check_expr(&lambda.lambda_expr)
+            }
+            Scalar::SubqueryExpr(subquery) => {
+                subquery
+                    .child_expr
+                    .iter()
+                    .all(|expr| Self::check_scalar(expr))
+                    && Self::check_plan(&subquery.subquery)
+            }
+        }
+    }
+
+    fn check_expr(expr: &RemoteExpr) -> bool {
+        match expr {
+            RemoteExpr::Constant { .. } | RemoteExpr::ColumnRef { .. } => true,
+            RemoteExpr::Cast { expr, .. } => Self::check_expr(expr),
+            RemoteExpr::FunctionCall { id, args, .. } => {
+                BUILTIN_FUNCTIONS
+                    .get(id)
+                    .map_or(true, |func| !func.signature.property.non_deterministic)
+                    && args.iter().all(Self::check_expr)
+            }
+            RemoteExpr::LambdaFunctionCall {
+                args, lambda_expr, ..
+            } => args.iter().all(Self::check_expr) && Self::check_expr(lambda_expr),
+        }
+    }
+
+    /// Whether any overload of the function is non-deterministic, such as `now()` or `rand()`.
+    fn is_non_deterministic_function(name: &str) -> bool {
+        let name = name.to_lowercase();
+        let name = BUILTIN_FUNCTIONS
+            .aliases
+            .get(&name)
+            .cloned()
+            .unwrap_or(name);
+        BUILTIN_FUNCTIONS.funcs.get(&name).map_or(false, |funcs| {
+            funcs
+                .iter()
+                .any(|func| func.signature.property.non_deterministic)
+        })
+    }
+}
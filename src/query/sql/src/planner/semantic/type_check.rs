@@ -34,6 +34,7 @@ use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
 use common_ast::DisplayError;
 use common_catalog::catalog::CatalogManager;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -41,23 +42,29 @@ use common_expression::infer_schema_type;
 use common_expression::type_check;
 use common_expression::type_check::check_literal;
 use common_expression::type_check::common_super_type;
+use common_expression::type_check::is_lambda_function;
 use common_expression::types::number::F64;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::DataBlock;
 use common_expression::Evaluator;
 use common_expression::RawExpr;
+use common_expression::RemoteExpr;
 use common_expression::TableDataType;
 use common_functions::aggregates::AggregateCountFunction;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
+use common_functions::scalars::query_terms;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::GetSequenceReq;
+use common_meta_app::schema::SequenceIdent;
 use common_users::UserApiProvider;
 
 use super::name_resolution::NameResolutionContext;
 use super::normalize_identifier;
 use crate::binder::Binder;
 use crate::binder::NameResolutionResult;
+use crate::binder::Visibility;
 use crate::optimizer::RelExpr;
 use crate::planner::binder::wrap_cast_if_needed;
 use crate::planner::metadata::optimize_remove_count_args;
@@ -69,6 +76,7 @@ use crate::plans::ComparisonExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::NotExpr;
 use crate::plans::OrExpr;
 use crate::plans::Scalar;
@@ -624,6 +632,9 @@ impl<'a> TypeChecker<'a> {
             } => {
                 let func_name = name.name.to_lowercase();
                 let func_name = func_name.as_str();
+                if args.iter().any(|arg| matches!(arg, Expr::Lambda { .. })) {
+                    return self.resolve_lambda_function(span, func_name, args).await;
+                }
                 if !is_builtin_function(func_name)
                     && !Self::all_rewritable_scalar_function().contains(&func_name)
                 {
@@ -919,6 +930,13 @@ impl<'a> TypeChecker<'a> {
             }
 
             Expr::Tuple { span, exprs, .. } => self.resolve_tuple(span, exprs).await?,
+
+            Expr::Lambda { span, .. } => {
+                return Err(ErrorCode::SemanticError(span.display_error(
+                    "lambda expression can only be used as an argument of higher-order functions"
+                        .to_string(),
+                )));
+            }
         };
 
         Ok(Box::new(self.post_resolve(&scalar, &data_type)?))
@@ -1419,6 +1437,7 @@ impl<'a> TypeChecker<'a> {
             "current_user",
             "current_role",
             "connection_id",
+            "last_query_id",
             "timezone",
             "nullif",
             "ifnull",
@@ -1434,7 +1453,24 @@ impl<'a> TypeChecker<'a> {
         func_name: &str,
         args: &[&Expr<'_>],
     ) -> Option<Result<Box<(Scalar, DataType)>>> {
-        match (func_name.to_lowercase().as_str(), args) {
+        let func_name = func_name.to_lowercase();
+        if matches!(
+            func_name.as_str(),
+            "database"
+                | "currentdatabase"
+                | "current_database"
+                | "user"
+                | "currentuser"
+                | "current_user"
+                | "current_role"
+                | "connection_id"
+                | "last_query_id"
+        ) {
+            // the result of the query depends on the session, it can't be cached
+            self.metadata.write().set_session_dependent();
+        }
+
+        match (func_name.as_str(), args) {
             ("database" | "currentdatabase" | "current_database", &[]) => Some(
                 self.resolve(
                     &Expr::Literal {
@@ -1493,6 +1529,16 @@ impl<'a> TypeChecker<'a> {
                 )
                 .await,
             ),
+            ("last_query_id", &[]) => Some(
+                self.resolve(
+                    &Expr::Literal {
+                        span,
+                        lit: Literal::String(self.ctx.get_last_query_id()),
+                    },
+                    None,
+                )
+                .await,
+            ),
             ("timezone", &[]) => {
                 let tz = self.ctx.get_settings().get_timezone().unwrap();
                 Some(
@@ -1574,6 +1620,8 @@ impl<'a> TypeChecker<'a> {
                     .await,
                 )
             }
+            ("nextval", args) => Some(self.resolve_nextval(span, args).await),
+            ("score", &[]) => Some(self.resolve_score(span).await),
             ("coalesce", args) => {
                 // coalesce(arg0, arg1, ..., argN) is essentially
                 // multi_if(is_not_null(arg0), assume_not_null(arg0), is_not_null(arg1), assume_not_null(arg1), ..., argN)
@@ -1625,6 +1673,152 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolve `nextval(<sequence>)` to `nextval('<tenant>', '<sequence>', <sequence_id>)`,
+    /// the sequence must exist when the query is planned.
+    async fn resolve_nextval(
+        &mut self,
+        span: &[Token<'_>],
+        args: &[&Expr<'_>],
+    ) -> Result<Box<(Scalar, DataType)>> {
+        let sequence = match args {
+            [
+                Expr::Literal {
+                    lit: Literal::String(sequence),
+                    ..
+                },
+            ] => sequence.clone(),
+            [
+                Expr::ColumnRef {
+                    database: None,
+                    table: None,
+                    column,
+                    ..
+                },
+            ] => normalize_identifier(column, self.name_resolution_ctx).name,
+            _ => {
+                return Err(ErrorCode::SemanticError(span.display_error(
+                    "nextval expects the name of a sequence as the only argument".to_string(),
+                )));
+            }
+        };
+
+        let tenant = self.ctx.get_tenant();
+        let reply = self
+            .ctx
+            .get_catalog(CATALOG_DEFAULT)?
+            .get_sequence(GetSequenceReq {
+                ident: SequenceIdent::new(&tenant, &sequence),
+            })
+            .await?;
+
+        // The id tells the cached values of a dropped sequence from the re-created one.
+        let mut args: Vec<Scalar> = [tenant, sequence]
+            .into_iter()
+            .map(|value| {
+                ConstantExpr {
+                    value: common_expression::Literal::String(value.into_bytes()),
+                    data_type: Box::new(DataType::String),
+                }
+                .into()
+            })
+            .collect();
+        args.push(
+            ConstantExpr {
+                value: common_expression::Literal::UInt64(reply.meta.sequence_id),
+                data_type: Box::new(DataType::Number(NumberDataType::UInt64)),
+            }
+            .into(),
+        );
+        self.resolve_scalar_function_call(span, "nextval", vec![], args, None)
+            .await
+    }
+
+    /// Resolve `score()` to `score(<column>, <query>, <idfs>)` of the `match` in the WHERE clause.
+    ///
+    /// The IDFs of the query terms are computed with the term statistics of the table.
+    async fn resolve_score(&mut self, span: &[Token<'_>]) -> Result<Box<(Scalar, DataType)>> {
+        let mut args = match &self.bind_context.match_predicate {
+            Some(args) => args.clone(),
+            None => {
+                return Err(ErrorCode::SemanticError(span.display_error(
+                    "SCORE() requires a MATCH() predicate in the WHERE clause".to_string(),
+                )));
+            }
+        };
+        let terms = match &args[1] {
+            Scalar::ConstantExpr(ConstantExpr {
+                value: common_expression::Literal::String(query),
+                ..
+            }) => query_terms(&String::from_utf8_lossy(query)),
+            _ => {
+                return Err(ErrorCode::SemanticError(span.display_error(
+                    "SCORE() requires the query of the MATCH() predicate to be a constant string"
+                        .to_string(),
+                )));
+            }
+        };
+
+        let indexed_column = match &args[0] {
+            Scalar::BoundColumnRef(BoundColumnRef { column }) => {
+                let metadata = self.metadata.read();
+                match metadata.column(column.index) {
+                    ColumnEntry::BaseTableColumn {
+                        table_index,
+                        column_name,
+                        ..
+                    } => Some((metadata.table(*table_index).table(), column_name.clone())),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        let statistics = match indexed_column {
+            Some((table, column_name)) => {
+                table
+                    .term_statistics(self.ctx.clone(), &column_name, &terms)
+                    .await?
+            }
+            None => None,
+        };
+
+        // The classic IDF of Lucene, all the terms weigh the same without the statistics.
+        let idfs = (0..terms.len())
+            .map(|i| {
+                let idf = match &statistics {
+                    Some(statistics) => {
+                        let num_rows = statistics.num_rows as f64;
+                        let doc_frequency = statistics.doc_frequencies[i] as f64;
+                        1.0 + (num_rows / (doc_frequency + 1.0)).ln()
+                    }
+                    None => 1.0,
+                };
+                ConstantExpr {
+                    value: common_expression::Literal::Float64(F64::from(idf)),
+                    data_type: Box::new(DataType::Number(NumberDataType::Float64)),
+                }
+                .into()
+            })
+            .collect::<Vec<Scalar>>();
+        if idfs.is_empty() {
+            // An empty query matches nothing.
+            return Ok(Box::new((
+                ConstantExpr {
+                    value: common_expression::Literal::Float64(F64::from(0.0)),
+                    data_type: Box::new(DataType::Number(NumberDataType::Float64)),
+                }
+                .into(),
+                DataType::Number(NumberDataType::Float64),
+            )));
+        }
+
+        let box (idfs, _) = self
+            .resolve_scalar_function_call(span, "array", vec![], idfs, None)
+            .await?;
+        args.push(idfs);
+        self.resolve_scalar_function_call(span, "score", vec![], args, None)
+            .await
+    }
+
     #[async_recursion::async_recursion]
     async fn resolve_trim_function(
         &mut self,
@@ -1801,6 +1995,152 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolve higher-order function with a lambda argument, like `array_filter(arr, x -> x > 1)`.
+    #[async_recursion::async_recursion]
+    async fn resolve_lambda_function(
+        &mut self,
+        span: &[Token<'_>],
+        func_name: &str,
+        args: &[Expr<'_>],
+    ) -> Result<Box<(Scalar, DataType)>> {
+        if !is_lambda_function(func_name) {
+            return Err(ErrorCode::SemanticError(span.display_error(format!(
+                "function `{func_name}` does not accept lambda"
+            ))));
+        }
+        let (arg, lambda, lambda_params, lambda_body) = match args {
+            [arg, lambda @ Expr::Lambda { params, expr, .. }] => (arg, lambda, params, expr),
+            _ => {
+                return Err(ErrorCode::SemanticError(span.display_error(format!(
+                    "function `{func_name}` expects an array argument and a lambda"
+                ))));
+            }
+        };
+
+        let box (arg, arg_type) = self.resolve(arg, None).await?;
+        let elem_type = match arg_type.remove_nullable() {
+            DataType::Array(box ty) => ty,
+            DataType::EmptyArray | DataType::Null => DataType::Null,
+            ty => {
+                return Err(ErrorCode::SemanticError(span.display_error(format!(
+                    "function `{func_name}` expects an array argument, but got `{ty}`"
+                ))));
+            }
+        };
+
+        let num_params = match func_name {
+            "array_reduce" | "array_sort" => 2,
+            _ => 1,
+        };
+        if lambda_params.len() != num_params {
+            return Err(ErrorCode::SemanticError(lambda.span().display_error(
+                format!(
+                    "lambda of `{func_name}` expects {num_params} parameters, but got {}",
+                    lambda_params.len()
+                ),
+            )));
+        }
+        let params = lambda_params
+            .iter()
+            .map(|param| normalize_identifier(param, self.name_resolution_ctx).name)
+            .collect::<Vec<_>>();
+        let mut param_types = vec![elem_type; num_params];
+        let mut lambda_expr = self
+            .resolve_lambda_body(&params, &param_types, lambda_body)
+            .await?;
+
+        // The accumulator of `array_reduce` has the same type as the lambda body, so
+        // re-resolve the body until the type of the accumulator becomes stable.
+        if func_name == "array_reduce" {
+            const MAX_ITERATIONS: usize = 8;
+            let mut iterations = 0;
+            while lambda_expr.data_type() != &param_types[0] {
+                iterations += 1;
+                if iterations > MAX_ITERATIONS {
+                    return Err(ErrorCode::SemanticError(lambda.span().display_error(
+                        "unable to infer the accumulator type of `array_reduce`".to_string(),
+                    )));
+                }
+                param_types[0] = lambda_expr.data_type().clone();
+                lambda_expr = self
+                    .resolve_lambda_body(&params, &param_types, lambda_body)
+                    .await?;
+            }
+        }
+
+        let lambda_display = format!("{:#}", lambda);
+        let raw_expr = RawExpr::LambdaFunctionCall {
+            span: None,
+            name: func_name.to_string(),
+            args: vec![arg.as_raw_expr()],
+            lambda_expr: lambda_expr.clone(),
+            lambda_display: lambda_display.clone(),
+        };
+        let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS)
+            .map_err(|(_, e)| ErrorCode::SemanticError(span.display_error(e)))?;
+        let return_type = expr.data_type().clone();
+
+        Ok(Box::new((
+            LambdaFunc {
+                func_name: func_name.to_string(),
+                args: vec![arg],
+                params: params.into_iter().zip(param_types).collect(),
+                lambda_expr: Box::new(lambda_expr),
+                lambda_display,
+                return_type: Box::new(return_type.clone()),
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
+    /// Resolve the body of a lambda in a context that only contains the lambda parameters,
+    /// the i-th parameter is referred by `ColumnRef` with index `i` in the result.
+    ///
+    /// The body is evaluated on the elements of the arrays only, so it can't refer to the
+    /// columns outside of the lambda, e.g. `array_filter(arr, x -> x > t.a)` is rejected
+    /// with a semantic error.
+    #[async_recursion::async_recursion]
+    async fn resolve_lambda_body(
+        &self,
+        params: &[String],
+        param_types: &[DataType],
+        body: &Expr<'_>,
+    ) -> Result<RemoteExpr> {
+        let mut bind_context = BindContext::new();
+        for (index, (name, data_type)) in params.iter().zip(param_types).enumerate() {
+            bind_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: name.clone(),
+                index,
+                data_type: Box::new(data_type.clone()),
+                visibility: Visibility::Visible,
+            });
+        }
+        let mut type_checker = TypeChecker::new(
+            &bind_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let box (scalar, _) = type_checker.resolve(body, None).await?;
+        let expr = scalar.as_expr()?;
+        if let Some(name) = expr
+            .column_refs()
+            .keys()
+            .find(|name| !params.contains(name))
+        {
+            return Err(ErrorCode::SemanticError(body.span().display_error(
+                format!("lambda expression can only refer to its parameters, but got `{name}`"),
+            )));
+        }
+        let expr =
+            expr.project_column_ref(|name| params.iter().position(|param| param == name).unwrap());
+        Ok(expr.as_remote_expr())
+    }
+
     #[async_recursion::async_recursion]
     async fn resolve_map_access(
         &mut self,
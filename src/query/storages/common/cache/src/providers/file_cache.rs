@@ -12,35 +12,389 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs;
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Instant;
 
+use common_base::base::tokio;
+use common_base::base::uuid;
+use common_base::runtime::Thread;
+use common_base::runtime::ThreadJoinHandle;
+use common_cache::Cache;
+use common_cache::DefaultHashBuilder;
+use common_cache::FileSize;
+use common_cache::LruCache;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use opendal::Object;
+use parking_lot::Mutex;
+use sha2::Digest;
+use sha2::Sha256;
+use tracing::warn;
 
+use crate::providers::metrics::*;
 use crate::CacheSettings;
 use crate::ObjectCacheProvider;
 
-// TODO(bohu): local disk file based LRU cache
-pub struct FileCache {}
+const TMP_DIR: &str = "tmp";
+const MAGIC: [u8; 4] = *b"DBFC";
+// magic, crc32 of the data, length of the key.
+const HEADER_LEN: usize = 12;
+/// The writes in the background are skipped if they would hold more bytes than this.
+const MAX_PENDING_WRITE_BYTES: u64 = 256 * 1024 * 1024;
+
+type FileLru = LruCache<String, u64, DefaultHashBuilder, FileSize>;
+
+/// Local disk LRU cache for bytes(Vec<u8>).
+///
+/// Every item is a file named by the sha256 of its key, which starts with a header
+/// containing the key and the crc32 of the data. Items are written to a temp file and then
+/// renamed, so the cache directory is the metadata of the cache: it is scanned to warm up
+/// the LRU again after restart, and a torn item is detected by the checksum when it is read.
+///
+/// The LRU is rebuilt in the background after the cache is created, the async readers
+/// read and write the items in the blocking threads of the runtime.
+pub struct FileCache {
+    inner: Arc<FileCacheInner>,
+    recovering: Mutex<Option<ThreadJoinHandle<()>>>,
+}
 
-/// By pass cache, ignore the cache, default.
 impl FileCache {
-    pub fn create(_settings: &CacheSettings) -> FileCache {
-        Self {}
+    pub fn try_create(settings: &CacheSettings) -> Result<FileCache> {
+        let root = PathBuf::from(&settings.file_cache_root);
+        fs::create_dir_all(root.join(TMP_DIR))?;
+
+        // Temp files are left by the writes interrupted by a crash, they are removed
+        // before any write of this process.
+        for entry in fs::read_dir(root.join(TMP_DIR))? {
+            let _ = fs::remove_file(entry?.path());
+        }
+
+        let inner = Arc::new(FileCacheInner {
+            root,
+            lru: Mutex::new(LruCache::with_meter_and_hasher(
+                settings.file_cache_capacity,
+                FileSize,
+                DefaultHashBuilder::new(),
+            )),
+            settings: settings.clone(),
+            pending_write_bytes: AtomicU64::new(0),
+        });
+
+        let recovering = inner.clone();
+        let handle = Thread::named_spawn(Some(String::from("FileCacheRecover")), move || {
+            if let Err(cause) = recovering.recover() {
+                warn!(
+                    "failed to recover file cache from {:?}, {}",
+                    recovering.root, cause
+                );
+            }
+        });
+
+        Ok(FileCache {
+            inner,
+            recovering: Mutex::new(Some(handle)),
+        })
+    }
+
+    /// Wait until the LRU is rebuilt from the items in the cache directory.
+    pub fn wait_recovered(&self) -> Result<()> {
+        let handle = self.recovering.lock().take();
+        match handle {
+            None => Ok(()),
+            Some(handle) => handle.join(),
+        }
+    }
+
+    /// Get the data of the key, returns None if it is not cached or the item is broken.
+    pub fn get(&self, key: &str) -> Option<Vec<u8>> {
+        self.inner.get(key)
+    }
+
+    /// Get the data of the key, the item is read in a blocking thread of the runtime.
+    pub async fn get_async(&self, key: String) -> Option<Vec<u8>> {
+        if !self.inner.lru.lock().contains(&key) {
+            metrics_inc_file_misses(1);
+            return None;
+        }
+
+        let inner = self.inner.clone();
+        tokio::task::spawn_blocking(move || inner.get(&key))
+            .await
+            .unwrap_or(None)
+    }
+
+    /// Put the data into the cache, evicting the least recently used items if it's full.
+    ///
+    /// Failures are only logged, the cache should never fail the reading.
+    pub fn put(&self, key: String, data: &[u8]) {
+        self.inner.put(key, data)
+    }
+
+    /// Put the data into the cache in a blocking thread of the runtime.
+    pub async fn put_async(&self, key: String, data: Vec<u8>) {
+        let inner = self.inner.clone();
+        let _ = tokio::task::spawn_blocking(move || inner.put(key, &data)).await;
+    }
+
+    /// Put the data into the cache in the background, the caller doesn't wait for the write.
+    /// It is written in place if it's not called in a runtime, and skipped if too many bytes
+    /// are waiting to be written.
+    pub fn put_in_background(&self, key: String, data: Vec<u8>) {
+        let handle = match tokio::runtime::Handle::try_current() {
+            Ok(handle) => handle,
+            Err(_) => return self.inner.put(key, &data),
+        };
+
+        let size = data.len() as u64;
+        let pending = self
+            .inner
+            .pending_write_bytes
+            .fetch_add(size, Ordering::Relaxed);
+        if pending + size > MAX_PENDING_WRITE_BYTES {
+            self.inner
+                .pending_write_bytes
+                .fetch_sub(size, Ordering::Relaxed);
+            return;
+        }
+
+        let inner = self.inner.clone();
+        handle.spawn_blocking(move || {
+            inner.put(key, &data);
+            inner.pending_write_bytes.fetch_sub(size, Ordering::Relaxed);
+        });
+    }
+
+    pub fn remove(&self, key: &str) {
+        self.inner.remove(key)
+    }
+}
+
+struct FileCacheInner {
+    root: PathBuf,
+    lru: Mutex<FileLru>,
+    settings: CacheSettings,
+    pending_write_bytes: AtomicU64,
+}
+
+impl FileCacheInner {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        if self.lru.lock().get(key).is_none() {
+            metrics_inc_file_misses(1);
+            return None;
+        }
+
+        let now = Instant::now();
+        match self.read_item(key) {
+            Ok(data) => {
+                // Perf.
+                {
+                    metrics_inc_file_hits(1);
+                    metrics_inc_file_read_milliseconds(now.elapsed().as_millis() as u64);
+                }
+
+                Some(data)
+            }
+            Err(cause) => {
+                warn!("invalid file cache item of {}, {}", key, cause);
+                self.remove(key);
+
+                // Perf.
+                {
+                    metrics_inc_file_invalid_items(1);
+                    metrics_inc_file_misses(1);
+                }
+
+                None
+            }
+        }
+    }
+
+    fn put(&self, key: String, data: &[u8]) {
+        let size = (HEADER_LEN + key.len() + data.len()) as u64;
+        if size > self.settings.file_cache_capacity {
+            return;
+        }
+
+        let now = Instant::now();
+        if let Err(cause) = self.write_item(&key, data) {
+            warn!("failed to write file cache item of {}, {}", key, cause);
+            return;
+        }
+
+        let mut lru = self.lru.lock();
+        lru.pop(&key);
+        self.evict(&mut lru, size);
+        lru.put(key, size);
+
+        // Perf.
+        {
+            metrics_inc_file_writes(1);
+            metrics_inc_file_write_milliseconds(now.elapsed().as_millis() as u64);
+            metrics_set_file_bytes(lru.size());
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        let mut lru = self.lru.lock();
+        if lru.pop(key).is_some() {
+            let _ = fs::remove_file(self.item_path(key));
+            metrics_set_file_bytes(lru.size());
+        }
+    }
+
+    fn evict(&self, lru: &mut FileLru, size: u64) {
+        while lru.size() + size > lru.capacity() {
+            match lru.pop_by_policy() {
+                None => break,
+                Some((key, _)) => {
+                    let _ = fs::remove_file(self.item_path(&key));
+                    metrics_inc_file_evictions(1);
+                }
+            }
+        }
+    }
+
+    /// Rebuild the LRU from the items in the cache directory, the older modified the earlier evicted.
+    fn recover(&self) -> Result<()> {
+        let mut items = vec![];
+        for dir in fs::read_dir(&self.root)? {
+            let dir = dir?;
+            if !dir.file_type()?.is_dir() || dir.file_name() == TMP_DIR {
+                continue;
+            }
+
+            for entry in fs::read_dir(dir.path())? {
+                let entry = entry?;
+                let path = entry.path();
+                let metadata = entry.metadata()?;
+                match Self::read_key(&path) {
+                    Ok(key) if self.item_path(&key) == path => {
+                        items.push((metadata.modified()?, key, metadata.len()));
+                    }
+                    _ => {
+                        warn!("remove unknown file cache item {:?}", path);
+                        let _ = fs::remove_file(&path);
+                    }
+                }
+            }
+        }
+        items.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut lru = self.lru.lock();
+        for (_, key, size) in items {
+            // Put again since the recovery started.
+            if lru.contains(&key) {
+                continue;
+            }
+            self.evict(&mut lru, size);
+            lru.put(key, size);
+        }
+        metrics_set_file_bytes(lru.size());
+        Ok(())
+    }
+
+    fn item_path(&self, key: &str) -> PathBuf {
+        let hash = format!("{:x}", Sha256::digest(key.as_bytes()));
+        self.root.join(&hash[0..2]).join(hash)
+    }
+
+    fn write_item(&self, key: &str, data: &[u8]) -> Result<()> {
+        let tmp_path = self
+            .root
+            .join(TMP_DIR)
+            .join(uuid::Uuid::new_v4().simple().to_string());
+
+        let mut file = File::create(&tmp_path)?;
+        file.write_all(&MAGIC)?;
+        file.write_all(&crc32fast::hash(data).to_le_bytes())?;
+        file.write_all(&(key.len() as u32).to_le_bytes())?;
+        file.write_all(key.as_bytes())?;
+        file.write_all(data)?;
+        drop(file);
+
+        let path = self.item_path(key);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        if let Err(cause) = fs::rename(&tmp_path, &path) {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(cause.into());
+        }
+        Ok(())
+    }
+
+    fn read_item(&self, key: &str) -> Result<Vec<u8>> {
+        let mut bytes = fs::read(self.item_path(key))?;
+        let (checksum, key_len) = Self::decode_header(&bytes)?;
+        if bytes.len() < HEADER_LEN + key_len
+            || &bytes[HEADER_LEN..HEADER_LEN + key_len] != key.as_bytes()
+        {
+            return Err(ErrorCode::StorageOther("file cache item key mismatch"));
+        }
+
+        let data = bytes.split_off(HEADER_LEN + key_len);
+        if crc32fast::hash(&data) != checksum {
+            return Err(ErrorCode::StorageOther("file cache item checksum mismatch"));
+        }
+        Ok(data)
+    }
+
+    fn read_key(path: &Path) -> Result<String> {
+        let mut file = File::open(path)?;
+        let mut header = [0; HEADER_LEN];
+        file.read_exact(&mut header)?;
+        let (_, key_len) = Self::decode_header(&header)?;
+
+        let mut key = vec![0; key_len];
+        file.read_exact(&mut key)?;
+        String::from_utf8(key)
+            .map_err(|_| ErrorCode::StorageOther("file cache item key is not utf8"))
+    }
+
+    fn decode_header(bytes: &[u8]) -> Result<(u32, usize)> {
+        if bytes.len() < HEADER_LEN || bytes[0..4] != MAGIC {
+            return Err(ErrorCode::StorageOther("invalid file cache item header"));
+        }
+        let checksum = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        let key_len = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+        Ok((checksum, key_len as usize))
     }
 }
 
 #[async_trait::async_trait]
 impl ObjectCacheProvider<Vec<u8>> for FileCache {
-    async fn read_object(&self, _object: &Object, _start: u64, _end: u64) -> Result<Arc<Vec<u8>>> {
-        todo!()
+    async fn read_object(&self, object: &Object, start: u64, end: u64) -> Result<Arc<Vec<u8>>> {
+        let key = format!("{}-{}-{}", object.path(), start, end);
+        if let Some(data) = self.get_async(key.clone()).await {
+            return Ok(Arc::new(data));
+        }
+
+        let data = object.range_read(start..end).await?;
+        self.put_in_background(key, data.clone());
+        Ok(Arc::new(data))
     }
 
-    async fn write_object(&self, _object: &Object, _v: Arc<Vec<u8>>) -> Result<()> {
-        todo!()
+    async fn write_object(&self, object: &Object, v: Arc<Vec<u8>>) -> Result<()> {
+        object.write(v.as_slice()).await?;
+
+        // The object is readable from the cache once it is written.
+        if self.inner.settings.cache_on_write {
+            let key = format!("{}-{}-{}", object.path(), 0, v.len());
+            self.put_async(key, v.to_vec()).await;
+        }
+        Ok(())
     }
 
     async fn remove_object(&self, object: &Object) -> Result<()> {
+        // The cached ranges of the object are unknown here, they are left to be evicted.
         object.delete().await?;
         Ok(())
     }
@@ -104,6 +104,39 @@ pub fn metrics_inc_memory_bytes_remove_milliseconds(c: u64) {
     increment_gauge!(key!("memory_bytes_remove_milliseconds"), c as f64);
 }
 
+/// File Cache Metrics
+pub fn metrics_inc_file_hits(c: u64) {
+    increment_gauge!(key!("file_hits"), c as f64);
+}
+
+pub fn metrics_inc_file_misses(c: u64) {
+    increment_gauge!(key!("file_misses"), c as f64);
+}
+
+pub fn metrics_inc_file_read_milliseconds(c: u64) {
+    increment_gauge!(key!("file_read_milliseconds"), c as f64);
+}
+
+pub fn metrics_inc_file_writes(c: u64) {
+    increment_gauge!(key!("file_writes"), c as f64);
+}
+
+pub fn metrics_inc_file_write_milliseconds(c: u64) {
+    increment_gauge!(key!("file_write_milliseconds"), c as f64);
+}
+
+pub fn metrics_inc_file_evictions(c: u64) {
+    increment_gauge!(key!("file_evictions"), c as f64);
+}
+
+pub fn metrics_inc_file_invalid_items(c: u64) {
+    increment_gauge!(key!("file_invalid_items"), c as f64);
+}
+
+pub fn metrics_set_file_bytes(c: u64) {
+    gauge!(key!("file_bytes"), c as f64);
+}
+
 pub fn metrics_reset() {
     let c = 0 as f64;
 
@@ -132,4 +165,13 @@ pub fn metrics_reset() {
     gauge!(key!("memory_bytes_write_milliseconds"), c);
     gauge!(key!("memory_bytes_removes"), c);
     gauge!(key!("memory_bytes_remove_milliseconds"), c);
+
+    // File metrics.
+    gauge!(key!("file_hits"), c);
+    gauge!(key!("file_misses"), c);
+    gauge!(key!("file_read_milliseconds"), c);
+    gauge!(key!("file_writes"), c);
+    gauge!(key!("file_write_milliseconds"), c);
+    gauge!(key!("file_evictions"), c);
+    gauge!(key!("file_invalid_items"), c);
 }
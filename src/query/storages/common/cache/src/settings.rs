@@ -17,6 +17,10 @@ pub struct CacheSettings {
     pub memory_items_cache_capacity: u64,
     pub memory_bytes_cache_capacity: u64,
 
+    // the root directory and the max bytes of the local disk cache.
+    pub file_cache_root: String,
+    pub file_cache_capacity: u64,
+
     // write cache if true.
     pub cache_on_write: bool,
 }
@@ -26,6 +30,8 @@ impl Default for CacheSettings {
         CacheSettings {
             memory_items_cache_capacity: 10000 * 100,
             memory_bytes_cache_capacity: 1024 * 1024 * 1024,
+            file_cache_root: "_cache".to_string(),
+            file_cache_capacity: 1024 * 1024 * 1024,
             cache_on_write: false,
         }
     }
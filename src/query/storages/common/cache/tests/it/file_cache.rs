@@ -0,0 +1,118 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_base::base::uuid;
+use common_exception::Result;
+use opendal::services::fs;
+use opendal::services::fs::Builder;
+use opendal::Operator;
+use storages_common_cache::CacheSettings;
+use storages_common_cache::CachedObjectAccessor;
+use storages_common_cache::FileCache;
+
+fn file_cache_settings(capacity: u64) -> CacheSettings {
+    CacheSettings {
+        file_cache_root: format!("/tmp/{}", uuid::Uuid::new_v4()),
+        file_cache_capacity: capacity,
+        ..Default::default()
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_file_cache() -> Result<()> {
+    let mut builder: Builder = fs::Builder::default();
+    builder.root("/tmp");
+    let op: Operator = Operator::new(builder.build()?);
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let object = op.object(&path);
+
+    // Cache.
+    let settings = file_cache_settings(1024 * 1024);
+    let cache = Arc::new(FileCache::try_create(&settings)?);
+
+    let expect: Arc<Vec<u8>> = Arc::new("hello, file cache".into());
+
+    // Cached Object Accessor.
+    let accessor = CachedObjectAccessor::create(cache.clone());
+    accessor.write(&object, expect.clone()).await?;
+
+    // Miss, then hit after the object removed.
+    let actual: Arc<Vec<u8>> = accessor.read(&object, 0, expect.len() as u64).await?;
+    assert_eq!(actual, expect);
+
+    accessor.remove(&object).await?;
+    let actual: Arc<Vec<u8>> = accessor.read(&object, 0, expect.len() as u64).await?;
+    assert_eq!(actual, expect);
+
+    std::fs::remove_dir_all(&settings.file_cache_root)?;
+    Ok(())
+}
+
+#[test]
+fn test_file_cache_evict_and_recover() -> Result<()> {
+    let settings = file_cache_settings(256);
+
+    {
+        let cache = FileCache::try_create(&settings)?;
+        cache.wait_recovered()?;
+        cache.put("a".to_string(), &[1; 100]);
+        cache.put("b".to_string(), &[2; 100]);
+        assert_eq!(cache.get("a"), Some(vec![1; 100]));
+
+        // "b" is the least recently used.
+        cache.put("c".to_string(), &[3; 100]);
+        assert_eq!(cache.get("b"), None);
+    }
+
+    // Warm up from the cache directory.
+    let cache = FileCache::try_create(&settings)?;
+    cache.wait_recovered()?;
+    assert_eq!(cache.get("a"), Some(vec![1; 100]));
+    assert_eq!(cache.get("b"), None);
+    assert_eq!(cache.get("c"), Some(vec![3; 100]));
+
+    std::fs::remove_dir_all(&settings.file_cache_root)?;
+    Ok(())
+}
+
+#[test]
+fn test_file_cache_checksum() -> Result<()> {
+    let settings = file_cache_settings(1024);
+    let cache = FileCache::try_create(&settings)?;
+    cache.put("a".to_string(), &[1; 100]);
+
+    // Corrupt the data of the item.
+    for dir in std::fs::read_dir(&settings.file_cache_root)? {
+        let dir = dir?;
+        if dir.file_name() == "tmp" {
+            continue;
+        }
+        for entry in std::fs::read_dir(dir.path())? {
+            let path = entry?.path();
+            let mut bytes = std::fs::read(&path)?;
+            let last = bytes.len() - 1;
+            bytes[last] = 0;
+            std::fs::write(&path, bytes)?;
+        }
+    }
+
+    assert_eq!(cache.get("a"), None);
+
+    std::fs::remove_dir_all(&settings.file_cache_root)?;
+    Ok(())
+}
@@ -13,5 +13,6 @@
 // limitations under the License.
 
 mod by_pass_cache;
+mod file_cache;
 mod memory_bytes_cache;
 mod memory_items_cache;
@@ -0,0 +1,441 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableSchemaRef;
+use common_functions::scalars::query_terms;
+use common_functions::scalars::tokenize;
+
+/// The postings of a term: the rows which contain the term, and how many times.
+pub type Postings = Vec<(u32, u32)>;
+
+const MAGIC: &[u8; 4] = b"INVX";
+
+/// Per-block inverted index of the string columns.
+///
+/// It's stored next to the bloom filter index of the block, and the location is
+/// recorded in `BlockMeta::inverted_index_location`. The file is read with
+/// [`InvertedIndexReader`] without decoding it as a whole, the layout is:
+///
+/// ```text
+/// file:      magic | num_rows | num_columns | directory | column sections
+/// directory: (name length | name | offset of the column section) of each column
+/// section:   num_terms | term offsets | postings offsets | doc frequencies | terms | postings
+/// ```
+///
+/// The integers are little endian u32. The terms are sorted, so that a term is found
+/// by binary search, and the postings of a term are varint encoded (row delta, frequency).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InvertedIndex {
+    pub num_rows: u32,
+    /// column name -> term -> postings.
+    pub columns: BTreeMap<String, BTreeMap<String, Postings>>,
+}
+
+impl InvertedIndex {
+    pub const VERSION: u64 = 1;
+
+    /// Build the index of the given columns of the block.
+    ///
+    /// Returns `None` if none of the columns are indexed strings of the block.
+    pub fn try_create(
+        schema: &TableSchemaRef,
+        index_columns: &[String],
+        block: &DataBlock,
+    ) -> Result<Option<Self>> {
+        let num_rows = block.num_rows();
+        let mut columns = BTreeMap::new();
+        for column_name in index_columns {
+            let Ok(offset) = schema.index_of(column_name) else {
+                continue;
+            };
+            let entry = block.get_by_offset(offset);
+            if entry.data_type.remove_nullable() != DataType::String {
+                continue;
+            }
+
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            let mut terms: BTreeMap<String, Postings> = BTreeMap::new();
+            for row in 0..num_rows {
+                let Some(ScalarRef::String(text)) = column.index(row) else {
+                    continue;
+                };
+                let mut frequencies: BTreeMap<String, u32> = BTreeMap::new();
+                for term in tokenize(&String::from_utf8_lossy(text)) {
+                    *frequencies.entry(term).or_default() += 1;
+                }
+                for (term, frequency) in frequencies {
+                    terms.entry(term).or_default().push((row as u32, frequency));
+                }
+            }
+            columns.insert(column_name.clone(), terms);
+        }
+
+        if columns.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            num_rows: num_rows as u32,
+            columns,
+        }))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let sections = self
+            .columns
+            .values()
+            .map(Self::column_section)
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut data = MAGIC.to_vec();
+        put_u32(&mut data, self.num_rows);
+        put_u32(&mut data, to_u32(self.columns.len())?);
+        let directory_len = self
+            .columns
+            .keys()
+            .map(|name| 8 + name.len())
+            .sum::<usize>();
+        let mut offset = data.len() + directory_len;
+        for (name, section) in self.columns.keys().zip(&sections) {
+            put_u32(&mut data, to_u32(name.len())?);
+            data.extend_from_slice(name.as_bytes());
+            put_u32(&mut data, to_u32(offset)?);
+            offset += section.len();
+        }
+        for section in sections {
+            data.extend_from_slice(&section);
+        }
+        Ok(data)
+    }
+
+    fn column_section(terms: &BTreeMap<String, Postings>) -> Result<Vec<u8>> {
+        let mut term_offsets = vec![0];
+        let mut postings_offsets = vec![0];
+        let mut doc_frequencies = Vec::with_capacity(terms.len());
+        let mut term_data = vec![];
+        let mut postings_data = vec![];
+        for (term, postings) in terms {
+            term_data.extend_from_slice(term.as_bytes());
+            let mut last_row = 0;
+            for (row, frequency) in postings {
+                put_varint(&mut postings_data, row - last_row);
+                put_varint(&mut postings_data, *frequency);
+                last_row = *row;
+            }
+            term_offsets.push(to_u32(term_data.len())?);
+            postings_offsets.push(to_u32(postings_data.len())?);
+            doc_frequencies.push(to_u32(postings.len())?);
+        }
+
+        let mut data = vec![];
+        put_u32(&mut data, to_u32(terms.len())?);
+        for value in term_offsets
+            .into_iter()
+            .chain(postings_offsets)
+            .chain(doc_frequencies)
+        {
+            put_u32(&mut data, value);
+        }
+        data.extend_from_slice(&term_data);
+        data.extend_from_slice(&postings_data);
+        Ok(data)
+    }
+
+    /// Find the `match(<column>, '<query>')` predicates which must be true for the
+    /// filter to be true, i.e. they are not under `or` or `not`.
+    pub fn find_match_predicates(expr: &Expr<String>) -> Vec<(String, String)> {
+        let mut predicates = vec![];
+        visit_match_predicates(expr, &mut predicates);
+        predicates
+    }
+}
+
+/// Reads the terms and postings of an inverted index file in place.
+pub struct InvertedIndexReader<'a> {
+    data: &'a [u8],
+    num_rows: u32,
+    /// (column name, offset of the column section).
+    columns: Vec<(&'a [u8], usize)>,
+}
+
+impl<'a> InvertedIndexReader<'a> {
+    pub fn try_create(data: &'a [u8]) -> Result<Self> {
+        if read_bytes(data, 0, MAGIC.len())? != MAGIC {
+            return Err(corrupted());
+        }
+        let num_rows = read_u32(data, 4)?;
+        let num_columns = read_u32(data, 8)?;
+        let mut columns = vec![];
+        let mut pos = 12;
+        for _ in 0..num_columns {
+            let name_len = read_u32(data, pos)? as usize;
+            let name = read_bytes(data, pos + 4, name_len)?;
+            let offset = read_u32(data, pos + 4 + name_len)? as usize;
+            columns.push((name, offset));
+            pos += 8 + name_len;
+        }
+        Ok(Self {
+            data,
+            num_rows,
+            columns,
+        })
+    }
+
+    pub fn num_rows(&self) -> u32 {
+        self.num_rows
+    }
+
+    pub fn has_column(&self, column_name: &str) -> bool {
+        self.columns
+            .iter()
+            .any(|(name, _)| *name == column_name.as_bytes())
+    }
+
+    /// The postings of the term, empty if no row contains it.
+    ///
+    /// Returns `None` if the column is not indexed.
+    pub fn postings(&self, column_name: &str, term: &str) -> Result<Option<Postings>> {
+        let Some(section) = self.column_section(column_name)? else {
+            return Ok(None);
+        };
+        match section.find(term)? {
+            Some(idx) => Ok(Some(section.postings(idx)?)),
+            None => Ok(Some(vec![])),
+        }
+    }
+
+    /// The number of rows which contain the term.
+    ///
+    /// Returns `None` if the column is not indexed.
+    pub fn doc_frequency(&self, column_name: &str, term: &str) -> Result<Option<u32>> {
+        let Some(section) = self.column_section(column_name)? else {
+            return Ok(None);
+        };
+        match section.find(term)? {
+            Some(idx) => Ok(Some(section.doc_frequency(idx)?)),
+            None => Ok(Some(0)),
+        }
+    }
+
+    /// The rows of the column which contain all the terms of the query.
+    ///
+    /// Returns `None` if the column is not indexed.
+    pub fn matched_rows(&self, column_name: &str, query: &str) -> Result<Option<Vec<u32>>> {
+        let Some(section) = self.column_section(column_name)? else {
+            return Ok(None);
+        };
+        let mut terms = Vec::new();
+        for term in query_terms(query) {
+            match section.find(&term)? {
+                Some(idx) => terms.push((section.doc_frequency(idx)?, idx)),
+                None => return Ok(Some(vec![])),
+            }
+        }
+        // Start from the rarest term, so that the intersections are small.
+        terms.sort();
+
+        let mut rows: Option<Vec<u32>> = None;
+        for (_, idx) in terms {
+            let postings = section.postings(idx)?.into_iter().map(|(row, _)| row);
+            rows = Some(match rows {
+                None => postings.collect(),
+                Some(rows) => {
+                    let postings = postings.collect::<Vec<_>>();
+                    rows.into_iter()
+                        .filter(|row| postings.binary_search(row).is_ok())
+                        .collect()
+                }
+            });
+        }
+        // An empty query matches nothing.
+        Ok(Some(rows.unwrap_or_default()))
+    }
+
+    /// Returns false only if the index proves no row of the block matches the query.
+    pub fn may_match(&self, column_name: &str, query: &str) -> Result<bool> {
+        match self.matched_rows(column_name, query)? {
+            Some(rows) => Ok(!rows.is_empty()),
+            None => Ok(true),
+        }
+    }
+
+    fn column_section(&self, column_name: &str) -> Result<Option<ColumnSection<'a>>> {
+        match self
+            .columns
+            .iter()
+            .find(|(name, _)| *name == column_name.as_bytes())
+        {
+            Some((_, offset)) => Ok(Some(ColumnSection::try_create(self.data, *offset)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+struct ColumnSection<'a> {
+    num_terms: usize,
+    term_offsets: &'a [u8],
+    postings_offsets: &'a [u8],
+    doc_frequencies: &'a [u8],
+    terms: &'a [u8],
+    postings: &'a [u8],
+}
+
+impl<'a> ColumnSection<'a> {
+    fn try_create(data: &'a [u8], offset: usize) -> Result<Self> {
+        let num_terms = read_u32(data, offset)? as usize;
+        let offsets_len = (num_terms + 1) * 4;
+        let mut pos = offset + 4;
+        let term_offsets = read_bytes(data, pos, offsets_len)?;
+        pos += offsets_len;
+        let postings_offsets = read_bytes(data, pos, offsets_len)?;
+        pos += offsets_len;
+        let doc_frequencies = read_bytes(data, pos, num_terms * 4)?;
+        pos += num_terms * 4;
+        let terms_len = read_u32(term_offsets, num_terms * 4)? as usize;
+        let terms = read_bytes(data, pos, terms_len)?;
+        pos += terms_len;
+        let postings_len = read_u32(postings_offsets, num_terms * 4)? as usize;
+        let postings = read_bytes(data, pos, postings_len)?;
+        Ok(Self {
+            num_terms,
+            term_offsets,
+            postings_offsets,
+            doc_frequencies,
+            terms,
+            postings,
+        })
+    }
+
+    fn find(&self, term: &str) -> Result<Option<usize>> {
+        let (mut low, mut high) = (0, self.num_terms);
+        while low < high {
+            let mid = (low + high) / 2;
+            match self.term(mid)?.cmp(term.as_bytes()) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(Some(mid)),
+            }
+        }
+        Ok(None)
+    }
+
+    fn term(&self, idx: usize) -> Result<&'a [u8]> {
+        let start = read_u32(self.term_offsets, idx * 4)? as usize;
+        let end = read_u32(self.term_offsets, (idx + 1) * 4)? as usize;
+        self.terms.get(start..end).ok_or_else(corrupted)
+    }
+
+    fn doc_frequency(&self, idx: usize) -> Result<u32> {
+        read_u32(self.doc_frequencies, idx * 4)
+    }
+
+    fn postings(&self, idx: usize) -> Result<Postings> {
+        let start = read_u32(self.postings_offsets, idx * 4)? as usize;
+        let end = read_u32(self.postings_offsets, (idx + 1) * 4)? as usize;
+        let mut data = self.postings.get(start..end).ok_or_else(corrupted)?;
+        let doc_frequency = self.doc_frequency(idx)? as usize;
+        let mut postings = Vec::with_capacity(doc_frequency);
+        let mut row = 0;
+        for _ in 0..doc_frequency {
+            row += read_varint(&mut data)?;
+            postings.push((row, read_varint(&mut data)?));
+        }
+        Ok(postings)
+    }
+}
+
+fn corrupted() -> ErrorCode {
+    ErrorCode::StorageOther("corrupted inverted index")
+}
+
+fn to_u32(value: usize) -> Result<u32> {
+    u32::try_from(value)
+        .map_err(|_| ErrorCode::StorageOther("inverted index of the block is too large"))
+}
+
+fn put_u32(data: &mut Vec<u8>, value: u32) {
+    data.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_varint(data: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        data.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    data.push(value as u8);
+}
+
+fn read_bytes(data: &[u8], pos: usize, len: usize) -> Result<&[u8]> {
+    pos.checked_add(len)
+        .and_then(|end| data.get(pos..end))
+        .ok_or_else(corrupted)
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Result<u32> {
+    let bytes = read_bytes(data, pos, 4)?;
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_varint(data: &mut &[u8]) -> Result<u32> {
+    let mut value = 0u32;
+    let mut shift = 0;
+    loop {
+        let (byte, rest) = data.split_first().ok_or_else(corrupted)?;
+        *data = rest;
+        if shift > 28 {
+            return Err(corrupted());
+        }
+        value |= ((byte & 0x7f) as u32) << shift;
+        if (byte & 0x80) == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+fn visit_match_predicates(expr: &Expr<String>, predicates: &mut Vec<(String, String)>) {
+    if let Expr::FunctionCall { function, args, .. } = expr {
+        match function.signature.name.as_str() {
+            "and" => {
+                for arg in args {
+                    visit_match_predicates(arg, predicates);
+                }
+            }
+            "match" => {
+                if let [
+                    Expr::ColumnRef { id, .. },
+                    Expr::Constant {
+                        scalar: Scalar::String(query),
+                        ..
+                    },
+                ] = args.as_slice()
+                {
+                    predicates.push((id.clone(), String::from_utf8_lossy(query).to_string()));
+                }
+            }
+            _ => (),
+        }
+    }
+}
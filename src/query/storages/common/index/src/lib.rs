@@ -17,12 +17,15 @@
 mod bloom;
 pub mod filters;
 pub mod index_min_max;
+mod inverted;
 pub mod range_filter;
 
 pub use bloom::BlockFilter;
 pub use bloom::FilterEvalResult;
 use common_expression::types::DataType;
 pub use index_min_max::*;
+pub use inverted::InvertedIndex;
+pub use inverted::InvertedIndexReader;
 pub use range_filter::*;
 
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::number::Int32Type;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use storages_common_index::InvertedIndex;
+use storages_common_index::InvertedIndexReader;
+
+#[test]
+fn test_inverted_index() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("id", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("msg", TableDataType::String),
+    ]));
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data(vec![1, 2, 3]),
+        StringType::from_data(vec![
+            "Disk error on node-1, disk is full",
+            "connection refused",
+            "disk error",
+        ]),
+    ]);
+
+    // Non-string columns are not indexed.
+    let index_columns = vec!["id".to_string()];
+    assert!(InvertedIndex::try_create(&schema, &index_columns, &block)?.is_none());
+
+    let index_columns = vec!["id".to_string(), "msg".to_string()];
+    let index = InvertedIndex::try_create(&schema, &index_columns, &block)?.unwrap();
+    assert_eq!(index.num_rows, 3);
+    assert_eq!(index.columns.len(), 1);
+    assert_eq!(index.columns["msg"]["disk"], vec![(0, 2), (2, 1)]);
+
+    let data = index.to_bytes()?;
+    let reader = InvertedIndexReader::try_create(&data)?;
+    assert_eq!(reader.num_rows(), 3);
+    assert_eq!(reader.postings("msg", "disk")?, Some(vec![(0, 2), (2, 1)]));
+    assert_eq!(reader.postings("msg", "timeout")?, Some(vec![]));
+    assert_eq!(reader.postings("id", "1")?, None);
+    assert_eq!(reader.doc_frequency("msg", "error")?, Some(2));
+    assert_eq!(reader.doc_frequency("msg", "timeout")?, Some(0));
+
+    assert_eq!(reader.matched_rows("msg", "ERROR disk")?, Some(vec![0, 2]));
+    assert_eq!(reader.matched_rows("msg", "connection")?, Some(vec![1]));
+    assert_eq!(reader.matched_rows("msg", "disk memory")?, Some(vec![]));
+    assert_eq!(reader.matched_rows("msg", "")?, Some(vec![]));
+    assert_eq!(reader.matched_rows("id", "1")?, None);
+
+    assert!(reader.may_match("msg", "node")?);
+    assert!(!reader.may_match("msg", "timeout")?);
+    assert!(reader.may_match("unknown", "timeout")?);
+
+    // Corrupted files are reported as errors instead of panics.
+    assert!(InvertedIndexReader::try_create(&data[..3]).is_err());
+    let reader = InvertedIndexReader::try_create(&data[..data.len() - 1])?;
+    assert!(reader.postings("msg", "disk").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_find_match_predicates() {
+    let match_expr = |query: &str| {
+        check_function(
+            None,
+            "match",
+            &[],
+            &[
+                Expr::ColumnRef {
+                    span: None,
+                    id: "msg".to_string(),
+                    data_type: DataType::String,
+                },
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::String(query.as_bytes().to_vec()),
+                    data_type: DataType::String,
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap()
+    };
+
+    let and = check_function(
+        None,
+        "and",
+        &[],
+        &[match_expr("disk"), match_expr("error")],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+    assert_eq!(InvertedIndex::find_match_predicates(&and), vec![
+        ("msg".to_string(), "disk".to_string()),
+        ("msg".to_string(), "error".to_string()),
+    ]);
+
+    // Either side of `or` may be false.
+    let or = check_function(
+        None,
+        "or",
+        &[],
+        &[match_expr("disk"), match_expr("error")],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+    assert!(InvertedIndex::find_match_predicates(&or).is_empty());
+}
@@ -13,4 +13,5 @@
 // limitations under the License.
 
 mod bloom_filter;
+mod inverted;
 mod xor8;
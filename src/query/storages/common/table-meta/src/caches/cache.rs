@@ -18,6 +18,8 @@ use common_base::base::GlobalInstance;
 use common_config::QueryConfig;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
+use storages_common_cache::CacheSettings;
+use storages_common_cache::FileCache;
 
 use crate::caches::memory_cache::new_bytes_cache;
 use crate::caches::memory_cache::new_item_cache;
@@ -27,6 +29,7 @@ use crate::caches::memory_cache::FileMetaDataCache;
 use crate::caches::memory_cache::LabeledBytesCache;
 use crate::caches::memory_cache::LabeledItemCache;
 use crate::caches::SegmentInfoCache;
+use crate::caches::TableDataCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
 
@@ -46,6 +49,9 @@ pub trait Loader<T> {
 
 static DEFAULT_FILE_META_DATA_CACHE_ITEMS: u64 = 3000;
 
+/// Local disk cache of the column chunks and bloom index, shared by all the tables.
+pub type TableDataCache = Arc<FileCache>;
+
 /// Where all the caches reside
 pub struct CacheManager {
     table_snapshot_cache: Option<TableSnapshotCache>,
@@ -54,6 +60,7 @@ pub struct CacheManager {
     bloom_index_meta_cache: Option<BloomIndexMetaCache>,
     file_meta_data_cache: Option<FileMetaDataCache>,
     table_statistic_cache: Option<TableSnapshotStatisticCache>,
+    table_data_cache: Option<TableDataCache>,
 }
 
 impl CacheManager {
@@ -61,6 +68,8 @@ impl CacheManager {
     ///
     /// For convenience, ids of cluster and tenant are also kept
     pub fn init(config: &QueryConfig) -> Result<()> {
+        let table_data_cache = Self::new_file_cache(config)?;
+
         if !config.table_meta_cache_enabled {
             GlobalInstance::set(Arc::new(Self {
                 table_snapshot_cache: None,
@@ -69,6 +78,7 @@ impl CacheManager {
                 bloom_index_meta_cache: None,
                 file_meta_data_cache: None,
                 table_statistic_cache: None,
+                table_data_cache,
             }));
         } else {
             let table_snapshot_cache = Self::new_item_cache(config.table_cache_snapshot_count);
@@ -88,6 +98,7 @@ impl CacheManager {
                 bloom_index_meta_cache,
                 file_meta_data_cache,
                 table_statistic_cache,
+                table_data_cache,
             }));
         }
 
@@ -122,6 +133,10 @@ impl CacheManager {
         self.file_meta_data_cache.clone()
     }
 
+    pub fn get_table_data_cache(&self) -> Option<TableDataCache> {
+        self.table_data_cache.clone()
+    }
+
     fn new_item_cache<T>(capacity: u64) -> Option<LabeledItemCache<T>> {
         if capacity > 0 {
             Some(new_item_cache(capacity))
@@ -137,4 +152,17 @@ impl CacheManager {
             None
         }
     }
+
+    fn new_file_cache(config: &QueryConfig) -> Result<Option<TableDataCache>> {
+        if !config.table_data_cache_enabled || config.table_disk_cache_mb_size == 0 {
+            return Ok(None);
+        }
+
+        let settings = CacheSettings {
+            file_cache_root: config.table_disk_cache_root.clone(),
+            file_cache_capacity: config.table_disk_cache_mb_size * 1024 * 1024,
+            ..Default::default()
+        };
+        Ok(Some(Arc::new(FileCache::try_create(&settings)?)))
+    }
 }
@@ -20,6 +20,7 @@ mod memory_cache_reader;
 pub use cache::CacheManager;
 pub use cache::LoadParams;
 pub use cache::Loader;
+pub use cache::TableDataCache;
 pub use cache_metrics::metrics_reset;
 pub use memory_cache::ItemCache;
 pub use memory_cache::LabeledItemCache;
@@ -58,6 +58,11 @@ pub struct BlockMeta {
 
     #[serde(default)]
     pub bloom_filter_index_size: u64,
+    /// location of inverted index, the blocks written before the index is created don't have it
+    #[serde(default)]
+    pub inverted_index_location: Option<Location>,
+    #[serde(default)]
+    pub inverted_index_size: u64,
     pub compression: Compression,
 }
 
@@ -73,6 +78,8 @@ impl BlockMeta {
         location: Location,
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
+        inverted_index_location: Option<Location>,
+        inverted_index_size: u64,
         compression: Compression,
     ) -> Self {
         Self {
@@ -85,6 +92,8 @@ impl BlockMeta {
             location,
             bloom_filter_index_location,
             bloom_filter_index_size,
+            inverted_index_location,
+            inverted_index_size,
             compression,
         }
     }
@@ -188,6 +197,8 @@ impl BlockMeta {
             location: (s.location.path.clone(), 0),
             bloom_filter_index_location: None,
             bloom_filter_index_size: 0,
+            inverted_index_location: None,
+            inverted_index_size: 0,
             compression: Compression::Lz4,
         }
     }
@@ -218,6 +229,8 @@ impl BlockMeta {
             location: s.location.clone(),
             bloom_filter_index_location: s.bloom_filter_index_location.clone(),
             bloom_filter_index_size: s.bloom_filter_index_size,
+            inverted_index_location: None,
+            inverted_index_size: 0,
             compression: s.compression,
         }
     }
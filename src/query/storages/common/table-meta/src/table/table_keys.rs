@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
 use once_cell::sync::Lazy;
@@ -20,6 +21,10 @@ pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 pub const OPT_KEY_STORAGE_FORMAT: &str = "storage_format";
 pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
+/// The storage prefixes of the tables whose data is shared by a cloned table, separated by comma
+pub const OPT_KEY_CLONED_FROM: &str = "cloned_from";
+/// The inverted indexes of the table, `<index>:<column>` pairs separated by comma
+pub const OPT_KEY_INVERTED_INDEXES: &str = "inverted_indexes";
 
 /// Legacy table snapshot location key
 ///
@@ -38,6 +43,8 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_INVERTED_INDEXES);
     r
 });
 
@@ -46,6 +53,8 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_INVERTED_INDEXES);
     r
 });
 
@@ -56,3 +65,20 @@ pub fn is_reserved_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
 pub fn is_internal_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
     INTERNAL_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
 }
+
+/// Parse the value of `OPT_KEY_INVERTED_INDEXES` into index name -> column name.
+pub fn parse_inverted_indexes(value: &str) -> BTreeMap<String, String> {
+    value
+        .split(',')
+        .filter_map(|pair| pair.split_once(':'))
+        .map(|(index, column)| (index.to_string(), column.to_string()))
+        .collect()
+}
+
+pub fn format_inverted_indexes(indexes: &BTreeMap<String, String>) -> String {
+    indexes
+        .iter()
+        .map(|(index, column)| format!("{}:{}", index, column))
+        .collect::<Vec<_>>()
+        .join(",")
+}
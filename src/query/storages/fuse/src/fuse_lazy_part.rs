@@ -13,11 +13,9 @@
 //  limitations under the License.
 
 use std::any::Any;
-use std::collections::hash_map::DefaultHasher;
-use std::hash::Hash;
-use std::hash::Hasher;
 use std::sync::Arc;
 
+use common_catalog::plan::stable_hash;
 use common_catalog::plan::PartInfo;
 use common_catalog::plan::PartInfoPtr;
 use storages_common_table_meta::meta::Location;
@@ -41,9 +39,7 @@ impl PartInfo for FuseLazyPartInfo {
     }
 
     fn hash(&self) -> u64 {
-        let mut s = DefaultHasher::new();
-        self.segment_location.0.hash(&mut s);
-        s.finish()
+        stable_hash(self.segment_location.0.as_bytes())
     }
 }
 
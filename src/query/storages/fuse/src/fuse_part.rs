@@ -13,18 +13,18 @@
 // limitations under the License.
 
 use std::any::Any;
-use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
-use std::hash::Hash;
-use std::hash::Hasher;
 use std::sync::Arc;
 
+use common_catalog::plan::stable_hash;
 use common_catalog::plan::PartInfo;
 use common_catalog::plan::PartInfoPtr;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::StatisticsOfColumns;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct FusePartInfo {
@@ -35,6 +35,17 @@ pub struct FusePartInfo {
     pub nums_rows: usize,
     pub columns_meta: HashMap<usize, ColumnMeta>,
     pub compression: Compression,
+    /// Only kept for the runtime filters to prune the partition before reading.
+    pub pruning_info: Option<FusePartPruningInfo>,
+    /// The rows which don't match the `match` predicates are skipped with the postings of it.
+    pub inverted_index_location: Option<Location>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct FusePartPruningInfo {
+    pub col_stats: StatisticsOfColumns,
+    pub bloom_filter_index_location: Option<Location>,
+    pub bloom_filter_index_size: u64,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -51,9 +62,7 @@ impl PartInfo for FusePartInfo {
     }
 
     fn hash(&self) -> u64 {
-        let mut s = DefaultHasher::new();
-        self.location.hash(&mut s);
-        s.finish()
+        stable_hash(self.location.as_bytes())
     }
 }
 
@@ -64,6 +73,8 @@ impl FusePartInfo {
         rows_count: u64,
         columns_meta: HashMap<usize, ColumnMeta>,
         compression: Compression,
+        pruning_info: Option<FusePartPruningInfo>,
+        inverted_index_location: Option<Location>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -71,6 +82,8 @@ impl FusePartInfo {
             columns_meta,
             nums_rows: rows_count as usize,
             compression,
+            pruning_info,
+            inverted_index_location,
         }))
     }
 
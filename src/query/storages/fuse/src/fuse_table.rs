@@ -30,6 +30,7 @@ use common_catalog::table::ColumnStatistics;
 use common_catalog::table::ColumnStatisticsProvider;
 use common_catalog::table::CompactTarget;
 use common_catalog::table::NavigationDescriptor;
+use common_catalog::table::TermStatistics;
 use common_catalog::table_context::TableContext;
 use common_catalog::table_mutator::TableMutator;
 use common_exception::ErrorCode;
@@ -57,9 +58,11 @@ use storages_common_table_meta::meta::Statistics as FuseStatistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::parse_inverted_indexes;
 use storages_common_table_meta::table::table_storage_prefix;
 use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEXES;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
@@ -187,9 +190,27 @@ impl FuseTable {
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            inverted_index_columns: self.inverted_index_columns(),
         }
     }
 
+    /// The columns which have inverted indexes, the index of a block is built when it's written.
+    pub fn inverted_index_columns(&self) -> Vec<String> {
+        let mut columns = self
+            .table_info
+            .options()
+            .get(OPT_KEY_INVERTED_INDEXES)
+            .map(|value| {
+                parse_inverted_indexes(value)
+                    .into_values()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        columns.sort();
+        columns.dedup();
+        columns
+    }
+
     pub fn parse_storage_prefix(table_info: &TableInfo) -> Result<String> {
         let table_id = table_info.ident.table_id;
         let db_id = table_info
@@ -514,6 +535,15 @@ impl Table for FuseTable {
         }))
     }
 
+    async fn term_statistics(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        column_name: &str,
+        terms: &[String],
+    ) -> Result<Option<TermStatistics>> {
+        self.do_term_statistics(&ctx, column_name, terms).await
+    }
+
     async fn column_statistics_provider(&self) -> Result<Box<dyn ColumnStatisticsProvider>> {
         let provider = if let Some(snapshot) = self.read_table_snapshot().await? {
             let stats = &snapshot.summary.col_stats;
@@ -608,6 +638,21 @@ impl Table for FuseTable {
     ) -> Result<()> {
         self.do_revert_to(ctx.as_ref(), point).await
     }
+
+    async fn clone_data_from(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: Arc<dyn Table>,
+    ) -> Result<()> {
+        let source = FuseTable::try_from_table(source.as_ref()).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "can not clone table {} of engine {}, only FUSE table is supported",
+                source.name(),
+                source.engine()
+            ))
+        })?;
+        self.do_clone_data_from(ctx, source).await
+    }
 }
 
 #[derive(Clone, Copy)]
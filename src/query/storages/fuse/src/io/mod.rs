@@ -22,6 +22,8 @@ mod write;
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
 pub use read::load_bloom_filter_by_columns;
+pub use read::load_inverted_index;
+pub use read::sync_load_inverted_index;
 pub use read::BlockFilterReader;
 pub use read::BlockReader;
 pub use read::MergeIOReadResult;
@@ -34,6 +34,8 @@ use common_storage::ColumnLeaves;
 use futures::future::try_join_all;
 use opendal::Object;
 use opendal::Operator;
+use storages_common_table_meta::caches::CacheManager;
+use storages_common_table_meta::caches::TableDataCache;
 use storages_common_table_meta::meta::ColumnMeta;
 
 use crate::fuse_part::FusePartInfo;
@@ -60,6 +62,12 @@ impl OwnerMemory {
         OwnerMemory { chunks }
     }
 
+    pub fn add_chunk(&mut self, chunk: Vec<u8>) -> usize {
+        let index = self.chunks.len();
+        self.chunks.insert(index, chunk);
+        index
+    }
+
     pub fn get_chunk(&self, index: usize, path: &str) -> Result<&[u8]> {
         match self.chunks.get(&index) {
             Some(chunk) => Ok(chunk.as_slice()),
@@ -108,6 +116,31 @@ where Self: 'static
     pub fn add_column_chunk(&mut self, chunk: usize, column: usize, range: Range<usize>) {
         self.columns_chunks.insert(column, (chunk, range));
     }
+
+    /// Add the column chunk which is read from the table data cache.
+    pub fn add_cached_column_chunk(&mut self, column: usize, chunk: Vec<u8>) {
+        let len = chunk.len();
+        let index = self.owner_memory.add_chunk(chunk);
+        self.columns_chunks.insert(column, (index, 0..len));
+    }
+
+    /// Put the column chunks read from the storage into the table data cache.
+    fn cache_column_chunks(
+        &self,
+        cache: &TableDataCache,
+        location: &str,
+        columns: &[(usize, Range<u64>)],
+    ) -> Result<()> {
+        for (column, _) in columns {
+            let (chunk, range) = &self.columns_chunks[column];
+            let chunk = self.owner_memory.get_chunk(*chunk, &self.path)?;
+            cache.put_in_background(
+                BlockReader::column_cache_key(location, *column),
+                chunk[range.clone()].to_vec(),
+            );
+        }
+        Ok(())
+    }
 }
 
 impl BlockReader {
@@ -280,8 +313,17 @@ impl BlockReader {
         let columns = self.projection.project_column_leaves(&self.column_leaves)?;
         let indices = Self::build_projection_indices(&columns);
 
+        let data_cache = CacheManager::instance().get_table_data_cache();
+        let mut cached_chunks = vec![];
         let mut ranges = vec![];
         for index in indices.keys() {
+            if let Some(chunk) =
+                Self::async_read_column_from_cache(&data_cache, location, *index).await
+            {
+                cached_chunks.push((*index, chunk));
+                continue;
+            }
+
             let column_meta = &columns_meta[index];
             let (offset, len) = column_meta.offset_length();
             ranges.push((*index, offset..(offset + len)));
@@ -295,7 +337,9 @@ impl BlockReader {
 
         let object = self.operator.object(location);
 
-        Self::merge_io_read(settings, object, ranges).await
+        let mut read_res = Self::merge_io_read(settings, object, ranges.clone()).await?;
+        Self::merge_cached_chunks(&mut read_res, &data_cache, location, &ranges, cached_chunks)?;
+        Ok(read_res)
     }
 
     pub fn sync_read_columns_data_by_merge_io(
@@ -307,15 +351,77 @@ impl BlockReader {
         let columns = self.projection.project_column_leaves(&self.column_leaves)?;
         let indices = Self::build_projection_indices(&columns);
 
+        let data_cache = CacheManager::instance().get_table_data_cache();
+        let mut cached_chunks = vec![];
         let mut ranges = vec![];
         for index in indices.keys() {
+            if let Some(chunk) = Self::read_column_from_cache(&data_cache, &part.location, *index) {
+                cached_chunks.push((*index, chunk));
+                continue;
+            }
+
             let column_meta = &part.columns_meta[index];
             let (offset, len) = column_meta.offset_length();
             ranges.push((*index, offset..(offset + len)));
         }
 
         let object = self.operator.object(&part.location);
-        Self::sync_merge_io_read(settings, object, ranges)
+        let mut read_res = Self::sync_merge_io_read(settings, object, ranges.clone())?;
+        Self::merge_cached_chunks(
+            &mut read_res,
+            &data_cache,
+            &part.location,
+            &ranges,
+            cached_chunks,
+        )?;
+        Ok(read_res)
+    }
+
+    /// The key of a column chunk in the table data cache, the block files are never rewritten.
+    pub(crate) fn column_cache_key(location: &str, column: usize) -> String {
+        format!("{location}-{column}")
+    }
+
+    pub(crate) fn read_column_from_cache(
+        cache: &Option<TableDataCache>,
+        location: &str,
+        column: usize,
+    ) -> Option<Vec<u8>> {
+        cache
+            .as_ref()
+            .and_then(|cache| cache.get(&Self::column_cache_key(location, column)))
+    }
+
+    pub(crate) async fn async_read_column_from_cache(
+        cache: &Option<TableDataCache>,
+        location: &str,
+        column: usize,
+    ) -> Option<Vec<u8>> {
+        match cache {
+            None => None,
+            Some(cache) => {
+                cache
+                    .get_async(Self::column_cache_key(location, column))
+                    .await
+            }
+        }
+    }
+
+    fn merge_cached_chunks(
+        read_res: &mut MergeIOReadResult,
+        cache: &Option<TableDataCache>,
+        location: &str,
+        ranges: &[(usize, Range<u64>)],
+        cached_chunks: Vec<(usize, Vec<u8>)>,
+    ) -> Result<()> {
+        if let Some(cache) = cache {
+            read_res.cache_column_chunks(cache, location, ranges)?;
+        }
+
+        for (column, chunk) in cached_chunks {
+            read_res.add_cached_column_chunk(column, chunk);
+        }
+        Ok(())
     }
 
     // Build non duplicate leaf_ids to avoid repeated read column from parquet
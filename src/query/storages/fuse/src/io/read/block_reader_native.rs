@@ -24,6 +24,7 @@ use common_catalog::plan::PartInfoPtr;
 use common_exception::Result;
 use common_expression::DataBlock;
 use opendal::Object;
+use storages_common_table_meta::caches::CacheManager;
 use storages_common_table_meta::meta::ColumnMeta;
 
 use crate::fuse_part::FusePartInfo;
@@ -91,10 +92,22 @@ impl BlockReader {
 
         let (offset, length) = meta.offset_length();
         let meta = meta.as_native().unwrap();
-        let reader = { || async { o.range_read(offset..offset + length).await } }
-            .retry(ExponentialBackoff::default())
-            .when(|err| err.is_temporary())
-            .await?;
+
+        let data_cache = CacheManager::instance().get_table_data_cache();
+        let reader = match Self::async_read_column_from_cache(&data_cache, o.path(), index).await {
+            Some(chunk) => chunk,
+            None => {
+                let chunk = { || async { o.range_read(offset..offset + length).await } }
+                    .retry(ExponentialBackoff::default())
+                    .when(|err| err.is_temporary())
+                    .await?;
+
+                if let Some(cache) = &data_cache {
+                    cache.put_in_background(Self::column_cache_key(o.path(), index), chunk.clone());
+                }
+                chunk
+            }
+        };
 
         let reader: Reader = Box::new(std::io::Cursor::new(reader));
         let fuse_reader = NativeReader::new(reader, data_type, meta.pages.clone(), vec![]);
@@ -137,8 +150,23 @@ impl BlockReader {
         data_type: common_arrow::arrow::datatypes::DataType,
     ) -> Result<(usize, NativeReader<Reader>)> {
         let (offset, length) = meta.offset_length();
-        let reader = o.blocking_range_reader(offset..offset + length)?;
-        let reader: Reader = Box::new(BufReader::new(reader));
+        let reader: Reader = match CacheManager::instance().get_table_data_cache() {
+            None => Box::new(BufReader::new(
+                o.blocking_range_reader(offset..offset + length)?,
+            )),
+            Some(cache) => {
+                let key = Self::column_cache_key(o.path(), index);
+                let chunk = match cache.get(&key) {
+                    Some(chunk) => chunk,
+                    None => {
+                        let chunk = o.blocking_range_read(offset..offset + length)?;
+                        cache.put_in_background(key, chunk.clone());
+                        chunk
+                    }
+                };
+                Box::new(std::io::Cursor::new(chunk))
+            }
+        };
 
         let page_metas = meta.as_native().unwrap().pages.clone();
         let fuse_reader = NativeReader::new(reader, data_type, page_metas, vec![]);
@@ -254,6 +254,7 @@ mod util_v1 {
                         col_meta.clone(),
                         dal.clone(),
                         path.to_owned(),
+                        cache_key.clone(),
                     )
                     .execute_in_runtime(&storage_runtime)
                     .await??,
@@ -268,6 +269,7 @@ mod util_v1 {
                         col_meta.clone(),
                         dal.clone(),
                         path.to_owned(),
+                        cache_key,
                     )
                     .execute_in_runtime(&storage_runtime)
                     .await??,
@@ -306,12 +308,21 @@ mod util_v1 {
         .await?
     }
 
+    /// Loads index column data from the table data cache on local disk, or from the storage
     #[tracing::instrument(level = "debug", skip_all)]
     async fn load_index_column_data_from_storage(
         col_meta: ColumnChunkMetaData,
         dal: Operator,
         path: String,
+        cache_key: String,
     ) -> Result<Vec<u8>> {
+        let data_cache = CacheManager::instance().get_table_data_cache();
+        if let Some(cache) = &data_cache {
+            if let Some(bytes) = cache.get_async(cache_key.clone()).await {
+                return Ok(bytes);
+            }
+        }
+
         let chunk_meta = col_meta.metadata();
         let chunk_offset = chunk_meta.data_page_offset as u64;
         let col_len = chunk_meta.total_compressed_size as u64;
@@ -319,6 +330,10 @@ mod util_v1 {
         let bytes = column_reader
             .range_read(chunk_offset..chunk_offset + col_len)
             .await?;
+
+        if let Some(cache) = &data_cache {
+            cache.put_in_background(cache_key, bytes.clone());
+        }
         Ok(bytes)
     }
 
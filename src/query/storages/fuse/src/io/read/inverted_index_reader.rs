@@ -0,0 +1,67 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_cache::Cache;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+use storages_common_table_meta::caches::CacheManager;
+use storages_common_table_meta::meta::Location;
+
+/// Loads the inverted index file of a block.
+///
+/// The files are kept in the cache of the bloom index data, keyed by the location,
+/// so that they are not read again by the pruning and the reading of the same blocks.
+pub async fn load_inverted_index(dal: &Operator, location: &Location) -> Result<Arc<Vec<u8>>> {
+    if let Some(bytes) = get_cached_inverted_index(location) {
+        return Ok(bytes);
+    }
+
+    let bytes = dal
+        .object(&location.0)
+        .read()
+        .await
+        .map_err(ErrorCode::from)?;
+    Ok(put_cached_inverted_index(location, bytes))
+}
+
+/// The blocking version of [`load_inverted_index`].
+pub fn sync_load_inverted_index(dal: &Operator, location: &Location) -> Result<Arc<Vec<u8>>> {
+    if let Some(bytes) = get_cached_inverted_index(location) {
+        return Ok(bytes);
+    }
+
+    let bytes = dal
+        .object(&location.0)
+        .blocking_read()
+        .map_err(ErrorCode::from)?;
+    Ok(put_cached_inverted_index(location, bytes))
+}
+
+fn get_cached_inverted_index(location: &Location) -> Option<Arc<Vec<u8>>> {
+    let cache = CacheManager::instance().get_bloom_index_cache()?;
+    let mut cache_guard = cache.write();
+    cache_guard.get(&location.0).cloned()
+}
+
+fn put_cached_inverted_index(location: &Location, bytes: Vec<u8>) -> Arc<Vec<u8>> {
+    let bytes = Arc::new(bytes);
+    if let Some(cache) = CacheManager::instance().get_bloom_index_cache() {
+        let mut cache_guard = cache.write();
+        cache_guard.put(location.0.clone(), bytes.clone());
+    }
+    bytes
+}
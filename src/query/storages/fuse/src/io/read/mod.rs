@@ -17,6 +17,7 @@ mod block_reader_native;
 mod block_reader_parquet;
 mod bloom_index_reader;
 mod decompressor;
+mod inverted_index_reader;
 mod meta_readers;
 mod read_settings;
 mod snapshot_history_reader;
@@ -27,6 +28,8 @@ pub use block_reader::MergeIOReadResult;
 pub use bloom_index_reader::load_bloom_filter_by_columns;
 pub use bloom_index_reader::BlockFilterReader;
 pub use decompressor::UncompressedBuffer;
+pub use inverted_index_reader::load_inverted_index;
+pub use inverted_index_reader::sync_load_inverted_index;
 pub use meta_readers::MetaReaders;
 pub use meta_readers::SegmentInfoReader;
 pub use meta_readers::TableSnapshotReader;
@@ -26,6 +26,9 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+
+    // the columns which have inverted indexes
+    pub inverted_index_columns: Vec<String>,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +38,7 @@ impl Default for WriteSettings {
             table_compression: TableCompression::LZ4,
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            inverted_index_columns: vec![],
         }
     }
 }
@@ -0,0 +1,186 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use futures_util::TryStreamExt;
+use opendal::ObjectMode;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_CLONED_FROM;
+use tracing::info;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::io::write_meta;
+use crate::FuseTable;
+use crate::FUSE_TBL_CLONE_REF_PREFIX;
+
+/// A cloned table shares the segments and blocks of its source table instead of copying them.
+///
+/// The shared files stay in the storage prefixes of the tables which wrote them(the owners),
+/// and every table only purges the files under its own prefix. Before committing its first
+/// snapshot, the clone puts a reference file into each owner's prefix, listing the segments
+/// it cloned, the owner will keep these segments and their blocks until the reference is
+/// released by the gc of the clone, or by `DROP TABLE ... ALL` of it. A clone dropped without
+/// `ALL` keeps its references, since it may be undropped.
+impl FuseTable {
+    pub async fn do_clone_data_from(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: &FuseTable,
+    ) -> Result<()> {
+        let snapshot = match source.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            // nothing to share
+            None => return Ok(()),
+        };
+
+        // 1. find out the owners of the data, segments may contain blocks of other tables.
+        let locations = source
+            .get_block_locations(ctx.clone(), &snapshot.segments)
+            .await?;
+        let owners = snapshot
+            .segments
+            .iter()
+            .map(|(location, _)| location)
+            .chain(locations.block_location.iter())
+            .filter_map(|location| Self::data_owner(location))
+            .filter(|owner| *owner != self.meta_location_generator.prefix())
+            .map(|owner| owner.to_string())
+            .collect::<BTreeSet<_>>();
+
+        // 2. reference the segments in the owners, before they are visible in the clone.
+        let table_id = self.table_info.ident.table_id;
+        for owner in &owners {
+            let location = Self::clone_ref_location(owner, table_id);
+            if let Err(e) = write_meta(&self.operator, &location, &snapshot.segments).await {
+                self.delete_clone_refs(owners.iter().map(|owner| owner.as_str()))
+                    .await;
+                return Err(e);
+            }
+        }
+
+        // 3. commit the first snapshot of the clone, the references are deleted if it fails.
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            snapshot.schema.clone(),
+            snapshot.summary.clone(),
+            snapshot.segments.clone(),
+            self.cluster_key_meta.clone(),
+            None,
+        );
+        let mut table_info = self.table_info.clone();
+        table_info.meta.options.insert(
+            OPT_KEY_CLONED_FROM.to_owned(),
+            owners.iter().cloned().collect::<Vec<_>>().join(","),
+        );
+        let result = FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+            None,
+            &self.operator,
+        )
+        .await;
+        if result.is_err() {
+            self.delete_clone_refs(owners.iter().map(|owner| owner.as_str()))
+                .await;
+        }
+        result
+    }
+
+    /// The segments of this table referenced by the clones of it.
+    pub(crate) async fn read_clone_refs(&self) -> Result<Vec<Location>> {
+        let prefix = format!(
+            "{}/{}/",
+            self.meta_location_generator.prefix(),
+            FUSE_TBL_CLONE_REF_PREFIX
+        );
+        let mut ds = match self.operator.object(&prefix).list().await {
+            Err(e) if e.kind() == opendal::ErrorKind::ObjectNotFound => return Ok(vec![]),
+            Err(e) => return Err(e.into()),
+            Ok(ds) => ds,
+        };
+
+        let mut segments = vec![];
+        while let Some(de) = ds.try_next().await? {
+            if de.mode().await? != ObjectMode::FILE {
+                continue;
+            }
+            let data = self.operator.object(de.path()).read().await?;
+            let refs: Vec<Location> = serde_json::from_slice(&data)?;
+            segments.extend(refs);
+        }
+        Ok(segments)
+    }
+
+    /// Release the references of this table in the owners of the data it cloned, should be
+    /// called only if none of the snapshots of this table references the data of other tables,
+    /// or the table is dropped and its data is purged.
+    ///
+    /// The option of the owners is kept, releasing again is harmless since a clone never
+    /// references the data of other tables after it is created.
+    pub async fn release_clone_refs(&self) -> Result<()> {
+        let owners = match self.table_info.options().get(OPT_KEY_CLONED_FROM) {
+            Some(owners) => owners,
+            None => return Ok(()),
+        };
+
+        let table_id = self.table_info.ident.table_id;
+        for owner in owners.split(',').filter(|owner| !owner.is_empty()) {
+            let location = Self::clone_ref_location(owner, table_id);
+            self.operator.object(&location).delete().await?;
+        }
+
+        info!(
+            "released clone references of table {} in {}",
+            self.table_info.desc, owners
+        );
+        Ok(())
+    }
+
+    /// Best effort to delete the references written by a failed clone, the ones left are
+    /// only harmful to the space of the owners.
+    async fn delete_clone_refs(&self, owners: impl Iterator<Item = &str>) {
+        let table_id = self.table_info.ident.table_id;
+        for owner in owners {
+            let location = Self::clone_ref_location(owner, table_id);
+            if let Err(e) = self.operator.object(&location).delete().await {
+                warn!("failed to delete clone reference {}: {}", location, e);
+            }
+        }
+    }
+
+    /// Whether the file is written by this table.
+    pub(crate) fn is_owned_location(&self, location: &str) -> bool {
+        Self::data_owner(location) == Some(self.meta_location_generator.prefix())
+    }
+
+    /// The storage prefix `{db_id}/{table_id}` of the table which wrote the file.
+    fn data_owner(location: &str) -> Option<&str> {
+        let mut separators = location.match_indices('/').map(|(idx, _)| idx);
+        separators.nth(1).map(|idx| &location[..idx])
+    }
+
+    fn clone_ref_location(owner: &str, table_id: u64) -> String {
+        format!("{owner}/{FUSE_TBL_CLONE_REF_PREFIX}/{table_id}.json")
+    }
+}
@@ -113,7 +113,8 @@ impl FuseTable {
             .with_max_elapsed_time(Some(max_elapsed))
             .build();
 
-        let transient = self.transient();
+        // historical data of transient table can not be purged before the transaction is committed
+        let transient = self.transient() && !ctx.txn_mgr().lock().is_active();
         loop {
             match tbl.try_commit(ctx.clone(), &operation_log, overwrite).await {
                 Ok(_) => {
@@ -355,7 +356,18 @@ impl FuseTable {
         };
 
         // 3. let's roll
-        let reply = catalog.update_table_meta(table_info, req).await;
+        //
+        // inside an explicit transaction, the new table meta is buffered by the session,
+        // and published together with the other tables of the transaction on `COMMIT`.
+        let txn_mgr = ctx.txn_mgr();
+        let in_txn = txn_mgr.lock().is_active();
+        let reply = if in_txn {
+            txn_mgr
+                .lock()
+                .update_table_meta(&table_info.meta.catalog, table_info, req)
+        } else {
+            catalog.update_table_meta(table_info, req).await.map(|_| ())
+        };
         match reply {
             Ok(_) => {
                 if let Some(snapshot_cache) = CacheManager::instance().get_table_snapshot_cache() {
@@ -374,9 +386,11 @@ impl FuseTable {
                         );
                     }
                 }
-                // try keep a hit file of last snapshot
-                Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
-                    .await;
+                // try keep a hit file of last snapshot, unless the snapshot is not visible yet
+                if !in_txn {
+                    Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
+                        .await;
+                }
                 Ok(())
             }
             Err(e) => {
@@ -98,6 +98,36 @@ impl BloomIndexState {
     }
 }
 
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        source_schema: &TableSchemaRef,
+        index_columns: &[String],
+        block: &DataBlock,
+        location: Location,
+    ) -> Result<Option<Self>> {
+        if index_columns.is_empty() {
+            return Ok(None);
+        }
+        match InvertedIndex::try_create(source_schema, index_columns, block)? {
+            Some(inverted_index) => {
+                let data = inverted_index.to_bytes()?;
+                Ok(Some(Self {
+                    size: data.len() as u64,
+                    data,
+                    location,
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
 enum State {
     None,
     NeedSerialize(DataBlock),
@@ -107,6 +137,7 @@ enum State {
         meta_data: HashMap<ColumnId, ColumnMeta>,
         block_statistics: BlockStatistics,
         bloom_index_state: Option<BloomIndexState>,
+        inverted_index_state: Option<InvertedIndexState>,
     },
     GenerateSegment,
     SerializedSegment {
@@ -225,6 +256,12 @@ impl Processor for FuseTableSink {
                     &block,
                     location,
                 )?;
+                let inverted_index_state = InvertedIndexState::try_create(
+                    &self.source_schema,
+                    &self.write_settings.inverted_index_columns,
+                    &block,
+                    self.meta_locations.block_inverted_index_location(&block_id),
+                )?;
                 let column_distinct_count = bloom_index_state
                     .as_ref()
                     .map(|i| i.column_distinct_count.clone());
@@ -246,6 +283,7 @@ impl Processor for FuseTableSink {
                     block_statistics,
                     meta_data,
                     bloom_index_state,
+                    inverted_index_state,
                 };
             }
             State::GenerateSegment => {
@@ -295,6 +333,7 @@ impl Processor for FuseTableSink {
                 meta_data,
                 block_statistics,
                 bloom_index_state,
+                inverted_index_state,
             } => {
                 let start = Instant::now();
 
@@ -334,6 +373,16 @@ impl Processor for FuseTableSink {
                     }
                 }
 
+                // write inverted index
+                if let Some(ref inverted_index_state) = inverted_index_state {
+                    io::write_data(
+                        &inverted_index_state.data,
+                        &self.data_accessor,
+                        &inverted_index_state.location.0,
+                    )
+                    .await?;
+                }
+
                 let (bloom_index_location, bloom_index_size) =
                     if let Some(bloom_index_state) = bloom_index_state {
                         (
@@ -343,6 +392,9 @@ impl Processor for FuseTableSink {
                     } else {
                         (None, 0u64)
                     };
+                let (inverted_index_location, inverted_index_size) = inverted_index_state
+                    .map(|state| (Some(state.location), state.size))
+                    .unwrap_or((None, 0));
 
                 self.accumulator.add_block(
                     size,
@@ -350,6 +402,8 @@ impl Processor for FuseTableSink {
                     block_statistics,
                     bloom_index_location,
                     bloom_index_size,
+                    inverted_index_location,
+                    inverted_index_size,
                     self.write_settings.table_compression.into(),
                 )?;
 
@@ -22,12 +22,16 @@ use crate::fuse_table::FuseStorageFormat;
 use crate::io::BlockReader;
 use crate::operations::read::build_fuse_parquet_source_pipeline;
 use crate::operations::read::fuse_source::build_fuse_native_source_pipeline;
+use crate::pruning::InvertedIndexMatcher;
+use crate::pruning::RuntimeFilterPruner;
 
 pub fn build_fuse_source_pipeline(
     ctx: Arc<dyn TableContext>,
     pipeline: &mut Pipeline,
     storage_format: FuseStorageFormat,
     block_reader: Arc<BlockReader>,
+    runtime_pruner: Option<Arc<RuntimeFilterPruner>>,
+    inverted_index_matcher: Option<Arc<InvertedIndexMatcher>>,
     max_io_requests: usize,
 ) -> Result<()> {
     let max_threads = ctx.get_settings().get_max_threads()? as usize;
@@ -37,6 +41,8 @@ pub fn build_fuse_source_pipeline(
             ctx,
             pipeline,
             block_reader,
+            runtime_pruner,
+            inverted_index_matcher,
             max_threads,
             max_io_requests,
         ),
@@ -44,6 +50,8 @@ pub fn build_fuse_source_pipeline(
             ctx,
             pipeline,
             block_reader,
+            runtime_pruner,
+            inverted_index_matcher,
             max_threads,
             max_io_requests,
         ),
@@ -29,6 +29,7 @@ use storages_common_table_meta::caches::CacheManager;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SnapshotId;
 use storages_common_table_meta::meta::TableSnapshotLite;
+use storages_common_table_meta::table::OPT_KEY_CLONED_FROM;
 use tracing::info;
 use tracing::warn;
 
@@ -39,9 +40,9 @@ use crate::io::SnapshotsIO;
 use crate::FuseTable;
 
 #[derive(Default)]
-struct LocationTuple {
-    block_location: HashSet<String>,
-    bloom_location: HashSet<String>,
+pub(crate) struct LocationTuple {
+    pub(crate) block_location: HashSet<String>,
+    pub(crate) bloom_location: HashSet<String>,
 }
 
 impl FuseTable {
@@ -83,10 +84,21 @@ impl FuseTable {
                 (SnapshotId::new_v4(), None, None)
             };
 
+        // 1.1 Data shared with the clones of this table, which must be kept.
+        let segments_referenced_by_clones =
+            HashSet::<Location>::from_iter(self.read_clone_refs().await?);
+        let locations_referenced_by_clones = if segments_referenced_by_clones.is_empty() {
+            Default::default()
+        } else {
+            let segments = Vec::from_iter(segments_referenced_by_clones.iter().cloned());
+            self.get_block_locations(ctx.clone(), &segments).await?
+        };
+
         // 2. Get all snapshot(including root snapshot).
         let mut chained_snapshots = vec![];
         let mut all_segment_locations = HashSet::new();
         let mut orphan_snapshots = vec![];
+        let mut orphan_snapshots_within_retention = 0;
 
         let mut status_snapshot_scan_count = 0;
         let mut status_snapshot_scan_cost = 0;
@@ -125,6 +137,8 @@ impl FuseTable {
                 snapshot_lites_extended.orphan_snapshot_lites,
             )?;
 
+            orphan_snapshots_within_retention = partitioned_snapshots.within_retention.len();
+
             // filter out segments that still referenced by snapshot that within retention period
             all_segment_locations = Self::filter_out_segments_within_retention(
                 partitioned_snapshots
@@ -176,6 +190,12 @@ impl FuseTable {
                 if keep_last_snapshot && segments_referenced_by_root.contains(segment) {
                     continue;
                 }
+                // Skip the segments written by other tables, or shared with the clones.
+                if !self.is_owned_location(&segment.0)
+                    || segments_referenced_by_clones.contains(segment)
+                {
+                    continue;
+                }
                 segments_to_be_purged.insert(segment.clone());
             }
         }
@@ -225,6 +245,11 @@ impl FuseTable {
                         {
                             continue;
                         }
+                        if !self.is_owned_location(loc)
+                            || locations_referenced_by_clones.block_location.contains(loc)
+                        {
+                            continue;
+                        }
                         block_locations_to_be_pruged.insert(loc.to_string());
                     }
                     status_block_to_be_purged_count += block_locations_to_be_pruged.len();
@@ -241,6 +266,11 @@ impl FuseTable {
                         {
                             continue;
                         }
+                        if !self.is_owned_location(loc)
+                            || locations_referenced_by_clones.bloom_location.contains(loc)
+                        {
+                            continue;
+                        }
                         bloom_locations_to_be_pruged.insert(loc.to_string());
                     }
                     status_bloom_to_be_purged_count += bloom_locations_to_be_pruged.len();
@@ -347,6 +377,23 @@ impl FuseTable {
             }
         }
 
+        // 7. Release the references to the data of other tables, if no snapshot left uses it.
+        if self.table_info.options().contains_key(OPT_KEY_CLONED_FROM)
+            && orphan_snapshots_within_retention == 0
+        {
+            let root_references_others = keep_last_snapshot
+                && (segments_referenced_by_root
+                    .iter()
+                    .any(|(loc, _)| !self.is_owned_location(loc))
+                    || locations_referenced_by_root
+                        .block_location
+                        .iter()
+                        .any(|loc| !self.is_owned_location(loc)));
+            if !root_references_others {
+                self.release_clone_refs().await?;
+            }
+        }
+
         Ok(())
     }
 
@@ -397,7 +444,7 @@ impl FuseTable {
         fuse_file.remove_file_in_batch(&locations).await
     }
 
-    async fn get_block_locations(
+    pub(crate) async fn get_block_locations(
         &self,
         ctx: Arc<dyn TableContext>,
         segment_locations: &[Location],
@@ -431,6 +478,10 @@ impl FuseTable {
                         .unwrap_or_default()
                         .0,
                 );
+                // the inverted indexes are purged along with the bloom filter indexes
+                if let Some(location) = &block_meta.inverted_index_location {
+                    blooms.insert(location.0.clone());
+                }
             }
         }
 
@@ -14,6 +14,7 @@
 
 mod analyze;
 mod append;
+mod clone;
 mod commit;
 mod compact;
 mod delete;
@@ -25,6 +26,7 @@ mod operation_log;
 mod read_data;
 mod read_partitions;
 mod recluster;
+mod term_statistics;
 mod truncate;
 mod update;
 
@@ -36,6 +38,7 @@ pub mod util;
 pub use compact::CompactOptions;
 pub use fuse_sink::BloomIndexState;
 pub use fuse_sink::FuseTableSink;
+pub use fuse_sink::InvertedIndexState;
 pub use mutation::ReclusterMutator;
 pub use mutation::SegmentCompactMutator;
 pub use mutation::SegmentCompactionState;
@@ -26,6 +26,8 @@ pub struct AbortOperation {
     pub segments: Vec<String>,
     pub blocks: Vec<String>,
     pub bloom_filter_indexes: Vec<String>,
+    #[serde(default)]
+    pub inverted_indexes: Vec<String>,
 }
 
 impl AbortOperation {
@@ -34,6 +36,7 @@ impl AbortOperation {
         self.blocks.extend(rhs.blocks.clone());
         self.bloom_filter_indexes
             .extend(rhs.bloom_filter_indexes.clone());
+        self.inverted_indexes.extend(rhs.inverted_indexes.clone());
     }
 
     pub fn add_block(&mut self, block: &BlockMeta) {
@@ -42,6 +45,9 @@ impl AbortOperation {
         if let Some(index) = block.bloom_filter_index_location.clone() {
             self.bloom_filter_indexes.push(index.0);
         }
+        if let Some(index) = block.inverted_index_location.clone() {
+            self.inverted_indexes.push(index.0);
+        }
     }
 
     pub fn add_segment(&mut self, segment: String) {
@@ -54,6 +60,7 @@ impl AbortOperation {
             .blocks
             .into_iter()
             .chain(self.bloom_filter_indexes.into_iter())
+            .chain(self.inverted_indexes.into_iter())
             .chain(self.segments.into_iter());
         fuse_file.remove_file_in_batch(locations).await
     }
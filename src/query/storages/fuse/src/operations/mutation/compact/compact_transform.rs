@@ -47,6 +47,7 @@ use crate::io::WriteSettings;
 use crate::metrics::*;
 use crate::operations::mutation::AbortOperation;
 use crate::operations::mutation::SerializeState;
+use crate::operations::InvertedIndexState;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
@@ -256,6 +257,17 @@ impl Processor for CompactTransform {
                         }
                         None => (None, 0u64, None),
                     };
+                    let inverted_index_state = InvertedIndexState::try_create(
+                        &self.schema,
+                        &self.write_settings.inverted_index_columns,
+                        &new_block,
+                        self.location_gen.block_inverted_index_location(&block_id),
+                    )?;
+                    let (inverted_index_data, inverted_index_size, inverted_index_location) =
+                        match inverted_index_state {
+                            Some(state) => (Some(state.data), state.size, Some(state.location)),
+                            None => (None, 0u64, None),
+                        };
 
                     // serialize data block.
                     let mut block_data = Vec::with_capacity(100 * 1024 * 1024);
@@ -277,6 +289,8 @@ impl Processor for CompactTransform {
                         block_location.clone(),
                         index_location.clone(),
                         index_size,
+                        inverted_index_location.clone(),
+                        inverted_index_size,
                         self.write_settings.table_compression.into(),
                     );
                     self.abort_operation.add_block(&new_meta);
@@ -287,6 +301,8 @@ impl Processor for CompactTransform {
                         block_location: block_location.0,
                         index_data,
                         index_location: index_location.map(|l| l.0),
+                        inverted_index_data,
+                        inverted_index_location: inverted_index_location.map(|l| l.0),
                     });
                 }
                 self.state = State::SerializedBlocks(serialize_states);
@@ -415,6 +431,11 @@ impl Processor for CompactTransform {
                         {
                             write_data(&index_data, dal, &index_location).await?;
                         }
+                        if let (Some(index_data), Some(index_location)) =
+                            (state.inverted_index_data, state.inverted_index_location)
+                        {
+                            write_data(&index_data, dal, &index_location).await?;
+                        }
                         // write block data.
                         write_data(&state.block_data, dal, &state.block_location).await
                     });
@@ -37,6 +37,7 @@ use crate::operations::mutation::MutationTransformMeta;
 use crate::operations::mutation::SerializeDataMeta;
 use crate::operations::util;
 use crate::operations::BloomIndexState;
+use crate::operations::InvertedIndexState;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::Processor;
@@ -49,6 +50,8 @@ pub struct SerializeState {
     pub block_location: String,
     pub index_data: Option<Vec<u8>>,
     pub index_location: Option<String>,
+    pub inverted_index_data: Option<Vec<u8>>,
+    pub inverted_index_location: Option<String>,
 }
 
 enum State {
@@ -73,6 +76,7 @@ pub struct SerializeDataTransform {
     index: BlockMetaIndex,
     origin_stats: Option<ClusterStatistics>,
     table_compression: TableCompression,
+    inverted_index_columns: Vec<String>,
 }
 
 impl SerializeDataTransform {
@@ -96,6 +100,7 @@ impl SerializeDataTransform {
             index: BlockMetaIndex::default(),
             origin_stats: None,
             table_compression: table.table_compression,
+            inverted_index_columns: table.inverted_index_columns(),
         })))
     }
 }
@@ -178,6 +183,12 @@ impl Processor for SerializeDataTransform {
                     &block,
                     location,
                 )?;
+                let inverted_index_state = InvertedIndexState::try_create(
+                    &self.schema,
+                    &self.inverted_index_columns,
+                    &block,
+                    self.location_gen.block_inverted_index_location(&block_id),
+                )?;
                 let column_distinct_count = bloom_index_state
                     .as_ref()
                     .map(|i| i.column_distinct_count.clone());
@@ -204,6 +215,11 @@ impl Processor for SerializeDataTransform {
                     } else {
                         (None, None, 0u64)
                     };
+                let (inverted_index_data, inverted_index_location, inverted_index_size) =
+                    match inverted_index_state {
+                        Some(state) => (Some(state.data), Some(state.location), state.size),
+                        None => (None, None, 0u64),
+                    };
 
                 // new block meta.
                 let new_meta = Arc::new(BlockMeta::new(
@@ -216,6 +232,8 @@ impl Processor for SerializeDataTransform {
                     block_location.clone(),
                     index_location.clone(),
                     index_size,
+                    inverted_index_location.clone(),
+                    inverted_index_size,
                     self.table_compression.into(),
                 ));
 
@@ -225,6 +243,8 @@ impl Processor for SerializeDataTransform {
                         block_location: block_location.0,
                         index_data,
                         index_location: index_location.map(|l| l.0),
+                        inverted_index_data,
+                        inverted_index_location: inverted_index_location.map(|l| l.0),
                     },
                     new_meta,
                 );
@@ -254,6 +274,13 @@ impl Processor for SerializeDataTransform {
                 {
                     write_data(&index_data, &self.dal, &index_location).await?;
                 }
+                // write inverted index data.
+                if let (Some(index_data), Some(index_location)) = (
+                    serialize_state.inverted_index_data,
+                    serialize_state.inverted_index_location,
+                ) {
+                    write_data(&index_data, &self.dal, &index_location).await?;
+                }
 
                 self.state = State::Output(Mutation::Replaced(block_meta));
             }
@@ -24,11 +24,15 @@ use crate::operations::read::native_data_source_deserializer::NativeDeserializeD
 use crate::operations::read::native_data_source_reader::ReadNativeDataSource;
 use crate::operations::read::parquet_data_source_deserializer::DeserializeDataTransform;
 use crate::operations::read::parquet_data_source_reader::ReadParquetDataSource;
+use crate::pruning::InvertedIndexMatcher;
+use crate::pruning::RuntimeFilterPruner;
 
 pub fn build_fuse_native_source_pipeline(
     ctx: Arc<dyn TableContext>,
     pipeline: &mut Pipeline,
     block_reader: Arc<BlockReader>,
+    runtime_pruner: Option<Arc<RuntimeFilterPruner>>,
+    inverted_index_matcher: Option<Arc<InvertedIndexMatcher>>,
     max_threads: usize,
     max_io_requests: usize,
 ) -> Result<()> {
@@ -36,7 +40,13 @@ pub fn build_fuse_native_source_pipeline(
         true => {
             pipeline.add_source(
                 |output| {
-                    ReadNativeDataSource::<true>::create(ctx.clone(), output, block_reader.clone())
+                    ReadNativeDataSource::<true>::create(
+                        ctx.clone(),
+                        output,
+                        block_reader.clone(),
+                        runtime_pruner.clone(),
+                        inverted_index_matcher.clone(),
+                    )
                 },
                 max_threads,
             )?;
@@ -45,7 +55,13 @@ pub fn build_fuse_native_source_pipeline(
             info!("read block data adjust max io requests:{}", max_io_requests);
             pipeline.add_source(
                 |output| {
-                    ReadNativeDataSource::<false>::create(ctx.clone(), output, block_reader.clone())
+                    ReadNativeDataSource::<false>::create(
+                        ctx.clone(),
+                        output,
+                        block_reader.clone(),
+                        runtime_pruner.clone(),
+                        inverted_index_matcher.clone(),
+                    )
                 },
                 max_io_requests,
             )?;
@@ -74,6 +90,8 @@ pub fn build_fuse_parquet_source_pipeline(
     ctx: Arc<dyn TableContext>,
     pipeline: &mut Pipeline,
     block_reader: Arc<BlockReader>,
+    runtime_pruner: Option<Arc<RuntimeFilterPruner>>,
+    inverted_index_matcher: Option<Arc<InvertedIndexMatcher>>,
     max_threads: usize,
     max_io_requests: usize,
 ) -> Result<()> {
@@ -81,7 +99,13 @@ pub fn build_fuse_parquet_source_pipeline(
         true => {
             pipeline.add_source(
                 |output| {
-                    ReadParquetDataSource::<true>::create(ctx.clone(), output, block_reader.clone())
+                    ReadParquetDataSource::<true>::create(
+                        ctx.clone(),
+                        output,
+                        block_reader.clone(),
+                        runtime_pruner.clone(),
+                        inverted_index_matcher.clone(),
+                    )
                 },
                 max_threads,
             )?;
@@ -94,6 +118,8 @@ pub fn build_fuse_parquet_source_pipeline(
                         ctx.clone(),
                         output,
                         block_reader.clone(),
+                        runtime_pruner.clone(),
+                        inverted_index_matcher.clone(),
                     )
                 },
                 max_io_requests,
@@ -29,11 +29,21 @@ pub type DataChunks = Vec<(usize, NativeReader<Box<dyn NativeReadBuf + Send + Sy
 pub struct NativeDataSourceMeta {
     pub part: Vec<PartInfoPtr>,
     pub chunks: Vec<DataChunks>,
+    /// The rows matched by the inverted indexes, None if all the rows are kept.
+    pub matched_rows: Vec<Option<Vec<u32>>>,
 }
 
 impl NativeDataSourceMeta {
-    pub fn create(part: Vec<PartInfoPtr>, chunks: Vec<DataChunks>) -> BlockMetaInfoPtr {
-        Box::new(NativeDataSourceMeta { part, chunks })
+    pub fn create(
+        part: Vec<PartInfoPtr>,
+        chunks: Vec<DataChunks>,
+        matched_rows: Vec<Option<Vec<u32>>>,
+    ) -> BlockMetaInfoPtr {
+        Box::new(NativeDataSourceMeta {
+            part,
+            chunks,
+            matched_rows,
+        })
     }
 }
 
@@ -40,6 +40,9 @@ pub struct NativeDeserializeDataTransform {
     output_data: Option<DataBlock>,
     parts: Vec<PartInfoPtr>,
     chunks: Vec<DataChunks>,
+    matched_rows: Vec<Option<Vec<u32>>>,
+    /// The offset of the next page in the block of the last chunks.
+    offset: usize,
 }
 
 impl NativeDeserializeDataTransform {
@@ -59,6 +62,8 @@ impl NativeDeserializeDataTransform {
                 output_data: None,
                 parts: vec![],
                 chunks: vec![],
+                matched_rows: vec![],
+                offset: 0,
             },
         )))
     }
@@ -107,6 +112,7 @@ impl Processor for NativeDeserializeDataTransform {
                 {
                     self.parts = source_meta.part.clone();
                     self.chunks = std::mem::take(&mut source_meta.chunks);
+                    self.matched_rows = std::mem::take(&mut source_meta.matched_rows);
                     return Ok(Event::Sync);
                 }
             }
@@ -131,13 +137,15 @@ impl Processor for NativeDeserializeDataTransform {
                 if !chunk.has_next() {
                     // No data anymore
                     let _ = self.chunks.pop();
+                    let _ = self.matched_rows.pop();
+                    self.offset = 0;
                     return Ok(());
                 }
 
                 arrays.push((*index, chunk.next_array()?));
             }
 
-            let data_block = self.block_reader.build_block(arrays)?;
+            let mut data_block = self.block_reader.build_block(arrays)?;
 
             let progress_values = ProgressValues {
                 rows: data_block.num_rows(),
@@ -145,7 +153,22 @@ impl Processor for NativeDeserializeDataTransform {
             };
             self.scan_progress.incr(&progress_values);
 
-            self.output_data = Some(data_block);
+            let num_rows = data_block.num_rows();
+            if let Some(Some(rows)) = self.matched_rows.last() {
+                // Only the matched rows of the page are kept.
+                let start = rows.partition_point(|row| (*row as usize) < self.offset);
+                let end = rows.partition_point(|row| (*row as usize) < self.offset + num_rows);
+                let indices = rows[start..end]
+                    .iter()
+                    .map(|row| row - self.offset as u32)
+                    .collect::<Vec<_>>();
+                data_block = data_block.take(&indices)?;
+            }
+            self.offset += num_rows;
+
+            if !data_block.is_empty() {
+                self.output_data = Some(data_block);
+            }
         }
 
         Ok(())
@@ -19,26 +19,31 @@ use common_base::base::tokio;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::BlockMetaInfoPtr;
 use common_expression::DataBlock;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::PlanProfile;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_sources::processors::sources::SyncSource;
 use common_pipeline_sources::processors::sources::SyncSourcer;
 
 use crate::io::BlockReader;
-use crate::operations::read::native_data_source::DataChunks;
 use crate::operations::read::native_data_source::NativeDataSourceMeta;
+use crate::pruning::InvertedIndexMatcher;
+use crate::pruning::RuntimeFilterPruner;
 
 pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
     finished: bool,
     batch_size: usize,
     ctx: Arc<dyn TableContext>,
     block_reader: Arc<BlockReader>,
+    runtime_pruner: Option<Arc<RuntimeFilterPruner>>,
+    inverted_index_matcher: Option<Arc<InvertedIndexMatcher>>,
 
     output: Arc<OutputPort>,
-    output_data: Option<(Vec<PartInfoPtr>, Vec<DataChunks>)>,
+    output_data: Option<BlockMetaInfoPtr>,
 }
 
 impl ReadNativeDataSource<true> {
@@ -46,6 +51,8 @@ impl ReadNativeDataSource<true> {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
+        runtime_pruner: Option<Arc<RuntimeFilterPruner>>,
+        inverted_index_matcher: Option<Arc<InvertedIndexMatcher>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         SyncSourcer::create(ctx.clone(), output.clone(), ReadNativeDataSource::<true> {
@@ -53,6 +60,8 @@ impl ReadNativeDataSource<true> {
             output,
             batch_size,
             block_reader,
+            runtime_pruner,
+            inverted_index_matcher,
             finished: false,
             output_data: None,
         })
@@ -64,6 +73,8 @@ impl ReadNativeDataSource<false> {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
+        runtime_pruner: Option<Arc<RuntimeFilterPruner>>,
+        inverted_index_matcher: Option<Arc<InvertedIndexMatcher>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         Ok(ProcessorPtr::create(Box::new(ReadNativeDataSource::<
@@ -73,23 +84,61 @@ impl ReadNativeDataSource<false> {
             output,
             batch_size,
             block_reader,
+            runtime_pruner,
+            inverted_index_matcher,
             finished: false,
             output_data: None,
         })))
     }
+
+    // The partitions stolen from other nodes are taken after the local ones are used up.
+    async fn next_parts(ctx: &Arc<dyn TableContext>, num: usize) -> Result<Vec<PartInfoPtr>> {
+        let parts = ctx.try_get_parts(num);
+        match parts.is_empty() {
+            true => ctx.try_steal_parts(num).await,
+            false => Ok(parts),
+        }
+    }
+}
+
+impl ReadNativeDataSource<true> {
+    // The blocking source only prunes partitions with min/max index of the runtime filters.
+    fn next_part(&self) -> Result<Option<PartInfoPtr>> {
+        while let Some(part) = self.ctx.try_get_part() {
+            match &self.runtime_pruner {
+                Some(runtime_pruner) if !runtime_pruner.should_keep_by_range(&part)? => {
+                    PlanProfile::record_current(|profile| profile.add_partitions(0, 1));
+                    continue;
+                }
+                _ => {
+                    PlanProfile::record_current(|profile| profile.add_partitions(1, 0));
+                    return Ok(Some(part));
+                }
+            }
+        }
+        Ok(None)
+    }
 }
 
 impl SyncSource for ReadNativeDataSource<true> {
     const NAME: &'static str = "SyncReadNativeDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.ctx.try_get_part() {
+        match self.next_part()? {
             None => Ok(None),
-            Some(part) => Ok(Some(DataBlock::empty_with_meta(
-                NativeDataSourceMeta::create(vec![part.clone()], vec![
-                    self.block_reader.sync_read_native_columns_data(part)?,
-                ]),
-            ))),
+            Some(part) => {
+                let matched_rows = self
+                    .inverted_index_matcher
+                    .as_ref()
+                    .and_then(|matcher| matcher.sync_matched_rows(&part));
+                Ok(Some(DataBlock::empty_with_meta(
+                    NativeDataSourceMeta::create(
+                        vec![part.clone()],
+                        vec![self.block_reader.sync_read_native_columns_data(part)?],
+                        vec![matched_rows],
+                    ),
+                )))
+            }
         }
     }
 }
@@ -118,8 +167,8 @@ impl Processor for ReadNativeDataSource<false> {
             return Ok(Event::NeedConsume);
         }
 
-        if let Some((part, data)) = self.output_data.take() {
-            let output = DataBlock::empty_with_meta(NativeDataSourceMeta::create(part, data));
+        if let Some(meta) = self.output_data.take() {
+            let output = DataBlock::empty_with_meta(meta);
             self.output.push_data(Ok(output));
             // return Ok(Event::NeedConsume);
         }
@@ -128,7 +177,31 @@ impl Processor for ReadNativeDataSource<false> {
     }
 
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.ctx.try_get_parts(self.batch_size);
+        let mut parts = Self::next_parts(&self.ctx, self.batch_size).await?;
+
+        if let Some(runtime_pruner) = &self.runtime_pruner {
+            // Fetch more partitions if all of them are pruned by the runtime filters.
+            while !parts.is_empty() {
+                let parts_len = parts.len();
+                let mut kept_parts = Vec::with_capacity(parts_len);
+                for part in parts {
+                    if runtime_pruner.should_keep(&part).await? {
+                        kept_parts.push(part);
+                    }
+                }
+
+                let pruned = parts_len - kept_parts.len();
+                PlanProfile::record_current(|profile| profile.add_partitions(0, pruned));
+
+                if !kept_parts.is_empty() {
+                    parts = kept_parts;
+                    break;
+                }
+                parts = Self::next_parts(&self.ctx, self.batch_size).await?;
+            }
+        }
+
+        PlanProfile::record_current(|profile| profile.add_partitions(parts.len(), 0));
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
@@ -144,7 +217,12 @@ impl Processor for ReadNativeDataSource<false> {
                 });
             }
 
-            self.output_data = Some((parts, futures::future::try_join_all(chunks).await?));
+            let data = futures::future::try_join_all(chunks).await?;
+            let matched_rows = match &self.inverted_index_matcher {
+                Some(matcher) => matcher.matched_rows_of_parts(&parts).await,
+                None => vec![None; parts.len()],
+            };
+            self.output_data = Some(NativeDataSourceMeta::create(parts, data, matched_rows));
             return Ok(());
         }
 
@@ -28,11 +28,21 @@ use crate::MergeIOReadResult;
 pub struct DataSourceMeta {
     pub part: Vec<PartInfoPtr>,
     pub data: Vec<MergeIOReadResult>,
+    /// The rows matched by the inverted indexes, None if all the rows are kept.
+    pub matched_rows: Vec<Option<Vec<u32>>>,
 }
 
 impl DataSourceMeta {
-    pub fn create(part: Vec<PartInfoPtr>, data: Vec<MergeIOReadResult>) -> BlockMetaInfoPtr {
-        Box::new(DataSourceMeta { part, data })
+    pub fn create(
+        part: Vec<PartInfoPtr>,
+        data: Vec<MergeIOReadResult>,
+        matched_rows: Vec<Option<Vec<u32>>>,
+    ) -> BlockMetaInfoPtr {
+        Box::new(DataSourceMeta {
+            part,
+            data,
+            matched_rows,
+        })
     }
 }
 
@@ -44,6 +44,7 @@ pub struct DeserializeDataTransform {
     output_data: Option<DataBlock>,
     parts: Vec<PartInfoPtr>,
     chunks: Vec<MergeIOReadResult>,
+    matched_rows: Vec<Option<Vec<u32>>>,
     uncompressed_buffer: Arc<UncompressedBuffer>,
 }
 
@@ -66,6 +67,7 @@ impl DeserializeDataTransform {
             output_data: None,
             parts: vec![],
             chunks: vec![],
+            matched_rows: vec![],
             uncompressed_buffer: UncompressedBuffer::new(buffer_size),
         })))
     }
@@ -113,6 +115,7 @@ impl Processor for DeserializeDataTransform {
                 {
                     self.parts = source_meta.part.clone();
                     self.chunks = std::mem::take(&mut source_meta.data);
+                    self.matched_rows = std::mem::take(&mut source_meta.matched_rows);
                     return Ok(Event::Sync);
                 }
             }
@@ -133,13 +136,14 @@ impl Processor for DeserializeDataTransform {
     fn process(&mut self) -> Result<()> {
         let part = self.parts.pop();
         let chunks = self.chunks.pop();
+        let matched_rows = self.matched_rows.pop().flatten();
         if let Some((part, read_res)) = part.zip(chunks) {
             let start = Instant::now();
 
             let columns_chunks = read_res.columns_chunks()?;
             let part = FusePartInfo::from_part(&part)?;
 
-            let data_block = self.block_reader.deserialize_parquet_chunks_with_buffer(
+            let mut data_block = self.block_reader.deserialize_parquet_chunks_with_buffer(
                 part.nums_rows,
                 &part.compression,
                 &part.columns_meta,
@@ -158,6 +162,11 @@ impl Processor for DeserializeDataTransform {
             };
             self.scan_progress.incr(&progress_values);
 
+            // Only the rows matched by the inverted index are kept.
+            if let Some(rows) = matched_rows {
+                data_block = data_block.take(&rows)?;
+            }
+
             self.output_data = Some(data_block);
         }
 
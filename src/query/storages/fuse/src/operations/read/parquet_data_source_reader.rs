@@ -19,10 +19,12 @@ use common_base::base::tokio;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::BlockMetaInfoPtr;
 use common_expression::DataBlock;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::PlanProfile;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_sources::processors::sources::SyncSource;
 use common_pipeline_sources::processors::sources::SyncSourcer;
@@ -31,16 +33,19 @@ use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
-use crate::MergeIOReadResult;
+use crate::pruning::InvertedIndexMatcher;
+use crate::pruning::RuntimeFilterPruner;
 
 pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
     finished: bool,
     ctx: Arc<dyn TableContext>,
     batch_size: usize,
     block_reader: Arc<BlockReader>,
+    runtime_pruner: Option<Arc<RuntimeFilterPruner>>,
+    inverted_index_matcher: Option<Arc<InvertedIndexMatcher>>,
 
     output: Arc<OutputPort>,
-    output_data: Option<(Vec<PartInfoPtr>, Vec<MergeIOReadResult>)>,
+    output_data: Option<BlockMetaInfoPtr>,
 }
 
 impl ReadParquetDataSource<true> {
@@ -48,6 +53,8 @@ impl ReadParquetDataSource<true> {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
+        runtime_pruner: Option<Arc<RuntimeFilterPruner>>,
+        inverted_index_matcher: Option<Arc<InvertedIndexMatcher>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         SyncSourcer::create(ctx.clone(), output.clone(), ReadParquetDataSource::<true> {
@@ -55,6 +62,8 @@ impl ReadParquetDataSource<true> {
             output,
             batch_size,
             block_reader,
+            runtime_pruner,
+            inverted_index_matcher,
             finished: false,
             output_data: None,
         })
@@ -66,6 +75,8 @@ impl ReadParquetDataSource<false> {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
+        runtime_pruner: Option<Arc<RuntimeFilterPruner>>,
+        inverted_index_matcher: Option<Arc<InvertedIndexMatcher>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         Ok(ProcessorPtr::create(Box::new(ReadParquetDataSource::<
@@ -75,25 +86,62 @@ impl ReadParquetDataSource<false> {
             output,
             batch_size,
             block_reader,
+            runtime_pruner,
+            inverted_index_matcher,
             finished: false,
             output_data: None,
         })))
     }
+
+    // The partitions stolen from other nodes are taken after the local ones are used up.
+    async fn next_parts(ctx: &Arc<dyn TableContext>, num: usize) -> Result<Vec<PartInfoPtr>> {
+        let parts = ctx.try_get_parts(num);
+        match parts.is_empty() {
+            true => ctx.try_steal_parts(num).await,
+            false => Ok(parts),
+        }
+    }
+}
+
+impl ReadParquetDataSource<true> {
+    // The blocking source only prunes partitions with min/max index of the runtime filters.
+    fn next_part(&self) -> Result<Option<PartInfoPtr>> {
+        while let Some(part) = self.ctx.try_get_part() {
+            match &self.runtime_pruner {
+                Some(runtime_pruner) if !runtime_pruner.should_keep_by_range(&part)? => {
+                    PlanProfile::record_current(|profile| profile.add_partitions(0, 1));
+                    continue;
+                }
+                _ => {
+                    PlanProfile::record_current(|profile| profile.add_partitions(1, 0));
+                    return Ok(Some(part));
+                }
+            }
+        }
+        Ok(None)
+    }
 }
 
 impl SyncSource for ReadParquetDataSource<true> {
     const NAME: &'static str = "SyncReadParquetDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.ctx.try_get_part() {
+        match self.next_part()? {
             None => Ok(None),
-            Some(part) => Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
-                vec![part.clone()],
-                vec![self.block_reader.sync_read_columns_data_by_merge_io(
-                    &ReadSettings::from_ctx(&self.ctx)?,
-                    part,
-                )?],
-            )))),
+            Some(part) => {
+                let matched_rows = self
+                    .inverted_index_matcher
+                    .as_ref()
+                    .and_then(|matcher| matcher.sync_matched_rows(&part));
+                Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
+                    vec![part.clone()],
+                    vec![self.block_reader.sync_read_columns_data_by_merge_io(
+                        &ReadSettings::from_ctx(&self.ctx)?,
+                        part,
+                    )?],
+                    vec![matched_rows],
+                ))))
+            }
         }
     }
 }
@@ -122,8 +170,8 @@ impl Processor for ReadParquetDataSource<false> {
             return Ok(Event::NeedConsume);
         }
 
-        if let Some((part, data)) = self.output_data.take() {
-            let output = DataBlock::empty_with_meta(DataSourceMeta::create(part, data));
+        if let Some(meta) = self.output_data.take() {
+            let output = DataBlock::empty_with_meta(meta);
 
             self.output.push_data(Ok(output));
             // return Ok(Event::NeedConsume);
@@ -133,7 +181,31 @@ impl Processor for ReadParquetDataSource<false> {
     }
 
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.ctx.try_get_parts(self.batch_size);
+        let mut parts = Self::next_parts(&self.ctx, self.batch_size).await?;
+
+        if let Some(runtime_pruner) = &self.runtime_pruner {
+            // Fetch more partitions if all of them are pruned by the runtime filters.
+            while !parts.is_empty() {
+                let parts_len = parts.len();
+                let mut kept_parts = Vec::with_capacity(parts_len);
+                for part in parts {
+                    if runtime_pruner.should_keep(&part).await? {
+                        kept_parts.push(part);
+                    }
+                }
+
+                let pruned = parts_len - kept_parts.len();
+                PlanProfile::record_current(|profile| profile.add_partitions(0, pruned));
+
+                if !kept_parts.is_empty() {
+                    parts = kept_parts;
+                    break;
+                }
+                parts = Self::next_parts(&self.ctx, self.batch_size).await?;
+            }
+        }
+
+        PlanProfile::record_current(|profile| profile.add_partitions(parts.len(), 0));
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
@@ -159,7 +231,12 @@ impl Processor for ReadParquetDataSource<false> {
                 });
             }
 
-            self.output_data = Some((parts, futures::future::try_join_all(chunks).await?));
+            let data = futures::future::try_join_all(chunks).await?;
+            let matched_rows = match &self.inverted_index_matcher {
+                Some(matcher) => matcher.matched_rows_of_parts(&parts).await,
+                None => vec![None; parts.len()],
+            };
+            self.output_data = Some(DataSourceMeta::create(parts, data, matched_rows));
             return Ok(());
         }
 
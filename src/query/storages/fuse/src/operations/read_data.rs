@@ -26,6 +26,8 @@ use common_pipeline_core::Pipeline;
 use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::io::BlockReader;
 use crate::operations::fuse_source::build_fuse_source_pipeline;
+use crate::pruning::InvertedIndexMatcher;
+use crate::pruning::RuntimeFilterPruner;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -109,12 +111,22 @@ impl FuseTable {
 
         let block_reader = self.build_block_reader(plan)?;
         let max_io_requests = self.adjust_io_request(&ctx)?;
+        let runtime_pruner = RuntimeFilterPruner::try_create(
+            ctx.clone(),
+            self.table_info.schema(),
+            self.operator.clone(),
+            &plan.push_downs,
+        );
+        let inverted_index_matcher =
+            InvertedIndexMatcher::try_create(self.operator.clone(), &plan.push_downs);
 
         build_fuse_source_pipeline(
             ctx,
             pipeline,
             self.storage_format,
             block_reader,
+            runtime_pruner,
+            inverted_index_matcher,
             max_io_requests,
         )
     }
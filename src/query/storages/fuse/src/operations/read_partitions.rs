@@ -36,6 +36,7 @@ use tracing::info;
 
 use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::fuse_part::FusePartInfo;
+use crate::fuse_part::FusePartPruningInfo;
 use crate::pruning::BlockPruner;
 use crate::FuseTable;
 
@@ -70,7 +71,7 @@ impl FuseTable {
                             snapshot.segments.len(),
                             snapshot.segments.len(),
                         ),
-                        Partitions::create(PartitionsShuffleKind::Mod, segments),
+                        Partitions::create(PartitionsShuffleKind::ConsistentHash, segments),
                     ));
                 }
 
@@ -130,7 +131,7 @@ impl FuseTable {
 
     pub fn read_partitions_with_metas(
         &self,
-        _: Arc<dyn TableContext>,
+        ctx: Arc<dyn TableContext>,
         schema: TableSchemaRef,
         push_downs: Option<PushDownInfo>,
         block_metas: Vec<Arc<BlockMeta>>,
@@ -141,7 +142,11 @@ impl FuseTable {
 
         let partitions_scanned = block_metas.len();
 
-        let (mut statistics, parts) = Self::to_partitions(&block_metas, &column_leaves, push_downs);
+        // Keep the pruning info in partitions for the runtime filters, which are
+        // pushed down after the partitions are read.
+        let runtime_pruning = ctx.get_settings().get_enable_runtime_filter()?;
+        let (mut statistics, parts) =
+            Self::to_partitions(&block_metas, &column_leaves, push_downs, runtime_pruning);
 
         // Update planner statistics.
         statistics.partitions_total = partitions_total;
@@ -160,6 +165,7 @@ impl FuseTable {
         blocks_metas: &[Arc<BlockMeta>],
         column_leaves: &ColumnLeaves,
         push_down: Option<PushDownInfo>,
+        runtime_pruning: bool,
     ) -> (PartStatistics, Partitions) {
         let limit = push_down
             .as_ref()
@@ -168,12 +174,16 @@ impl FuseTable {
             .unwrap_or(usize::MAX);
 
         let (mut statistics, partitions) = match &push_down {
-            None => Self::all_columns_partitions(blocks_metas, limit),
+            None => Self::all_columns_partitions(blocks_metas, limit, runtime_pruning),
             Some(extras) => match &extras.projection {
-                None => Self::all_columns_partitions(blocks_metas, limit),
-                Some(projection) => {
-                    Self::projection_partitions(blocks_metas, column_leaves, projection, limit)
-                }
+                None => Self::all_columns_partitions(blocks_metas, limit, runtime_pruning),
+                Some(projection) => Self::projection_partitions(
+                    blocks_metas,
+                    column_leaves,
+                    projection,
+                    limit,
+                    runtime_pruning,
+                ),
             },
         };
 
@@ -191,9 +201,10 @@ impl FuseTable {
     pub fn all_columns_partitions(
         metas: &[Arc<BlockMeta>],
         limit: usize,
+        runtime_pruning: bool,
     ) -> (PartStatistics, Partitions) {
         let mut statistics = PartStatistics::default_exact();
-        let mut partitions = Partitions::create(PartitionsShuffleKind::Mod, vec![]);
+        let mut partitions = Partitions::create(PartitionsShuffleKind::ConsistentHash, vec![]);
 
         if limit == 0 {
             return (statistics, partitions);
@@ -205,7 +216,7 @@ impl FuseTable {
             let rows = block_meta.row_count as usize;
             partitions
                 .partitions
-                .push(Self::all_columns_part(block_meta));
+                .push(Self::all_columns_part(block_meta, runtime_pruning));
             statistics.read_rows += rows;
             statistics.read_bytes += block_meta.block_size as usize;
 
@@ -228,9 +239,10 @@ impl FuseTable {
         column_leaves: &ColumnLeaves,
         projection: &Projection,
         limit: usize,
+        runtime_pruning: bool,
     ) -> (PartStatistics, Partitions) {
         let mut statistics = PartStatistics::default_exact();
-        let mut partitions = Partitions::default();
+        let mut partitions = Partitions::create(PartitionsShuffleKind::ConsistentHash, vec![]);
 
         if limit == 0 {
             return (statistics, partitions);
@@ -243,6 +255,7 @@ impl FuseTable {
                 block_meta,
                 column_leaves,
                 projection,
+                runtime_pruning,
             ));
             let rows = block_meta.row_count as usize;
 
@@ -270,7 +283,7 @@ impl FuseTable {
         (statistics, partitions)
     }
 
-    pub fn all_columns_part(meta: &BlockMeta) -> PartInfoPtr {
+    pub fn all_columns_part(meta: &BlockMeta, runtime_pruning: bool) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(meta.col_metas.len());
 
         for (idx, column_meta) in &meta.col_metas {
@@ -286,6 +299,8 @@ impl FuseTable {
             rows_count,
             columns_meta,
             meta.compression(),
+            Self::part_pruning_info(meta, runtime_pruning),
+            meta.inverted_index_location.clone(),
         )
     }
 
@@ -293,6 +308,7 @@ impl FuseTable {
         meta: &BlockMeta,
         column_leaves: &ColumnLeaves,
         projection: &Projection,
+        runtime_pruning: bool,
     ) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(projection.len());
 
@@ -306,6 +322,13 @@ impl FuseTable {
             }
         }
 
+        // Only the statistics of projected columns are kept.
+        let pruning_info = Self::part_pruning_info(meta, runtime_pruning).map(|mut info| {
+            info.col_stats
+                .retain(|id, _| columns_meta.contains_key(&(*id as usize)));
+            info
+        });
+
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let format_version = meta.location.1;
@@ -318,9 +341,19 @@ impl FuseTable {
             rows_count,
             columns_meta,
             meta.compression(),
+            pruning_info,
+            meta.inverted_index_location.clone(),
         )
     }
 
+    fn part_pruning_info(meta: &BlockMeta, runtime_pruning: bool) -> Option<FusePartPruningInfo> {
+        runtime_pruning.then(|| FusePartPruningInfo {
+            col_stats: meta.col_stats.clone(),
+            bloom_filter_index_location: meta.bloom_filter_index_location.clone(),
+            bloom_filter_index_size: meta.bloom_filter_index_size,
+        })
+    }
+
     fn check_quick_path(
         &self,
         snapshot: &TableSnapshot,
@@ -0,0 +1,68 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table::TermStatistics;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use storages_common_index::InvertedIndexReader;
+
+use crate::io::load_inverted_index;
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Sum up the document frequencies of the terms in the inverted indexes of the blocks,
+    /// the blocks written before the index is created are not counted.
+    pub async fn do_term_statistics(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        column_name: &str,
+        terms: &[String],
+    ) -> Result<Option<TermStatistics>> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(None);
+        };
+
+        let mut num_rows = 0;
+        let mut doc_frequencies = vec![0; terms.len()];
+        let mut indexed = false;
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        for segment in segments_io.read_segments(&snapshot.segments).await? {
+            for block in &segment?.blocks {
+                let Some(location) = &block.inverted_index_location else {
+                    continue;
+                };
+                let data = load_inverted_index(&self.operator, location).await?;
+                let index = InvertedIndexReader::try_create(&data)?;
+                if !index.has_column(column_name) {
+                    continue;
+                }
+                indexed = true;
+                num_rows += index.num_rows() as u64;
+                for (term, doc_frequency) in terms.iter().zip(doc_frequencies.iter_mut()) {
+                    let frequency = index.doc_frequency(column_name, term)?.unwrap_or_default();
+                    *doc_frequency += frequency as u64;
+                }
+            }
+        }
+
+        Ok(indexed.then_some(TermStatistics {
+            num_rows,
+            doc_frequencies,
+        }))
+    }
+}
@@ -50,7 +50,12 @@ impl FuseTable {
             let bytes = serde_json::to_vec(&new_snapshot)?;
             self.operator.object(&new_snapshot_loc).write(bytes).await?;
 
-            if purge {
+            // a `DELETE` without filter inside an explicit transaction, the table must
+            // stay untouched until the transaction is committed
+            let txn_mgr = ctx.txn_mgr();
+            let in_txn = txn_mgr.lock().is_active();
+
+            if purge && !in_txn {
                 let keep_last_snapshot = false;
                 self.do_purge(&ctx, keep_last_snapshot).await?
             }
@@ -67,15 +72,21 @@ impl FuseTable {
 
             let table_id = self.table_info.ident.table_id;
             let table_version = self.table_info.ident.seq;
-            let catalog = ctx.get_catalog(self.table_info.catalog())?;
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+            };
+            if in_txn {
+                // the copied files of the table are forgotten on `COMMIT`
+                let mut txn_mgr = txn_mgr.lock();
+                txn_mgr.update_table_meta(self.table_info.catalog(), &self.table_info, req)?;
+                txn_mgr.truncate_table(table_id);
+                return Ok(());
+            }
 
-            catalog
-                .update_table_meta(&self.table_info, UpdateTableMetaReq {
-                    table_id,
-                    seq: MatchSeq::Exact(table_version),
-                    new_table_meta,
-                })
-                .await?;
+            let catalog = ctx.get_catalog(self.table_info.catalog())?;
+            catalog.update_table_meta(&self.table_info, req).await?;
 
             catalog
                 .truncate_table(&self.table_info, TruncateTableReq { table_id })
@@ -0,0 +1,177 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::PushDownInfo;
+use common_exception::Result;
+use common_expression::Expr;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use opendal::Operator;
+use storages_common_index::InvertedIndex;
+use storages_common_index::InvertedIndexReader;
+use storages_common_table_meta::meta::Location;
+
+use crate::fuse_part::FusePartInfo;
+use crate::io::load_inverted_index;
+use crate::io::sync_load_inverted_index;
+use crate::pruning::pruner::Pruner;
+
+/// Prunes the blocks whose inverted indexes prove `match(<column>, '<query>')` is false.
+struct InvertedIndexPruner {
+    /// (column name, query) of the `match` predicates.
+    predicates: Vec<(String, String)>,
+
+    /// the data accessor
+    dal: Operator,
+}
+
+impl InvertedIndexPruner {
+    async fn may_match(&self, location: &Location) -> Result<bool> {
+        let data = load_inverted_index(&self.dal, location).await?;
+        let index = InvertedIndexReader::try_create(&data)?;
+        for (column, query) in &self.predicates {
+            if !index.may_match(column, query)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+#[async_trait::async_trait]
+impl Pruner for InvertedIndexPruner {
+    async fn should_keep(&self, index_location: &Option<Location>, _index_length: u64) -> bool {
+        if let Some(loc) = index_location {
+            match self.may_match(loc).await {
+                Ok(v) => v,
+                Err(e) => {
+                    // swallow exceptions intentionally, corrupted index should not prevent execution
+                    tracing::warn!("failed to apply inverted index, returning true. {}", e);
+                    true
+                }
+            }
+        } else {
+            // the block is written before the index is created
+            true
+        }
+    }
+}
+
+/// Try to build a pruner of the `match` predicates.
+///
+/// None will be returned if there are no `match` predicates which must be true.
+pub fn new_inverted_index_pruner(
+    filter_exprs: Option<&[Expr<String>]>,
+    dal: Operator,
+) -> Option<Arc<dyn Pruner + Send + Sync>> {
+    let predicates = filter_exprs?
+        .iter()
+        .flat_map(InvertedIndex::find_match_predicates)
+        .collect::<Vec<_>>();
+    if predicates.is_empty() {
+        return None;
+    }
+    Some(Arc::new(InvertedIndexPruner { predicates, dal }))
+}
+
+/// Finds the rows of a partition which match the `match` predicates pushed down to the scan,
+/// with the postings of the inverted index of the block.
+///
+/// The other rows are dropped once the block is deserialized, so that the predicates are
+/// only evaluated against the matched rows.
+pub struct InvertedIndexMatcher {
+    /// (column name, query) of the `match` predicates.
+    predicates: Vec<(String, String)>,
+
+    /// the data accessor
+    dal: Operator,
+}
+
+impl InvertedIndexMatcher {
+    pub fn try_create(dal: Operator, push_downs: &Option<PushDownInfo>) -> Option<Arc<Self>> {
+        let predicates = push_downs
+            .as_ref()?
+            .filters
+            .iter()
+            .filter_map(|f| f.as_expr(&BUILTIN_FUNCTIONS))
+            .flat_map(|expr| InvertedIndex::find_match_predicates(&expr))
+            .collect::<Vec<_>>();
+        if predicates.is_empty() {
+            return None;
+        }
+        Some(Arc::new(InvertedIndexMatcher { predicates, dal }))
+    }
+
+    /// The sorted offsets of the matched rows of the partition.
+    ///
+    /// Returns `None` if all the rows have to be read, e.g. the block has no inverted index.
+    pub async fn matched_rows(&self, part: &PartInfoPtr) -> Option<Vec<u32>> {
+        let location = FusePartInfo::from_part(part)
+            .ok()?
+            .inverted_index_location
+            .as_ref()?;
+        let data = load_inverted_index(&self.dal, location).await;
+        Self::ignore_error(data.and_then(|data| self.intersect(&data)))
+    }
+
+    /// The matched rows of each of the partitions.
+    pub async fn matched_rows_of_parts(&self, parts: &[PartInfoPtr]) -> Vec<Option<Vec<u32>>> {
+        let mut matched_rows = Vec::with_capacity(parts.len());
+        for part in parts {
+            matched_rows.push(self.matched_rows(part).await);
+        }
+        matched_rows
+    }
+
+    /// The blocking version of [`InvertedIndexMatcher::matched_rows`].
+    pub fn sync_matched_rows(&self, part: &PartInfoPtr) -> Option<Vec<u32>> {
+        let location = FusePartInfo::from_part(part)
+            .ok()?
+            .inverted_index_location
+            .as_ref()?;
+        let data = sync_load_inverted_index(&self.dal, location);
+        Self::ignore_error(data.and_then(|data| self.intersect(&data)))
+    }
+
+    fn ignore_error(rows: Result<Option<Vec<u32>>>) -> Option<Vec<u32>> {
+        rows.unwrap_or_else(|e| {
+            // swallow exceptions intentionally, corrupted index should not prevent execution
+            tracing::warn!(
+                "failed to apply inverted index, reading all the rows. {}",
+                e
+            );
+            None
+        })
+    }
+
+    fn intersect(&self, data: &[u8]) -> Result<Option<Vec<u32>>> {
+        let index = InvertedIndexReader::try_create(data)?;
+        let mut matched: Option<Vec<u32>> = None;
+        for (column, query) in &self.predicates {
+            let Some(rows) = index.matched_rows(column, query)? else {
+                continue;
+            };
+            matched = Some(match matched {
+                None => rows,
+                Some(matched) => matched
+                    .into_iter()
+                    .filter(|row| rows.binary_search(row).is_ok())
+                    .collect(),
+            });
+        }
+        Ok(matched)
+    }
+}
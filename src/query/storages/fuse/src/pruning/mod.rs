@@ -12,7 +12,11 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod inverted_index_pruner;
 mod pruner;
 mod pruning_executor;
+mod runtime_pruner;
 
+pub use inverted_index_pruner::InvertedIndexMatcher;
 pub use pruning_executor::BlockPruner;
+pub use runtime_pruner::RuntimeFilterPruner;
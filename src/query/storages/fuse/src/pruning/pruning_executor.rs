@@ -42,6 +42,7 @@ use storages_common_table_meta::meta::SegmentInfo;
 use tracing::warn;
 use tracing::Instrument;
 
+use super::inverted_index_pruner;
 use super::pruner;
 use crate::io::MetaReaders;
 use crate::metrics::*;
@@ -53,6 +54,7 @@ struct PruningContext {
     limiter: LimiterPruner,
     range_pruner: Arc<dyn RangePruner + Send + Sync>,
     filter_pruner: Option<Arc<dyn Pruner + Send + Sync>>,
+    inverted_index_pruner: Option<Arc<dyn Pruner + Send + Sync>>,
     rt: Arc<Runtime>,
     semaphore: Arc<Semaphore>,
 }
@@ -104,6 +106,10 @@ impl BlockPruner {
         let filter_pruner =
             pruner::new_filter_pruner(ctx, filter_exprs.as_deref(), &schema, dal.clone())?;
 
+        // prepare the inverted index pruner, if there are `match` predicates.
+        let inverted_index_pruner =
+            inverted_index_pruner::new_inverted_index_pruner(filter_exprs.as_deref(), dal.clone());
+
         // 2. constraint the degree of parallelism
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
         let max_concurrency = {
@@ -131,6 +137,7 @@ impl BlockPruner {
             limiter: limiter.clone(),
             range_pruner: range_pruner.clone(),
             filter_pruner,
+            inverted_index_pruner,
             rt: pruning_runtime.clone(),
             semaphore: semaphore.clone(),
         });
@@ -220,8 +227,8 @@ impl BlockPruner {
             .range_pruner
             .should_keep(&segment_info.summary.col_stats)
         {
-            if let Some(filter_pruner) = &pruning_ctx.filter_pruner {
-                Self::prune_blocks(&pruning_ctx, filter_pruner, segment_idx, &segment_info).await?
+            if pruning_ctx.filter_pruner.is_some() || pruning_ctx.inverted_index_pruner.is_some() {
+                Self::prune_blocks(&pruning_ctx, segment_idx, &segment_info).await?
             } else {
                 // if no available filter pruners, just prune the blocks by
                 // using zone map index, and do not spawn async tasks
@@ -236,7 +243,6 @@ impl BlockPruner {
     #[tracing::instrument(level = "debug", skip_all)]
     async fn prune_blocks(
         pruning_ctx: &Arc<PruningContext>,
-        filter_pruner: &Arc<dyn Pruner + Send + Sync>,
         segment_idx: usize,
         segment_info: &SegmentInfo,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
@@ -257,13 +263,26 @@ impl BlockPruner {
                 if pruning_ctx.range_pruner.should_keep(&block_meta.col_stats) {
                     // not pruned by block zone map index,
                     let ctx = pruning_ctx.clone();
-                    let filter_pruner = filter_pruner.clone();
                     let index_location = block_meta.bloom_filter_index_location.clone();
                     let index_size = block_meta.bloom_filter_index_size;
+                    let inverted_index_location = block_meta.inverted_index_location.clone();
+                    let inverted_index_size = block_meta.inverted_index_size;
                     let v: BlockPruningFuture = Box::new(move |permit: OwnedSemaphorePermit| {
                         Box::pin(async move {
                             let _permit = permit;
-                            let keep = filter_pruner.should_keep(&index_location, index_size).await
+                            let keep_by_filter = Self::should_keep_by(
+                                &ctx.filter_pruner,
+                                &index_location,
+                                index_size,
+                            )
+                            .await;
+                            let keep = keep_by_filter
+                                && Self::should_keep_by(
+                                    &ctx.inverted_index_pruner,
+                                    &inverted_index_location,
+                                    inverted_index_size,
+                                )
+                                .await
                                 && ctx.limiter.within_limit(row_count);
                             (block_idx, keep)
                         })
@@ -316,6 +335,17 @@ impl BlockPruner {
         Ok(result)
     }
 
+    async fn should_keep_by(
+        pruner: &Option<Arc<dyn Pruner + Send + Sync>>,
+        index_location: &Option<Location>,
+        index_size: u64,
+    ) -> bool {
+        match pruner {
+            Some(pruner) => pruner.should_keep(index_location, index_size).await,
+            None => true,
+        }
+    }
+
     fn prune_blocks_sync(
         pruning_ctx: &Arc<PruningContext>,
         segment_idx: usize,
@@ -0,0 +1,215 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::RuntimeFilter;
+use common_catalog::plan::RuntimeFilterDesc;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::DataType;
+use common_expression::Expr;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_functions::scalars::BUILTIN_FUNCTIONS;
+use opendal::Operator;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+
+use super::pruner;
+use crate::fuse_part::FusePartInfo;
+use crate::pruning::pruner::Pruner;
+
+/// Pruners built from a ready runtime filter.
+struct ReadyPruner {
+    /// The build side has no keys, all the partitions can be pruned.
+    prune_all: bool,
+    range_pruner: Arc<dyn RangePruner + Send + Sync>,
+    filter_pruner: Option<Arc<dyn Pruner + Send + Sync>>,
+}
+
+/// Prune the partitions with the runtime filters generated by hash joins, before
+/// reading the blocks. The filters not built yet are skipped.
+pub struct RuntimeFilterPruner {
+    ctx: Arc<dyn TableContext>,
+    schema: TableSchemaRef,
+    dal: Operator,
+    filters: Vec<RuntimeFilterDesc>,
+    ready_pruners: RwLock<HashMap<usize, Arc<ReadyPruner>>>,
+}
+
+impl RuntimeFilterPruner {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        schema: TableSchemaRef,
+        dal: Operator,
+        push_downs: &Option<PushDownInfo>,
+    ) -> Option<Arc<RuntimeFilterPruner>> {
+        let filters = push_downs
+            .as_ref()
+            .map(|p| p.runtime_filters.clone())
+            .unwrap_or_default();
+        if filters.is_empty() {
+            return None;
+        }
+
+        Some(Arc::new(RuntimeFilterPruner {
+            ctx,
+            schema,
+            dal,
+            filters,
+            ready_pruners: RwLock::new(HashMap::new()),
+        }))
+    }
+
+    /// Prune the partition with min/max index only, it never blocks.
+    pub fn should_keep_by_range(&self, part: &PartInfoPtr) -> Result<bool> {
+        let part = FusePartInfo::from_part(part)?;
+        let info = match &part.pruning_info {
+            None => return Ok(true),
+            Some(info) => info,
+        };
+
+        for pruner in self.ready_pruners()? {
+            if pruner.prune_all || !pruner.range_pruner.should_keep(&info.col_stats) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Prune the partition with both min/max index and bloom filter index.
+    pub async fn should_keep(&self, part: &PartInfoPtr) -> Result<bool> {
+        let fuse_part = FusePartInfo::from_part(part)?;
+        let info = match &fuse_part.pruning_info {
+            None => return Ok(true),
+            Some(info) => info,
+        };
+
+        for pruner in self.ready_pruners()? {
+            if pruner.prune_all || !pruner.range_pruner.should_keep(&info.col_stats) {
+                return Ok(false);
+            }
+            if let Some(filter_pruner) = &pruner.filter_pruner {
+                if !filter_pruner
+                    .should_keep(
+                        &info.bloom_filter_index_location,
+                        info.bloom_filter_index_size,
+                    )
+                    .await
+                {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn ready_pruners(&self) -> Result<Vec<Arc<ReadyPruner>>> {
+        let mut pruners = Vec::with_capacity(self.filters.len());
+        for desc in self.filters.iter() {
+            if let Some(pruner) = self.ready_pruners.read().unwrap().get(&desc.id) {
+                pruners.push(pruner.clone());
+                continue;
+            }
+
+            if let Some(filter) = self.ctx.get_runtime_filter(desc.id) {
+                let pruner = Arc::new(self.build_pruner(desc, &filter)?);
+                self.ready_pruners
+                    .write()
+                    .unwrap()
+                    .insert(desc.id, pruner.clone());
+                pruners.push(pruner);
+            }
+        }
+        Ok(pruners)
+    }
+
+    fn build_pruner(
+        &self,
+        desc: &RuntimeFilterDesc,
+        filter: &RuntimeFilter,
+    ) -> Result<ReadyPruner> {
+        let (min, max) = match &filter.min_max {
+            None => {
+                return Ok(ReadyPruner {
+                    prune_all: true,
+                    range_pruner: RangePrunerCreator::try_create(
+                        self.ctx.try_get_function_context()?,
+                        None,
+                        &self.schema,
+                    )?,
+                    filter_pruner: None,
+                });
+            }
+            Some(min_max) => min_max,
+        };
+
+        let field = self.schema.field_with_name(&desc.column_name)?;
+        let column = Expr::ColumnRef {
+            span: None,
+            id: desc.column_name.clone(),
+            data_type: DataType::from(field.data_type()),
+        };
+        let constant = |scalar: &Scalar| Expr::Constant {
+            span: None,
+            scalar: scalar.clone(),
+            data_type: desc.data_type.clone(),
+        };
+        let compare =
+            |op: &str, scalar: &Scalar| call_function(op, &[column.clone(), constant(scalar)]);
+
+        // `col >= min AND col <= max`
+        let range_expr = call_function("and", &[compare("gte", min)?, compare("lte", max)?])?;
+        let range_pruner = RangePrunerCreator::try_create(
+            self.ctx.try_get_function_context()?,
+            Some(&[range_expr]),
+            &self.schema,
+        )?;
+
+        // `col = v1 OR col = v2 ...`
+        let filter_pruner = match &filter.in_list {
+            Some(in_list) if !in_list.is_empty() => {
+                let mut in_list_expr = compare("eq", &in_list[0])?;
+                for scalar in in_list.iter().skip(1) {
+                    in_list_expr = call_function("or", &[in_list_expr, compare("eq", scalar)?])?;
+                }
+                pruner::new_filter_pruner(
+                    &self.ctx,
+                    Some(&[in_list_expr]),
+                    &self.schema,
+                    self.dal.clone(),
+                )?
+            }
+            _ => None,
+        };
+
+        Ok(ReadyPruner {
+            prune_all: false,
+            range_pruner,
+            filter_pruner,
+        })
+    }
+}
+
+fn call_function(name: &str, args: &[Expr<String>]) -> Result<Expr<String>> {
+    check_function(None, name, &[], args, &BUILTIN_FUNCTIONS)
+        .map_err(|(_, e)| ErrorCode::Internal(format!("Invalid expression: {}", e)))
+}
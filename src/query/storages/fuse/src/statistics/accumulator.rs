@@ -50,6 +50,7 @@ impl StatisticsAccumulator {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn add_block(
         &mut self,
         file_size: u64,
@@ -57,6 +58,8 @@ impl StatisticsAccumulator {
         block_statistics: BlockStatistics,
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
+        inverted_index_location: Option<Location>,
+        inverted_index_size: u64,
         block_compression: meta::Compression,
     ) -> Result<()> {
         self.add(
@@ -65,6 +68,8 @@ impl StatisticsAccumulator {
             block_statistics,
             bloom_filter_index_location,
             bloom_filter_index_size,
+            inverted_index_location,
+            inverted_index_size,
             block_compression,
         )
     }
@@ -77,6 +82,8 @@ impl StatisticsAccumulator {
     ) -> Result<()> {
         let bloom_filter_index_location = block_meta.bloom_filter_index_location;
         let bloom_filter_index_size = block_meta.bloom_filter_index_size;
+        let inverted_index_location = block_meta.inverted_index_location;
+        let inverted_index_size = block_meta.inverted_index_size;
         let file_size = block_meta.file_size;
         let col_metas = block_meta.col_metas;
 
@@ -86,6 +93,8 @@ impl StatisticsAccumulator {
             block_statistics,
             bloom_filter_index_location,
             bloom_filter_index_size,
+            inverted_index_location,
+            inverted_index_size,
             block_compression,
         )
     }
@@ -94,6 +103,7 @@ impl StatisticsAccumulator {
         super::reduce_block_statistics(&self.blocks_statistics, None)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn add(
         &mut self,
         file_size: u64,
@@ -101,10 +111,12 @@ impl StatisticsAccumulator {
         block_statistics: BlockStatistics,
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
+        inverted_index_location: Option<Location>,
+        inverted_index_size: u64,
         block_compression: meta::Compression,
     ) -> Result<()> {
         self.file_size += file_size;
-        self.index_size += bloom_filter_index_size;
+        self.index_size += bloom_filter_index_size + inverted_index_size;
         self.summary_block_count += 1;
         self.in_memory_size += block_statistics.block_bytes_size;
         self.summary_row_count += block_statistics.block_rows_size;
@@ -134,6 +146,8 @@ impl StatisticsAccumulator {
             data_location,
             bloom_filter_index_location,
             bloom_filter_index_size,
+            inverted_index_location,
+            inverted_index_size,
             block_compression,
         )));
 
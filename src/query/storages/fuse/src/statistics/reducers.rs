@@ -165,7 +165,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         block_count += 1;
         uncompressed_byte_size += b.block_size;
         compressed_byte_size += b.file_size;
-        index_size += b.bloom_filter_index_size;
+        index_size += b.bloom_filter_index_size + b.inverted_index_size;
         if thresholds.check_large_enough(b.row_count as usize, b.block_size as usize) {
             perfect_block_count += 1;
         }
@@ -24,7 +24,9 @@ use common_catalog::table_args::TableArgs;
 use common_catalog::table_function::TableFunction;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_hive_meta_store::AddPartitionsRequest;
 use common_hive_meta_store::Partition;
+use common_hive_meta_store::StorageDescriptor;
 use common_hive_meta_store::TThriftHiveMetastoreSyncClient;
 use common_hive_meta_store::ThriftHiveMetastoreSyncClient;
 use common_meta_app::schema::CountTablesReply;
@@ -153,6 +155,73 @@ impl HiveCatalog {
             .map_err(from_thrift_error)
     }
 
+    /// The storage descriptor of the table, which is the template of its partitions.
+    pub async fn get_storage_descriptor(
+        &self,
+        db: String,
+        table: String,
+    ) -> Result<StorageDescriptor> {
+        let client = self.get_client()?;
+        tokio::task::spawn_blocking(move || {
+            let mut client = client;
+            let table = client.get_table(db, table).map_err(from_thrift_error)?;
+            table
+                .sd
+                .ok_or_else(|| ErrorCode::TableInfoError("hive table storage descriptor is empty"))
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tracing::instrument(level = "info", skip(self, partitions))]
+    pub async fn add_partitions(
+        &self,
+        db: String,
+        table: String,
+        partitions: Vec<Partition>,
+    ) -> Result<()> {
+        let client = self.get_client()?;
+        tokio::task::spawn_blocking(move || {
+            let mut client = client;
+            let request = AddPartitionsRequest {
+                db_name: db,
+                tbl_name: table,
+                parts: partitions,
+                if_not_exists: true,
+                need_result: Some(false),
+                cat_name: None,
+            };
+            client
+                .add_partitions_req(request)
+                .map(|_| ())
+                .map_err(from_thrift_error)
+        })
+        .await
+        .unwrap()
+    }
+
+    /// Drop the partitions from the metastore, the data files are left to the caller.
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn drop_partitions(
+        &self,
+        db: String,
+        table: String,
+        partition_names: Vec<String>,
+    ) -> Result<()> {
+        let client = self.get_client()?;
+        tokio::task::spawn_blocking(move || {
+            let mut client = client;
+            for name in partition_names {
+                client
+                    .drop_partition_by_name(db.clone(), table.clone(), name, false)
+                    .map_err(from_thrift_error)?;
+            }
+            Ok(())
+        })
+        .await
+        .unwrap()
+    }
+
     fn do_get_table(
         client: impl TThriftHiveMetastoreSyncClient,
         db_name: String,
@@ -25,6 +25,8 @@ use common_catalog::plan::PartInfoPtr;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::hive_table_sink::unescape_partition_value;
+
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
 pub struct HivePartInfo {
     // file location, like /usr/hive/warehouse/ssb.db/customer.table/c_region=ASIA/c_nation=CHINA/f00.parquet
@@ -88,13 +90,13 @@ impl HivePartInfo {
     }
 }
 
-// partitions like 'c_region=ASIA/c_nation=CHINA'
+// partitions like 'c_region=ASIA/c_nation=CHINA', the values are escaped in the path
 pub fn parse_hive_partitions(partitions: &str) -> HashMap<String, String> {
     let mut partition_map = HashMap::new();
     let parts = partitions.split('/').collect::<Vec<_>>();
     for part in parts {
         let kv = part.split('=').collect::<Vec<_>>();
-        partition_map.insert(kv[0].to_string(), kv[1].to_string());
+        partition_map.insert(kv[0].to_string(), unescape_partition_value(kv[1]));
     }
     partition_map
 }
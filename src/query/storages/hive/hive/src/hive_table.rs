@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
@@ -19,6 +20,7 @@ use std::time::Instant;
 use async_recursion::async_recursion;
 use common_base::base::tokio;
 use common_base::base::tokio::sync::Semaphore;
+use common_base::runtime::GlobalIORuntime;
 use common_catalog::catalog_kind::CATALOG_HIVE;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartStatistics;
@@ -26,6 +28,7 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::AppendMode;
 use common_catalog::table::Table;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
@@ -41,6 +44,7 @@ use common_expression::Scalar;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_functions::scalars::BUILTIN_FUNCTIONS;
+use common_hive_meta_store::Partition;
 use common_meta_app::schema::TableInfo;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
@@ -56,10 +60,16 @@ use opendal::Operator;
 use storages_common_index::RangeFilter;
 
 use super::hive_catalog::HiveCatalog;
+use super::hive_partition::parse_hive_partitions;
 use super::hive_partition_pruner::HivePartitionPruner;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
 use crate::hive_parquet_block_reader::HiveBlockReader;
+use crate::hive_table_sink::escape_partition_value;
+use crate::hive_table_sink::hive_staging_dir;
+use crate::hive_table_sink::partition_value_to_string;
+use crate::hive_table_sink::HiveInsertLogEntry;
+use crate::hive_table_sink::HiveTableSink;
 use crate::hive_table_source::HiveTableSource;
 use crate::HiveBlockFilter;
 use crate::HiveFileSplitter;
@@ -399,9 +409,9 @@ impl HiveTable {
         let hive_catalog = hive_catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
 
         // todo may use get_partition_names_ps to filter
-        let table_info = self.table_info.desc.split('.').collect::<Vec<&str>>();
+        let (db, table) = self.database_and_table_name()?;
         let mut partition_names = hive_catalog
-            .get_partition_names(table_info[0].to_string(), table_info[1].to_string(), -1)
+            .get_partition_names(db.clone(), table.clone(), -1)
             .await?;
 
         if tracing::enabled!(tracing::Level::TRACE) {
@@ -437,11 +447,7 @@ impl HiveTable {
         }
 
         let partitions = hive_catalog
-            .get_partitions(
-                table_info[0].to_string(),
-                table_info[1].to_string(),
-                partition_names.clone(),
-            )
+            .get_partitions(db, table, partition_names.clone())
             .await?;
         let res = partitions
             .into_iter()
@@ -457,15 +463,7 @@ impl HiveTable {
         ctx: Arc<dyn TableContext>,
         push_downs: &Option<PushDownInfo>,
     ) -> Result<Vec<(String, Option<String>)>> {
-        let path = match &self.table_options.location {
-            Some(path) => path,
-            None => {
-                return Err(ErrorCode::TableInfoError(format!(
-                    "{}, table location is empty",
-                    self.table_info.name
-                )));
-            }
-        };
+        let path = self.table_location()?;
 
         if let Some(partition_keys) = &self.table_options.partition_keys {
             if !partition_keys.is_empty() {
@@ -488,10 +486,211 @@ impl HiveTable {
             }
         }
 
-        let location = convert_hdfs_path(path, true);
+        let location = convert_hdfs_path(&path, true);
         Ok(vec![(location, None)])
     }
 
+    fn table_location(&self) -> Result<String> {
+        match &self.table_options.location {
+            Some(path) => Ok(path.clone()),
+            None => Err(ErrorCode::TableInfoError(format!(
+                "{}, table location is empty",
+                self.table_info.name
+            ))),
+        }
+    }
+
+    // the desc of a hive table is in form of `db.table`, see `converters::try_into_table_info`
+    fn database_and_table_name(&self) -> Result<(String, String)> {
+        match self.table_info.desc.split_once('.') {
+            Some((db, table)) => Ok((db.to_string(), table.to_string())),
+            None => Err(ErrorCode::TableInfoError(format!(
+                "{}, table desc {} is not in form of `db.table`",
+                self.table_info.name, self.table_info.desc
+            ))),
+        }
+    }
+
+    // the data files are written into the staging directory of the query by the sinks, here they
+    // are moved into the directories of their partitions and the new partitions are registered
+    // into the metastore. For overwrite, the other files are removed from the overwritten partitions,
+    // which are the partitions written in dynamic mode, or the partitions matching the static
+    // partition values in static mode.
+    #[tracing::instrument(level = "info", skip(self, ctx, entries))]
+    async fn do_commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        entries: Vec<HiveInsertLogEntry>,
+        overwrite: bool,
+        static_partition: &[(String, String)],
+    ) -> Result<()> {
+        let location = convert_hdfs_path(&self.table_location()?, true);
+
+        let partition_keys = self
+            .table_options
+            .partition_keys
+            .clone()
+            .unwrap_or_default();
+        if partition_keys.is_empty() {
+            let written = self.move_staged_files(&location, &entries).await?;
+            if overwrite {
+                self.remove_files_except(location, &written).await?;
+            }
+            return Ok(());
+        }
+
+        // partition name => partition values
+        let mut new_partitions = BTreeMap::new();
+        for entry in &entries {
+            if let Some(partition) = &entry.partition {
+                new_partitions
+                    .entry(partition.name.clone())
+                    .or_insert_with(|| partition.values.clone());
+            }
+        }
+
+        let hive_catalog = ctx.get_catalog(CATALOG_HIVE)?;
+        let hive_catalog = hive_catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
+        let (db, table) = self.database_and_table_name()?;
+
+        let partition_names = hive_catalog
+            .get_partition_names(db.clone(), table.clone(), -1)
+            .await?;
+        let existing = partition_names.iter().cloned().collect::<HashSet<_>>();
+
+        // the existing partitions to be checked or overwritten
+        let dynamic = ctx.get_settings().get_hive_partition_overwrite_mode()? == "dynamic";
+        let targets = partition_names
+            .into_iter()
+            .filter(|name| {
+                new_partitions.contains_key(name)
+                    || (overwrite && !dynamic && partition_matches(name, static_partition))
+            })
+            .collect::<Vec<_>>();
+        let mut partition_locations = vec![];
+        for partition in hive_catalog
+            .get_partitions(db.clone(), table.clone(), targets)
+            .await?
+        {
+            let name = partition_keys
+                .iter()
+                .zip(partition.values.unwrap_or_default())
+                .map(|(key, value)| format!("{}={}", key, escape_partition_value(&value)))
+                .collect::<Vec<_>>()
+                .join("/");
+            let partition_location = match partition.sd.and_then(|sd| sd.location) {
+                Some(partition_location) => convert_hdfs_path(&partition_location, true),
+                None => format!("{}{}/", location, name),
+            };
+            // checked before any file is moved, the staged files are removed on failure
+            if new_partitions.contains_key(&name)
+                && partition_location != format!("{}{}/", location, name)
+            {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "insert into partition {} of table {}, which is located at {} rather than under the table location",
+                    name, self.table_info.desc, partition_location
+                )));
+            }
+            partition_locations.push((name, partition_location));
+        }
+
+        let written = self.move_staged_files(&location, &entries).await?;
+
+        if overwrite {
+            let mut dropped = vec![];
+            for (name, partition_location) in partition_locations {
+                self.remove_files_except(partition_location, &written)
+                    .await?;
+                if !new_partitions.contains_key(&name) {
+                    dropped.push(name);
+                }
+            }
+            hive_catalog
+                .drop_partitions(db.clone(), table.clone(), dropped)
+                .await?;
+        }
+
+        let added = new_partitions
+            .into_iter()
+            .filter(|(name, _)| !existing.contains(name))
+            .collect::<Vec<_>>();
+        if added.is_empty() {
+            return Ok(());
+        }
+
+        let sd = hive_catalog
+            .get_storage_descriptor(db.clone(), table.clone())
+            .await?;
+        let table_sd_location = sd.location.clone().unwrap_or_default();
+        let now = chrono::Utc::now().timestamp() as i32;
+        let partitions = added
+            .into_iter()
+            .map(|(name, values)| {
+                let mut partition_sd = sd.clone();
+                partition_sd.location = Some(format!(
+                    "{}/{}",
+                    table_sd_location.trim_end_matches('/'),
+                    name
+                ));
+                Partition {
+                    values: Some(values),
+                    db_name: Some(db.clone()),
+                    table_name: Some(table.clone()),
+                    create_time: Some(now),
+                    last_access_time: Some(0),
+                    sd: Some(partition_sd),
+                    parameters: Some(BTreeMap::new()),
+                    privileges: None,
+                    cat_name: None,
+                }
+            })
+            .collect::<Vec<_>>();
+        hive_catalog.add_partitions(db, table, partitions).await
+    }
+
+    // move the staged files into the directories of their partitions, returns the moved files.
+    // opendal can't rename objects, so the files are copied and then removed from the staging directory.
+    async fn move_staged_files(
+        &self,
+        location: &str,
+        entries: &[HiveInsertLogEntry],
+    ) -> Result<HashSet<String>> {
+        let mut moved = HashSet::with_capacity(entries.len());
+        for entry in entries {
+            let file_name = &entry.location[entry.location.rfind('/').map_or(0, |i| i + 1)..];
+            let target = match &entry.partition {
+                Some(partition) => format!("{}{}/{}", location, partition.name, file_name),
+                None => format!("{}{}", location, file_name),
+            };
+            let data = self.dal.object(&entry.location).read().await?;
+            self.dal.object(&target).write(data).await?;
+            self.dal.object(&entry.location).delete().await?;
+            moved.insert(target.trim_start_matches('/').to_string());
+        }
+        Ok(moved)
+    }
+
+    // remove the data files under the directory, except the ones just written
+    async fn remove_files_except(&self, dir: String, kept: &HashSet<String>) -> Result<()> {
+        let sem = Arc::new(Semaphore::new(60));
+        let files = list_files_from_dir(self.dal.clone(), dir, sem).await?;
+        for file in files_to_remove(&files, kept) {
+            self.dal.object(&file).delete().await?;
+        }
+        Ok(())
+    }
+
+    // remove the staging directory of the query, with the files not committed
+    async fn remove_staging_dir(&self, query_id: &str) -> Result<()> {
+        let location = convert_hdfs_path(&self.table_location()?, true);
+        let staging_dir = format!("{}{}", location, hive_staging_dir(query_id));
+        match self.dal.object(&staging_dir).metadata().await {
+            Ok(_) => Ok(self.dal.batch().remove_all(&staging_dir).await?),
+            Err(e) if e.kind() == opendal::ErrorKind::ObjectNotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     #[tracing::instrument(level = "info", skip(self))]
     async fn list_files_from_dirs(
         &self,
@@ -561,7 +760,7 @@ impl Table for HiveTable {
     }
 
     fn as_any(&self) -> &(dyn std::any::Any + 'static) {
-        todo!()
+        self
     }
 
     fn get_table_info(&self) -> &TableInfo {
@@ -597,17 +796,91 @@ impl Table for HiveTable {
         self.do_read2(ctx, plan, pipeline)
     }
 
+    fn partition_keys(&self) -> Vec<String> {
+        self.table_options
+            .partition_keys
+            .clone()
+            .unwrap_or_default()
+    }
+
+    fn append_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _: AppendMode,
+        _: bool,
+    ) -> Result<()> {
+        let location = convert_hdfs_path(&self.table_location()?, true);
+        let partition_keys = self.partition_keys();
+        let schema = self.table_info.schema();
+        pipeline.add_sink(|input| {
+            HiveTableSink::try_create(
+                input,
+                ctx.clone(),
+                self.dal.clone(),
+                location.clone(),
+                schema.clone(),
+                &partition_keys,
+            )
+        })?;
+
+        // the staged files are not committed if the insertion fails, remove them
+        let table = Arc::new(HiveTable::try_create(self.table_info.clone())?);
+        pipeline.set_on_finished(move |may_error| {
+            if may_error.is_some() {
+                let table = table.clone();
+                let query_id = ctx.get_id();
+                if let Err(cause) = GlobalIORuntime::instance()
+                    .block_on(async move { table.remove_staging_dir(&query_id).await })
+                {
+                    tracing::warn!("failed to remove the staging directory, {}", cause);
+                }
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+
     async fn commit_insertion(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _operations: Vec<DataBlock>,
-        _overwrite: bool,
+        ctx: Arc<dyn TableContext>,
+        operations: Vec<DataBlock>,
+        overwrite: bool,
     ) -> Result<()> {
-        Err(ErrorCode::Unimplemented(format!(
-            "commit_insertion operation for table {} is not implemented, table engine is {}",
-            self.name(),
-            self.get_table_info().meta.engine
-        )))
+        self.commit_partition_insertion(ctx, operations, overwrite, vec![])
+            .await
+    }
+
+    async fn commit_partition_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        operations: Vec<DataBlock>,
+        overwrite: bool,
+        partition: Vec<(String, Scalar)>,
+    ) -> Result<()> {
+        let func_ctx = ctx.try_get_function_context()?;
+        let static_partition = partition
+            .iter()
+            .map(|(key, value)| {
+                Ok((
+                    key.clone(),
+                    partition_value_to_string(value.as_ref(), &func_ctx)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let entries = operations
+            .iter()
+            .map(HiveInsertLogEntry::try_from)
+            .collect::<Result<Vec<_>>>()?;
+
+        let res = self
+            .do_commit_insertion(ctx.clone(), entries, overwrite, &static_partition)
+            .await;
+        // the staging directory is empty if all the files are moved
+        if let Err(cause) = self.remove_staging_dir(&ctx.get_id()).await {
+            tracing::warn!("failed to remove the staging directory, {}", cause);
+        }
+        res
     }
 
     async fn truncate(&self, _ctx: Arc<dyn TableContext>, _: bool) -> Result<()> {
@@ -684,6 +957,25 @@ impl HiveFileInfo {
     }
 }
 
+/// Whether the partition, like 'c_region=ASIA/c_nation=CHINA', has the static partition values
+/// of `INSERT ... PARTITION (...)`, all the partitions match if there's no static partition values.
+pub fn partition_matches(name: &str, static_partition: &[(String, String)]) -> bool {
+    let values = parse_hive_partitions(name);
+    static_partition
+        .iter()
+        .all(|(key, value)| values.get(key) == Some(value))
+}
+
+/// The files removed from an overwritten directory, which are all the files except the ones
+/// written by the insertion.
+pub fn files_to_remove(files: &[HiveFileInfo], kept: &HashSet<String>) -> Vec<String> {
+    files
+        .iter()
+        .filter(|file| !kept.contains(file.filename.trim_start_matches('/')))
+        .map(|file| file.filename.clone())
+        .collect()
+}
+
 // convert hdfs path format to opendal path formated
 //
 // there are two rules:
@@ -790,8 +1082,10 @@ async fn do_list_files_from_dir(
     let mut all_dirs = vec![];
     while let Some(de) = m.try_next().await? {
         let path = de.path();
-        let file_offset = path.rfind('/').unwrap_or_default() + 1;
-        if path[file_offset..].starts_with('.') || path[file_offset..].starts_with('_') {
+        // skip the hidden files and directories, like the staging directories of insertions
+        let name = path.trim_end_matches('/');
+        let file_offset = name.rfind('/').map_or(0, |i| i + 1);
+        if name[file_offset..].starts_with('.') || name[file_offset..].starts_with('_') {
             continue;
         }
         match de.mode().await? {
@@ -0,0 +1,408 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_base::base::uuid;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::date::date_to_string;
+use common_expression::types::timestamp::timestamp_to_string;
+use common_expression::BlockMetaInfo;
+use common_expression::DataBlock;
+use common_expression::FunctionContext;
+use common_expression::ScalarRef;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::processors::sinks::AsyncSink;
+use common_pipeline_sinks::processors::sinks::AsyncSinker;
+use opendal::Operator;
+use storages_common_blocks::blocks_to_parquet;
+use storages_common_table_meta::table::TableCompression;
+
+use crate::hive_table::HIVE_DEFAULT_PARTITION;
+
+// the max in-memory size of the data buffered for a partition, before written into a file
+const MAX_BUFFERED_BYTES: usize = 64 * 1024 * 1024;
+
+/// A partition of hive table, like 'c_region=ASIA/c_nation=CHINA'.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HivePartitionSpec {
+    // escaped partition path, like 'c_region=ASIA/c_nation=CHINA'
+    pub name: String,
+    // partition values, like ['ASIA', 'CHINA']
+    pub values: Vec<String>,
+}
+
+/// A data file written by the insertion, committed by `HiveTable::commit_insertion`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct HiveInsertLogEntry {
+    pub partition: Option<HivePartitionSpec>,
+    // location of the file in the staging directory
+    pub location: String,
+    pub row_count: usize,
+}
+
+/// The directory under the table location where the insertion writes its files before commit,
+/// it's hidden from the readers of hive and databend, which skip the names starting with '.'.
+pub fn hive_staging_dir(query_id: &str) -> String {
+    format!(".databend-staging-{}/", query_id)
+}
+
+/// Splits the rows of the inserted blocks by the hive partitions they belong to.
+pub struct HivePartitionSplitter {
+    partition_keys: Vec<String>,
+    partition_offsets: Vec<usize>,
+    func_ctx: FunctionContext,
+}
+
+impl HivePartitionSplitter {
+    pub fn try_create(
+        schema: &TableSchema,
+        partition_keys: &[String],
+        func_ctx: FunctionContext,
+    ) -> Result<Self> {
+        let mut partition_offsets = Vec::with_capacity(partition_keys.len());
+        for key in partition_keys {
+            partition_offsets.push(schema.index_of(key)?);
+        }
+        Ok(HivePartitionSplitter {
+            partition_keys: partition_keys.to_vec(),
+            partition_offsets,
+            func_ctx,
+        })
+    }
+
+    pub fn partition_offsets(&self) -> &[usize] {
+        &self.partition_offsets
+    }
+
+    /// Returns the rows of each partition, in the order of the first row of the partitions.
+    pub fn split(&self, block: &DataBlock) -> Result<Vec<(HivePartitionSpec, DataBlock)>> {
+        let mut partitions: Vec<(HivePartitionSpec, Vec<u32>)> = vec![];
+        let mut index_of_partitions = HashMap::new();
+        for row in 0..block.num_rows() {
+            let partition = self.partition_of_row(block, row)?;
+            let index = *index_of_partitions
+                .entry(partition.clone())
+                .or_insert_with(|| {
+                    partitions.push((partition, vec![]));
+                    partitions.len() - 1
+                });
+            partitions[index].1.push(row as u32);
+        }
+
+        partitions
+            .into_iter()
+            .map(|(partition, rows)| Ok((partition, block.take(&rows)?)))
+            .collect()
+    }
+
+    fn partition_of_row(&self, block: &DataBlock, row: usize) -> Result<HivePartitionSpec> {
+        let mut names = Vec::with_capacity(self.partition_keys.len());
+        let mut values = Vec::with_capacity(self.partition_keys.len());
+        for (key, offset) in self.partition_keys.iter().zip(&self.partition_offsets) {
+            let entry = block.get_by_offset(*offset);
+            let value = entry.value.as_ref();
+            let value = partition_value_to_string(
+                value.index(row).unwrap_or(ScalarRef::Null),
+                &self.func_ctx,
+            )?;
+            names.push(format!("{}={}", key, escape_partition_value(&value)));
+            values.push(value);
+        }
+        Ok(HivePartitionSpec {
+            name: names.join("/"),
+            values,
+        })
+    }
+}
+
+impl TryFrom<HiveInsertLogEntry> for DataBlock {
+    type Error = ErrorCode;
+    fn try_from(value: HiveInsertLogEntry) -> Result<Self, Self::Error> {
+        Ok(DataBlock::new_with_meta(vec![], 0, Some(Box::new(value))))
+    }
+}
+
+impl TryFrom<&DataBlock> for HiveInsertLogEntry {
+    type Error = ErrorCode;
+    fn try_from(block: &DataBlock) -> Result<Self, Self::Error> {
+        let err = ErrorCode::Internal(format!(
+            "invalid data block meta of hive insertion log, {:?}",
+            block.meta()
+        ));
+
+        if let Some(meta) = block.meta()? {
+            let cast = meta.as_any().downcast_ref::<HiveInsertLogEntry>();
+            return match cast {
+                None => Err(err),
+                Some(entry) => Ok(entry.clone()),
+            };
+        }
+
+        Err(err)
+    }
+}
+
+#[typetag::serde(name = "hive_insert_log")]
+impl BlockMetaInfo for HiveInsertLogEntry {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        match info.as_any().downcast_ref::<HiveInsertLogEntry>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+}
+
+#[derive(Default)]
+struct PartitionBuffer {
+    blocks: Vec<DataBlock>,
+    bytes: usize,
+}
+
+/// Writes the data into parquet files under the partition directories of the staging directory,
+/// the files are moved into the table and visible to hive when the insertion is committed.
+pub struct HiveTableSink {
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    // staging directory of the query, like /user/hive/warehouse/ssb.db/customer/.databend-staging-<query_id>/
+    location: String,
+    // schema of the data files, which excludes the partition columns
+    data_schema: TableSchemaRef,
+    data_offsets: Vec<usize>,
+    splitter: Option<HivePartitionSplitter>,
+    buffers: HashMap<Option<HivePartitionSpec>, PartitionBuffer>,
+    file_prefix: String,
+    file_count: usize,
+}
+
+impl HiveTableSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        location: String,
+        schema: TableSchemaRef,
+        partition_keys: &[String],
+    ) -> Result<ProcessorPtr> {
+        let splitter = HivePartitionSplitter::try_create(
+            &schema,
+            partition_keys,
+            ctx.try_get_function_context()?,
+        )?;
+
+        let mut data_offsets = vec![];
+        let mut data_fields = vec![];
+        for (offset, field) in schema.fields().iter().enumerate() {
+            if !splitter.partition_offsets().contains(&offset) {
+                data_offsets.push(offset);
+                data_fields.push(field.clone());
+            }
+        }
+
+        let location = format!("{}{}", location, hive_staging_dir(&ctx.get_id()));
+        Ok(AsyncSinker::create(input, HiveTableSink {
+            ctx,
+            dal,
+            location,
+            data_schema: Arc::new(TableSchema::new(data_fields)),
+            data_offsets,
+            splitter: (!partition_keys.is_empty()).then_some(splitter),
+            buffers: HashMap::new(),
+            file_prefix: format!("part-{}", uuid::Uuid::new_v4().simple()),
+            file_count: 0,
+        }))
+    }
+
+    fn buffer(&mut self, partition: Option<HivePartitionSpec>, block: DataBlock) -> bool {
+        let num_rows = block.num_rows();
+        let columns = self
+            .data_offsets
+            .iter()
+            .map(|offset| block.get_by_offset(*offset).clone())
+            .collect();
+        let block = DataBlock::new(columns, num_rows);
+
+        let buffer = self.buffers.entry(partition).or_default();
+        buffer.bytes += block.memory_size();
+        buffer.blocks.push(block);
+        buffer.bytes >= MAX_BUFFERED_BYTES
+    }
+
+    async fn flush(&mut self, partition: Option<HivePartitionSpec>) -> Result<()> {
+        let buffer = match self.buffers.remove(&partition) {
+            Some(buffer) if !buffer.blocks.is_empty() => buffer,
+            _ => return Ok(()),
+        };
+
+        let row_count = buffer.blocks.iter().map(|block| block.num_rows()).sum();
+        let mut data = Vec::with_capacity(buffer.bytes);
+        blocks_to_parquet(
+            &self.data_schema,
+            buffer.blocks,
+            &mut data,
+            TableCompression::Snappy,
+        )?;
+
+        let directory = match &partition {
+            Some(partition) => format!("{}{}/", self.location, partition.name),
+            None => self.location.clone(),
+        };
+        let location = format!(
+            "{}{}-{:05}.parquet",
+            directory, self.file_prefix, self.file_count
+        );
+        self.file_count += 1;
+        self.dal.object(&location).write(data).await?;
+
+        let entry = HiveInsertLogEntry {
+            partition,
+            location,
+            row_count,
+        };
+        self.ctx.push_precommit_block(DataBlock::try_from(entry)?);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncSink for HiveTableSink {
+    const NAME: &'static str = "HiveTableSink";
+
+    async fn on_finish(&mut self) -> Result<()> {
+        let partitions = self.buffers.keys().cloned().collect::<Vec<_>>();
+        for partition in partitions {
+            self.flush(partition).await?;
+        }
+        Ok(())
+    }
+
+    #[unboxed_simple]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<()> {
+        if data_block.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let splitter = match &self.splitter {
+            None => {
+                if self.buffer(None, data_block) {
+                    self.flush(None).await?;
+                }
+                return Ok(());
+            }
+            Some(splitter) => splitter,
+        };
+
+        for (partition, block) in splitter.split(&data_block)? {
+            if self.buffer(Some(partition.clone()), block) {
+                self.flush(Some(partition)).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats the partition value in the partition path the same way as hive.
+pub fn partition_value_to_string(value: ScalarRef, func_ctx: &FunctionContext) -> Result<String> {
+    match value {
+        ScalarRef::Null => Ok(HIVE_DEFAULT_PARTITION.to_string()),
+        ScalarRef::String(s) if s.is_empty() => Ok(HIVE_DEFAULT_PARTITION.to_string()),
+        ScalarRef::String(s) => Ok(String::from_utf8_lossy(s).to_string()),
+        ScalarRef::Number(n) => Ok(n.to_string()),
+        ScalarRef::Boolean(b) => Ok(b.to_string()),
+        ScalarRef::Date(d) => Ok(date_to_string(d, func_ctx.tz).to_string()),
+        ScalarRef::Timestamp(ts) => {
+            // hive writes the fraction of seconds only if it's not zero, like '2023-01-01 08:00:00.5'
+            let ts = timestamp_to_string(ts, func_ctx.tz).to_string();
+            Ok(ts.trim_end_matches('0').trim_end_matches('.').to_string())
+        }
+        other => Err(ErrorCode::Unimplemented(format!(
+            "hive partition value {} is not supported",
+            other
+        ))),
+    }
+}
+
+// escape the partition value in the path, same as `FileUtils.escapePathName` of hive
+pub fn escape_partition_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c < ' '
+            || matches!(
+                c,
+                '"' | '#'
+                    | '%'
+                    | '\''
+                    | '*'
+                    | '/'
+                    | ':'
+                    | '='
+                    | '?'
+                    | '\\'
+                    | '\x7F'
+                    | '{'
+                    | '['
+                    | ']'
+                    | '^'
+            )
+        {
+            escaped.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+// unescape the partition value in the path, same as `FileUtils.unescapePathName` of hive
+pub fn unescape_partition_value(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut unescaped = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let code = std::str::from_utf8(&bytes[i + 1..i + 3])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            if let Some(code) = code {
+                unescaped.push(code);
+                i += 3;
+                continue;
+            }
+        }
+        unescaped.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&unescaped).to_string()
+}
@@ -28,6 +28,7 @@ mod hive_partition_filler;
 mod hive_partition_pruner;
 mod hive_table;
 mod hive_table_options;
+mod hive_table_sink;
 mod hive_table_source;
 mod utils;
 
@@ -39,5 +40,12 @@ pub use hive_meta_data_reader::MetaDataReader;
 pub use hive_parquet_block_reader::filter_hive_partition_from_partition_keys;
 pub use hive_partition::HivePartInfo;
 pub use hive_partition_filler::HivePartitionFiller;
+pub use hive_table::files_to_remove;
+pub use hive_table::partition_matches;
 pub use hive_table::HiveFileInfo;
 pub use hive_table::HiveTable;
+pub use hive_table_sink::escape_partition_value;
+pub use hive_table_sink::partition_value_to_string;
+pub use hive_table_sink::unescape_partition_value;
+pub use hive_table_sink::HivePartitionSpec;
+pub use hive_table_sink::HivePartitionSplitter;
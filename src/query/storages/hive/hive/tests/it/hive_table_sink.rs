@@ -0,0 +1,194 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::Int32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_storages_hive::escape_partition_value;
+use common_storages_hive::files_to_remove;
+use common_storages_hive::partition_matches;
+use common_storages_hive::partition_value_to_string;
+use common_storages_hive::unescape_partition_value;
+use common_storages_hive::HiveFileInfo;
+use common_storages_hive::HivePartitionSplitter;
+
+#[test]
+fn test_escape_partition_value() {
+    let cases = vec![
+        ("ASIA", "ASIA"),
+        ("a b", "a b"),
+        ("a/b", "a%2Fb"),
+        ("a=b:c", "a%3Db%3Ac"),
+        ("100%", "100%25"),
+        ("{x}[y]", "%7Bx}%5By%5D"),
+        ("tab\t", "tab%09"),
+        ("中国", "中国"),
+    ];
+    for (value, escaped) in cases {
+        assert_eq!(escape_partition_value(value), escaped);
+        assert_eq!(unescape_partition_value(escaped), value);
+    }
+
+    // not escaped by hive, kept as is
+    assert_eq!(unescape_partition_value("100%"), "100%");
+    assert_eq!(unescape_partition_value("%zz"), "%zz");
+}
+
+#[test]
+fn test_partition_value_to_string() -> Result<()> {
+    let func_ctx = FunctionContext::default();
+    let cases = vec![
+        (ScalarRef::Null, "__HIVE_DEFAULT_PARTITION__"),
+        (ScalarRef::String(b""), "__HIVE_DEFAULT_PARTITION__"),
+        (ScalarRef::String(b"ASIA"), "ASIA"),
+        (ScalarRef::Number(NumberScalar::Int32(-1)), "-1"),
+        (ScalarRef::Boolean(true), "true"),
+        (ScalarRef::Boolean(false), "false"),
+        (ScalarRef::Date(19358), "2023-01-01"),
+        (
+            ScalarRef::Timestamp(1672560000000000),
+            "2023-01-01 08:00:00",
+        ),
+        (
+            ScalarRef::Timestamp(1672560000500000),
+            "2023-01-01 08:00:00.5",
+        ),
+        (
+            ScalarRef::Timestamp(1672560010000000),
+            "2023-01-01 08:00:10",
+        ),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(partition_value_to_string(value, &func_ctx)?, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_split_by_partition() -> Result<()> {
+    let schema = TableSchema::new(vec![
+        TableField::new("id", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("region", TableDataType::String),
+        TableField::new("nation", TableDataType::String),
+    ]);
+    let splitter = HivePartitionSplitter::try_create(
+        &schema,
+        &["region".to_string(), "nation".to_string()],
+        FunctionContext::default(),
+    )?;
+    assert_eq!(splitter.partition_offsets(), &[1, 2]);
+
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data(vec![1, 2, 3, 4]),
+        StringType::from_data(vec!["ASIA", "EUROPE", "ASIA", "ASIA"]),
+        StringType::from_data(vec!["CHINA", "FRANCE", "JAPAN/X", "CHINA"]),
+    ]);
+    let partitions = splitter.split(&block)?;
+    let partitions = partitions
+        .iter()
+        .map(|(partition, block)| {
+            let ids = block
+                .get_by_offset(0)
+                .value
+                .as_column()
+                .unwrap()
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>();
+            (partition.name.as_str(), partition.values.clone(), ids)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(partitions, vec![
+        (
+            "region=ASIA/nation=CHINA",
+            vec!["ASIA".to_string(), "CHINA".to_string()],
+            vec!["1".to_string(), "4".to_string()]
+        ),
+        (
+            "region=EUROPE/nation=FRANCE",
+            vec!["EUROPE".to_string(), "FRANCE".to_string()],
+            vec!["2".to_string()]
+        ),
+        (
+            "region=ASIA/nation=JAPAN%2FX",
+            vec!["ASIA".to_string(), "JAPAN/X".to_string()],
+            vec!["3".to_string()]
+        ),
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_partition_matches() {
+    let name = "region=ASIA/nation=JAPAN%2FX";
+    assert!(partition_matches(name, &[]));
+    assert!(partition_matches(name, &[(
+        "region".to_string(),
+        "ASIA".to_string()
+    )]));
+    assert!(partition_matches(name, &[
+        ("region".to_string(), "ASIA".to_string()),
+        ("nation".to_string(), "JAPAN/X".to_string()),
+    ]));
+    assert!(!partition_matches(name, &[(
+        "region".to_string(),
+        "EUROPE".to_string()
+    )]));
+    assert!(!partition_matches(name, &[(
+        "nation".to_string(),
+        "JAPAN".to_string()
+    )]));
+}
+
+#[test]
+fn test_files_to_remove() {
+    let files = vec![
+        HiveFileInfo::create("/warehouse/t/region=ASIA/old-00000.parquet".to_string(), 1),
+        HiveFileInfo::create(
+            "/warehouse/t/region=ASIA/part-1-00000.parquet".to_string(),
+            1,
+        ),
+        HiveFileInfo::create(
+            "/warehouse/t/region=ASIA/part-1-00001.parquet".to_string(),
+            1,
+        ),
+        HiveFileInfo::create("/warehouse/t/region=ASIA/old-00001.parquet".to_string(), 1),
+    ];
+    let kept = [
+        "warehouse/t/region=ASIA/part-1-00000.parquet",
+        "warehouse/t/region=ASIA/part-1-00001.parquet",
+        "warehouse/t/region=EUROPE/part-1-00002.parquet",
+    ]
+    .iter()
+    .map(|file| file.to_string())
+    .collect::<HashSet<_>>();
+
+    assert_eq!(files_to_remove(&files, &kept), vec![
+        "/warehouse/t/region=ASIA/old-00000.parquet".to_string(),
+        "/warehouse/t/region=ASIA/old-00001.parquet".to_string(),
+    ]);
+    assert!(files_to_remove(&files[1..3], &kept).is_empty());
+    assert_eq!(files_to_remove(&files, &HashSet::new()).len(), 4);
+}
@@ -13,3 +13,4 @@
 // limitations under the License.
 
 mod hive_file_splitter;
+mod hive_table_sink;
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use backon::ExponentialBackoff;
 use backon::Retryable;
+use common_catalog::plan::RangeBlockMeta;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -56,6 +57,9 @@ pub struct StageTableSink {
     uuid: String,
     group_id: usize,
     batch_id: usize,
+    /// Range of the ordered merge exchange being unloaded, every range is
+    /// written into its own sequence of files.
+    range: Option<usize>,
 
     single: bool,
     max_file_size: usize,
@@ -104,6 +108,7 @@ impl StageTableSink {
             uuid,
             group_id,
             batch_id: 0,
+            range: None,
             max_file_size,
         })))
     }
@@ -113,26 +118,61 @@ impl StageTableSink {
             "{:?}",
             self.table_info.user_stage_info.file_format_options.format
         );
+        // The files of the ranges are named by the zero-padded range index and batch id,
+        // so that the order of the names follows the sort order.
+        let file_id = match self.range {
+            Some(range) => format!("{:06}_{:06}", range, self.batch_id),
+            None => format!("{}_{}", self.group_id, self.batch_id),
+        };
         if self.table_info.path.ends_with("data_") {
             format!(
-                "{}{}_{}_{}.{}",
+                "{}{}_{}.{}",
                 self.table_info.path,
                 self.uuid,
-                self.group_id,
-                self.batch_id,
+                file_id,
                 format_name.to_ascii_lowercase()
             )
         } else {
             format!(
-                "{}/data_{}_{}_{}.{}",
+                "{}/data_{}_{}.{}",
                 self.table_info.path,
                 self.uuid,
-                self.group_id,
-                self.batch_id,
+                file_id,
                 format_name.to_ascii_lowercase()
             )
         }
     }
+
+    /// Switch to the range of the block, returns the data of the current file
+    /// if it should be written before the block.
+    fn switch_range(&mut self, datablock: &DataBlock) -> Result<Option<Vec<u8>>> {
+        let meta = datablock
+            .get_meta()
+            .and_then(|meta| meta.as_any().downcast_ref::<RangeBlockMeta>());
+        let range = match meta {
+            Some(meta) if !self.single => meta.range,
+            _ => return Ok(None),
+        };
+
+        if self.range == Some(range) {
+            return Ok(None);
+        }
+
+        if self.range.is_some() {
+            if self.output_format.buffer_size() > 0 {
+                let bs = self.output_format.finalize()?;
+                self.working_buffer.extend_from_slice(&bs);
+            }
+
+            if !self.working_buffer.is_empty() {
+                return Ok(Some(std::mem::take(&mut self.working_buffer)));
+            }
+        }
+
+        self.range = Some(range);
+        self.batch_id = 0;
+        Ok(None)
+    }
 }
 
 #[async_trait]
@@ -196,6 +236,12 @@ impl Processor for StageTableSink {
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::None) {
             State::NeedSerialize(datablock) => {
+                if let Some(data) = self.switch_range(&datablock)? {
+                    self.working_datablocks.clear();
+                    self.state = State::NeedWrite(data, Some(datablock));
+                    return Ok(());
+                }
+
                 if !self.write_header {
                     let prefix = self.output_format.serialize_prefix()?;
                     self.working_buffer.extend_from_slice(&prefix);
@@ -133,7 +133,7 @@ impl ColumnsTable {
                 .list_tables(tenant.as_str(), database.name())
                 .await?
             {
-                for field in table.schema().fields() {
+                for field in table.get_table_info().meta.schema.fields() {
                     rows.push((database.name().into(), table.name().into(), field.clone()))
                 }
             }
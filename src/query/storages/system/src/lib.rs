@@ -39,6 +39,7 @@ mod table;
 mod tables_table;
 mod tracing_table;
 mod users_table;
+mod workload_groups_table;
 
 pub use catalogs_table::CatalogsTable;
 pub use clustering_history_table::ClusteringHistoryLogElement;
@@ -74,3 +75,4 @@ pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
 pub use tracing_table::TracingTable;
 pub use users_table::UsersTable;
+pub use workload_groups_table::WorkloadGroupsTable;
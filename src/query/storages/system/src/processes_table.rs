@@ -67,6 +67,7 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_mysql_connection_id = Vec::with_capacity(processes_info.len());
         let mut processes_time = Vec::with_capacity(processes_info.len());
         let mut processes_status = Vec::with_capacity(processes_info.len());
+        let mut processes_workload_group = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             let data_metrics = &process_info.data_metrics;
@@ -96,6 +97,10 @@ impl SyncSystemTable for ProcessesTable {
             processes_scan_progress_read_bytes.push(scan_progress.bytes as u64);
             processes_mysql_connection_id.push(process_info.mysql_connection_id);
             processes_time.push(time);
+            processes_workload_group.push(
+                ProcessesTable::process_option_value(process_info.workload_group.clone())
+                    .into_bytes(),
+            );
 
             if let Some(data_metrics) = data_metrics {
                 processes_data_read_bytes.push(data_metrics.get_read_bytes() as u64);
@@ -124,6 +129,7 @@ impl SyncSystemTable for ProcessesTable {
             UInt32Type::from_opt_data(processes_mysql_connection_id),
             UInt64Type::from_data(processes_time),
             StringType::from_data(processes_status),
+            StringType::from_data(processes_workload_group),
         ]))
     }
 }
@@ -164,6 +170,7 @@ impl ProcessesTable {
             ),
             TableField::new("time", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("status", TableDataType::String),
+            TableField::new("workload_group", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
@@ -22,6 +22,7 @@ mod user_mgr;
 mod user_setting;
 mod user_stage;
 mod user_udf;
+mod user_workload_group;
 
 pub mod idm_config;
 pub mod role_cache_mgr;
@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::WorkloadGroup;
+
+use crate::UserApiProvider;
+
+/// Workload group operations.
+impl UserApiProvider {
+    // Add a new workload group.
+    pub async fn add_workload_group(
+        &self,
+        tenant: &str,
+        group: WorkloadGroup,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        match client.add_workload_group(group).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::WORKLOAD_GROUP_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get all workload groups for the tenant.
+    pub async fn get_workload_groups(&self, tenant: &str) -> Result<Vec<WorkloadGroup>> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        match client.get_workload_groups().await {
+            Err(e) => Err(e.add_message_back("(while get workload groups).")),
+            Ok(groups) => Ok(groups),
+        }
+    }
+
+    // Drop a workload group by name.
+    pub async fn drop_workload_group(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        match client.drop_workload_group(name, None).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop workload group)"))
+                }
+            }
+        }
+    }
+}
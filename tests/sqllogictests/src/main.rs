@@ -21,7 +21,6 @@ use clap::Parser;
 use client::ClickhouseHttpClient;
 use futures_util::stream;
 use futures_util::StreamExt;
-use sqllogictest::default_validator;
 use sqllogictest::parse_file;
 use sqllogictest::update_test_file;
 use sqllogictest::DBOutput;
@@ -37,6 +36,7 @@ use crate::client::MySQLClient;
 use crate::error::DSqlLogicTestError;
 use crate::error::Result;
 use crate::util::get_files;
+use crate::util::mask_duration_validator;
 
 mod arg;
 mod client;
@@ -147,7 +147,6 @@ async fn create_databend(client_type: &ClientType) -> Result<Databend> {
 }
 
 async fn run_suits(suits: ReadDir, client_type: ClientType) -> Result<()> {
-    // Todo: set validator to process regex
     let args = SqlLogicTestArgs::parse();
     let mut tasks = vec![];
     let start = Instant::now();
@@ -175,7 +174,7 @@ async fn run_suits(suits: ReadDir, client_type: ClientType) -> Result<()> {
             }
             if args.complete {
                 let col_separator = " ";
-                let validator = default_validator;
+                let validator = mask_duration_validator;
                 let mut runner = Runner::new(create_databend(&client_type).await?);
                 update_test_file(file.unwrap().path(), &mut runner, col_separator, validator)
                     .await
@@ -232,6 +231,7 @@ async fn run_file_async(
     let no_fail_fast = SqlLogicTestArgs::parse().no_fail_fast;
     let records = parse_file(&filename).unwrap();
     let mut runner = Runner::new(create_databend(client_type).await.unwrap());
+    runner.with_validator(mask_duration_validator);
     for record in records.into_iter() {
         if let Record::Halt { .. } = record {
             break;
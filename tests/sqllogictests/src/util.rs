@@ -22,6 +22,7 @@ use regex::RegexBuilder;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
+use sqllogictest::default_validator;
 use walkdir::DirEntry;
 use walkdir::WalkDir;
 
@@ -43,6 +44,8 @@ lazy_static! {
         .case_insensitive(true)
         .build()
         .unwrap();
+    pub static ref DURATION_RE: Regex =
+        Regex::new(r"(?P<key>\w+ time): \d+(\.\d+)?(ns|µs|ms|s)").unwrap();
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -76,6 +79,25 @@ pub fn parser_rows(rows: &Value) -> Result<Vec<Vec<String>>> {
     Ok(parsed_rows)
 }
 
+/// Compare the results like `default_validator`, but mask the durations, e.g. the
+/// `cpu time: 1.2ms` of `EXPLAIN ANALYZE`, which are different in every run.
+/// The expected results write them as `cpu time: <duration>`.
+pub fn mask_duration_validator(actual: &[Vec<String>], expected: &[String]) -> bool {
+    let masked_actual = actual
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| {
+                    DURATION_RE
+                        .replace_all(cell, "$key: <duration>")
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    default_validator(&masked_actual, expected)
+}
+
 fn find_specific_dir(dir: &str, suit: PathBuf) -> Result<DirEntry> {
     for entry in WalkDir::new(suit)
         .min_depth(0)
@@ -0,0 +1,16 @@
+insert into hive.default.t_w select number, to_string(number) from numbers(3);
+select * from hive.default.t_w order by id;
+insert overwrite hive.default.t_w select number + 10, to_string(number) from numbers(2);
+select * from hive.default.t_w order by id;
+insert into hive.default.customer_w values ('a', 'ASIA', 'CHINA'), ('b', 'ASIA', 'JAPAN'), ('c', 'EUROPE', 'FRANCE');
+select * from hive.default.customer_w order by c_region, c_nation;
+select '==static overwrite==';
+insert overwrite hive.default.customer_w partition (c_region = 'ASIA') select 'd', 'KOREA';
+select * from hive.default.customer_w order by c_region, c_nation;
+select '==dynamic overwrite==';
+set hive_partition_overwrite_mode = 'dynamic';
+insert overwrite hive.default.customer_w select 'e', 'EUROPE', 'FRANCE';
+select * from hive.default.customer_w order by c_region, c_nation;
+select '==static partition==';
+insert into hive.default.customer_w partition (c_region = 'EUROPE', c_nation = 'GERMANY') select 'f';
+select * from hive.default.customer_w order by c_region, c_nation;